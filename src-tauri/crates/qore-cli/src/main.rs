@@ -5,9 +5,9 @@ use std::process::ExitCode;
 use clap::{Parser, Subcommand};
 
 use qore_core::{CollectionListOptions, Namespace, SessionId};
+use qore_service::paths::{config_dir, PROJECT_ID, QUERY_TIMEOUT_MS};
 use qore_service::vault::backend::KeyringProvider;
 use qore_service::vault::VaultStorage;
-use qore_service::paths::{config_dir, PROJECT_ID, QUERY_TIMEOUT_MS};
 use qore_service::ServiceContext;
 
 #[derive(Parser)]
@@ -58,7 +58,7 @@ async fn connect(ctx: &ServiceContext, connection_id: &str) -> Result<SessionId,
     let config = saved
         .to_connection_config(&creds)
         .map_err(|e| e.sanitized_message())?;
-    qore_service::connection::connect(&ctx.session_manager, config)
+    qore_service::connection::connect(&ctx.session_manager, &ctx.query_manager, config)
         .await
         .map_err(|e| e.sanitized())
 }
@@ -121,6 +121,7 @@ async fn run(command: Command) -> Result<String, String> {
                 false,
                 None,
                 None,
+                None,
                 |_, _| {},
             )
             .await;
@@ -145,6 +146,7 @@ async fn run(command: Command) -> Result<String, String> {
                 search: None,
                 page: None,
                 page_size: None,
+                cursor: None,
             };
             let list = driver
                 .list_collections(session, &namespace, options)
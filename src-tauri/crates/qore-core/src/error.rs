@@ -13,6 +13,9 @@ pub enum EngineError {
     #[error("Authentication failed: {message}")]
     AuthenticationFailed { message: String },
 
+    #[error("Permission denied: {message}")]
+    PermissionDenied { message: String },
+
     #[error("Query syntax error: {message}")]
     SyntaxError { message: String },
 
@@ -72,6 +75,12 @@ impl EngineError {
         }
     }
 
+    pub fn permission_denied(msg: impl Into<String>) -> Self {
+        Self::PermissionDenied {
+            message: msg.into(),
+        }
+    }
+
     pub fn syntax_error(msg: impl Into<String>) -> Self {
         Self::SyntaxError {
             message: msg.into(),
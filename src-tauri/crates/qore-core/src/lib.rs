@@ -9,5 +9,7 @@ pub mod types;
 
 pub use error::{sanitize_error_message, EngineError, EngineResult};
 pub use registry::DriverRegistry;
-pub use traits::{DataEngine, StreamEvent, StreamSender};
+pub use traits::{
+    CopyInSource, CopyOutSender, DataEngine, NotificationSender, StreamEvent, StreamSender,
+};
 pub use types::*;
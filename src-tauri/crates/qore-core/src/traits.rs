@@ -8,14 +8,18 @@ use async_trait::async_trait;
 
 use crate::error::{EngineError, EngineResult};
 use crate::types::{
-    CancelSupport, CollectionList, CollectionListOptions, ColumnInfo, ConnectionConfig,
-    CreationOptions, DriverCapabilities, EventDefinition, EventList, EventListOptions,
-    EventOperationResult, ForeignKey, MaintenanceOperationInfo, MaintenanceRequest,
-    MaintenanceResult, Namespace, PaginatedQueryResult, QueryId, QueryResult, RoutineDefinition,
-    RoutineList, RoutineListOptions, RoutineOperationResult, RoutineType, Row, RowData,
-    SequenceDefinition, SequenceList, SequenceListOptions, SequenceOperationResult, SessionId,
-    TableQueryOptions, TableSchema, TriggerDefinition, TriggerList, TriggerListOptions,
-    TriggerOperationResult, Value,
+    BigKeysReport, BloatReport, BulkImportOptions, CancelSupport, CollectionList,
+    CollectionListOptions, ColumnInfo, ColumnStats, ConnectionConfig, CopyOptions, CopySource,
+    CreationOptions, DescribeTableOptions, DriverCapabilities, EventDefinition, EventList,
+    EventListOptions, EventOperationResult, ExplainPlan, ForeignKey, IndexSpec, IsolationLevel,
+    KeyMetadata, MaintenanceOperationInfo, MaintenanceRequest, MaintenanceResult, Namespace,
+    Notification, PaginatedQueryResult, PoolStats, QueryId, QueryProgress, QueryResult,
+    RoutineDefinition, RoutineList, RoutineListOptions, RoutineOperationResult, RoutineType, Row,
+    RowData, ScheduledJobList, ScheduledJobOperationResult, SequenceDefinition, SequenceList,
+    SequenceListOptions, SequenceOperationResult, ServerInfo, ServerLogChunk, SessionId,
+    SqlSyntaxError, TablePartitioning, TableQueryOptions, TableSchema, TriggerDefinition,
+    TriggerList, TriggerListOptions, TriggerOperationResult, UnusedIndexReport, UpsertResult,
+    Value,
 };
 
 /// Events emitted during query streaming
@@ -29,6 +33,9 @@ pub enum StreamEvent {
     RowBatch(Vec<Row>),
     /// Error occurred during streaming
     Error(String),
+    /// The row cap was reached: `returned` rows were sent out of a larger
+    /// result, which was cancelled. Followed by `Done`.
+    Truncated { returned: u64, cap: u64 },
     /// Streaming complete. Contains affected rows count if applicable.
     Done(u64),
 }
@@ -36,6 +43,15 @@ pub enum StreamEvent {
 /// Sender for streaming events
 pub type StreamSender = tokio::sync::mpsc::Sender<StreamEvent>;
 
+/// Sender for raw bytes produced by a server-side COPY export
+pub type CopyOutSender = tokio::sync::mpsc::Sender<Vec<u8>>;
+
+/// Sender for the notifications of a `listen` subscription
+pub type NotificationSender = tokio::sync::mpsc::Sender<Notification>;
+
+/// Raw CSV bytes consumed by a server-side COPY import
+pub type CopyInSource = Box<dyn tokio::io::AsyncRead + Send + Unpin>;
+
 /// Universal database driver interface. One implementor per backend
 /// (PostgreSQL, MySQL, MongoDB, …).
 #[async_trait]
@@ -61,13 +77,44 @@ pub trait DataEngine: Send + Sync {
 
     /// Lightweight health check for an active session.
     ///
-    /// Returns `Ok(())` if the connection is alive, or an error if unreachable.
-    /// Used by the keep-alive monitor to detect stale connections.
-    async fn ping(&self, session: SessionId) -> EngineResult<()>;
+    /// Runs the cheapest no-op the backend supports on the existing session
+    /// and returns its round-trip latency, or an error if unreachable. Used by
+    /// the keep-alive monitor to detect stale connections.
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration>;
+
+    /// Connection pool occupancy of a session.
+    ///
+    /// Returns `None` for drivers without a connection pool (single
+    /// connection or HTTP based). Default returns `None`.
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        let _ = session;
+        Ok(None)
+    }
+
+    /// Version, product flavor and version-gated features of the server.
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        let _ = session;
+        Err(EngineError::not_supported(
+            "Server version detection is not supported by this driver",
+        ))
+    }
 
     /// Lists all namespaces (databases/schemas) accessible in this session
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>>;
 
+    /// Lists the databases (catalogs) on the server, including those the
+    /// session is not connected to. Default collects the distinct databases
+    /// of `list_namespaces`.
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        let mut databases: Vec<String> = Vec::new();
+        for namespace in self.list_namespaces(session).await? {
+            if !databases.contains(&namespace.database) {
+                databases.push(namespace.database);
+            }
+        }
+        Ok(databases)
+    }
+
     /// Lists all collections (tables/views/collections) in a namespace
     async fn list_collections(
         &self,
@@ -289,6 +336,123 @@ pub trait DataEngine: Send + Sync {
         ))
     }
 
+    /// Lists the jobs of the server's scheduler (pg_cron, MySQL events,
+    /// SQL Server Agent). Returns an empty list without a scheduler when it is
+    /// not installed. Default returns NotSupported.
+    async fn list_scheduled_jobs(&self, session: SessionId) -> EngineResult<ScheduledJobList> {
+        let _ = session;
+        Err(EngineError::not_supported(
+            "Scheduled jobs are not supported by this driver",
+        ))
+    }
+
+    /// Enables or disables a scheduled job. `database` is the job's
+    /// `ScheduledJob::database`. Default returns NotSupported.
+    async fn set_scheduled_job_enabled(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        database: Option<&str>,
+        enabled: bool,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        let _ = (session, job_id, database, enabled);
+        Err(EngineError::not_supported(
+            "Toggling scheduled jobs is not supported by this driver",
+        ))
+    }
+
+    /// Runs a scheduled job immediately. Default returns NotSupported.
+    async fn run_scheduled_job(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        database: Option<&str>,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        let _ = (session, job_id, database);
+        Err(EngineError::not_supported(
+            "Running scheduled jobs is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver can list scheduled jobs.
+    fn supports_scheduled_jobs(&self) -> bool {
+        false
+    }
+
+    /// Reads the last `lines` lines of the server log, or only the lines
+    /// logged after `after` (a previous `ServerLogChunk::cursor`). Requires
+    /// elevated privileges. Default returns NotSupported.
+    async fn read_server_log(
+        &self,
+        session: SessionId,
+        lines: u32,
+        after: Option<&str>,
+    ) -> EngineResult<ServerLogChunk> {
+        let _ = (session, lines, after);
+        Err(EngineError::not_supported(
+            "Reading the server log is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver can read the server log.
+    fn supports_server_log(&self) -> bool {
+        false
+    }
+
+    /// Runs an aggregation pipeline, given as a JSON array of stages, on
+    /// `collection`. Default returns NotSupported.
+    async fn run_aggregation(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        collection: &str,
+        pipeline: &str,
+    ) -> EngineResult<QueryResult> {
+        let _ = (session, namespace, collection, pipeline);
+        Err(EngineError::not_supported(
+            "Aggregation pipelines are not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver runs aggregation pipelines.
+    fn supports_aggregation(&self) -> bool {
+        false
+    }
+
+    /// Subscribes to `channel` (`LISTEN`) on a connection dedicated to it
+    /// and forwards every notification to `sender`. Runs until the receiver
+    /// is dropped; the connection is released on return. Default returns
+    /// NotSupported.
+    async fn listen(
+        &self,
+        session: SessionId,
+        channel: &str,
+        sender: NotificationSender,
+    ) -> EngineResult<()> {
+        let _ = (session, channel, sender);
+        Err(EngineError::not_supported(
+            "Notifications are not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver can listen for notifications.
+    fn supports_notifications(&self) -> bool {
+        false
+    }
+
+    /// Re-expresses a spatial value (WKT, EWKT or hex EWKB, as shown in a
+    /// result grid) as a GeoJSON document. Default returns NotSupported.
+    async fn geometry_to_geojson(
+        &self,
+        session: SessionId,
+        geometry: &str,
+    ) -> EngineResult<String> {
+        let _ = (session, geometry);
+        Err(EngineError::not_supported(
+            "Geometry conversion is not supported by this driver",
+        ))
+    }
+
     /// Returns the options available when creating a database (charsets, collations, etc.).
     /// Default implementation returns empty options (no driver-specific choices).
     async fn get_creation_options(&self, session: SessionId) -> EngineResult<CreationOptions> {
@@ -311,6 +475,37 @@ pub trait DataEngine: Send + Sync {
     /// Drops an existing database (or schema in PostgreSQL)
     async fn drop_database(&self, session: SessionId, name: &str) -> EngineResult<()>;
 
+    /// Attaches the database file at `file_path` to the session under
+    /// `alias` (SQLite / DuckDB `ATTACH`); `":memory:"` attaches an empty
+    /// in-memory database. The caller has already vetted the path.
+    /// Default returns NotSupported.
+    async fn attach_database(
+        &self,
+        session: SessionId,
+        file_path: &str,
+        alias: &str,
+    ) -> EngineResult<()> {
+        let _ = (session, file_path, alias);
+        Err(EngineError::not_supported(
+            "Attaching databases is not supported by this driver",
+        ))
+    }
+
+    /// Creates a view named `view_name` over the data file at `file_path`
+    /// (Parquet, CSV or JSON), so the file can be queried like a table.
+    /// Default returns NotSupported.
+    async fn register_file_view(
+        &self,
+        session: SessionId,
+        file_path: &str,
+        view_name: &str,
+    ) -> EngineResult<()> {
+        let _ = (session, file_path, view_name);
+        Err(EngineError::not_supported(
+            "Registering files as views is not supported by this driver",
+        ))
+    }
+
     /// Executes a query and returns the result
     ///
     /// For SQL engines: executes SQL statements
@@ -338,6 +533,23 @@ pub trait DataEngine: Send + Sync {
         self.execute(session, query, query_id).await
     }
 
+    /// Executes a query whose placeholders (`$1` for PostgreSQL, `?` for
+    /// MySQL and SQLite, `@P1` for SQL Server) are bound to `params` through
+    /// the backend's prepared statements, so values never become SQL text.
+    /// Default returns NotSupported.
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let _ = (session, query, params, query_id);
+        Err(EngineError::not_supported(
+            "Bound parameters are not supported by this driver",
+        ))
+    }
+
     /// Executes a query and streams results via the provided sender
     async fn execute_stream(
         &self,
@@ -377,6 +589,51 @@ pub trait DataEngine: Send + Sync {
         table: &str,
     ) -> EngineResult<TableSchema>;
 
+    /// Like `describe_table`, with control over how the row count is
+    /// obtained. `row_count_is_estimate` on the result tells whether the
+    /// count is exact.
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
+    ) -> EngineResult<TableSchema> {
+        let _ = options;
+        self.describe_table(session, namespace, table).await
+    }
+
+    /// Returns the `CREATE TABLE` statement for a table, followed by its
+    /// index statements, as text suitable for recreating it.
+    /// Default returns NotSupported.
+    async fn get_table_ddl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        let _ = (session, namespace, table);
+        Err(EngineError::not_supported(
+            "Table DDL is not supported by this driver",
+        ))
+    }
+
+    /// Creates an index on `table` (a collection for document stores).
+    /// Implementations reject specs failing `IndexSpec::validate`.
+    /// Default returns NotSupported.
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        let _ = (session, namespace, table, spec);
+        Err(EngineError::not_supported(
+            "Index creation is not supported by this driver",
+        ))
+    }
+
     /// Returns a preview of the table data (first N rows)
     async fn preview_table(
         &self,
@@ -423,6 +680,23 @@ pub trait DataEngine: Send + Sync {
         ))
     }
 
+    /// Computes null and distinct counts, min, max and (for numeric
+    /// columns) the average of `column` in one aggregate query.
+    ///
+    /// Default implementation returns NotSupported. SQL drivers should override.
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        let _ = (session, namespace, table, column);
+        Err(EngineError::not_supported(
+            "Column statistics are not supported by this driver",
+        ))
+    }
+
     /// Cancels a running query for the given session
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         let _ = (session, query_id);
@@ -436,6 +710,12 @@ pub trait DataEngine: Send + Sync {
         CancelSupport::None
     }
 
+    /// Maximum number of queries a session opened with `config` runs at once;
+    /// the query manager queues the rest. Defaults to the pool size.
+    fn default_query_concurrency(&self, config: &ConnectionConfig) -> usize {
+        config.pool_max_connections.unwrap_or(10).max(1) as usize
+    }
+
     /// Reports whether the driver supports SSH tunneling.
     fn supports_ssh(&self) -> bool {
         true
@@ -452,6 +732,23 @@ pub trait DataEngine: Send + Sync {
             streaming: self.supports_streaming(),
             explain: self.supports_explain(),
             maintenance: self.supports_maintenance(),
+            index_usage: self.supports_index_usage(),
+            key_metadata: self.supports_key_metadata(),
+            big_keys: self.supports_big_keys(),
+            key_management: self.supports_key_management(),
+            computed_columns: self.supports_computed_columns(),
+            copy_export: self.supports_copy_export(),
+            bloat_report: self.supports_bloat_report(),
+            syntax_validation: self.supports_syntax_validation(),
+            partitions: self.supports_partitions(),
+            upsert: self.supports_upsert(),
+            query_progress: self.supports_query_progress(),
+            execute_as_role: self.supports_execute_as_role(),
+            row_security: self.supports_row_security(),
+            scheduled_jobs: self.supports_scheduled_jobs(),
+            server_log: self.supports_server_log(),
+            aggregation: self.supports_aggregation(),
+            notifications: self.supports_notifications(),
         }
     }
 
@@ -495,6 +792,13 @@ pub trait DataEngine: Send + Sync {
         false
     }
 
+    /// Isolation levels accepted as a connection default
+    /// (`ConnectionConfig::default_isolation_level`). Empty when the driver
+    /// cannot apply one.
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        &[]
+    }
+
     /// Check if the driver supports schema inspection (describe, list, etc).
     fn supports_schema(&self) -> bool {
         true
@@ -510,6 +814,22 @@ pub trait DataEngine: Send + Sync {
         false
     }
 
+    /// Returns the execution plan of `query`. With `analyze` the query is
+    /// actually run and the plan carries actual rows and timings. Default
+    /// returns NotSupported.
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        let _ = (session, namespace, query, analyze);
+        Err(EngineError::not_supported(
+            "Explain plans are not supported by this driver",
+        ))
+    }
+
     // Default implementations return NotSupported.
 
     /// Insert a new row. Returns `QueryResult` with `affected_rows = 1`.
@@ -557,6 +877,41 @@ pub trait DataEngine: Send + Sync {
         ))
     }
 
+    /// Remove every row of `table`. Drivers without a `TRUNCATE` statement
+    /// fall back to an unfiltered `DELETE`.
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        let _ = (session, namespace, table);
+        Err(EngineError::not_supported(
+            "Truncate is not supported by this driver",
+        ))
+    }
+
+    /// Insert `data`, or update the existing row matching it on
+    /// `conflict_columns`. Values are bound as parameters.
+    async fn upsert_row(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        let _ = (session, namespace, table, data, conflict_columns);
+        Err(EngineError::not_supported(
+            "Upsert operations are not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports upserts.
+    fn supports_upsert(&self) -> bool {
+        false
+    }
+
     /// Check if the driver supports CRUD mutations.
     fn supports_mutations(&self) -> bool {
         false
@@ -595,4 +950,274 @@ pub trait DataEngine: Send + Sync {
     fn supports_maintenance(&self) -> bool {
         false
     }
+
+    /// Reports indexes never scanned since statistics were last reset.
+    /// Default returns NotSupported.
+    async fn unused_indexes(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+    ) -> EngineResult<UnusedIndexReport> {
+        let _ = (session, namespace);
+        Err(EngineError::not_supported(
+            "Index usage analysis is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports index usage analysis.
+    fn supports_index_usage(&self) -> bool {
+        false
+    }
+
+    /// Reports estimated table and index bloat in a namespace, worst first.
+    /// Default returns NotSupported.
+    async fn bloat_report(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+    ) -> EngineResult<BloatReport> {
+        let _ = (session, namespace);
+        Err(EngineError::not_supported(
+            "Bloat analysis is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports bloat analysis.
+    fn supports_bloat_report(&self) -> bool {
+        false
+    }
+
+    /// Reports the progress of a running maintenance operation (VACUUM,
+    /// CREATE INDEX, ...) started as `query_id`. Default returns NotSupported.
+    async fn query_progress(
+        &self,
+        session: SessionId,
+        query_id: QueryId,
+    ) -> EngineResult<QueryProgress> {
+        let _ = (session, query_id);
+        Err(EngineError::not_supported(
+            "Query progress is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports query progress reporting.
+    fn supports_query_progress(&self) -> bool {
+        false
+    }
+
+    /// Executes a query as `role` inside a transaction that is rolled back,
+    /// resetting the role afterwards even on error. Default returns
+    /// NotSupported.
+    async fn execute_as_role(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        role: &str,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let _ = (session, namespace, role, query, query_id);
+        Err(EngineError::not_supported(
+            "Executing as another role is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports executing as another role.
+    fn supports_execute_as_role(&self) -> bool {
+        false
+    }
+
+    /// Turns row-level security off (`bypass = true`) or back on for every
+    /// statement of the session. Default returns NotSupported.
+    async fn set_row_security_bypass(&self, session: SessionId, bypass: bool) -> EngineResult<()> {
+        let _ = (session, bypass);
+        Err(EngineError::not_supported(
+            "Row-level security is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver reports row-level security and can bypass it.
+    fn supports_row_security(&self) -> bool {
+        false
+    }
+
+    /// Returns storage metadata (type, encoding, memory, idle time) for a key.
+    /// Default returns NotSupported.
+    async fn key_metadata(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        key: &str,
+    ) -> EngineResult<KeyMetadata> {
+        let _ = (session, namespace, key);
+        Err(EngineError::not_supported(
+            "Key metadata is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports key metadata.
+    fn supports_key_metadata(&self) -> bool {
+        false
+    }
+
+    /// Samples up to `sample_size` keys and ranks them by memory usage,
+    /// keeping the `top_n` largest. Registered under `query_id` so it can be
+    /// cancelled like a query. Default returns NotSupported.
+    async fn big_keys(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        sample_size: u64,
+        top_n: usize,
+        query_id: QueryId,
+    ) -> EngineResult<BigKeysReport> {
+        let _ = (session, namespace, sample_size, top_n, query_id);
+        Err(EngineError::not_supported(
+            "Big keys analysis is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports big keys analysis.
+    fn supports_big_keys(&self) -> bool {
+        false
+    }
+
+    /// Makes `key` expire after `seconds`. Returns false when the key does
+    /// not exist. Default returns NotSupported.
+    async fn set_key_ttl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        key: &str,
+        seconds: u64,
+    ) -> EngineResult<bool> {
+        let _ = (session, namespace, key, seconds);
+        Err(EngineError::not_supported(
+            "Key expiration is not supported by this driver",
+        ))
+    }
+
+    /// Removes the expiration of `key`. Returns false when the key does not
+    /// exist or has no expiration. Default returns NotSupported.
+    async fn persist_key(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        key: &str,
+    ) -> EngineResult<bool> {
+        let _ = (session, namespace, key);
+        Err(EngineError::not_supported(
+            "Key expiration is not supported by this driver",
+        ))
+    }
+
+    /// Renames `old_key` to `new_key`, replacing `new_key` if it exists.
+    /// Default returns NotSupported.
+    async fn rename_key(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        old_key: &str,
+        new_key: &str,
+    ) -> EngineResult<()> {
+        let _ = (session, namespace, old_key, new_key);
+        Err(EngineError::not_supported(
+            "Renaming keys is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports key TTL editing and renaming.
+    fn supports_key_management(&self) -> bool {
+        false
+    }
+
+    /// Check if `query_table` honors `TableQueryOptions::computed_columns`.
+    fn supports_computed_columns(&self) -> bool {
+        false
+    }
+
+    /// Runs a server-formatted export (`COPY ... TO STDOUT`) and forwards the
+    /// raw output chunks to `sender`. Default returns NotSupported.
+    async fn copy_out(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        source: &CopySource,
+        options: &CopyOptions,
+        sender: CopyOutSender,
+    ) -> EngineResult<()> {
+        let _ = (session, namespace, source, options, sender);
+        Err(EngineError::not_supported(
+            "COPY export is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports COPY export.
+    fn supports_copy_export(&self) -> bool {
+        false
+    }
+
+    /// Loads CSV data into `table` through the server bulk path
+    /// (`COPY ... FROM STDIN`) and returns the number of rows loaded.
+    /// `columns` lists the target columns in file order; empty means every
+    /// table column. Default returns NotSupported.
+    async fn copy_in_csv(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        columns: &[String],
+        source: CopyInSource,
+        options: &BulkImportOptions,
+    ) -> EngineResult<u64> {
+        let _ = (session, namespace, table, columns, source, options);
+        Err(EngineError::not_supported(
+            "COPY import is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports COPY import.
+    fn supports_copy_import(&self) -> bool {
+        false
+    }
+
+    /// Asks the server to parse `sql` without executing it (e.g. PREPARE then
+    /// DEALLOCATE). Returns the syntax error if the server rejects it.
+    /// Default returns NotSupported.
+    async fn validate_syntax(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        sql: &str,
+    ) -> EngineResult<Option<SqlSyntaxError>> {
+        let _ = (session, namespace, sql);
+        Err(EngineError::not_supported(
+            "Server-side syntax validation is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports server-side syntax validation.
+    fn supports_syntax_validation(&self) -> bool {
+        false
+    }
+
+    /// Returns the partition key, strategy and partitions of `table`, or
+    /// `None` when the table is not partitioned.
+    /// Default returns NotSupported.
+    async fn table_partitioning(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<Option<TablePartitioning>> {
+        let _ = (session, namespace, table);
+        Err(EngineError::not_supported(
+            "Table partitioning is not supported by this driver",
+        ))
+    }
+
+    /// Check if the driver supports partitioned table introspection.
+    fn supports_partitions(&self) -> bool {
+        false
+    }
 }
@@ -2,10 +2,14 @@
 
 //! Normalized data types shared across SQL and NoSQL engines.
 
+use std::collections::HashMap;
+
 use compact_str::CompactString;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::{EngineError, EngineResult};
+
 /// Unique identifier for a database session
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SessionId(pub Uuid);
@@ -83,6 +87,38 @@ pub struct ConnectionConfig {
     /// system trust store.
     #[serde(default)]
     pub ssl_ca_cert: Option<String>,
+    /// Certificates and server verification of TLS connections (Postgres,
+    /// MySQL, SQL Server). `None` keeps the environment defaults, see
+    /// [`ConnectionConfig::tls_verify_mode`].
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// Driver-specific key/value settings that don't warrant a dedicated
+    /// field (e.g. DuckDB `httpfs` / S3 credentials). Values may hold secrets
+    /// and are redacted from `Debug`.
+    #[serde(default)]
+    pub extra_params: Option<HashMap<String, String>>,
+    /// Default statement timeout applied to every pooled connection right
+    /// after connect. `None` keeps the server default.
+    #[serde(default)]
+    pub statement_timeout_ms: Option<u64>,
+    /// Default lock-wait timeout applied to every pooled connection right
+    /// after connect. `None` keeps the server default.
+    #[serde(default)]
+    pub lock_timeout_ms: Option<u64>,
+    /// Default isolation level for every transaction on this connection,
+    /// applied at session setup. `None` keeps the server default.
+    #[serde(default)]
+    pub default_isolation_level: Option<IsolationLevel>,
+    /// Keep retrying the initial connection for up to this many seconds while
+    /// the server is not accepting connections yet (e.g. a container still
+    /// starting). `None` fails on the first refused attempt.
+    #[serde(default)]
+    pub wait_for_ready_secs: Option<u64>,
+    /// Client-side limit for a single query, in seconds. On expiry the driver
+    /// cancels the query server-side and returns `EngineError::Timeout`.
+    /// `None` lets queries run until they finish.
+    #[serde(default)]
+    pub query_timeout_secs: Option<u64>,
 }
 
 impl std::fmt::Debug for ConnectionConfig {
@@ -107,6 +143,19 @@ impl std::fmt::Debug for ConnectionConfig {
             .field("clickhouse_cluster", &self.clickhouse_cluster)
             .field("search_auth_mode", &self.search_auth_mode)
             .field("ssl_ca_cert", &self.ssl_ca_cert)
+            .field("tls", &self.tls)
+            .field(
+                "extra_params",
+                &self
+                    .extra_params
+                    .as_ref()
+                    .map(|params| params.keys().collect::<Vec<_>>()),
+            )
+            .field("statement_timeout_ms", &self.statement_timeout_ms)
+            .field("lock_timeout_ms", &self.lock_timeout_ms)
+            .field("default_isolation_level", &self.default_isolation_level)
+            .field("wait_for_ready_secs", &self.wait_for_ready_secs)
+            .field("query_timeout_secs", &self.query_timeout_secs)
             .finish()
     }
 }
@@ -122,6 +171,96 @@ fn redacted_field(value: &str) -> &'static str {
     }
 }
 
+impl ConnectionConfig {
+    /// Server certificate verification of TLS connections: the explicit
+    /// `tls.verify_mode`, otherwise none on development connections and full
+    /// verification everywhere else.
+    pub fn tls_verify_mode(&self) -> TlsVerifyMode {
+        match self.tls.as_ref().and_then(|tls| tls.verify_mode) {
+            Some(mode) => mode,
+            None if self.environment.eq_ignore_ascii_case("development") => TlsVerifyMode::None,
+            None => TlsVerifyMode::Full,
+        }
+    }
+
+    /// CA certificate used to verify the server, falling back to the legacy
+    /// `ssl_ca_cert` field.
+    pub fn tls_ca_cert_path(&self) -> Option<&str> {
+        self.tls
+            .as_ref()
+            .and_then(|tls| tls.ca_cert_path.as_deref())
+            .or(self.ssl_ca_cert.as_deref())
+            .filter(|path| !path.trim().is_empty())
+    }
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl ConnectionConfig {
+    /// Test fixture: a development connection to `host` (a file path or
+    /// `:memory:`) on an embedded driver, every optional setting unset.
+    pub fn local_for_tests(driver: &str, host: &str) -> Self {
+        Self {
+            driver: driver.to_string(),
+            host: host.to_string(),
+            port: 0,
+            username: String::new(),
+            password: String::new(),
+            database: None,
+            ssl: false,
+            ssl_mode: None,
+            environment: "development".to_string(),
+            read_only: false,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            pool_acquire_timeout_secs: None,
+            ssh_tunnel: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        }
+    }
+
+    /// Test fixture: an in-memory SQLite connection.
+    pub fn sqlite_memory_for_tests() -> Self {
+        Self::local_for_tests("sqlite", ":memory:")
+    }
+}
+
+/// How the server certificate of a TLS connection is verified.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsVerifyMode {
+    /// Certificate chain and host name
+    Full,
+    /// Certificate chain only
+    CaOnly,
+    /// Encrypt without verifying the certificate
+    None,
+}
+
+/// TLS certificates of a connection. Paths point to PEM files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TlsConfig {
+    #[serde(default)]
+    pub ca_cert_path: Option<String>,
+    #[serde(default)]
+    pub client_cert_path: Option<String>,
+    #[serde(default)]
+    pub client_key_path: Option<String>,
+    /// `None` picks the default of the connection environment
+    #[serde(default)]
+    pub verify_mode: Option<TlsVerifyMode>,
+}
+
 /// Authentication mode for SQL Server connections.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -132,6 +271,31 @@ pub enum MssqlAuthMode {
     WindowsIntegrated,
 }
 
+/// Transaction isolation level.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+    /// SQL Server row-versioning isolation
+    Snapshot,
+}
+
+impl IsolationLevel {
+    /// SQL spelling, as used in `SET TRANSACTION ISOLATION LEVEL ...`.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+            IsolationLevel::Snapshot => "SNAPSHOT",
+        }
+    }
+}
+
 /// Network proxy configuration for corporate environments. `Debug` redacts
 /// the password.
 #[derive(Clone, Serialize, Deserialize)]
@@ -278,6 +442,40 @@ pub struct DriverCapabilities {
     pub streaming: bool,
     pub explain: bool,
     pub maintenance: bool,
+    #[serde(default)]
+    pub index_usage: bool,
+    #[serde(default)]
+    pub key_metadata: bool,
+    #[serde(default)]
+    pub big_keys: bool,
+    #[serde(default)]
+    pub key_management: bool,
+    #[serde(default)]
+    pub computed_columns: bool,
+    #[serde(default)]
+    pub copy_export: bool,
+    #[serde(default)]
+    pub bloat_report: bool,
+    #[serde(default)]
+    pub syntax_validation: bool,
+    #[serde(default)]
+    pub partitions: bool,
+    #[serde(default)]
+    pub upsert: bool,
+    #[serde(default)]
+    pub query_progress: bool,
+    #[serde(default)]
+    pub execute_as_role: bool,
+    #[serde(default)]
+    pub row_security: bool,
+    #[serde(default)]
+    pub scheduled_jobs: bool,
+    #[serde(default)]
+    pub server_log: bool,
+    #[serde(default)]
+    pub aggregation: bool,
+    #[serde(default)]
+    pub notifications: bool,
 }
 
 /// Driver metadata exposed to the frontend.
@@ -288,6 +486,49 @@ pub struct DriverInfo {
     pub capabilities: DriverCapabilities,
 }
 
+/// Connection pool occupancy of a session, for spotting pool exhaustion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolStats {
+    /// Connections currently open, idle or checked out
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    /// Configured upper bound on `size`
+    pub max: u32,
+}
+
+/// Server product a session is connected to. MySQL forks share a driver but
+/// diverge in features, so they are told apart here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerFlavor {
+    Mysql,
+    Mariadb,
+    Percona,
+    Postgres,
+    SqlServer,
+}
+
+/// SQL features gated on the server version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerFeatures {
+    pub window_functions: bool,
+    pub cte: bool,
+    pub json_functions: bool,
+}
+
+/// Version and product of the server behind a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerInfo {
+    /// Version string as reported by the server
+    pub version: String,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub flavor: ServerFlavor,
+    pub features: ServerFeatures,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,6 +587,107 @@ mod tests {
         assert!(cfg.mssql_auth.is_none());
     }
 
+    #[test]
+    fn connection_config_parses_session_timeouts() {
+        let json = r#"{
+            "driver":"postgres","host":"localhost","port":5432,
+            "username":"u","password":"","database":null,"ssl":false,
+            "environment":"development","read_only":false,
+            "pool_max_connections":null,"pool_min_connections":null,
+            "pool_acquire_timeout_secs":null,"ssh_tunnel":null,
+            "statement_timeout_ms":30000,"lock_timeout_ms":5000
+        }"#;
+        let cfg: ConnectionConfig = serde_json::from_str(json).expect("must parse");
+        assert_eq!(cfg.statement_timeout_ms, Some(30000));
+        assert_eq!(cfg.lock_timeout_ms, Some(5000));
+    }
+
+    #[test]
+    fn tls_verify_mode_defaults_by_environment() {
+        let json = r#"{
+            "driver":"postgres","host":"db","port":5432,
+            "username":"u","password":"","database":null,"ssl":true,
+            "environment":"development","read_only":false,
+            "pool_max_connections":null,"pool_min_connections":null,
+            "pool_acquire_timeout_secs":null,"ssh_tunnel":null,
+            "ssl_ca_cert":"/legacy/ca.pem"
+        }"#;
+        let mut cfg: ConnectionConfig = serde_json::from_str(json).expect("must parse");
+        assert!(cfg.tls.is_none());
+        assert_eq!(cfg.tls_verify_mode(), TlsVerifyMode::None);
+        assert_eq!(cfg.tls_ca_cert_path(), Some("/legacy/ca.pem"));
+
+        cfg.environment = "production".to_string();
+        assert_eq!(cfg.tls_verify_mode(), TlsVerifyMode::Full);
+
+        cfg.tls = Some(
+            serde_json::from_str(r#"{"ca_cert_path":"/etc/ca.pem","verify_mode":"ca_only"}"#)
+                .expect("must parse"),
+        );
+        assert_eq!(cfg.tls_verify_mode(), TlsVerifyMode::CaOnly);
+        assert_eq!(cfg.tls_ca_cert_path(), Some("/etc/ca.pem"));
+    }
+
+    #[test]
+    fn connection_config_accepts_legacy_json_without_session_timeouts() {
+        let legacy = r#"{
+            "driver":"postgres","host":"localhost","port":5432,
+            "username":"u","password":"","database":null,"ssl":false,
+            "environment":"development","read_only":false,
+            "pool_max_connections":null,"pool_min_connections":null,
+            "pool_acquire_timeout_secs":null,"ssh_tunnel":null
+        }"#;
+        let cfg: ConnectionConfig = serde_json::from_str(legacy).expect("legacy config must parse");
+        assert!(cfg.statement_timeout_ms.is_none());
+        assert!(cfg.lock_timeout_ms.is_none());
+    }
+
+    #[test]
+    fn connection_config_parses_default_isolation_level() {
+        let json = r#"{
+            "driver":"postgres","host":"localhost","port":5432,
+            "username":"u","password":"","database":null,"ssl":false,
+            "environment":"development","read_only":false,
+            "pool_max_connections":null,"pool_min_connections":null,
+            "pool_acquire_timeout_secs":null,"ssh_tunnel":null,
+            "default_isolation_level":"serializable"
+        }"#;
+        let cfg: ConnectionConfig = serde_json::from_str(json).expect("must parse");
+        assert_eq!(
+            cfg.default_isolation_level,
+            Some(IsolationLevel::Serializable)
+        );
+
+        let mut legacy: serde_json::Value = serde_json::from_str(json).unwrap();
+        legacy
+            .as_object_mut()
+            .unwrap()
+            .remove("default_isolation_level");
+        let cfg: ConnectionConfig =
+            serde_json::from_value(legacy).expect("legacy config must parse");
+        assert!(cfg.default_isolation_level.is_none());
+    }
+
+    #[test]
+    fn connection_config_rejects_unknown_isolation_level() {
+        let json = r#"{
+            "driver":"postgres","host":"localhost","port":5432,
+            "username":"u","password":"","database":null,"ssl":false,
+            "environment":"development","read_only":false,
+            "pool_max_connections":null,"pool_min_connections":null,
+            "pool_acquire_timeout_secs":null,"ssh_tunnel":null,
+            "default_isolation_level":"chaos"
+        }"#;
+        assert!(serde_json::from_str::<ConnectionConfig>(json).is_err());
+    }
+
+    #[test]
+    fn isolation_level_sql_spelling() {
+        assert_eq!(IsolationLevel::ReadCommitted.as_sql(), "READ COMMITTED");
+        assert_eq!(IsolationLevel::RepeatableRead.as_sql(), "REPEATABLE READ");
+        assert_eq!(IsolationLevel::Serializable.as_sql(), "SERIALIZABLE");
+    }
+
     #[test]
     fn connection_config_debug_redacts_password() {
         let cfg = ConnectionConfig {
@@ -368,6 +710,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         };
         let dbg = format!("{:?}", cfg);
         assert!(dbg.contains("[REDACTED]"), "expected redaction in {dbg}");
@@ -425,6 +774,108 @@ mod tests {
         let cfg: ConnectionConfig = serde_json::from_str(json).expect("must parse");
         assert_eq!(cfg.mssql_auth, Some(MssqlAuthMode::WindowsNtlm));
     }
+
+    #[test]
+    fn upsert_columns_split_keys_from_updates() {
+        let data = RowData::new()
+            .with_column("name", Value::Text("a".into()))
+            .with_column("id", Value::Int(1))
+            .with_column("qty", Value::Int(2));
+        let cols = UpsertColumns::new(&data, &["id".to_string()]).unwrap();
+        assert_eq!(cols.insert, vec!["id", "name", "qty"]);
+        assert_eq!(cols.conflict, vec!["id"]);
+        assert_eq!(cols.update, vec!["name", "qty"]);
+
+        let keys_only = RowData::new().with_column("id", Value::Int(1));
+        let cols = UpsertColumns::new(&keys_only, &["id".to_string()]).unwrap();
+        assert_eq!(cols.update, vec!["id"]);
+    }
+
+    #[test]
+    fn upsert_columns_reject_missing_conflict_values() {
+        let data = RowData::new().with_column("name", Value::Text("a".into()));
+        assert!(UpsertColumns::new(&data, &[]).is_err());
+        assert!(UpsertColumns::new(&data, &["id".to_string()]).is_err());
+    }
+
+    #[test]
+    fn index_spec_rejects_unsafe_names() {
+        let spec = |name: &str| IndexSpec {
+            name: name.to_string(),
+            columns: vec![IndexColumnSpec {
+                name: "email".to_string(),
+                direction: SortDirection::Asc,
+            }],
+            unique: false,
+        };
+        assert!(spec("idx_users_email").validate().is_ok());
+        for name in [
+            "",
+            "1idx",
+            "idx; DROP TABLE users",
+            "idx\"x",
+            "a".repeat(64).as_str(),
+        ] {
+            assert!(spec(name).validate().is_err(), "accepted {name:?}");
+        }
+
+        let mut no_columns = spec("idx_users_email");
+        no_columns.columns.clear();
+        assert!(no_columns.validate().is_err());
+    }
+
+    #[test]
+    fn table_order_falls_back_to_primary_key_then_row_id() {
+        let quote = |c: &str| format!("\"{}\"", c);
+        let unsorted = TableQueryOptions::default();
+
+        let pk = ImplicitOrder::new(vec!["tenant".into(), "id".into()], Some("ctid"));
+        assert_eq!(
+            unsorted.order_by_sql(&pk, quote),
+            " ORDER BY \"tenant\", \"id\""
+        );
+
+        let row_id = ImplicitOrder::new(Vec::new(), Some("rowid"));
+        assert_eq!(unsorted.order_by_sql(&row_id, quote), " ORDER BY rowid");
+
+        let view = ImplicitOrder::new(Vec::new(), None);
+        assert_eq!(unsorted.order_by_sql(&view, quote), "");
+
+        let sorted = TableQueryOptions {
+            sort_column: Some("name".into()),
+            sort_direction: Some(SortDirection::Desc),
+            ..Default::default()
+        };
+        assert_eq!(sorted.order_by_sql(&pk, quote), " ORDER BY \"name\" DESC");
+    }
+
+    #[test]
+    fn computed_column_paths_parse_keys_and_indexes() {
+        let computed = |path: &str| ComputedColumn {
+            alias: "value".into(),
+            source_column: "data".into(),
+            json_path: path.into(),
+        };
+        let key = |k: &str| JsonPathSegment::Key(k.into());
+
+        assert_eq!(computed("status").path_segments().unwrap(), [key("status")]);
+        assert_eq!(
+            computed("$.status").path_segments().unwrap(),
+            [key("status")]
+        );
+        assert_eq!(
+            computed("items[0].sku").path_segments().unwrap(),
+            [key("items"), JsonPathSegment::Index(0), key("sku")]
+        );
+        assert_eq!(
+            computed("$[2]").path_segments().unwrap(),
+            [JsonPathSegment::Index(2)]
+        );
+
+        for bad in ["", "$.", "a..b", "a'b", "items[x]", "items[0", "a]"] {
+            assert!(computed(bad).path_segments().is_err(), "accepted {bad:?}");
+        }
+    }
 }
 
 /// Namespace represents the hierarchy level above collections
@@ -459,6 +910,9 @@ impl Namespace {
 pub struct Collection {
     pub namespace: Namespace,
     pub name: String,
+    /// Parent table name when this table is a partition of another
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition_of: Option<String>,
     pub collection_type: CollectionType,
 }
 
@@ -637,6 +1091,10 @@ pub struct ColumnInfo {
     pub name: CompactString,
     pub data_type: CompactString,
     pub nullable: bool,
+    /// Comparisons on this column ignore case (Postgres `citext`), so the grid
+    /// should search and filter it case-insensitively.
+    #[serde(default)]
+    pub case_insensitive: bool,
 }
 
 /// A single row of data (indexed by column order)
@@ -671,6 +1129,71 @@ impl Default for RowData {
     }
 }
 
+/// Column split of an upsert, shared by the drivers' statement builders
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpsertColumns {
+    /// Every column of the row, sorted
+    pub insert: Vec<String>,
+    /// Columns identifying an existing row, in caller order
+    pub conflict: Vec<String>,
+    /// Columns overwritten when the row exists, sorted. Falls back to the
+    /// conflict columns (a no-op assignment) when every column is a key, so
+    /// the statement still touches the existing row.
+    pub update: Vec<String>,
+}
+
+impl UpsertColumns {
+    pub fn new(data: &RowData, conflict_columns: &[String]) -> EngineResult<Self> {
+        if conflict_columns.is_empty() {
+            return Err(EngineError::validation(
+                "Upsert requires at least one conflict column",
+            ));
+        }
+        if let Some(missing) = conflict_columns
+            .iter()
+            .find(|c| !data.columns.contains_key(*c))
+        {
+            return Err(EngineError::validation(format!(
+                "Conflict column '{}' has no value in the row",
+                missing
+            )));
+        }
+
+        let mut insert: Vec<String> = data.columns.keys().cloned().collect();
+        insert.sort();
+        let mut update: Vec<String> = insert
+            .iter()
+            .filter(|c| !conflict_columns.contains(*c))
+            .cloned()
+            .collect();
+        if update.is_empty() {
+            update = conflict_columns.to_vec();
+        }
+
+        Ok(Self {
+            insert,
+            conflict: conflict_columns.to_vec(),
+            update,
+        })
+    }
+}
+
+/// Which branch an upsert took
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpsertAction {
+    Inserted,
+    Updated,
+}
+
+/// Result of an upsert
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpsertResult {
+    pub result: QueryResult,
+    /// `None` when the driver cannot tell whether the row was inserted or updated
+    pub action: Option<UpsertAction>,
+}
+
 /// Query execution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryResult {
@@ -704,6 +1227,56 @@ impl QueryResult {
     }
 }
 
+/// Format of `ExplainPlan::raw`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExplainFormat {
+    Json,
+    Xml,
+    Text,
+}
+
+/// Execution plan of a query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainPlan {
+    /// Plan exactly as returned by the server
+    pub raw: String,
+    pub format: ExplainFormat,
+    /// Parsed plan tree, `None` when the driver only returns text
+    pub root: Option<ExplainNode>,
+    /// The query was run (`EXPLAIN ANALYZE`) and actual values are filled
+    pub analyzed: bool,
+}
+
+/// One operator of a parsed plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainNode {
+    /// Operator name (`Seq Scan`, `Nested loop`, `Clustered Index Seek`, ...)
+    pub node_type: String,
+    /// Table the operator reads, if any
+    pub relation: Option<String>,
+    pub estimated_rows: Option<f64>,
+    pub actual_rows: Option<f64>,
+    /// Estimated total cost, in the engine's own units
+    pub estimated_cost: Option<f64>,
+    pub actual_time_ms: Option<f64>,
+    pub children: Vec<ExplainNode>,
+}
+
+impl ExplainNode {
+    pub fn new(node_type: impl Into<String>) -> Self {
+        Self {
+            node_type: node_type.into(),
+            relation: None,
+            estimated_rows: None,
+            actual_rows: None,
+            estimated_cost: None,
+            actual_time_ms: None,
+            children: Vec::new(),
+        }
+    }
+}
+
 /// Foreign Key definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForeignKey {
@@ -737,6 +1310,53 @@ pub struct TableIndex {
     pub index_type: Option<String>,
 }
 
+/// Key column of an index created through `DataEngine::create_index`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexColumnSpec {
+    pub name: String,
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+/// Index to create through `DataEngine::create_index`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexSpec {
+    pub name: String,
+    pub columns: Vec<IndexColumnSpec>,
+    #[serde(default)]
+    pub unique: bool,
+}
+
+impl IndexSpec {
+    /// Rejects empty column lists and names that are not plain identifiers
+    /// (letters, digits, `_` and `$`, not starting with a digit, at most 63
+    /// bytes so PostgreSQL does not truncate them).
+    pub fn validate(&self) -> EngineResult<()> {
+        let name = self.name.as_str();
+        let valid = !name.is_empty()
+            && name.len() <= 63
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        if !valid {
+            return Err(EngineError::validation(format!(
+                "Invalid index name '{}': use letters, digits and underscores",
+                name
+            )));
+        }
+        if self.columns.is_empty() {
+            return Err(EngineError::validation(
+                "An index needs at least one column",
+            ));
+        }
+        if self.columns.iter().any(|c| c.name.trim().is_empty()) {
+            return Err(EngineError::validation("Index column name is empty"));
+        }
+        Ok(())
+    }
+}
+
 /// Table schema metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableSchema {
@@ -748,8 +1368,27 @@ pub struct TableSchema {
     pub foreign_keys: Vec<ForeignKey>,
     /// Estimated row count (if available)
     pub row_count_estimate: Option<u64>,
+    /// Whether `row_count_estimate` comes from statistics rather than an
+    /// exact count
+    #[serde(default)]
+    pub row_count_is_estimate: bool,
     /// Table indexes
     pub indexes: Vec<TableIndex>,
+    /// Partition key and partitions, for partitioned tables
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partitioning: Option<TablePartitioning>,
+    /// Row-level security state, for drivers that report it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_security: Option<TableRowSecurity>,
+}
+
+/// Options for describing a table
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DescribeTableOptions {
+    /// Report the row count from statistics only, skipping exact counts
+    /// even on small tables
+    #[serde(default)]
+    pub fast_estimate: bool,
 }
 
 /// Column metadata for table schema
@@ -769,6 +1408,30 @@ pub struct TableColumn {
     /// serial / SQLite rowid). Such columns must not receive a generated value.
     #[serde(default)]
     pub is_auto_increment: bool,
+    /// Whether the value is computed from other columns (SQL Server computed
+    /// column, generated column). Such columns reject explicit values.
+    #[serde(default)]
+    pub is_computed: bool,
+    /// Expression of a computed column
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed_definition: Option<String>,
+    /// SQL Server `SPARSE` column
+    #[serde(default)]
+    pub is_sparse: bool,
+    /// SQL Server `COLUMN_SET FOR ALL_SPARSE_COLUMNS` column
+    #[serde(default)]
+    pub is_column_set: bool,
+    /// Values accepted by a MySQL `ENUM` / `SET` column
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<AllowedValues>,
+}
+
+/// Values an enumerated column accepts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowedValues {
+    pub values: Vec<String>,
+    /// `SET` column: any subset, written as a comma-joined list
+    pub multiple: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -776,12 +1439,21 @@ pub struct CollectionListOptions {
     pub search: Option<String>,
     pub page: Option<u32>,
     pub page_size: Option<u32>,
+    /// Opaque resume token from a previous `CollectionList::next_cursor`.
+    /// Drivers that support it page forward from there instead of `page`.
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CollectionList {
     pub collections: Vec<Collection>,
+    /// Exact count, or an estimate when paging by cursor.
     pub total_count: u32,
+    /// Token for the next page; `None` once the listing is exhausted or when
+    /// the driver does not page by cursor.
+    #[serde(default)]
+    pub next_cursor: Option<String>,
 }
 
 /// Type of database routine
@@ -964,6 +1636,98 @@ pub struct EventOperationResult {
     pub execution_time_ms: f64,
 }
 
+/// Job scheduler backing a driver's scheduled jobs
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobScheduler {
+    PgCron,
+    MysqlEvents,
+    SqlServerAgent,
+}
+
+/// A scheduled job, normalized across pg_cron, MySQL events and SQL Server Agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    /// Identifier passed back to enable/disable/run-now (pg_cron `jobid`,
+    /// event name, Agent `job_id`)
+    pub id: String,
+    pub name: String,
+    /// Database the job runs in (pg_cron) or belongs to (MySQL event schema)
+    pub database: Option<String>,
+    /// Cron expression, `EVERY 1 DAY` / `AT ...` interval, or Agent schedule name
+    pub schedule: Option<String>,
+    /// Command of the job (first step for Agent jobs)
+    pub command: Option<String>,
+    pub last_run: Option<String>,
+    pub next_run: Option<String>,
+    pub enabled: bool,
+}
+
+/// Scheduled jobs of a connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJobList {
+    /// `None` when no scheduler is installed or visible to the current user
+    pub scheduler: Option<JobScheduler>,
+    pub jobs: Vec<ScheduledJob>,
+}
+
+impl ScheduledJobList {
+    pub fn unavailable() -> Self {
+        Self {
+            scheduler: None,
+            jobs: Vec::new(),
+        }
+    }
+}
+
+/// Result of a scheduled job operation (enable, disable, run now)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJobOperationResult {
+    pub success: bool,
+    /// The SQL command that was executed
+    pub executed_command: String,
+    pub message: Option<String>,
+    pub execution_time_ms: f64,
+}
+
+/// Mechanism a driver reads its server log through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerLogSource {
+    /// `performance_schema.error_log` (MySQL 8.0.22+)
+    MysqlErrorLog,
+    /// `pg_read_binary_file` on `pg_current_logfile()`
+    PgLogFile,
+    /// `xp_readerrorlog` on the current error log
+    SqlServerErrorLog,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerLogLine {
+    pub logged_at: Option<String>,
+    /// Severity as reported by the server (`ERROR`, `Warning`, ...)
+    pub level: Option<String>,
+    pub message: String,
+}
+
+/// Recent server log lines, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerLogChunk {
+    pub source: ServerLogSource,
+    pub lines: Vec<ServerLogLine>,
+    /// Pass back as `after` to read only the lines logged since this chunk
+    pub cursor: Option<String>,
+}
+
+/// A `NOTIFY` received on a listened channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub channel: String,
+    pub payload: String,
+    /// Backend process that sent the notification
+    pub process_id: u32,
+}
+
 /// Database sequence metadata (MariaDB 10.3+)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sequence {
@@ -1124,6 +1888,74 @@ pub struct ColumnFilter {
     pub options: FilterOptions,
 }
 
+/// Value extracted from a JSON column and returned as an extra column of a
+/// table page. Sort columns and filters may name it by `alias`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputedColumn {
+    pub alias: String,
+    /// JSON column the value is extracted from
+    pub source_column: String,
+    /// Dotted path with optional array indexes: `status`, `$.status`,
+    /// `address.city`, `items[0].sku`
+    pub json_path: String,
+}
+
+/// One step of a parsed `ComputedColumn::json_path`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(u32),
+}
+
+impl ComputedColumn {
+    /// Parses `json_path`. Keys are limited to letters, digits, `_` and `-`
+    /// so every backend can embed them without escaping.
+    pub fn path_segments(&self) -> EngineResult<Vec<JsonPathSegment>> {
+        let invalid = || {
+            EngineError::validation(format!(
+                "Invalid JSON path '{}' for computed column '{}'",
+                self.json_path, self.alias
+            ))
+        };
+        let path = self.json_path.trim();
+        let path = path
+            .strip_prefix("$.")
+            .or_else(|| path.strip_prefix('$'))
+            .unwrap_or(path);
+
+        let mut segments = Vec::new();
+        for part in path.split('.') {
+            let (key, mut rest) = part.find('[').map_or((part, ""), |i| part.split_at(i));
+            if key.is_empty() && rest.is_empty() {
+                return Err(invalid());
+            }
+            if !key.is_empty() {
+                if !key
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                {
+                    return Err(invalid());
+                }
+                segments.push(JsonPathSegment::Key(key.to_string()));
+            }
+            while let Some(bracketed) = rest.strip_prefix('[') {
+                let (index, tail) = bracketed.split_once(']').ok_or_else(invalid)?;
+                segments.push(JsonPathSegment::Index(
+                    index.parse().map_err(|_| invalid())?,
+                ));
+                rest = tail;
+            }
+            if !rest.is_empty() {
+                return Err(invalid());
+            }
+        }
+        if segments.is_empty() {
+            return Err(invalid());
+        }
+        Ok(segments)
+    }
+}
+
 /// Options for querying table data with pagination, sorting, and filtering
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TableQueryOptions {
@@ -1139,9 +1971,21 @@ pub struct TableQueryOptions {
     pub filters: Option<Vec<ColumnFilter>>,
     /// Full-text search term (searches all string columns)
     pub search: Option<String>,
+    /// JSON values exposed as extra columns, addressable by alias in
+    /// `sort_column` and `filters`
+    pub computed_columns: Option<Vec<ComputedColumn>>,
 }
 
 impl TableQueryOptions {
+    /// Computed column named `alias`, if any
+    pub fn computed_column(&self, alias: &str) -> Option<&ComputedColumn> {
+        self.computed_columns
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|c| c.alias == alias)
+    }
+
     /// Effective page number
     pub fn effective_page(&self) -> u32 {
         self.page.unwrap_or(0)
@@ -1158,6 +2002,54 @@ impl TableQueryOptions {
         let zero_indexed_page = if page > 0 { page - 1 } else { 0 };
         zero_indexed_page as u64 * self.effective_page_size() as u64
     }
+
+    /// `ORDER BY` clause of a page: the requested sort column, else the
+    /// table's implicit order. Without any ORDER BY, engines may return rows
+    /// in a different order on each page (Postgres after updates), so rows
+    /// would show up twice or not at all while paging.
+    pub fn order_by_sql(
+        &self,
+        implicit: &ImplicitOrder,
+        quote_ident: impl Fn(&str) -> String,
+    ) -> String {
+        if let Some(sort_col) = &self.sort_column {
+            let direction = match self.sort_direction.unwrap_or_default() {
+                SortDirection::Asc => "ASC",
+                SortDirection::Desc => "DESC",
+            };
+            return format!(" ORDER BY {} {}", quote_ident(sort_col), direction);
+        }
+        match implicit {
+            ImplicitOrder::PrimaryKey(columns) => {
+                let columns: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+                format!(" ORDER BY {}", columns.join(", "))
+            }
+            ImplicitOrder::RowId(expr) => format!(" ORDER BY {}", expr),
+            ImplicitOrder::Unordered => String::new(),
+        }
+    }
+}
+
+/// Order `query_table` falls back to when no sort column is given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImplicitOrder {
+    /// Primary key columns, in key order
+    PrimaryKey(Vec<String>),
+    /// Engine row identifier, used verbatim (`ctid`, `rowid`)
+    RowId(&'static str),
+    /// No stable order is known (views, heaps without a key)
+    Unordered,
+}
+
+impl ImplicitOrder {
+    /// The primary key when there is one, else the row identifier.
+    pub fn new(primary_key: Vec<String>, row_id: Option<&'static str>) -> Self {
+        match (primary_key.is_empty(), row_id) {
+            (false, _) => Self::PrimaryKey(primary_key),
+            (true, Some(row_id)) => Self::RowId(row_id),
+            (true, None) => Self::Unordered,
+        }
+    }
 }
 
 /// Paginated query result with metadata
@@ -1173,6 +2065,9 @@ pub struct PaginatedQueryResult {
     pub page_size: u32,
     /// Total number of pages
     pub total_pages: u32,
+    /// Caveats about the rows returned (e.g. row-level security filtering)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
 }
 
 impl PaginatedQueryResult {
@@ -1188,6 +2083,7 @@ impl PaginatedQueryResult {
             page,
             page_size,
             total_pages,
+            notes: Vec::new(),
         }
     }
 }
@@ -1273,3 +2169,293 @@ pub struct MaintenanceResult {
     /// Whether the operation succeeded
     pub success: bool,
 }
+
+/// An index with no recorded scans since usage statistics were last reset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnusedIndex {
+    pub index_name: String,
+    pub table_name: String,
+    pub schema: Option<String>,
+    /// On-disk size in bytes, when the engine reports it
+    pub size_bytes: Option<i64>,
+    /// Number of scans recorded for the index
+    pub scan_count: i64,
+}
+
+/// Result of an unused index analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnusedIndexReport {
+    pub indexes: Vec<UnusedIndex>,
+    /// Caveat about the statistics window ("unused" is relative to uptime)
+    pub note: String,
+}
+
+/// How bloat figures were obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BloatMethod {
+    /// Estimated from planner statistics (`pg_class` / `pg_stats`)
+    Estimate,
+    /// Measured by scanning the relations (`pgstattuple`)
+    Pgstattuple,
+}
+
+/// Estimated wasted space of a table or index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BloatEntry {
+    pub schema: String,
+    pub table_name: String,
+    /// Set for index entries
+    pub index_name: Option<String>,
+    /// Current on-disk size in bytes
+    pub size_bytes: i64,
+    /// Bytes that a rewrite (VACUUM FULL / REINDEX) would likely reclaim
+    pub bloat_bytes: i64,
+    pub bloat_percent: f64,
+}
+
+/// Result of a bloat analysis, each list sorted worst-first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BloatReport {
+    pub tables: Vec<BloatEntry>,
+    pub indexes: Vec<BloatEntry>,
+    pub method: BloatMethod,
+    /// Caveat about the accuracy of the figures
+    pub note: String,
+}
+
+/// Long-running operation that reports progress (`pg_stat_progress_*`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressOperation {
+    Vacuum,
+    Analyze,
+    /// CREATE INDEX and REINDEX
+    CreateIndex,
+    /// CLUSTER and VACUUM FULL
+    Cluster,
+    Copy,
+}
+
+/// Progress of a running operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryProgress {
+    pub backend_pid: i32,
+    pub operation: ProgressOperation,
+    /// Current phase as reported by the server; `None` until it starts reporting
+    pub phase: Option<String>,
+    /// Units of work done (blocks, tuples or bytes depending on the phase)
+    pub work_done: Option<i64>,
+    pub work_total: Option<i64>,
+    /// `None` while the total is unknown
+    pub percent: Option<f64>,
+}
+
+/// Storage metadata of a single key (Redis)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyMetadata {
+    pub key: String,
+    /// Value type (`string`, `hash`, `list`, ...)
+    pub key_type: String,
+    /// Internal encoding (`listpack`, `hashtable`, `embstr`, ...)
+    pub encoding: Option<String>,
+    /// Seconds until expiry; `None` when the key never expires
+    pub ttl_secs: Option<i64>,
+    /// Bytes used by the key and its value (`MEMORY USAGE`)
+    pub memory_usage_bytes: Option<u64>,
+    /// References to the value object (`OBJECT REFCOUNT`)
+    pub refcount: Option<i64>,
+    /// Seconds since the key was last accessed (`OBJECT IDLETIME`); `None`
+    /// under an LFU eviction policy, where the server does not track it
+    pub idle_time_secs: Option<u64>,
+    /// Number of elements (fields, members, entries); 1 for strings
+    pub element_count: Option<u64>,
+}
+
+/// A sampled key ranked by memory footprint (Redis)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BigKeyEntry {
+    pub key: String,
+    pub key_type: String,
+    /// Bytes reported by `MEMORY USAGE`
+    pub memory_usage_bytes: u64,
+}
+
+/// Per-type aggregate over the sampled keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyTypeStats {
+    pub key_type: String,
+    pub key_count: u64,
+    pub memory_usage_bytes: u64,
+}
+
+/// Result of a sampled keyspace memory scan (`redis-cli --bigkeys` style)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BigKeysReport {
+    /// Largest sampled keys, biggest first
+    pub top_keys: Vec<BigKeyEntry>,
+    /// Aggregates per key type, largest total first
+    pub by_type: Vec<KeyTypeStats>,
+    pub keys_sampled: u64,
+    /// `true` when the scan stopped at the sample cap before covering the keyspace
+    pub truncated: bool,
+}
+
+/// What a server-side COPY export reads from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CopySource {
+    /// `COPY (query) TO STDOUT`
+    Query { query: String },
+    /// `COPY table TO STDOUT`, resolved in the request namespace
+    Table { table: String },
+}
+
+/// Text layout of a COPY export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyFormat {
+    #[default]
+    Csv,
+    Text,
+}
+
+/// Columns always quoted in CSV output (`FORCE_QUOTE`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "columns", rename_all = "snake_case")]
+pub enum CopyForceQuote {
+    All,
+    Columns(Vec<String>),
+}
+
+/// Server formatting options of a COPY export, mirroring `COPY ... WITH (...)`.
+/// Unset fields keep the server defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CopyOptions {
+    #[serde(default)]
+    pub format: CopyFormat,
+    /// Single one-byte character separating columns
+    pub delimiter: Option<String>,
+    /// String written for NULL values
+    pub null_string: Option<String>,
+    /// CSV quoting character
+    pub quote: Option<String>,
+    /// CSV escape character inside quoted values
+    pub escape: Option<String>,
+    pub force_quote: Option<CopyForceQuote>,
+    #[serde(default)]
+    pub header: bool,
+    /// Output encoding name (`UTF8`, `LATIN1`, ...)
+    pub encoding: Option<String>,
+}
+
+/// Layout of a CSV file loaded by a bulk import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BulkImportOptions {
+    /// Single one-byte character separating columns
+    pub delimiter: String,
+    /// First line holds column names, matched against the table by name
+    pub header: bool,
+    /// Field value loaded as NULL; empty unquoted fields by default
+    pub null_string: Option<String>,
+    /// Rows per INSERT statement on drivers without a native bulk path
+    pub batch_size: usize,
+}
+
+impl Default for BulkImportOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ",".to_string(),
+            header: true,
+            null_string: None,
+            batch_size: 500,
+        }
+    }
+}
+
+/// How a table is copied from one connection into another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CopyTableOptions {
+    /// Create the destination table from the source columns first
+    pub create: bool,
+    /// Rows per INSERT statement, each committed in its own transaction
+    pub batch_size: usize,
+}
+
+impl Default for CopyTableOptions {
+    fn default() -> Self {
+        Self {
+            create: false,
+            batch_size: 500,
+        }
+    }
+}
+
+/// A syntax error located in the SQL text (1-based line and column)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SqlSyntaxError {
+    pub message: String,
+    pub line: Option<u64>,
+    pub column: Option<u64>,
+}
+
+/// Partitioning strategy of a partitioned table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+/// One partition of a partitioned table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TablePartition {
+    pub schema: String,
+    pub name: String,
+    /// Partition bound as the server prints it
+    /// (e.g. `FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')`, `DEFAULT`)
+    pub bound: String,
+    /// `true` when the partition is itself partitioned
+    pub is_partitioned: bool,
+}
+
+/// Profile of one column: null and distinct counts, bounds and, for numeric
+/// columns, the average
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub column: String,
+    pub total_rows: u64,
+    pub null_count: u64,
+    /// Distinct non-NULL values
+    pub distinct_count: u64,
+    /// Smallest non-NULL value (`Null` when the column holds none)
+    pub min: Value,
+    pub max: Value,
+    /// Mean of the non-NULL values; `None` for non-numeric columns
+    pub avg: Option<f64>,
+}
+
+/// Partition key and partitions of a partitioned table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TablePartitioning {
+    pub strategy: PartitionStrategy,
+    /// Partition key as the server prints it (e.g. `created_at`, `lower(name)`)
+    pub key: String,
+    pub partitions: Vec<TablePartition>,
+}
+
+/// Row-level security state of a table (PostgreSQL `pg_class.relrowsecurity`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableRowSecurity {
+    /// `ENABLE ROW LEVEL SECURITY` is set on the table
+    pub enabled: bool,
+    /// `FORCE ROW LEVEL SECURITY` is set, so policies also apply to the owner
+    pub forced: bool,
+    /// Names of the policies defined on the table
+    pub policies: Vec<String>,
+    /// Whether policies filter the rows the current session reads
+    pub filtering: bool,
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Column profiling shared by the SQL drivers: one aggregate query built by
+//! `qore_sql::generator::generate_column_stats`, run through the driver's own
+//! `execute_in_namespace`.
+
+use qore_core::error::{EngineError, EngineResult};
+use qore_core::traits::DataEngine;
+use qore_core::types::{ColumnStats, Namespace, QueryId, SessionId, Value};
+use qore_sql::generator::{generate_column_stats, SqlDialect};
+
+/// Looks `column` up in the table schema, then profiles it. The average is
+/// only computed for numeric column types.
+pub async fn column_stats(
+    driver: &dyn DataEngine,
+    dialect: SqlDialect,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    column: &str,
+) -> EngineResult<ColumnStats> {
+    let schema = driver.describe_table(session, namespace, table).await?;
+    let table_column = schema
+        .columns
+        .iter()
+        .find(|c| c.name == column)
+        .ok_or_else(|| {
+            EngineError::validation(format!("Column '{}' not found in '{}'", column, table))
+        })?;
+    let numeric = is_numeric_type(&table_column.data_type);
+
+    let sql = generate_column_stats(dialect, namespace, table, column, numeric);
+    let result = driver
+        .execute_in_namespace(session, Some(namespace.clone()), &sql, QueryId::new())
+        .await?;
+    let mut values = result
+        .rows
+        .into_iter()
+        .next()
+        .map(|row| row.values.into_iter())
+        .ok_or_else(|| EngineError::execution_error("Column statistics query returned no row"))?;
+    let mut next = || values.next().unwrap_or(Value::Null);
+
+    Ok(ColumnStats {
+        column: column.to_string(),
+        total_rows: count(&next()),
+        null_count: count(&next()),
+        distinct_count: count(&next()),
+        min: next(),
+        max: next(),
+        avg: number(&next()),
+    })
+}
+
+/// Whether `data_type` (as reported by `describe_table`) holds numbers.
+pub fn is_numeric_type(data_type: &str) -> bool {
+    let base = data_type
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let base = base.strip_suffix(" unsigned").unwrap_or(&base);
+    matches!(
+        base,
+        "smallint"
+            | "integer"
+            | "int"
+            | "int2"
+            | "int4"
+            | "int8"
+            | "bigint"
+            | "tinyint"
+            | "mediumint"
+            | "serial"
+            | "bigserial"
+            | "smallserial"
+            | "real"
+            | "float"
+            | "float4"
+            | "float8"
+            | "double"
+            | "double precision"
+            | "decimal"
+            | "numeric"
+            | "money"
+            | "smallmoney"
+    )
+}
+
+/// Counts come back as integers, except MySQL `SUM` which yields a DECIMAL.
+fn count(value: &Value) -> u64 {
+    number(value).map_or(0, |n| n.max(0.0) as u64)
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int(i) => Some(*i as f64),
+        Value::Float(f) => Some(*f),
+        Value::Text(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::sqlite::SqliteDriver;
+    use qore_core::types::ConnectionConfig;
+
+    #[test]
+    fn test_is_numeric_type() {
+        assert!(is_numeric_type("INTEGER"));
+        assert!(is_numeric_type("numeric(10,2)"));
+        assert!(is_numeric_type("int unsigned"));
+        assert!(is_numeric_type("double precision"));
+        assert!(!is_numeric_type("varchar(20)"));
+        assert!(!is_numeric_type("interval"));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_column_stats_counts_nulls_and_duplicates() {
+        let driver = SqliteDriver::new();
+        let session = driver
+            .connect(&ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE scores (name TEXT, score INTEGER)",
+            "INSERT INTO scores VALUES ('a', 10), ('b', 10), ('c', 40), ('d', NULL), (NULL, NULL)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        let namespace = Namespace::new("main");
+
+        let stats = driver
+            .column_stats(session, &namespace, "scores", "score")
+            .await
+            .unwrap();
+        assert_eq!(stats.total_rows, 5);
+        assert_eq!(stats.null_count, 2);
+        assert_eq!(stats.distinct_count, 2);
+        assert!(matches!(stats.min, Value::Int(10)));
+        assert!(matches!(stats.max, Value::Int(40)));
+        assert_eq!(stats.avg, Some(20.0));
+
+        let stats = driver
+            .column_stats(session, &namespace, "scores", "name")
+            .await
+            .unwrap();
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.distinct_count, 4);
+        assert!(matches!(&stats.min, Value::Text(s) if s == "a"));
+        assert_eq!(stats.avg, None);
+
+        assert!(driver
+            .column_stats(session, &namespace, "scores", "missing")
+            .await
+            .is_err());
+    }
+}
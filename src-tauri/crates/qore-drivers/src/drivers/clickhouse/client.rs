@@ -17,6 +17,8 @@ use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use reqwest::{Client as HttpClient, Url};
 use uuid::Uuid;
 
+use crate::query_timeout::query_timeout;
+
 /// Stateless ClickHouse client. Each instance owns one `reqwest::Client` so
 /// that connection pooling and TLS reuse work across queries.
 #[derive(Debug, Clone)]
@@ -30,6 +32,8 @@ pub(crate) struct ClickHouseClient {
     /// Distributed cluster name for DDL `ON CLUSTER` propagation. `None` means
     /// single-node behaviour (DDL applies locally only).
     cluster: Option<String>,
+    /// `ConnectionConfig::query_timeout_secs`, enforced by the driver.
+    query_timeout: Option<Duration>,
 }
 
 impl ClickHouseClient {
@@ -74,11 +78,17 @@ impl ClickHouseClient {
         );
 
         let timeout = Duration::from_secs(config.pool_acquire_timeout_secs.unwrap_or(30) as u64);
+        let query_timeout = query_timeout(config.query_timeout_secs);
 
         let mut builder = HttpClient::builder()
             .default_headers(headers)
             .connect_timeout(Duration::from_secs(10))
-            .timeout(timeout.max(Duration::from_secs(60)))
+            // Never cut a request before the query timeout had a chance to cancel it.
+            .timeout(
+                timeout
+                    .max(Duration::from_secs(60))
+                    .max(query_timeout.unwrap_or_default() + Duration::from_secs(10)),
+            )
             .pool_idle_timeout(Duration::from_secs(90));
 
         if matches!(
@@ -113,9 +123,14 @@ impl ClickHouseClient {
             default_database: default_database.clone(),
             active_database: Arc::new(RwLock::new(Some(default_database))),
             cluster,
+            query_timeout,
         })
     }
 
+    pub fn query_timeout(&self) -> Option<Duration> {
+        self.query_timeout
+    }
+
     /// Returns the configured cluster name (validated, trimmed), if any.
     pub fn cluster(&self) -> Option<&str> {
         self.cluster.as_deref()
@@ -320,6 +335,13 @@ mod tests {
             clickhouse_cluster: cluster.map(|s| s.to_string()),
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 
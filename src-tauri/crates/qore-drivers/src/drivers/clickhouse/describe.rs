@@ -8,6 +8,8 @@
 //! expose ClickHouse-specific knobs (`engine`, `partition_key`, `sorting_key`,
 //! `total_rows`) that the UI surfaces.
 
+use std::time::{Duration, Instant};
+
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::types::{
     Collection, CollectionList, CollectionListOptions, CollectionType, Namespace, TableColumn,
@@ -128,6 +130,7 @@ pub async fn list_tables(
                 namespace: namespace.clone(),
                 name,
                 collection_type,
+                partition_of: None,
             }
         })
         .collect();
@@ -135,6 +138,7 @@ pub async fn list_tables(
     Ok(CollectionList {
         collections,
         total_count: total,
+        next_cursor: None,
     })
 }
 
@@ -192,6 +196,11 @@ pub async fn describe_table(
             default_value: default_expr,
             is_primary_key: is_pk,
             is_auto_increment: false,
+            is_computed: false,
+            computed_definition: None,
+            is_sparse: false,
+            is_column_set: false,
+            allowed_values: None,
         });
     }
 
@@ -227,7 +236,10 @@ pub async fn describe_table(
         primary_key,
         foreign_keys: Vec::new(), // ClickHouse has no FK enforcement.
         row_count_estimate,
+        row_count_is_estimate: false,
         indexes,
+        partitioning: None,
+        row_security: None,
     })
 }
 
@@ -306,7 +318,8 @@ fn escape_like(s: &str) -> String {
 
 /// Pings the server. We use `SELECT 1` + a fresh query_id so it shows up
 /// in `system.query_log` distinctly from real workload.
-pub async fn ping(client: &ClickHouseClient) -> EngineResult<()> {
+pub async fn ping(client: &ClickHouseClient) -> EngineResult<Duration> {
+    let start = Instant::now();
     let qid = Uuid::new_v4();
     let body = client.fetch_json("SELECT 1", Some(&qid)).await?;
     let qr = parse_query_result(&body, 0.0)?;
@@ -317,7 +330,7 @@ pub async fn ping(client: &ClickHouseClient) -> EngineResult<()> {
         .map(|v| matches!(v, Value::Int(1)))
         .unwrap_or(false)
     {
-        Ok(())
+        Ok(start.elapsed())
     } else {
         Err(EngineError::execution_error("Ping failed"))
     }
@@ -21,6 +21,7 @@ use super::client::ClickHouseClient;
 use super::describe::{describe_table, list_databases, list_tables, ping};
 use super::literal::format_literal;
 use super::response::parse_query_result;
+use crate::query_timeout::with_query_timeout;
 
 type SessionMap = Arc<RwLock<HashMap<SessionId, Arc<ClickHouseClient>>>>;
 
@@ -60,6 +61,46 @@ impl ClickHouseDriver {
     async fn untrack_query(&self, query_id: &QueryId) -> Option<(SessionId, Uuid)> {
         self.queries.lock().await.remove(query_id)
     }
+
+    /// `execute_in_namespace` without the query timeout.
+    async fn run_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let client = self.get(session).await?;
+        if let Some(ns) = namespace {
+            client.set_current_database(ns.database);
+        }
+
+        let server_id = Uuid::new_v4();
+        self.track_query(session, query_id, server_id).await;
+
+        let is_query = is_result_query(query);
+
+        let started = Instant::now();
+        let res = if is_query {
+            client.fetch_json(query, Some(&server_id)).await
+        } else {
+            client.execute(query, Some(&server_id)).await
+        };
+        let elapsed_ms = started.elapsed().as_micros() as f64 / 1000.0;
+
+        self.untrack_query(&query_id).await;
+
+        match res {
+            Ok(body) => {
+                if is_query {
+                    parse_query_result(&body, elapsed_ms)
+                } else {
+                    Ok(QueryResult::with_affected_rows(0, elapsed_ms))
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
 }
 
 impl Default for ClickHouseDriver {
@@ -80,7 +121,8 @@ impl DataEngine for ClickHouseDriver {
 
     async fn test_connection(&self, config: &ConnectionConfig) -> EngineResult<()> {
         let client = ClickHouseClient::new(config)?;
-        ping(&client).await
+        ping(&client).await?;
+        Ok(())
     }
 
     async fn connect(&self, config: &ConnectionConfig) -> EngineResult<SessionId> {
@@ -98,7 +140,7 @@ impl DataEngine for ClickHouseDriver {
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         let client = self.get(session).await?;
         ping(&client).await
     }
@@ -145,36 +187,13 @@ impl DataEngine for ClickHouseDriver {
         query: &str,
         query_id: QueryId,
     ) -> EngineResult<QueryResult> {
-        let client = self.get(session).await?;
-        if let Some(ns) = namespace {
-            client.set_current_database(ns.database);
-        }
-
-        let server_id = Uuid::new_v4();
-        self.track_query(session, query_id, server_id).await;
-
-        let is_query = is_result_query(query);
-
-        let started = Instant::now();
-        let res = if is_query {
-            client.fetch_json(query, Some(&server_id)).await
-        } else {
-            client.execute(query, Some(&server_id)).await
-        };
-        let elapsed_ms = started.elapsed().as_micros() as f64 / 1000.0;
-
-        self.untrack_query(&query_id).await;
-
-        match res {
-            Ok(body) => {
-                if is_query {
-                    parse_query_result(&body, elapsed_ms)
-                } else {
-                    Ok(QueryResult::with_affected_rows(0, elapsed_ms))
-                }
-            }
-            Err(e) => Err(e),
-        }
+        let timeout = self.get(session).await?.query_timeout();
+        with_query_timeout(
+            timeout,
+            self.run_in_namespace(session, namespace, query, query_id),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
     }
 
     async fn preview_table(
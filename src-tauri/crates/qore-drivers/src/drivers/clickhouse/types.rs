@@ -54,6 +54,7 @@ pub fn build_column_info(name: &str, declared_type: &str) -> ColumnInfo {
         name: CompactString::new(name),
         data_type: CompactString::new(inner),
         nullable,
+        case_insensitive: false,
     }
 }
 
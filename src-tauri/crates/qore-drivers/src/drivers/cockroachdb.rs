@@ -8,17 +8,22 @@
 
 use async_trait::async_trait;
 
+use crate::column_stats;
 use crate::drivers::pg_compat::{self, SessionMap};
+use crate::explain;
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::{DataEngine, StreamSender};
 use qore_core::types::{
-    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType,
-    ConnectionConfig, ForeignKey, MaintenanceMessage, MaintenanceMessageLevel,
-    MaintenanceOperationInfo, MaintenanceOperationType, MaintenanceRequest, MaintenanceResult,
-    Namespace, PaginatedQueryResult, QueryId, QueryResult, RoutineDefinition, RoutineList,
-    RoutineListOptions, RoutineOperationResult, RoutineType, RowData, SessionId, TableQueryOptions,
-    TableSchema, TriggerDefinition, TriggerList, TriggerListOptions, TriggerOperationResult, Value,
+    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnStats,
+    ConnectionConfig, DescribeTableOptions, ExplainPlan, ForeignKey, IndexSpec, IsolationLevel,
+    MaintenanceMessage, MaintenanceMessageLevel, MaintenanceOperationInfo,
+    MaintenanceOperationType, MaintenanceRequest, MaintenanceResult, Namespace,
+    PaginatedQueryResult, PoolStats, QueryId, QueryResult, RoutineDefinition, RoutineList,
+    RoutineListOptions, RoutineOperationResult, RoutineType, RowData, ServerInfo, SessionId,
+    TableQueryOptions, TableSchema, TriggerDefinition, TriggerList, TriggerListOptions,
+    TriggerOperationResult, Value,
 };
+use qore_sql::generator::SqlDialect;
 
 pub struct CockroachDbDriver {
     sessions: SessionMap,
@@ -64,10 +69,20 @@ impl DataEngine for CockroachDbDriver {
         pg_compat::disconnect(&self.sessions, session).await
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         pg_compat::ping(&self.sessions, session).await
     }
 
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        pg_compat::pool_stats(&self.sessions, session)
+            .await
+            .map(Some)
+    }
+
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        pg_compat::server_info(&self.sessions, session).await
+    }
+
     // CockroachDB-specific: filter out crdb_internal, pg_extension
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
@@ -99,6 +114,10 @@ impl DataEngine for CockroachDbDriver {
             .collect())
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        pg_compat::list_databases(&self.sessions, session).await
+    }
+
     // CockroachDB-specific: no materialized views
 
     async fn list_collections(
@@ -163,12 +182,14 @@ impl DataEngine for CockroachDbDriver {
                 } else {
                     CollectionType::Table
                 },
+                partition_of: None,
             })
             .collect();
 
         Ok(CollectionList {
             collections,
             total_count: count_row.0 as u32,
+            next_cursor: None,
         })
     }
 
@@ -180,7 +201,41 @@ impl DataEngine for CockroachDbDriver {
         namespace: &Namespace,
         table: &str,
     ) -> EngineResult<TableSchema> {
-        pg_compat::describe_table_core(&self.sessions, session, namespace, table, false).await
+        self.describe_table_with_options(
+            session,
+            namespace,
+            table,
+            &DescribeTableOptions::default(),
+        )
+        .await
+    }
+
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
+    ) -> EngineResult<TableSchema> {
+        pg_compat::describe_table_core(
+            &self.sessions,
+            session,
+            namespace,
+            table,
+            false,
+            options.fast_estimate,
+        )
+        .await
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        pg_compat::create_index(&self.sessions, session, namespace, table, spec).await
     }
 
     async fn execute(
@@ -218,6 +273,24 @@ impl DataEngine for CockroachDbDriver {
         .await
     }
 
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::execute_with_params(
+            &self.sessions,
+            self.driver_id(),
+            session,
+            query,
+            params,
+            query_id,
+        )
+        .await
+    }
+
     async fn execute_stream(
         &self,
         session: SessionId,
@@ -264,16 +337,19 @@ impl DataEngine for CockroachDbDriver {
         table: &str,
         limit: u32,
     ) -> EngineResult<QueryResult> {
-        let schema = namespace.schema.as_deref().unwrap_or("public");
-        let query = format!(
-            "SELECT * FROM {}.{} LIMIT {}",
-            pg_compat::quote_ident(schema),
-            pg_compat::quote_ident(table),
-            limit
-        );
+        let query =
+            pg_compat::preview_table_sql(&self.sessions, session, namespace, table, limit).await?;
         self.execute(session, &query, QueryId::new()).await
     }
 
+    async fn geometry_to_geojson(
+        &self,
+        session: SessionId,
+        geometry: &str,
+    ) -> EngineResult<String> {
+        pg_compat::geometry_to_geojson(&self.sessions, session, geometry).await
+    }
+
     async fn query_table(
         &self,
         session: SessionId,
@@ -303,6 +379,24 @@ impl DataEngine for CockroachDbDriver {
         .await
     }
 
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        column_stats::column_stats(
+            self,
+            SqlDialect::Postgres,
+            session,
+            namespace,
+            table,
+            column,
+        )
+        .await
+    }
+
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         pg_compat::cancel(&self.sessions, session, query_id).await
     }
@@ -327,6 +421,12 @@ impl DataEngine for CockroachDbDriver {
         true
     }
 
+    /// Only the levels CockroachDB actually runs: weaker requests are
+    /// silently upgraded, so accepting them would be misleading.
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        &[IsolationLevel::ReadCommitted, IsolationLevel::Serializable]
+    }
+
     async fn insert_row(
         &self,
         session: SessionId,
@@ -358,10 +458,23 @@ impl DataEngine for CockroachDbDriver {
         pg_compat::delete_row(&self.sessions, session, namespace, table, primary_key).await
     }
 
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::truncate_table(&self.sessions, session, namespace, table).await
+    }
+
     fn supports_mutations(&self) -> bool {
         true
     }
 
+    fn supports_computed_columns(&self) -> bool {
+        true
+    }
+
     fn supports_routines(&self) -> bool {
         true
     }
@@ -543,6 +656,21 @@ impl DataEngine for CockroachDbDriver {
     fn supports_explain(&self) -> bool {
         true
     }
+
+    /// CockroachDB has no JSON plan format; the plan is returned as text.
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        let sql = explain::text_explain_sql(query, analyze);
+        let result = self
+            .execute_in_namespace(session, namespace, &sql, QueryId::new())
+            .await?;
+        Ok(explain::text_plan(explain::plan_text(&result), analyze))
+    }
 }
 
 #[cfg(test)]
@@ -570,6 +698,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 
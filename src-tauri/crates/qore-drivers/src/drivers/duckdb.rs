@@ -18,6 +18,13 @@
 //! in `tokio::task::spawn_blocking`. The `Connection` is `Send` but `!Sync`,
 //! so it is protected by a `std::sync::Mutex`.
 //!
+//! ## Remote Files
+//!
+//! When `extra_params` sets `httpfs = true` (or any `s3_*` key), the `httpfs`
+//! extension is installed and loaded on connect so queries can read remote
+//! files (`read_parquet('s3://…')`, `read_csv('https://…')`). S3 settings are
+//! applied with `SET` right after the extension is loaded.
+//!
 //! ## Transaction Handling
 //!
 //! Uses a simple `transaction_active` flag. Since all operations go through
@@ -26,7 +33,7 @@
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use ::duckdb::{params_from_iter, types::Value as DuckValue, Connection};
 use async_trait::async_trait;
@@ -36,13 +43,19 @@ use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::{DataEngine, StreamEvent, StreamSender};
 use qore_core::types::{
     CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnInfo,
-    ConnectionConfig, FilterOperator, ForeignKey, MaintenanceMessage, MaintenanceMessageLevel,
-    MaintenanceOperationInfo, MaintenanceOperationType, MaintenanceRequest, MaintenanceResult,
-    Namespace, PaginatedQueryResult, QueryId, QueryResult, Row as QRow, RowData, SessionId,
-    SortDirection, TableColumn, TableIndex, TableQueryOptions, TableSchema, Value,
+    ConnectionConfig, ExplainPlan, FilterOperator, ForeignKey, ImplicitOrder, MaintenanceMessage,
+    MaintenanceMessageLevel, MaintenanceOperationInfo, MaintenanceOperationType,
+    MaintenanceRequest, MaintenanceResult, Namespace, PaginatedQueryResult, QueryId, QueryResult,
+    Row as QRow, RowData, SessionId, TableColumn, TableIndex, TableQueryOptions, TableSchema,
+    Value,
 };
+use qore_sql::generator::SqlDialect;
 use qore_sql::safety;
 
+use crate::drivers::sqlite::validate_attach_alias;
+use crate::explain;
+use crate::query_timeout::{query_timeout, with_detached_timeout};
+
 /// Holds the connection state for a DuckDB session.
 pub struct DuckDbSession {
     /// The DuckDB connection, protected by a std Mutex (Connection is !Sync).
@@ -50,6 +63,9 @@ pub struct DuckDbSession {
     transaction_active: AtomicBool,
     /// The file path to the database (or ":memory:").
     pub db_path: String,
+    /// `ConnectionConfig::query_timeout_secs`. DuckDB queries cannot be
+    /// cancelled, so an expired query keeps running in its blocking task.
+    query_timeout: Option<Duration>,
 }
 
 pub struct DuckDbDriver {
@@ -71,25 +87,239 @@ impl DuckDbDriver {
             .ok_or_else(|| EngineError::session_not_found(session.0.to_string()))
     }
 
+    /// DuckDB quotes identifiers the PostgreSQL way.
     fn quote_ident(name: &str) -> String {
-        format!("\"{}\"", name.replace('"', "\"\""))
+        safety::quote_ident(SqlDialect::Postgres, name)
+    }
+
+    /// `execute_in_namespace` without the query timeout.
+    async fn run_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        _query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        // Block INSTALL / LOAD / ATTACH / COPY ... TO / PRAGMA
+        // enable_external_access. Without this filter, a single statement in
+        // the editor can install `httpfs` and then exfiltrate data over HTTP
+        // or write to arbitrary local paths (cf. audit B4-C3).
+        if let Some(danger) = safety::classify_duckdb_dangerous(query) {
+            return Err(EngineError::not_supported(danger.reason()));
+        }
+        let duck_session = self.get_session(session).await?;
+        let query = query.to_string();
+        let returns_rows = safety::returns_rows("duckdb", &query)
+            .unwrap_or_else(|_| safety::is_select_prefix(&query));
+
+        Self::with_conn(&duck_session, move |conn| {
+            if let Some(ns) = &namespace {
+                let schema = ns.schema.as_deref().unwrap_or(&ns.database);
+                conn.execute(
+                    &format!("SET schema = '{}'", schema.replace('\'', "''")),
+                    [],
+                )
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            }
+
+            let start = Instant::now();
+            if returns_rows {
+                execute_select(conn, &query, start)
+            } else {
+                execute_dml(conn, &query, start)
+            }
+        })
+        .await
+    }
+
+    /// `execute_stream_in_namespace` without the query timeout.
+    async fn stream_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        _query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        if let Some(danger) = safety::classify_duckdb_dangerous(query) {
+            return Err(EngineError::not_supported(danger.reason()));
+        }
+        let duck_session = self.get_session(session).await?;
+        let query = query.to_string();
+
+        let returns_rows = safety::returns_rows("duckdb", &query)
+            .unwrap_or_else(|_| safety::is_select_prefix(&query));
+
+        if !returns_rows {
+            let result = self
+                .run_in_namespace(session, namespace, &query, QueryId::new())
+                .await?;
+            let _ = sender
+                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
+                .await;
+            return Ok(());
+        }
+
+        let session_clone = Arc::clone(&duck_session);
+        tokio::task::spawn_blocking(move || {
+            let conn = session_clone.conn.lock().map_err(|e| {
+                EngineError::internal(format!("Failed to lock DuckDB connection: {e}"))
+            })?;
+
+            if let Some(ns) = &namespace {
+                let schema = ns.schema.as_deref().unwrap_or(&ns.database);
+                conn.execute(
+                    &format!("SET schema = '{}'", schema.replace('\'', "''")),
+                    [],
+                )
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            }
+
+            let mut stmt = conn
+                .prepare(&query)
+                .map_err(|e| classify_error(e.to_string()))?;
+
+            // duckdb crate quirk: column_count/column_name panic before execution, and query_map
+            // holds a mutable borrow on stmt. Collect rows first to release the borrow.
+            let rows: Vec<QRow> = stmt
+                .query_map([], |row| {
+                    let col_count = row.as_ref().column_count();
+                    let values: Vec<Value> = (0..col_count)
+                        .map(|i| duckdb_value_to_qoredb(row, i))
+                        .collect();
+                    Ok(QRow { values })
+                })
+                .map_err(|e| classify_error(e.to_string()))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+            let column_count = stmt.column_count();
+            let columns: Vec<ColumnInfo> = (0..column_count)
+                .map(|i| ColumnInfo {
+                    name: stmt
+                        .column_name(i)
+                        .map(|s| s.into())
+                        .unwrap_or_else(|_| format!("col_{}", i).into()),
+                    data_type: "VARCHAR".into(),
+                    nullable: true,
+                    case_insensitive: false,
+                })
+                .collect();
+
+            if sender.blocking_send(StreamEvent::Columns(columns)).is_err() {
+                return Ok(());
+            }
+
+            let row_count = rows.len() as u64;
+            let mut batch = Vec::with_capacity(500);
+            for row in rows {
+                batch.push(row);
+                if batch.len() >= 500 {
+                    if sender
+                        .blocking_send(StreamEvent::RowBatch(std::mem::replace(
+                            &mut batch,
+                            Vec::with_capacity(500),
+                        )))
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+            if !batch.is_empty() {
+                let _ = sender.blocking_send(StreamEvent::RowBatch(batch));
+            }
+
+            let _ = sender.blocking_send(StreamEvent::Done(row_count));
+            Ok::<(), EngineError>(())
+        })
+        .await
+        .map_err(|e| EngineError::internal(format!("DuckDB streaming task panicked: {e}")))?
+    }
+
+    /// Order of a page without sort column: the primary key, else the
+    /// `rowid` of base tables (views have none).
+    fn table_implicit_order(conn: &Connection, schema: &str, table: &str) -> ImplicitOrder {
+        let rows = conn
+            .prepare(
+                "SELECT t.table_type, k.column_name FROM information_schema.tables t \
+                 LEFT JOIN information_schema.table_constraints c \
+                 ON c.table_schema = t.table_schema AND c.table_name = t.table_name \
+                 AND c.constraint_type = 'PRIMARY KEY' \
+                 LEFT JOIN information_schema.key_column_usage k \
+                 ON k.constraint_name = c.constraint_name \
+                 AND k.table_schema = c.table_schema AND k.table_name = c.table_name \
+                 WHERE t.table_schema = ?1 AND t.table_name = ?2 \
+                 ORDER BY k.ordinal_position",
+            )
+            .and_then(|mut stmt| {
+                let rows = stmt
+                    .query_map([schema, table], |row| {
+                        Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+                    })?
+                    .collect::<Result<Vec<_>, _>>();
+                rows
+            });
+
+        match rows {
+            Ok(rows) => {
+                let is_table = rows.first().is_some_and(|(kind, _)| kind == "BASE TABLE");
+                let primary_key = rows.into_iter().filter_map(|(_, name)| name).collect();
+                ImplicitOrder::new(primary_key, is_table.then_some("rowid"))
+            }
+            Err(e) => {
+                tracing::debug!("No implicit order for {}.{}: {}", schema, table, e);
+                ImplicitOrder::Unordered
+            }
+        }
     }
 
     fn open_connection(config: &ConnectionConfig) -> EngineResult<Connection> {
         let path = config.host.trim();
+        let httpfs_setup = httpfs_setup_statements(config.extra_params.as_ref())?;
 
-        if path == ":memory:" || path == "duckdb::memory:" {
+        let conn = if path == ":memory:" || path == "duckdb::memory:" {
             Connection::open_in_memory().map_err(|e| {
                 EngineError::connection_failed(format!("Failed to open DuckDB in-memory: {e}"))
-            })
+            })?
         } else {
             Connection::open(path).map_err(|e| {
                 EngineError::connection_failed(format!(
                     "Failed to open DuckDB file '{}': {e}",
                     path
                 ))
-            })
+            })?
+        };
+
+        if !httpfs_setup.is_empty() {
+            Self::apply_httpfs_setup(&conn, &httpfs_setup)?;
+        }
+
+        Ok(conn)
+    }
+
+    /// Installs/loads `httpfs` then applies the S3 settings.
+    ///
+    /// A missing extension (offline machine, build without extension support)
+    /// is not fatal: the connection stays usable for local files and remote
+    /// reads fail later with DuckDB's own error.
+    fn apply_httpfs_setup(conn: &Connection, statements: &[String]) -> EngineResult<()> {
+        let (load, settings) = statements.split_at(HTTPFS_LOAD_STATEMENTS.len());
+        for stmt in load {
+            if let Err(e) = conn.execute_batch(stmt) {
+                tracing::warn!(
+                    "DuckDB: httpfs extension unavailable ({}), remote file access disabled",
+                    e
+                );
+                return Ok(());
+            }
+        }
+        for stmt in settings {
+            conn.execute_batch(stmt).map_err(|e| {
+                EngineError::connection_failed(format!("Failed to apply httpfs setting: {e}"))
+            })?;
         }
+        Ok(())
     }
 
     fn validate_path(path: &str) -> EngineResult<()> {
@@ -125,6 +355,214 @@ impl DuckDbDriver {
         Ok(())
     }
 
+    /// Filtered, sorted page of `schema_name.table` on an open connection.
+    /// Shared with the local result cache, which browses its own in-memory
+    /// database the same way.
+    pub fn query_table_in(
+        conn: &Connection,
+        schema_name: &str,
+        table: &str,
+        options: &TableQueryOptions,
+    ) -> EngineResult<PaginatedQueryResult> {
+        let start = Instant::now();
+        let page = options.effective_page();
+        let page_size = options.effective_page_size();
+        let offset = options.offset();
+
+        let table_ref = format!(
+            "{}.{}",
+            Self::quote_ident(schema_name),
+            Self::quote_ident(table)
+        );
+
+        let mut where_clauses: Vec<String> = Vec::new();
+        let mut bind_values: Vec<DuckValue> = Vec::new();
+
+        if let Some(filters) = &options.filters {
+            for filter in filters {
+                let col_ident = Self::quote_ident(&filter.column);
+                let clause = match filter.operator {
+                    FilterOperator::Eq => {
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("{} = ?", col_ident)
+                    }
+                    FilterOperator::Neq => {
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("{} != ?", col_ident)
+                    }
+                    FilterOperator::Gt => {
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("{} > ?", col_ident)
+                    }
+                    FilterOperator::Gte => {
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("{} >= ?", col_ident)
+                    }
+                    FilterOperator::Lt => {
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("{} < ?", col_ident)
+                    }
+                    FilterOperator::Lte => {
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("{} <= ?", col_ident)
+                    }
+                    FilterOperator::Like => {
+                        // CAST to VARCHAR so substring search works on every
+                        // column type (numbers, booleans, dates…), not just text.
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        format!("CAST({} AS VARCHAR) ILIKE ?", col_ident)
+                    }
+                    FilterOperator::IsNull => format!("{} IS NULL", col_ident),
+                    FilterOperator::IsNotNull => format!("{} IS NOT NULL", col_ident),
+                    FilterOperator::Regex => {
+                        filter.value.as_text().ok_or_else(|| {
+                            EngineError::syntax_error(
+                                "regex operator requires a string value in 'value'",
+                            )
+                        })?;
+                        bind_values.push(value_to_duckdb(&filter.value));
+                        // sanitized_regex_flags restricts flags to `imxs` so the literal is safe to interpolate.
+                        let flags_lit = filter.options.sanitized_regex_flags();
+                        if flags_lit.is_empty() {
+                            format!("regexp_matches({}::VARCHAR, ?)", col_ident)
+                        } else {
+                            format!("regexp_matches({}::VARCHAR, ?, '{}')", col_ident, flags_lit)
+                        }
+                    }
+                    FilterOperator::Text => {
+                        // DuckDB has no full-text index; fall back to a case-insensitive substring match.
+                        // The filter bar UI warns on absence of a text index.
+                        let term = filter.value.as_text().ok_or_else(|| {
+                            EngineError::syntax_error(
+                                "text operator requires a string value in 'value'",
+                            )
+                        })?;
+                        bind_values.push(value_to_duckdb(&qore_core::types::Value::Text(format!(
+                            "%{}%",
+                            term
+                        ))));
+                        format!("{}::VARCHAR ILIKE ?", col_ident)
+                    }
+                };
+                where_clauses.push(clause);
+            }
+        }
+
+        if let Some(ref search_term) = options.search {
+            if !search_term.trim().is_empty() {
+                if let Ok(mut col_stmt) = conn.prepare(
+                    "SELECT column_name, data_type FROM information_schema.columns \
+                     WHERE table_schema = ?1 AND table_name = ?2",
+                ) {
+                    if let Ok(col_rows) = col_stmt.query_map([schema_name, table], |row| {
+                        let name: String = row.get(0)?;
+                        let dtype: String = row.get(1)?;
+                        Ok((name, dtype))
+                    }) {
+                        let mut search_clauses: Vec<String> = Vec::new();
+                        for row in col_rows {
+                            if let Ok((col_name, dtype)) = row {
+                                let upper = dtype.to_uppercase();
+                                if upper.contains("BLOB") {
+                                    continue;
+                                }
+
+                                let col_ident = Self::quote_ident(&col_name);
+                                bind_values.push(DuckValue::Text(format!("%{}%", search_term)));
+
+                                if upper.contains("VARCHAR")
+                                    || upper.contains("TEXT")
+                                    || upper.contains("CHAR")
+                                {
+                                    search_clauses.push(format!("{} ILIKE ?", col_ident));
+                                } else {
+                                    search_clauses
+                                        .push(format!("CAST({} AS VARCHAR) ILIKE ?", col_ident));
+                                }
+                            }
+                        }
+                        if !search_clauses.is_empty() {
+                            where_clauses.push(format!("({})", search_clauses.join(" OR ")));
+                        }
+                    }
+                }
+            }
+        }
+
+        let where_sql = if where_clauses.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", where_clauses.join(" AND "))
+        };
+
+        let implicit_order = if options.sort_column.is_none() {
+            Self::table_implicit_order(conn, schema_name, table)
+        } else {
+            ImplicitOrder::Unordered
+        };
+        let order_sql = options.order_by_sql(&implicit_order, Self::quote_ident);
+
+        let count_sql = format!("SELECT COUNT(*) AS cnt FROM {}{}", table_ref, where_sql);
+        let total_rows: i64 = conn
+            .query_row(&count_sql, params_from_iter(bind_values.iter()), |row| {
+                row.get(0)
+            })
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let total_rows = total_rows.max(0) as u64;
+
+        let data_sql = format!(
+            "SELECT * FROM {}{}{} LIMIT {} OFFSET {}",
+            table_ref, where_sql, order_sql, page_size, offset
+        );
+
+        let mut stmt = conn
+            .prepare(&data_sql)
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        // duckdb crate: column_count/column_name panic before execution — collect first.
+        let rows_iter = stmt
+            .query_map(params_from_iter(bind_values.iter()), |row| {
+                let col_count = row.as_ref().column_count();
+                let values: Vec<Value> = (0..col_count)
+                    .map(|i| duckdb_value_to_qoredb(row, i))
+                    .collect();
+                Ok(QRow { values })
+            })
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let mut rows = Vec::new();
+        for row_result in rows_iter {
+            let row = row_result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+            rows.push(row);
+        }
+
+        let column_count = stmt.column_count();
+        let columns: Vec<ColumnInfo> = (0..column_count)
+            .map(|i| ColumnInfo {
+                name: stmt
+                    .column_name(i)
+                    .map(|s| s.into())
+                    .unwrap_or_else(|_| format!("col_{}", i).into()),
+                data_type: "VARCHAR".into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect();
+
+        let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+        let result = QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms,
+        };
+
+        Ok(PaginatedQueryResult::new(
+            result, total_rows, page, page_size,
+        ))
+    }
+
     /// Runs a synchronous closure on the session's connection inside spawn_blocking.
     async fn with_conn<F, R>(session: &Arc<DuckDbSession>, f: F) -> EngineResult<R>
     where
@@ -149,8 +587,126 @@ impl Default for DuckDbDriver {
     }
 }
 
+const HTTPFS_LOAD_STATEMENTS: [&str; 2] = ["INSTALL httpfs", "LOAD httpfs"];
+
+/// `extra_params` keys forwarded verbatim as DuckDB `SET` statements.
+const HTTPFS_SETTINGS: &[&str] = &[
+    "s3_region",
+    "s3_access_key_id",
+    "s3_secret_access_key",
+    "s3_session_token",
+    "s3_endpoint",
+    "s3_url_style",
+    "s3_use_ssl",
+];
+
+/// Builds the statements enabling remote file access from `extra_params`.
+///
+/// Returns an empty list when httpfs is not requested. Unknown `s3_*` keys
+/// and malformed values are rejected so a typo doesn't silently fall back to
+/// anonymous access.
+fn httpfs_setup_statements(params: Option<&HashMap<String, String>>) -> EngineResult<Vec<String>> {
+    let Some(params) = params else {
+        return Ok(Vec::new());
+    };
+
+    let explicit = params.get("httpfs").map(|v| {
+        matches!(
+            v.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    });
+    let has_s3 = params.keys().any(|k| k.starts_with("s3_"));
+    if explicit == Some(false) || (explicit.is_none() && !has_s3) {
+        return Ok(Vec::new());
+    }
+
+    let mut settings: Vec<(&String, &String)> = params
+        .iter()
+        .filter(|(k, _)| k.starts_with("s3_"))
+        .collect();
+    settings.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut statements: Vec<String> = HTTPFS_LOAD_STATEMENTS
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    for (key, value) in settings {
+        if !HTTPFS_SETTINGS.contains(&key.as_str()) {
+            return Err(EngineError::validation(format!(
+                "Unknown DuckDB S3 setting '{key}'"
+            )));
+        }
+        let value = value.trim();
+        if value.chars().any(|c| c.is_control()) {
+            return Err(EngineError::validation(format!(
+                "Invalid value for '{key}': control characters are not allowed"
+            )));
+        }
+        match key.as_str() {
+            "s3_region" => {
+                if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                {
+                    return Err(EngineError::validation(format!(
+                        "Invalid S3 region '{value}'"
+                    )));
+                }
+            }
+            "s3_url_style" => {
+                if !matches!(value, "vhost" | "path") {
+                    return Err(EngineError::validation(
+                        "s3_url_style must be 'vhost' or 'path'",
+                    ));
+                }
+            }
+            "s3_use_ssl" => {
+                if !matches!(value, "true" | "false") {
+                    return Err(EngineError::validation(
+                        "s3_use_ssl must be 'true' or 'false'",
+                    ));
+                }
+                statements.push(format!("SET {key} = {value}"));
+                continue;
+            }
+            _ => {}
+        }
+        statements.push(format!("SET {key} = '{}'", value.replace('\'', "''")));
+    }
+
+    Ok(statements)
+}
+
+/// `CREATE VIEW` statement exposing a data file through the DuckDB reader
+/// matching its extension.
+fn file_view_sql(file_path: &str, view_name: &str) -> EngineResult<String> {
+    if view_name.trim().is_empty() {
+        return Err(EngineError::validation("View name cannot be empty"));
+    }
+    let extension = std::path::Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    let reader = match extension.as_deref() {
+        Some("parquet") => "read_parquet",
+        Some("csv" | "tsv") => "read_csv_auto",
+        Some("json" | "jsonl" | "ndjson") => "read_json_auto",
+        _ => {
+            return Err(EngineError::validation(format!(
+                "Unsupported file type for {file_path}: expected parquet, csv, tsv, json or jsonl"
+            )))
+        }
+    };
+    Ok(format!(
+        "CREATE VIEW {} AS SELECT * FROM {}('{}')",
+        DuckDbDriver::quote_ident(view_name),
+        reader,
+        file_path.replace('\'', "''")
+    ))
+}
+
 /// Converts a QoreDB Value to a DuckDB Value for parameter binding.
-fn value_to_duckdb(value: &Value) -> DuckValue {
+pub fn value_to_duckdb(value: &Value) -> DuckValue {
     match value {
         Value::Null => DuckValue::Null,
         Value::Bool(b) => DuckValue::Boolean(*b),
@@ -208,7 +764,7 @@ fn duckdb_value_to_qoredb(row: &::duckdb::Row<'_>, idx: usize) -> Value {
 /// NOTE: DuckDB crate requires that `column_name()` is called AFTER the statement
 /// has been executed (i.e., after iterating rows). We collect rows first, then
 /// extract column names.
-fn execute_select(conn: &Connection, sql: &str, start: Instant) -> EngineResult<QueryResult> {
+pub fn execute_select(conn: &Connection, sql: &str, start: Instant) -> EngineResult<QueryResult> {
     let mut stmt = conn
         .prepare(sql)
         .map_err(|e| classify_error(e.to_string()))?;
@@ -239,6 +795,7 @@ fn execute_select(conn: &Connection, sql: &str, start: Instant) -> EngineResult<
                 .unwrap_or_else(|_| format!("col_{}", i).into()),
             data_type: "VARCHAR".into(),
             nullable: true,
+            case_insensitive: false,
         })
         .collect();
 
@@ -304,6 +861,7 @@ impl DataEngine for DuckDbDriver {
         Self::validate_path(&config.host)?;
 
         let db_path = config.host.clone();
+        let query_timeout = query_timeout(config.query_timeout_secs);
         let config = config.clone();
         let conn = tokio::task::spawn_blocking(move || Self::open_connection(&config))
             .await
@@ -314,6 +872,7 @@ impl DataEngine for DuckDbDriver {
             conn: std::sync::Mutex::new(conn),
             transaction_active: AtomicBool::new(false),
             db_path,
+            query_timeout,
         });
 
         let mut sessions = self.sessions.write().await;
@@ -330,14 +889,16 @@ impl DataEngine for DuckDbDriver {
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<Duration> {
         let duck_session = self.get_session(session).await?;
+        let start = Instant::now();
         Self::with_conn(&duck_session, |conn| {
             conn.execute("SELECT 1", [])
                 .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
             Ok(())
         })
-        .await
+        .await?;
+        Ok(start.elapsed())
     }
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
@@ -371,6 +932,17 @@ impl DataEngine for DuckDbDriver {
         .await
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        let duck_session = self.get_session(session).await?;
+
+        Self::with_conn(&duck_session, |conn| {
+            conn.query_row("SELECT current_database()", [], |row| row.get(0))
+                .map(|name: String| vec![name])
+                .map_err(|e| EngineError::execution_error(e.to_string()))
+        })
+        .await
+    }
+
     async fn list_collections(
         &self,
         session: SessionId,
@@ -448,12 +1020,14 @@ impl DataEngine for DuckDbDriver {
                     namespace: namespace.clone(),
                     name,
                     collection_type,
+                    partition_of: None,
                 });
             }
 
             Ok(CollectionList {
                 collections,
                 total_count: total_count as u32,
+                next_cursor: None,
             })
         })
         .await
@@ -503,6 +1077,11 @@ impl DataEngine for DuckDbDriver {
                     default_value,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 });
             }
 
@@ -616,7 +1195,10 @@ impl DataEngine for DuckDbDriver {
                 },
                 foreign_keys,
                 row_count_estimate,
+                row_count_is_estimate: true,
                 indexes,
+                partitioning: None,
+                row_security: None,
             })
         })
         .await
@@ -657,152 +1239,41 @@ impl DataEngine for DuckDbDriver {
         session: SessionId,
         namespace: Option<Namespace>,
         query: &str,
-        _query_id: QueryId,
-    ) -> EngineResult<QueryResult> {
-        // Block INSTALL / LOAD / ATTACH / COPY ... TO / PRAGMA
-        // enable_external_access. Without this filter, a single statement in
-        // the editor can install `httpfs` and then exfiltrate data over HTTP
-        // or write to arbitrary local paths (cf. audit B4-C3).
-        if let Some(danger) = safety::classify_duckdb_dangerous(query) {
-            return Err(EngineError::not_supported(danger.reason()));
-        }
-        let duck_session = self.get_session(session).await?;
-        let query = query.to_string();
-        let returns_rows = safety::returns_rows("duckdb", &query)
-            .unwrap_or_else(|_| safety::is_select_prefix(&query));
-
-        Self::with_conn(&duck_session, move |conn| {
-            if let Some(ns) = &namespace {
-                let schema = ns.schema.as_deref().unwrap_or(&ns.database);
-                conn.execute(
-                    &format!("SET schema = '{}'", schema.replace('\'', "''")),
-                    [],
-                )
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
-            }
-
-            let start = Instant::now();
-            if returns_rows {
-                execute_select(conn, &query, start)
-            } else {
-                execute_dml(conn, &query, start)
-            }
-        })
-        .await
-    }
-
-    async fn execute_stream(
-        &self,
-        session: SessionId,
-        query: &str,
         query_id: QueryId,
-        sender: StreamSender,
-    ) -> EngineResult<()> {
-        self.execute_stream_in_namespace(session, None, query, query_id, sender)
-            .await
-    }
-
-    async fn execute_stream_in_namespace(
-        &self,
-        session: SessionId,
-        namespace: Option<Namespace>,
-        query: &str,
-        _query_id: QueryId,
-        sender: StreamSender,
-    ) -> EngineResult<()> {
-        if let Some(danger) = safety::classify_duckdb_dangerous(query) {
-            return Err(EngineError::not_supported(danger.reason()));
-        }
-        let duck_session = self.get_session(session).await?;
-        let query = query.to_string();
-
-        let returns_rows = safety::returns_rows("duckdb", &query)
-            .unwrap_or_else(|_| safety::is_select_prefix(&query));
-
-        if !returns_rows {
-            let result = self
-                .execute_in_namespace(session, namespace, &query, QueryId::new())
-                .await?;
-            let _ = sender
-                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
-                .await;
-            return Ok(());
-        }
-
-        let session_clone = Arc::clone(&duck_session);
-        tokio::task::spawn_blocking(move || {
-            let conn = session_clone.conn.lock().map_err(|e| {
-                EngineError::internal(format!("Failed to lock DuckDB connection: {e}"))
-            })?;
-
-            if let Some(ns) = &namespace {
-                let schema = ns.schema.as_deref().unwrap_or(&ns.database);
-                conn.execute(
-                    &format!("SET schema = '{}'", schema.replace('\'', "''")),
-                    [],
-                )
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
-            }
-
-            let mut stmt = conn
-                .prepare(&query)
-                .map_err(|e| classify_error(e.to_string()))?;
-
-            // duckdb crate quirk: column_count/column_name panic before execution, and query_map
-            // holds a mutable borrow on stmt. Collect rows first to release the borrow.
-            let rows: Vec<QRow> = stmt
-                .query_map([], |row| {
-                    let col_count = row.as_ref().column_count();
-                    let values: Vec<Value> = (0..col_count)
-                        .map(|i| duckdb_value_to_qoredb(row, i))
-                        .collect();
-                    Ok(QRow { values })
-                })
-                .map_err(|e| classify_error(e.to_string()))?
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
-
-            let column_count = stmt.column_count();
-            let columns: Vec<ColumnInfo> = (0..column_count)
-                .map(|i| ColumnInfo {
-                    name: stmt
-                        .column_name(i)
-                        .map(|s| s.into())
-                        .unwrap_or_else(|_| format!("col_{}", i).into()),
-                    data_type: "VARCHAR".into(),
-                    nullable: true,
-                })
-                .collect();
-
-            if sender.blocking_send(StreamEvent::Columns(columns)).is_err() {
-                return Ok(());
-            }
+    ) -> EngineResult<QueryResult> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_detached_timeout(
+            timeout,
+            self.run_in_namespace(session, namespace, query, query_id),
+        )
+        .await
+    }
 
-            let row_count = rows.len() as u64;
-            let mut batch = Vec::with_capacity(500);
-            for row in rows {
-                batch.push(row);
-                if batch.len() >= 500 {
-                    if sender
-                        .blocking_send(StreamEvent::RowBatch(std::mem::replace(
-                            &mut batch,
-                            Vec::with_capacity(500),
-                        )))
-                        .is_err()
-                    {
-                        return Ok(());
-                    }
-                }
-            }
-            if !batch.is_empty() {
-                let _ = sender.blocking_send(StreamEvent::RowBatch(batch));
-            }
+    async fn execute_stream(
+        &self,
+        session: SessionId,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        self.execute_stream_in_namespace(session, None, query, query_id, sender)
+            .await
+    }
 
-            let _ = sender.blocking_send(StreamEvent::Done(row_count));
-            Ok::<(), EngineError>(())
-        })
+    async fn execute_stream_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_detached_timeout(
+            timeout,
+            self.stream_in_namespace(session, namespace, query, query_id, sender),
+        )
         .await
-        .map_err(|e| EngineError::internal(format!("DuckDB streaming task panicked: {e}")))?
     }
 
     async fn query_table(
@@ -819,211 +1290,8 @@ impl DataEngine for DuckDbDriver {
             .unwrap_or_else(|| namespace.database.clone());
         let table = table.to_string();
 
-        let page = options.effective_page();
-        let page_size = options.effective_page_size();
-        let offset = options.offset();
-
         Self::with_conn(&duck_session, move |conn| {
-            let start = Instant::now();
-            let table_ref = format!(
-                "{}.{}",
-                Self::quote_ident(&schema_name),
-                Self::quote_ident(&table)
-            );
-
-            let mut where_clauses: Vec<String> = Vec::new();
-            let mut bind_values: Vec<DuckValue> = Vec::new();
-
-            if let Some(filters) = &options.filters {
-                for filter in filters {
-                    let col_ident = Self::quote_ident(&filter.column);
-                    let clause = match filter.operator {
-                        FilterOperator::Eq => {
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("{} = ?", col_ident)
-                        }
-                        FilterOperator::Neq => {
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("{} != ?", col_ident)
-                        }
-                        FilterOperator::Gt => {
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("{} > ?", col_ident)
-                        }
-                        FilterOperator::Gte => {
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("{} >= ?", col_ident)
-                        }
-                        FilterOperator::Lt => {
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("{} < ?", col_ident)
-                        }
-                        FilterOperator::Lte => {
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("{} <= ?", col_ident)
-                        }
-                        FilterOperator::Like => {
-                            // CAST to VARCHAR so substring search works on every
-                            // column type (numbers, booleans, dates…), not just text.
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            format!("CAST({} AS VARCHAR) ILIKE ?", col_ident)
-                        }
-                        FilterOperator::IsNull => format!("{} IS NULL", col_ident),
-                        FilterOperator::IsNotNull => format!("{} IS NOT NULL", col_ident),
-                        FilterOperator::Regex => {
-                            filter.value.as_text().ok_or_else(|| {
-                                EngineError::syntax_error(
-                                    "regex operator requires a string value in 'value'",
-                                )
-                            })?;
-                            bind_values.push(value_to_duckdb(&filter.value));
-                            // sanitized_regex_flags restricts flags to `imxs` so the literal is safe to interpolate.
-                            let flags_lit = filter.options.sanitized_regex_flags();
-                            if flags_lit.is_empty() {
-                                format!("regexp_matches({}::VARCHAR, ?)", col_ident)
-                            } else {
-                                format!(
-                                    "regexp_matches({}::VARCHAR, ?, '{}')",
-                                    col_ident, flags_lit
-                                )
-                            }
-                        }
-                        FilterOperator::Text => {
-                            // DuckDB has no full-text index; fall back to a case-insensitive substring match.
-                            // The filter bar UI warns on absence of a text index.
-                            let term = filter.value.as_text().ok_or_else(|| {
-                                EngineError::syntax_error(
-                                    "text operator requires a string value in 'value'",
-                                )
-                            })?;
-                            bind_values.push(value_to_duckdb(&qore_core::types::Value::Text(
-                                format!("%{}%", term),
-                            )));
-                            format!("{}::VARCHAR ILIKE ?", col_ident)
-                        }
-                    };
-                    where_clauses.push(clause);
-                }
-            }
-
-            if let Some(ref search_term) = options.search {
-                if !search_term.trim().is_empty() {
-                    if let Ok(mut col_stmt) = conn.prepare(
-                        "SELECT column_name, data_type FROM information_schema.columns \
-                         WHERE table_schema = ?1 AND table_name = ?2",
-                    ) {
-                        if let Ok(col_rows) = col_stmt.query_map([&schema_name, &table], |row| {
-                            let name: String = row.get(0)?;
-                            let dtype: String = row.get(1)?;
-                            Ok((name, dtype))
-                        }) {
-                            let mut search_clauses: Vec<String> = Vec::new();
-                            for row in col_rows {
-                                if let Ok((col_name, dtype)) = row {
-                                    let upper = dtype.to_uppercase();
-                                    if upper.contains("BLOB") {
-                                        continue;
-                                    }
-
-                                    let col_ident = Self::quote_ident(&col_name);
-                                    bind_values.push(DuckValue::Text(format!("%{}%", search_term)));
-
-                                    if upper.contains("VARCHAR")
-                                        || upper.contains("TEXT")
-                                        || upper.contains("CHAR")
-                                    {
-                                        search_clauses.push(format!("{} ILIKE ?", col_ident));
-                                    } else {
-                                        search_clauses.push(format!(
-                                            "CAST({} AS VARCHAR) ILIKE ?",
-                                            col_ident
-                                        ));
-                                    }
-                                }
-                            }
-                            if !search_clauses.is_empty() {
-                                where_clauses.push(format!("({})", search_clauses.join(" OR ")));
-                            }
-                        }
-                    }
-                }
-            }
-
-            let where_sql = if where_clauses.is_empty() {
-                String::new()
-            } else {
-                format!(" WHERE {}", where_clauses.join(" AND "))
-            };
-
-            let order_sql = if let Some(sort_col) = &options.sort_column {
-                let sort_ident = Self::quote_ident(sort_col);
-                let direction = match options.sort_direction.unwrap_or_default() {
-                    SortDirection::Asc => "ASC",
-                    SortDirection::Desc => "DESC",
-                };
-                format!(" ORDER BY {} {}", sort_ident, direction)
-            } else {
-                String::new()
-            };
-
-            let count_sql = format!("SELECT COUNT(*) AS cnt FROM {}{}", table_ref, where_sql);
-            let total_rows: i64 = conn
-                .query_row(&count_sql, params_from_iter(bind_values.iter()), |row| {
-                    row.get(0)
-                })
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
-            let total_rows = total_rows.max(0) as u64;
-
-            let data_sql = format!(
-                "SELECT * FROM {}{}{} LIMIT {} OFFSET {}",
-                table_ref, where_sql, order_sql, page_size, offset
-            );
-
-            let mut stmt = conn
-                .prepare(&data_sql)
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
-
-            // duckdb crate: column_count/column_name panic before execution — collect first.
-            let rows_iter = stmt
-                .query_map(params_from_iter(bind_values.iter()), |row| {
-                    let col_count = row.as_ref().column_count();
-                    let values: Vec<Value> = (0..col_count)
-                        .map(|i| duckdb_value_to_qoredb(row, i))
-                        .collect();
-                    Ok(QRow { values })
-                })
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
-
-            let mut rows = Vec::new();
-            for row_result in rows_iter {
-                let row = row_result.map_err(|e| EngineError::execution_error(e.to_string()))?;
-                rows.push(row);
-            }
-
-            let column_count = stmt.column_count();
-            let columns: Vec<ColumnInfo> = (0..column_count)
-                .map(|i| ColumnInfo {
-                    name: stmt
-                        .column_name(i)
-                        .map(|s| s.into())
-                        .unwrap_or_else(|_| format!("col_{}", i).into()),
-                    data_type: "VARCHAR".into(),
-                    nullable: true,
-                })
-                .collect();
-
-            let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-            let result = QueryResult {
-                columns,
-                rows,
-                affected_rows: None,
-                execution_time_ms,
-            };
-
-            Ok(PaginatedQueryResult::new(
-                result, total_rows, page, page_size,
-            ))
+            Self::query_table_in(conn, &schema_name, &table, &options)
         })
         .await
     }
@@ -1086,6 +1354,7 @@ impl DataEngine for DuckDbDriver {
                         .unwrap_or_else(|_| format!("col_{}", i).into()),
                     data_type: "VARCHAR".into(),
                     nullable: true,
+                    case_insensitive: false,
                 })
                 .collect();
 
@@ -1132,6 +1401,45 @@ impl DataEngine for DuckDbDriver {
         .await
     }
 
+    async fn attach_database(
+        &self,
+        session: SessionId,
+        file_path: &str,
+        alias: &str,
+    ) -> EngineResult<()> {
+        validate_attach_alias(alias)?;
+        let duck_session = self.get_session(session).await?;
+        let sql = format!(
+            "ATTACH '{}' AS {}",
+            file_path.replace('\'', "''"),
+            Self::quote_ident(alias)
+        );
+
+        Self::with_conn(&duck_session, move |conn| {
+            conn.execute(&sql, [])
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn register_file_view(
+        &self,
+        session: SessionId,
+        file_path: &str,
+        view_name: &str,
+    ) -> EngineResult<()> {
+        let sql = file_view_sql(file_path, view_name)?;
+        let duck_session = self.get_session(session).await?;
+
+        Self::with_conn(&duck_session, move |conn| {
+            conn.execute(&sql, [])
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            Ok(())
+        })
+        .await
+    }
+
     async fn begin_transaction(&self, session: SessionId) -> EngineResult<()> {
         let duck_session = self.get_session(session).await?;
 
@@ -1417,6 +1725,21 @@ impl DataEngine for DuckDbDriver {
         true
     }
 
+    /// DuckDB renders its plans as text only.
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        let sql = explain::text_explain_sql(query, analyze);
+        let result = self
+            .execute_in_namespace(session, namespace, &sql, QueryId::new())
+            .await?;
+        Ok(explain::text_plan(explain::plan_text(&result), analyze))
+    }
+
     async fn cancel(&self, _session: SessionId, _query_id: Option<QueryId>) -> EngineResult<()> {
         Err(EngineError::not_supported(
             "DuckDB does not support query cancellation",
@@ -1427,6 +1750,12 @@ impl DataEngine for DuckDbDriver {
         CancelSupport::None
     }
 
+    /// A session wraps a single connection behind a mutex, so queries are
+    /// queued one at a time.
+    fn default_query_concurrency(&self, _config: &ConnectionConfig) -> usize {
+        1
+    }
+
     fn supports_maintenance(&self) -> bool {
         true
     }
@@ -1518,29 +1847,23 @@ mod tests {
     async fn test_connect_memory() {
         let driver = DuckDbDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "duckdb".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::local_for_tests("duckdb", ":memory:");
+
+        let session_id = driver.connect(&config).await.unwrap();
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ping_reports_latency() {
+        let driver = DuckDbDriver::new();
+        let config = ConnectionConfig::local_for_tests("duckdb", ":memory:");
 
         let session_id = driver.connect(&config).await.unwrap();
+        let latency = driver.ping(session_id).await.unwrap();
+        assert!(latency > Duration::ZERO);
+
+        let err = driver.ping(SessionId::new()).await.unwrap_err();
+        assert!(matches!(err, EngineError::SessionNotFound { .. }), "{err}");
         driver.disconnect(session_id).await.unwrap();
     }
 
@@ -1548,27 +1871,7 @@ mod tests {
     async fn test_create_and_query() {
         let driver = DuckDbDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "duckdb".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::local_for_tests("duckdb", ":memory:");
 
         let session_id = driver.connect(&config).await.unwrap();
 
@@ -1606,27 +1909,7 @@ mod tests {
     async fn test_list_namespaces() {
         let driver = DuckDbDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "duckdb".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::local_for_tests("duckdb", ":memory:");
 
         let session_id = driver.connect(&config).await.unwrap();
 
@@ -1641,27 +1924,7 @@ mod tests {
     async fn test_transactions() {
         let driver = DuckDbDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "duckdb".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::local_for_tests("duckdb", ":memory:");
 
         let session_id = driver.connect(&config).await.unwrap();
 
@@ -1705,6 +1968,157 @@ mod tests {
         assert!(DuckDbDriver::validate_path("").is_err());
     }
 
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_httpfs_setup_disabled_by_default() {
+        assert!(httpfs_setup_statements(None).unwrap().is_empty());
+        assert!(
+            httpfs_setup_statements(Some(&params(&[("httpfs", "false")])))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_httpfs_setup_statements() {
+        let stmts = httpfs_setup_statements(Some(&params(&[
+            ("s3_region", "eu-west-1"),
+            ("s3_access_key_id", "AKIA"),
+            ("s3_secret_access_key", "it's"),
+            ("s3_use_ssl", "true"),
+        ])))
+        .unwrap();
+        assert_eq!(
+            stmts,
+            vec![
+                "INSTALL httpfs",
+                "LOAD httpfs",
+                "SET s3_access_key_id = 'AKIA'",
+                "SET s3_region = 'eu-west-1'",
+                "SET s3_secret_access_key = 'it''s'",
+                "SET s3_use_ssl = true",
+            ]
+        );
+
+        let stmts = httpfs_setup_statements(Some(&params(&[("httpfs", "true")]))).unwrap();
+        assert_eq!(stmts, vec!["INSTALL httpfs", "LOAD httpfs"]);
+    }
+
+    #[test]
+    fn test_httpfs_setup_rejects_invalid_settings() {
+        assert!(httpfs_setup_statements(Some(&params(&[("s3_bogus", "x")]))).is_err());
+        assert!(httpfs_setup_statements(Some(&params(&[("s3_region", "eu'; DROP")]))).is_err());
+        assert!(httpfs_setup_statements(Some(&params(&[("s3_use_ssl", "maybe")]))).is_err());
+        assert!(httpfs_setup_statements(Some(&params(&[("s3_endpoint", "a\nb")]))).is_err());
+    }
+
+    /// Network-gated: set `QOREDB_TEST_NETWORK=1` to run.
+    #[tokio::test]
+    async fn test_httpfs_reads_public_parquet() {
+        if std::env::var("QOREDB_TEST_NETWORK").is_err() {
+            return;
+        }
+        let driver = DuckDbDriver::new();
+        let config = ConnectionConfig {
+            extra_params: Some(params(&[("httpfs", "true")])),
+            ..ConnectionConfig::local_for_tests("duckdb", ":memory:")
+        };
+
+        let session_id = driver.connect(&config).await.unwrap();
+        let result = driver
+            .execute(
+                session_id,
+                "SELECT count(*) FROM read_parquet('https://blobs.duckdb.org/data/taxi_2019_04.parquet')",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[test]
+    fn test_file_view_sql() {
+        assert_eq!(
+            file_view_sql("/data/o'neil.PARQUET", "sales").unwrap(),
+            "CREATE VIEW \"sales\" AS SELECT * FROM read_parquet('/data/o''neil.PARQUET')"
+        );
+        assert!(file_view_sql("/data/events.ndjson", "events")
+            .unwrap()
+            .contains("read_json_auto('/data/events.ndjson')"));
+        assert!(file_view_sql("/data/app.duckdb", "app").is_err());
+        assert!(file_view_sql("/data/no_extension", "data").is_err());
+        assert!(file_view_sql("/data/a.csv", " ").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_register_csv_and_parquet_views() {
+        let driver = DuckDbDriver::new();
+        let config = ConnectionConfig::local_for_tests("duckdb", ":memory:");
+        let session_id = driver.connect(&config).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let csv_path = dir.path().join("people.csv");
+        std::fs::write(&csv_path, "id,name\n1,Ada\n2,Grace\n").unwrap();
+        driver
+            .register_file_view(session_id, &csv_path.to_string_lossy(), "people")
+            .await
+            .unwrap();
+
+        let parquet_path = dir.path().join("scores.parquet");
+        driver
+            .execute(
+                session_id,
+                &format!(
+                    "COPY (SELECT 1 AS id, 42.5 AS score) TO '{}' (FORMAT PARQUET)",
+                    parquet_path.to_string_lossy()
+                ),
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        driver
+            .register_file_view(session_id, &parquet_path.to_string_lossy(), "scores")
+            .await
+            .unwrap();
+
+        let result = driver
+            .execute(
+                session_id,
+                "SELECT p.name, s.score FROM people p JOIN scores s ON s.id = p.id",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+        assert!(matches!(&result.rows[0].values[0], Value::Text(name) if name == "Ada"));
+
+        let list = driver
+            .list_collections(
+                session_id,
+                &Namespace::new("main"),
+                CollectionListOptions::default(),
+            )
+            .await
+            .unwrap();
+        let views: Vec<&str> = list
+            .collections
+            .iter()
+            .filter(|c| matches!(c.collection_type, CollectionType::View))
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(views, vec!["people", "scores"]);
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
     #[test]
     fn test_extract_index_columns() {
         assert_eq!(
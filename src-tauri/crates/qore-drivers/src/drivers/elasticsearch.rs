@@ -55,7 +55,7 @@ impl DataEngine for ElasticsearchDriver {
         search_compat::disconnect(&self.sessions, session).await
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         search_compat::ping(&self.sessions, session).await
     }
 
@@ -14,15 +14,20 @@ use sqlx::Row as SqlxRow;
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::{DataEngine, StreamSender};
 use qore_core::types::{
-    CancelSupport, CollectionList, CollectionListOptions, ConnectionConfig, CreationOptions,
-    DriverCapabilities, EventDefinition, EventList, EventListOptions, EventOperationResult,
-    ForeignKey, MaintenanceOperationInfo, MaintenanceRequest, MaintenanceResult, Namespace,
-    PaginatedQueryResult, QueryId, QueryResult, RoutineDefinition, RoutineList, RoutineListOptions,
-    RoutineOperationResult, RoutineType, RowData, Sequence, SequenceDefinition, SequenceList,
-    SequenceListOptions, SequenceOperationResult, SessionId, TableQueryOptions, TableSchema,
-    TriggerDefinition, TriggerList, TriggerListOptions, TriggerOperationResult, Value,
+    CancelSupport, CollectionList, CollectionListOptions, ColumnStats, ConnectionConfig,
+    CreationOptions, DescribeTableOptions, DriverCapabilities, EventDefinition, EventList,
+    EventListOptions, EventOperationResult, ExplainPlan, ForeignKey, IndexSpec, IsolationLevel,
+    MaintenanceOperationInfo, MaintenanceRequest, MaintenanceResult, Namespace,
+    PaginatedQueryResult, PoolStats, QueryId, QueryResult, RoutineDefinition, RoutineList,
+    RoutineListOptions, RoutineOperationResult, RoutineType, RowData, ScheduledJobList,
+    ScheduledJobOperationResult, Sequence, SequenceDefinition, SequenceList, SequenceListOptions,
+    SequenceOperationResult, ServerInfo, SessionId, SqlSyntaxError, TableQueryOptions, TableSchema,
+    TriggerDefinition, TriggerList, TriggerListOptions, TriggerOperationResult, UpsertResult,
+    Value,
 };
 
+use crate::explain;
+
 use super::mysql::MySqlDriver;
 
 /// MariaDB driver — delegates to MySqlDriver with MariaDB-specific overrides.
@@ -66,10 +71,18 @@ impl DataEngine for MariaDbDriver {
         self.inner.disconnect(session).await
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         self.inner.ping(session).await
     }
 
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        self.inner.pool_stats(session).await
+    }
+
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        self.inner.server_info(session).await
+    }
+
     /// MariaDB-specific namespace filtering.
     /// Unlike MySQL, MariaDB may not have `performance_schema` or `sys` enabled by default.
     /// We filter only the guaranteed system schemas.
@@ -95,6 +108,10 @@ impl DataEngine for MariaDbDriver {
         Ok(namespaces)
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        self.inner.list_databases(session).await
+    }
+
     async fn list_collections(
         &self,
         session: SessionId,
@@ -214,6 +231,26 @@ impl DataEngine for MariaDbDriver {
         self.inner.drop_event(session, namespace, event_name).await
     }
 
+    async fn list_scheduled_jobs(&self, session: SessionId) -> EngineResult<ScheduledJobList> {
+        self.inner.list_scheduled_jobs(session).await
+    }
+
+    async fn set_scheduled_job_enabled(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        database: Option<&str>,
+        enabled: bool,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        self.inner
+            .set_scheduled_job_enabled(session, job_id, database, enabled)
+            .await
+    }
+
+    fn supports_scheduled_jobs(&self) -> bool {
+        true
+    }
+
     fn supports_sequences(&self) -> bool {
         true
     }
@@ -436,6 +473,18 @@ impl DataEngine for MariaDbDriver {
             .await
     }
 
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        self.inner
+            .execute_with_params(session, query, params, query_id)
+            .await
+    }
+
     async fn execute_stream(
         &self,
         session: SessionId,
@@ -470,6 +519,39 @@ impl DataEngine for MariaDbDriver {
         self.inner.describe_table(session, namespace, table).await
     }
 
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
+    ) -> EngineResult<TableSchema> {
+        self.inner
+            .describe_table_with_options(session, namespace, table, options)
+            .await
+    }
+
+    async fn get_table_ddl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        self.inner.get_table_ddl(session, namespace, table).await
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        self.inner
+            .create_index(session, namespace, table, spec)
+            .await
+    }
+
     async fn preview_table(
         &self,
         session: SessionId,
@@ -507,6 +589,18 @@ impl DataEngine for MariaDbDriver {
             .await
     }
 
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        self.inner
+            .column_stats(session, namespace, table, column)
+            .await
+    }
+
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         self.inner.cancel(session, query_id).await
     }
@@ -531,6 +625,10 @@ impl DataEngine for MariaDbDriver {
         true
     }
 
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        self.inner.supported_isolation_levels()
+    }
+
     fn supports_streaming(&self) -> bool {
         true
     }
@@ -539,6 +637,18 @@ impl DataEngine for MariaDbDriver {
         true
     }
 
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        let sql = explain::mariadb_explain_sql(query, analyze);
+        let raw = self.inner.fetch_plan(session, namespace, &sql).await?;
+        Ok(explain::json_plan(raw, analyze, explain::parse_mysql_plan))
+    }
+
     async fn insert_row(
         &self,
         session: SessionId,
@@ -549,6 +659,23 @@ impl DataEngine for MariaDbDriver {
         self.inner.insert_row(session, namespace, table, data).await
     }
 
+    async fn upsert_row(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        self.inner
+            .upsert_row(session, namespace, table, data, conflict_columns)
+            .await
+    }
+
+    fn supports_upsert(&self) -> bool {
+        true
+    }
+
     async fn update_row(
         &self,
         session: SessionId,
@@ -574,6 +701,15 @@ impl DataEngine for MariaDbDriver {
             .await
     }
 
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        self.inner.truncate_table(session, namespace, table).await
+    }
+
     fn supports_mutations(&self) -> bool {
         true
     }
@@ -607,6 +743,19 @@ impl DataEngine for MariaDbDriver {
             .await
     }
 
+    async fn validate_syntax(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        sql: &str,
+    ) -> EngineResult<Option<SqlSyntaxError>> {
+        self.inner.validate_syntax(session, namespace, sql).await
+    }
+
+    fn supports_syntax_validation(&self) -> bool {
+        true
+    }
+
     fn capabilities(&self) -> DriverCapabilities {
         DriverCapabilities {
             transactions: true,
@@ -617,6 +766,23 @@ impl DataEngine for MariaDbDriver {
             streaming: true,
             explain: true,
             maintenance: true,
+            index_usage: false,
+            key_metadata: false,
+            big_keys: false,
+            key_management: false,
+            computed_columns: true,
+            copy_export: false,
+            bloat_report: false,
+            syntax_validation: true,
+            partitions: false,
+            upsert: true,
+            query_progress: false,
+            execute_as_role: false,
+            row_security: false,
+            scheduled_jobs: true,
+            server_log: false,
+            aggregation: false,
+            notifications: false,
         }
     }
 }
@@ -19,7 +19,9 @@ use mongodb::{Client, ClientSession, IndexModel};
 use qore_core::types::RowData as QRowData;
 use tokio::sync::{Mutex, RwLock};
 
-use crate::mongo_pipeline::{assert_no_forbidden_operators, validate_pipeline};
+use crate::mongo_pipeline::{
+    assert_no_forbidden_operators, parse_pipeline, validate_pipeline, ValidatedPipeline,
+};
 
 /// Hard ceiling on the number of documents the non-streaming `execute` path
 /// will accumulate in memory from a single MongoDB query. Without this cap,
@@ -32,29 +34,91 @@ const MAX_NON_STREAMING_ROWS: usize = 1_000_000;
 fn too_many_rows_error() -> EngineError {
     EngineError::result_too_large(MAX_NON_STREAMING_ROWS as u64, MAX_NON_STREAMING_ROWS as u64)
 }
+
+/// Documents sampled by `documents_to_table` to find the result columns.
+const AGGREGATION_COLUMN_SAMPLE: usize = 100;
+
+/// Collections whose estimated size is at most this many documents get an
+/// exact `countDocuments` in `describe_table`, unless a fast estimate is
+/// requested.
+const SMALL_COLLECTION_MAX_DOCS: u64 = 100_000;
+
+/// Type name of a BSON value as shown in column headers.
+fn bson_type_name(value: &Bson) -> &'static str {
+    match value {
+        Bson::Null => "null",
+        Bson::Boolean(_) => "boolean",
+        Bson::Int32(_) => "int32",
+        Bson::Int64(_) => "int64",
+        Bson::Double(_) => "double",
+        Bson::String(_) => "string",
+        Bson::ObjectId(_) => "ObjectId",
+        Bson::DateTime(_) => "datetime",
+        Bson::Array(_) => "array",
+        Bson::Document(_) => "document",
+        Bson::Binary(_) => "binary",
+        Bson::Decimal128(_) => "decimal",
+        _ => "mixed",
+    }
+}
+
+/// Scalars map to their `Value`. ObjectIds keep their extended JSON tag
+/// (`{"$oid": …}`) so they stay distinguishable from strings and convert back
+/// through `value_to_bson`; dates become RFC 3339 text, decimals their exact
+/// digits and binary data bytes. Documents, arrays and other BSON types keep
+/// their extended JSON form.
+fn bson_to_value(value: &Bson) -> Value {
+    match value {
+        Bson::Null | Bson::Undefined => Value::Null,
+        Bson::Boolean(b) => Value::Bool(*b),
+        Bson::Int32(n) => Value::Int(*n as i64),
+        Bson::Int64(n) => Value::Int(*n),
+        Bson::Double(n) => Value::Float(*n),
+        Bson::String(s) => Value::Text(s.clone()),
+        Bson::ObjectId(id) => Value::Json(serde_json::json!({ "$oid": id.to_hex() })),
+        Bson::DateTime(date) => match date.try_to_rfc3339_string() {
+            Ok(text) => Value::Text(text),
+            Err(_) => Value::Json(value.clone().into_relaxed_extjson()),
+        },
+        Bson::Binary(binary) => Value::Bytes(binary.bytes.clone()),
+        Bson::Decimal128(_) => {
+            let extjson = value.clone().into_relaxed_extjson();
+            match extjson.get("$numberDecimal").and_then(|d| d.as_str()) {
+                Some(digits) => Value::Text(digits.to_string()),
+                None => Value::Json(extjson),
+            }
+        }
+        other => Value::Json(other.clone().into_relaxed_extjson()),
+    }
+}
+
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::DataEngine;
 use qore_core::traits::{StreamEvent, StreamSender};
 use qore_core::types::{
     CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnInfo,
-    ConnectionConfig, FilterOperator, MaintenanceMessage, MaintenanceMessageLevel,
-    MaintenanceOperationInfo, MaintenanceOperationType, MaintenanceRequest, MaintenanceResult,
-    Namespace, PaginatedQueryResult, QueryId, QueryResult, Row as QRow, SessionId, SortDirection,
-    TableColumn, TableIndex, TableQueryOptions, TableSchema, Value,
+    ConnectionConfig, DescribeTableOptions, FilterOperator, IndexSpec, JsonPathSegment,
+    MaintenanceMessage, MaintenanceMessageLevel, MaintenanceOperationInfo,
+    MaintenanceOperationType, MaintenanceRequest, MaintenanceResult, Namespace,
+    PaginatedQueryResult, QueryId, QueryResult, Row as QRow, SessionId, SortDirection, TableColumn,
+    TableIndex, TableQueryOptions, TableSchema, Value,
 };
 
 pub struct MongoSession {
     pub client: Client,
     pub transaction_session: Mutex<Option<ClientSession>>,
     pub supports_transactions: bool,
+    /// `ConnectionConfig::read_only`, checked by `run_aggregation`.
+    pub read_only: bool,
 }
 
 impl MongoSession {
-    pub fn new(client: Client, supports_transactions: bool) -> Self {
+    pub fn new(client: Client, supports_transactions: bool, read_only: bool) -> Self {
         Self {
             client,
             transaction_session: Mutex::new(None),
             supports_transactions,
+            read_only,
         }
     }
 }
@@ -151,12 +215,121 @@ impl MongoDriver {
         }
     }
 
+    /// BSON documents of the validated pipeline stages.
+    fn pipeline_documents(pipeline: &ValidatedPipeline) -> EngineResult<Vec<Document>> {
+        let mut stage_docs: Vec<Document> = Vec::with_capacity(pipeline.stages.len());
+        for (idx, stage) in pipeline.stages.iter().enumerate() {
+            let mut stage_obj = serde_json::Map::new();
+            stage_obj.insert(stage.operator.clone(), stage.body.clone());
+            let stage_json = serde_json::Value::Object(stage_obj);
+            let doc = mongodb::bson::to_document(&stage_json).map_err(|e| {
+                EngineError::syntax_error(format!(
+                    "Stage {} ({}) is not a valid BSON document: {}",
+                    idx, stage.operator, e
+                ))
+            })?;
+            stage_docs.push(doc);
+        }
+        Ok(stage_docs)
+    }
+
+    /// Runs an aggregation, inside the open transaction if there is one.
+    async fn aggregate(
+        mongo_session: &MongoSession,
+        database: &str,
+        collection: &str,
+        pipeline: Vec<Document>,
+    ) -> EngineResult<Vec<Document>> {
+        let col = mongo_session
+            .client
+            .database(database)
+            .collection::<Document>(collection);
+
+        let mut tx_guard = mongo_session.transaction_session.lock().await;
+        let documents: Vec<Document> = if let Some(txn) = tx_guard.as_mut() {
+            let mut cursor = col
+                .aggregate(pipeline)
+                .session(&mut *txn)
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+            let mut out: Vec<Document> = Vec::new();
+            while let Some(doc_result) = cursor.next(&mut *txn).await {
+                let doc = doc_result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+                if out.len() >= MAX_NON_STREAMING_ROWS {
+                    return Err(too_many_rows_error());
+                }
+                out.push(doc);
+            }
+            out
+        } else {
+            drop(tx_guard);
+            let mut cursor = col
+                .aggregate(pipeline)
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+            let mut out: Vec<Document> = Vec::new();
+            use futures::TryStreamExt;
+            while let Some(doc) = cursor
+                .try_next()
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?
+            {
+                if out.len() >= MAX_NON_STREAMING_ROWS {
+                    return Err(too_many_rows_error());
+                }
+                out.push(doc);
+            }
+            out
+        };
+        Ok(documents)
+    }
+
+    /// Tabulates documents: one column per top-level key of the first
+    /// `column_sample` documents, `_id` first and the others in order of
+    /// appearance. Keys seen only in later documents are dropped.
+    fn documents_to_table(
+        documents: &[Document],
+        column_sample: usize,
+    ) -> (Vec<ColumnInfo>, Vec<QRow>) {
+        let mut columns: Vec<ColumnInfo> = Vec::new();
+        for doc in documents.iter().take(column_sample) {
+            for (key, value) in doc.iter() {
+                if !columns.iter().any(|c| c.name == *key) {
+                    columns.push(ColumnInfo {
+                        name: key.clone(),
+                        data_type: bson_type_name(value).to_string(),
+                        nullable: true,
+                        case_insensitive: false,
+                    });
+                }
+            }
+        }
+        if let Some(pos) = columns.iter().position(|c| c.name == "_id") {
+            let id = columns.remove(pos);
+            columns.insert(0, id);
+        }
+
+        let rows = documents
+            .iter()
+            .map(|doc| QRow {
+                values: columns
+                    .iter()
+                    .map(|c| doc.get(&c.name).map(bson_to_value).unwrap_or(Value::Null))
+                    .collect(),
+            })
+            .collect();
+        (columns, rows)
+    }
+
     /// Column info for document-centric output.
     fn document_column_info() -> Vec<ColumnInfo> {
         vec![ColumnInfo {
             name: "document".into(),
             data_type: "json".into(),
             nullable: true,
+            case_insensitive: false,
         }]
     }
 
@@ -225,11 +398,195 @@ impl MongoDriver {
                 subtype: mongodb::bson::spec::BinarySubtype::Generic,
                 bytes: b.clone(),
             }),
-            Value::Json(j) => mongodb::bson::to_bson(j).unwrap_or(Bson::Null),
+            // Extended JSON (`{"$oid": …}`, `{"$date": …}`) turns back into
+            // the BSON type it describes.
+            Value::Json(j) => Bson::try_from(j.clone())
+                .or_else(|_| mongodb::bson::to_bson(j))
+                .unwrap_or(Bson::Null),
             Value::Array(arr) => Bson::Array(arr.iter().map(Self::value_to_bson).collect()),
         }
     }
 
+    /// Sort of a collection page. Without a sort column pages follow `_id`,
+    /// so skip/limit neither repeats nor skips documents.
+    fn page_sort(options: &TableQueryOptions) -> Document {
+        match &options.sort_column {
+            Some(sort_col) => {
+                let sort_direction = match options.sort_direction.unwrap_or_default() {
+                    SortDirection::Asc => 1,
+                    SortDirection::Desc => -1,
+                };
+                doc! { sort_col: sort_direction }
+            }
+            None => doc! { "_id": 1 },
+        }
+    }
+
+    /// Dotted field paths of the computed columns, as `(alias, path)`. Filters
+    /// and the sort column naming an alias are rewritten to its path, which
+    /// Mongo queries natively (`data.items.0.sku`).
+    fn resolve_computed_columns(
+        options: &mut TableQueryOptions,
+    ) -> EngineResult<Vec<(String, String)>> {
+        let mut computed = Vec::new();
+        for column in options.computed_columns.as_deref().unwrap_or_default() {
+            let mut path = column.source_column.clone();
+            for segment in column.path_segments()? {
+                path.push('.');
+                match segment {
+                    JsonPathSegment::Key(key) => path.push_str(&key),
+                    JsonPathSegment::Index(index) => path.push_str(&index.to_string()),
+                }
+            }
+            computed.push((column.alias.clone(), path));
+        }
+
+        let resolve = |name: &mut String| {
+            if let Some((_, path)) = computed.iter().find(|(alias, _)| alias == name) {
+                *name = path.clone();
+            }
+        };
+        for filter in options.filters.iter_mut().flatten() {
+            resolve(&mut filter.column);
+        }
+        if let Some(sort_column) = options.sort_column.as_mut() {
+            resolve(sort_column);
+        }
+        Ok(computed)
+    }
+
+    /// Value at a dotted `path`, descending into subdocuments and arrays.
+    fn value_at_path<'a>(document: &'a Document, path: &str) -> Option<&'a Bson> {
+        let mut parts = path.split('.');
+        let mut current = document.get(parts.next()?)?;
+        for part in parts {
+            current = match current {
+                Bson::Document(inner) => inner.get(part)?,
+                Bson::Array(items) => items.get(part.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    /// Query document for the column filters of a collection page. Each
+    /// `FilterOperator` maps to its Mongo counterpart; `like` becomes a
+    /// case-insensitive regex.
+    fn table_filter(options: &TableQueryOptions) -> EngineResult<Document> {
+        let mut filter_doc = Document::new();
+        // `like` conditions are expressed via `$expr`/`$regexMatch` over a
+        // `$toString` of the field so they match any BSON type (numbers,
+        // booleans, dates…), not just string fields. Collected here and merged
+        // into a single `$and` so multiple `like` columns don't collide.
+        let mut like_exprs: Vec<Document> = Vec::new();
+
+        if let Some(filters) = &options.filters {
+            for filter in filters {
+                let bson_value = Self::value_to_bson(&filter.value);
+
+                // `$text` lives at the top level of the query document and applies across all fields
+                // covered by the text index — emit only once, regardless of the source column.
+                if matches!(filter.operator, FilterOperator::Text) {
+                    if filter_doc.contains_key("$text") {
+                        continue;
+                    }
+                    let search = filter.value.as_text().ok_or_else(|| {
+                        EngineError::syntax_error(
+                            "text operator requires a string value in 'value'",
+                        )
+                    })?;
+                    let mut text_doc = doc! { "$search": search.to_string() };
+                    // sanitized_text_language enforces `[a-z_]{1,32}`; empty means server default.
+                    let lang = filter.options.sanitized_text_language("");
+                    if !lang.is_empty() {
+                        text_doc.insert("$language", lang);
+                    }
+                    filter_doc.insert("$text", text_doc);
+                    continue;
+                }
+
+                // The field name is interpolated into aggregation expressions
+                // (`$field`) and used as a BSON key below, so reject anything
+                // outside a safe identifier charset to prevent injection.
+                if !Self::is_valid_field_name(&filter.column) {
+                    return Err(EngineError::syntax_error(format!(
+                        "Invalid field name in filter: {}",
+                        filter.column
+                    )));
+                }
+
+                let condition = match filter.operator {
+                    FilterOperator::Eq => bson_value,
+                    FilterOperator::Neq => {
+                        mongodb::bson::Bson::Document(doc! { "$ne": bson_value })
+                    }
+                    FilterOperator::Gt => mongodb::bson::Bson::Document(doc! { "$gt": bson_value }),
+                    FilterOperator::Gte => {
+                        mongodb::bson::Bson::Document(doc! { "$gte": bson_value })
+                    }
+                    FilterOperator::Lt => mongodb::bson::Bson::Document(doc! { "$lt": bson_value }),
+                    FilterOperator::Lte => {
+                        mongodb::bson::Bson::Document(doc! { "$lte": bson_value })
+                    }
+                    FilterOperator::Like => {
+                        if let mongodb::bson::Bson::String(s) = &bson_value {
+                            // Escape regex metacharacters first so only the LIKE
+                            // wildcards (`%`, `_`) carry pattern meaning — avoids
+                            // ReDoS and over-matching from user-supplied input.
+                            let pattern =
+                                Self::escape_regex(s).replace('%', ".*").replace('_', ".");
+                            like_exprs.push(doc! {
+                                "$regexMatch": {
+                                    "input": {
+                                        "$ifNull": [
+                                            { "$toString": format!("${}", filter.column) },
+                                            "",
+                                        ],
+                                    },
+                                    "regex": pattern,
+                                    "options": "i",
+                                }
+                            });
+                            continue;
+                        }
+                        bson_value
+                    }
+                    FilterOperator::IsNull => {
+                        mongodb::bson::Bson::Document(doc! { "$eq": mongodb::bson::Bson::Null })
+                    }
+                    FilterOperator::IsNotNull => {
+                        mongodb::bson::Bson::Document(doc! { "$ne": mongodb::bson::Bson::Null })
+                    }
+                    FilterOperator::Regex => {
+                        // Flags are restricted to the `imxs` subset MongoDB accepts.
+                        let pattern = filter.value.as_text().ok_or_else(|| {
+                            EngineError::syntax_error(
+                                "regex operator requires a string value in 'value'",
+                            )
+                        })?;
+                        let flags = filter.options.sanitized_regex_flags();
+                        mongodb::bson::Bson::Document(
+                            doc! { "$regex": pattern.to_string(), "$options": flags },
+                        )
+                    }
+                    FilterOperator::Text => unreachable!("handled above"),
+                };
+
+                filter_doc.insert(&filter.column, condition);
+            }
+        }
+
+        if !like_exprs.is_empty() {
+            let and_clauses: Vec<mongodb::bson::Bson> = like_exprs
+                .into_iter()
+                .map(|expr| mongodb::bson::Bson::Document(doc! { "$expr": expr }))
+                .collect();
+            filter_doc.insert("$and", and_clauses);
+        }
+
+        Ok(filter_doc)
+    }
+
     fn row_data_to_document(data: &QRowData) -> Document {
         let mut doc = Document::new();
         for (key, value) in &data.columns {
@@ -327,7 +684,11 @@ impl DataEngine for MongoDriver {
 
         let session_id = SessionId::new();
         let supports_transactions = Self::detect_transaction_support(&client).await;
-        let mongo_session = Arc::new(MongoSession::new(client, supports_transactions));
+        let mongo_session = Arc::new(MongoSession::new(
+            client,
+            supports_transactions,
+            config.read_only,
+        ));
 
         let mut sessions = self.sessions.write().await;
         sessions.insert(session_id, mongo_session);
@@ -356,15 +717,16 @@ impl DataEngine for MongoDriver {
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         let mongo_session = self.get_session(session).await?;
+        let start = Instant::now();
         mongo_session
             .client
             .database("admin")
             .run_command(doc! { "ping": 1 })
             .await
             .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
-        Ok(())
+        Ok(start.elapsed())
     }
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
@@ -505,12 +867,14 @@ impl DataEngine for MongoDriver {
                 namespace: namespace.clone(),
                 name,
                 collection_type: CollectionType::Collection,
+                partition_of: None,
             })
             .collect();
 
         Ok(CollectionList {
             collections,
             total_count: total_count as u32,
+            next_cursor: None,
         })
     }
 
@@ -1108,6 +1472,7 @@ impl DataEngine for MongoDriver {
                                     name: "index_name".into(),
                                     data_type: "string".into(),
                                     nullable: false,
+                                    case_insensitive: false,
                                 }];
                                 let rows = vec![QRow {
                                     values: vec![Value::Text(created.index_name)],
@@ -1394,26 +1759,31 @@ impl DataEngine for MongoDriver {
                                         name: "inserted_count".into(),
                                         data_type: "int".into(),
                                         nullable: false,
+                                        case_insensitive: false,
                                     },
                                     ColumnInfo {
                                         name: "matched_count".into(),
                                         data_type: "int".into(),
                                         nullable: false,
+                                        case_insensitive: false,
                                     },
                                     ColumnInfo {
                                         name: "modified_count".into(),
                                         data_type: "int".into(),
                                         nullable: false,
+                                        case_insensitive: false,
                                     },
                                     ColumnInfo {
                                         name: "deleted_count".into(),
                                         data_type: "int".into(),
                                         nullable: false,
+                                        case_insensitive: false,
                                     },
                                     ColumnInfo {
                                         name: "upserted_count".into(),
                                         data_type: "int".into(),
                                         nullable: false,
+                                        case_insensitive: false,
                                     },
                                 ];
                                 let rows = vec![QRow {
@@ -1596,64 +1966,10 @@ impl DataEngine for MongoDriver {
                                     ))
                                 })?;
 
-                                let mut stage_docs: Vec<Document> =
-                                    Vec::with_capacity(validated.stages.len());
-                                for (idx, stage) in validated.stages.iter().enumerate() {
-                                    let mut stage_obj = serde_json::Map::new();
-                                    stage_obj.insert(stage.operator.clone(), stage.body.clone());
-                                    let stage_json = serde_json::Value::Object(stage_obj);
-                                    let doc = mongodb::bson::to_document(&stage_json).map_err(|e| {
-                                        EngineError::syntax_error(format!(
-                                            "Stage {} ({}) is not a valid BSON document: {}",
-                                            idx, stage.operator, e
-                                        ))
-                                    })?;
-                                    stage_docs.push(doc);
-                                }
-
-                                let col = client
-                                    .database(database)
-                                    .collection::<Document>(coll_name);
-
-                                let mut tx_guard = mongo_session.transaction_session.lock().await;
-                                let documents: Vec<Document> = if let Some(txn) = tx_guard.as_mut() {
-                                    let mut cursor = col
-                                        .aggregate(stage_docs)
-                                        .session(&mut *txn)
-                                        .await
-                                        .map_err(|e| EngineError::execution_error(e.to_string()))?;
-
-                                    let mut out: Vec<Document> = Vec::new();
-                                    while let Some(doc_result) = cursor.next(&mut *txn).await {
-                                        let doc = doc_result
-                                            .map_err(|e| EngineError::execution_error(e.to_string()))?;
-                                        if out.len() >= MAX_NON_STREAMING_ROWS {
-                                            return Err(too_many_rows_error());
-                                        }
-                                        out.push(doc);
-                                    }
-                                    out
-                                } else {
-                                    drop(tx_guard);
-                                    let mut cursor = col
-                                        .aggregate(stage_docs)
-                                        .await
-                                        .map_err(|e| EngineError::execution_error(e.to_string()))?;
-
-                                    let mut out: Vec<Document> = Vec::new();
-                                    use futures::TryStreamExt;
-                                    while let Some(doc) = cursor
-                                        .try_next()
-                                        .await
-                                        .map_err(|e| EngineError::execution_error(e.to_string()))?
-                                    {
-                                        if out.len() >= MAX_NON_STREAMING_ROWS {
-                                            return Err(too_many_rows_error());
-                                        }
-                                        out.push(doc);
-                                    }
-                                    out
-                                };
+                                let stage_docs = Self::pipeline_documents(&validated)?;
+                                let documents =
+                                    Self::aggregate(&mongo_session, database, coll_name, stage_docs)
+                                        .await?;
 
                                 let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
                                 let columns = Self::document_column_info();
@@ -1701,6 +2017,7 @@ impl DataEngine for MongoDriver {
                                         name: "count".into(),
                                         data_type: "int".into(),
                                         nullable: false,
+                                        case_insensitive: false,
                                     }],
                                     rows: vec![QRow {
                                         values: vec![Value::Int(count as i64)],
@@ -1757,6 +2074,7 @@ impl DataEngine for MongoDriver {
                                         name: field.into(),
                                         data_type: "json".into(),
                                         nullable: true,
+                                        case_insensitive: false,
                                     }],
                                     rows,
                                     affected_rows: None,
@@ -1858,6 +2176,22 @@ impl DataEngine for MongoDriver {
         session: SessionId,
         namespace: &Namespace,
         table: &str,
+    ) -> EngineResult<TableSchema> {
+        self.describe_table_with_options(
+            session,
+            namespace,
+            table,
+            &DescribeTableOptions::default(),
+        )
+        .await
+    }
+
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
     ) -> EngineResult<TableSchema> {
         let mongo_session = self.get_session(session).await?;
 
@@ -1916,6 +2250,11 @@ impl DataEngine for MongoDriver {
                     data_type,
                     nullable: true,
                     default_value: None,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 })
                 .collect();
 
@@ -1929,11 +2268,17 @@ impl DataEngine for MongoDriver {
                 }
             });
 
-            let count = collection
-                .count_documents(doc! {})
-                .session(&mut *txn)
-                .await
-                .ok();
+            // estimatedDocumentCount is not allowed inside a transaction, so
+            // the fast estimate reads the collection metadata outside of it.
+            let count = if options.fast_estimate {
+                collection.estimated_document_count().await.ok()
+            } else {
+                collection
+                    .count_documents(doc! {})
+                    .session(&mut *txn)
+                    .await
+                    .ok()
+            };
 
             let mut index_cursor = collection
                 .list_indexes()
@@ -1945,30 +2290,7 @@ impl DataEngine for MongoDriver {
             while let Some(index_result) = index_cursor.next(&mut *txn).await {
                 let index_model =
                     index_result.map_err(|e| EngineError::execution_error(e.to_string()))?;
-                let name = index_model
-                    .options
-                    .as_ref()
-                    .and_then(|o| o.name.clone())
-                    .unwrap_or_else(|| "unknown".to_string());
-                let columns: Vec<String> = index_model
-                    .keys
-                    .iter()
-                    .map(|(k, _)| k.to_string())
-                    .collect();
-                let is_unique = index_model
-                    .options
-                    .as_ref()
-                    .and_then(|o| o.unique)
-                    .unwrap_or(false);
-                let is_primary = name == "_id_";
-                let index_type = infer_mongo_index_type(&index_model.keys);
-                indexes.push(TableIndex {
-                    name,
-                    columns,
-                    is_unique,
-                    is_primary,
-                    index_type,
-                });
+                indexes.push(table_index_from_model(&index_model));
             }
 
             return Ok(TableSchema {
@@ -1976,7 +2298,10 @@ impl DataEngine for MongoDriver {
                 primary_key: Some(vec!["_id".to_string()]),
                 foreign_keys: Vec::new(),
                 row_count_estimate: count,
+                row_count_is_estimate: options.fast_estimate,
                 indexes,
+                partitioning: None,
+                row_security: None,
             });
         }
 
@@ -2029,6 +2354,11 @@ impl DataEngine for MongoDriver {
                 data_type,
                 nullable: true,
                 default_value: None,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
             })
             .collect();
 
@@ -2042,7 +2372,14 @@ impl DataEngine for MongoDriver {
             }
         });
 
-        let count = collection.estimated_document_count().await.ok();
+        let estimate = collection.estimated_document_count().await.ok();
+        let count_exact = !options.fast_estimate
+            && estimate.is_some_and(|count| count <= SMALL_COLLECTION_MAX_DOCS);
+        let count = if count_exact {
+            collection.count_documents(doc! {}).await.ok()
+        } else {
+            estimate
+        };
 
         let mut index_cursor = collection
             .list_indexes()
@@ -2055,30 +2392,7 @@ impl DataEngine for MongoDriver {
             .await
             .map_err(|e| EngineError::execution_error(e.to_string()))?
         {
-            let name = index_model
-                .options
-                .as_ref()
-                .and_then(|o| o.name.clone())
-                .unwrap_or_else(|| "unknown".to_string());
-            let columns: Vec<String> = index_model
-                .keys
-                .iter()
-                .map(|(k, _)| k.to_string())
-                .collect();
-            let is_unique = index_model
-                .options
-                .as_ref()
-                .and_then(|o| o.unique)
-                .unwrap_or(false);
-            let is_primary = name == "_id_";
-            let index_type = infer_mongo_index_type(&index_model.keys);
-            indexes.push(TableIndex {
-                name,
-                columns,
-                is_unique,
-                is_primary,
-                index_type,
-            });
+            indexes.push(table_index_from_model(&index_model));
         }
 
         Ok(TableSchema {
@@ -2086,10 +2400,41 @@ impl DataEngine for MongoDriver {
             primary_key: Some(vec!["_id".to_string()]),
             foreign_keys: Vec::new(),
             row_count_estimate: count,
+            row_count_is_estimate: !count_exact,
             indexes,
+            partitioning: None,
+            row_security: None,
         })
     }
 
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        spec.validate()?;
+        let mongo_session = self.get_session(session).await?;
+        let options = IndexOptions::builder()
+            .name(Some(spec.name.clone()))
+            .unique(Some(spec.unique))
+            .build();
+        let model = IndexModel::builder()
+            .keys(index_keys(spec))
+            .options(options)
+            .build();
+
+        mongo_session
+            .client
+            .database(&namespace.database)
+            .collection::<Document>(table)
+            .create_index(model)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(())
+    }
+
     async fn preview_table(
         &self,
         session: SessionId,
@@ -2168,7 +2513,7 @@ impl DataEngine for MongoDriver {
         session: SessionId,
         namespace: &Namespace,
         table: &str,
-        options: TableQueryOptions,
+        mut options: TableQueryOptions,
     ) -> EngineResult<PaginatedQueryResult> {
         let mongo_session = self.get_session(session).await?;
 
@@ -2191,119 +2536,11 @@ impl DataEngine for MongoDriver {
             table
         );
 
-        let mut filter_doc = Document::new();
-        // `like` conditions are expressed via `$expr`/`$regexMatch` over a
-        // `$toString` of the field so they match any BSON type (numbers,
-        // booleans, dates…), not just string fields. Collected here and merged
-        // into a single `$and` so multiple `like` columns don't collide.
-        let mut like_exprs: Vec<Document> = Vec::new();
-
-        if let Some(filters) = &options.filters {
-            for filter in filters {
-                let bson_value = Self::value_to_bson(&filter.value);
-
-                // `$text` lives at the top level of the query document and applies across all fields
-                // covered by the text index — emit only once, regardless of the source column.
-                if matches!(filter.operator, FilterOperator::Text) {
-                    if filter_doc.contains_key("$text") {
-                        continue;
-                    }
-                    let search = filter.value.as_text().ok_or_else(|| {
-                        EngineError::syntax_error(
-                            "text operator requires a string value in 'value'",
-                        )
-                    })?;
-                    let mut text_doc = doc! { "$search": search.to_string() };
-                    // sanitized_text_language enforces `[a-z_]{1,32}`; empty means server default.
-                    let lang = filter.options.sanitized_text_language("");
-                    if !lang.is_empty() {
-                        text_doc.insert("$language", lang);
-                    }
-                    filter_doc.insert("$text", text_doc);
-                    continue;
-                }
-
-                // The field name is interpolated into aggregation expressions
-                // (`$field`) and used as a BSON key below, so reject anything
-                // outside a safe identifier charset to prevent injection.
-                if !Self::is_valid_field_name(&filter.column) {
-                    return Err(EngineError::syntax_error(format!(
-                        "Invalid field name in filter: {}",
-                        filter.column
-                    )));
-                }
-
-                let condition = match filter.operator {
-                    FilterOperator::Eq => bson_value,
-                    FilterOperator::Neq => {
-                        mongodb::bson::Bson::Document(doc! { "$ne": bson_value })
-                    }
-                    FilterOperator::Gt => mongodb::bson::Bson::Document(doc! { "$gt": bson_value }),
-                    FilterOperator::Gte => {
-                        mongodb::bson::Bson::Document(doc! { "$gte": bson_value })
-                    }
-                    FilterOperator::Lt => mongodb::bson::Bson::Document(doc! { "$lt": bson_value }),
-                    FilterOperator::Lte => {
-                        mongodb::bson::Bson::Document(doc! { "$lte": bson_value })
-                    }
-                    FilterOperator::Like => {
-                        if let mongodb::bson::Bson::String(s) = &bson_value {
-                            // Escape regex metacharacters first so only the LIKE
-                            // wildcards (`%`, `_`) carry pattern meaning — avoids
-                            // ReDoS and over-matching from user-supplied input.
-                            let pattern =
-                                Self::escape_regex(s).replace('%', ".*").replace('_', ".");
-                            like_exprs.push(doc! {
-                                "$regexMatch": {
-                                    "input": {
-                                        "$ifNull": [
-                                            { "$toString": format!("${}", filter.column) },
-                                            "",
-                                        ],
-                                    },
-                                    "regex": pattern,
-                                    "options": "i",
-                                }
-                            });
-                            continue;
-                        }
-                        bson_value
-                    }
-                    FilterOperator::IsNull => {
-                        mongodb::bson::Bson::Document(doc! { "$eq": mongodb::bson::Bson::Null })
-                    }
-                    FilterOperator::IsNotNull => {
-                        mongodb::bson::Bson::Document(doc! { "$ne": mongodb::bson::Bson::Null })
-                    }
-                    FilterOperator::Regex => {
-                        // Flags are restricted to the `imxs` subset MongoDB accepts.
-                        let pattern = filter.value.as_text().ok_or_else(|| {
-                            EngineError::syntax_error(
-                                "regex operator requires a string value in 'value'",
-                            )
-                        })?;
-                        let flags = filter.options.sanitized_regex_flags();
-                        mongodb::bson::Bson::Document(
-                            doc! { "$regex": pattern.to_string(), "$options": flags },
-                        )
-                    }
-                    FilterOperator::Text => unreachable!("handled above"),
-                };
-
-                filter_doc.insert(&filter.column, condition);
-            }
-        }
-
-        if !like_exprs.is_empty() {
-            let and_clauses: Vec<mongodb::bson::Bson> = like_exprs
-                .into_iter()
-                .map(|expr| mongodb::bson::Bson::Document(doc! { "$expr": expr }))
-                .collect();
-            filter_doc.insert("$and", and_clauses);
-        }
+        let computed = Self::resolve_computed_columns(&mut options)?;
+        let mut filter_doc = Self::table_filter(&options)?;
 
         let mut tx_guard = mongo_session.transaction_session.lock().await;
-        let (total_rows, documents) = if let Some(txn) = tx_guard.as_mut() {
+        let (total_rows, mut documents) = if let Some(txn) = tx_guard.as_mut() {
             if let Some(ref search_term) = options.search {
                 if !search_term.trim().is_empty() {
                     let escaped_term = Self::escape_regex(search_term);
@@ -2339,19 +2576,12 @@ impl DataEngine for MongoDriver {
                 .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
             use mongodb::options::FindOptions;
-            let mut find_options = FindOptions::builder()
+            let find_options = FindOptions::builder()
                 .skip(Some(offset))
                 .limit(Some(page_size as i64))
+                .sort(Some(Self::page_sort(&options)))
                 .build();
 
-            if let Some(sort_col) = &options.sort_column {
-                let sort_direction = match options.sort_direction.unwrap_or_default() {
-                    SortDirection::Asc => 1,
-                    SortDirection::Desc => -1,
-                };
-                find_options.sort = Some(doc! { sort_col: sort_direction });
-            }
-
             let mut cursor = collection
                 .find(filter_doc)
                 .with_options(find_options)
@@ -2401,19 +2631,12 @@ impl DataEngine for MongoDriver {
                 .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
             use mongodb::options::FindOptions;
-            let mut find_options = FindOptions::builder()
+            let find_options = FindOptions::builder()
                 .skip(Some(offset))
                 .limit(Some(page_size as i64))
+                .sort(Some(Self::page_sort(&options)))
                 .build();
 
-            if let Some(sort_col) = &options.sort_column {
-                let sort_direction = match options.sort_direction.unwrap_or_default() {
-                    SortDirection::Asc => 1,
-                    SortDirection::Desc => -1,
-                };
-                find_options.sort = Some(doc! { sort_col: sort_direction });
-            }
-
             use futures::TryStreamExt;
             let cursor = collection
                 .find(filter_doc)
@@ -2435,24 +2658,23 @@ impl DataEngine for MongoDriver {
             total_rows
         );
 
+        for document in &mut documents {
+            for (alias, path) in &computed {
+                let value = Self::value_at_path(document, path)
+                    .cloned()
+                    .unwrap_or(Bson::Null);
+                document.insert(alias.clone(), value);
+            }
+        }
+
         let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
 
-        let result = if documents.is_empty() {
-            QueryResult {
-                columns: Vec::new(),
-                rows: Vec::new(),
-                affected_rows: None,
-                execution_time_ms,
-            }
-        } else {
-            let columns = Self::document_column_info();
-            let rows: Vec<QRow> = documents.iter().map(Self::document_to_row).collect();
-            QueryResult {
-                columns,
-                rows,
-                affected_rows: None,
-                execution_time_ms,
-            }
+        let (columns, rows) = Self::documents_to_table(&documents, documents.len());
+        let result = QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms,
         };
 
         Ok(PaginatedQueryResult::new(
@@ -2706,10 +2928,55 @@ impl DataEngine for MongoDriver {
         true
     }
 
+    fn supports_computed_columns(&self) -> bool {
+        true
+    }
+
     fn supports_streaming(&self) -> bool {
         true
     }
 
+    async fn run_aggregation(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        collection: &str,
+        pipeline: &str,
+    ) -> EngineResult<QueryResult> {
+        let mongo_session = self.get_session(session).await?;
+        let validated = parse_pipeline(pipeline).map_err(|e| {
+            EngineError::syntax_error(format!(
+                "Invalid aggregation pipeline: {}",
+                e.user_message()
+            ))
+        })?;
+        if mongo_session.read_only {
+            if let Some(stage) = validated.write_stage() {
+                return Err(EngineError::not_supported(format!(
+                    "Aggregation stage '{}' writes to the database: session is read-only",
+                    stage.operator
+                )));
+            }
+        }
+
+        let start = Instant::now();
+        let stage_docs = Self::pipeline_documents(&validated)?;
+        let documents =
+            Self::aggregate(&mongo_session, &namespace.database, collection, stage_docs).await?;
+        let (columns, rows) = Self::documents_to_table(&documents, AGGREGATION_COLUMN_SAMPLE);
+
+        Ok(QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+        })
+    }
+
+    fn supports_aggregation(&self) -> bool {
+        true
+    }
+
     fn supports_maintenance(&self) -> bool {
         true
     }
@@ -2815,6 +3082,41 @@ impl DataEngine for MongoDriver {
     }
 }
 
+/// Maps a `listIndexes` entry to a `TableIndex`: key fields become the
+/// columns in key order, `unique` comes from the index options.
+fn table_index_from_model(model: &IndexModel) -> TableIndex {
+    let name = model
+        .options
+        .as_ref()
+        .and_then(|o| o.name.clone())
+        .unwrap_or_else(|| "unknown".to_string());
+    let is_unique = model
+        .options
+        .as_ref()
+        .and_then(|o| o.unique)
+        .unwrap_or(false);
+    TableIndex {
+        columns: model.keys.keys().cloned().collect(),
+        is_primary: name == "_id_",
+        index_type: infer_mongo_index_type(&model.keys),
+        is_unique,
+        name,
+    }
+}
+
+/// Key document of a `create_index` spec: 1 for ascending, -1 for descending.
+fn index_keys(spec: &IndexSpec) -> Document {
+    let mut keys = Document::new();
+    for column in &spec.columns {
+        let order = match column.direction {
+            SortDirection::Asc => 1,
+            SortDirection::Desc => -1,
+        };
+        keys.insert(column.name.clone(), order);
+    }
+    keys
+}
+
 /// Infer a MongoDB index type from its key document. Returns the first
 /// string value found (e.g. `"text"`, `"2dsphere"`, `"2d"`, `"hashed"`).
 /// Returns `None` for ordinary ascending/descending compound indexes.
@@ -2826,3 +3128,215 @@ fn infer_mongo_index_type(keys: &Document) -> Option<String> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+    use qore_core::types::{ColumnFilter, ComputedColumn, FilterOptions};
+
+    #[test]
+    fn test_table_index_from_compound_unique_model() {
+        let model = IndexModel::builder()
+            .keys(doc! { "tenant_id": 1, "email": -1 })
+            .options(
+                IndexOptions::builder()
+                    .name(Some("tenant_email".to_string()))
+                    .unique(Some(true))
+                    .build(),
+            )
+            .build();
+        let index = table_index_from_model(&model);
+        assert_eq!(index.name, "tenant_email");
+        assert_eq!(index.columns, ["tenant_id", "email"]);
+        assert!(index.is_unique);
+        assert!(!index.is_primary);
+        assert_eq!(index.index_type, None);
+
+        let id_index = IndexModel::builder()
+            .keys(doc! { "_id": 1 })
+            .options(
+                IndexOptions::builder()
+                    .name(Some("_id_".to_string()))
+                    .build(),
+            )
+            .build();
+        let id_index = table_index_from_model(&id_index);
+        assert!(id_index.is_primary);
+        assert!(!id_index.is_unique);
+    }
+
+    #[test]
+    fn test_index_keys_follow_spec_order() {
+        let spec = IndexSpec {
+            name: "by_date".to_string(),
+            columns: vec![
+                qore_core::types::IndexColumnSpec {
+                    name: "created_at".to_string(),
+                    direction: SortDirection::Desc,
+                },
+                qore_core::types::IndexColumnSpec {
+                    name: "status".to_string(),
+                    direction: SortDirection::Asc,
+                },
+            ],
+            unique: false,
+        };
+        assert_eq!(index_keys(&spec), doc! { "created_at": -1, "status": 1 });
+    }
+
+    #[test]
+    fn test_documents_to_table_unions_top_level_keys() {
+        let id = ObjectId::new();
+        let documents = vec![
+            doc! { "status": "A", "_id": id, "total": 3_i64 },
+            doc! { "_id": "B", "status": "B", "tags": ["x"] },
+        ];
+        let (columns, rows) = MongoDriver::documents_to_table(&documents, documents.len());
+
+        let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["_id", "status", "total", "tags"]);
+        assert_eq!(columns[0].data_type, "ObjectId");
+        assert_eq!(columns[2].data_type, "int64");
+
+        assert!(matches!(&rows[0].values[0], Value::Json(oid) if oid["$oid"] == id.to_hex()));
+        assert!(matches!(rows[0].values[2], Value::Int(3)));
+        assert!(matches!(rows[0].values[3], Value::Null));
+        assert!(matches!(rows[1].values[2], Value::Null));
+        assert!(matches!(&rows[1].values[3], Value::Json(tags) if tags.is_array()));
+    }
+
+    #[test]
+    fn test_bson_to_value_tags_extended_types() {
+        let id = ObjectId::new();
+        let price = Bson::try_from(serde_json::json!({ "$numberDecimal": "19.90" })).unwrap();
+        let document = doc! {
+            "_id": id,
+            "created_at": mongodb::bson::DateTime::from_millis(1_700_000_000_000),
+            "price": price,
+            "payload": mongodb::bson::Binary {
+                subtype: mongodb::bson::spec::BinarySubtype::Generic,
+                bytes: vec![1, 2, 3],
+            },
+        };
+        let (columns, rows) = MongoDriver::documents_to_table(&[document], 1);
+        let values = &rows[0].values;
+
+        assert!(matches!(&values[0], Value::Json(oid) if oid["$oid"] == id.to_hex()));
+        assert!(matches!(&values[1], Value::Text(date) if date == "2023-11-14T22:13:20Z"));
+        assert_eq!(columns[2].data_type, "decimal");
+        assert!(matches!(&values[2], Value::Text(digits) if digits == "19.90"));
+        assert!(matches!(&values[3], Value::Bytes(bytes) if bytes == &[1, 2, 3]));
+
+        assert_eq!(MongoDriver::value_to_bson(&values[0]), Bson::ObjectId(id));
+    }
+
+    fn filter(column: &str, operator: FilterOperator, value: Value) -> ColumnFilter {
+        ColumnFilter {
+            column: column.to_string(),
+            operator,
+            value,
+            options: FilterOptions::default(),
+        }
+    }
+
+    #[test]
+    fn test_page_sort_direction() {
+        let mut options = TableQueryOptions {
+            sort_column: Some("created_at".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(MongoDriver::page_sort(&options), doc! { "created_at": 1 });
+
+        options.sort_direction = Some(SortDirection::Desc);
+        assert_eq!(MongoDriver::page_sort(&options), doc! { "created_at": -1 });
+
+        options.sort_column = None;
+        assert_eq!(MongoDriver::page_sort(&options), doc! { "_id": 1 });
+    }
+
+    #[test]
+    fn test_computed_columns_resolve_to_field_paths() {
+        let mut options = TableQueryOptions {
+            sort_column: Some("sku".to_string()),
+            filters: Some(vec![filter(
+                "sku",
+                FilterOperator::Eq,
+                Value::Text("A-1".to_string()),
+            )]),
+            computed_columns: Some(vec![ComputedColumn {
+                alias: "sku".to_string(),
+                source_column: "data".to_string(),
+                json_path: "$.items[0].sku".to_string(),
+            }]),
+            ..Default::default()
+        };
+        let computed = MongoDriver::resolve_computed_columns(&mut options).unwrap();
+        assert_eq!(
+            computed,
+            [("sku".to_string(), "data.items.0.sku".to_string())]
+        );
+        assert_eq!(options.sort_column.as_deref(), Some("data.items.0.sku"));
+        let filter_doc = MongoDriver::table_filter(&options).unwrap();
+        assert_eq!(filter_doc.get_str("data.items.0.sku").unwrap(), "A-1");
+
+        let document = doc! { "data": { "items": [{ "sku": "A-1" }] } };
+        assert_eq!(
+            MongoDriver::value_at_path(&document, "data.items.0.sku"),
+            Some(&Bson::String("A-1".to_string()))
+        );
+        assert_eq!(
+            MongoDriver::value_at_path(&document, "data.items.1.sku"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_table_filter_maps_operators() {
+        let options = TableQueryOptions {
+            filters: Some(vec![
+                filter("total", FilterOperator::Gt, Value::Int(10)),
+                filter(
+                    "status",
+                    FilterOperator::Eq,
+                    Value::Text("paid".to_string()),
+                ),
+                filter("deleted_at", FilterOperator::IsNull, Value::Null),
+                filter(
+                    "email",
+                    FilterOperator::Like,
+                    Value::Text("%a.b%".to_string()),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let filter_doc = MongoDriver::table_filter(&options).unwrap();
+
+        assert_eq!(
+            filter_doc.get_document("total").unwrap(),
+            &doc! { "$gt": 10_i64 }
+        );
+        assert_eq!(filter_doc.get_str("status").unwrap(), "paid");
+        assert_eq!(
+            filter_doc.get_document("deleted_at").unwrap(),
+            &doc! { "$eq": Bson::Null }
+        );
+
+        let like = filter_doc.get_array("$and").unwrap()[0]
+            .as_document()
+            .and_then(|d| d.get_document("$expr").ok())
+            .and_then(|d| d.get_document("$regexMatch").ok())
+            .unwrap();
+        assert_eq!(like.get_str("regex").unwrap(), ".*a\\.b.*");
+        assert_eq!(like.get_str("options").unwrap(), "i");
+    }
+
+    #[test]
+    fn test_table_filter_rejects_operator_field_names() {
+        let options = TableQueryOptions {
+            filters: Some(vec![filter("$where", FilterOperator::Eq, Value::Int(1))]),
+            ..Default::default()
+        };
+        assert!(MongoDriver::table_filter(&options).is_err());
+    }
+}
@@ -6,48 +6,109 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use sqlx::mysql::{
-    MySql, MySqlConnectOptions, MySqlPool, MySqlPoolOptions, MySqlRow, MySqlSslMode,
+    MySql, MySqlConnectOptions, MySqlDatabaseError, MySqlPool, MySqlPoolOptions, MySqlRow,
+    MySqlSslMode,
 };
 use sqlx::pool::PoolConnection;
 use sqlx::{Column, Executor, Row, TypeInfo};
 use tokio::sync::{Mutex, RwLock};
 use uuid::Uuid;
 
+use crate::column_stats;
+use crate::explain;
+use crate::query_timeout::{query_timeout, with_query_timeout};
+use crate::scheduled_jobs;
+use crate::server_info;
+use crate::server_log;
 use futures::StreamExt;
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::DataEngine;
 use qore_core::traits::{StreamEvent, StreamSender};
 use qore_core::types::{
-    CancelSupport, CharsetInfo, CollationInfo, Collection, CollectionList, CollectionListOptions,
-    CollectionType, ColumnInfo, ConnectionConfig, CreationOptions, DatabaseEvent, EventDefinition,
-    EventList, EventListOptions, EventOperationResult, EventStatus, FilterOperator, ForeignKey,
-    MaintenanceMessage, MaintenanceMessageLevel, MaintenanceOperationInfo,
-    MaintenanceOperationType, MaintenanceRequest, MaintenanceResult, Namespace,
-    PaginatedQueryResult, QueryId, QueryResult, Routine, RoutineDefinition, RoutineList,
-    RoutineListOptions, RoutineOperationResult, RoutineType, Row as QRow, RowData, SessionId,
-    SortDirection, TableColumn, TableIndex, TableQueryOptions, TableSchema, Trigger,
-    TriggerDefinition, TriggerEvent, TriggerList, TriggerListOptions, TriggerOperationResult,
-    TriggerTiming, Value,
+    AllowedValues, CancelSupport, CharsetInfo, CollationInfo, Collection, CollectionList,
+    CollectionListOptions, CollectionType, ColumnInfo, ColumnStats, ConnectionConfig,
+    CreationOptions, DatabaseEvent, DescribeTableOptions, EventDefinition, EventList,
+    EventListOptions, EventOperationResult, EventStatus, ExplainPlan, FilterOperator, ForeignKey,
+    ImplicitOrder, IndexSpec, IsolationLevel, JobScheduler, MaintenanceMessage,
+    MaintenanceMessageLevel, MaintenanceOperationInfo, MaintenanceOperationType,
+    MaintenanceRequest, MaintenanceResult, Namespace, PaginatedQueryResult, PoolStats, QueryId,
+    QueryResult, Routine, RoutineDefinition, RoutineList, RoutineListOptions,
+    RoutineOperationResult, RoutineType, Row as QRow, RowData, ScheduledJob, ScheduledJobList,
+    ScheduledJobOperationResult, ServerInfo, ServerLogChunk, ServerLogLine, ServerLogSource,
+    SessionId, SqlSyntaxError, TableColumn, TableIndex, TableQueryOptions, TableSchema,
+    TlsVerifyMode, Trigger, TriggerDefinition, TriggerEvent, TriggerList, TriggerListOptions,
+    TriggerOperationResult, TriggerTiming, UnusedIndex, UnusedIndexReport, UpsertAction,
+    UpsertColumns, UpsertResult, Value,
 };
+use qore_sql::generator::{generate_create_index, PageColumns, SqlDialect};
 use qore_sql::safety;
 
+/// Unused indexes from the `sys` schema (MySQL 5.7+), joined with InnoDB page
+/// counts for size. Primary keys are already excluded by the view.
+const UNUSED_INDEXES_SQL: &str = "SELECT u.object_name, u.index_name, \
+     CAST(s.stat_value * @@innodb_page_size AS SIGNED) AS index_size \
+     FROM sys.schema_unused_indexes u \
+     LEFT JOIN mysql.innodb_index_stats s \
+     ON s.database_name = u.object_schema AND s.table_name = u.object_name \
+     AND s.index_name = u.index_name AND s.stat_name = 'size' \
+     WHERE u.object_schema = ? \
+     ORDER BY index_size DESC, u.object_name, u.index_name";
+
+/// `ER_DBACCESS_DENIED_ERROR`, `ER_TABLEACCESS_DENIED_ERROR`,
+/// `ER_COLUMNACCESS_DENIED_ERROR`, `ER_SPECIFIC_ACCESS_DENIED_ERROR`. Login
+/// failures (1045) surface at connect time as authentication errors instead.
+const ACCESS_DENIED_ERRORS: [u16; 4] = [1044, 1142, 1143, 1227];
+
+/// Tables whose `TABLE_ROWS` estimate is at most this many rows get an exact
+/// `COUNT(*)` in `describe_table`, unless a fast estimate is requested.
+/// InnoDB's estimate can be off by a wide margin on small tables.
+const SMALL_TABLE_MAX_ROWS: u64 = 100_000;
+
+/// Maps a MySQL statement error to an `EngineError`, using the server error
+/// number for privilege errors.
+fn classify_error(e: sqlx::Error) -> EngineError {
+    let error_number = e
+        .as_database_error()
+        .and_then(|db| db.try_downcast_ref::<MySqlDatabaseError>())
+        .map(|db| db.number());
+    classify_error_number(error_number, e.to_string())
+}
+
+fn classify_error_number(error_number: Option<u16>, msg: String) -> EngineError {
+    match error_number {
+        Some(n) if ACCESS_DENIED_ERRORS.contains(&n) => EngineError::permission_denied(msg),
+        _ if msg.contains("syntax") => EngineError::syntax_error(msg),
+        _ => EngineError::execution_error(msg),
+    }
+}
+
+const UNUSED_INDEXES_NOTE: &str = "Usage comes from performance_schema, which is reset when \
+     the server restarts; \"unused\" is relative to the current uptime.";
+
 pub struct MySqlSession {
     pub pool: MySqlPool,
+    /// Single reserved connection for `KILL QUERY` and health checks, so both
+    /// keep working when every connection of `pool` is busy.
+    pub control_pool: MySqlPool,
     pub transaction_conn: Mutex<Option<PoolConnection<MySql>>>,
     pub active_queries: Mutex<HashMap<QueryId, u64>>,
+    /// `ConnectionConfig::query_timeout_secs`, enforced around each execute.
+    pub query_timeout: Option<Duration>,
 }
 
 impl MySqlSession {
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: MySqlPool, control_pool: MySqlPool, query_timeout: Option<Duration>) -> Self {
         Self {
             pool,
+            control_pool,
             transaction_conn: Mutex::new(None),
             active_queries: Mutex::new(HashMap::new()),
+            query_timeout,
         }
     }
 }
@@ -72,18 +133,22 @@ impl MySqlDriver {
         run_test_query: bool,
     ) -> EngineResult<MySqlPool> {
         let opts = Self::build_connect_options(config);
+        let setup_statements = Arc::new(Self::session_setup_statements(config));
 
         let pool = MySqlPoolOptions::new()
             .max_connections(max_connections)
             .min_connections(min_connections)
             .acquire_timeout(std::time::Duration::from_secs(acquire_timeout_secs))
+            // Replace connections that died while idle instead of failing the query.
+            .test_before_acquire(true)
             // Force NO_BACKSLASH_ESCAPES + utf8mb4 on every new connection.
             // Without this, the server's default sql_mode controls whether
             // `\'` is interpreted as an escape — turning quote-doubling
             // (`replace('\'', "''")`) into an unsafe defence. utf8mb4 also
             // avoids silent corruption of emoji/4-byte chars when the server
             // default is latin1 / utf8mb3.
-            .after_connect(|conn, _meta| {
+            .after_connect(move |conn, _meta| {
+                let setup_statements = Arc::clone(&setup_statements);
                 Box::pin(async move {
                     sqlx::query(
                         "SET SESSION sql_mode = CONCAT_WS(',', @@sql_mode, 'NO_BACKSLASH_ESCAPES')",
@@ -97,6 +162,9 @@ impl MySqlDriver {
                     sqlx::query("SET time_zone = '+00:00'")
                         .execute(&mut *conn)
                         .await?;
+                    for stmt in setup_statements.iter() {
+                        sqlx::query(stmt).execute(&mut *conn).await?;
+                    }
                     Ok(())
                 })
             })
@@ -121,6 +189,41 @@ impl MySqlDriver {
         Ok(pool)
     }
 
+    /// Session timeouts, default isolation level and read-only mode issued
+    /// on every new pooled connection. MariaDB names the statement limit
+    /// `max_statement_time` (seconds) instead of MySQL's `max_execution_time`
+    /// (milliseconds, SELECT only); InnoDB lock waits are whole seconds on
+    /// both.
+    fn session_setup_statements(config: &ConnectionConfig) -> Vec<String> {
+        let mut statements = Vec::new();
+        if let Some(ms) = config.statement_timeout_ms {
+            if config.driver == "mariadb" {
+                statements.push(format!(
+                    "SET SESSION max_statement_time = {}",
+                    ms as f64 / 1000.0
+                ));
+            } else {
+                statements.push(format!("SET SESSION max_execution_time = {ms}"));
+            }
+        }
+        if let Some(ms) = config.lock_timeout_ms {
+            statements.push(format!(
+                "SET SESSION innodb_lock_wait_timeout = {}",
+                ms.div_ceil(1000).max(1)
+            ));
+        }
+        if let Some(level) = config.default_isolation_level {
+            statements.push(format!(
+                "SET SESSION TRANSACTION ISOLATION LEVEL {}",
+                level.as_sql()
+            ));
+        }
+        if config.read_only {
+            statements.push("SET SESSION TRANSACTION READ ONLY".to_string());
+        }
+        statements
+    }
+
     pub(crate) async fn get_session(&self, session: SessionId) -> EngineResult<Arc<MySqlSession>> {
         let sessions = self.sessions.read().await;
         sessions
@@ -141,8 +244,9 @@ impl MySqlDriver {
             Value::Text(s) => query.bind(s),
             Value::Bytes(b) => query.bind(b),
             Value::Json(j) => query.bind(j),
-            // MySQL has no native array binding — drop to NULL rather than emit garbage.
-            Value::Array(_) => query.bind(Option::<String>::None),
+            // MySQL has no native array binding: a list of strings is a SET
+            // value, anything else drops to NULL rather than emit garbage.
+            Value::Array(members) => query.bind(join_set_members(members)),
         }
     }
 
@@ -153,9 +257,11 @@ impl MySqlDriver {
             .map_err(|e| EngineError::execution_error(e.to_string()))
     }
 
-    /// Resolve SSL mode from config: explicit ssl_mode string takes precedence over boolean.
+    /// Resolve SSL mode from config. An explicit `tls.verify_mode` wins over
+    /// the legacy `ssl_mode` string, which wins over the environment default.
     fn resolve_ssl_mode(config: &ConnectionConfig) -> MySqlSslMode {
-        match config.ssl_mode.as_deref() {
+        let explicit_verify = config.tls.as_ref().is_some_and(|t| t.verify_mode.is_some());
+        match config.ssl_mode.as_deref().filter(|_| !explicit_verify) {
             Some("disabled" | "disable") => MySqlSslMode::Disabled,
             Some("preferred" | "prefer") => MySqlSslMode::Preferred,
             Some("required" | "require") => MySqlSslMode::Required,
@@ -163,23 +269,37 @@ impl MySqlDriver {
             Some("verify-full" | "verify-identity" | "verify_identity") => {
                 MySqlSslMode::VerifyIdentity
             }
-            _ => {
-                if config.ssl {
-                    MySqlSslMode::Required
-                } else {
-                    MySqlSslMode::Disabled
-                }
-            }
+            _ if !config.ssl => MySqlSslMode::Disabled,
+            _ => match config.tls_verify_mode() {
+                TlsVerifyMode::Full => MySqlSslMode::VerifyIdentity,
+                TlsVerifyMode::CaOnly => MySqlSslMode::VerifyCa,
+                TlsVerifyMode::None => MySqlSslMode::Required,
+            },
         }
     }
 
     fn build_connect_options(config: &ConnectionConfig) -> MySqlConnectOptions {
+        let ssl_mode = Self::resolve_ssl_mode(config);
         let mut opts = MySqlConnectOptions::new()
             .host(&config.host)
             .port(config.port)
             .username(&config.username)
             .password(&config.password)
-            .ssl_mode(Self::resolve_ssl_mode(config));
+            .ssl_mode(ssl_mode);
+
+        if !matches!(ssl_mode, MySqlSslMode::Disabled) {
+            if let Some(ca) = config.tls_ca_cert_path() {
+                opts = opts.ssl_ca(ca);
+            }
+            if let Some(tls) = &config.tls {
+                if let Some(cert) = tls.client_cert_path.as_deref() {
+                    opts = opts.ssl_client_cert(cert);
+                }
+                if let Some(key) = tls.client_key_path.as_deref() {
+                    opts = opts.ssl_client_key(key);
+                }
+            }
+        }
 
         if let Some(db) = config.database.as_deref() {
             let db = db.trim();
@@ -192,7 +312,391 @@ impl MySqlDriver {
     }
 
     fn quote_ident(name: &str) -> String {
-        format!("`{}`", name.replace('`', "``"))
+        safety::quote_ident(SqlDialect::MySql, name)
+    }
+
+    /// `execute_in_namespace` without the query timeout.
+    async fn run_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let mysql_session = self.get_session(session).await?;
+        let start = Instant::now();
+
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+
+        let mut tx_guard = mysql_session.transaction_conn.lock().await;
+        let result = if let Some(ref mut conn) = *tx_guard {
+            let connection_id = Self::fetch_connection_id(conn).await?;
+            {
+                let mut active = mysql_session.active_queries.lock().await;
+                active.insert(query_id, connection_id);
+            }
+
+            Self::apply_namespace_on_conn(conn, &namespace, query).await?;
+
+            let result = if returns_rows {
+                let mysql_rows: Vec<MySqlRow> = sqlx::query(query)
+                    .fetch_all(&mut **conn)
+                    .await
+                    .map_err(classify_error)?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                let (columns, rows) = Self::columns_and_rows(&mysql_rows);
+                Ok(QueryResult {
+                    columns,
+                    rows,
+                    affected_rows: None,
+                    execution_time_ms,
+                })
+            } else {
+                // Simple query protocol — some MySQL/MariaDB versions reject DDL over prepared statements.
+                let result = conn
+                    .execute(sqlx::raw_sql(query))
+                    .await
+                    .map_err(classify_error)?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                Ok(QueryResult::with_affected_rows(
+                    result.rows_affected(),
+                    execution_time_ms,
+                ))
+            };
+
+            let mut active = mysql_session.active_queries.lock().await;
+            active.remove(&query_id);
+            result
+        } else {
+            let mut conn = mysql_session
+                .pool
+                .acquire()
+                .await
+                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+            let connection_id = Self::fetch_connection_id(&mut conn).await?;
+            {
+                let mut active = mysql_session.active_queries.lock().await;
+                active.insert(query_id, connection_id);
+            }
+
+            Self::apply_namespace_on_conn(&mut conn, &namespace, query).await?;
+
+            let result = if returns_rows {
+                let mysql_rows: Vec<MySqlRow> = sqlx::query(query)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .map_err(classify_error)?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                let (columns, rows) = Self::columns_and_rows(&mysql_rows);
+                Ok(QueryResult {
+                    columns,
+                    rows,
+                    affected_rows: None,
+                    execution_time_ms,
+                })
+            } else {
+                // Simple query protocol — some MySQL/MariaDB versions reject DDL over prepared statements.
+                let result = conn
+                    .execute(sqlx::raw_sql(query))
+                    .await
+                    .map_err(classify_error)?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                Ok(QueryResult::with_affected_rows(
+                    result.rows_affected(),
+                    execution_time_ms,
+                ))
+            };
+
+            let mut active = mysql_session.active_queries.lock().await;
+            active.remove(&query_id);
+            result
+        };
+
+        result
+    }
+
+    /// `execute_with_params` without the query timeout.
+    async fn run_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let mysql_session = self.get_session(session).await?;
+        let start = Instant::now();
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+
+        let mut tx_guard = mysql_session.transaction_conn.lock().await;
+        let result = if let Some(ref mut conn) = *tx_guard {
+            let connection_id = Self::fetch_connection_id(conn).await?;
+            mysql_session
+                .active_queries
+                .lock()
+                .await
+                .insert(query_id, connection_id);
+            Self::exec_bound(conn, query, params, returns_rows, start).await
+        } else {
+            drop(tx_guard);
+            let mut conn = mysql_session
+                .pool
+                .acquire()
+                .await
+                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+            let connection_id = Self::fetch_connection_id(&mut conn).await?;
+            mysql_session
+                .active_queries
+                .lock()
+                .await
+                .insert(query_id, connection_id);
+            Self::exec_bound(&mut conn, query, params, returns_rows, start).await
+        };
+
+        mysql_session.active_queries.lock().await.remove(&query_id);
+        result
+    }
+
+    /// Runs `query` as a prepared statement with `params` bound to its `?`
+    /// placeholders.
+    async fn exec_bound(
+        conn: &mut PoolConnection<MySql>,
+        query: &str,
+        params: &[Value],
+        returns_rows: bool,
+        start: Instant,
+    ) -> EngineResult<QueryResult> {
+        let bound = params
+            .iter()
+            .fold(sqlx::query(query), |q, value| Self::bind_param(q, value));
+
+        if !returns_rows {
+            let result = bound.execute(&mut **conn).await.map_err(classify_error)?;
+            return Ok(QueryResult::with_affected_rows(
+                result.rows_affected(),
+                start.elapsed().as_micros() as f64 / 1000.0,
+            ));
+        }
+
+        let mysql_rows: Vec<MySqlRow> =
+            bound.fetch_all(&mut **conn).await.map_err(classify_error)?;
+        let (columns, rows) = Self::columns_and_rows(&mysql_rows);
+        Ok(QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+        })
+    }
+
+    /// `execute_stream_in_namespace` without the query timeout.
+    async fn stream_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        let mysql_session = self.get_session(session).await?;
+
+        let mut conn = mysql_session
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+
+        Self::apply_namespace_on_conn(&mut conn, &namespace, query).await?;
+
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+
+        if !returns_rows {
+            let result = self
+                .run_in_namespace(session, namespace, query, query_id)
+                .await?;
+            let _ = sender
+                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
+                .await;
+            return Ok(());
+        }
+
+        let connection_id = Self::fetch_connection_id(&mut conn).await?;
+        {
+            let mut active = mysql_session.active_queries.lock().await;
+            active.insert(query_id, connection_id);
+        }
+
+        let mut stream = sqlx::query(query).fetch(&mut *conn);
+        let mut decoders: Vec<MysqlDecoder> = Vec::new();
+        let mut columns_sent = false;
+        let mut row_count = 0;
+        let mut stream_error: Option<String> = None;
+        let mut batch = Vec::with_capacity(500);
+
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(mysql_row) => {
+                    if !columns_sent {
+                        let columns = Self::get_column_info(&mysql_row);
+                        decoders = Self::build_decoders(&mysql_row);
+                        if sender.send(StreamEvent::Columns(columns)).await.is_err() {
+                            break;
+                        }
+                        columns_sent = true;
+                    }
+
+                    let row = Self::convert_row_with_decoders(&mysql_row, &decoders);
+                    batch.push(row);
+                    row_count += 1;
+
+                    if batch.len() >= 500 {
+                        if sender
+                            .send(StreamEvent::RowBatch(std::mem::replace(
+                                &mut batch,
+                                Vec::with_capacity(500),
+                            )))
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                }
+                Err(e) => {
+                    let error_msg = e.to_string();
+                    let _ = sender.send(StreamEvent::Error(error_msg.clone())).await;
+                    stream_error = Some(error_msg);
+                    break;
+                }
+            }
+        }
+
+        if !batch.is_empty() {
+            let _ = sender.send(StreamEvent::RowBatch(batch)).await;
+        }
+
+        {
+            let mut active = mysql_session.active_queries.lock().await;
+            active.remove(&query_id);
+        }
+
+        if stream_error.is_none() {
+            let _ = sender.send(StreamEvent::Done(row_count)).await;
+        }
+
+        if let Some(err) = stream_error {
+            return Err(EngineError::execution_error(err));
+        }
+
+        Ok(())
+    }
+
+    /// Order of a page without sort column: the primary key. MySQL has no
+    /// row identifier; InnoDB scans a table without one in the order of its
+    /// hidden clustered key, which is stable between pages.
+    async fn table_implicit_order(
+        session: &MySqlSession,
+        database: &str,
+        table: &str,
+    ) -> ImplicitOrder {
+        let query = sqlx::query_scalar::<_, String>(
+            "SELECT CAST(COLUMN_NAME AS CHAR) FROM information_schema.KEY_COLUMN_USAGE \
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND CONSTRAINT_NAME = 'PRIMARY' \
+             ORDER BY ORDINAL_POSITION",
+        )
+        .bind(database)
+        .bind(table);
+        let primary_key = {
+            let mut tx_guard = session.transaction_conn.lock().await;
+            if let Some(ref mut conn) = *tx_guard {
+                query.fetch_all(&mut **conn).await
+            } else {
+                query.fetch_all(&session.pool).await
+            }
+        };
+
+        match primary_key {
+            Ok(primary_key) => ImplicitOrder::new(primary_key, None),
+            Err(e) => {
+                tracing::debug!("No implicit order for {}.{}: {}", database, table, e);
+                ImplicitOrder::Unordered
+            }
+        }
+    }
+
+    /// Database a foreign key points into, from
+    /// `KEY_COLUMN_USAGE.REFERENCED_TABLE_SCHEMA`. Only kept when it differs
+    /// from the table's own database, so same-database keys stay unqualified.
+    fn referenced_database(current: &str, referenced: String) -> Option<String> {
+        (referenced != current).then_some(referenced)
+    }
+
+    /// Lookup for `peek_foreign_key`, always qualified with the database so a
+    /// key into another database reads the right table.
+    fn peek_foreign_key_sql(namespace: &Namespace, foreign_key: &ForeignKey, limit: u32) -> String {
+        let database = foreign_key
+            .referenced_database
+            .as_deref()
+            .unwrap_or(namespace.database.as_str());
+        format!(
+            "SELECT * FROM {}.{} WHERE {} = ? LIMIT {}",
+            Self::quote_ident(database),
+            Self::quote_ident(&foreign_key.referenced_table),
+            Self::quote_ident(&foreign_key.referenced_column),
+            limit.clamp(1, 50)
+        )
+    }
+
+    /// `INSERT ... ON DUPLICATE KEY UPDATE ...`. MySQL matches on any unique
+    /// key of the table, so the conflict columns only decide what is left out
+    /// of the update list.
+    fn upsert_sql(table_name: &str, columns: &UpsertColumns) -> String {
+        let cols_str = columns
+            .insert
+            .iter()
+            .map(|c| Self::quote_ident(c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let set_clauses = columns
+            .update
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} = VALUES({})",
+                    Self::quote_ident(c),
+                    Self::quote_ident(c)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "INSERT INTO {} ({}) VALUES ({}) ON DUPLICATE KEY UPDATE {}",
+            table_name,
+            cols_str,
+            vec!["?"; columns.insert.len()].join(", "),
+            set_clauses
+        )
+    }
+
+    /// Maps the affected-row count of `ON DUPLICATE KEY UPDATE` to the branch
+    /// taken: 2 means an existing row changed. The connection uses
+    /// CLIENT_FOUND_ROWS, so 1 is either a fresh insert or an update that left
+    /// the row as it was, and is not reported.
+    fn upsert_action(rows_affected: u64) -> Option<UpsertAction> {
+        match rows_affected {
+            2 => Some(UpsertAction::Updated),
+            _ => None,
+        }
     }
 
     async fn apply_namespace_on_conn(
@@ -217,6 +721,36 @@ impl MySqlDriver {
         Ok(())
     }
 
+    /// Runs an EXPLAIN statement and returns its single plan cell, on the
+    /// transaction connection when one is open. Goes around `execute`, which
+    /// does not know MariaDB's `ANALYZE` returns rows.
+    pub(crate) async fn fetch_plan(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        sql: &str,
+    ) -> EngineResult<String> {
+        let mysql_session = self.get_session(session).await?;
+        let mut tx_guard = mysql_session.transaction_conn.lock().await;
+        let row = if let Some(ref mut conn) = *tx_guard {
+            Self::apply_namespace_on_conn(conn, &namespace, sql).await?;
+            sqlx::raw_sql(sql).fetch_one(&mut **conn).await
+        } else {
+            drop(tx_guard);
+            let mut conn = mysql_session
+                .pool
+                .acquire()
+                .await
+                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+            Self::apply_namespace_on_conn(&mut conn, &namespace, sql).await?;
+            sqlx::raw_sql(sql).fetch_one(&mut *conn).await
+        }
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        row.try_get::<String, _>(0)
+            .map_err(|e| EngineError::execution_error(e.to_string()))
+    }
+
     /// Hot-path row conversion: uses a precomputed per-column decoder to
     /// avoid the 14-branch trial-and-error cascade per cell.
     fn convert_row_with_decoders(mysql_row: &MySqlRow, decoders: &[MysqlDecoder]) -> QRow {
@@ -258,14 +792,77 @@ impl MySqlDriver {
                 name: col.name().into(),
                 data_type: col.type_info().name().into(),
                 nullable: true,
+                case_insensitive: false,
             })
             .collect()
     }
 }
 
+/// Allowed values of an `enum(...)` / `set(...)` column type as printed in
+/// `information_schema.COLUMNS.COLUMN_TYPE`, e.g. `set('a','b''c')`.
+fn parse_allowed_values(column_type: &str) -> Option<AllowedValues> {
+    let lower = column_type.to_ascii_lowercase();
+    let (multiple, body) = if lower.starts_with("enum(") {
+        (false, &column_type[5..])
+    } else if lower.starts_with("set(") {
+        (true, &column_type[4..])
+    } else {
+        return None;
+    };
+    let body = body.strip_suffix(')')?;
+
+    let mut values = Vec::new();
+    let mut chars = body.chars().peekable();
+    loop {
+        if chars.next()? != '\'' {
+            return None;
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    value.push('\'');
+                }
+                '\'' => break,
+                '\\' => value.push(chars.next()?),
+                c => value.push(c),
+            }
+        }
+        values.push(value);
+        match chars.next() {
+            Some(',') => continue,
+            None => break,
+            Some(_) => return None,
+        }
+    }
+    Some(AllowedValues { values, multiple })
+}
+
+/// A `SET` value sent as a list of members, bound as the comma-joined string
+/// MySQL expects. `None` when a member is not text.
+fn join_set_members(members: &[Value]) -> Option<String> {
+    members
+        .iter()
+        .map(|m| m.as_text())
+        .collect::<Option<Vec<_>>>()
+        .map(|members| members.join(","))
+}
+
+/// `BIT(n)` payload (big-endian, up to 8 bytes) as an integer, or as a bit
+/// string when it does not fit an `i64`.
+fn bit_value(bytes: &[u8]) -> Value {
+    let bits = bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+    match i64::try_from(bits) {
+        Ok(v) if bytes.len() <= 8 => Value::Int(v),
+        _ => Value::Text(bytes.iter().map(|b| format!("{b:08b}")).collect()),
+    }
+}
+
 #[derive(Clone, Copy)]
 enum MysqlDecoder {
     Bool,
+    Bit,
     TinyI,
     TinyU,
     SmallI,
@@ -295,6 +892,7 @@ impl MysqlDecoder {
         // ones fall through to the Fallback cascade (preserves legacy behavior).
         match name {
             "BOOLEAN" => Self::Bool,
+            "BIT" => Self::Bit,
             "TINYINT" => Self::TinyI,
             "TINYINT UNSIGNED" => Self::TinyU,
             "SMALLINT" => Self::SmallI,
@@ -326,6 +924,13 @@ impl MysqlDecoder {
                 Ok(None) => Value::Null,
                 Err(_) => Self::fallback_extract(row, idx),
             },
+            // BIT is raw bytes; the unchecked read skips sqlx's UNSIGNED-flag
+            // check on integer types.
+            Self::Bit => match row.try_get_unchecked::<Option<Vec<u8>>, _>(idx) {
+                Ok(Some(v)) => bit_value(&v),
+                Ok(None) => Value::Null,
+                Err(_) => Self::fallback_extract(row, idx),
+            },
             Self::TinyI => match row.try_get::<Option<i8>, _>(idx) {
                 Ok(Some(v)) => Value::Int(v as i64),
                 Ok(None) => Value::Null,
@@ -553,9 +1158,21 @@ impl DataEngine for MySqlDriver {
             false,
         )
         .await?;
+        let control_pool =
+            match Self::create_pool(config, 1, 1, acquire_timeout as u64, false, false).await {
+                Ok(control_pool) => control_pool,
+                Err(e) => {
+                    pool.close().await;
+                    return Err(e);
+                }
+            };
 
         let session_id = SessionId::new();
-        let session = Arc::new(MySqlSession::new(pool));
+        let session = Arc::new(MySqlSession::new(
+            pool,
+            control_pool,
+            query_timeout(config.query_timeout_secs),
+        ));
 
         let mut sessions = self.sessions.write().await;
         sessions.insert(session_id, session);
@@ -577,16 +1194,42 @@ impl DataEngine for MySqlDriver {
         }
 
         session.pool.close().await;
+        session.control_pool.close().await;
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<Duration> {
         let session = self.get_session(session).await?;
+        let start = Instant::now();
         sqlx::query("SELECT 1")
-            .execute(&session.pool)
+            .execute(&session.control_pool)
             .await
             .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
-        Ok(())
+        Ok(start.elapsed())
+    }
+
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        let mysql_session = self.get_session(session).await?;
+        let pool = &mysql_session.pool;
+        let size = pool.size();
+        let idle = pool.num_idle() as u32;
+        Ok(Some(PoolStats {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle),
+            max: pool.options().get_max_connections(),
+        }))
+    }
+
+    /// Percona is only recognizable from `@@version_comment`.
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        let mysql_session = self.get_session(session).await?;
+        let (version, comment): (String, String) =
+            sqlx::query_as("SELECT CAST(VERSION() AS CHAR), CAST(@@version_comment AS CHAR)")
+                .fetch_one(&mysql_session.pool)
+                .await
+                .map_err(classify_error)?;
+        Ok(server_info::mysql_server_info(&version, &comment))
     }
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
@@ -621,6 +1264,15 @@ impl DataEngine for MySqlDriver {
         Ok(namespaces)
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        let mysql_session = self.get_session(session).await?;
+        let rows: Vec<(String,)> = sqlx::query_as("SHOW DATABASES")
+            .fetch_all(&mysql_session.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(rows.into_iter().map(|(db,)| db).collect())
+    }
+
     async fn list_collections(
         &self,
         session: SessionId,
@@ -686,6 +1338,7 @@ impl DataEngine for MySqlDriver {
                     namespace: namespace.clone(),
                     name,
                     collection_type,
+                    partition_of: None,
                 }
             })
             .collect();
@@ -693,6 +1346,7 @@ impl DataEngine for MySqlDriver {
         Ok(CollectionList {
             collections,
             total_count: total_count as u32,
+            next_cursor: None,
         })
     }
 
@@ -1163,60 +1817,171 @@ impl DataEngine for MySqlDriver {
         "#
         .to_string();
 
-        if let Some(limit) = options.page_size {
-            query_str.push_str(&format!(" LIMIT {}", limit));
-            if let Some(page) = options.page {
-                let offset = (page.max(1) - 1) * limit;
-                query_str.push_str(&format!(" OFFSET {}", offset));
-            }
-        }
+        if let Some(limit) = options.page_size {
+            query_str.push_str(&format!(" LIMIT {}", limit));
+            if let Some(page) = options.page {
+                let offset = (page.max(1) - 1) * limit;
+                query_str.push_str(&format!(" OFFSET {}", offset));
+            }
+        }
+
+        let rows: Vec<(String, String, String, String, String)> = sqlx::query_as(&query_str)
+            .bind(&namespace.database)
+            .bind(&search_pattern)
+            .bind(&search_pattern)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let events = rows
+            .into_iter()
+            .map(
+                |(name, event_type, interval_value, interval_field, status)| {
+                    let event_status = match status.as_str() {
+                        "ENABLED" => EventStatus::Enabled,
+                        "DISABLED" => EventStatus::Disabled,
+                        "SLAVESIDE_DISABLED" => EventStatus::SlavesideDisabled,
+                        _ => EventStatus::Disabled,
+                    };
+
+                    DatabaseEvent {
+                        namespace: namespace.clone(),
+                        name,
+                        event_type,
+                        interval_value: if interval_value.is_empty() {
+                            None
+                        } else {
+                            Some(interval_value)
+                        },
+                        interval_field: if interval_field.is_empty() {
+                            None
+                        } else {
+                            Some(interval_field)
+                        },
+                        status: event_status,
+                    }
+                },
+            )
+            .collect();
+
+        Ok(EventList {
+            events,
+            total_count: total_count as u32,
+        })
+    }
+
+    fn supports_events(&self) -> bool {
+        true
+    }
+
+    async fn list_scheduled_jobs(&self, session: SessionId) -> EngineResult<ScheduledJobList> {
+        let mysql_session = self.get_session(session).await?;
+        let pool = &mysql_session.pool;
+
+        type EventRow = (
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            String,
+        );
+        let rows: Vec<EventRow> = sqlx::query_as(scheduled_jobs::MYSQL_EVENTS_SQL)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let jobs = rows
+            .into_iter()
+            .map(
+                |(name, schema, schedule, command, last_run, next_run, status)| ScheduledJob {
+                    id: name.clone(),
+                    name,
+                    database: Some(schema),
+                    schedule,
+                    command,
+                    last_run,
+                    next_run,
+                    enabled: status == "ENABLED",
+                },
+            )
+            .collect();
+
+        Ok(ScheduledJobList {
+            scheduler: Some(JobScheduler::MysqlEvents),
+            jobs,
+        })
+    }
+
+    async fn set_scheduled_job_enabled(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        database: Option<&str>,
+        enabled: bool,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        let mysql_session = self.get_session(session).await?;
+        let pool = &mysql_session.pool;
+
+        let sql = scheduled_jobs::mysql_event_toggle_sql(database, job_id, enabled)?;
+
+        let start = Instant::now();
+        sqlx::query(&sql)
+            .execute(pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let elapsed = start.elapsed().as_millis() as f64;
+
+        Ok(ScheduledJobOperationResult {
+            success: true,
+            executed_command: sql,
+            message: None,
+            execution_time_ms: elapsed,
+        })
+    }
+
+    fn supports_scheduled_jobs(&self) -> bool {
+        true
+    }
+
+    async fn read_server_log(
+        &self,
+        session: SessionId,
+        lines: u32,
+        after: Option<&str>,
+    ) -> EngineResult<ServerLogChunk> {
+        let mysql_session = self.get_session(session).await?;
+        let pool = &mysql_session.pool;
 
-        let rows: Vec<(String, String, String, String, String)> = sqlx::query_as(&query_str)
-            .bind(&namespace.database)
-            .bind(&search_pattern)
-            .bind(&search_pattern)
+        let limit = server_log::log_read_limit(lines, after);
+        let query = match after {
+            Some(cursor) => sqlx::query_as(server_log::MYSQL_ERROR_LOG_AFTER_SQL)
+                .bind(cursor)
+                .bind(limit),
+            None => sqlx::query_as(server_log::MYSQL_ERROR_LOG_TAIL_SQL).bind(limit),
+        };
+        let rows: Vec<(Option<String>, Option<String>, Option<String>)> = query
             .fetch_all(pool)
             .await
-            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            .map_err(server_log::from_mysql_error)?;
 
-        let events = rows
+        let log_lines = rows
             .into_iter()
-            .map(
-                |(name, event_type, interval_value, interval_field, status)| {
-                    let event_status = match status.as_str() {
-                        "ENABLED" => EventStatus::Enabled,
-                        "DISABLED" => EventStatus::Disabled,
-                        "SLAVESIDE_DISABLED" => EventStatus::SlavesideDisabled,
-                        _ => EventStatus::Disabled,
-                    };
-
-                    DatabaseEvent {
-                        namespace: namespace.clone(),
-                        name,
-                        event_type,
-                        interval_value: if interval_value.is_empty() {
-                            None
-                        } else {
-                            Some(interval_value)
-                        },
-                        interval_field: if interval_field.is_empty() {
-                            None
-                        } else {
-                            Some(interval_field)
-                        },
-                        status: event_status,
-                    }
-                },
-            )
+            .map(|(logged_at, level, message)| ServerLogLine {
+                logged_at,
+                level,
+                message: message.unwrap_or_default(),
+            })
             .collect();
-
-        Ok(EventList {
-            events,
-            total_count: total_count as u32,
-        })
+        Ok(server_log::timestamped_chunk(
+            ServerLogSource::MysqlErrorLog,
+            log_lines,
+            after,
+        ))
     }
 
-    fn supports_events(&self) -> bool {
+    fn supports_server_log(&self) -> bool {
         true
     }
 
@@ -1427,7 +2192,7 @@ impl DataEngine for MySqlDriver {
             tracing::error!("MySQL: Failed to create database: {}", e);
             let msg = e.to_string();
             if msg.contains("Access denied") {
-                EngineError::auth_failed(format!("Permission denied: {}", msg))
+                EngineError::permission_denied(msg)
             } else if msg.contains("exists") {
                 EngineError::validation(format!("Database '{}' already exists", name))
             } else {
@@ -1456,7 +2221,7 @@ impl DataEngine for MySqlDriver {
             tracing::error!("MySQL: Failed to drop database: {}", e);
             let msg = e.to_string();
             if msg.contains("Access denied") {
-                EngineError::auth_failed(format!("Permission denied: {}", msg))
+                EngineError::permission_denied(msg)
             } else if msg.contains("doesn't exist") || msg.contains("Unknown database") {
                 EngineError::validation(format!("Database '{}' does not exist", name))
             } else {
@@ -1483,243 +2248,60 @@ impl DataEngine for MySqlDriver {
     async fn execute_stream_in_namespace(
         &self,
         session: SessionId,
-        namespace: Option<Namespace>,
-        query: &str,
-        query_id: QueryId,
-        sender: StreamSender,
-    ) -> EngineResult<()> {
-        let mysql_session = self.get_session(session).await?;
-
-        let mut conn = mysql_session
-            .pool
-            .acquire()
-            .await
-            .map_err(|e| EngineError::connection_failed(e.to_string()))?;
-
-        Self::apply_namespace_on_conn(&mut conn, &namespace, query).await?;
-
-        let returns_rows = safety::returns_rows(self.driver_id(), query)
-            .unwrap_or_else(|_| safety::is_select_prefix(query));
-
-        if !returns_rows {
-            let result = self
-                .execute_in_namespace(session, namespace, query, query_id)
-                .await?;
-            let _ = sender
-                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
-                .await;
-            return Ok(());
-        }
-
-        let connection_id = Self::fetch_connection_id(&mut conn).await?;
-        {
-            let mut active = mysql_session.active_queries.lock().await;
-            active.insert(query_id, connection_id);
-        }
-
-        let mut stream = sqlx::query(query).fetch(&mut *conn);
-        let mut decoders: Vec<MysqlDecoder> = Vec::new();
-        let mut columns_sent = false;
-        let mut row_count = 0;
-        let mut stream_error: Option<String> = None;
-        let mut batch = Vec::with_capacity(500);
-
-        while let Some(item) = stream.next().await {
-            match item {
-                Ok(mysql_row) => {
-                    if !columns_sent {
-                        let columns = Self::get_column_info(&mysql_row);
-                        decoders = Self::build_decoders(&mysql_row);
-                        if sender.send(StreamEvent::Columns(columns)).await.is_err() {
-                            break;
-                        }
-                        columns_sent = true;
-                    }
-
-                    let row = Self::convert_row_with_decoders(&mysql_row, &decoders);
-                    batch.push(row);
-                    row_count += 1;
-
-                    if batch.len() >= 500 {
-                        if sender
-                            .send(StreamEvent::RowBatch(std::mem::replace(
-                                &mut batch,
-                                Vec::with_capacity(500),
-                            )))
-                            .await
-                            .is_err()
-                        {
-                            break;
-                        }
-                    }
-                }
-                Err(e) => {
-                    let error_msg = e.to_string();
-                    let _ = sender.send(StreamEvent::Error(error_msg.clone())).await;
-                    stream_error = Some(error_msg);
-                    break;
-                }
-            }
-        }
-
-        if !batch.is_empty() {
-            let _ = sender.send(StreamEvent::RowBatch(batch)).await;
-        }
-
-        {
-            let mut active = mysql_session.active_queries.lock().await;
-            active.remove(&query_id);
-        }
-
-        if stream_error.is_none() {
-            let _ = sender.send(StreamEvent::Done(row_count)).await;
-        }
-
-        if let Some(err) = stream_error {
-            return Err(EngineError::execution_error(err));
-        }
-
-        Ok(())
-    }
-
-    async fn execute(
-        &self,
-        session: SessionId,
-        query: &str,
-        query_id: QueryId,
-    ) -> EngineResult<QueryResult> {
-        self.execute_in_namespace(session, None, query, query_id)
-            .await
-    }
-
-    async fn execute_in_namespace(
-        &self,
-        session: SessionId,
-        namespace: Option<Namespace>,
-        query: &str,
-        query_id: QueryId,
-    ) -> EngineResult<QueryResult> {
-        let mysql_session = self.get_session(session).await?;
-        let start = Instant::now();
-
-        let returns_rows = safety::returns_rows(self.driver_id(), query)
-            .unwrap_or_else(|_| safety::is_select_prefix(query));
-
-        let mut tx_guard = mysql_session.transaction_conn.lock().await;
-        let result = if let Some(ref mut conn) = *tx_guard {
-            let connection_id = Self::fetch_connection_id(conn).await?;
-            {
-                let mut active = mysql_session.active_queries.lock().await;
-                active.insert(query_id, connection_id);
-            }
-
-            Self::apply_namespace_on_conn(conn, &namespace, query).await?;
-
-            let result = if returns_rows {
-                let mysql_rows: Vec<MySqlRow> = sqlx::query(query)
-                    .fetch_all(&mut **conn)
-                    .await
-                    .map_err(|e| {
-                        let msg = e.to_string();
-                        if msg.contains("syntax") {
-                            EngineError::syntax_error(msg)
-                        } else {
-                            EngineError::execution_error(msg)
-                        }
-                    })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                let (columns, rows) = Self::columns_and_rows(&mysql_rows);
-                Ok(QueryResult {
-                    columns,
-                    rows,
-                    affected_rows: None,
-                    execution_time_ms,
-                })
-            } else {
-                // Simple query protocol — some MySQL/MariaDB versions reject DDL over prepared statements.
-                let result = conn.execute(sqlx::raw_sql(query)).await.map_err(|e| {
-                    let msg = e.to_string();
-                    if msg.contains("syntax") {
-                        EngineError::syntax_error(msg)
-                    } else {
-                        EngineError::execution_error(msg)
-                    }
-                })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                Ok(QueryResult::with_affected_rows(
-                    result.rows_affected(),
-                    execution_time_ms,
-                ))
-            };
-
-            let mut active = mysql_session.active_queries.lock().await;
-            active.remove(&query_id);
-            result
-        } else {
-            let mut conn = mysql_session
-                .pool
-                .acquire()
-                .await
-                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
-            let connection_id = Self::fetch_connection_id(&mut conn).await?;
-            {
-                let mut active = mysql_session.active_queries.lock().await;
-                active.insert(query_id, connection_id);
-            }
-
-            Self::apply_namespace_on_conn(&mut conn, &namespace, query).await?;
-
-            let result = if returns_rows {
-                let mysql_rows: Vec<MySqlRow> = sqlx::query(query)
-                    .fetch_all(&mut *conn)
-                    .await
-                    .map_err(|e| {
-                        let msg = e.to_string();
-                        if msg.contains("syntax") {
-                            EngineError::syntax_error(msg)
-                        } else {
-                            EngineError::execution_error(msg)
-                        }
-                    })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                let (columns, rows) = Self::columns_and_rows(&mysql_rows);
-                Ok(QueryResult {
-                    columns,
-                    rows,
-                    affected_rows: None,
-                    execution_time_ms,
-                })
-            } else {
-                // Simple query protocol — some MySQL/MariaDB versions reject DDL over prepared statements.
-                let result = conn.execute(sqlx::raw_sql(query)).await.map_err(|e| {
-                    let msg = e.to_string();
-                    if msg.contains("syntax") {
-                        EngineError::syntax_error(msg)
-                    } else {
-                        EngineError::execution_error(msg)
-                    }
-                })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_query_timeout(
+            timeout,
+            self.stream_in_namespace(session, namespace, query, query_id, sender),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
+    }
 
-                Ok(QueryResult::with_affected_rows(
-                    result.rows_affected(),
-                    execution_time_ms,
-                ))
-            };
+    async fn execute(
+        &self,
+        session: SessionId,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        self.execute_in_namespace(session, None, query, query_id)
+            .await
+    }
 
-            let mut active = mysql_session.active_queries.lock().await;
-            active.remove(&query_id);
-            result
-        };
+    async fn execute_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_query_timeout(
+            timeout,
+            self.run_in_namespace(session, namespace, query, query_id),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
+    }
 
-        result
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_query_timeout(
+            timeout,
+            self.run_with_params(session, query, params, query_id),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
     }
 
     async fn describe_table(
@@ -1727,6 +2309,22 @@ impl DataEngine for MySqlDriver {
         session: SessionId,
         namespace: &Namespace,
         table: &str,
+    ) -> EngineResult<TableSchema> {
+        self.describe_table_with_options(
+            session,
+            namespace,
+            table,
+            &DescribeTableOptions::default(),
+        )
+        .await
+    }
+
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
     ) -> EngineResult<TableSchema> {
         let mysql_session = self.get_session(session).await?;
         let pool = &mysql_session.pool;
@@ -1763,6 +2361,7 @@ impl DataEngine for MySqlDriver {
                     if is_primary_key {
                         pk_columns.push(name.clone());
                     }
+                    let allowed_values = parse_allowed_values(&data_type);
                     TableColumn {
                         name,
                         data_type,
@@ -1770,6 +2369,11 @@ impl DataEngine for MySqlDriver {
                         default_value,
                         is_primary_key,
                         is_auto_increment: extra.to_lowercase().contains("auto_increment"),
+                        is_computed: false,
+                        computed_definition: None,
+                        is_sparse: false,
+                        is_column_set: false,
+                        allowed_values,
                     }
                 },
             )
@@ -1810,7 +2414,7 @@ impl DataEngine for MySqlDriver {
                     referenced_table,
                     referenced_column,
                     referenced_schema: None,
-                    referenced_database: Some(referenced_database),
+                    referenced_database: Self::referenced_database(database, referenced_database),
                     constraint_name: Some(constraint_name),
                     is_virtual: false,
                 },
@@ -1831,7 +2435,23 @@ impl DataEngine for MySqlDriver {
         .await
         .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
-        let row_count_estimate = count_row.map(|(c,)| c);
+        let table_rows = count_row.map(|(c,)| c);
+        let count_exact =
+            !options.fast_estimate && table_rows.is_some_and(|rows| rows <= SMALL_TABLE_MAX_ROWS);
+        let row_count_estimate = if count_exact {
+            let count_sql = format!(
+                "SELECT COUNT(*) FROM {}.{}",
+                Self::quote_ident(database),
+                Self::quote_ident(table)
+            );
+            let (count,): (i64,) = sqlx::query_as(&count_sql)
+                .fetch_one(pool)
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            u64::try_from(count).ok()
+        } else {
+            table_rows
+        };
 
         let index_rows: Vec<(String, String, i32, i32, Option<String>)> = sqlx::query_as(
             r#"
@@ -1893,10 +2513,52 @@ impl DataEngine for MySqlDriver {
             },
             foreign_keys,
             row_count_estimate,
+            row_count_is_estimate: !count_exact,
             indexes,
+            partitioning: None,
+            row_security: None,
         })
     }
 
+    async fn get_table_ddl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        let mysql_session = self.get_session(session).await?;
+        let sql = format!(
+            "SHOW CREATE TABLE {}.{}",
+            Self::quote_ident(&namespace.database),
+            Self::quote_ident(table)
+        );
+        let row = sqlx::query(&sql)
+            .fetch_one(&mysql_session.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let ddl: String = row
+            .try_get(1)
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(format!("{ddl};\n"))
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        spec.validate()?;
+        let mysql_session = self.get_session(session).await?;
+        let sql = generate_create_index(SqlDialect::MySql, namespace, table, spec);
+        sqlx::query(&sql)
+            .execute(&mysql_session.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(())
+    }
+
     async fn preview_table(
         &self,
         session: SessionId,
@@ -1930,13 +2592,14 @@ impl DataEngine for MySqlDriver {
         let page = options.effective_page();
         let page_size = options.effective_page_size();
         let offset = options.offset();
+        let page_columns = PageColumns::new(SqlDialect::MySql, &options)?;
 
         let mut where_clauses: Vec<String> = Vec::new();
         let mut bind_values: Vec<Value> = Vec::new();
 
         if let Some(filters) = &options.filters {
             for filter in filters {
-                let col_ident = Self::quote_ident(&filter.column);
+                let col_ident = page_columns.expr(&filter.column);
 
                 let clause = match filter.operator {
                     FilterOperator::Eq => {
@@ -2087,16 +2750,12 @@ impl DataEngine for MySqlDriver {
             format!(" WHERE {}", where_clauses.join(" AND "))
         };
 
-        let order_sql = if let Some(sort_col) = &options.sort_column {
-            let sort_ident = Self::quote_ident(sort_col);
-            let direction = match options.sort_direction.unwrap_or_default() {
-                SortDirection::Asc => "ASC",
-                SortDirection::Desc => "DESC",
-            };
-            format!(" ORDER BY {} {}", sort_ident, direction)
+        let implicit_order = if options.sort_column.is_none() {
+            Self::table_implicit_order(&mysql_session, &namespace.database, table).await
         } else {
-            String::new()
+            ImplicitOrder::Unordered
         };
+        let order_sql = options.order_by_sql(&implicit_order, |c| page_columns.expr(c));
 
         let count_sql = format!("SELECT COUNT(*) AS cnt FROM {}{}", table_ref, where_sql);
         let mut count_query = sqlx::query(&count_sql);
@@ -2120,8 +2779,13 @@ impl DataEngine for MySqlDriver {
         let total_rows = total_rows.max(0) as u64;
 
         let data_sql = format!(
-            "SELECT * FROM {}{}{} LIMIT {} OFFSET {}",
-            table_ref, where_sql, order_sql, page_size, offset
+            "SELECT {} FROM {}{}{} LIMIT {} OFFSET {}",
+            page_columns.select_list(&table_ref),
+            table_ref,
+            where_sql,
+            order_sql,
+            page_size,
+            offset
         );
 
         let mut data_query = sqlx::query(&data_sql);
@@ -2172,6 +2836,7 @@ impl DataEngine for MySqlDriver {
                         name: name.into(),
                         data_type: data_type.into(),
                         nullable: is_nullable == "YES",
+                        case_insensitive: false,
                     })
                 })
                 .collect();
@@ -2206,22 +2871,7 @@ impl DataEngine for MySqlDriver {
         limit: u32,
     ) -> EngineResult<QueryResult> {
         let mysql_session = self.get_session(session).await?;
-        let limit = limit.max(1).min(50);
-        let database = foreign_key
-            .referenced_database
-            .as_deref()
-            .unwrap_or(namespace.database.as_str());
-
-        let table_ref = format!(
-            "{}.{}",
-            Self::quote_ident(database),
-            Self::quote_ident(&foreign_key.referenced_table)
-        );
-        let column_ref = Self::quote_ident(&foreign_key.referenced_column);
-        let sql = format!(
-            "SELECT * FROM {} WHERE {} = ? LIMIT {}",
-            table_ref, column_ref, limit
-        );
+        let sql = Self::peek_foreign_key_sql(namespace, foreign_key, limit);
 
         let mut query = sqlx::query(&sql);
         query = Self::bind_param(query, value);
@@ -2256,6 +2906,16 @@ impl DataEngine for MySqlDriver {
         })
     }
 
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        column_stats::column_stats(self, SqlDialect::MySql, session, namespace, table, column).await
+    }
+
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         let mysql_session = self.get_session(session).await?;
 
@@ -2276,7 +2936,7 @@ impl DataEngine for MySqlDriver {
         }
 
         let mut conn = mysql_session
-            .pool
+            .control_pool
             .acquire()
             .await
             .map_err(|e| EngineError::connection_failed(e.to_string()))?;
@@ -2357,14 +3017,104 @@ impl DataEngine for MySqlDriver {
         true
     }
 
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        &[
+            IsolationLevel::ReadUncommitted,
+            IsolationLevel::ReadCommitted,
+            IsolationLevel::RepeatableRead,
+            IsolationLevel::Serializable,
+        ]
+    }
+
     fn supports_streaming(&self) -> bool {
         true
     }
 
+    fn supports_syntax_validation(&self) -> bool {
+        true
+    }
+
+    /// Validates `sql` with `PREPARE ... FROM @var` + `DEALLOCATE PREPARE`.
+    /// The text travels as a bound user variable, so it is never spliced into
+    /// the PREPARE statement itself.
+    async fn validate_syntax(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        sql: &str,
+    ) -> EngineResult<Option<SqlSyntaxError>> {
+        let sql = sql.trim().trim_end_matches(';').trim_end();
+        let mysql_session = self.get_session(session).await?;
+        let mut conn = mysql_session
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+        Self::apply_namespace_on_conn(&mut conn, &namespace, sql).await?;
+
+        sqlx::query("SET @qore_validate = ?")
+            .bind(sql)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let prepared = conn
+            .execute(sqlx::raw_sql("PREPARE qore_validate FROM @qore_validate"))
+            .await;
+        let result = match prepared {
+            Ok(_) => {
+                if let Err(e) = conn
+                    .execute(sqlx::raw_sql("DEALLOCATE PREPARE qore_validate"))
+                    .await
+                {
+                    tracing::warn!("DEALLOCATE PREPARE after validation failed: {}", e);
+                }
+                None
+            }
+            Err(e) => {
+                let Some(db_error) = e.as_database_error() else {
+                    return Err(EngineError::execution_error(e.to_string()));
+                };
+                let message = db_error.message().to_string();
+                // MySQL only reports "... near '...' at line N".
+                let line = message
+                    .rsplit_once(" at line ")
+                    .and_then(|(_, line)| line.trim().parse().ok());
+                Some(SqlSyntaxError {
+                    message,
+                    line,
+                    column: None,
+                })
+            }
+        };
+
+        let _ = conn
+            .execute(sqlx::raw_sql("SET @qore_validate = NULL"))
+            .await;
+        Ok(result)
+    }
+
     fn supports_explain(&self) -> bool {
         true
     }
 
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        let sql = explain::mysql_explain_sql(query, analyze);
+        let raw = self.fetch_plan(session, namespace, &sql).await?;
+        // `EXPLAIN ANALYZE` only prints a text tree
+        Ok(if analyze {
+            explain::text_plan(raw, true)
+        } else {
+            explain::json_plan(raw, false, explain::parse_mysql_plan)
+        })
+    }
+
     async fn insert_row(
         &self,
         session: SessionId,
@@ -2413,7 +3163,7 @@ impl DataEngine for MySqlDriver {
             query.execute(&mysql_session.pool).await
         };
 
-        let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let result = result.map_err(classify_error)?;
 
         Ok(QueryResult::with_affected_rows(
             result.rows_affected(),
@@ -2421,6 +3171,52 @@ impl DataEngine for MySqlDriver {
         ))
     }
 
+    async fn upsert_row(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        let mysql_session = self.get_session(session).await?;
+        let columns = UpsertColumns::new(data, conflict_columns)?;
+
+        let table_name = format!(
+            "{}.{}",
+            Self::quote_ident(&namespace.database),
+            Self::quote_ident(table)
+        );
+        let sql = Self::upsert_sql(&table_name, &columns);
+
+        let mut query = sqlx::query(&sql);
+        for k in &columns.insert {
+            query = Self::bind_param(query, &data.columns[k]);
+        }
+
+        let start = Instant::now();
+        let mut tx_guard = mysql_session.transaction_conn.lock().await;
+        let result = if let Some(ref mut conn) = *tx_guard {
+            query.execute(&mut **conn).await
+        } else {
+            query.execute(&mysql_session.pool).await
+        };
+
+        let result = result.map_err(classify_error)?;
+
+        Ok(UpsertResult {
+            result: QueryResult::with_affected_rows(
+                result.rows_affected(),
+                start.elapsed().as_micros() as f64 / 1000.0,
+            ),
+            action: Self::upsert_action(result.rows_affected()),
+        })
+    }
+
+    fn supports_upsert(&self) -> bool {
+        true
+    }
+
     async fn update_row(
         &self,
         session: SessionId,
@@ -2490,7 +3286,7 @@ impl DataEngine for MySqlDriver {
             query.execute(&mysql_session.pool).await
         };
 
-        let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let result = result.map_err(classify_error)?;
 
         Ok(QueryResult::with_affected_rows(
             result.rows_affected(),
@@ -2547,7 +3343,37 @@ impl DataEngine for MySqlDriver {
             query.execute(&mysql_session.pool).await
         };
 
-        let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let result = result.map_err(classify_error)?;
+
+        Ok(QueryResult::with_affected_rows(
+            result.rows_affected(),
+            start.elapsed().as_micros() as f64 / 1000.0,
+        ))
+    }
+
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        let mysql_session = self.get_session(session).await?;
+
+        let sql = format!(
+            "TRUNCATE TABLE `{}`.`{}`",
+            namespace.database.replace("`", "``"),
+            table.replace("`", "``")
+        );
+
+        let start = Instant::now();
+        let mut tx_guard = mysql_session.transaction_conn.lock().await;
+        let result = if let Some(ref mut conn) = *tx_guard {
+            sqlx::query(&sql).execute(&mut **conn).await
+        } else {
+            sqlx::query(&sql).execute(&mysql_session.pool).await
+        };
+
+        let result = result.map_err(classify_error)?;
 
         Ok(QueryResult::with_affected_rows(
             result.rows_affected(),
@@ -2559,6 +3385,10 @@ impl DataEngine for MySqlDriver {
         true
     }
 
+    fn supports_computed_columns(&self) -> bool {
+        true
+    }
+
     fn supports_maintenance(&self) -> bool {
         true
     }
@@ -2675,4 +3505,253 @@ impl DataEngine for MySqlDriver {
             success,
         })
     }
+
+    fn supports_index_usage(&self) -> bool {
+        true
+    }
+
+    async fn unused_indexes(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+    ) -> EngineResult<UnusedIndexReport> {
+        let mysql_session = self.get_session(session).await?;
+
+        let rows: Vec<(String, String, Option<i64>)> = sqlx::query_as(UNUSED_INDEXES_SQL)
+            .bind(&namespace.database)
+            .fetch_all(&mysql_session.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let indexes = rows
+            .into_iter()
+            .map(|(table_name, index_name, size_bytes)| UnusedIndex {
+                index_name,
+                table_name,
+                schema: Some(namespace.database.clone()),
+                size_bytes,
+                scan_count: 0,
+            })
+            .collect();
+
+        Ok(UnusedIndexReport {
+            indexes,
+            note: UNUSED_INDEXES_NOTE.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::types::TlsConfig;
+
+    fn make_config(driver: &str) -> ConnectionConfig {
+        ConnectionConfig {
+            driver: driver.to_string(),
+            host: "localhost".to_string(),
+            port: 3306,
+            username: "root".to_string(),
+            password: String::new(),
+            database: None,
+            ssl: false,
+            ssl_mode: None,
+            environment: "development".to_string(),
+            read_only: false,
+            ssh_tunnel: None,
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: Some(1500),
+            lock_timeout_ms: Some(2500),
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        }
+    }
+
+    #[test]
+    fn test_session_setup_statements() {
+        assert_eq!(
+            MySqlDriver::session_setup_statements(&make_config("mysql")),
+            vec![
+                "SET SESSION max_execution_time = 1500",
+                "SET SESSION innodb_lock_wait_timeout = 3",
+            ]
+        );
+        assert_eq!(
+            MySqlDriver::session_setup_statements(&make_config("mariadb")),
+            vec![
+                "SET SESSION max_statement_time = 1.5",
+                "SET SESSION innodb_lock_wait_timeout = 3",
+            ]
+        );
+
+        let mut config = make_config("mysql");
+        config.default_isolation_level = Some(IsolationLevel::ReadCommitted);
+        assert_eq!(
+            MySqlDriver::session_setup_statements(&config)
+                .last()
+                .unwrap(),
+            "SET SESSION TRANSACTION ISOLATION LEVEL READ COMMITTED"
+        );
+
+        config.read_only = true;
+        assert_eq!(
+            MySqlDriver::session_setup_statements(&config)
+                .last()
+                .unwrap(),
+            "SET SESSION TRANSACTION READ ONLY"
+        );
+    }
+
+    #[test]
+    fn test_ssl_mode_from_tls_config() {
+        let mut config = make_config("mysql");
+        config.ssl = true;
+        assert!(matches!(
+            MySqlDriver::resolve_ssl_mode(&config),
+            MySqlSslMode::Required
+        ));
+
+        config.environment = "production".to_string();
+        assert!(matches!(
+            MySqlDriver::resolve_ssl_mode(&config),
+            MySqlSslMode::VerifyIdentity
+        ));
+
+        config.ssl_mode = Some("required".to_string());
+        config.tls = Some(TlsConfig {
+            ca_cert_path: Some("/etc/certs/ca.pem".to_string()),
+            client_cert_path: Some("/etc/certs/client.pem".to_string()),
+            client_key_path: Some("/etc/certs/client.key".to_string()),
+            verify_mode: Some(TlsVerifyMode::CaOnly),
+        });
+        let opts = MySqlDriver::build_connect_options(&config);
+        assert!(matches!(opts.get_ssl_mode(), MySqlSslMode::VerifyCa));
+
+        config.ssl = false;
+        config.tls = None;
+        config.ssl_mode = None;
+        let opts = MySqlDriver::build_connect_options(&config);
+        assert!(matches!(opts.get_ssl_mode(), MySqlSslMode::Disabled));
+    }
+
+    #[test]
+    fn test_unused_indexes_query() {
+        assert!(UNUSED_INDEXES_SQL.contains("FROM sys.schema_unused_indexes u"));
+        assert!(UNUSED_INDEXES_SQL.contains("mysql.innodb_index_stats"));
+        assert!(UNUSED_INDEXES_SQL.contains("s.stat_name = 'size'"));
+        assert!(UNUSED_INDEXES_SQL.contains("WHERE u.object_schema = ?"));
+    }
+
+    #[test]
+    fn test_upsert_sql() {
+        let data = RowData::new()
+            .with_column("id", Value::Int(1))
+            .with_column("name", Value::Text("a".into()))
+            .with_column("qty", Value::Null);
+        let columns = UpsertColumns::new(&data, &["id".to_string()]).unwrap();
+        assert_eq!(
+            MySqlDriver::upsert_sql("`shop`.`items`", &columns),
+            "INSERT INTO `shop`.`items` (`id`, `name`, `qty`) VALUES (?, ?, ?) \
+             ON DUPLICATE KEY UPDATE `name` = VALUES(`name`), `qty` = VALUES(`qty`)"
+        );
+    }
+
+    #[test]
+    fn test_cross_database_foreign_key_peek() {
+        assert_eq!(
+            MySqlDriver::referenced_database("shop", "crm".to_string()).as_deref(),
+            Some("crm")
+        );
+        assert_eq!(
+            MySqlDriver::referenced_database("shop", "shop".to_string()),
+            None
+        );
+
+        let mut foreign_key = ForeignKey {
+            column: "customer_id".to_string(),
+            referenced_table: "customers".to_string(),
+            referenced_column: "id".to_string(),
+            referenced_schema: None,
+            referenced_database: MySqlDriver::referenced_database("shop", "crm".to_string()),
+            constraint_name: Some("fk_orders_customer".to_string()),
+            is_virtual: false,
+        };
+        let namespace = Namespace::new("shop");
+        assert_eq!(
+            MySqlDriver::peek_foreign_key_sql(&namespace, &foreign_key, 10),
+            "SELECT * FROM `crm`.`customers` WHERE `id` = ? LIMIT 10"
+        );
+
+        foreign_key.referenced_database = None;
+        assert_eq!(
+            MySqlDriver::peek_foreign_key_sql(&namespace, &foreign_key, 500),
+            "SELECT * FROM `shop`.`customers` WHERE `id` = ? LIMIT 50"
+        );
+    }
+
+    #[test]
+    fn test_classify_error_number() {
+        let err = classify_error_number(
+            Some(1142),
+            "SELECT command denied to user 'app'@'%' for table 'orders'".to_string(),
+        );
+        assert!(matches!(err, EngineError::PermissionDenied { .. }));
+        let err = classify_error_number(
+            Some(1044),
+            "Access denied for user 'app'@'%' to database 'billing'".to_string(),
+        );
+        assert!(matches!(err, EngineError::PermissionDenied { .. }));
+        let err = classify_error_number(
+            Some(1064),
+            "You have an error in your SQL syntax".to_string(),
+        );
+        assert!(matches!(err, EngineError::SyntaxError { .. }));
+        let err = classify_error_number(Some(1146), "Table 'shop.nope' doesn't exist".to_string());
+        assert!(matches!(err, EngineError::ExecutionError { .. }));
+    }
+
+    #[test]
+    fn test_upsert_action() {
+        assert_eq!(MySqlDriver::upsert_action(2), Some(UpsertAction::Updated));
+        assert_eq!(MySqlDriver::upsert_action(1), None);
+    }
+
+    #[test]
+    fn test_bit_value() {
+        assert!(matches!(bit_value(&[0x01, 0x02]), Value::Int(258)));
+        assert!(matches!(bit_value(&[]), Value::Int(0)));
+        assert!(matches!(bit_value(&[0xFF; 8]), Value::Text(ref t) if *t == "1".repeat(64)));
+    }
+
+    #[test]
+    fn test_parse_allowed_values() {
+        let values = parse_allowed_values("enum('small','it''s','a,b')").unwrap();
+        assert_eq!(values.values, vec!["small", "it's", "a,b"]);
+        assert!(!values.multiple);
+
+        let values = parse_allowed_values("set('read','write')").unwrap();
+        assert_eq!(values.values, vec!["read", "write"]);
+        assert!(values.multiple);
+
+        assert!(parse_allowed_values("varchar(10)").is_none());
+        assert!(parse_allowed_values("enum('open").is_none());
+    }
+
+    #[test]
+    fn test_join_set_members() {
+        let members = vec![Value::Text("read".into()), Value::Text("write".into())];
+        assert_eq!(join_set_members(&members).as_deref(), Some("read,write"));
+        assert_eq!(join_set_members(&[]).as_deref(), Some(""));
+        assert_eq!(join_set_members(&[Value::Int(1)]), None);
+    }
 }
@@ -9,30 +9,49 @@
 //! which queries and behaviours to override.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
 use futures::StreamExt;
 use sqlx::pool::PoolConnection;
-use sqlx::postgres::{PgPool, PgPoolOptions, PgRow, Postgres};
+use sqlx::postgres::{PgListener, PgPool, PgPoolOptions, PgRow, Postgres};
 use sqlx::Row;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::drivers::postgres_utils::{
     bind_param, build_decoders, collect_enum_type_oids, columns_and_rows,
-    convert_row_with_decoders, get_column_info, load_enum_labels, EnumLabelMap, PgDecoder,
+    convert_row_with_decoders, get_column_info, is_case_insensitive_type, load_enum_labels,
+    param_placeholder, EnumLabelMap, PgDecoder,
 };
+use crate::explain;
+use crate::query_timeout::{query_timeout, with_query_timeout};
+use crate::role_switch::{self, RoleExecution, RoleSwitch, StatementRunner};
+use crate::scheduled_jobs;
+use crate::server_info;
+use crate::server_log::{self, PgLogCursor};
 use qore_core::error::{EngineError, EngineResult};
-use qore_core::traits::{StreamEvent, StreamSender};
+use qore_core::traits::{
+    CopyInSource, CopyOutSender, NotificationSender, StreamEvent, StreamSender,
+};
 use qore_core::types::{
-    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnInfo,
-    ConnectionConfig, FilterOperator, ForeignKey, MaintenanceMessage, MaintenanceMessageLevel,
-    MaintenanceOperationInfo, MaintenanceOperationType, MaintenanceRequest, MaintenanceResult,
-    Namespace, PaginatedQueryResult, QueryId, QueryResult, Routine, RoutineDefinition, RoutineList,
-    RoutineListOptions, RoutineOperationResult, RoutineType, RowData, SessionId, SortDirection,
-    TableColumn, TableIndex, TableQueryOptions, TableSchema, Trigger, TriggerDefinition,
-    TriggerEvent, TriggerList, TriggerListOptions, TriggerOperationResult, TriggerTiming, Value,
+    BloatEntry, BloatMethod, BloatReport, BulkImportOptions, CancelSupport, Collection,
+    CollectionList, CollectionListOptions, CollectionType, ColumnInfo, ConnectionConfig,
+    CopyForceQuote, CopyFormat, CopyOptions, CopySource, ExplainPlan, FilterOperator, ForeignKey,
+    ImplicitOrder, IndexSpec, IsolationLevel, JobScheduler, MaintenanceMessage,
+    MaintenanceMessageLevel, MaintenanceOperationInfo, MaintenanceOperationType,
+    MaintenanceRequest, MaintenanceResult, Namespace, Notification, PaginatedQueryResult,
+    PartitionStrategy, PoolStats, ProgressOperation, QueryId, QueryProgress, QueryResult, Routine,
+    RoutineDefinition, RoutineList, RoutineListOptions, RoutineOperationResult, RoutineType,
+    RowData, ScheduledJob, ScheduledJobList, ScheduledJobOperationResult, ServerInfo,
+    ServerLogChunk, ServerLogSource, SessionId, SqlSyntaxError, TableColumn, TableIndex,
+    TablePartition, TablePartitioning, TableQueryOptions, TableRowSecurity, TableSchema,
+    TlsVerifyMode, Trigger, TriggerDefinition, TriggerEvent, TriggerList, TriggerListOptions,
+    TriggerOperationResult, TriggerTiming, UnusedIndex, UnusedIndexReport, UpsertAction,
+    UpsertColumns, UpsertResult, Value,
 };
+use qore_sql::generator::{generate_create_index, PageColumns, SqlDialect};
 use qore_sql::safety;
 
 // Session
@@ -40,17 +59,68 @@ use qore_sql::safety;
 /// A session backed by a PgPool (works for any PG-compatible database).
 pub struct PgCompatSession {
     pub pool: PgPool,
+    /// Single reserved connection for cancellation and health checks, so
+    /// both keep working when every connection of `pool` is busy.
+    pub control_pool: PgPool,
     pub transaction_conn: Mutex<Option<PoolConnection<Postgres>>>,
     pub active_queries: Mutex<HashMap<QueryId, i32>>,
+    /// Shared with the pool, which applies it to every connection it hands out.
+    pub row_security: Arc<RowSecurityToggle>,
+    /// `ConnectionConfig::query_timeout_secs`, enforced around each execute.
+    pub query_timeout: Option<Duration>,
 }
 
 impl PgCompatSession {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(
+        pool: PgPool,
+        control_pool: PgPool,
+        row_security: Arc<RowSecurityToggle>,
+        query_timeout: Option<Duration>,
+    ) -> Self {
         Self {
             pool,
+            control_pool,
             transaction_conn: Mutex::new(None),
             active_queries: Mutex::new(HashMap::new()),
+            row_security,
+            query_timeout,
+        }
+    }
+}
+
+/// Per-session `row_security` override. Until it is first toggled the pool
+/// leaves the server setting alone.
+#[derive(Debug, Default)]
+pub struct RowSecurityToggle {
+    touched: AtomicBool,
+    bypass: AtomicBool,
+}
+
+impl RowSecurityToggle {
+    pub fn set_bypass(&self, bypass: bool) {
+        self.bypass.store(bypass, Ordering::SeqCst);
+        self.touched.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_bypassed(&self) -> bool {
+        self.bypass.load(Ordering::SeqCst)
+    }
+
+    /// Statement bringing a connection in line with the toggle, `None` while
+    /// it was never toggled.
+    pub fn sync_statement(&self) -> Option<&'static str> {
+        if !self.touched.load(Ordering::SeqCst) {
+            return None;
         }
+        Some(row_security_statement(self.is_bypassed()))
+    }
+}
+
+pub fn row_security_statement(bypass: bool) -> &'static str {
+    if bypass {
+        "SET row_security = off"
+    } else {
+        "RESET row_security"
     }
 }
 
@@ -63,6 +133,39 @@ pub fn new_session_map() -> SessionMap {
 
 // Pool and connection helpers
 
+/// Isolation levels PostgreSQL accepts (`READ UNCOMMITTED` behaves as
+/// `READ COMMITTED`).
+pub const ISOLATION_LEVELS: &[IsolationLevel] = &[
+    IsolationLevel::ReadUncommitted,
+    IsolationLevel::ReadCommitted,
+    IsolationLevel::RepeatableRead,
+    IsolationLevel::Serializable,
+];
+
+/// Session settings (`statement_timeout`, `lock_timeout`, default isolation
+/// level, read-only mode) issued on every new pooled connection, so they
+/// survive pool churn and apply to every `BEGIN` on the connection.
+pub fn session_setup_statements(config: &ConnectionConfig) -> Vec<String> {
+    let mut statements = Vec::new();
+    if let Some(ms) = config.statement_timeout_ms {
+        statements.push(format!("SET statement_timeout = {ms}"));
+    }
+    if let Some(ms) = config.lock_timeout_ms {
+        statements.push(format!("SET lock_timeout = {ms}"));
+    }
+    if let Some(level) = config.default_isolation_level {
+        statements.push(format!(
+            "SET SESSION CHARACTERISTICS AS TRANSACTION ISOLATION LEVEL {}",
+            level.as_sql()
+        ));
+    }
+    if config.read_only {
+        statements.push("SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY".to_string());
+    }
+    statements
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn create_pg_pool(
     conn_str: &str,
     max_connections: u32,
@@ -70,11 +173,41 @@ pub async fn create_pg_pool(
     acquire_timeout_secs: u64,
     classify_auth_error: bool,
     run_test_query: bool,
+    setup_statements: Vec<String>,
+    row_security: Option<Arc<RowSecurityToggle>>,
 ) -> EngineResult<PgPool> {
-    let pool = PgPoolOptions::new()
+    let setup_statements = Arc::new(setup_statements);
+    let mut options = PgPoolOptions::new()
         .max_connections(max_connections)
         .min_connections(min_connections)
         .acquire_timeout(std::time::Duration::from_secs(acquire_timeout_secs))
+        // Pings idle connections on checkout so one dropped by the server or
+        // a NAT while the app sat idle is replaced instead of failing a query.
+        .test_before_acquire(true);
+    if let Some(row_security) = row_security.clone() {
+        // Idle connections may predate the last toggle, so re-sync on every
+        // acquire. Fresh connections skip this hook and sync in `after_connect`.
+        options = options.before_acquire(move |conn, _meta| {
+            let sync = row_security.sync_statement();
+            Box::pin(async move {
+                if let Some(stmt) = sync {
+                    sqlx::query(stmt).execute(&mut *conn).await?;
+                }
+                Ok(true)
+            })
+        });
+    }
+    let pool = options
+        .after_connect(move |conn, _meta| {
+            let setup_statements = Arc::clone(&setup_statements);
+            let sync = row_security.as_ref().and_then(|r| r.sync_statement());
+            Box::pin(async move {
+                for stmt in setup_statements.iter().map(String::as_str).chain(sync) {
+                    sqlx::query(stmt).execute(&mut *conn).await?;
+                }
+                Ok(())
+            })
+        })
         .connect(conn_str)
         .await
         .map_err(|e| {
@@ -107,7 +240,7 @@ pub async fn get_session(
 }
 
 pub fn quote_ident(name: &str) -> String {
-    format!("\"{}\"", name.replace('"', "\"\""))
+    safety::quote_ident(SqlDialect::Postgres, name)
 }
 
 // Maintenance
@@ -260,10 +393,30 @@ pub async fn fetch_backend_pid(conn: &mut PoolConnection<Postgres>) -> EngineRes
         .map_err(|e| EngineError::execution_error(e.to_string()))
 }
 
+/// Opens the one-connection pool reserved for `cancel` and `ping`. It keeps
+/// its connection open and skips the session setup statements, so a
+/// `statement_timeout` cannot cut a cancellation short.
+pub async fn create_control_pool(
+    conn_str: &str,
+    acquire_timeout_secs: u64,
+) -> EngineResult<PgPool> {
+    create_pg_pool(
+        conn_str,
+        1,
+        1,
+        acquire_timeout_secs,
+        false,
+        false,
+        Vec::new(),
+        None,
+    )
+    .await
+}
+
 // Connection lifecycle
 
 pub async fn test_connection(conn_str: &str) -> EngineResult<()> {
-    let pool = create_pg_pool(conn_str, 1, 0, 10, true, true).await?;
+    let pool = create_pg_pool(conn_str, 1, 0, 10, true, true, Vec::new(), None).await?;
     pool.close().await;
     Ok(())
 }
@@ -277,10 +430,33 @@ pub async fn connect(
     let min = config.pool_min_connections.unwrap_or(2).min(max);
     let timeout = config.pool_acquire_timeout_secs.unwrap_or(15) as u64;
 
-    let pool = create_pg_pool(conn_str, max, min, timeout, false, false).await?;
+    let row_security = Arc::new(RowSecurityToggle::default());
+    let pool = create_pg_pool(
+        conn_str,
+        max,
+        min,
+        timeout,
+        false,
+        false,
+        session_setup_statements(config),
+        Some(Arc::clone(&row_security)),
+    )
+    .await?;
+    let control_pool = match create_control_pool(conn_str, timeout).await {
+        Ok(control_pool) => control_pool,
+        Err(e) => {
+            pool.close().await;
+            return Err(e);
+        }
+    };
 
     let session_id = SessionId::new();
-    let session = Arc::new(PgCompatSession::new(pool));
+    let session = Arc::new(PgCompatSession::new(
+        pool,
+        control_pool,
+        row_security,
+        query_timeout(config.query_timeout_secs),
+    ));
 
     let mut map = sessions.write().await;
     map.insert(session_id, session);
@@ -309,16 +485,46 @@ pub async fn disconnect(sessions: &SessionMap, session: SessionId) -> EngineResu
     }
 
     session.pool.close().await;
+    session.control_pool.close().await;
     Ok(())
 }
 
-pub async fn ping(sessions: &SessionMap, session: SessionId) -> EngineResult<()> {
+pub async fn ping(sessions: &SessionMap, session: SessionId) -> EngineResult<Duration> {
     let pg = get_session(sessions, session).await?;
+    let start = Instant::now();
     sqlx::query("SELECT 1")
-        .execute(&pg.pool)
+        .execute(&pg.control_pool)
         .await
         .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
-    Ok(())
+    Ok(start.elapsed())
+}
+
+/// Occupancy of the session query pool. The control pool is not counted: it
+/// is reserved for cancellation and never serves queries.
+pub async fn pool_stats(sessions: &SessionMap, session: SessionId) -> EngineResult<PoolStats> {
+    let pg = get_session(sessions, session).await?;
+    let size = pg.pool.size();
+    let idle = pg.pool.num_idle() as u32;
+    Ok(PoolStats {
+        size,
+        idle,
+        in_use: size.saturating_sub(idle),
+        max: pg.pool.options().get_max_connections(),
+    })
+}
+
+pub async fn server_info(sessions: &SessionMap, session: SessionId) -> EngineResult<ServerInfo> {
+    let pg = get_session(sessions, session).await?;
+    let (version, version_num): (String, String) = sqlx::query_as(
+        "SELECT current_setting('server_version'), current_setting('server_version_num')",
+    )
+    .fetch_one(&pg.pool)
+    .await
+    .map_err(|e| classify_error(e.to_string()))?;
+    let version_num = version_num.trim().parse().map_err(|_| {
+        EngineError::execution_error(format!("Unexpected server_version_num '{version_num}'"))
+    })?;
+    Ok(server_info::postgres_server_info(&version, version_num))
 }
 
 // Execute
@@ -330,6 +536,27 @@ pub async fn execute_in_namespace(
     namespace: Option<Namespace>,
     query: &str,
     query_id: QueryId,
+) -> EngineResult<QueryResult> {
+    let pg = get_session(sessions, session).await?;
+    let result = with_query_timeout(
+        pg.query_timeout,
+        run_in_namespace(sessions, driver_id, session, namespace, query, query_id),
+        cancel(sessions, session, Some(query_id)),
+    )
+    .await;
+    // Failed and timed out queries skip the removal in `run_in_namespace`.
+    pg.active_queries.lock().await.remove(&query_id);
+    result
+}
+
+/// `execute_in_namespace` without the query timeout.
+async fn run_in_namespace(
+    sessions: &SessionMap,
+    driver_id: &str,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    query: &str,
+    query_id: QueryId,
 ) -> EngineResult<QueryResult> {
     let pg = get_session(sessions, session).await?;
     let start = Instant::now();
@@ -354,7 +581,7 @@ pub async fn execute_in_namespace(
             let r = sqlx::query(query)
                 .execute(&mut **conn)
                 .await
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+                .map_err(|e| classify_error(e.to_string()))?;
             QueryResult::with_affected_rows(
                 r.rows_affected(),
                 start.elapsed().as_micros() as f64 / 1000.0,
@@ -389,7 +616,7 @@ pub async fn execute_in_namespace(
             let r = sqlx::query(query)
                 .execute(&mut *conn)
                 .await
-                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+                .map_err(|e| classify_error(e.to_string()))?;
             QueryResult::with_affected_rows(
                 r.rows_affected(),
                 start.elapsed().as_micros() as f64 / 1000.0,
@@ -406,6 +633,100 @@ pub async fn execute_in_namespace(
     Ok(result)
 }
 
+/// Runs `query` with `params` bound to its `$n` placeholders, on the open
+/// transaction if there is one.
+pub async fn execute_with_params(
+    sessions: &SessionMap,
+    driver_id: &str,
+    session: SessionId,
+    query: &str,
+    params: &[Value],
+    query_id: QueryId,
+) -> EngineResult<QueryResult> {
+    let pg = get_session(sessions, session).await?;
+    let result = with_query_timeout(
+        pg.query_timeout,
+        run_with_params(&pg, driver_id, query, params, query_id),
+        cancel(sessions, session, Some(query_id)),
+    )
+    .await;
+    pg.active_queries.lock().await.remove(&query_id);
+    result
+}
+
+async fn run_with_params(
+    pg: &PgCompatSession,
+    driver_id: &str,
+    query: &str,
+    params: &[Value],
+    query_id: QueryId,
+) -> EngineResult<QueryResult> {
+    let start = Instant::now();
+    let returns_rows =
+        safety::returns_rows(driver_id, query).unwrap_or_else(|_| safety::is_select_prefix(query));
+
+    let mut tx_guard = pg.transaction_conn.lock().await;
+    if let Some(ref mut conn) = *tx_guard {
+        let backend_pid = fetch_backend_pid(conn).await?;
+        pg.active_queries.lock().await.insert(query_id, backend_pid);
+        return exec_bound_on_conn(conn, &pg.pool, query, params, returns_rows, start).await;
+    }
+    drop(tx_guard);
+
+    let mut conn = pg
+        .pool
+        .acquire()
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+    let backend_pid = fetch_backend_pid(&mut conn).await?;
+    pg.active_queries.lock().await.insert(query_id, backend_pid);
+    exec_bound_on_conn(&mut conn, &pg.pool, query, params, returns_rows, start).await
+}
+
+async fn exec_bound_on_conn(
+    conn: &mut PoolConnection<Postgres>,
+    pool: &PgPool,
+    query: &str,
+    params: &[Value],
+    returns_rows: bool,
+    start: Instant,
+) -> EngineResult<QueryResult> {
+    let mut bound = sqlx::query(query);
+    for value in params {
+        bound = bind_param(bound, value)?;
+    }
+
+    if returns_rows {
+        let pg_rows: Vec<PgRow> = bound
+            .fetch_all(&mut **conn)
+            .await
+            .map_err(|e| classify_error(e.to_string()))?;
+        return rows_to_result(pg_rows, pool, start).await;
+    }
+
+    let r = bound
+        .execute(&mut **conn)
+        .await
+        .map_err(|e| classify_error(e.to_string()))?;
+    Ok(QueryResult::with_affected_rows(
+        r.rows_affected(),
+        start.elapsed().as_micros() as f64 / 1000.0,
+    ))
+}
+
+/// Maps a PostgreSQL error message to an `EngineError`. Privilege errors
+/// (SQLSTATE 42501) read "permission denied for ..." and are kept apart from
+/// login failures.
+pub fn classify_error(msg: String) -> EngineError {
+    if msg.contains("permission denied") {
+        EngineError::permission_denied(msg)
+    } else if msg.contains("syntax") {
+        EngineError::syntax_error(msg)
+    } else {
+        EngineError::execution_error(msg)
+    }
+}
+
 /// Execute a SELECT on a transaction-owned connection
 async fn exec_rows_on_conn(
     conn: &mut PoolConnection<Postgres>,
@@ -416,14 +737,7 @@ async fn exec_rows_on_conn(
     let pg_rows: Vec<PgRow> = sqlx::query(query)
         .fetch_all(&mut **conn)
         .await
-        .map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("syntax") {
-                EngineError::syntax_error(msg)
-            } else {
-                EngineError::execution_error(msg)
-            }
-        })?;
+        .map_err(|e| classify_error(e.to_string()))?;
 
     rows_to_result(pg_rows, pool, start).await
 }
@@ -457,6 +771,137 @@ async fn rows_to_result(
     })
 }
 
+// Execute as role
+
+struct PgRoleRunner<'a> {
+    conn: &'a mut PoolConnection<Postgres>,
+    pool: &'a PgPool,
+    driver_id: &'a str,
+}
+
+#[async_trait]
+impl StatementRunner for PgRoleRunner<'_> {
+    async fn run(&mut self, sql: &str) -> EngineResult<QueryResult> {
+        let start = Instant::now();
+        let returns_rows = safety::returns_rows(self.driver_id, sql)
+            .unwrap_or_else(|_| safety::is_select_prefix(sql));
+        if returns_rows {
+            return exec_rows_on_conn(self.conn, self.pool, sql, start).await;
+        }
+        let r = sqlx::query(sql)
+            .execute(&mut **self.conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(QueryResult::with_affected_rows(
+            r.rows_affected(),
+            start.elapsed().as_micros() as f64 / 1000.0,
+        ))
+    }
+}
+
+/// Keeps a role-switched connection out of the pool unless the role was
+/// reset, including when the call is dropped mid-flight (timeout, cancel).
+struct RoleConnection {
+    conn: Option<PoolConnection<Postgres>>,
+    reset: bool,
+}
+
+impl Drop for RoleConnection {
+    fn drop(&mut self) {
+        if !self.reset {
+            if let Some(conn) = self.conn.take() {
+                drop(conn.detach());
+            }
+        }
+    }
+}
+
+/// `EXPLAIN (FORMAT JSON)` of `query`, parsed into a plan tree.
+pub async fn explain_query(
+    sessions: &SessionMap,
+    driver_id: &str,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    query: &str,
+    analyze: bool,
+) -> EngineResult<ExplainPlan> {
+    let sql = explain::pg_explain_sql(query, analyze);
+    let result = execute_in_namespace(
+        sessions,
+        driver_id,
+        session,
+        namespace,
+        &sql,
+        QueryId::new(),
+    )
+    .await?;
+    Ok(explain::json_plan(
+        explain::plan_text(&result),
+        analyze,
+        explain::parse_pg_plan,
+    ))
+}
+
+/// Runs `query` under `SET ROLE role` on a pooled connection.
+pub async fn execute_as_role(
+    sessions: &SessionMap,
+    driver_id: &str,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    role: &str,
+    query: &str,
+    query_id: QueryId,
+) -> EngineResult<QueryResult> {
+    let switch = RoleSwitch::postgres(role)?;
+    let pg = get_session(sessions, session).await?;
+
+    let conn = pg
+        .pool
+        .acquire()
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+    let mut held = RoleConnection {
+        conn: Some(conn),
+        reset: false,
+    };
+    let Some(conn) = held.conn.as_mut() else {
+        unreachable!("connection is held until drop");
+    };
+
+    let backend_pid = fetch_backend_pid(conn).await?;
+    {
+        let mut active = pg.active_queries.lock().await;
+        active.insert(query_id, backend_pid);
+    }
+
+    let execution = match apply_namespace_on_conn(conn, &namespace, query, false).await {
+        Ok(()) => {
+            let mut runner = PgRoleRunner {
+                conn,
+                pool: &pg.pool,
+                driver_id,
+            };
+            role_switch::execute_as_role(&mut runner, &switch, query).await
+        }
+        Err(e) => RoleExecution {
+            result: Err(e),
+            reset_error: None,
+        },
+    };
+
+    {
+        let mut active = pg.active_queries.lock().await;
+        active.remove(&query_id);
+    }
+
+    match execution.reset_error {
+        Some(e) => tracing::warn!("Failed to reset role, discarding connection: {}", e),
+        None => held.reset = true,
+    }
+
+    execution.result
+}
+
 // Streaming
 
 pub async fn execute_stream_in_namespace(
@@ -469,6 +914,29 @@ pub async fn execute_stream_in_namespace(
     sender: StreamSender,
 ) -> EngineResult<()> {
     let pg = get_session(sessions, session).await?;
+    let result = with_query_timeout(
+        pg.query_timeout,
+        stream_in_namespace(
+            sessions, driver_id, session, namespace, query, query_id, sender,
+        ),
+        cancel(sessions, session, Some(query_id)),
+    )
+    .await;
+    pg.active_queries.lock().await.remove(&query_id);
+    result
+}
+
+/// `execute_stream_in_namespace` without the query timeout.
+async fn stream_in_namespace(
+    sessions: &SessionMap,
+    driver_id: &str,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    query: &str,
+    query_id: QueryId,
+    sender: StreamSender,
+) -> EngineResult<()> {
+    let pg = get_session(sessions, session).await?;
 
     let mut conn = pg
         .pool
@@ -483,7 +951,7 @@ pub async fn execute_stream_in_namespace(
 
     if !returns_rows {
         let result =
-            execute_in_namespace(sessions, driver_id, session, namespace, query, query_id).await?;
+            run_in_namespace(sessions, driver_id, session, namespace, query, query_id).await?;
         let _ = sender
             .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
             .await;
@@ -602,7 +1070,7 @@ pub async fn cancel(
     }
 
     let mut conn = pg
-        .pool
+        .control_pool
         .acquire()
         .await
         .map_err(|e| EngineError::connection_failed(e.to_string()))?;
@@ -732,6 +1200,9 @@ pub async fn insert_row(
     let mut keys: Vec<&String> = data.columns.keys().collect();
     keys.sort();
 
+    let mut tx_guard = pg.transaction_conn.lock().await;
+    let null_types = null_column_types(&pg.pool, &mut tx_guard, &table_name, data).await;
+
     let sql = if keys.is_empty() {
         format!("INSERT INTO {} DEFAULT VALUES", table_name)
     } else {
@@ -740,8 +1211,16 @@ pub async fn insert_row(
             .map(|k| quote_ident(k))
             .collect::<Vec<_>>()
             .join(", ");
-        let params_str = (1..=keys.len())
-            .map(|i| format!("${}", i))
+        let params_str = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| {
+                param_placeholder(
+                    i + 1,
+                    &data.columns[*k],
+                    null_types.get(*k).map(String::as_str),
+                )
+            })
             .collect::<Vec<_>>()
             .join(", ");
         format!(
@@ -753,24 +1232,102 @@ pub async fn insert_row(
     let mut query = sqlx::query(&sql);
     for k in &keys {
         let val = data.columns.get(*k).unwrap();
-        query = bind_param(query, val);
+        query = bind_param(query, val)?;
     }
 
     let start = Instant::now();
-    let mut tx_guard = pg.transaction_conn.lock().await;
     let result = if let Some(ref mut conn) = *tx_guard {
         query.execute(&mut **conn).await
     } else {
         query.execute(&pg.pool).await
     };
 
-    let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let result = result.map_err(|e| classify_error(e.to_string()))?;
     Ok(QueryResult::with_affected_rows(
         result.rows_affected(),
         start.elapsed().as_micros() as f64 / 1000.0,
     ))
 }
 
+/// `INSERT ... ON CONFLICT (keys) DO UPDATE SET ...`. `RETURNING (xmax = 0)`
+/// is true for a freshly inserted row and false when an existing row was updated.
+pub fn upsert_sql(table_name: &str, columns: &UpsertColumns, placeholders: &[String]) -> String {
+    let quoted = |cols: &[String]| {
+        cols.iter()
+            .map(|c| quote_ident(c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let set_clauses = columns
+        .update
+        .iter()
+        .map(|c| format!("{} = EXCLUDED.{}", quote_ident(c), quote_ident(c)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT ({}) DO UPDATE SET {} RETURNING (xmax = 0)",
+        table_name,
+        quoted(&columns.insert),
+        placeholders.join(", "),
+        quoted(&columns.conflict),
+        set_clauses
+    )
+}
+
+pub async fn upsert_row(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    data: &RowData,
+    conflict_columns: &[String],
+) -> EngineResult<UpsertResult> {
+    let pg = get_session(sessions, session).await?;
+    let columns = UpsertColumns::new(data, conflict_columns)?;
+    let table_name = qualified_table_name(namespace, table);
+
+    let mut tx_guard = pg.transaction_conn.lock().await;
+    let null_types = null_column_types(&pg.pool, &mut tx_guard, &table_name, data).await;
+
+    let placeholders: Vec<String> = columns
+        .insert
+        .iter()
+        .enumerate()
+        .map(|(i, k)| {
+            param_placeholder(
+                i + 1,
+                &data.columns[k],
+                null_types.get(k).map(String::as_str),
+            )
+        })
+        .collect();
+    let sql = upsert_sql(&table_name, &columns, &placeholders);
+
+    let mut query = sqlx::query(&sql);
+    for k in &columns.insert {
+        query = bind_param(query, &data.columns[k])?;
+    }
+
+    let start = Instant::now();
+    let row = if let Some(ref mut conn) = *tx_guard {
+        query.fetch_one(&mut **conn).await
+    } else {
+        query.fetch_one(&pg.pool).await
+    };
+    let inserted: bool = row
+        .and_then(|row| row.try_get(0))
+        .map_err(|e| classify_error(e.to_string()))?;
+
+    Ok(UpsertResult {
+        result: QueryResult::with_affected_rows(1, start.elapsed().as_micros() as f64 / 1000.0),
+        action: Some(if inserted {
+            UpsertAction::Inserted
+        } else {
+            UpsertAction::Updated
+        }),
+    })
+}
+
 pub async fn update_row(
     sessions: &SessionMap,
     session: SessionId,
@@ -797,10 +1354,15 @@ pub async fn update_row(
     let mut pk_keys: Vec<&String> = primary_key.columns.keys().collect();
     pk_keys.sort();
 
+    let mut tx_guard = pg.transaction_conn.lock().await;
+    let null_types = null_column_types(&pg.pool, &mut tx_guard, &table_name, data).await;
+
     let mut set_clauses = Vec::new();
     let mut i = 1;
     for k in &data_keys {
-        set_clauses.push(format!("{}=${}", quote_ident(k), i));
+        let placeholder =
+            param_placeholder(i, &data.columns[*k], null_types.get(*k).map(String::as_str));
+        set_clauses.push(format!("{}={}", quote_ident(k), placeholder));
         i += 1;
     }
     let mut where_clauses = Vec::new();
@@ -818,27 +1380,59 @@ pub async fn update_row(
 
     let mut query = sqlx::query(&sql);
     for k in &data_keys {
-        query = bind_param(query, data.columns.get(*k).unwrap());
+        query = bind_param(query, data.columns.get(*k).unwrap())?;
     }
     for k in &pk_keys {
-        query = bind_param(query, primary_key.columns.get(*k).unwrap());
+        query = bind_param(query, primary_key.columns.get(*k).unwrap())?;
     }
 
     let start = Instant::now();
-    let mut tx_guard = pg.transaction_conn.lock().await;
     let result = if let Some(ref mut conn) = *tx_guard {
         query.execute(&mut **conn).await
     } else {
         query.execute(&pg.pool).await
     };
 
-    let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let result = result.map_err(|e| classify_error(e.to_string()))?;
     Ok(QueryResult::with_affected_rows(
         result.rows_affected(),
         start.elapsed().as_micros() as f64 / 1000.0,
     ))
 }
 
+/// Declared types of the columns `data` sets to NULL, keyed by column name,
+/// so the NULLs can be cast (see [`param_placeholder`]). Skips the catalog
+/// round-trip when nothing is NULL; a failed lookup yields an empty map and
+/// the statement falls back to untyped placeholders.
+async fn null_column_types(
+    pool: &PgPool,
+    tx: &mut Option<PoolConnection<Postgres>>,
+    table_name: &str,
+    data: &RowData,
+) -> HashMap<String, String> {
+    if !data.columns.values().any(|v| matches!(v, Value::Null)) {
+        return HashMap::new();
+    }
+
+    let query = sqlx::query_as::<_, (String, String)>(
+        "SELECT a.attname::text, format_type(a.atttypid, NULL) \
+         FROM pg_catalog.pg_attribute a \
+         WHERE a.attrelid = to_regclass($1) AND a.attnum > 0 AND NOT a.attisdropped",
+    )
+    .bind(table_name);
+    let rows = match tx {
+        Some(conn) => query.fetch_all(&mut **conn).await,
+        None => query.fetch_all(pool).await,
+    };
+
+    rows.map(|rows| {
+        rows.into_iter()
+            .filter(|(name, _)| matches!(data.columns.get(name), Some(Value::Null)))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
 pub async fn delete_row(
     sessions: &SessionMap,
     session: SessionId,
@@ -874,7 +1468,7 @@ pub async fn delete_row(
 
     let mut query = sqlx::query(&sql);
     for k in &pk_keys {
-        query = bind_param(query, primary_key.columns.get(*k).unwrap());
+        query = bind_param(query, primary_key.columns.get(*k).unwrap())?;
     }
 
     let start = Instant::now();
@@ -885,32 +1479,58 @@ pub async fn delete_row(
         query.execute(&pg.pool).await
     };
 
-    let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let result = result.map_err(|e| classify_error(e.to_string()))?;
     Ok(QueryResult::with_affected_rows(
         result.rows_affected(),
         start.elapsed().as_micros() as f64 / 1000.0,
     ))
 }
 
-// Peek FK
+// Truncate
 
-pub async fn peek_foreign_key(
+pub async fn truncate_table(
     sessions: &SessionMap,
     session: SessionId,
     namespace: &Namespace,
-    foreign_key: &ForeignKey,
-    value: &Value,
-    limit: u32,
+    table: &str,
 ) -> EngineResult<QueryResult> {
     let pg = get_session(sessions, session).await?;
-    let limit = limit.max(1).min(50);
-    let schema = foreign_key
-        .referenced_schema
-        .as_deref()
-        .or(namespace.schema.as_deref())
-        .unwrap_or("public");
+    let sql = format!("TRUNCATE TABLE {}", qualified_table_name(namespace, table));
 
-    let table_ref = format!(
+    let start = Instant::now();
+    let mut tx_guard = pg.transaction_conn.lock().await;
+    let result = if let Some(ref mut conn) = *tx_guard {
+        sqlx::query(&sql).execute(&mut **conn).await
+    } else {
+        sqlx::query(&sql).execute(&pg.pool).await
+    };
+
+    let result = result.map_err(|e| classify_error(e.to_string()))?;
+    Ok(QueryResult::with_affected_rows(
+        result.rows_affected(),
+        start.elapsed().as_micros() as f64 / 1000.0,
+    ))
+}
+
+// Peek FK
+
+pub async fn peek_foreign_key(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    foreign_key: &ForeignKey,
+    value: &Value,
+    limit: u32,
+) -> EngineResult<QueryResult> {
+    let pg = get_session(sessions, session).await?;
+    let limit = limit.max(1).min(50);
+    let schema = foreign_key
+        .referenced_schema
+        .as_deref()
+        .or(namespace.schema.as_deref())
+        .unwrap_or("public");
+
+    let table_ref = format!(
         "{}.{}",
         quote_ident(schema),
         quote_ident(&foreign_key.referenced_table)
@@ -922,7 +1542,7 @@ pub async fn peek_foreign_key(
     );
 
     let mut query = sqlx::query(&sql);
-    query = bind_param(query, value);
+    query = bind_param(query, value)?;
 
     let start = Instant::now();
     let mut tx_guard = pg.transaction_conn.lock().await;
@@ -938,6 +1558,135 @@ pub async fn peek_foreign_key(
 
 // Query Table (paginated)
 
+/// Primary key columns of a relation in key order, and whether it has a
+/// `ctid` (tables and materialized views; neither views nor CockroachDB).
+const TABLE_ORDER_KEY_SQL: &str = "SELECT \
+     COALESCE((SELECT array_agg(a.attname::text ORDER BY k.ord) \
+        FROM pg_catalog.pg_index i \
+        CROSS JOIN LATERAL unnest(i.indkey::int2[]) WITH ORDINALITY AS k(attnum, ord) \
+        JOIN pg_catalog.pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = k.attnum \
+        WHERE i.indrelid = c.oid AND i.indisprimary), '{}'::text[]), \
+     EXISTS (SELECT 1 FROM pg_catalog.pg_attribute a \
+        WHERE a.attrelid = c.oid AND a.attname = 'ctid') \
+     FROM pg_catalog.pg_class c \
+     JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+     WHERE n.nspname = $1 AND c.relname = $2";
+
+/// Order of a page without sort column: the primary key, else the physical
+/// row position. `ctid` is only unique within one table, so `tableoid`
+/// separates the rows of partitions and inheritance children.
+async fn table_implicit_order(pg: &PgCompatSession, schema: &str, table: &str) -> ImplicitOrder {
+    let query = sqlx::query_as::<_, (Vec<String>, bool)>(TABLE_ORDER_KEY_SQL)
+        .bind(schema)
+        .bind(table);
+    let key = {
+        let mut tx_guard = pg.transaction_conn.lock().await;
+        if let Some(ref mut conn) = *tx_guard {
+            query.fetch_optional(&mut **conn).await
+        } else {
+            query.fetch_optional(&pg.pool).await
+        }
+    };
+
+    match key {
+        Ok(Some((primary_key, has_ctid))) => {
+            ImplicitOrder::new(primary_key, has_ctid.then_some("tableoid, ctid"))
+        }
+        Ok(None) => ImplicitOrder::Unordered,
+        Err(e) => {
+            tracing::debug!("No implicit order for {}.{}: {}", schema, table, e);
+            ImplicitOrder::Unordered
+        }
+    }
+}
+
+/// Columns of a relation in order with their type name, to spot PostGIS
+/// columns. `udt_name` is the extension type, `data_type` only says
+/// "USER-DEFINED".
+const TABLE_COLUMN_TYPES_SQL: &str = "SELECT column_name::text, udt_name::text \
+     FROM information_schema.columns \
+     WHERE table_schema = $1 AND table_name = $2 \
+     ORDER BY ordinal_position";
+
+fn is_spatial_type(type_name: &str) -> bool {
+    type_name.eq_ignore_ascii_case("geometry") || type_name.eq_ignore_ascii_case("geography")
+}
+
+/// Page columns of `table` with PostGIS `geometry` and `geography` columns
+/// selected as WKT through `ST_AsText`, so they come back as readable text
+/// instead of EWKB bytes. Falls back to plain columns if the lookup fails.
+async fn spatial_page_columns(
+    pg: &PgCompatSession,
+    schema: &str,
+    table: &str,
+    options: &TableQueryOptions,
+) -> EngineResult<PageColumns> {
+    let mut page_columns = PageColumns::new(SqlDialect::Postgres, options)?;
+    let query = sqlx::query_as::<_, (String, String)>(TABLE_COLUMN_TYPES_SQL)
+        .bind(schema)
+        .bind(table);
+    let columns = {
+        let mut tx_guard = pg.transaction_conn.lock().await;
+        if let Some(ref mut conn) = *tx_guard {
+            query.fetch_all(&mut **conn).await
+        } else {
+            query.fetch_all(&pg.pool).await
+        }
+    };
+
+    match columns {
+        Ok(columns) => {
+            let converted = columns
+                .iter()
+                .filter(|(_, type_name)| is_spatial_type(type_name))
+                .map(|(name, _)| (name.clone(), format!("ST_AsText({})", quote_ident(name))))
+                .collect();
+            let names = columns.into_iter().map(|(name, _)| name).collect();
+            page_columns.convert_columns(names, converted);
+        }
+        Err(e) => {
+            tracing::debug!("No column types for {}.{}: {}", schema, table, e);
+        }
+    }
+    Ok(page_columns)
+}
+
+/// `SELECT … LIMIT` for `preview_table`, with spatial columns as WKT.
+pub async fn preview_table_sql(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    limit: u32,
+) -> EngineResult<String> {
+    let pg = get_session(sessions, session).await?;
+    let schema = namespace.schema.as_deref().unwrap_or("public");
+    let table_ref = format!("{}.{}", quote_ident(schema), quote_ident(table));
+    let page_columns =
+        spatial_page_columns(&pg, schema, table, &TableQueryOptions::default()).await?;
+    Ok(format!(
+        "SELECT {} FROM {} LIMIT {}",
+        page_columns.select_list(&table_ref),
+        table_ref,
+        limit
+    ))
+}
+
+/// Re-expresses a geometry given as WKT, EWKT or hex EWKB as GeoJSON.
+pub async fn geometry_to_geojson(
+    sessions: &SessionMap,
+    session: SessionId,
+    geometry: &str,
+) -> EngineResult<String> {
+    let pg = get_session(sessions, session).await?;
+    let (geojson,): (Option<String>,) = sqlx::query_as("SELECT ST_AsGeoJSON($1::geometry)")
+        .bind(geometry)
+        .fetch_one(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    geojson.ok_or_else(|| EngineError::validation("Geometry is empty"))
+}
+
 pub async fn query_table(
     sessions: &SessionMap,
     session: SessionId,
@@ -956,13 +1705,14 @@ pub async fn query_table(
     let page = options.effective_page();
     let page_size = options.effective_page_size();
     let offset = options.offset();
+    let page_columns = spatial_page_columns(&pg, schema_name, table, &options).await?;
 
     let mut where_clauses: Vec<String> = Vec::new();
     let mut bind_values: Vec<Value> = Vec::new();
 
     if let Some(filters) = &options.filters {
         for filter in filters {
-            let col_ident = quote_ident(&filter.column);
+            let col_ident = page_columns.expr(&filter.column);
             let param_idx = bind_values.len() + 1;
 
             let clause = match filter.operator {
@@ -1032,7 +1782,7 @@ pub async fn query_table(
 
     if let Some(ref search_term) = options.search {
         if !search_term.trim().is_empty() {
-            let columns_sql = "SELECT column_name, data_type FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2";
+            let columns_sql = "SELECT column_name, data_type, udt_name::text AS udt_name FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2";
             let columns_rows: Vec<PgRow> = {
                 let mut tx_guard = pg.transaction_conn.lock().await;
                 if let Some(ref mut conn) = *tx_guard {
@@ -1059,6 +1809,8 @@ pub async fn query_table(
                 let data_type: String = col_row
                     .try_get("data_type")
                     .map_err(|e| EngineError::execution_error(e.to_string()))?;
+                // `data_type` is "USER-DEFINED" for extension types like citext.
+                let udt_name: String = col_row.try_get("udt_name").unwrap_or_default();
 
                 let is_unsearchable =
                     matches!(data_type.as_str(), "bytea" | "tsvector" | "tsquery");
@@ -1072,14 +1824,8 @@ pub async fn query_table(
 
                 let is_text = matches!(
                     data_type.as_str(),
-                    "text"
-                        | "character varying"
-                        | "character"
-                        | "varchar"
-                        | "char"
-                        | "name"
-                        | "citext"
-                );
+                    "text" | "character varying" | "character" | "varchar" | "char" | "name"
+                ) || is_case_insensitive_type(&udt_name);
                 if is_text {
                     search_clauses.push(format!("{} ILIKE ${}", col_ident, param_idx));
                 } else {
@@ -1099,16 +1845,12 @@ pub async fn query_table(
         format!(" WHERE {}", where_clauses.join(" AND "))
     };
 
-    let order_sql = if let Some(sort_col) = &options.sort_column {
-        let sort_ident = quote_ident(sort_col);
-        let direction = match options.sort_direction.unwrap_or_default() {
-            SortDirection::Asc => "ASC",
-            SortDirection::Desc => "DESC",
-        };
-        format!(" ORDER BY {} {}", sort_ident, direction)
+    let implicit_order = if options.sort_column.is_none() {
+        table_implicit_order(&pg, schema_name, table).await
     } else {
-        String::new()
+        ImplicitOrder::Unordered
     };
+    let order_sql = options.order_by_sql(&implicit_order, |c| page_columns.expr(c));
 
     let count_sql = format!(
         "SELECT COUNT(*)::bigint AS cnt FROM {}{}",
@@ -1116,7 +1858,7 @@ pub async fn query_table(
     );
     let mut count_query = sqlx::query(&count_sql);
     for val in &bind_values {
-        count_query = bind_param(count_query, val);
+        count_query = bind_param(count_query, val)?;
     }
 
     let count_row: PgRow = {
@@ -1135,13 +1877,18 @@ pub async fn query_table(
     let total_rows = total_rows.max(0) as u64;
 
     let data_sql = format!(
-        "SELECT * FROM {}{}{} LIMIT {} OFFSET {}",
-        table_ref, where_sql, order_sql, page_size, offset
+        "SELECT {} FROM {}{}{} LIMIT {} OFFSET {}",
+        page_columns.select_list(&table_ref),
+        table_ref,
+        where_sql,
+        order_sql,
+        page_size,
+        offset
     );
 
     let mut data_query = sqlx::query(&data_sql);
     for val in &bind_values {
-        data_query = bind_param(data_query, val);
+        data_query = bind_param(data_query, val)?;
     }
 
     let pg_rows: Vec<PgRow> = {
@@ -1157,7 +1904,7 @@ pub async fn query_table(
     let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
 
     let result = if pg_rows.is_empty() {
-        let col_meta_sql = "SELECT column_name, data_type, is_nullable FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2 ORDER BY ordinal_position";
+        let col_meta_sql = "SELECT column_name, data_type, udt_name::text AS udt_name, is_nullable FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2 ORDER BY ordinal_position";
         let col_meta_rows: Vec<PgRow> = {
             let mut tx_guard = pg.transaction_conn.lock().await;
             if let Some(ref mut conn) = *tx_guard {
@@ -1181,11 +1928,13 @@ pub async fn query_table(
             .filter_map(|r| {
                 let name: String = r.try_get("column_name").ok()?;
                 let data_type: String = r.try_get("data_type").ok()?;
+                let udt_name: String = r.try_get("udt_name").unwrap_or_default();
                 let is_nullable: String = r.try_get("is_nullable").ok()?;
                 Some(ColumnInfo {
                     name: name.into(),
                     data_type: data_type.into(),
                     nullable: is_nullable == "YES",
+                    case_insensitive: is_case_insensitive_type(&udt_name),
                 })
             })
             .collect();
@@ -1227,6 +1976,7 @@ pub async fn describe_table_core(
     namespace: &Namespace,
     table: &str,
     use_pg_stat: bool,
+    fast_estimate: bool,
 ) -> EngineResult<TableSchema> {
     let pg = get_session(sessions, session).await?;
     let pool = &pg.pool;
@@ -1322,6 +2072,11 @@ pub async fn describe_table_core(
                     nullable: is_nullable == "YES",
                     default_value,
                     is_auto_increment,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 }
             },
         )
@@ -1392,7 +2147,7 @@ pub async fn describe_table_core(
         .map(|v| v <= SMALL_TABLE_MAX_ROWS)
         .unwrap_or(false);
     let small_by_bytes = total_bytes <= SMALL_TABLE_MAX_BYTES;
-    let should_count_exact = small_by_rows || small_by_bytes;
+    let should_count_exact = !fast_estimate && (small_by_rows || small_by_bytes);
 
     let row_count_estimate = if should_count_exact {
         let count_sql = format!(
@@ -1460,10 +2215,168 @@ pub async fn describe_table_core(
         },
         foreign_keys,
         row_count_estimate,
+        row_count_is_estimate: !should_count_exact,
         indexes,
+        partitioning: None,
+        row_security: None,
     })
 }
 
+// Table DDL
+
+/// Rebuilds the `CREATE TABLE` statement of `table` from pg_catalog, followed
+/// by the indexes that do not back a constraint. PostgreSQL has no
+/// server-side equivalent of `SHOW CREATE TABLE`, so columns, constraints and
+/// indexes are reassembled from `format_type`, `pg_get_constraintdef` and
+/// `pg_get_indexdef`. Sequence-backed integer columns are emitted as
+/// `serial` types so the statement does not depend on an existing sequence.
+pub async fn table_ddl(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+) -> EngineResult<String> {
+    let pg = get_session(sessions, session).await?;
+    let pool = &pg.pool;
+    let schema = namespace.schema.as_deref().unwrap_or("public");
+
+    let relid: i64 = sqlx::query_scalar(
+        r#"
+        SELECT c.oid::bigint
+        FROM pg_class c
+        JOIN pg_namespace n ON c.relnamespace = n.oid
+        WHERE n.nspname = $1 AND c.relname = $2 AND c.relkind IN ('r', 'p')
+        "#,
+    )
+    .bind(schema)
+    .bind(table)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| EngineError::execution_error(e.to_string()))?
+    .ok_or_else(|| EngineError::execution_error("Table not found"))?;
+
+    let columns: Vec<(String, String, bool, Option<String>, String, String)> = sqlx::query_as(
+        r#"
+        SELECT a.attname::text,
+               format_type(a.atttypid, a.atttypmod)::text,
+               a.attnotnull,
+               pg_get_expr(d.adbin, d.adrelid)::text,
+               a.attidentity::text,
+               a.attgenerated::text
+        FROM pg_attribute a
+        LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+        WHERE a.attrelid = $1::oid AND a.attnum > 0 AND NOT a.attisdropped
+        ORDER BY a.attnum
+        "#,
+    )
+    .bind(relid)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let constraints: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT conname::text, pg_get_constraintdef(oid)::text
+        FROM pg_constraint
+        WHERE conrelid = $1::oid AND contype IN ('p', 'u', 'x', 'c', 'f')
+        ORDER BY CASE contype
+                     WHEN 'p' THEN 0 WHEN 'u' THEN 1 WHEN 'x' THEN 2
+                     WHEN 'c' THEN 3 ELSE 4
+                 END,
+                 conname
+        "#,
+    )
+    .bind(relid)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let indexes: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT pg_get_indexdef(i.indexrelid)::text
+        FROM pg_index i
+        JOIN pg_class ic ON ic.oid = i.indexrelid
+        WHERE i.indrelid = $1::oid
+          AND NOT EXISTS (
+              SELECT 1 FROM pg_constraint c
+              WHERE c.conindid = i.indexrelid
+                AND c.conrelid = i.indrelid
+                AND c.contype IN ('p', 'u', 'x')
+          )
+        ORDER BY ic.relname
+        "#,
+    )
+    .bind(relid)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let mut lines: Vec<String> = columns
+        .into_iter()
+        .map(
+            |(name, data_type, not_null, default, identity, generated)| {
+                pg_column_definition(
+                    &name,
+                    &data_type,
+                    not_null,
+                    default.as_deref(),
+                    &identity,
+                    &generated,
+                )
+            },
+        )
+        .collect();
+    lines.extend(
+        constraints
+            .into_iter()
+            .map(|(name, definition)| format!("CONSTRAINT {} {}", quote_ident(&name), definition)),
+    );
+
+    let mut ddl = format!(
+        "CREATE TABLE {}.{} (\n    {}\n);\n",
+        quote_ident(schema),
+        quote_ident(table),
+        lines.join(",\n    ")
+    );
+    for index in indexes {
+        ddl.push_str(&index);
+        ddl.push_str(";\n");
+    }
+    Ok(ddl)
+}
+
+/// One column line of a reconstructed `CREATE TABLE`.
+fn pg_column_definition(
+    name: &str,
+    data_type: &str,
+    not_null: bool,
+    default: Option<&str>,
+    identity: &str,
+    generated: &str,
+) -> String {
+    let serial_type = match (data_type, default) {
+        (_, Some(expr)) if !expr.starts_with("nextval(") => None,
+        ("integer", Some(_)) => Some("serial"),
+        ("bigint", Some(_)) => Some("bigserial"),
+        ("smallint", Some(_)) => Some("smallserial"),
+        _ => None,
+    };
+
+    let mut def = format!("{} {}", quote_ident(name), serial_type.unwrap_or(data_type));
+    match (identity, generated, default) {
+        ("a", _, _) => def.push_str(" GENERATED ALWAYS AS IDENTITY"),
+        ("d", _, _) => def.push_str(" GENERATED BY DEFAULT AS IDENTITY"),
+        (_, "s", Some(expr)) => def.push_str(&format!(" GENERATED ALWAYS AS ({expr}) STORED")),
+        (_, "v", Some(expr)) => def.push_str(&format!(" GENERATED ALWAYS AS ({expr}) VIRTUAL")),
+        (_, _, Some(expr)) if serial_type.is_none() => def.push_str(&format!(" DEFAULT {expr}")),
+        _ => {}
+    }
+    if not_null {
+        def.push_str(" NOT NULL");
+    }
+    def
+}
+
 // Namespaces & collections (default PG-compat implementation, matviews included)
 
 /// Default `list_namespaces` implementation: lists every non-system schema in
@@ -1500,6 +2413,22 @@ pub async fn list_namespaces_default(
         .collect())
 }
 
+/// Every database of the server except the templates.
+pub async fn list_databases(
+    sessions: &SessionMap,
+    session: SessionId,
+) -> EngineResult<Vec<String>> {
+    let pg = get_session(sessions, session).await?;
+
+    let rows: Vec<(String,)> =
+        sqlx::query_as("SELECT datname FROM pg_database WHERE NOT datistemplate ORDER BY datname")
+            .fetch_all(&pg.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    Ok(rows.into_iter().map(|(name,)| name).collect())
+}
+
 /// Default `list_collections` implementation: tables, views and materialized
 /// views. Drivers without matview support (CockroachDB) keep their own override.
 pub async fn list_collections_default(
@@ -1580,6 +2509,7 @@ pub async fn list_collections_default(
                 namespace: namespace.clone(),
                 name,
                 collection_type,
+                partition_of: None,
             }
         })
         .collect();
@@ -1587,6 +2517,7 @@ pub async fn list_collections_default(
     Ok(CollectionList {
         collections,
         total_count: count_row.0 as u32,
+        next_cursor: None,
     })
 }
 
@@ -1696,50 +2627,32 @@ pub async fn get_routine_definition(
         RoutineType::Procedure => "p",
     };
 
-    let query = if arguments.is_some() {
-        r#"
-        SELECT p.proname::text, pg_get_functiondef(p.oid)::text, l.lanname::text,
-               pg_get_function_identity_arguments(p.oid)::text, pg_get_function_result(p.oid)::text
-        FROM pg_proc p JOIN pg_namespace n ON p.pronamespace = n.oid
-        LEFT JOIN pg_language l ON p.prolang = l.oid
-        WHERE n.nspname = $1 AND p.proname = $2 AND p.prokind = $3
-        AND pg_get_function_identity_arguments(p.oid) = $4
-        LIMIT 1
-        "#
-    } else {
+    // Every overload is fetched so a missing or stale signature can be told
+    // apart from an ambiguous one.
+    let overloads: Vec<RoutineDefinitionRow> = sqlx::query_as(
         r#"
         SELECT p.proname::text, pg_get_functiondef(p.oid)::text, l.lanname::text,
                pg_get_function_identity_arguments(p.oid)::text, pg_get_function_result(p.oid)::text
         FROM pg_proc p JOIN pg_namespace n ON p.pronamespace = n.oid
         LEFT JOIN pg_language l ON p.prolang = l.oid
         WHERE n.nspname = $1 AND p.proname = $2 AND p.prokind = $3
-        AND ($4::text IS NULL)
-        LIMIT 1
-        "#
-    };
-
-    let args_bind = arguments.unwrap_or("");
+        ORDER BY p.oid
+        "#,
+    )
+    .bind(schema)
+    .bind(routine_name)
+    .bind(kind_filter)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
-    let row: (
-        String,
-        Option<String>,
-        Option<String>,
-        String,
-        Option<String>,
-    ) = sqlx::query_as(query)
-        .bind(schema)
-        .bind(routine_name)
-        .bind(kind_filter)
-        .bind(args_bind)
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| EngineError::execution_error(e.to_string()))?
-        .ok_or_else(|| {
-            EngineError::execution_error(format!(
-                "Routine '{}' not found in schema '{}'",
-                routine_name, schema
-            ))
-        })?;
+    if overloads.is_empty() {
+        return Err(EngineError::execution_error(format!(
+            "Routine '{}' not found in schema '{}'",
+            routine_name, schema
+        )));
+    }
+    let row = select_overload(overloads, routine_name, arguments)?;
 
     let (name, def, lang, args, ret) = row;
     Ok(RoutineDefinition {
@@ -1754,6 +2667,53 @@ pub async fn get_routine_definition(
     })
 }
 
+/// Name, definition, language, identity arguments and result of one `pg_proc` row
+type RoutineDefinitionRow = (
+    String,
+    Option<String>,
+    Option<String>,
+    String,
+    Option<String>,
+);
+
+/// Picks the overload whose identity arguments match `arguments`, ignoring
+/// whitespace and case. Without a signature the routine must not be
+/// overloaded. `overloads` is never empty.
+fn select_overload(
+    mut overloads: Vec<RoutineDefinitionRow>,
+    routine_name: &str,
+    arguments: Option<&str>,
+) -> EngineResult<RoutineDefinitionRow> {
+    let normalize = |args: &str| {
+        args.split_whitespace()
+            .collect::<String>()
+            .to_ascii_lowercase()
+    };
+    let signatures = overloads
+        .iter()
+        .map(|row| format!("{}({})", routine_name, row.3))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    match arguments {
+        Some(arguments) => {
+            let wanted = normalize(arguments);
+            match overloads.iter().position(|row| normalize(&row.3) == wanted) {
+                Some(index) => Ok(overloads.swap_remove(index)),
+                None => Err(EngineError::validation(format!(
+                    "No overload of '{}' takes ({}); available: {}",
+                    routine_name, arguments, signatures
+                ))),
+            }
+        }
+        None if overloads.len() > 1 => Err(EngineError::validation(format!(
+            "'{}' is overloaded, pass the argument signature of one of: {}",
+            routine_name, signatures
+        ))),
+        None => Ok(overloads.swap_remove(0)),
+    }
+}
+
 pub async fn drop_routine(
     sessions: &SessionMap,
     session: SessionId,
@@ -1978,6 +2938,28 @@ pub async fn toggle_trigger(
 
 // Schema operations
 
+/// Runs `CREATE [UNIQUE] INDEX` for `spec` on `table`.
+pub async fn create_index(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    spec: &IndexSpec,
+) -> EngineResult<()> {
+    spec.validate()?;
+    let pg = get_session(sessions, session).await?;
+    let query = generate_create_index(SqlDialect::Postgres, namespace, table, spec);
+    sqlx::query(&query).execute(&pg.pool).await.map_err(|e| {
+        let msg = e.to_string();
+        if msg.contains("already exists") {
+            EngineError::validation(format!("Index '{}' already exists", spec.name))
+        } else {
+            EngineError::execution_error(msg)
+        }
+    })?;
+    Ok(())
+}
+
 pub async fn create_schema(
     sessions: &SessionMap,
     session: SessionId,
@@ -1997,7 +2979,7 @@ pub async fn create_schema(
         tracing::error!("{}: Failed to create schema: {}", driver_label, e);
         let msg = e.to_string();
         if msg.contains("permission denied") {
-            EngineError::auth_failed(format!("Permission denied: {}", msg))
+            EngineError::permission_denied(msg)
         } else if msg.contains("exists") {
             EngineError::validation(format!("Schema '{}' already exists", name))
         } else {
@@ -2027,7 +3009,7 @@ pub async fn drop_schema(
         tracing::error!("{}: Failed to drop schema: {}", driver_label, e);
         let msg = e.to_string();
         if msg.contains("permission denied") {
-            EngineError::auth_failed(format!("Permission denied: {}", msg))
+            EngineError::permission_denied(msg)
         } else if msg.contains("does not exist") {
             EngineError::validation(format!("Schema '{}' does not exist", name))
         } else {
@@ -2039,62 +3021,1447 @@ pub async fn drop_schema(
     Ok(())
 }
 
-// Internal helpers
+// Index usage
+
+/// Non-unique indexes with `idx_scan = 0` in a schema, largest first.
+/// Unique and primary-key indexes are skipped: they enforce constraints even
+/// when never scanned.
+pub const UNUSED_INDEXES_SQL: &str = "SELECT s.indexrelname, s.relname, s.schemaname, \
+     pg_relation_size(s.indexrelid) AS index_size, s.idx_scan \
+     FROM pg_stat_user_indexes s \
+     JOIN pg_index i ON i.indexrelid = s.indexrelid \
+     WHERE s.schemaname = $1 AND s.idx_scan = 0 \
+     AND NOT i.indisunique AND NOT i.indisprimary \
+     ORDER BY index_size DESC, s.relname, s.indexrelname";
+
+pub const UNUSED_INDEXES_NOTE: &str =
+    "Scan counts come from pg_stat_user_indexes and are reset by \
+     pg_stat_reset() or a crash; \"unused\" is relative to the time since the last reset. \
+     Check replicas before dropping: their usage is tracked separately.";
+
+/// Lists indexes with no recorded scans for any full PostgreSQL server.
+pub async fn unused_indexes(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+) -> EngineResult<UnusedIndexReport> {
+    let pg = get_session(sessions, session).await?;
+    let schema = namespace.schema.as_deref().unwrap_or("public");
 
-fn qualified_table_name(namespace: &Namespace, table: &str) -> String {
-    if let Some(schema) = &namespace.schema {
-        format!("{}.{}", quote_ident(schema), quote_ident(table))
-    } else {
-        quote_ident(table)
-    }
-}
+    let rows: Vec<(String, String, String, i64, i64)> = sqlx::query_as(UNUSED_INDEXES_SQL)
+        .bind(schema)
+        .fetch_all(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
-fn decode_trigger_timing(tg_type: i32) -> TriggerTiming {
-    if tg_type & (1 << 6) != 0 {
-        TriggerTiming::InsteadOf
-    } else if tg_type & (1 << 1) != 0 {
-        TriggerTiming::Before
-    } else {
-        TriggerTiming::After
-    }
-}
+    let indexes = rows
+        .into_iter()
+        .map(
+            |(index_name, table_name, schema, size_bytes, scan_count)| UnusedIndex {
+                index_name,
+                table_name,
+                schema: Some(schema),
+                size_bytes: Some(size_bytes),
+                scan_count,
+            },
+        )
+        .collect();
 
-fn decode_trigger_events(tg_type: i32) -> Vec<TriggerEvent> {
-    let mut events = Vec::new();
-    if tg_type & (1 << 2) != 0 {
-        events.push(TriggerEvent::Insert);
-    }
-    if tg_type & (1 << 3) != 0 {
-        events.push(TriggerEvent::Delete);
-    }
-    if tg_type & (1 << 4) != 0 {
-        events.push(TriggerEvent::Update);
-    }
-    if tg_type & (1 << 5) != 0 {
-        events.push(TriggerEvent::Truncate);
-    }
-    events
+    Ok(UnusedIndexReport {
+        indexes,
+        note: UNUSED_INDEXES_NOTE.to_string(),
+    })
 }
 
-// Connection string builder
+// Syntax validation
 
-pub fn build_pg_connection_string(config: &ConnectionConfig, default_db: &str) -> String {
-    use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+/// Statement kinds PostgreSQL accepts in `PREPARE name AS ...`.
+const PREPARABLE_KEYWORDS: &[&str] = &[
+    "select", "with", "insert", "update", "delete", "merge", "values", "table",
+];
 
-    let db = config.database.as_deref().unwrap_or(default_db);
+const VALIDATE_STATEMENT_NAME: &str = "qore_validate";
 
-    // Explicit ssl_mode wins; otherwise derive a sslmode from the boolean.
-    let ssl_mode =
-        config
-            .ssl_mode
-            .as_deref()
-            .unwrap_or(if config.ssl { "require" } else { "disable" });
+/// Validates `sql` with `PREPARE` + `DEALLOCATE`: the server parses and
+/// plans the statement (so unknown tables and columns are reported too)
+/// without running it. Positions are mapped back onto `sql`.
+pub async fn validate_syntax(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    sql: &str,
+) -> EngineResult<Option<SqlSyntaxError>> {
+    let sql = sql.trim().trim_end_matches(';').trim_end();
+    let keyword = sql
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .find(|word| !word.is_empty())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    if !PREPARABLE_KEYWORDS.contains(&keyword.as_str()) {
+        return Err(EngineError::not_supported(
+            "Only SELECT, INSERT, UPDATE, DELETE, MERGE and VALUES can be validated by the server",
+        ));
+    }
 
-    let encoded_user = utf8_percent_encode(&config.username, NON_ALPHANUMERIC);
+    let pg = get_session(sessions, session).await?;
+    let mut conn = pg
+        .pool
+        .acquire()
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+    apply_namespace_on_conn(&mut conn, &namespace, sql, false).await?;
+
+    let prefix = format!("PREPARE {} AS ", VALIDATE_STATEMENT_NAME);
+    let prepare = format!("{}{}", prefix, sql);
+    if let Err(e) = sqlx::raw_sql(&prepare).execute(&mut *conn).await {
+        let Some(db_error) = e.as_database_error() else {
+            return Err(EngineError::execution_error(e.to_string()));
+        };
+        let offset = db_error
+            .try_downcast_ref::<sqlx::postgres::PgDatabaseError>()
+            .and_then(|pg_error| match pg_error.position() {
+                Some(sqlx::postgres::PgErrorPosition::Original(position)) => {
+                    position.checked_sub(prefix.chars().count())
+                }
+                _ => None,
+            })
+            .filter(|offset| *offset > 0);
+        let (line, column) = match offset {
+            Some(offset) => {
+                let (line, column) = qore_sql::lint::line_column_at(sql, offset);
+                (Some(line), Some(column))
+            }
+            None => (None, None),
+        };
+        return Ok(Some(SqlSyntaxError {
+            message: db_error.message().to_string(),
+            line,
+            column,
+        }));
+    }
+
+    let deallocate = format!("DEALLOCATE {}", VALIDATE_STATEMENT_NAME);
+    if let Err(e) = sqlx::raw_sql(&deallocate).execute(&mut *conn).await {
+        // Never hand a connection holding the named statement back to the
+        // pool: the next validation on it would fail with "already exists".
+        tracing::warn!("DEALLOCATE after validation failed: {}", e);
+        let _ = conn.detach();
+    }
+
+    Ok(None)
+}
+
+// Bloat
+
+/// Table bloat estimated from planner statistics (adapted from the widely
+/// used ioguix query). Needs reasonably fresh ANALYZE data.
+pub const TABLE_BLOAT_ESTIMATE_SQL: &str = "SELECT schemaname::text, tblname::text, \
+     (tblpages * bs)::bigint AS real_size, \
+     GREATEST((tblpages - est_tblpages_ff) * bs, 0)::bigint AS bloat_size, \
+     (CASE WHEN tblpages > 0 AND tblpages - est_tblpages_ff > 0 \
+       THEN 100 * (tblpages - est_tblpages_ff) / tblpages ELSE 0 END)::float8 AS bloat_pct \
+     FROM ( \
+       SELECT ceil(reltuples / ((bs - page_hdr) * fillfactor / (tpl_size * 100))) \
+           + ceil(toasttuples / 4) AS est_tblpages_ff, \
+         tblpages, bs, schemaname, tblname, is_na \
+       FROM ( \
+         SELECT (4 + tpl_hdr_size + tpl_data_size + (2 * ma) \
+             - CASE WHEN tpl_hdr_size % ma = 0 THEN ma ELSE tpl_hdr_size % ma END \
+             - CASE WHEN ceil(tpl_data_size)::int % ma = 0 THEN ma \
+                 ELSE ceil(tpl_data_size)::int % ma END) AS tpl_size, \
+           (heappages + toastpages) AS tblpages, reltuples, toasttuples, bs, page_hdr, \
+           schemaname, tblname, fillfactor, is_na \
+         FROM ( \
+           SELECT tbl.oid AS tblid, ns.nspname AS schemaname, tbl.relname AS tblname, \
+             tbl.reltuples, tbl.relpages AS heappages, \
+             coalesce(toast.relpages, 0) AS toastpages, \
+             coalesce(toast.reltuples, 0) AS toasttuples, \
+             coalesce(substring(array_to_string(tbl.reloptions, ' ') \
+               FROM 'fillfactor=([0-9]+)')::smallint, 100) AS fillfactor, \
+             current_setting('block_size')::numeric AS bs, \
+             CASE WHEN version() ~ 'mingw32|64-bit|x86_64|ppc64|ia64|amd64' \
+               THEN 8 ELSE 4 END AS ma, \
+             24 AS page_hdr, \
+             23 + CASE WHEN max(coalesce(s.null_frac, 0)) > 0 \
+               THEN (7 + count(s.attname)) / 8 ELSE 0::int END AS tpl_hdr_size, \
+             sum((1 - coalesce(s.null_frac, 0)) * coalesce(s.avg_width, 0)) AS tpl_data_size, \
+             bool_or(att.atttypid = 'pg_catalog.name'::regtype) \
+               OR sum(CASE WHEN att.attnum > 0 THEN 1 ELSE 0 END) <> count(s.attname) AS is_na \
+           FROM pg_attribute att \
+           JOIN pg_class tbl ON att.attrelid = tbl.oid \
+           JOIN pg_namespace ns ON ns.oid = tbl.relnamespace \
+           LEFT JOIN pg_stats s ON s.schemaname = ns.nspname \
+             AND s.tablename = tbl.relname AND s.inherited = false AND s.attname = att.attname \
+           LEFT JOIN pg_class toast ON tbl.reltoastrelid = toast.oid \
+           WHERE NOT att.attisdropped AND tbl.relkind IN ('r', 'm') \
+             AND tbl.reltuples >= 0 AND ns.nspname = $1 \
+           GROUP BY 1, 2, 3, 4, 5, 6, 7, 8, 9, 10 \
+         ) AS s \
+       ) AS s2 \
+     ) AS s3 \
+     WHERE NOT is_na \
+     ORDER BY bloat_size DESC, tblname";
+
+/// B-tree index bloat estimated from planner statistics (ioguix).
+pub const INDEX_BLOAT_ESTIMATE_SQL: &str = "SELECT nspname::text, tblname::text, idxname::text, \
+     (bs * relpages)::bigint AS real_size, \
+     GREATEST(bs * (relpages - est_pages_ff), 0)::bigint AS bloat_size, \
+     (CASE WHEN relpages > 0 AND relpages > est_pages_ff \
+       THEN 100 * (relpages - est_pages_ff)::float8 / relpages ELSE 0 END)::float8 AS bloat_pct \
+     FROM ( \
+       SELECT coalesce(1 + ceil(reltuples / floor((bs - pageopqdata - pagehdr) * fillfactor \
+           / (100 * (4 + nulldatahdrwidth)::float))), 0) AS est_pages_ff, \
+         bs, nspname, tblname, idxname, relpages, is_na \
+       FROM ( \
+         SELECT bs, nspname, tblname, idxname, reltuples, relpages, fillfactor, \
+           (index_tuple_hdr_bm + maxalign \
+             - CASE WHEN index_tuple_hdr_bm % maxalign = 0 THEN maxalign \
+                 ELSE index_tuple_hdr_bm % maxalign END \
+             + nulldatawidth + maxalign \
+             - CASE WHEN nulldatawidth = 0 THEN 0 \
+                 WHEN nulldatawidth::integer % maxalign = 0 THEN maxalign \
+                 ELSE nulldatawidth::integer % maxalign END)::numeric AS nulldatahdrwidth, \
+           pagehdr, pageopqdata, is_na \
+         FROM ( \
+           SELECT n.nspname, i.tblname, i.idxname, i.reltuples, i.relpages, i.idxoid, \
+             i.fillfactor, current_setting('block_size')::numeric AS bs, \
+             CASE WHEN version() ~ 'mingw32|64-bit|x86_64|ppc64|ia64|amd64' \
+               THEN 8 ELSE 4 END AS maxalign, \
+             24 AS pagehdr, 16 AS pageopqdata, \
+             CASE WHEN max(coalesce(s.null_frac, 0)) = 0 THEN 8 \
+               ELSE 8 + ((32 + 8 - 1) / 8) END AS index_tuple_hdr_bm, \
+             sum((1 - coalesce(s.null_frac, 0)) * coalesce(s.avg_width, 1024)) AS nulldatawidth, \
+             max(CASE WHEN i.atttypid = 'pg_catalog.name'::regtype THEN 1 ELSE 0 END) > 0 AS is_na \
+           FROM ( \
+             SELECT ct.relname AS tblname, ct.relnamespace, ic.idxname, ic.reltuples, \
+               ic.relpages, ic.idxoid, ic.fillfactor, \
+               coalesce(a1.attname, a2.attname) AS attname, \
+               coalesce(a1.atttypid, a2.atttypid) AS atttypid, \
+               CASE WHEN a1.attnum IS NULL THEN ic.idxname ELSE ct.relname END AS attrelname \
+             FROM ( \
+               SELECT idxname, reltuples, relpages, tbloid, idxoid, fillfactor, indkey, \
+                 generate_series(1, indnatts) AS attpos \
+               FROM ( \
+                 SELECT ci.relname AS idxname, ci.reltuples, ci.relpages, \
+                   i.indrelid AS tbloid, i.indexrelid AS idxoid, \
+                   coalesce(substring(array_to_string(ci.reloptions, ' ') \
+                     FROM 'fillfactor=([0-9]+)')::smallint, 90) AS fillfactor, \
+                   i.indnatts, \
+                   string_to_array(textin(int2vectorout(i.indkey)), ' ')::int[] AS indkey \
+                 FROM pg_index i \
+                 JOIN pg_class ci ON ci.oid = i.indexrelid \
+                 WHERE ci.relam = (SELECT oid FROM pg_am WHERE amname = 'btree') \
+                   AND ci.relpages > 0 \
+               ) AS idx_data \
+             ) AS ic \
+             JOIN pg_class ct ON ct.oid = ic.tbloid \
+             LEFT JOIN pg_attribute a1 ON ic.indkey[ic.attpos] <> 0 \
+               AND a1.attrelid = ic.tbloid AND a1.attnum = ic.indkey[ic.attpos] \
+             LEFT JOIN pg_attribute a2 ON ic.indkey[ic.attpos] = 0 \
+               AND a2.attrelid = ic.idxoid AND a2.attnum = ic.attpos \
+           ) i \
+           JOIN pg_namespace n ON n.oid = i.relnamespace \
+           JOIN pg_stats s ON s.schemaname = n.nspname \
+             AND s.tablename = i.attrelname AND s.attname = i.attname \
+           WHERE n.nspname = $1 \
+           GROUP BY 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11 \
+         ) AS rows_data_stats \
+       ) AS rows_hdr_pdg_stats \
+     ) AS relation_stats \
+     WHERE NOT is_na \
+     ORDER BY bloat_size DESC, tblname, idxname";
+
+/// Exact table bloat: dead tuples plus free space, measured by `pgstattuple`.
+pub const TABLE_BLOAT_PGSTATTUPLE_SQL: &str = "SELECT n.nspname::text, c.relname::text, \
+     st.table_len AS real_size, \
+     (st.dead_tuple_len + st.free_space)::bigint AS bloat_size, \
+     (st.dead_tuple_percent + st.free_percent)::float8 AS bloat_pct \
+     FROM pg_class c \
+     JOIN pg_namespace n ON n.oid = c.relnamespace \
+     CROSS JOIN LATERAL pgstattuple(c.oid::regclass) st \
+     WHERE n.nspname = $1 AND c.relkind IN ('r', 'm') \
+     ORDER BY bloat_size DESC, c.relname";
+
+/// Exact B-tree bloat: leaf density below the index fillfactor (`pgstatindex`).
+pub const INDEX_BLOAT_PGSTATTUPLE_SQL: &str = "SELECT n.nspname::text, t.relname::text, \
+     ci.relname::text, st.index_size AS real_size, \
+     (st.index_size * GREATEST(ff.fillfactor - st.avg_leaf_density, 0) / 100)::bigint \
+       AS bloat_size, \
+     GREATEST(ff.fillfactor - st.avg_leaf_density, 0)::float8 AS bloat_pct \
+     FROM pg_index i \
+     JOIN pg_class ci ON ci.oid = i.indexrelid \
+     JOIN pg_class t ON t.oid = i.indrelid \
+     JOIN pg_namespace n ON n.oid = ci.relnamespace \
+     CROSS JOIN LATERAL (SELECT coalesce(substring(array_to_string(ci.reloptions, ' ') \
+       FROM 'fillfactor=([0-9]+)')::float8, 90) AS fillfactor) ff \
+     CROSS JOIN LATERAL pgstatindex(ci.oid::regclass) st \
+     WHERE n.nspname = $1 \
+       AND ci.relam = (SELECT oid FROM pg_am WHERE amname = 'btree') \
+       AND st.leaf_pages > 0 \
+     ORDER BY bloat_size DESC, t.relname, ci.relname";
+
+pub const PGSTATTUPLE_INSTALLED_SQL: &str =
+    "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'pgstattuple')";
+
+pub const BLOAT_ESTIMATE_NOTE: &str =
+    "Estimated from planner statistics: figures are approximate and depend on a recent \
+     ANALYZE. Install pgstattuple for exact values.";
+
+pub const BLOAT_PGSTATTUPLE_NOTE: &str =
+    "Measured with pgstattuple, which reads every page of the relations: run it off-peak \
+     on large schemas. Table bloat includes free space left by fillfactor.";
+
+/// Queries used for a bloat report, chosen by whether `pgstattuple` is present.
+#[derive(Debug, Clone, Copy)]
+pub struct BloatQueries {
+    pub table_sql: &'static str,
+    pub index_sql: &'static str,
+    pub method: BloatMethod,
+    pub note: &'static str,
+}
+
+pub fn bloat_queries(pgstattuple_installed: bool) -> BloatQueries {
+    if pgstattuple_installed {
+        BloatQueries {
+            table_sql: TABLE_BLOAT_PGSTATTUPLE_SQL,
+            index_sql: INDEX_BLOAT_PGSTATTUPLE_SQL,
+            method: BloatMethod::Pgstattuple,
+            note: BLOAT_PGSTATTUPLE_NOTE,
+        }
+    } else {
+        BloatQueries {
+            table_sql: TABLE_BLOAT_ESTIMATE_SQL,
+            index_sql: INDEX_BLOAT_ESTIMATE_SQL,
+            method: BloatMethod::Estimate,
+            note: BLOAT_ESTIMATE_NOTE,
+        }
+    }
+}
+
+/// Reports table and index bloat for any full PostgreSQL server. Uses
+/// `pgstattuple` when installed, and falls back to the statistics-based
+/// estimate if it fails (typically missing `pg_stat_scan_tables`).
+pub async fn bloat_report(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+) -> EngineResult<BloatReport> {
+    let pg = get_session(sessions, session).await?;
+    let schema = namespace.schema.as_deref().unwrap_or("public");
+
+    let installed: bool = sqlx::query_scalar(PGSTATTUPLE_INSTALLED_SQL)
+        .fetch_one(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    if installed {
+        match fetch_bloat(&pg.pool, schema, bloat_queries(true)).await {
+            Ok(report) => return Ok(report),
+            Err(e) => tracing::warn!("pgstattuple bloat scan failed, using estimates: {}", e),
+        }
+    }
+
+    fetch_bloat(&pg.pool, schema, bloat_queries(false)).await
+}
+
+async fn fetch_bloat(
+    pool: &PgPool,
+    schema: &str,
+    queries: BloatQueries,
+) -> EngineResult<BloatReport> {
+    let tables: Vec<(String, String, i64, i64, f64)> = sqlx::query_as(queries.table_sql)
+        .bind(schema)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let indexes: Vec<(String, String, String, i64, i64, f64)> = sqlx::query_as(queries.index_sql)
+        .bind(schema)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let tables = tables
+        .into_iter()
+        .map(
+            |(schema, table_name, size_bytes, bloat_bytes, bloat_percent)| BloatEntry {
+                schema,
+                table_name,
+                index_name: None,
+                size_bytes,
+                bloat_bytes,
+                bloat_percent,
+            },
+        )
+        .collect();
+
+    let indexes = indexes
+        .into_iter()
+        .map(
+            |(schema, table_name, index_name, size_bytes, bloat_bytes, bloat_percent)| BloatEntry {
+                schema,
+                table_name,
+                index_name: Some(index_name),
+                size_bytes,
+                bloat_bytes,
+                bloat_percent,
+            },
+        )
+        .collect();
+
+    Ok(BloatReport {
+        tables,
+        indexes,
+        method: queries.method,
+        note: queries.note.to_string(),
+    })
+}
+
+// Query progress
+
+/// Text of the statement a backend is running.
+pub const BACKEND_QUERY_SQL: &str = "SELECT query FROM pg_stat_activity WHERE pid = $1";
+
+/// Operation a statement reports progress for, from its leading keywords.
+/// `VACUUM FULL` rewrites the table and reports through the CLUSTER view.
+pub fn progress_operation(sql: &str) -> Option<ProgressOperation> {
+    let upper = skip_leading_comments(sql).to_ascii_uppercase();
+    let (keyword, rest) = split_keyword(&upper);
+    match keyword {
+        "VACUUM" if vacuum_is_full(rest) => Some(ProgressOperation::Cluster),
+        "VACUUM" => Some(ProgressOperation::Vacuum),
+        "ANALYZE" | "ANALYSE" => Some(ProgressOperation::Analyze),
+        "REINDEX" => Some(ProgressOperation::CreateIndex),
+        "CLUSTER" => Some(ProgressOperation::Cluster),
+        "COPY" => Some(ProgressOperation::Copy),
+        "CREATE" => {
+            let (next, rest) = split_keyword(rest);
+            let next = if next == "UNIQUE" {
+                split_keyword(rest).0
+            } else {
+                next
+            };
+            (next == "INDEX").then_some(ProgressOperation::CreateIndex)
+        }
+        _ => None,
+    }
+}
+
+/// `(phase, work_done, work_total)` for a backend in the view matching `operation`.
+pub fn progress_sql(operation: ProgressOperation) -> &'static str {
+    match operation {
+        ProgressOperation::Vacuum => {
+            "SELECT phase, heap_blks_scanned, heap_blks_total \
+             FROM pg_stat_progress_vacuum WHERE pid = $1"
+        }
+        ProgressOperation::Analyze => {
+            "SELECT phase, sample_blks_scanned, sample_blks_total \
+             FROM pg_stat_progress_analyze WHERE pid = $1"
+        }
+        // Block counters are zero outside the table scan; the tuple counters
+        // cover the sort and load phases.
+        ProgressOperation::CreateIndex => {
+            "SELECT phase, \
+             CASE WHEN blocks_total > 0 THEN blocks_done ELSE tuples_done END, \
+             CASE WHEN blocks_total > 0 THEN blocks_total ELSE tuples_total END \
+             FROM pg_stat_progress_create_index WHERE pid = $1"
+        }
+        ProgressOperation::Cluster => {
+            "SELECT phase, heap_blks_scanned, heap_blks_total \
+             FROM pg_stat_progress_cluster WHERE pid = $1"
+        }
+        // No phases for COPY; `bytes_total` is 0 when reading from STDIN.
+        ProgressOperation::Copy => {
+            "SELECT command, bytes_processed, bytes_total \
+             FROM pg_stat_progress_copy WHERE pid = $1"
+        }
+    }
+}
+
+pub fn progress_percent(work_done: Option<i64>, work_total: Option<i64>) -> Option<f64> {
+    match (work_done, work_total) {
+        (Some(done), Some(total)) if total > 0 => {
+            Some((done as f64 / total as f64 * 100.0).clamp(0.0, 100.0))
+        }
+        _ => None,
+    }
+}
+
+fn skip_leading_comments(sql: &str) -> &str {
+    let mut rest = sql.trim_start();
+    loop {
+        if let Some(comment) = rest.strip_prefix("--") {
+            rest = comment.split_once('\n').map_or("", |(_, r)| r).trim_start();
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/").map_or("", |(_, r)| r).trim_start();
+        } else {
+            return rest;
+        }
+    }
+}
+
+fn split_keyword(sql: &str) -> (&str, &str) {
+    let end = sql
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(sql.len());
+    (&sql[..end], sql[end..].trim_start())
+}
+
+/// Whether the options after `VACUUM` (legacy or parenthesized) enable FULL.
+fn vacuum_is_full(options: &str) -> bool {
+    match options.strip_prefix('(') {
+        Some(list) => list
+            .split(')')
+            .next()
+            .unwrap_or_default()
+            .split(',')
+            .any(|option| {
+                let mut parts = option.split_whitespace();
+                parts.next() == Some("FULL") && !matches!(parts.next(), Some("FALSE" | "OFF" | "0"))
+            }),
+        None => split_keyword(options).0 == "FULL",
+    }
+}
+
+/// Progress of the operation running as `query_id`, read from the
+/// `pg_stat_progress_*` view matching its statement.
+pub async fn query_progress(
+    sessions: &SessionMap,
+    session: SessionId,
+    query_id: QueryId,
+) -> EngineResult<QueryProgress> {
+    let pg = get_session(sessions, session).await?;
+
+    let backend_pid = {
+        let active = pg.active_queries.lock().await;
+        match active.get(&query_id) {
+            Some(pid) => *pid,
+            None => return Err(EngineError::execution_error("Query not found")),
+        }
+    };
+
+    // The reserved connection: the main pool may be held by the operation
+    // being monitored.
+    let mut conn = pg
+        .control_pool
+        .acquire()
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+
+    let query: Option<Option<String>> = sqlx::query_scalar(BACKEND_QUERY_SQL)
+        .bind(backend_pid)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let Some(query) = query.flatten() else {
+        return Err(EngineError::execution_error("Query is no longer running"));
+    };
+
+    let operation = progress_operation(&query).ok_or_else(|| {
+        EngineError::not_supported(
+            "Progress is only reported for VACUUM, ANALYZE, CREATE INDEX, REINDEX, CLUSTER and COPY",
+        )
+    })?;
+
+    // No row yet while the operation waits for locks or is about to finish.
+    let row: Option<(Option<String>, Option<i64>, Option<i64>)> =
+        sqlx::query_as(progress_sql(operation))
+            .bind(backend_pid)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let (phase, work_done, work_total) = row.unwrap_or_default();
+
+    Ok(QueryProgress {
+        backend_pid,
+        operation,
+        phase,
+        work_done,
+        work_total,
+        percent: progress_percent(work_done, work_total),
+    })
+}
+
+// Partitioning
+
+/// Strategy code and key definition of a partitioned table (`pg_partitioned_table`).
+pub const PARTITION_KEY_SQL: &str = "SELECT pt.partstrat::text, pg_get_partkeydef(c.oid) \
+     FROM pg_catalog.pg_partitioned_table pt \
+     JOIN pg_catalog.pg_class c ON c.oid = pt.partrelid \
+     JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+     WHERE n.nspname = $1 AND c.relname = $2";
+
+/// Direct partitions of a table with their bounds, the DEFAULT partition last.
+pub const PARTITIONS_SQL: &str = "SELECT n.nspname::text, c.relname::text, \
+     pg_get_expr(c.relpartbound, c.oid) AS bound, c.relkind = 'p' \
+     FROM pg_catalog.pg_inherits i \
+     JOIN pg_catalog.pg_class c ON c.oid = i.inhrelid \
+     JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+     JOIN pg_catalog.pg_class p ON p.oid = i.inhparent \
+     JOIN pg_catalog.pg_namespace pn ON pn.oid = p.relnamespace \
+     WHERE pn.nspname = $1 AND p.relname = $2 AND c.relispartition \
+     ORDER BY bound = 'DEFAULT', c.relname";
+
+/// Maps a `pg_partitioned_table.partstrat` code to a strategy.
+pub fn partition_strategy(code: &str) -> Option<PartitionStrategy> {
+    match code {
+        "r" => Some(PartitionStrategy::Range),
+        "l" => Some(PartitionStrategy::List),
+        "h" => Some(PartitionStrategy::Hash),
+        _ => None,
+    }
+}
+
+/// Returns the partition key and partitions of `table`, or `None` when it is
+/// not a partitioned table.
+pub async fn table_partitioning(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+) -> EngineResult<Option<TablePartitioning>> {
+    let pg = get_session(sessions, session).await?;
+    let schema = namespace.schema.as_deref().unwrap_or("public");
+
+    let key_row: Option<(String, String)> = sqlx::query_as(PARTITION_KEY_SQL)
+        .bind(schema)
+        .bind(table)
+        .fetch_optional(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let Some((code, key)) = key_row else {
+        return Ok(None);
+    };
+    let strategy = partition_strategy(&code).ok_or_else(|| {
+        EngineError::execution_error(format!("Unknown partition strategy '{}'", code))
+    })?;
+
+    let rows: Vec<(String, String, String, bool)> = sqlx::query_as(PARTITIONS_SQL)
+        .bind(schema)
+        .bind(table)
+        .fetch_all(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let partitions = rows
+        .into_iter()
+        .map(|(schema, name, bound, is_partitioned)| TablePartition {
+            schema,
+            name,
+            bound,
+            is_partitioned,
+        })
+        .collect();
+
+    Ok(Some(TablePartitioning {
+        strategy,
+        key,
+        partitions,
+    }))
+}
+
+// Row-level security
+
+/// RLS flags and policies of a table, plus whether the current role escapes
+/// them (superuser / BYPASSRLS, or owner of the table).
+pub const ROW_SECURITY_SQL: &str = "SELECT c.relrowsecurity, c.relforcerowsecurity, \
+     ARRAY(SELECT p.polname::text FROM pg_catalog.pg_policy p \
+           WHERE p.polrelid = c.oid ORDER BY p.polname) AS policies, \
+     COALESCE((SELECT r.rolsuper OR r.rolbypassrls FROM pg_catalog.pg_roles r \
+               WHERE r.rolname = current_user), false) AS bypass_role, \
+     pg_catalog.pg_has_role(c.relowner, 'USAGE') AS is_owner \
+     FROM pg_catalog.pg_class c \
+     JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+     WHERE n.nspname = $1 AND c.relname = $2";
+
+/// Whether the current role may turn `row_security` off.
+pub const ROW_SECURITY_PRIVILEGE_SQL: &str = "SELECT rolsuper OR rolbypassrls \
+     FROM pg_catalog.pg_roles WHERE rolname = current_user";
+
+/// Note attached to table results when policies may hide rows.
+pub const RLS_FILTERING_NOTE: &str = "RLS may be filtering rows";
+
+/// Whether policies filter what a role reads. Superusers and BYPASSRLS roles
+/// are never filtered, the owner only under `FORCE ROW LEVEL SECURITY`.
+pub fn rls_filters_rows(enabled: bool, forced: bool, bypass_role: bool, is_owner: bool) -> bool {
+    enabled && !bypass_role && (forced || !is_owner)
+}
+
+/// Returns the row-level security state of `table`, or `None` when there is
+/// no such relation.
+pub async fn table_row_security(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+) -> EngineResult<Option<TableRowSecurity>> {
+    let pg = get_session(sessions, session).await?;
+    let schema = namespace.schema.as_deref().unwrap_or("public");
+
+    let row: Option<(bool, bool, Vec<String>, bool, bool)> = sqlx::query_as(ROW_SECURITY_SQL)
+        .bind(schema)
+        .bind(table)
+        .fetch_optional(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    Ok(row.map(
+        |(enabled, forced, policies, bypass_role, is_owner)| TableRowSecurity {
+            enabled,
+            forced,
+            policies,
+            filtering: rls_filters_rows(enabled, forced, bypass_role, is_owner)
+                && !pg.row_security.is_bypassed(),
+        },
+    ))
+}
+
+/// Sets `row_security = off` (or back to the server default) on every
+/// connection of the session, including an open transaction. Bypassing is
+/// refused for roles without superuser or BYPASSRLS, for which PostgreSQL
+/// would fail every query a policy applies to.
+pub async fn set_row_security_bypass(
+    sessions: &SessionMap,
+    session: SessionId,
+    bypass: bool,
+) -> EngineResult<()> {
+    let pg = get_session(sessions, session).await?;
+
+    if bypass {
+        let privileged: Option<bool> = sqlx::query_scalar(ROW_SECURITY_PRIVILEGE_SQL)
+            .fetch_optional(&pg.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        if !privileged.unwrap_or(false) {
+            return Err(EngineError::validation(
+                "Bypassing row-level security requires a superuser or BYPASSRLS role",
+            ));
+        }
+    }
+
+    // Hold the transaction lock so no statement runs between the toggle and
+    // the transaction connection catching up. Pooled connections sync on
+    // their next acquire.
+    let mut tx = pg.transaction_conn.lock().await;
+    pg.row_security.set_bypass(bypass);
+    if let Some(conn) = tx.as_mut() {
+        sqlx::query(row_security_statement(bypass))
+            .execute(&mut **conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    }
+    Ok(())
+}
+
+// Scheduled jobs (pg_cron)
+
+/// Lists the pg_cron jobs of the current database, or none when pg_cron is
+/// not installed there.
+pub async fn list_scheduled_jobs(
+    sessions: &SessionMap,
+    session: SessionId,
+) -> EngineResult<ScheduledJobList> {
+    let pg = get_session(sessions, session).await?;
+
+    let (has_job_table, has_run_details): (bool, bool) =
+        sqlx::query_as(scheduled_jobs::PG_CRON_PRESENCE_SQL)
+            .fetch_one(&pg.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let Some(sql) = scheduled_jobs::pg_cron_jobs_sql(has_job_table, has_run_details) else {
+        return Ok(ScheduledJobList::unavailable());
+    };
+
+    type JobRow = (
+        String,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        bool,
+    );
+    let rows: Vec<JobRow> = sqlx::query_as(&sql)
+        .fetch_all(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let jobs = rows
+        .into_iter()
+        .map(
+            |(id, name, database, schedule, command, last_run, enabled)| ScheduledJob {
+                id,
+                name,
+                database,
+                schedule,
+                command,
+                last_run,
+                next_run: None,
+                enabled,
+            },
+        )
+        .collect();
+
+    Ok(ScheduledJobList {
+        scheduler: Some(JobScheduler::PgCron),
+        jobs,
+    })
+}
+
+pub async fn set_scheduled_job_enabled(
+    sessions: &SessionMap,
+    session: SessionId,
+    job_id: &str,
+    enabled: bool,
+) -> EngineResult<ScheduledJobOperationResult> {
+    let pg = get_session(sessions, session).await?;
+    let job_id = scheduled_jobs::parse_pg_cron_job_id(job_id)?;
+
+    let start = Instant::now();
+    sqlx::query(scheduled_jobs::PG_CRON_ALTER_JOB_SQL)
+        .bind(job_id)
+        .bind(enabled)
+        .execute(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    Ok(ScheduledJobOperationResult {
+        success: true,
+        executed_command: format!("SELECT cron.alter_job(job_id := {job_id}, active := {enabled})"),
+        message: None,
+        execution_time_ms: start.elapsed().as_millis() as f64,
+    })
+}
+
+/// Runs the command of a pg_cron job on this session. pg_cron has no
+/// run-now, so the command runs as the current user rather than the job's,
+/// and only when the session is connected to the job's database.
+pub async fn run_scheduled_job(
+    sessions: &SessionMap,
+    session: SessionId,
+    job_id: &str,
+) -> EngineResult<ScheduledJobOperationResult> {
+    let pg = get_session(sessions, session).await?;
+    let job_id = scheduled_jobs::parse_pg_cron_job_id(job_id)?;
+
+    let job: Option<(String, String)> = sqlx::query_as(scheduled_jobs::PG_CRON_JOB_COMMAND_SQL)
+        .bind(job_id)
+        .fetch_optional(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let (command, database) =
+        job.ok_or_else(|| EngineError::validation(format!("pg_cron job {job_id} not found")))?;
+
+    let current: String = sqlx::query_scalar("SELECT current_database()")
+        .fetch_one(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    if current != database {
+        return Err(EngineError::validation(format!(
+            "pg_cron job {job_id} runs in database '{database}'; connect to it to run the job now"
+        )));
+    }
+
+    let start = Instant::now();
+    sqlx::raw_sql(&command)
+        .execute(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    Ok(ScheduledJobOperationResult {
+        success: true,
+        executed_command: command,
+        message: None,
+        execution_time_ms: start.elapsed().as_millis() as f64,
+    })
+}
+
+// Server log
+
+/// Reads the log file the logging collector currently writes to. Drivers
+/// whose hosts do not expose the file (see `server_log::log_source_for_driver`)
+/// are refused up front.
+pub async fn read_server_log(
+    sessions: &SessionMap,
+    driver_id: &str,
+    session: SessionId,
+    lines: u32,
+    after: Option<&str>,
+) -> EngineResult<ServerLogChunk> {
+    if server_log::log_source_for_driver(driver_id) != Some(ServerLogSource::PgLogFile) {
+        return Err(EngineError::not_supported(
+            "Reading the server log is not supported by this driver",
+        ));
+    }
+    let after = after.map(PgLogCursor::parse).transpose()?;
+    let pg = get_session(sessions, session).await?;
+
+    let privileged: Option<bool> = sqlx::query_scalar(server_log::PG_LOG_PRIVILEGE_SQL)
+        .fetch_optional(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    if !privileged.unwrap_or(false) {
+        return Err(server_log::insufficient_privileges(
+            ServerLogSource::PgLogFile,
+        ));
+    }
+
+    let file: Option<String> = sqlx::query_scalar(server_log::PG_CURRENT_LOGFILE_SQL)
+        .fetch_one(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let file = file.ok_or_else(|| {
+        EngineError::not_supported(
+            "The server log is not written to a file (logging_collector is off)",
+        )
+    })?;
+    let size: i64 = sqlx::query_scalar(server_log::PG_LOG_FILE_SIZE_SQL)
+        .bind(&file)
+        .fetch_one(&pg.pool)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let read = server_log::pg_log_read(&file, size, lines, after.as_ref());
+    let bytes: Vec<u8> = if read.length > 0 {
+        sqlx::query_scalar(server_log::PG_READ_LOG_SQL)
+            .bind(&file)
+            .bind(read.offset)
+            .bind(read.length)
+            .fetch_one(&pg.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+    } else {
+        Vec::new()
+    };
+
+    let (mut log_lines, consumed) = server_log::split_pg_log(&bytes, read.skip_partial_line);
+    if after.is_none() {
+        let excess = log_lines.len().saturating_sub(lines.max(1) as usize);
+        log_lines.drain(..excess);
+    }
+
+    Ok(ServerLogChunk {
+        source: ServerLogSource::PgLogFile,
+        lines: log_lines,
+        cursor: Some(
+            PgLogCursor {
+                file,
+                offset: read.offset + consumed as i64,
+            }
+            .to_string(),
+        ),
+    })
+}
+
+// COPY export
+
+/// Builds `COPY <source> TO STDOUT WITH (...)` after validating the options.
+/// Every user-provided value ends up quoted, as an identifier or as an
+/// escaped string literal.
+pub fn copy_to_stdout_sql(
+    namespace: &Namespace,
+    source: &CopySource,
+    options: &CopyOptions,
+) -> EngineResult<String> {
+    let target = match source {
+        CopySource::Table { table } => {
+            if table.trim().is_empty() {
+                return Err(EngineError::validation("COPY table name is empty"));
+            }
+            qualified_table_name(namespace, table)
+        }
+        CopySource::Query { query } => {
+            let query = query.trim().trim_end_matches(';').trim_end();
+            if query.is_empty() {
+                return Err(EngineError::validation("COPY query is empty"));
+            }
+            format!("({})", query)
+        }
+    };
+
+    Ok(format!(
+        "COPY {} TO STDOUT WITH ({})",
+        target,
+        copy_options_clause(options)?
+    ))
+}
+
+/// Renders the `WITH (...)` option list of a COPY statement.
+pub fn copy_options_clause(options: &CopyOptions) -> EngineResult<String> {
+    let csv = options.format == CopyFormat::Csv;
+    let mut parts = vec![match options.format {
+        CopyFormat::Csv => "FORMAT csv".to_string(),
+        CopyFormat::Text => "FORMAT text".to_string(),
+    }];
+
+    let delimiter = copy_single_char("DELIMITER", options.delimiter.as_deref())?;
+    if let Some(delimiter) = delimiter {
+        parts.push(format!(
+            "DELIMITER {}",
+            copy_literal(&delimiter.to_string())
+        ));
+    }
+
+    if let Some(null_string) = &options.null_string {
+        if null_string.contains(['\r', '\n']) {
+            return Err(EngineError::validation(
+                "COPY NULL string cannot contain newlines",
+            ));
+        }
+        parts.push(format!("NULL {}", copy_literal(null_string)));
+    }
+
+    if options.header {
+        if !csv {
+            return Err(EngineError::validation(
+                "COPY HEADER requires the CSV format",
+            ));
+        }
+        parts.push("HEADER true".to_string());
+    }
+
+    let quote = copy_single_char("QUOTE", options.quote.as_deref())?;
+    let escape = copy_single_char("ESCAPE", options.escape.as_deref())?;
+    if !csv && (quote.is_some() || escape.is_some() || options.force_quote.is_some()) {
+        return Err(EngineError::validation(
+            "COPY QUOTE, ESCAPE and FORCE_QUOTE require the CSV format",
+        ));
+    }
+    if let Some(quote) = quote {
+        if Some(quote) == delimiter {
+            return Err(EngineError::validation(
+                "COPY DELIMITER and QUOTE must differ",
+            ));
+        }
+        parts.push(format!("QUOTE {}", copy_literal(&quote.to_string())));
+    }
+    if let Some(escape) = escape {
+        parts.push(format!("ESCAPE {}", copy_literal(&escape.to_string())));
+    }
+
+    match &options.force_quote {
+        None => {}
+        Some(CopyForceQuote::All) => parts.push("FORCE_QUOTE *".to_string()),
+        Some(CopyForceQuote::Columns(columns)) => {
+            if columns.is_empty() {
+                return Err(EngineError::validation(
+                    "COPY FORCE_QUOTE needs at least one column",
+                ));
+            }
+            let columns: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+            parts.push(format!("FORCE_QUOTE ({})", columns.join(", ")));
+        }
+    }
+
+    if let Some(encoding) = &options.encoding {
+        let valid = !encoding.is_empty()
+            && encoding
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(EngineError::validation(format!(
+                "Invalid COPY encoding '{}'",
+                encoding
+            )));
+        }
+        parts.push(format!("ENCODING {}", copy_literal(encoding)));
+    }
+
+    Ok(parts.join(", "))
+}
+
+/// COPY only accepts single one-byte characters for DELIMITER, QUOTE and ESCAPE.
+fn copy_single_char(option: &str, value: Option<&str>) -> EngineResult<Option<char>> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii() && c != '\r' && c != '\n' => Ok(Some(c)),
+        _ => Err(EngineError::validation(format!(
+            "COPY {} must be a single one-byte character other than a newline",
+            option
+        ))),
+    }
+}
+
+/// Escaped string literal, safe regardless of `standard_conforming_strings`.
+fn copy_literal(value: &str) -> String {
+    format!("E'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// Streams the raw output of a `COPY ... TO STDOUT` to `sender`.
+///
+/// Runs on a dedicated pool connection so an open transaction is left
+/// untouched. Stops with `Cancelled` once the receiver is dropped.
+pub async fn copy_out(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    source: &CopySource,
+    options: &CopyOptions,
+    sender: CopyOutSender,
+) -> EngineResult<()> {
+    let sql = copy_to_stdout_sql(namespace, source, options)?;
+    let pg = get_session(sessions, session).await?;
+
+    let mut conn = pg
+        .pool
+        .acquire()
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+
+    if let CopySource::Query { query } = source {
+        apply_namespace_on_conn(&mut conn, &Some(namespace.clone()), query, false).await?;
+    }
+
+    let mut stream = conn
+        .copy_out_raw(&sql)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| EngineError::execution_error(e.to_string()))?;
+        if sender.send(chunk.to_vec()).await.is_err() {
+            return Err(EngineError::Cancelled);
+        }
+    }
+
+    Ok(())
+}
+
+// COPY import
+
+/// Builds `COPY <table> (<columns>) FROM STDIN WITH (FORMAT csv, ...)`.
+/// With `header` set the first line is skipped; `columns` must already list
+/// the file columns in order.
+pub fn copy_from_stdin_sql(
+    namespace: &Namespace,
+    table: &str,
+    columns: &[String],
+    options: &BulkImportOptions,
+) -> EngineResult<String> {
+    if table.trim().is_empty() {
+        return Err(EngineError::validation("COPY table name is empty"));
+    }
+    let clause = copy_options_clause(&CopyOptions {
+        format: CopyFormat::Csv,
+        delimiter: Some(options.delimiter.clone()),
+        null_string: options.null_string.clone(),
+        header: options.header,
+        ..Default::default()
+    })?;
+
+    let column_list = if columns.is_empty() {
+        String::new()
+    } else {
+        let quoted: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+        format!(" ({})", quoted.join(", "))
+    };
+
+    Ok(format!(
+        "COPY {}{} FROM STDIN WITH ({})",
+        qualified_table_name(namespace, table),
+        column_list,
+        clause
+    ))
+}
+
+/// Streams CSV bytes from `source` into `table` with `COPY ... FROM STDIN`
+/// and returns the number of rows loaded.
+///
+/// Runs on a dedicated pool connection; COPY is atomic, so a failing row
+/// leaves the table untouched.
+pub async fn copy_in_csv(
+    sessions: &SessionMap,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    columns: &[String],
+    source: CopyInSource,
+    options: &BulkImportOptions,
+) -> EngineResult<u64> {
+    let sql = copy_from_stdin_sql(namespace, table, columns, options)?;
+    let pg = get_session(sessions, session).await?;
+
+    let mut conn = pg
+        .pool
+        .acquire()
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+
+    let mut copy = conn
+        .copy_in_raw(&sql)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let read = copy.read_from(source).await.map(|_| ());
+    if let Err(e) = read {
+        let _ = copy.abort("client failed to read the import file").await;
+        return Err(EngineError::execution_error(e.to_string()));
+    }
+
+    copy.finish()
+        .await
+        .map_err(|e| copy_in_error(&e.to_string(), columns.len()))
+}
+
+/// Turns the server's column-count complaints into a validation error that
+/// says what the import expected.
+fn copy_in_error(message: &str, expected_columns: usize) -> EngineError {
+    let mismatch = message.contains("extra data after last expected column")
+        || message.contains("missing data for column");
+    if mismatch && expected_columns > 0 {
+        EngineError::validation(format!(
+            "CSV column count does not match the {} target columns: {}",
+            expected_columns, message
+        ))
+    } else if mismatch {
+        EngineError::validation(format!(
+            "CSV column count does not match the table: {}",
+            message
+        ))
+    } else {
+        EngineError::execution_error(message.to_string())
+    }
+}
+
+// Notifications (LISTEN / NOTIFY)
+
+/// Longest channel name the server keeps (`NAMEDATALEN - 1`).
+const MAX_CHANNEL_LEN: usize = 63;
+
+/// `LISTEN`s on a connection taken from the session pool for the lifetime of
+/// the subscription. `PgListener` reconnects on its own if the connection
+/// drops, and issues `UNLISTEN *` when it is dropped.
+pub async fn listen(
+    sessions: &SessionMap,
+    session: SessionId,
+    channel: &str,
+    sender: NotificationSender,
+) -> EngineResult<()> {
+    if channel.is_empty() || channel.len() > MAX_CHANNEL_LEN {
+        return Err(EngineError::validation(format!(
+            "Channel name must be 1 to {} bytes long",
+            MAX_CHANNEL_LEN
+        )));
+    }
+    let pg = get_session(sessions, session).await?;
+
+    let mut listener = PgListener::connect_with(&pg.pool)
+        .await
+        .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+    listener
+        .listen(channel)
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    loop {
+        let received = tokio::select! {
+            _ = sender.closed() => return Ok(()),
+            received = listener.recv() => {
+                received.map_err(|e| EngineError::execution_error(e.to_string()))?
+            }
+        };
+        let notification = Notification {
+            channel: received.channel().to_string(),
+            payload: received.payload().to_string(),
+            process_id: received.process_id(),
+        };
+        if sender.send(notification).await.is_err() {
+            return Ok(());
+        }
+    }
+}
+
+// Internal helpers
+
+fn qualified_table_name(namespace: &Namespace, table: &str) -> String {
+    if let Some(schema) = &namespace.schema {
+        format!("{}.{}", quote_ident(schema), quote_ident(table))
+    } else {
+        quote_ident(table)
+    }
+}
+
+fn decode_trigger_timing(tg_type: i32) -> TriggerTiming {
+    if tg_type & (1 << 6) != 0 {
+        TriggerTiming::InsteadOf
+    } else if tg_type & (1 << 1) != 0 {
+        TriggerTiming::Before
+    } else {
+        TriggerTiming::After
+    }
+}
+
+fn decode_trigger_events(tg_type: i32) -> Vec<TriggerEvent> {
+    let mut events = Vec::new();
+    if tg_type & (1 << 2) != 0 {
+        events.push(TriggerEvent::Insert);
+    }
+    if tg_type & (1 << 3) != 0 {
+        events.push(TriggerEvent::Delete);
+    }
+    if tg_type & (1 << 4) != 0 {
+        events.push(TriggerEvent::Update);
+    }
+    if tg_type & (1 << 5) != 0 {
+        events.push(TriggerEvent::Truncate);
+    }
+    events
+}
+
+// Connection string builder
+
+pub fn build_pg_connection_string(config: &ConnectionConfig, default_db: &str) -> String {
+    use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+    let db = config.database.as_deref().unwrap_or(default_db);
+    let ssl_mode = pg_ssl_mode(config);
+
+    let encoded_user = utf8_percent_encode(&config.username, NON_ALPHANUMERIC);
     let encoded_pass = utf8_percent_encode(&config.password, NON_ALPHANUMERIC);
 
-    format!(
+    let mut url = format!(
         "postgres://{}:{}@{}:{}/{}?sslmode={}",
         encoded_user, encoded_pass, config.host, config.port, db, ssl_mode
-    )
+    );
+
+    if ssl_mode != "disable" {
+        let tls = config.tls.as_ref();
+        let certs = [
+            ("sslrootcert", config.tls_ca_cert_path()),
+            ("sslcert", tls.and_then(|t| t.client_cert_path.as_deref())),
+            ("sslkey", tls.and_then(|t| t.client_key_path.as_deref())),
+        ];
+        for (param, path) in certs {
+            if let Some(path) = path.filter(|p| !p.trim().is_empty()) {
+                url.push_str(&format!(
+                    "&{}={}",
+                    param,
+                    utf8_percent_encode(path, NON_ALPHANUMERIC)
+                ));
+            }
+        }
+    }
+
+    url
+}
+
+/// An explicit `tls.verify_mode` wins over the legacy `ssl_mode` string,
+/// which wins over the environment default of `tls_verify_mode`.
+fn pg_ssl_mode(config: &ConnectionConfig) -> &str {
+    let explicit_verify = config.tls.as_ref().is_some_and(|t| t.verify_mode.is_some());
+    match config.ssl_mode.as_deref() {
+        Some(mode) if !explicit_verify => mode,
+        _ if !config.ssl => "disable",
+        _ => match config.tls_verify_mode() {
+            TlsVerifyMode::Full => "verify-full",
+            TlsVerifyMode::CaOnly => "verify-ca",
+            TlsVerifyMode::None => "require",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::types::TlsConfig;
+
+    fn overload(arguments: &str) -> RoutineDefinitionRow {
+        (
+            "add".to_string(),
+            Some(format!("CREATE FUNCTION add({})", arguments)),
+            Some("sql".to_string()),
+            arguments.to_string(),
+            Some("integer".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_select_overload_matches_signature() {
+        let overloads = vec![overload("a integer, b integer"), overload("a text, b text")];
+
+        let row = select_overload(overloads.clone(), "add", Some("A TEXT,b   text")).unwrap();
+        assert_eq!(row.3, "a text, b text");
+
+        let err = select_overload(overloads.clone(), "add", None).unwrap_err();
+        assert!(err.to_string().contains("add(a integer, b integer)"));
+        assert!(select_overload(overloads, "add", Some("a bigint")).is_err());
+    }
+
+    #[test]
+    fn test_select_overload_without_signature() {
+        let row = select_overload(vec![overload("")], "add", None).unwrap();
+        assert_eq!(row.1.as_deref(), Some("CREATE FUNCTION add()"));
+    }
+
+    fn tls_test_config(environment: &str) -> ConnectionConfig {
+        ConnectionConfig {
+            driver: "postgres".to_string(),
+            host: "db.internal".to_string(),
+            port: 5432,
+            username: "app".to_string(),
+            password: String::new(),
+            database: Some("shop".to_string()),
+            ssl: true,
+            ssl_mode: None,
+            environment: environment.to_string(),
+            read_only: false,
+            ssh_tunnel: None,
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        }
+    }
+
+    #[test]
+    fn test_connection_string_tls_defaults_by_environment() {
+        let url = build_pg_connection_string(&tls_test_config("development"), "postgres");
+        assert_eq!(url, "postgres://app:@db.internal:5432/shop?sslmode=require");
+
+        let url = build_pg_connection_string(&tls_test_config("production"), "postgres");
+        assert!(url.ends_with("?sslmode=verify-full"));
+
+        let mut config = tls_test_config("production");
+        config.ssl = false;
+        assert!(build_pg_connection_string(&config, "postgres").ends_with("?sslmode=disable"));
+    }
+
+    #[test]
+    fn test_connection_string_from_tls_config() {
+        let mut config = tls_test_config("production");
+        config.ssl_mode = Some("require".to_string());
+        config.tls = Some(TlsConfig {
+            ca_cert_path: Some("/etc/certs/ca.pem".to_string()),
+            client_cert_path: Some("/etc/certs/client.pem".to_string()),
+            client_key_path: Some("/etc/certs/client.key".to_string()),
+            verify_mode: Some(TlsVerifyMode::CaOnly),
+        });
+
+        let url = build_pg_connection_string(&config, "postgres");
+        assert!(url.contains("?sslmode=verify-ca&"));
+        assert!(url.contains("&sslrootcert=%2Fetc%2Fcerts%2Fca%2Epem"));
+        assert!(url.contains("&sslcert=%2Fetc%2Fcerts%2Fclient%2Epem"));
+        assert!(url.contains("&sslkey=%2Fetc%2Fcerts%2Fclient%2Ekey"));
+
+        let options: sqlx::postgres::PgConnectOptions = url.parse().unwrap();
+        assert!(matches!(
+            options.get_ssl_mode(),
+            sqlx::postgres::PgSslMode::VerifyCa
+        ));
+    }
 }
@@ -9,16 +9,25 @@
 
 use async_trait::async_trait;
 
+use crate::column_stats;
 use crate::drivers::pg_compat::{self, SessionMap};
 use qore_core::error::{EngineError, EngineResult};
-use qore_core::traits::{DataEngine, StreamSender};
+use qore_core::traits::{
+    CopyInSource, CopyOutSender, DataEngine, NotificationSender, StreamSender,
+};
 use qore_core::types::{
-    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType,
-    ConnectionConfig, ForeignKey, MaintenanceOperationInfo, MaintenanceRequest, MaintenanceResult,
-    Namespace, PaginatedQueryResult, QueryId, QueryResult, RoutineDefinition, RoutineList,
-    RoutineListOptions, RoutineOperationResult, RoutineType, RowData, SessionId, TableQueryOptions,
-    TableSchema, TriggerDefinition, TriggerList, TriggerListOptions, TriggerOperationResult, Value,
+    BloatReport, BulkImportOptions, CancelSupport, Collection, CollectionList,
+    CollectionListOptions, CollectionType, ColumnStats, ConnectionConfig, CopyOptions, CopySource,
+    DescribeTableOptions, ExplainPlan, ForeignKey, IndexSpec, IsolationLevel,
+    MaintenanceOperationInfo, MaintenanceRequest, MaintenanceResult, Namespace,
+    PaginatedQueryResult, PoolStats, QueryId, QueryProgress, QueryResult, RoutineDefinition,
+    RoutineList, RoutineListOptions, RoutineOperationResult, RoutineType, RowData,
+    ScheduledJobList, ScheduledJobOperationResult, ServerInfo, ServerLogChunk, SessionId,
+    SqlSyntaxError, TablePartitioning, TableQueryOptions, TableSchema, TriggerDefinition,
+    TriggerList, TriggerListOptions, TriggerOperationResult, UnusedIndexReport, UpsertResult,
+    Value,
 };
+use qore_sql::generator::SqlDialect;
 
 pub struct PostgresDriver {
     sessions: SessionMap,
@@ -64,10 +73,33 @@ impl DataEngine for PostgresDriver {
         pg_compat::disconnect(&self.sessions, session).await
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         pg_compat::ping(&self.sessions, session).await
     }
 
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        pg_compat::pool_stats(&self.sessions, session)
+            .await
+            .map(Some)
+    }
+
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        pg_compat::server_info(&self.sessions, session).await
+    }
+
+    async fn listen(
+        &self,
+        session: SessionId,
+        channel: &str,
+        sender: NotificationSender,
+    ) -> EngineResult<()> {
+        pg_compat::listen(&self.sessions, session, channel, sender).await
+    }
+
+    fn supports_notifications(&self) -> bool {
+        true
+    }
+
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
         let pg = pg_compat::get_session(&self.sessions, session).await?;
         let pool = &pg.pool;
@@ -96,6 +128,10 @@ impl DataEngine for PostgresDriver {
             .collect())
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        pg_compat::list_databases(&self.sessions, session).await
+    }
+
     // Postgres-specific: materialized views are surfaced alongside tables and views.
     async fn list_collections(
         &self,
@@ -131,19 +167,30 @@ impl DataEngine for PostgresDriver {
         .await
         .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
+        // Partitions are listed right after their parent table.
         let mut query_str = r#"
-            SELECT name, ctype FROM (
-                SELECT table_name AS name,
-                    CASE WHEN table_type = 'VIEW' THEN 'View' ELSE 'Table' END AS ctype
-                FROM information_schema.tables
-                WHERE table_schema = $1
-                AND ($2 IS NULL OR table_name LIKE $3)
+            SELECT name, ctype, parent FROM (
+                SELECT t.table_name::text AS name,
+                    CASE WHEN t.table_type = 'VIEW' THEN 'View' ELSE 'Table' END AS ctype,
+                    (
+                        SELECT p.relname::text
+                        FROM pg_catalog.pg_inherits i
+                        JOIN pg_catalog.pg_class c ON c.oid = i.inhrelid
+                        JOIN pg_catalog.pg_class p ON p.oid = i.inhparent
+                        JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+                        WHERE n.nspname = t.table_schema
+                          AND c.relname = t.table_name
+                          AND c.relispartition
+                    ) AS parent
+                FROM information_schema.tables t
+                WHERE t.table_schema = $1
+                AND ($2 IS NULL OR t.table_name LIKE $3)
                 UNION ALL
-                SELECT matviewname AS name, 'MaterializedView' AS ctype
+                SELECT matviewname::text AS name, 'MaterializedView' AS ctype, NULL::text AS parent
                 FROM pg_matviews
                 WHERE schemaname = $1
                 AND ($2 IS NULL OR matviewname LIKE $3)
-            ) combined ORDER BY name
+            ) combined ORDER BY COALESCE(parent, name), parent IS NOT NULL, name
         "#
         .to_string();
 
@@ -155,7 +202,7 @@ impl DataEngine for PostgresDriver {
             }
         }
 
-        let rows: Vec<(String, String)> = sqlx::query_as(&query_str)
+        let rows: Vec<(String, String, Option<String>)> = sqlx::query_as(&query_str)
             .bind(schema)
             .bind(&search_pattern)
             .bind(&search_pattern)
@@ -165,7 +212,7 @@ impl DataEngine for PostgresDriver {
 
         let collections = rows
             .into_iter()
-            .map(|(name, ctype)| {
+            .map(|(name, ctype, parent)| {
                 let collection_type = match ctype.as_str() {
                     "View" => CollectionType::View,
                     "MaterializedView" => CollectionType::MaterializedView,
@@ -175,6 +222,7 @@ impl DataEngine for PostgresDriver {
                     namespace: namespace.clone(),
                     name,
                     collection_type,
+                    partition_of: parent,
                 }
             })
             .collect();
@@ -182,6 +230,7 @@ impl DataEngine for PostgresDriver {
         Ok(CollectionList {
             collections,
             total_count: count_row.0 as u32,
+            next_cursor: None,
         })
     }
 
@@ -191,7 +240,55 @@ impl DataEngine for PostgresDriver {
         namespace: &Namespace,
         table: &str,
     ) -> EngineResult<TableSchema> {
-        pg_compat::describe_table_core(&self.sessions, session, namespace, table, true).await
+        self.describe_table_with_options(
+            session,
+            namespace,
+            table,
+            &DescribeTableOptions::default(),
+        )
+        .await
+    }
+
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
+    ) -> EngineResult<TableSchema> {
+        let mut schema = pg_compat::describe_table_core(
+            &self.sessions,
+            session,
+            namespace,
+            table,
+            true,
+            options.fast_estimate,
+        )
+        .await?;
+        schema.partitioning =
+            pg_compat::table_partitioning(&self.sessions, session, namespace, table).await?;
+        schema.row_security =
+            pg_compat::table_row_security(&self.sessions, session, namespace, table).await?;
+        Ok(schema)
+    }
+
+    async fn get_table_ddl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        pg_compat::table_ddl(&self.sessions, session, namespace, table).await
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        pg_compat::create_index(&self.sessions, session, namespace, table, spec).await
     }
 
     async fn execute(
@@ -229,6 +326,24 @@ impl DataEngine for PostgresDriver {
         .await
     }
 
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::execute_with_params(
+            &self.sessions,
+            self.driver_id(),
+            session,
+            query,
+            params,
+            query_id,
+        )
+        .await
+    }
+
     async fn execute_stream(
         &self,
         session: SessionId,
@@ -275,16 +390,19 @@ impl DataEngine for PostgresDriver {
         table: &str,
         limit: u32,
     ) -> EngineResult<QueryResult> {
-        let schema = namespace.schema.as_deref().unwrap_or("public");
-        let query = format!(
-            "SELECT * FROM {}.{} LIMIT {}",
-            pg_compat::quote_ident(schema),
-            pg_compat::quote_ident(table),
-            limit
-        );
+        let query =
+            pg_compat::preview_table_sql(&self.sessions, session, namespace, table, limit).await?;
         self.execute(session, &query, QueryId::new()).await
     }
 
+    async fn geometry_to_geojson(
+        &self,
+        session: SessionId,
+        geometry: &str,
+    ) -> EngineResult<String> {
+        pg_compat::geometry_to_geojson(&self.sessions, session, geometry).await
+    }
+
     async fn query_table(
         &self,
         session: SessionId,
@@ -292,7 +410,14 @@ impl DataEngine for PostgresDriver {
         table: &str,
         options: TableQueryOptions,
     ) -> EngineResult<PaginatedQueryResult> {
-        pg_compat::query_table(&self.sessions, session, namespace, table, options).await
+        let mut result =
+            pg_compat::query_table(&self.sessions, session, namespace, table, options).await?;
+        let row_security =
+            pg_compat::table_row_security(&self.sessions, session, namespace, table).await?;
+        if row_security.is_some_and(|rls| rls.filtering) {
+            result.notes.push(pg_compat::RLS_FILTERING_NOTE.to_string());
+        }
+        Ok(result)
     }
 
     async fn peek_foreign_key(
@@ -314,6 +439,24 @@ impl DataEngine for PostgresDriver {
         .await
     }
 
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        column_stats::column_stats(
+            self,
+            SqlDialect::Postgres,
+            session,
+            namespace,
+            table,
+            column,
+        )
+        .await
+    }
+
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         pg_compat::cancel(&self.sessions, session, query_id).await
     }
@@ -338,6 +481,10 @@ impl DataEngine for PostgresDriver {
         true
     }
 
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        pg_compat::ISOLATION_LEVELS
+    }
+
     async fn insert_row(
         &self,
         session: SessionId,
@@ -348,6 +495,29 @@ impl DataEngine for PostgresDriver {
         pg_compat::insert_row(&self.sessions, session, namespace, table, data).await
     }
 
+    async fn upsert_row(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        pg_compat::upsert_row(
+            &self.sessions,
+            session,
+            namespace,
+            table,
+            data,
+            conflict_columns,
+        )
+        .await
+    }
+
+    fn supports_upsert(&self) -> bool {
+        true
+    }
+
     async fn update_row(
         &self,
         session: SessionId,
@@ -369,10 +539,23 @@ impl DataEngine for PostgresDriver {
         pg_compat::delete_row(&self.sessions, session, namespace, table, primary_key).await
     }
 
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::truncate_table(&self.sessions, session, namespace, table).await
+    }
+
     fn supports_mutations(&self) -> bool {
         true
     }
 
+    fn supports_computed_columns(&self) -> bool {
+        true
+    }
+
     fn supports_routines(&self) -> bool {
         true
     }
@@ -519,11 +702,207 @@ impl DataEngine for PostgresDriver {
     fn supports_explain(&self) -> bool {
         true
     }
+
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        pg_compat::explain_query(
+            &self.sessions,
+            self.driver_id(),
+            session,
+            namespace,
+            query,
+            analyze,
+        )
+        .await
+    }
+
+    fn supports_index_usage(&self) -> bool {
+        true
+    }
+
+    fn supports_bloat_report(&self) -> bool {
+        true
+    }
+
+    async fn query_progress(
+        &self,
+        session: SessionId,
+        query_id: QueryId,
+    ) -> EngineResult<QueryProgress> {
+        pg_compat::query_progress(&self.sessions, session, query_id).await
+    }
+
+    fn supports_query_progress(&self) -> bool {
+        true
+    }
+
+    async fn execute_as_role(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        role: &str,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::execute_as_role(
+            &self.sessions,
+            self.driver_id(),
+            session,
+            namespace,
+            role,
+            query,
+            query_id,
+        )
+        .await
+    }
+
+    fn supports_execute_as_role(&self) -> bool {
+        true
+    }
+
+    async fn set_row_security_bypass(&self, session: SessionId, bypass: bool) -> EngineResult<()> {
+        pg_compat::set_row_security_bypass(&self.sessions, session, bypass).await
+    }
+
+    fn supports_row_security(&self) -> bool {
+        true
+    }
+
+    async fn list_scheduled_jobs(&self, session: SessionId) -> EngineResult<ScheduledJobList> {
+        pg_compat::list_scheduled_jobs(&self.sessions, session).await
+    }
+
+    async fn set_scheduled_job_enabled(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        _database: Option<&str>,
+        enabled: bool,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        pg_compat::set_scheduled_job_enabled(&self.sessions, session, job_id, enabled).await
+    }
+
+    async fn run_scheduled_job(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        _database: Option<&str>,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        pg_compat::run_scheduled_job(&self.sessions, session, job_id).await
+    }
+
+    fn supports_scheduled_jobs(&self) -> bool {
+        true
+    }
+
+    async fn read_server_log(
+        &self,
+        session: SessionId,
+        lines: u32,
+        after: Option<&str>,
+    ) -> EngineResult<ServerLogChunk> {
+        pg_compat::read_server_log(&self.sessions, self.driver_id(), session, lines, after).await
+    }
+
+    fn supports_server_log(&self) -> bool {
+        true
+    }
+
+    fn supports_syntax_validation(&self) -> bool {
+        true
+    }
+
+    async fn validate_syntax(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        sql: &str,
+    ) -> EngineResult<Option<SqlSyntaxError>> {
+        pg_compat::validate_syntax(&self.sessions, session, namespace, sql).await
+    }
+
+    async fn unused_indexes(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+    ) -> EngineResult<UnusedIndexReport> {
+        pg_compat::unused_indexes(&self.sessions, session, namespace).await
+    }
+
+    async fn copy_out(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        source: &CopySource,
+        options: &CopyOptions,
+        sender: CopyOutSender,
+    ) -> EngineResult<()> {
+        pg_compat::copy_out(&self.sessions, session, namespace, source, options, sender).await
+    }
+
+    fn supports_copy_export(&self) -> bool {
+        true
+    }
+
+    async fn copy_in_csv(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        columns: &[String],
+        source: CopyInSource,
+        options: &BulkImportOptions,
+    ) -> EngineResult<u64> {
+        pg_compat::copy_in_csv(
+            &self.sessions,
+            session,
+            namespace,
+            table,
+            columns,
+            source,
+            options,
+        )
+        .await
+    }
+
+    fn supports_copy_import(&self) -> bool {
+        true
+    }
+
+    async fn bloat_report(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+    ) -> EngineResult<BloatReport> {
+        pg_compat::bloat_report(&self.sessions, session, namespace).await
+    }
+
+    fn supports_partitions(&self) -> bool {
+        true
+    }
+
+    async fn table_partitioning(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<Option<TablePartitioning>> {
+        pg_compat::table_partitioning(&self.sessions, session, namespace, table).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qore_core::types::{
+        BloatMethod, CopyForceQuote, CopyFormat, PartitionStrategy, ProgressOperation,
+        UpsertColumns,
+    };
 
     fn make_config(username: &str, password: &str) -> ConnectionConfig {
         ConnectionConfig {
@@ -546,9 +925,32 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 
+    #[test]
+    fn test_classify_error() {
+        let err = pg_compat::classify_error(
+            "error returned from database: permission denied for table orders".to_string(),
+        );
+        assert!(matches!(err, EngineError::PermissionDenied { .. }));
+        let err = pg_compat::classify_error(
+            "error returned from database: syntax error at or near \"SELEC\"".to_string(),
+        );
+        assert!(matches!(err, EngineError::SyntaxError { .. }));
+        let err = pg_compat::classify_error(
+            "error returned from database: relation \"nope\" does not exist".to_string(),
+        );
+        assert!(matches!(err, EngineError::ExecutionError { .. }));
+    }
+
     #[test]
     fn test_connection_string_building() {
         let config = make_config("user", "pass");
@@ -577,4 +979,894 @@ mod tests {
         assert!(conn_str.contains("user%40domain"));
         assert!(conn_str.contains("@localhost:5432"));
     }
+
+    #[test]
+    fn test_unused_indexes_query() {
+        let sql = pg_compat::UNUSED_INDEXES_SQL;
+        assert!(sql.contains("FROM pg_stat_user_indexes"));
+        assert!(sql.contains("s.idx_scan = 0"));
+        assert!(sql.contains("pg_relation_size(s.indexrelid)"));
+        assert!(sql.contains("NOT i.indisunique AND NOT i.indisprimary"));
+        assert!(sql.contains("s.schemaname = $1"));
+    }
+
+    #[test]
+    fn test_bloat_queries_estimate_without_pgstattuple() {
+        let queries = pg_compat::bloat_queries(false);
+        assert_eq!(queries.method, BloatMethod::Estimate);
+        assert!(queries.table_sql.contains("LEFT JOIN pg_stats s"));
+        assert!(queries.table_sql.contains("current_setting('block_size')"));
+        assert!(queries.table_sql.contains("ns.nspname = $1"));
+        assert!(queries.index_sql.contains("amname = 'btree'"));
+        assert!(queries.index_sql.contains("n.nspname = $1"));
+        for sql in [queries.table_sql, queries.index_sql] {
+            assert!(!sql.contains("pgstat"));
+            assert!(sql.contains("ORDER BY bloat_size DESC"));
+        }
+    }
+
+    #[test]
+    fn test_bloat_queries_exact_with_pgstattuple() {
+        let queries = pg_compat::bloat_queries(true);
+        assert_eq!(queries.method, BloatMethod::Pgstattuple);
+        assert!(queries.table_sql.contains("pgstattuple(c.oid::regclass)"));
+        assert!(queries.index_sql.contains("pgstatindex(ci.oid::regclass)"));
+        for sql in [queries.table_sql, queries.index_sql] {
+            assert!(!sql.contains("pg_stats "));
+            assert!(sql.contains("n.nspname = $1"));
+            assert!(sql.contains("ORDER BY bloat_size DESC"));
+        }
+    }
+
+    #[test]
+    fn test_progress_view_selected_by_operation() {
+        let cases = [
+            ("VACUUM public.events", ProgressOperation::Vacuum),
+            (
+                "vacuum (verbose, analyze) events",
+                ProgressOperation::Vacuum,
+            ),
+            ("VACUUM (FULL false) events", ProgressOperation::Vacuum),
+            ("VACUUM FULL events", ProgressOperation::Cluster),
+            ("VACUUM (ANALYZE, FULL) events", ProgressOperation::Cluster),
+            (
+                "CLUSTER events USING events_pkey",
+                ProgressOperation::Cluster,
+            ),
+            ("ANALYZE events", ProgressOperation::Analyze),
+            (
+                "-- nightly\nCREATE INDEX CONCURRENTLY idx ON events (ts)",
+                ProgressOperation::CreateIndex,
+            ),
+            (
+                "/* dedupe */ create unique index idx on events (id)",
+                ProgressOperation::CreateIndex,
+            ),
+            ("REINDEX TABLE events", ProgressOperation::CreateIndex),
+            ("COPY events FROM STDIN", ProgressOperation::Copy),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(pg_compat::progress_operation(sql), Some(expected), "{sql}");
+        }
+        assert_eq!(pg_compat::progress_operation("SELECT * FROM events"), None);
+        assert_eq!(
+            pg_compat::progress_operation("CREATE TABLE t (id int)"),
+            None
+        );
+
+        let views = [
+            (ProgressOperation::Vacuum, "pg_stat_progress_vacuum"),
+            (ProgressOperation::Analyze, "pg_stat_progress_analyze"),
+            (
+                ProgressOperation::CreateIndex,
+                "pg_stat_progress_create_index",
+            ),
+            (ProgressOperation::Cluster, "pg_stat_progress_cluster"),
+            (ProgressOperation::Copy, "pg_stat_progress_copy"),
+        ];
+        for (operation, view) in views {
+            let sql = pg_compat::progress_sql(operation);
+            assert!(
+                sql.contains(&format!("FROM {view} WHERE pid = $1")),
+                "{sql}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_progress_percent() {
+        assert_eq!(pg_compat::progress_percent(Some(25), Some(100)), Some(25.0));
+        assert_eq!(pg_compat::progress_percent(Some(0), Some(0)), None);
+        assert_eq!(pg_compat::progress_percent(None, None), None);
+    }
+
+    #[test]
+    fn test_session_setup_statements() {
+        let mut config = make_config("user", "pass");
+        assert!(pg_compat::session_setup_statements(&config).is_empty());
+
+        config.statement_timeout_ms = Some(30000);
+        config.lock_timeout_ms = Some(5000);
+        assert_eq!(
+            pg_compat::session_setup_statements(&config),
+            vec!["SET statement_timeout = 30000", "SET lock_timeout = 5000"]
+        );
+
+        config.default_isolation_level = Some(IsolationLevel::Serializable);
+        assert_eq!(
+            pg_compat::session_setup_statements(&config).last().unwrap(),
+            "SET SESSION CHARACTERISTICS AS TRANSACTION ISOLATION LEVEL SERIALIZABLE"
+        );
+
+        config.read_only = true;
+        assert_eq!(
+            pg_compat::session_setup_statements(&config).last().unwrap(),
+            "SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY"
+        );
+    }
+
+    /// Live: a read-only session rejects writes at the server, not just in
+    /// the UI.
+    #[tokio::test]
+    async fn test_read_only_session_rejects_insert() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+
+        let driver = PostgresDriver::new();
+        let writer = driver.connect(&config).await.unwrap();
+        for sql in [
+            "DROP TABLE IF EXISTS qoredb_read_only",
+            "CREATE TABLE qoredb_read_only (id integer PRIMARY KEY)",
+        ] {
+            driver.execute(writer, sql, QueryId::new()).await.unwrap();
+        }
+
+        config.read_only = true;
+        let reader = driver.connect(&config).await.unwrap();
+        let insert = driver
+            .execute(
+                reader,
+                "INSERT INTO qoredb_read_only VALUES (1)",
+                QueryId::new(),
+            )
+            .await;
+        driver.disconnect(reader).await.unwrap();
+
+        driver
+            .execute(writer, "DROP TABLE qoredb_read_only", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(writer).await.unwrap();
+
+        let err = insert.expect_err("INSERT must fail on a read-only session");
+        assert!(err.to_string().contains("read-only"), "{err}");
+    }
+
+    /// Live: a connection pinned by an open transaction is reported as in use.
+    #[tokio::test]
+    async fn test_pool_stats_counts_transaction_connection() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+        config.pool_max_connections = Some(2);
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        driver.begin_transaction(session).await.unwrap();
+
+        let stats = driver.pool_stats(session).await.unwrap().unwrap();
+        driver.rollback(session).await.unwrap();
+        driver.disconnect(session).await.unwrap();
+
+        assert_eq!(stats.max, 2);
+        assert_eq!(stats.in_use, 1);
+        assert_eq!(stats.size, stats.idle + stats.in_use);
+    }
+
+    /// Live: the reconstructed DDL recreates an equivalent table after the
+    /// original is dropped.
+    #[tokio::test]
+    async fn test_get_table_ddl_recreates_table() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::with_schema("postgres", "public");
+        for sql in [
+            "DROP TABLE IF EXISTS qoredb_ddl",
+            "CREATE TABLE qoredb_ddl (
+                id serial PRIMARY KEY,
+                code varchar(40) NOT NULL,
+                price numeric(10,2) DEFAULT 0,
+                tags text[],
+                CONSTRAINT qoredb_ddl_code_key UNIQUE (code),
+                CONSTRAINT qoredb_ddl_price_check CHECK (price >= 0)
+            )",
+            "CREATE INDEX qoredb_ddl_price_idx ON qoredb_ddl (price)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+
+        let ddl = driver
+            .get_table_ddl(session, &namespace, "qoredb_ddl")
+            .await
+            .unwrap();
+        driver
+            .execute(session, "DROP TABLE qoredb_ddl", QueryId::new())
+            .await
+            .unwrap();
+        for statement in ddl.split(";\n").filter(|s| !s.trim().is_empty()) {
+            driver
+                .execute(session, statement, QueryId::new())
+                .await
+                .unwrap_or_else(|e| panic!("{statement}: {e}"));
+        }
+
+        let schema = driver
+            .describe_table(session, &namespace, "qoredb_ddl")
+            .await
+            .unwrap();
+        driver
+            .execute(session, "DROP TABLE qoredb_ddl", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+
+        assert!(ddl.contains("\"id\" serial NOT NULL"), "{ddl}");
+        assert!(ddl.contains("character varying(40) NOT NULL"), "{ddl}");
+        assert!(ddl.contains("text[]"), "{ddl}");
+        assert!(
+            ddl.contains("CONSTRAINT \"qoredb_ddl_price_check\" CHECK"),
+            "{ddl}"
+        );
+        let columns: Vec<&str> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(columns, vec!["id", "code", "price", "tags"]);
+        assert_eq!(schema.primary_key, Some(vec!["id".to_string()]));
+        let mut indexes: Vec<&str> = schema.indexes.iter().map(|i| i.name.as_str()).collect();
+        indexes.sort();
+        assert_eq!(
+            indexes,
+            vec![
+                "qoredb_ddl_code_key",
+                "qoredb_ddl_pkey",
+                "qoredb_ddl_price_idx"
+            ]
+        );
+    }
+
+    /// Live: the connection default isolation level is in effect inside
+    /// transactions opened with `begin_transaction`.
+    #[tokio::test]
+    async fn test_default_isolation_level_applied_on_begin() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+        config.default_isolation_level = Some(IsolationLevel::Serializable);
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        driver.begin_transaction(session).await.unwrap();
+        let result = driver
+            .execute(session, "SHOW transaction_isolation", QueryId::new())
+            .await
+            .unwrap();
+        driver.rollback(session).await.unwrap();
+        driver.disconnect(session).await.unwrap();
+
+        assert!(matches!(
+            &result.rows[0].values[0],
+            Value::Text(level) if level == "serializable"
+        ));
+    }
+
+    /// Live-gated: set `QOREDB_TEST_PG_HOST` (plus `QOREDB_TEST_PG_USER` /
+    /// `QOREDB_TEST_PG_PASSWORD`, default `postgres`) to run against a
+    /// disposable server.
+    #[tokio::test]
+    async fn test_null_params_bind_to_typed_columns() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::with_schema("postgres", "public");
+        for sql in [
+            "DROP TABLE IF EXISTS qoredb_null_params",
+            "CREATE TABLE qoredb_null_params (id integer PRIMARY KEY, label text, qty integer)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+
+        let mut data = RowData::new();
+        data.columns.insert("id".into(), Value::Int(1));
+        data.columns.insert("label".into(), Value::Null);
+        data.columns.insert("qty".into(), Value::Null);
+        driver
+            .insert_row(session, &namespace, "qoredb_null_params", &data)
+            .await
+            .unwrap();
+
+        let mut pk = RowData::new();
+        pk.columns.insert("id".into(), Value::Int(1));
+        let mut update = RowData::new();
+        update.columns.insert("qty".into(), Value::Int(5));
+        driver
+            .update_row(session, &namespace, "qoredb_null_params", &pk, &update)
+            .await
+            .unwrap();
+        update.columns.insert("qty".into(), Value::Null);
+        update.columns.insert("label".into(), Value::Null);
+        let result = driver
+            .update_row(session, &namespace, "qoredb_null_params", &pk, &update)
+            .await
+            .unwrap();
+        assert_eq!(result.affected_rows, Some(1));
+
+        driver
+            .execute(session, "DROP TABLE qoredb_null_params", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    fn copy_sql(source: CopySource, options: CopyOptions) -> EngineResult<String> {
+        let namespace = Namespace::with_schema("app", "public");
+        pg_compat::copy_to_stdout_sql(&namespace, &source, &options)
+    }
+
+    #[test]
+    fn test_copy_sql_defaults_to_csv() {
+        let sql = copy_sql(
+            CopySource::Table {
+                table: "users".into(),
+            },
+            CopyOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(sql, "COPY \"public\".\"users\" TO STDOUT WITH (FORMAT csv)");
+    }
+
+    #[test]
+    fn test_copy_sql_renders_all_csv_options() {
+        let options = CopyOptions {
+            delimiter: Some(";".into()),
+            null_string: Some("\\N".into()),
+            quote: Some("'".into()),
+            escape: Some("\\".into()),
+            force_quote: Some(CopyForceQuote::Columns(vec![
+                "na\"me".into(),
+                "email".into(),
+            ])),
+            header: true,
+            encoding: Some("LATIN1".into()),
+            ..CopyOptions::default()
+        };
+        let sql = copy_sql(
+            CopySource::Query {
+                query: "SELECT * FROM users;  ".into(),
+            },
+            options,
+        )
+        .unwrap();
+        assert_eq!(
+            sql,
+            "COPY (SELECT * FROM users) TO STDOUT WITH (FORMAT csv, DELIMITER E';', \
+             NULL E'\\\\N', HEADER true, QUOTE E'\\'', ESCAPE E'\\\\', \
+             FORCE_QUOTE (\"na\"\"me\", \"email\"), ENCODING E'LATIN1')"
+        );
+    }
+
+    #[test]
+    fn test_copy_sql_force_quote_all() {
+        let options = CopyOptions {
+            force_quote: Some(CopyForceQuote::All),
+            ..CopyOptions::default()
+        };
+        let clause = pg_compat::copy_options_clause(&options).unwrap();
+        assert_eq!(clause, "FORMAT csv, FORCE_QUOTE *");
+    }
+
+    #[test]
+    fn test_copy_sql_rejects_invalid_options() {
+        let invalid = [
+            CopyOptions {
+                delimiter: Some("||".into()),
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                delimiter: Some("\n".into()),
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                quote: Some("é".into()),
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                delimiter: Some("\"".into()),
+                quote: Some("\"".into()),
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                format: CopyFormat::Text,
+                header: true,
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                format: CopyFormat::Text,
+                force_quote: Some(CopyForceQuote::All),
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                force_quote: Some(CopyForceQuote::Columns(Vec::new())),
+                ..CopyOptions::default()
+            },
+            CopyOptions {
+                encoding: Some("UTF8'; DROP TABLE users; --".into()),
+                ..CopyOptions::default()
+            },
+        ];
+        for options in invalid {
+            assert!(
+                pg_compat::copy_options_clause(&options).is_err(),
+                "accepted {:?}",
+                options
+            );
+        }
+
+        let empty = copy_sql(
+            CopySource::Query {
+                query: " ; ".into(),
+            },
+            CopyOptions::default(),
+        );
+        assert!(empty.is_err());
+    }
+
+    #[test]
+    fn test_copy_from_stdin_sql() {
+        let namespace = Namespace::with_schema("app", "public");
+        let options = BulkImportOptions {
+            delimiter: ";".into(),
+            null_string: Some("NULL".into()),
+            ..BulkImportOptions::default()
+        };
+        let sql = pg_compat::copy_from_stdin_sql(
+            &namespace,
+            "users",
+            &["id".to_string(), "na\"me".to_string()],
+            &options,
+        )
+        .unwrap();
+        assert_eq!(
+            sql,
+            "COPY \"public\".\"users\" (\"id\", \"na\"\"me\") FROM STDIN WITH \
+             (FORMAT csv, DELIMITER E';', NULL E'NULL', HEADER true)"
+        );
+
+        let bad = BulkImportOptions {
+            delimiter: "||".into(),
+            ..BulkImportOptions::default()
+        };
+        assert!(pg_compat::copy_from_stdin_sql(&namespace, "users", &[], &bad).is_err());
+    }
+
+    /// Live: COPY FROM STDIN loads every CSV row and reports the count; a row
+    /// with an extra field fails as a validation error and loads nothing.
+    #[tokio::test]
+    async fn test_copy_in_csv_imports_rows() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::with_schema("postgres", "public");
+        driver
+            .execute(
+                session,
+                "DROP TABLE IF EXISTS qoredb_copy_import",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        driver
+            .execute(
+                session,
+                "CREATE TABLE qoredb_copy_import (id integer PRIMARY KEY, name text)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let csv = "id,name\n1,alice\n2,\"bob, jr\"\n3,\n";
+        let imported = driver
+            .copy_in_csv(
+                session,
+                &namespace,
+                "qoredb_copy_import",
+                &columns,
+                Box::new(std::io::Cursor::new(csv.as_bytes().to_vec())),
+                &BulkImportOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(imported, 3);
+
+        let mismatch = "id,name\n4,dave,extra\n";
+        let err = driver
+            .copy_in_csv(
+                session,
+                &namespace,
+                "qoredb_copy_import",
+                &columns,
+                Box::new(std::io::Cursor::new(mismatch.as_bytes().to_vec())),
+                &BulkImportOptions::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, EngineError::ValidationError { .. }), "{err}");
+
+        let count = driver
+            .execute(
+                session,
+                "SELECT count(*) FROM qoredb_copy_import",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(count.rows[0].values[0], Value::Int(3)));
+
+        driver
+            .execute(session, "DROP TABLE qoredb_copy_import", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    /// Live: with the single pooled connection busy, `cancel` goes through the
+    /// reserved control connection instead of waiting on the pool.
+    #[tokio::test]
+    async fn test_cancel_with_exhausted_pool() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+        config.pool_max_connections = Some(1);
+        config.pool_min_connections = Some(1);
+        config.pool_acquire_timeout_secs = Some(30);
+
+        let driver = std::sync::Arc::new(PostgresDriver::new());
+        let session = driver.connect(&config).await.unwrap();
+        let query_id = QueryId::new();
+
+        let running = {
+            let driver = std::sync::Arc::clone(&driver);
+            tokio::spawn(async move {
+                driver
+                    .execute(session, "SELECT pg_sleep(30)", query_id)
+                    .await
+            })
+        };
+
+        let pg = pg_compat::get_session(&driver.sessions, session)
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if pg.active_queries.lock().await.contains_key(&query_id) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+        assert_eq!(pg.pool.num_idle(), 0);
+
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            driver.cancel(session, Some(query_id)),
+        )
+        .await
+        .expect("cancel waited on the exhausted pool")
+        .unwrap();
+
+        let result = tokio::time::timeout(std::time::Duration::from_secs(5), running)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result.is_err());
+
+        driver.ping(session).await.unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    /// Live: a query running past `query_timeout_secs` is cancelled on the
+    /// server and reported as a timeout; the session stays usable.
+    #[tokio::test]
+    async fn test_query_timeout_cancels_backend() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+        config.query_timeout_secs = Some(1);
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+
+        let started = std::time::Instant::now();
+        let result = driver
+            .execute(session, "SELECT pg_sleep(30)", QueryId::new())
+            .await;
+        assert!(matches!(
+            result,
+            Err(EngineError::Timeout { timeout_ms: 1000 })
+        ));
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+
+        let pg = pg_compat::get_session(&driver.sessions, session)
+            .await
+            .unwrap();
+        assert!(pg.active_queries.lock().await.is_empty());
+
+        let result = driver
+            .execute(session, "SELECT 1", QueryId::new())
+            .await
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+        driver.disconnect(session).await.unwrap();
+    }
+
+    #[test]
+    fn test_upsert_sql() {
+        let data = RowData::new()
+            .with_column("id", Value::Int(1))
+            .with_column("name", Value::Text("a".into()))
+            .with_column("qty", Value::Null);
+        let columns = UpsertColumns::new(&data, &["id".to_string()]).unwrap();
+        let placeholders = vec![
+            "$1".to_string(),
+            "$2".to_string(),
+            "$3::integer".to_string(),
+        ];
+        assert_eq!(
+            pg_compat::upsert_sql("\"public\".\"items\"", &columns, &placeholders),
+            "INSERT INTO \"public\".\"items\" (\"id\", \"name\", \"qty\") \
+             VALUES ($1, $2, $3::integer) ON CONFLICT (\"id\") \
+             DO UPDATE SET \"name\" = EXCLUDED.\"name\", \"qty\" = EXCLUDED.\"qty\" \
+             RETURNING (xmax = 0)"
+        );
+    }
+
+    #[test]
+    fn test_partition_strategy_codes() {
+        assert_eq!(
+            pg_compat::partition_strategy("r"),
+            Some(PartitionStrategy::Range)
+        );
+        assert_eq!(
+            pg_compat::partition_strategy("l"),
+            Some(PartitionStrategy::List)
+        );
+        assert_eq!(
+            pg_compat::partition_strategy("h"),
+            Some(PartitionStrategy::Hash)
+        );
+        assert_eq!(pg_compat::partition_strategy("x"), None);
+    }
+
+    /// Live: a range-partitioned table reports its key and bounds, and its
+    /// partitions are listed right after it.
+    #[tokio::test]
+    async fn test_range_partitioned_table() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::with_schema("postgres", "public");
+        for sql in [
+            "DROP TABLE IF EXISTS qoredb_events CASCADE",
+            "CREATE TABLE qoredb_events (id bigint, created_at date NOT NULL) \
+             PARTITION BY RANGE (created_at)",
+            "CREATE TABLE qoredb_events_2024 PARTITION OF qoredb_events \
+             FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')",
+            "CREATE TABLE qoredb_events_default PARTITION OF qoredb_events DEFAULT",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+
+        let schema = driver
+            .describe_table(session, &namespace, "qoredb_events")
+            .await
+            .unwrap();
+        let partitioning = schema.partitioning.expect("partitioned table");
+        assert_eq!(partitioning.strategy, PartitionStrategy::Range);
+        assert_eq!(partitioning.key, "RANGE (created_at)");
+        let bounds: Vec<(&str, &str)> = partitioning
+            .partitions
+            .iter()
+            .map(|p| (p.name.as_str(), p.bound.as_str()))
+            .collect();
+        assert_eq!(
+            bounds,
+            vec![
+                (
+                    "qoredb_events_2024",
+                    "FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')"
+                ),
+                ("qoredb_events_default", "DEFAULT"),
+            ]
+        );
+
+        let partition = driver
+            .table_partitioning(session, &namespace, "qoredb_events_2024")
+            .await
+            .unwrap();
+        assert!(partition.is_none());
+
+        let options = CollectionListOptions {
+            search: Some("qoredb_events".to_string()),
+            ..Default::default()
+        };
+        let list = driver
+            .list_collections(session, &namespace, options)
+            .await
+            .unwrap();
+        let listed: Vec<(&str, Option<&str>)> = list
+            .collections
+            .iter()
+            .map(|c| (c.name.as_str(), c.partition_of.as_deref()))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                ("qoredb_events", None),
+                ("qoredb_events_2024", Some("qoredb_events")),
+                ("qoredb_events_default", Some("qoredb_events")),
+            ]
+        );
+
+        driver
+            .execute(session, "DROP TABLE qoredb_events CASCADE", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    #[test]
+    fn test_rls_filters_rows() {
+        // enabled, forced, bypass_role, is_owner
+        assert!(pg_compat::rls_filters_rows(true, false, false, false));
+        assert!(!pg_compat::rls_filters_rows(false, false, false, false));
+        assert!(!pg_compat::rls_filters_rows(true, true, true, false));
+        assert!(!pg_compat::rls_filters_rows(true, false, false, true));
+        assert!(pg_compat::rls_filters_rows(true, true, false, true));
+    }
+
+    #[test]
+    fn test_row_security_toggle_statements() {
+        let toggle = pg_compat::RowSecurityToggle::default();
+        assert_eq!(toggle.sync_statement(), None);
+
+        toggle.set_bypass(true);
+        assert!(toggle.is_bypassed());
+        assert_eq!(toggle.sync_statement(), Some("SET row_security = off"));
+
+        toggle.set_bypass(false);
+        assert_eq!(toggle.sync_statement(), Some("RESET row_security"));
+    }
+
+    /// Live: a table with a policy reports RLS, and the bypass reaches the
+    /// pooled connections.
+    #[tokio::test]
+    async fn test_row_security_detection_and_bypass() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let user = std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into());
+        let password =
+            std::env::var("QOREDB_TEST_PG_PASSWORD").unwrap_or_else(|_| "postgres".into());
+        let mut config = make_config(&user, &password);
+        config.host = host;
+        config.database = Some("postgres".to_string());
+
+        let driver = PostgresDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::with_schema("postgres", "public");
+        for sql in [
+            "DROP TABLE IF EXISTS qoredb_rls",
+            "CREATE TABLE qoredb_rls (id integer, owner_name text)",
+            "CREATE POLICY qoredb_rls_own_rows ON qoredb_rls \
+             USING (owner_name = current_user)",
+            "ALTER TABLE qoredb_rls ENABLE ROW LEVEL SECURITY",
+            "ALTER TABLE qoredb_rls FORCE ROW LEVEL SECURITY",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+
+        let schema = driver
+            .describe_table(session, &namespace, "qoredb_rls")
+            .await
+            .unwrap();
+        let rls = schema.row_security.expect("row security reported");
+        assert!(rls.enabled);
+        assert!(rls.forced);
+        assert_eq!(rls.policies, vec!["qoredb_rls_own_rows"]);
+
+        for (bypass, expected) in [(true, "off"), (false, "on")] {
+            driver
+                .set_row_security_bypass(session, bypass)
+                .await
+                .unwrap();
+            let result = driver
+                .execute(session, "SHOW row_security", QueryId::new())
+                .await
+                .unwrap();
+            assert!(
+                matches!(&result.rows[0].values[0], Value::Text(v) if v == expected),
+                "bypass = {bypass}"
+            );
+        }
+
+        driver
+            .execute(session, "DROP TABLE qoredb_rls", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
 }
@@ -3,12 +3,17 @@
 //! PostgreSQL driver helpers
 
 use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use bigdecimal::BigDecimal;
 use bigdecimal::ToPrimitive as BigDecimalToPrimitive;
 use rust_decimal::Decimal;
-use sqlx::postgres::{PgColumn, PgRow, PgTypeKind, PgValueFormat, Postgres};
-use sqlx::{Column, Executor, Row, TypeInfo, ValueRef};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{
+    PgArgumentBuffer, PgColumn, PgRow, PgTypeInfo, PgTypeKind, PgValueFormat, Postgres,
+};
+use sqlx::{Column, Encode, Executor, Row, Type, TypeInfo, ValueRef};
 use uuid::Uuid;
 
 use qore_core::error::{EngineError, EngineResult};
@@ -225,6 +230,316 @@ pub(crate) fn decode_enum_array_binary(
     }
 }
 
+// Network types (inet / cidr / macaddr)
+
+/// `PGSQL_AF_INET` / `PGSQL_AF_INET6` from Postgres' `utils/inet.h`.
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+/// An `inet` or `cidr` value.
+///
+/// Decoded into `Value::Json({"type": "inet"|"cidr", "address", "prefix"})`
+/// so the grid can render and edit address and prefix separately. The same
+/// shape is accepted back by [`bind_param`], which re-encodes it with the
+/// matching Postgres type instead of `jsonb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PgInet {
+    pub addr: IpAddr,
+    pub prefix: u8,
+    pub is_cidr: bool,
+}
+
+impl PgInet {
+    fn max_prefix(addr: &IpAddr) -> u8 {
+        match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    /// Parse the Postgres text form (`10.0.0.1`, `10.0.0.0/8`, `::1/128`).
+    /// A missing prefix means a single host.
+    pub(crate) fn parse(input: &str, is_cidr: bool) -> Result<Self, String> {
+        let input = input.trim();
+        let (addr_str, prefix_str) = match input.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (input, None),
+        };
+        let addr: IpAddr = addr_str
+            .parse()
+            .map_err(|_| format!("'{}' is not a valid IP address", addr_str))?;
+        let max = Self::max_prefix(&addr);
+        let prefix = match prefix_str {
+            Some(p) => p
+                .parse::<u8>()
+                .ok()
+                .filter(|p| *p <= max)
+                .ok_or_else(|| format!("invalid prefix length '{}' (expected 0-{})", p, max))?,
+            None => max,
+        };
+        let inet = Self {
+            addr,
+            prefix,
+            is_cidr,
+        };
+        if is_cidr && inet.has_host_bits() {
+            return Err(format!(
+                "'{}' has bits set to the right of the /{} network mask",
+                input, prefix
+            ));
+        }
+        Ok(inet)
+    }
+
+    fn has_host_bits(&self) -> bool {
+        let (bits, width) = match self.addr {
+            IpAddr::V4(a) => (u32::from(a) as u128, 32u32),
+            IpAddr::V6(a) => (u128::from(a), 128u32),
+        };
+        let host_bits = width - self.prefix as u32;
+        if host_bits == 0 {
+            return false;
+        }
+        let mask = if host_bits == 128 {
+            u128::MAX
+        } else {
+            (1u128 << host_bits) - 1
+        };
+        bits & mask != 0
+    }
+
+    /// Decode the binary wire format: family, bits, is_cidr, length, address.
+    fn from_binary(bytes: &[u8]) -> Option<Self> {
+        let [family, bits, is_cidr, len, addr @ ..] = bytes else {
+            return None;
+        };
+        let addr = match (*family, *len as usize, addr.len()) {
+            (PGSQL_AF_INET, 4, 4) => IpAddr::V4(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3])),
+            (PGSQL_AF_INET6, 16, 16) => {
+                let octets: [u8; 16] = addr.try_into().ok()?;
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => return None,
+        };
+        if *bits > Self::max_prefix(&addr) {
+            return None;
+        }
+        Some(Self {
+            addr,
+            prefix: *bits,
+            is_cidr: *is_cidr != 0,
+        })
+    }
+
+    fn type_name(&self) -> &'static str {
+        if self.is_cidr {
+            "cidr"
+        } else {
+            "inet"
+        }
+    }
+
+    pub(crate) fn to_value(self) -> Value {
+        Value::Json(serde_json::json!({
+            "type": self.type_name(),
+            "address": self.addr.to_string(),
+            "prefix": self.prefix,
+        }))
+    }
+}
+
+impl Type<Postgres> for PgInet {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("inet")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        matches!(ty.name(), "INET" | "CIDR" | "inet" | "cidr")
+    }
+}
+
+impl Encode<'_, Postgres> for PgInet {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let (family, octets): (u8, Vec<u8>) = match self.addr {
+            IpAddr::V4(a) => (PGSQL_AF_INET, a.octets().to_vec()),
+            IpAddr::V6(a) => (PGSQL_AF_INET6, a.octets().to_vec()),
+        };
+        buf.push(family);
+        buf.push(self.prefix);
+        buf.push(self.is_cidr as u8);
+        buf.push(octets.len() as u8);
+        buf.extend_from_slice(&octets);
+        Ok(IsNull::No)
+    }
+
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(PgTypeInfo::with_name(self.type_name()))
+    }
+}
+
+/// A `macaddr` (6 bytes) or `macaddr8` (8 bytes) value, normalized to
+/// lowercase colon-separated hex (`08:00:2b:01:02:03`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PgMacAddr(Vec<u8>);
+
+impl PgMacAddr {
+    /// Parse any of the input forms Postgres accepts (`:`, `-` or `.`
+    /// separators, or none at all).
+    pub(crate) fn parse(input: &str, len: usize) -> Result<Self, String> {
+        let digits: String = input
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, ':' | '-' | '.'))
+            .collect();
+        if digits.len() != len * 2 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "'{}' is not a valid {}-byte MAC address",
+                input.trim(),
+                len
+            ));
+        }
+        let bytes = (0..len)
+            .map(|i| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|e| e.to_string())?;
+        Ok(Self(bytes))
+    }
+
+    fn type_name(&self) -> &'static str {
+        if self.0.len() == 8 {
+            "macaddr8"
+        } else {
+            "macaddr"
+        }
+    }
+
+    pub(crate) fn to_value(&self) -> Value {
+        let address = self
+            .0
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(":");
+        Value::Json(serde_json::json!({
+            "type": self.type_name(),
+            "address": address,
+        }))
+    }
+}
+
+impl Type<Postgres> for PgMacAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("macaddr")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        matches!(ty.name(), "MACADDR" | "MACADDR8" | "macaddr" | "macaddr8")
+    }
+}
+
+impl Encode<'_, Postgres> for PgMacAddr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&self.0);
+        Ok(IsNull::No)
+    }
+
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(PgTypeInfo::with_name(self.type_name()))
+    }
+}
+
+/// Decode an `inet` / `cidr` column from either wire format.
+fn decode_inet(raw: &sqlx::postgres::PgValueRef<'_>, is_cidr: bool) -> Option<Value> {
+    if raw.is_null() {
+        return Some(Value::Null);
+    }
+    let inet = match raw.format() {
+        PgValueFormat::Binary => PgInet::from_binary(raw.as_bytes().ok()?)?,
+        PgValueFormat::Text => PgInet::parse(raw.as_str().ok()?, is_cidr).ok()?,
+    };
+    Some(inet.to_value())
+}
+
+/// Decode a `macaddr` / `macaddr8` column from either wire format.
+fn decode_macaddr(raw: &sqlx::postgres::PgValueRef<'_>, len: usize) -> Option<Value> {
+    if raw.is_null() {
+        return Some(Value::Null);
+    }
+    let mac = match raw.format() {
+        PgValueFormat::Binary => {
+            let bytes = raw.as_bytes().ok()?;
+            if bytes.len() != len {
+                return None;
+            }
+            PgMacAddr(bytes.to_vec())
+        }
+        PgValueFormat::Text => PgMacAddr::parse(raw.as_str().ok()?, len).ok()?,
+    };
+    Some(mac.to_value())
+}
+
+/// A network value coming back from the grid, ready to bind.
+enum NetworkParam {
+    Inet(PgInet),
+    MacAddr(PgMacAddr),
+}
+
+/// Recognize the tagged objects produced by [`PgInet::to_value`] and
+/// [`PgMacAddr::to_value`]. Only objects whose keys are exactly
+/// `type`/`address`(/`prefix`) qualify, so ordinary `jsonb` documents keep
+/// binding as JSON. Malformed addresses are rejected here with a validation
+/// error rather than surfacing as an opaque server-side cast failure.
+fn network_param(json: &serde_json::Value) -> EngineResult<Option<NetworkParam>> {
+    let Some(obj) = json.as_object() else {
+        return Ok(None);
+    };
+    let Some(kind) = obj.get("type").and_then(|t| t.as_str()) else {
+        return Ok(None);
+    };
+    let allowed: &[&str] = match kind {
+        "inet" | "cidr" => &["type", "address", "prefix"],
+        "macaddr" | "macaddr8" => &["type", "address"],
+        _ => return Ok(None),
+    };
+    if !obj.contains_key("address") || obj.keys().any(|k| !allowed.contains(&k.as_str())) {
+        return Ok(None);
+    }
+
+    let address = obj
+        .get("address")
+        .and_then(|a| a.as_str())
+        .ok_or_else(|| EngineError::validation(format!("{} address must be a string", kind)))?;
+
+    match kind {
+        "inet" | "cidr" => {
+            let text = match obj.get("prefix") {
+                None | Some(serde_json::Value::Null) => address.to_string(),
+                Some(serde_json::Value::Number(n)) => format!("{}/{}", address, n),
+                Some(other) => {
+                    return Err(EngineError::validation(format!(
+                        "Invalid {} prefix: {}",
+                        kind, other
+                    )))
+                }
+            };
+            PgInet::parse(&text, kind == "cidr")
+                .map(|inet| Some(NetworkParam::Inet(inet)))
+                .map_err(|e| EngineError::validation(format!("Invalid {} value: {}", kind, e)))
+        }
+        _ => {
+            let len = if kind == "macaddr8" { 8 } else { 6 };
+            PgMacAddr::parse(address, len)
+                .map(|mac| Some(NetworkParam::MacAddr(mac)))
+                .map_err(|e| EngineError::validation(format!("Invalid {} value: {}", kind, e)))
+        }
+    }
+}
+
+/// Postgres types that compare case-insensitively.
+pub(crate) fn is_case_insensitive_type(type_name: &str) -> bool {
+    type_name.eq_ignore_ascii_case("citext")
+}
+
 /// A canonical 8-4-4-4-12 hyphenated UUID string round-trips through `Uuid`.
 /// Anything else (URN form, no-hyphen, padding) is treated as plain text so we
 /// don't misclassify legitimate text columns containing uuid-shaped data.
@@ -241,11 +556,14 @@ fn parse_canonical_uuid(s: &str) -> Option<Uuid> {
 /// rather than `text`. Without this, `WHERE id = $1` on a `uuid` column fails
 /// with "operator does not exist: uuid = text" because reads decode uuid
 /// columns into `Value::Text(uuid.to_string())`.
+///
+/// Tagged network objects (see [`PgInet`] / [`PgMacAddr`]) are validated and
+/// bound as `inet` / `cidr` / `macaddr`; a malformed address is an error.
 pub(crate) fn bind_param<'q>(
     query: sqlx::query::Query<'q, Postgres, sqlx::postgres::PgArguments>,
     value: &'q Value,
-) -> sqlx::query::Query<'q, Postgres, sqlx::postgres::PgArguments> {
-    match value {
+) -> EngineResult<sqlx::query::Query<'q, Postgres, sqlx::postgres::PgArguments>> {
+    Ok(match value {
         Value::Null => query.bind(Option::<String>::None),
         Value::Bool(b) => query.bind(b),
         Value::Int(i) => query.bind(i),
@@ -255,11 +573,42 @@ pub(crate) fn bind_param<'q>(
             None => query.bind(s),
         },
         Value::Bytes(b) => query.bind(b),
-        Value::Json(j) => query.bind(j),
+        Value::Json(j) => match network_param(j)? {
+            Some(NetworkParam::Inet(inet)) => query.bind(inet),
+            Some(NetworkParam::MacAddr(mac)) => query.bind(mac),
+            None => query.bind(j),
+        },
         Value::Array(_) => query.bind(Option::<String>::None),
+    })
+}
+
+/// Placeholder for the `index`-th (1-based) bound parameter.
+///
+/// [`bind_param`] binds `Value::Null` as a `text` NULL, which Postgres refuses
+/// to assign to a non-text column ("column is of type integer but expression
+/// is of type text") and cannot type at all in some contexts ("could not
+/// determine data type of parameter"). When the target column type is known,
+/// the NULL is cast explicitly (`$1::integer`). Hints that do not look like a
+/// type name are ignored rather than interpolated.
+pub(crate) fn param_placeholder(index: usize, value: &Value, type_hint: Option<&str>) -> String {
+    match (value, type_hint) {
+        (Value::Null, Some(hint)) if is_safe_type_name(hint) => format!("${}::{}", index, hint),
+        _ => format!("${}", index),
     }
 }
 
+/// Accepts `format_type()` output such as `integer`, `character varying`,
+/// `timestamp with time zone`, `public."Mood"` or `numeric(10,2)[]`.
+fn is_safe_type_name(name: &str) -> bool {
+    let name = name.trim();
+    !name.is_empty()
+        && name.len() <= 128
+        && !name.contains("--")
+        && name.chars().all(|c| {
+            c.is_alphanumeric() || matches!(c, '_' | ' ' | '.' | '"' | '[' | ']' | '(' | ')' | ',')
+        })
+}
+
 /// Hot-path conversion using a precomputed per-column decoder.
 pub(crate) fn convert_row_with_decoders(
     pg_row: &PgRow,
@@ -488,6 +837,17 @@ pub(crate) fn extract_value(row: &PgRow, idx: usize, enum_labels: &EnumLabelMap)
                         return value;
                     }
                 }
+                _ if matches!(type_info.name(), "INET" | "CIDR") => {
+                    if let Some(value) = decode_inet(&raw, type_info.name() == "CIDR") {
+                        return value;
+                    }
+                }
+                _ if matches!(type_info.name(), "MACADDR" | "MACADDR8") => {
+                    let len = if type_info.name() == "MACADDR8" { 8 } else { 6 };
+                    if let Some(value) = decode_macaddr(&raw, len) {
+                        return value;
+                    }
+                }
                 PgTypeKind::Array(elem_type) => {
                     if matches!(elem_type.kind(), PgTypeKind::Enum(_)) {
                         if let Some(value) = decode_enum_array_binary(&raw, enum_labels) {
@@ -516,14 +876,19 @@ pub(crate) fn extract_value(row: &PgRow, idx: usize, enum_labels: &EnumLabelMap)
 pub(crate) fn get_column_info(row: &PgRow) -> Vec<ColumnInfo> {
     row.columns()
         .iter()
-        .map(|col| ColumnInfo {
-            name: col.name().into(),
-            data_type: col.type_info().name().into(),
-            nullable: true,
-        })
+        .map(|col| column_info(col.name(), col.type_info().name()))
         .collect()
 }
 
+fn column_info(name: &str, type_name: &str) -> ColumnInfo {
+    ColumnInfo {
+        name: name.into(),
+        data_type: type_name.into(),
+        nullable: true,
+        case_insensitive: is_case_insensitive_type(type_name),
+    }
+}
+
 /// Per-column typed decoder. Built once per result from `type_info().name()`;
 #[derive(Clone, Copy)]
 pub(crate) enum PgDecoder {
@@ -538,6 +903,10 @@ pub(crate) enum PgDecoder {
     Text,
     Bytea,
     Json,
+    Inet,
+    Cidr,
+    MacAddr,
+    MacAddr8,
     TimestampTz,
     Timestamp,
     Date,
@@ -566,9 +935,13 @@ impl PgDecoder {
             "FLOAT4" => Self::Float4,
             "NUMERIC" | "MONEY" => Self::Numeric,
             "UUID" => Self::Uuid,
-            "TEXT" | "VARCHAR" | "CHAR" | "BPCHAR" | "NAME" | "CITEXT" => Self::Text,
+            "TEXT" | "VARCHAR" | "CHAR" | "BPCHAR" | "NAME" | "CITEXT" | "citext" => Self::Text,
             "BYTEA" => Self::Bytea,
             "JSON" | "JSONB" => Self::Json,
+            "INET" => Self::Inet,
+            "CIDR" => Self::Cidr,
+            "MACADDR" => Self::MacAddr,
+            "MACADDR8" => Self::MacAddr8,
             "TIMESTAMPTZ" => Self::TimestampTz,
             "TIMESTAMP" => Self::Timestamp,
             "DATE" => Self::Date,
@@ -675,6 +1048,19 @@ impl PgDecoder {
                 Ok(None) => Value::Null,
                 Err(_) => extract_value(row, idx, enum_labels),
             },
+            Self::Inet | Self::Cidr => match row.try_get_raw(idx) {
+                Ok(raw) => decode_inet(&raw, matches!(self, Self::Cidr))
+                    .unwrap_or_else(|| extract_value(row, idx, enum_labels)),
+                Err(_) => extract_value(row, idx, enum_labels),
+            },
+            Self::MacAddr | Self::MacAddr8 => {
+                let len = if matches!(self, Self::MacAddr8) { 8 } else { 6 };
+                match row.try_get_raw(idx) {
+                    Ok(raw) => decode_macaddr(&raw, len)
+                        .unwrap_or_else(|| extract_value(row, idx, enum_labels)),
+                    Err(_) => extract_value(row, idx, enum_labels),
+                }
+            }
             Self::TimestampTz => {
                 if let Ok(opt) = row.try_get::<Option<chrono::DateTime<chrono::Utc>>, _>(idx) {
                     return opt
@@ -757,3 +1143,148 @@ impl PgDecoder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_of(value: Value) -> serde_json::Value {
+        match value {
+            Value::Json(j) => j,
+            other => panic!("expected JSON value, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_param_placeholder_casts_typed_null() {
+        assert_eq!(param_placeholder(1, &Value::Null, Some("text")), "$1::text");
+        assert_eq!(
+            param_placeholder(2, &Value::Null, Some("integer")),
+            "$2::integer"
+        );
+        assert_eq!(
+            param_placeholder(3, &Value::Null, Some("timestamp with time zone")),
+            "$3::timestamp with time zone"
+        );
+        assert_eq!(
+            param_placeholder(4, &Value::Null, Some("public.\"Mood\"")),
+            "$4::public.\"Mood\""
+        );
+        assert_eq!(param_placeholder(5, &Value::Null, None), "$5");
+        assert_eq!(param_placeholder(6, &Value::Int(1), Some("integer")), "$6");
+    }
+
+    #[test]
+    fn test_param_placeholder_ignores_unsafe_hint() {
+        assert_eq!(
+            param_placeholder(1, &Value::Null, Some("text; DROP TABLE t")),
+            "$1"
+        );
+        assert_eq!(param_placeholder(1, &Value::Null, Some("int--")), "$1");
+        assert_eq!(param_placeholder(1, &Value::Null, Some("text'")), "$1");
+        assert_eq!(param_placeholder(1, &Value::Null, Some("")), "$1");
+    }
+
+    #[test]
+    fn test_decode_inet_binary() {
+        // 192.168.1.10/24, inet
+        let bytes = [PGSQL_AF_INET, 24, 0, 4, 192, 168, 1, 10];
+        let inet = PgInet::from_binary(&bytes).unwrap();
+        assert_eq!(
+            json_of(inet.to_value()),
+            serde_json::json!({
+                "type": "inet",
+                "address": "192.168.1.10",
+                "prefix": 24,
+            })
+        );
+
+        // 2001:db8::/32, cidr
+        let mut bytes = vec![PGSQL_AF_INET6, 32, 1, 16];
+        bytes.extend_from_slice(&"2001:db8::".parse::<Ipv6Addr>().unwrap().octets());
+        let cidr = PgInet::from_binary(&bytes).unwrap();
+        assert!(cidr.is_cidr);
+        assert_eq!(cidr.addr.to_string(), "2001:db8::");
+        assert_eq!(cidr.prefix, 32);
+
+        assert!(PgInet::from_binary(&[PGSQL_AF_INET, 33, 0, 4, 1, 2, 3, 4]).is_none());
+        assert!(PgInet::from_binary(&[PGSQL_AF_INET, 24, 0, 4, 1]).is_none());
+    }
+
+    #[test]
+    fn test_parse_inet_text() {
+        let host = PgInet::parse("10.0.0.1", false).unwrap();
+        assert_eq!(host.prefix, 32);
+        assert_eq!(PgInet::parse("::1/128", false).unwrap().prefix, 128);
+
+        assert!(PgInet::parse("10.0.0.0/8", true).is_ok());
+        let err = PgInet::parse("10.0.0.1/8", true).unwrap_err();
+        assert!(err.contains("network mask"));
+        assert!(PgInet::parse("10.0.0.1/33", false).is_err());
+        assert!(PgInet::parse("not-an-ip", false).is_err());
+    }
+
+    #[test]
+    fn test_macaddr_normalized() {
+        for input in [
+            "08:00:2B:01:02:03",
+            "08-00-2b-01-02-03",
+            "0800.2b01.0203",
+            "08002b010203",
+        ] {
+            assert_eq!(
+                json_of(PgMacAddr::parse(input, 6).unwrap().to_value()),
+                serde_json::json!({
+                    "type": "macaddr",
+                    "address": "08:00:2b:01:02:03",
+                })
+            );
+        }
+        assert!(PgMacAddr::parse("08:00:2b:01:02", 6).is_err());
+        assert!(PgMacAddr::parse("zz:00:2b:01:02:03", 6).is_err());
+        assert_eq!(
+            PgMacAddr::parse("0800:2b01:0203:0405", 8)
+                .unwrap()
+                .type_name(),
+            "macaddr8"
+        );
+    }
+
+    #[test]
+    fn test_network_param_validation() {
+        let inet = serde_json::json!({"type": "inet", "address": "192.168.1.10", "prefix": 24});
+        assert!(matches!(
+            network_param(&inet).unwrap(),
+            Some(NetworkParam::Inet(PgInet {
+                prefix: 24,
+                is_cidr: false,
+                ..
+            }))
+        ));
+
+        let bad = serde_json::json!({"type": "inet", "address": "192.168.1.300"});
+        let err = network_param(&bad).err().unwrap();
+        assert!(err.to_string().contains("Invalid inet value"));
+
+        let bad_mac = serde_json::json!({"type": "macaddr", "address": "08:00:2b"});
+        assert!(network_param(&bad_mac).is_err());
+
+        // Ordinary JSON documents are left alone.
+        let doc = serde_json::json!({"type": "inet", "address": "x", "owner": "ops"});
+        assert!(network_param(&doc).unwrap().is_none());
+        assert!(network_param(&serde_json::json!([1, 2])).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_citext_column_hint() {
+        let citext = column_info("email", "citext");
+        assert!(citext.case_insensitive);
+        assert!(matches!(PgDecoder::for_type("citext"), PgDecoder::Text));
+
+        let text = column_info("name", "TEXT");
+        assert!(!text.case_insensitive);
+        let inet = column_info("ip", "INET");
+        assert!(!inet.case_insensitive);
+        assert!(matches!(PgDecoder::for_type("INET"), PgDecoder::Inet));
+    }
+}
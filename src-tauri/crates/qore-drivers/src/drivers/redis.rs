@@ -6,24 +6,27 @@
 //! Redis is a key-value store; this driver maps keys as "collections" and
 //! displays their contents in type-specific tabular formats.
 
+use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap};
-use std::sync::atomic::{AtomicU16, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use futures::future::{AbortHandle, Abortable};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{Mutex, MutexGuard, RwLock};
 
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::DataEngine;
 use qore_core::types::{
-    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnInfo,
-    ConnectionConfig, Namespace, PaginatedQueryResult, QueryId, QueryResult, Row as QRow, RowData,
-    SessionId, TableColumn, TableQueryOptions, TableSchema, Value,
+    BigKeyEntry, BigKeysReport, CancelSupport, Collection, CollectionList, CollectionListOptions,
+    CollectionType, ColumnInfo, ConnectionConfig, KeyMetadata, KeyTypeStats, Namespace,
+    PaginatedQueryResult, QueryId, QueryResult, Row as QRow, RowData, SessionId, TableColumn,
+    TableQueryOptions, TableSchema, Value,
 };
 
+use crate::query_timeout::{query_timeout, with_query_timeout};
 use crate::redis_safety::{classify, RedisQueryClass};
 
 /// Holds a Redis connection and session metadata
@@ -35,6 +38,172 @@ pub struct RedisSession {
     /// the driver needs (read_only flag + environment).
     pub read_only: bool,
     pub environment: String,
+    /// `ConnectionConfig::query_timeout_secs`; expiry aborts the command.
+    pub query_timeout: Option<Duration>,
+    /// Opens a replacement when `connection` turns out to be dead. The client
+    /// holds the connection info, credentials included.
+    client: redis::Client,
+    /// End of the last round trip on `connection`
+    last_used: std::sync::Mutex<Instant>,
+    /// Set when a command failed because the connection dropped
+    broken: AtomicBool,
+}
+
+/// Idle time after which a connection is pinged before it is handed out.
+const IDLE_CHECK_AFTER: Duration = Duration::from_secs(60);
+
+/// Bound on the liveness PING of an idle connection.
+const IDLE_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl RedisSession {
+    /// Locks the connection, replacing it first when it is known to be
+    /// broken or fails a PING after sitting idle. A replacement is attempted
+    /// once; if it fails the error is returned rather than retried, so a real
+    /// outage is not masked.
+    async fn lock_connection(
+        &self,
+    ) -> EngineResult<MutexGuard<'_, redis::aio::MultiplexedConnection>> {
+        let mut conn = self.connection.lock().await;
+        let idle = self
+            .last_used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .elapsed()
+            >= IDLE_CHECK_AFTER;
+
+        let mut dead = self.broken.load(Ordering::Relaxed);
+        if !dead && idle {
+            let ping = tokio::time::timeout(
+                IDLE_CHECK_TIMEOUT,
+                redis::cmd("PING").query_async::<String>(&mut *conn),
+            )
+            .await;
+            dead = !matches!(ping, Ok(Ok(_)));
+        }
+
+        if dead {
+            *conn = self.reconnect().await?;
+            self.broken.store(false, Ordering::Relaxed);
+        }
+        *self
+            .last_used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Instant::now();
+        Ok(conn)
+    }
+
+    async fn reconnect(&self) -> EngineResult<redis::aio::MultiplexedConnection> {
+        let reconnect_failed = |reason: String| {
+            EngineError::connection_failed(format!(
+                "Redis connection lost and reconnecting failed: {}",
+                reason
+            ))
+        };
+        let mut conn = tokio::time::timeout(
+            RedisDriver::DEFAULT_TIMEOUT,
+            self.client.get_multiplexed_async_connection(),
+        )
+        .await
+        .map_err(|_| reconnect_failed("timed out".to_string()))?
+        .map_err(|e| reconnect_failed(e.to_string()))?;
+
+        // The new connection starts on the configured database.
+        RedisDriver::select_db(&mut conn, self.current_db.load(Ordering::Relaxed)).await?;
+        Ok(conn)
+    }
+
+    /// Maps a command error, flagging the connection for replacement when
+    /// the error means it dropped.
+    fn command_error(&self, e: redis::RedisError) -> EngineError {
+        if e.is_connection_dropped() || e.is_io_error() || e.is_unrecoverable_error() {
+            self.broken.store(true, Ordering::Relaxed);
+            EngineError::connection_failed(format!("Redis connection lost: {}", e))
+        } else {
+            EngineError::execution_error(e.to_string())
+        }
+    }
+}
+
+/// Keys requested per SCAN round during a big keys analysis.
+const BIG_KEYS_SCAN_BATCH: u64 = 500;
+
+/// Keys requested per SCAN round when listing keys by cursor.
+const KEY_LIST_SCAN_BATCH: u64 = 500;
+
+/// Page size of a cursor listing when the caller does not set one.
+const DEFAULT_KEY_PAGE_SIZE: u32 = 100;
+
+/// `TYPE` reply for keys written by the RedisJSON module.
+const REDIS_JSON_TYPE: &str = "ReJSON-RL";
+
+/// Running top-N and per-type totals for a big keys scan.
+struct BigKeysRanking {
+    top_n: usize,
+    /// Min-heap on size so the smallest retained key is evicted first.
+    largest: BinaryHeap<Reverse<(u64, String, String)>>,
+    by_type: HashMap<String, KeyTypeStats>,
+    keys_sampled: u64,
+}
+
+impl BigKeysRanking {
+    fn new(top_n: usize) -> Self {
+        Self {
+            top_n,
+            largest: BinaryHeap::with_capacity(top_n.saturating_add(1)),
+            by_type: HashMap::new(),
+            keys_sampled: 0,
+        }
+    }
+
+    fn record(&mut self, key: String, key_type: String, bytes: u64) {
+        self.keys_sampled += 1;
+
+        let stats = self
+            .by_type
+            .entry(key_type.clone())
+            .or_insert_with(|| KeyTypeStats {
+                key_type: key_type.clone(),
+                key_count: 0,
+                memory_usage_bytes: 0,
+            });
+        stats.key_count += 1;
+        stats.memory_usage_bytes = stats.memory_usage_bytes.saturating_add(bytes);
+
+        if self.top_n == 0 {
+            return;
+        }
+        self.largest.push(Reverse((bytes, key, key_type)));
+        if self.largest.len() > self.top_n {
+            self.largest.pop();
+        }
+    }
+
+    fn into_report(self, truncated: bool) -> BigKeysReport {
+        let top_keys = self
+            .largest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((memory_usage_bytes, key, key_type))| BigKeyEntry {
+                key,
+                key_type,
+                memory_usage_bytes,
+            })
+            .collect();
+
+        let mut by_type: Vec<KeyTypeStats> = self.by_type.into_values().collect();
+        by_type.sort_by(|a, b| {
+            b.memory_usage_bytes
+                .cmp(&a.memory_usage_bytes)
+                .then_with(|| a.key_type.cmp(&b.key_type))
+        });
+
+        BigKeysReport {
+            top_keys,
+            by_type,
+            keys_sampled: self.keys_sampled,
+            truncated,
+        }
+    }
 }
 
 pub struct RedisDriver {
@@ -80,7 +249,7 @@ impl RedisDriver {
     /// Creates a multiplexed connection and pings it
     async fn create_connection_and_ping(
         config: &ConnectionConfig,
-    ) -> EngineResult<(redis::aio::MultiplexedConnection, u16)> {
+    ) -> EngineResult<(redis::Client, redis::aio::MultiplexedConnection, u16)> {
         let conn_str = Self::build_connection_string(config);
         let client = redis::Client::open(conn_str)
             .map_err(|e| EngineError::connection_failed(e.to_string()))?;
@@ -117,7 +286,7 @@ impl RedisDriver {
             .and_then(|d| d.parse::<u16>().ok())
             .unwrap_or(0);
 
-        Ok((conn, db))
+        Ok((client, conn, db))
     }
 
     async fn get_session(&self, session: SessionId) -> EngineResult<Arc<RedisSession>> {
@@ -128,10 +297,34 @@ impl RedisDriver {
             .ok_or_else(|| EngineError::session_not_found(session.0.to_string()))
     }
 
+    /// Redis exposes its database count via `CONFIG GET databases` (default 16).
+    async fn database_count(conn: &mut redis::aio::MultiplexedConnection) -> u16 {
+        match redis::cmd("CONFIG")
+            .arg("GET")
+            .arg("databases")
+            .query_async::<Vec<String>>(conn)
+            .await
+        {
+            Ok(vals) if vals.len() >= 2 => vals[1].parse().unwrap_or(16),
+            _ => 16,
+        }
+    }
+
     fn parse_db_index(database: &str) -> u16 {
         database.trim_start_matches("db").parse().unwrap_or(0)
     }
 
+    fn keys_to_collections(namespace: &Namespace, keys: Vec<String>) -> Vec<Collection> {
+        keys.into_iter()
+            .map(|name| Collection {
+                namespace: namespace.clone(),
+                name,
+                collection_type: CollectionType::Collection,
+                partition_of: None,
+            })
+            .collect()
+    }
+
     fn encode_userinfo_component(value: &str) -> String {
         utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
     }
@@ -165,21 +358,27 @@ impl RedisDriver {
         }
 
         let query = query.to_string();
-        let result = Abortable::new(
-            async move { Self::execute_with_lock(redis_session, query, target_db).await },
-            abort_reg,
-        )
-        .await;
+        let query_timeout = redis_session.query_timeout;
+        let run = async {
+            match Abortable::new(
+                async move { Self::execute_with_lock(redis_session, query, target_db).await },
+                abort_reg,
+            )
+            .await
+            {
+                Ok(inner) => inner,
+                Err(_) => Err(EngineError::Cancelled),
+            }
+        };
+        let result =
+            with_query_timeout(query_timeout, run, self.cancel(session, Some(query_id))).await;
 
         {
             let mut active = self.active_queries.lock().await;
             active.remove(&query_id);
         }
 
-        match result {
-            Ok(inner) => inner,
-            Err(_) => Err(EngineError::Cancelled),
-        }
+        result
     }
 
     async fn execute_with_lock(
@@ -221,7 +420,7 @@ impl RedisDriver {
             RedisQueryClass::Read | RedisQueryClass::Unknown => {}
         }
 
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
 
         if let Some(db_index) = target_db {
             Self::select_db(&mut conn, db_index).await?;
@@ -236,7 +435,7 @@ impl RedisDriver {
         let value: redis::Value = cmd
             .query_async(&mut *conn)
             .await
-            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            .map_err(|e| redis_session.command_error(e))?;
 
         // Track explicit SELECT commands to keep current_db in sync.
         if cmd_name == "SELECT" {
@@ -273,6 +472,130 @@ impl RedisDriver {
         Ok(ttl)
     }
 
+    /// Number of elements held by a key (fields, items, members or entries).
+    /// Strings count as a single element; unknown types yield `None`.
+    async fn element_count(
+        conn: &mut redis::aio::MultiplexedConnection,
+        type_str: &str,
+        key: &str,
+    ) -> Option<u64> {
+        let cmd = match type_str {
+            "string" | REDIS_JSON_TYPE => return Some(1),
+            "hash" => "HLEN",
+            "list" => "LLEN",
+            "set" => "SCARD",
+            "zset" => "ZCARD",
+            "stream" => "XLEN",
+            _ => return None,
+        };
+        redis::cmd(cmd).arg(key).query_async::<u64>(conn).await.ok()
+    }
+
+    /// Resumes SCAN at `cursor` until at least `page_size` keys matched or the
+    /// keyspace is exhausted. Returns the keys and the cursor to resume from,
+    /// `0` once the scan is complete.
+    async fn scan_key_page(
+        conn: &mut impl redis::aio::ConnectionLike,
+        mut cursor: u64,
+        pattern: &str,
+        page_size: usize,
+    ) -> EngineResult<(Vec<String>, u64)> {
+        let mut keys = Vec::new();
+        loop {
+            let (next_cursor, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(pattern)
+                .arg("COUNT")
+                .arg(KEY_LIST_SCAN_BATCH)
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+            keys.extend(batch);
+            cursor = next_cursor;
+            if cursor == 0 || keys.len() >= page_size {
+                return Ok((keys, cursor));
+            }
+        }
+    }
+
+    /// Walks the keyspace with SCAN, pipelining TYPE + MEMORY USAGE for each
+    /// batch. The session lock is taken per batch so other work on the
+    /// multiplexed connection can interleave with a long scan.
+    async fn scan_big_keys(
+        redis_session: Arc<RedisSession>,
+        db_index: u16,
+        sample_size: u64,
+        top_n: usize,
+    ) -> EngineResult<BigKeysReport> {
+        let mut ranking = BigKeysRanking::new(top_n);
+        let mut cursor: u64 = 0;
+        let mut truncated = false;
+
+        loop {
+            let sampled = {
+                let mut conn = redis_session.lock_connection().await?;
+                Self::select_db(&mut conn, db_index).await?;
+
+                let (next_cursor, mut keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                    .arg(cursor)
+                    .arg("COUNT")
+                    .arg(BIG_KEYS_SCAN_BATCH)
+                    .query_async(&mut *conn)
+                    .await
+                    .map_err(|e| EngineError::execution_error(e.to_string()))?;
+                cursor = next_cursor;
+
+                let remaining = sample_size.saturating_sub(ranking.keys_sampled) as usize;
+                if keys.len() > remaining {
+                    keys.truncate(remaining);
+                    truncated = true;
+                }
+
+                if keys.is_empty() {
+                    Vec::new()
+                } else {
+                    let mut pipe = redis::pipe();
+                    for key in &keys {
+                        pipe.cmd("TYPE").arg(key);
+                        pipe.cmd("MEMORY").arg("USAGE").arg(key);
+                    }
+                    let replies: Vec<redis::Value> = pipe
+                        .query_async(&mut *conn)
+                        .await
+                        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+                    keys.into_iter()
+                        .zip(replies.chunks(2))
+                        .filter_map(|(key, reply)| {
+                            let key_type: String = redis::from_redis_value(&reply[0]).ok()?;
+                            let bytes: Option<u64> = redis::from_redis_value(reply.get(1)?).ok()?;
+                            Some((key, key_type, bytes?))
+                        })
+                        .collect()
+                }
+            };
+
+            for (key, key_type, bytes) in sampled {
+                // Keys deleted between SCAN and TYPE report "none"
+                if key_type != "none" {
+                    ranking.record(key, key_type, bytes);
+                }
+            }
+
+            if truncated || cursor == 0 {
+                break;
+            }
+            if ranking.keys_sampled >= sample_size {
+                truncated = true;
+                break;
+            }
+        }
+
+        Ok(ranking.into_report(truncated))
+    }
+
     async fn read_string(
         conn: &mut redis::aio::MultiplexedConnection,
         key: &str,
@@ -288,6 +611,7 @@ impl RedisDriver {
             name: "value".into(),
             data_type: "string".into(),
             nullable: false,
+            case_insensitive: false,
         }];
 
         let val = Self::redis_value_to_value(&value);
@@ -302,6 +626,61 @@ impl RedisDriver {
         })
     }
 
+    /// Reads a RedisJSON document as a single `json` row. If `JSON.GET`
+    /// fails (module not loaded, command denied by an ACL), the raw `GET`
+    /// reply is shown instead.
+    async fn read_json(
+        conn: &mut impl redis::aio::ConnectionLike,
+        key: &str,
+    ) -> EngineResult<QueryResult> {
+        let start = Instant::now();
+        let value = match redis::cmd("JSON.GET")
+            .arg(key)
+            .arg("$")
+            .query_async::<redis::Value>(&mut *conn)
+            .await
+        {
+            Ok(reply) => Self::parse_json_get(&reply),
+            Err(_) => {
+                let raw: redis::Value = redis::cmd("GET")
+                    .arg(key)
+                    .query_async(&mut *conn)
+                    .await
+                    .map_err(|e| EngineError::execution_error(e.to_string()))?;
+                Self::redis_value_to_value(&raw)
+            }
+        };
+
+        Ok(QueryResult {
+            columns: vec![ColumnInfo {
+                name: "value".into(),
+                data_type: "json".into(),
+                nullable: false,
+                case_insensitive: false,
+            }],
+            rows: vec![QRow {
+                values: vec![value],
+            }],
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+        })
+    }
+
+    /// `JSON.GET key $` replies with a JSON array holding the root document.
+    fn parse_json_get(reply: &redis::Value) -> Value {
+        if matches!(reply, redis::Value::Nil) {
+            return Value::Null;
+        }
+        let text = Self::redis_value_to_string(reply);
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Ok(serde_json::Value::Array(mut matches)) if matches.len() == 1 => {
+                Value::Json(matches.remove(0))
+            }
+            Ok(json) => Value::Json(json),
+            Err(_) => Value::Text(text),
+        }
+    }
+
     async fn read_hash_page(
         conn: &mut redis::aio::MultiplexedConnection,
         key: &str,
@@ -370,11 +749,13 @@ impl RedisDriver {
                 name: "index".into(),
                 data_type: "integer".into(),
                 nullable: false,
+                case_insensitive: false,
             },
             ColumnInfo {
                 name: "value".into(),
                 data_type: "string".into(),
                 nullable: false,
+                case_insensitive: false,
             },
         ];
 
@@ -469,11 +850,13 @@ impl RedisDriver {
                 name: "member".into(),
                 data_type: "string".into(),
                 nullable: false,
+                case_insensitive: false,
             },
             ColumnInfo {
                 name: "score".into(),
                 data_type: "float".into(),
                 nullable: false,
+                case_insensitive: false,
             },
         ];
 
@@ -524,11 +907,13 @@ impl RedisDriver {
                         name: "id".into(),
                         data_type: "string".into(),
                         nullable: false,
+                        case_insensitive: false,
                     },
                     ColumnInfo {
                         name: "data".into(),
                         data_type: "json".into(),
                         nullable: false,
+                        case_insensitive: false,
                     },
                 ],
                 rows: Vec::new(),
@@ -556,11 +941,13 @@ impl RedisDriver {
                 name: "id".into(),
                 data_type: "string".into(),
                 nullable: false,
+                case_insensitive: false,
             },
             ColumnInfo {
                 name: "data".into(),
                 data_type: "json".into(),
                 nullable: false,
+                case_insensitive: false,
             },
         ];
 
@@ -719,6 +1106,7 @@ impl RedisDriver {
                     name: "result".into(),
                     data_type: "string".into(),
                     nullable: true,
+                    case_insensitive: false,
                 }],
                 rows: vec![QRow {
                     values: vec![Value::Null],
@@ -737,6 +1125,7 @@ impl RedisDriver {
                     name: "result".into(),
                     data_type: "integer".into(),
                     nullable: false,
+                    case_insensitive: false,
                 }],
                 rows: vec![QRow {
                     values: vec![Value::Int(*i)],
@@ -749,6 +1138,7 @@ impl RedisDriver {
                     name: "value".into(),
                     data_type: "string".into(),
                     nullable: true,
+                    case_insensitive: false,
                 }];
                 let rows: Vec<QRow> = arr
                     .iter()
@@ -768,6 +1158,7 @@ impl RedisDriver {
                     name: "result".into(),
                     data_type: "string".into(),
                     nullable: true,
+                    case_insensitive: false,
                 }];
                 let rows = vec![QRow {
                     values: vec![Self::redis_value_to_value(&value)],
@@ -897,7 +1288,7 @@ impl DataEngine for RedisDriver {
     }
 
     async fn connect(&self, config: &ConnectionConfig) -> EngineResult<SessionId> {
-        let (conn, db) = Self::create_connection_and_ping(config).await?;
+        let (client, conn, db) = Self::create_connection_and_ping(config).await?;
 
         let session_id = SessionId::new();
         let redis_session = Arc::new(RedisSession {
@@ -905,6 +1296,10 @@ impl DataEngine for RedisDriver {
             current_db: AtomicU16::new(db),
             read_only: config.read_only,
             environment: config.environment.clone(),
+            query_timeout: query_timeout(config.query_timeout_secs),
+            client,
+            last_used: std::sync::Mutex::new(Instant::now()),
+            broken: AtomicBool::new(false),
         });
 
         let mut sessions = self.sessions.write().await;
@@ -921,30 +1316,22 @@ impl DataEngine for RedisDriver {
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<Duration> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
+        let start = Instant::now();
         redis::cmd("PING")
             .query_async::<String>(&mut *conn)
             .await
             .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
-        Ok(())
+        Ok(start.elapsed())
     }
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
 
-        // Redis exposes its database count via `CONFIG GET databases` (default 16).
-        let db_count: u16 = match redis::cmd("CONFIG")
-            .arg("GET")
-            .arg("databases")
-            .query_async::<Vec<String>>(&mut *conn)
-            .await
-        {
-            Ok(vals) if vals.len() >= 2 => vals[1].parse().unwrap_or(16),
-            _ => 16,
-        };
+        let db_count = Self::database_count(&mut conn).await;
 
         let mut namespaces = Vec::new();
 
@@ -985,6 +1372,13 @@ impl DataEngine for RedisDriver {
         Ok(namespaces)
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        let redis_session = self.get_session(session).await?;
+        let mut conn = redis_session.lock_connection().await?;
+        let db_count = Self::database_count(&mut conn).await;
+        Ok((0..db_count).map(|db| format!("db{}", db)).collect())
+    }
+
     async fn list_collections(
         &self,
         session: SessionId,
@@ -992,7 +1386,7 @@ impl DataEngine for RedisDriver {
         options: CollectionListOptions,
     ) -> EngineResult<CollectionList> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
 
         let db_index = Self::parse_db_index(&namespace.database);
 
@@ -1012,6 +1406,31 @@ impl DataEngine for RedisDriver {
             "*".to_string()
         };
 
+        // Cursor paging: one page of SCAN per call, total estimated by DBSIZE.
+        // "0" starts a new scan. SCAN may repeat a key across pages.
+        if let Some(token) = options.cursor.as_deref() {
+            let cursor: u64 = token.parse().map_err(|_| {
+                EngineError::validation(format!("Invalid key listing cursor: {}", token))
+            })?;
+            let page_size = options.page_size.unwrap_or(DEFAULT_KEY_PAGE_SIZE).max(1) as usize;
+
+            let (mut keys, next_cursor) =
+                Self::scan_key_page(&mut *conn, cursor, &pattern, page_size).await?;
+            keys.sort();
+            keys.dedup();
+
+            let db_size: u64 = redis::cmd("DBSIZE")
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+            return Ok(CollectionList {
+                collections: Self::keys_to_collections(namespace, keys),
+                total_count: db_size.min(u32::MAX as u64) as u32,
+                next_cursor: (next_cursor != 0).then(|| next_cursor.to_string()),
+            });
+        }
+
         let mut cursor: u64 = 0;
 
         let (paginated, total_count) = if let Some(limit_u32) = options.page_size {
@@ -1108,18 +1527,10 @@ impl DataEngine for RedisDriver {
             (all_keys, total_count)
         };
 
-        let collections = paginated
-            .into_iter()
-            .map(|name| Collection {
-                namespace: namespace.clone(),
-                name,
-                collection_type: CollectionType::Collection,
-            })
-            .collect();
-
         Ok(CollectionList {
-            collections,
+            collections: Self::keys_to_collections(namespace, paginated),
             total_count: total_count.min(u32::MAX as usize) as u32,
+            next_cursor: None,
         })
     }
 
@@ -1171,7 +1582,7 @@ impl DataEngine for RedisDriver {
         table: &str,
     ) -> EngineResult<TableSchema> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
 
         let db_index = Self::parse_db_index(&namespace.database);
 
@@ -1183,17 +1594,22 @@ impl DataEngine for RedisDriver {
 
         let key = table;
         let type_str = Self::key_type(&mut conn, key).await?;
-        let ttl = Self::key_ttl(&mut conn, key).await?;
-
-        let encoding: String = redis::cmd("OBJECT")
-            .arg("ENCODING")
-            .arg(key)
-            .query_async(&mut *conn)
-            .await
-            .unwrap_or_else(|_| "unknown".to_string());
 
         // Synthetic columns vary per Redis key type so the UI can render typed pages.
         let columns = match type_str.as_str() {
+            REDIS_JSON_TYPE => vec![TableColumn {
+                name: "value".into(),
+                data_type: "json".into(),
+                nullable: false,
+                default_value: None,
+                is_primary_key: false,
+                is_auto_increment: false,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
+            }],
             "string" => vec![TableColumn {
                 name: "value".into(),
                 data_type: "string".into(),
@@ -1201,6 +1617,11 @@ impl DataEngine for RedisDriver {
                 default_value: None,
                 is_primary_key: false,
                 is_auto_increment: false,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
             }],
             "hash" => vec![
                 TableColumn {
@@ -1210,6 +1631,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "value".into(),
@@ -1218,6 +1644,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             "list" => vec![
@@ -1228,6 +1659,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "value".into(),
@@ -1236,6 +1672,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             "set" => vec![TableColumn {
@@ -1245,6 +1686,11 @@ impl DataEngine for RedisDriver {
                 default_value: None,
                 is_primary_key: false,
                 is_auto_increment: false,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
             }],
             "zset" => vec![
                 TableColumn {
@@ -1254,6 +1700,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "score".into(),
@@ -1262,6 +1713,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             "stream" => vec![
@@ -1272,6 +1728,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "data".into(),
@@ -1280,6 +1741,11 @@ impl DataEngine for RedisDriver {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             _ => vec![TableColumn {
@@ -1289,62 +1755,25 @@ impl DataEngine for RedisDriver {
                 default_value: None,
                 is_primary_key: false,
                 is_auto_increment: false,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
             }],
         };
 
-        let element_count: Option<u64> = match type_str.as_str() {
-            "string" => Some(1),
-            "hash" => redis::cmd("HLEN")
-                .arg(key)
-                .query_async::<u64>(&mut *conn)
-                .await
-                .ok(),
-            "list" => redis::cmd("LLEN")
-                .arg(key)
-                .query_async::<u64>(&mut *conn)
-                .await
-                .ok(),
-            "set" => redis::cmd("SCARD")
-                .arg(key)
-                .query_async::<u64>(&mut *conn)
-                .await
-                .ok(),
-            "zset" => redis::cmd("ZCARD")
-                .arg(key)
-                .query_async::<u64>(&mut *conn)
-                .await
-                .ok(),
-            "stream" => redis::cmd("XLEN")
-                .arg(key)
-                .query_async::<u64>(&mut *conn)
-                .await
-                .ok(),
-            _ => None,
-        };
-
-        let ttl_display = if ttl == -1 {
-            "no expiry".to_string()
-        } else if ttl == -2 {
-            "key not found".to_string()
-        } else {
-            format!("{}s", ttl)
-        };
+        let element_count = Self::element_count(&mut conn, &type_str, key).await;
 
         Ok(TableSchema {
             columns,
             primary_key: None,
             foreign_keys: Vec::new(),
             row_count_estimate: element_count,
-            indexes: vec![qore_core::types::TableIndex {
-                name: format!(
-                    "type:{} | encoding:{} | ttl:{}",
-                    type_str, encoding, ttl_display
-                ),
-                columns: vec![key.to_string()],
-                is_unique: false,
-                index_type: None,
-                is_primary: false,
-            }],
+            row_count_is_estimate: false,
+            indexes: Vec::new(),
+            partitioning: None,
+            row_security: None,
         })
     }
 
@@ -1356,7 +1785,7 @@ impl DataEngine for RedisDriver {
         limit: u32,
     ) -> EngineResult<QueryResult> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
 
         let db_index = Self::parse_db_index(&namespace.database);
 
@@ -1371,6 +1800,7 @@ impl DataEngine for RedisDriver {
 
         match type_str.as_str() {
             "string" => Self::read_string(&mut conn, key).await,
+            REDIS_JSON_TYPE => Self::read_json(&mut *conn, key).await,
             "hash" => {
                 let start = Instant::now();
                 let rows =
@@ -1381,11 +1811,13 @@ impl DataEngine for RedisDriver {
                             name: "field".into(),
                             data_type: "string".into(),
                             nullable: false,
+                            case_insensitive: false,
                         },
                         ColumnInfo {
                             name: "value".into(),
                             data_type: "string".into(),
                             nullable: false,
+                            case_insensitive: false,
                         },
                     ],
                     rows,
@@ -1402,6 +1834,7 @@ impl DataEngine for RedisDriver {
                         name: "member".into(),
                         data_type: "string".into(),
                         nullable: false,
+                        case_insensitive: false,
                     }],
                     rows,
                     affected_rows: None,
@@ -1429,7 +1862,7 @@ impl DataEngine for RedisDriver {
         options: TableQueryOptions,
     ) -> EngineResult<PaginatedQueryResult> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
 
         let db_index = Self::parse_db_index(&namespace.database);
 
@@ -1450,6 +1883,10 @@ impl DataEngine for RedisDriver {
                 let result = Self::read_string(&mut conn, key).await?;
                 Ok(PaginatedQueryResult::new(result, 1, page, page_size))
             }
+            REDIS_JSON_TYPE => {
+                let result = Self::read_json(&mut *conn, key).await?;
+                Ok(PaginatedQueryResult::new(result, 1, page, page_size))
+            }
             "hash" => {
                 let start = Instant::now();
                 let total: u64 = redis::cmd("HLEN")
@@ -1466,11 +1903,13 @@ impl DataEngine for RedisDriver {
                             name: "field".into(),
                             data_type: "string".into(),
                             nullable: false,
+                            case_insensitive: false,
                         },
                         ColumnInfo {
                             name: "value".into(),
                             data_type: "string".into(),
                             nullable: false,
+                            case_insensitive: false,
                         },
                     ],
                     rows,
@@ -1502,6 +1941,7 @@ impl DataEngine for RedisDriver {
                         name: "member".into(),
                         data_type: "string".into(),
                         nullable: false,
+                        case_insensitive: false,
                     }],
                     rows,
                     affected_rows: None,
@@ -1581,6 +2021,183 @@ impl DataEngine for RedisDriver {
         true
     }
 
+    fn supports_key_metadata(&self) -> bool {
+        true
+    }
+
+    /// Gathers TYPE, TTL, OBJECT ENCODING/REFCOUNT/IDLETIME and MEMORY USAGE
+    /// for a single key. Introspection commands that the server refuses
+    /// (OBJECT IDLETIME under an LFU policy, MEMORY on older versions) leave
+    /// the corresponding field empty instead of failing the whole call.
+    async fn key_metadata(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        key: &str,
+    ) -> EngineResult<KeyMetadata> {
+        let redis_session = self.get_session(session).await?;
+        let mut conn = redis_session.lock_connection().await?;
+
+        let db_index = Self::parse_db_index(&namespace.database);
+
+        redis::cmd("SELECT")
+            .arg(db_index)
+            .query_async::<String>(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(format!("SELECT db{}: {}", db_index, e)))?;
+
+        let key_type = Self::key_type(&mut conn, key).await?;
+        if key_type == "none" {
+            return Err(EngineError::validation(format!("Key '{}' not found", key)));
+        }
+
+        let ttl = Self::key_ttl(&mut conn, key).await?;
+
+        let encoding: Option<String> = redis::cmd("OBJECT")
+            .arg("ENCODING")
+            .arg(key)
+            .query_async(&mut *conn)
+            .await
+            .ok();
+
+        let memory_usage_bytes: Option<u64> = redis::cmd("MEMORY")
+            .arg("USAGE")
+            .arg(key)
+            .query_async::<Option<u64>>(&mut *conn)
+            .await
+            .ok()
+            .flatten();
+
+        let refcount: Option<i64> = redis::cmd("OBJECT")
+            .arg("REFCOUNT")
+            .arg(key)
+            .query_async::<Option<i64>>(&mut *conn)
+            .await
+            .ok()
+            .flatten();
+
+        let idle_time_secs: Option<u64> = redis::cmd("OBJECT")
+            .arg("IDLETIME")
+            .arg(key)
+            .query_async::<Option<u64>>(&mut *conn)
+            .await
+            .ok()
+            .flatten();
+
+        let element_count = Self::element_count(&mut conn, &key_type, key).await;
+
+        Ok(KeyMetadata {
+            key: key.to_string(),
+            key_type,
+            encoding,
+            ttl_secs: (ttl >= 0).then_some(ttl),
+            memory_usage_bytes,
+            refcount,
+            idle_time_secs,
+            element_count,
+        })
+    }
+
+    fn supports_big_keys(&self) -> bool {
+        true
+    }
+
+    async fn big_keys(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        sample_size: u64,
+        top_n: usize,
+        query_id: QueryId,
+    ) -> EngineResult<BigKeysReport> {
+        let redis_session = self.get_session(session).await?;
+        let db_index = Self::parse_db_index(&namespace.database);
+
+        let (abort_handle, abort_reg) = AbortHandle::new_pair();
+        {
+            let mut active = self.active_queries.lock().await;
+            active.insert(query_id, (session, abort_handle));
+        }
+
+        let result = Abortable::new(
+            Self::scan_big_keys(redis_session, db_index, sample_size, top_n),
+            abort_reg,
+        )
+        .await;
+
+        {
+            let mut active = self.active_queries.lock().await;
+            active.remove(&query_id);
+        }
+
+        match result {
+            Ok(inner) => inner,
+            Err(_) => Err(EngineError::Cancelled),
+        }
+    }
+
+    fn supports_key_management(&self) -> bool {
+        true
+    }
+
+    async fn set_key_ttl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        key: &str,
+        seconds: u64,
+    ) -> EngineResult<bool> {
+        let redis_session = self.get_session(session).await?;
+        let mut conn = redis_session.lock_connection().await?;
+        Self::select_db_for_namespace(&mut conn, namespace).await?;
+
+        let applied: i64 = redis::cmd("EXPIRE")
+            .arg(key)
+            .arg(seconds)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(applied == 1)
+    }
+
+    async fn persist_key(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        key: &str,
+    ) -> EngineResult<bool> {
+        let redis_session = self.get_session(session).await?;
+        let mut conn = redis_session.lock_connection().await?;
+        Self::select_db_for_namespace(&mut conn, namespace).await?;
+
+        let applied: i64 = redis::cmd("PERSIST")
+            .arg(key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(applied == 1)
+    }
+
+    async fn rename_key(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        old_key: &str,
+        new_key: &str,
+    ) -> EngineResult<()> {
+        let redis_session = self.get_session(session).await?;
+        let mut conn = redis_session.lock_connection().await?;
+        Self::select_db_for_namespace(&mut conn, namespace).await?;
+
+        redis::cmd("RENAME")
+            .arg(old_key)
+            .arg(new_key)
+            .query_async::<String>(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(())
+    }
+
     /// Insert a new element into a Redis key.
     ///
     /// The semantics depend on the key type:
@@ -1598,7 +2215,7 @@ impl DataEngine for RedisDriver {
         data: &RowData,
     ) -> EngineResult<QueryResult> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
         Self::select_db_for_namespace(&mut conn, namespace).await?;
 
         let key = table;
@@ -1724,7 +2341,7 @@ impl DataEngine for RedisDriver {
         data: &RowData,
     ) -> EngineResult<QueryResult> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
         Self::select_db_for_namespace(&mut conn, namespace).await?;
 
         let key = table;
@@ -1827,7 +2444,7 @@ impl DataEngine for RedisDriver {
         primary_key: &RowData,
     ) -> EngineResult<QueryResult> {
         let redis_session = self.get_session(session).await?;
-        let mut conn = redis_session.connection.lock().await;
+        let mut conn = redis_session.lock_connection().await?;
         Self::select_db_for_namespace(&mut conn, namespace).await?;
 
         let key = table;
@@ -1945,6 +2562,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         };
 
         let conn_str = RedisDriver::build_connection_string(&config);
@@ -1973,6 +2597,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         };
 
         let conn_str = RedisDriver::build_connection_string(&config);
@@ -2001,6 +2632,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         };
 
         let conn_str = RedisDriver::build_connection_string(&config);
@@ -2091,4 +2729,434 @@ mod tests {
             other => panic!("expected stream id text, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_big_keys_ranking_keeps_largest_first() {
+        let mut ranking = BigKeysRanking::new(2);
+        ranking.record("small".into(), "string".into(), 10);
+        ranking.record("huge".into(), "hash".into(), 5_000);
+        ranking.record("medium".into(), "string".into(), 300);
+        ranking.record("tiny".into(), "set".into(), 1);
+
+        let report = ranking.into_report(false);
+        assert_eq!(report.keys_sampled, 4);
+        let top: Vec<&str> = report.top_keys.iter().map(|k| k.key.as_str()).collect();
+        assert_eq!(top, vec!["huge", "medium"]);
+
+        assert_eq!(report.by_type[0].key_type, "hash");
+        let strings = report
+            .by_type
+            .iter()
+            .find(|t| t.key_type == "string")
+            .unwrap();
+        assert_eq!(strings.key_count, 2);
+        assert_eq!(strings.memory_usage_bytes, 310);
+    }
+
+    /// Live-gated: set `QOREDB_TEST_REDIS_HOST` (e.g. `localhost`) to run
+    /// against a disposable Redis on port 6379.
+    #[tokio::test]
+    async fn test_key_metadata_live() {
+        let Ok(host) = std::env::var("QOREDB_TEST_REDIS_HOST") else {
+            return;
+        };
+        let driver = RedisDriver::new();
+        let config = ConnectionConfig {
+            driver: "redis".to_string(),
+            host,
+            port: 6379,
+            username: String::new(),
+            password: String::new(),
+            database: None,
+            ssl: false,
+            ssl_mode: None,
+            environment: "development".to_string(),
+            read_only: false,
+            ssh_tunnel: None,
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        };
+
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::new("0");
+        driver
+            .execute(session, "DEL qoredb:test:meta", QueryId::new())
+            .await
+            .unwrap();
+        driver
+            .execute(session, "HSET qoredb:test:meta a 1 b 2 c 3", QueryId::new())
+            .await
+            .unwrap();
+        driver
+            .execute(session, "EXPIRE qoredb:test:meta 600", QueryId::new())
+            .await
+            .unwrap();
+
+        let meta = driver
+            .key_metadata(session, &namespace, "qoredb:test:meta")
+            .await
+            .unwrap();
+        assert_eq!(meta.key_type, "hash");
+        assert_eq!(meta.element_count, Some(3));
+        assert!(meta.encoding.is_some());
+        assert!(meta.memory_usage_bytes.unwrap_or(0) > 0);
+        assert!(meta.refcount.is_some());
+        assert!(matches!(meta.ttl_secs, Some(ttl) if ttl > 0 && ttl <= 600));
+
+        assert!(driver
+            .key_metadata(session, &namespace, "qoredb:test:missing")
+            .await
+            .is_err());
+
+        driver
+            .execute(session, "DEL qoredb:test:meta", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    /// Live-gated like `test_key_metadata_live`.
+    #[tokio::test]
+    async fn test_key_ttl_and_rename_live() {
+        let Ok(host) = std::env::var("QOREDB_TEST_REDIS_HOST") else {
+            return;
+        };
+        let driver = RedisDriver::new();
+        let config = ConnectionConfig {
+            driver: "redis".to_string(),
+            host,
+            port: 6379,
+            username: String::new(),
+            password: String::new(),
+            database: None,
+            ssl: false,
+            ssl_mode: None,
+            environment: "development".to_string(),
+            read_only: false,
+            ssh_tunnel: None,
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        };
+
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::new("0");
+        let ttl = |key: &'static str| {
+            let driver = &driver;
+            async move {
+                let result = driver
+                    .execute(session, &format!("TTL {}", key), QueryId::new())
+                    .await
+                    .unwrap();
+                match result.rows[0].values[0] {
+                    Value::Int(ttl) => ttl,
+                    ref other => panic!("unexpected TTL reply: {:?}", other),
+                }
+            }
+        };
+        driver
+            .execute(
+                session,
+                "DEL qoredb:test:ttl qoredb:test:renamed",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        driver
+            .execute(session, "SET qoredb:test:ttl v", QueryId::new())
+            .await
+            .unwrap();
+        assert_eq!(ttl("qoredb:test:ttl").await, -1);
+
+        assert!(driver
+            .set_key_ttl(session, &namespace, "qoredb:test:ttl", 300)
+            .await
+            .unwrap());
+        assert!(matches!(ttl("qoredb:test:ttl").await, t if t > 0 && t <= 300));
+        assert!(!driver
+            .set_key_ttl(session, &namespace, "qoredb:test:missing", 300)
+            .await
+            .unwrap());
+
+        driver
+            .rename_key(
+                session,
+                &namespace,
+                "qoredb:test:ttl",
+                "qoredb:test:renamed",
+            )
+            .await
+            .unwrap();
+        assert_eq!(ttl("qoredb:test:ttl").await, -2);
+        assert!(ttl("qoredb:test:renamed").await > 0);
+
+        assert!(driver
+            .persist_key(session, &namespace, "qoredb:test:renamed")
+            .await
+            .unwrap());
+        assert_eq!(ttl("qoredb:test:renamed").await, -1);
+        assert!(!driver
+            .persist_key(session, &namespace, "qoredb:test:renamed")
+            .await
+            .unwrap());
+
+        driver
+            .execute(session, "DEL qoredb:test:renamed", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    /// Live-gated like `test_key_metadata_live`.
+    #[tokio::test]
+    async fn test_big_keys_live() {
+        let Ok(host) = std::env::var("QOREDB_TEST_REDIS_HOST") else {
+            return;
+        };
+        let driver = RedisDriver::new();
+        let config = ConnectionConfig {
+            driver: "redis".to_string(),
+            host,
+            port: 6379,
+            username: String::new(),
+            password: String::new(),
+            database: Some("15".to_string()),
+            ssl: false,
+            ssl_mode: None,
+            environment: "development".to_string(),
+            read_only: false,
+            ssh_tunnel: None,
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        };
+
+        let session = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::new("15");
+        driver
+            .execute(
+                session,
+                "DEL qoredb:big:small qoredb:big:medium qoredb:big:large",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        let seeds = [
+            ("qoredb:big:small", 16),
+            ("qoredb:big:medium", 4_096),
+            ("qoredb:big:large", 65_536),
+        ];
+        for (key, size) in seeds {
+            let command = format!("SET {} {}", key, "x".repeat(size));
+            driver
+                .execute(session, &command, QueryId::new())
+                .await
+                .unwrap();
+        }
+
+        let report = driver
+            .big_keys(session, &namespace, 10_000, 3, QueryId::new())
+            .await
+            .unwrap();
+        assert_eq!(report.top_keys[0].key, "qoredb:big:large");
+        assert_eq!(report.top_keys[0].key_type, "string");
+        assert!(report.top_keys[0].memory_usage_bytes >= 65_536);
+        assert!(report.by_type.iter().any(|t| t.key_type == "string"));
+
+        driver
+            .execute(
+                session,
+                "DEL qoredb:big:small qoredb:big:medium qoredb:big:large",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+    }
+
+    /// Replies to SCAN from a script keyed by the requested cursor.
+    struct ScriptedScan {
+        rounds: HashMap<u64, (u64, Vec<&'static str>)>,
+        requested: Vec<u64>,
+    }
+
+    impl redis::aio::ConnectionLike for ScriptedScan {
+        fn req_packed_command<'a>(
+            &'a mut self,
+            cmd: &'a redis::Cmd,
+        ) -> redis::RedisFuture<'a, redis::Value> {
+            let args: Vec<Vec<u8>> = cmd
+                .args_iter()
+                .filter_map(|arg| match arg {
+                    redis::Arg::Simple(bytes) => Some(bytes.to_vec()),
+                    redis::Arg::Cursor => None,
+                })
+                .collect();
+            assert_eq!(args[0], b"SCAN");
+            let cursor: u64 = std::str::from_utf8(&args[1]).unwrap().parse().unwrap();
+            self.requested.push(cursor);
+
+            let (next, keys) = self.rounds[&cursor].clone();
+            let reply = redis::Value::Array(vec![
+                redis::Value::BulkString(next.to_string().into_bytes()),
+                redis::Value::Array(
+                    keys.into_iter()
+                        .map(|key| redis::Value::BulkString(key.as_bytes().to_vec()))
+                        .collect(),
+                ),
+            ]);
+            Box::pin(async move { Ok(reply) })
+        }
+
+        fn req_packed_commands<'a>(
+            &'a mut self,
+            _cmd: &'a redis::Pipeline,
+            _offset: usize,
+            _count: usize,
+        ) -> redis::RedisFuture<'a, Vec<redis::Value>> {
+            unimplemented!("pipelines are not scripted")
+        }
+
+        fn get_db(&self) -> i64 {
+            0
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_key_page_resumes_across_rounds() {
+        let mut conn = ScriptedScan {
+            rounds: HashMap::from([
+                (0, (17, vec!["a"])),
+                (17, (42, vec![])),
+                (42, (99, vec!["b", "c"])),
+                (99, (0, vec!["d"])),
+            ]),
+            requested: Vec::new(),
+        };
+
+        let (keys, next) = RedisDriver::scan_key_page(&mut conn, 0, "*", 3)
+            .await
+            .unwrap();
+        assert_eq!(keys, vec!["a", "b", "c"]);
+        assert_eq!(next, 99);
+        assert_eq!(conn.requested, vec![0, 17, 42]);
+
+        let (keys, next) = RedisDriver::scan_key_page(&mut conn, next, "*", 3)
+            .await
+            .unwrap();
+        assert_eq!(keys, vec!["d"]);
+        assert_eq!(next, 0);
+        assert_eq!(conn.requested, vec![0, 17, 42, 99]);
+    }
+
+    /// Answers `JSON.GET` with `json_reply` (or an unknown-command error when
+    /// `None`) and `GET` with `raw`.
+    struct ScriptedJson {
+        json_reply: Option<redis::Value>,
+        raw: redis::Value,
+    }
+
+    impl redis::aio::ConnectionLike for ScriptedJson {
+        fn req_packed_command<'a>(
+            &'a mut self,
+            cmd: &'a redis::Cmd,
+        ) -> redis::RedisFuture<'a, redis::Value> {
+            let name = match cmd.args_iter().next() {
+                Some(redis::Arg::Simple(bytes)) => bytes.to_vec(),
+                _ => Vec::new(),
+            };
+            let reply = match (name.as_slice(), &self.json_reply) {
+                (b"JSON.GET", Some(reply)) => Ok(reply.clone()),
+                (b"JSON.GET", None) => Err(redis::RedisError::from((
+                    redis::ErrorKind::ResponseError,
+                    "unknown command 'JSON.GET'",
+                ))),
+                (b"GET", _) => Ok(self.raw.clone()),
+                (other, _) => panic!("unexpected command {:?}", String::from_utf8_lossy(other)),
+            };
+            Box::pin(async move { reply })
+        }
+
+        fn req_packed_commands<'a>(
+            &'a mut self,
+            _cmd: &'a redis::Pipeline,
+            _offset: usize,
+            _count: usize,
+        ) -> redis::RedisFuture<'a, Vec<redis::Value>> {
+            unimplemented!("pipelines are not scripted")
+        }
+
+        fn get_db(&self) -> i64 {
+            0
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_json_parses_document() {
+        let mut conn = ScriptedJson {
+            json_reply: Some(redis::Value::BulkString(
+                br#"[{"name":"qore","tags":["db","gui"],"stars":42}]"#.to_vec(),
+            )),
+            raw: redis::Value::Nil,
+        };
+
+        let result = RedisDriver::read_json(&mut conn, "doc:1").await.unwrap();
+        assert_eq!(result.columns[0].name, "value");
+        assert_eq!(result.columns[0].data_type, "json");
+        match &result.rows[0].values[0] {
+            Value::Json(json) => assert_eq!(
+                json,
+                &serde_json::json!({"name": "qore", "tags": ["db", "gui"], "stars": 42})
+            ),
+            other => panic!("expected JSON, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_json_falls_back_without_module() {
+        let mut conn = ScriptedJson {
+            json_reply: None,
+            raw: redis::Value::BulkString(b"raw-bytes".to_vec()),
+        };
+
+        let result = RedisDriver::read_json(&mut conn, "doc:1").await.unwrap();
+        assert!(matches!(&result.rows[0].values[0], Value::Text(t) if t == "raw-bytes"));
+    }
 }
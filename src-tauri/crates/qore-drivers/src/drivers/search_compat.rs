@@ -268,10 +268,11 @@ pub async fn disconnect(map: &SessionMap, session: SessionId) -> EngineResult<()
     Ok(())
 }
 
-pub async fn ping(map: &SessionMap, session: SessionId) -> EngineResult<()> {
+pub async fn ping(map: &SessionMap, session: SessionId) -> EngineResult<Duration> {
     let s = get(map, session).await?;
+    let start = Instant::now();
     s.request(Method::GET, "/_cluster/health", None).await?;
-    Ok(())
+    Ok(start.elapsed())
 }
 
 async fn get(map: &SessionMap, session: SessionId) -> EngineResult<Arc<SearchSession>> {
@@ -315,6 +316,7 @@ pub async fn list_collections(
                     namespace: namespace.clone(),
                     name: name.to_string(),
                     collection_type: CollectionType::Table,
+                    partition_of: None,
                 });
             }
         }
@@ -338,6 +340,7 @@ pub async fn list_collections(
                     namespace: namespace.clone(),
                     name: name.to_string(),
                     collection_type: CollectionType::View,
+                    partition_of: None,
                 });
             }
         }
@@ -357,6 +360,7 @@ pub async fn list_collections(
                         namespace: namespace.clone(),
                         name: name.to_string(),
                         collection_type: CollectionType::Table,
+                        partition_of: None,
                     });
                 }
             }
@@ -369,6 +373,7 @@ pub async fn list_collections(
     Ok(CollectionList {
         collections,
         total_count: total,
+        next_cursor: None,
     })
 }
 
@@ -398,6 +403,11 @@ pub async fn describe_table(
         default_value: None,
         is_primary_key: true,
         is_auto_increment: true,
+        is_computed: false,
+        computed_definition: None,
+        is_sparse: false,
+        is_column_set: false,
+        allowed_values: None,
     }];
 
     if let Some(props) = props {
@@ -415,7 +425,10 @@ pub async fn describe_table(
         primary_key: Some(vec!["_id".to_string()]),
         foreign_keys: Vec::new(),
         row_count_estimate: count,
+        row_count_is_estimate: false,
         indexes: Vec::new(),
+        partitioning: None,
+        row_security: None,
     })
 }
 
@@ -461,6 +474,11 @@ fn make_column(name: &str, data_type: &str) -> TableColumn {
         default_value: None,
         is_primary_key: false,
         is_auto_increment: false,
+        is_computed: false,
+        computed_definition: None,
+        is_sparse: false,
+        is_column_set: false,
+        allowed_values: None,
     }
 }
 
@@ -1247,6 +1265,7 @@ fn col(name: &str, data_type: &str) -> ColumnInfo {
         name: name.into(),
         data_type: data_type.into(),
         nullable: true,
+        case_insensitive: false,
     }
 }
 
@@ -1787,6 +1806,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 }
@@ -9,7 +9,8 @@
 //! - SQLite is a file-based database, so `host` in ConnectionConfig contains the file path
 //! - Supports `:memory:` for in-memory databases
 //! - Uses WAL mode for better concurrency
-//! - Single namespace per file (no schema switching)
+//! - One namespace per file; databases attached with `attach_database` are
+//!   listed as extra namespaces and re-attached on every pooled connection
 //!
 //! ## Transaction Handling
 //!
@@ -17,45 +18,163 @@
 //! on BEGIN and released on COMMIT/ROLLBACK.
 
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use futures::StreamExt;
 use sqlx::pool::PoolConnection;
 use sqlx::sqlite::{
-    Sqlite, SqliteColumn, SqliteConnectOptions, SqlitePool, SqlitePoolOptions, SqliteRow,
+    Sqlite, SqliteColumn, SqliteConnectOptions, SqliteConnection, SqlitePool, SqlitePoolOptions,
+    SqliteRow,
 };
 use sqlx::{Column, Row, TypeInfo, ValueRef};
 use tokio::sync::{Mutex, RwLock};
 
+use crate::column_stats;
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::{DataEngine, StreamEvent, StreamSender};
 use qore_core::types::{
     CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnInfo,
-    ConnectionConfig, FilterOperator, ForeignKey, MaintenanceMessage, MaintenanceMessageLevel,
+    ColumnStats, ConnectionConfig, ExplainFormat, ExplainPlan, FilterOperator, ForeignKey,
+    ImplicitOrder, IndexSpec, IsolationLevel, MaintenanceMessage, MaintenanceMessageLevel,
     MaintenanceOperationInfo, MaintenanceOperationType, MaintenanceRequest, MaintenanceResult,
     Namespace, PaginatedQueryResult, QueryId, QueryResult, Row as QRow, RowData, SessionId,
-    SortDirection, TableColumn, TableIndex, TableQueryOptions, TableSchema, Trigger, TriggerEvent,
-    TriggerList, TriggerListOptions, TriggerOperationResult, TriggerTiming, Value,
+    TableColumn, TableIndex, TableQueryOptions, TableSchema, Trigger, TriggerEvent, TriggerList,
+    TriggerListOptions, TriggerOperationResult, TriggerTiming, UpsertColumns, UpsertResult, Value,
 };
+use qore_sql::generator::{generate_create_index, PageColumns, SqlDialect};
 use qore_sql::safety;
 
+use crate::explain;
+use crate::query_timeout::{query_timeout, with_detached_timeout};
+
 /// Holds the connection state for a SQLite session.
 pub struct SqliteSession {
     pub pool: SqlitePool,
     pub transaction_conn: Mutex<Option<PoolConnection<Sqlite>>>,
     pub db_path: String,
+    /// `ConnectionConfig::query_timeout_secs`. SQLite cannot be interrupted
+    /// from here, so an expired query is only abandoned.
+    pub query_timeout: Option<Duration>,
+    /// Databases attached with `attach_database`, shared with the pool hooks.
+    attached: AttachedDatabases,
 }
 
 impl SqliteSession {
-    pub fn new(pool: SqlitePool, db_path: String) -> Self {
+    fn new(
+        pool: SqlitePool,
+        db_path: String,
+        query_timeout: Option<Duration>,
+        attached: AttachedDatabases,
+    ) -> Self {
         Self {
             pool,
             transaction_conn: Mutex::new(None),
             db_path,
+            query_timeout,
+            attached,
+        }
+    }
+
+    /// Name of the main database as exposed in `list_namespaces`.
+    fn main_namespace(&self) -> String {
+        if self.db_path == ":memory:" {
+            "memory".to_string()
+        } else {
+            std::path::Path::new(&self.db_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("main")
+                .to_string()
+        }
+    }
+
+    /// Alias of the attached database shown as `namespace`, `None` for the
+    /// main database.
+    fn attached_alias(&self, namespace: &Namespace) -> Option<String> {
+        let attached = self.attached.read().unwrap_or_else(PoisonError::into_inner);
+        attached
+            .iter()
+            .find(|db| db.alias == namespace.database)
+            .map(|db| db.alias.clone())
+    }
+
+    /// Schema qualifier for objects of `namespace`: `"alias".` for an
+    /// attached database, empty for the main one.
+    fn schema_prefix(&self, namespace: &Namespace) -> String {
+        self.attached_alias(namespace)
+            .map(|alias| format!("{}.", SqliteDriver::quote_ident(&alias)))
+            .unwrap_or_default()
+    }
+}
+
+/// A database attached to a session. `ATTACH` only affects the connection it
+/// runs on, so the pool hooks replay it on every connection it hands out.
+#[derive(Debug, Clone)]
+struct AttachedDatabase {
+    alias: String,
+    /// `ATTACH` filename: a file path or a shared in-memory URI.
+    target: String,
+}
+
+type AttachedDatabases = Arc<std::sync::RwLock<Vec<AttachedDatabase>>>;
+
+fn attach_sql(target: &str, alias: &str) -> String {
+    format!(
+        "ATTACH DATABASE '{}' AS {}",
+        target.replace('\'', "''"),
+        SqliteDriver::quote_ident(alias)
+    )
+}
+
+/// Attaches the registered databases missing from `conn`.
+async fn attach_missing(
+    conn: &mut SqliteConnection,
+    attached: &AttachedDatabases,
+) -> Result<(), sqlx::Error> {
+    let wanted = attached
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    if wanted.is_empty() {
+        return Ok(());
+    }
+
+    let present: Vec<(String,)> = sqlx::query_as("SELECT name FROM pragma_database_list")
+        .fetch_all(&mut *conn)
+        .await?;
+    for db in wanted {
+        if !present.iter().any(|(name,)| *name == db.alias) {
+            sqlx::query(&attach_sql(&db.target, &db.alias))
+                .execute(&mut *conn)
+                .await?;
         }
     }
+    Ok(())
+}
+
+/// Attach aliases must be plain identifiers and not shadow the built-in
+/// `main` / `temp` schemas. Shared with the DuckDB driver.
+pub(crate) fn validate_attach_alias(alias: &str) -> EngineResult<()> {
+    let mut chars = alias.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(EngineError::validation(format!(
+            "Invalid attach alias '{}': use letters, digits and underscores",
+            alias
+        )));
+    }
+    if alias.eq_ignore_ascii_case("main") || alias.eq_ignore_ascii_case("temp") {
+        return Err(EngineError::validation(format!(
+            "'{}' is reserved by SQLite",
+            alias
+        )));
+    }
+    Ok(())
 }
 
 pub struct SqliteDriver {
@@ -75,13 +194,23 @@ impl SqliteDriver {
         min_connections: u32,
         acquire_timeout_secs: u64,
         run_test_query: bool,
+        attached: AttachedDatabases,
     ) -> EngineResult<SqlitePool> {
         let opts = Self::build_connect_options(config);
+        let on_connect = Arc::clone(&attached);
 
         let pool = SqlitePoolOptions::new()
             .max_connections(max_connections)
             .min_connections(min_connections)
             .acquire_timeout(std::time::Duration::from_secs(acquire_timeout_secs))
+            .after_connect(move |conn, _meta| {
+                let attached = Arc::clone(&on_connect);
+                Box::pin(async move { attach_missing(conn, &attached).await })
+            })
+            .before_acquire(move |conn, _meta| {
+                let attached = Arc::clone(&attached);
+                Box::pin(async move { attach_missing(conn, &attached).await.map(|_| true) })
+            })
             .connect_with(opts)
             .await
             .map_err(|e| EngineError::connection_failed(e.to_string()))?;
@@ -105,95 +234,493 @@ impl SqliteDriver {
     }
 
     fn quote_ident(name: &str) -> String {
-        format!("\"{}\"", name.replace('"', "\"\""))
+        safety::quote_ident(SqlDialect::Sqlite, name)
     }
 
-    fn build_connect_options(config: &ConnectionConfig) -> SqliteConnectOptions {
-        use std::str::FromStr;
+    /// `execute_in_namespace` without the query timeout.
+    async fn run_in_namespace(
+        &self,
+        session: SessionId,
+        _namespace: Option<Namespace>,
+        query: &str,
+        _query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        // Block ATTACH DATABASE (mounts arbitrary files past the read-only
+        // flag) and the specific destructive PRAGMA assignments
+        // (`writable_schema`, `journal_mode = OFF`, `foreign_keys = OFF`).
+        // Read-only PRAGMA inspections used by the UI remain allowed. See
+        // audit B3-C4 / B3-C5.
+        if let Some(danger) = safety::classify_sqlite_dangerous(query) {
+            return Err(EngineError::not_supported(danger.reason()));
+        }
+        let sqlite_session = self.get_session(session).await?;
+        let start = Instant::now();
 
-        let path = &config.host;
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
 
-        let conn_str = if path == ":memory:" {
-            "sqlite::memory:".to_string()
+        let mut tx_guard = sqlite_session.transaction_conn.lock().await;
+
+        let result = if let Some(ref mut conn) = *tx_guard {
+            if returns_rows {
+                let sqlite_rows: Vec<SqliteRow> = sqlx::query(query)
+                    .fetch_all(&mut **conn)
+                    .await
+                    .map_err(|e| {
+                    let msg = e.to_string();
+                    if msg.contains("syntax") {
+                        EngineError::syntax_error(msg)
+                    } else {
+                        EngineError::execution_error(msg)
+                    }
+                })?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                if sqlite_rows.is_empty() {
+                    Ok(QueryResult {
+                        columns: Vec::new(),
+                        rows: Vec::new(),
+                        affected_rows: None,
+                        execution_time_ms,
+                    })
+                } else {
+                    let columns = Self::get_column_info(&sqlite_rows[0]);
+                    let decoders = build_decoders(sqlite_rows[0].columns());
+                    let rows: Vec<QRow> = sqlite_rows
+                        .iter()
+                        .map(|r| convert_row_with_decoders(r, &decoders))
+                        .collect();
+
+                    Ok(QueryResult {
+                        columns,
+                        rows,
+                        affected_rows: None,
+                        execution_time_ms,
+                    })
+                }
+            } else {
+                let result = sqlx::query(query).execute(&mut **conn).await.map_err(|e| {
+                    let msg = e.to_string();
+                    if msg.contains("syntax") {
+                        EngineError::syntax_error(msg)
+                    } else {
+                        EngineError::execution_error(msg)
+                    }
+                })?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                Ok(QueryResult::with_affected_rows(
+                    result.rows_affected(),
+                    execution_time_ms,
+                ))
+            }
         } else {
-            format!("sqlite:{}", path)
+            // Use pool
+            let mut conn = sqlite_session
+                .pool
+                .acquire()
+                .await
+                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+
+            if returns_rows {
+                let sqlite_rows: Vec<SqliteRow> = sqlx::query(query)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .map_err(|e| {
+                        let msg = e.to_string();
+                        if msg.contains("syntax") {
+                            EngineError::syntax_error(msg)
+                        } else {
+                            EngineError::execution_error(msg)
+                        }
+                    })?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                if sqlite_rows.is_empty() {
+                    Ok(QueryResult {
+                        columns: Vec::new(),
+                        rows: Vec::new(),
+                        affected_rows: None,
+                        execution_time_ms,
+                    })
+                } else {
+                    let columns = Self::get_column_info(&sqlite_rows[0]);
+                    let decoders = build_decoders(sqlite_rows[0].columns());
+                    let rows: Vec<QRow> = sqlite_rows
+                        .iter()
+                        .map(|r| convert_row_with_decoders(r, &decoders))
+                        .collect();
+
+                    Ok(QueryResult {
+                        columns,
+                        rows,
+                        affected_rows: None,
+                        execution_time_ms,
+                    })
+                }
+            } else {
+                let result = sqlx::query(query).execute(&mut *conn).await.map_err(|e| {
+                    let msg = e.to_string();
+                    if msg.contains("syntax") {
+                        EngineError::syntax_error(msg)
+                    } else {
+                        EngineError::execution_error(msg)
+                    }
+                })?;
+
+                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+                Ok(QueryResult::with_affected_rows(
+                    result.rows_affected(),
+                    execution_time_ms,
+                ))
+            }
         };
 
-        SqliteConnectOptions::from_str(&conn_str)
-            .unwrap_or_else(|_| SqliteConnectOptions::new().filename(path))
-            .create_if_missing(true)
-            .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal)
-            .busy_timeout(std::time::Duration::from_secs(30))
+        result
     }
 
-    fn bind_param<'q>(
-        query: sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
-        value: &'q Value,
-    ) -> sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>> {
-        match value {
-            Value::Null => query.bind(Option::<String>::None),
-            Value::Bool(b) => query.bind(b),
-            Value::Int(i) => query.bind(i),
-            Value::Float(f) => query.bind(f),
-            Value::Text(s) => query.bind(s.as_str()),
-            Value::Bytes(b) => query.bind(b.as_slice()),
-            Value::Json(j) => query.bind(j.to_string()),
-            Value::Array(_) => query.bind(Option::<String>::None),
+    /// `execute_with_params` without the query timeout.
+    async fn run_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+    ) -> EngineResult<QueryResult> {
+        if let Some(danger) = safety::classify_sqlite_dangerous(query) {
+            return Err(EngineError::not_supported(danger.reason()));
         }
-    }
+        let sqlite_session = self.get_session(session).await?;
+        let start = Instant::now();
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
 
-    /// Extracts a value from a SqliteRow by inspecting its actual runtime
-    /// storage class. Used for columns SQLx could not type statically — it
-    /// reports those as `NULL`, e.g. expressions or columns declared without
-    /// a type — and as the recovery path when a dispatched decoder fails.
-    /// SQLite is dynamically typed, so the storage class is read per value
-    /// rather than per column.
-    fn extract_value(row: &SqliteRow, idx: usize) -> Value {
-        let raw = match row.try_get_raw(idx) {
-            Ok(raw) => raw,
-            Err(_) => return Value::Null,
-        };
-        if raw.is_null() {
-            return Value::Null;
+        let mut tx_guard = sqlite_session.transaction_conn.lock().await;
+        if let Some(ref mut conn) = *tx_guard {
+            return Self::exec_bound(conn, query, params, returns_rows, start).await;
         }
+        drop(tx_guard);
 
-        // For a non-NULL value, `type_info()` reflects the real storage class.
-        let decoded = match raw.type_info().name() {
-            "INTEGER" => row.try_get::<i64, _>(idx).map(Value::Int),
-            "REAL" => row.try_get::<f64, _>(idx).map(Value::Float),
-            "BLOB" => row.try_get::<Vec<u8>, _>(idx).map(Value::Bytes),
-            _ => row.try_get::<String, _>(idx).map(Value::Text),
-        };
-        decoded.unwrap_or(Value::Null)
+        let mut conn = sqlite_session
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+        Self::exec_bound(&mut conn, query, params, returns_rows, start).await
     }
 
-    fn get_column_info(row: &SqliteRow) -> Vec<ColumnInfo> {
-        row.columns()
+    async fn exec_bound(
+        conn: &mut SqliteConnection,
+        query: &str,
+        params: &[Value],
+        returns_rows: bool,
+        start: Instant,
+    ) -> EngineResult<QueryResult> {
+        let bound = params
             .iter()
-            .map(|col| ColumnInfo {
-                name: col.name().into(),
-                data_type: col.type_info().name().into(),
-                nullable: true, // SQLite doesn't easily expose nullability from row metadata
-            })
-            .collect()
-    }
-    fn validate_path(path: &str) -> EngineResult<()> {
-        let path = path.trim();
-
-        if path == ":memory:" || path == "sqlite::memory:" {
-            return Ok(());
-        }
+            .fold(sqlx::query(query), |q, value| Self::bind_param(q, value));
 
-        if path.eq_ignore_ascii_case("localhost") {
-            return Err(EngineError::connection_failed(
-                "Invalid SQLite path: 'localhost'. Please select a valid file path.".to_string(),
+        if !returns_rows {
+            let result = bound
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            return Ok(QueryResult::with_affected_rows(
+                result.rows_affected(),
+                start.elapsed().as_micros() as f64 / 1000.0,
             ));
         }
 
-        if path.is_empty() {
-            return Err(EngineError::connection_failed(
-                "SQLite path cannot be empty.".to_string(),
-            ));
+        let sqlite_rows: Vec<SqliteRow> = bound
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+        let Some(first) = sqlite_rows.first() else {
+            return Ok(QueryResult {
+                columns: Vec::new(),
+                rows: Vec::new(),
+                affected_rows: None,
+                execution_time_ms,
+            });
+        };
+        let columns = Self::get_column_info(first);
+        let decoders = build_decoders(first.columns());
+        let rows: Vec<QRow> = sqlite_rows
+            .iter()
+            .map(|r| convert_row_with_decoders(r, &decoders))
+            .collect();
+        Ok(QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms,
+        })
+    }
+
+    /// `execute_stream_in_namespace` without the query timeout.
+    async fn stream_in_namespace(
+        &self,
+        session: SessionId,
+        _namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        if let Some(danger) = safety::classify_sqlite_dangerous(query) {
+            return Err(EngineError::not_supported(danger.reason()));
+        }
+        let sqlite_session = self.get_session(session).await?;
+
+        let mut conn = sqlite_session
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+
+        if !returns_rows {
+            let result = self
+                .run_in_namespace(session, None, query, query_id)
+                .await?;
+            let _ = sender
+                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
+                .await;
+            return Ok(());
+        }
+
+        let mut stream = sqlx::query(query).fetch(&mut *conn);
+        let mut columns_sent = false;
+        let mut decoders: Vec<SqliteDecoder> = Vec::new();
+        let mut row_count = 0;
+        let mut stream_error: Option<String> = None;
+        let mut batch = Vec::with_capacity(500);
+
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(sqlite_row) => {
+                    if !columns_sent {
+                        let columns = Self::get_column_info(&sqlite_row);
+                        decoders = build_decoders(sqlite_row.columns());
+                        if sender.send(StreamEvent::Columns(columns)).await.is_err() {
+                            break;
+                        }
+                        columns_sent = true;
+                    }
+
+                    let row = convert_row_with_decoders(&sqlite_row, &decoders);
+                    batch.push(row);
+                    row_count += 1;
+
+                    if batch.len() >= 500 {
+                        if sender
+                            .send(StreamEvent::RowBatch(std::mem::replace(
+                                &mut batch,
+                                Vec::with_capacity(500),
+                            )))
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                }
+                Err(e) => {
+                    let error_msg = e.to_string();
+                    let _ = sender.send(StreamEvent::Error(error_msg.clone())).await;
+                    stream_error = Some(error_msg);
+                    break;
+                }
+            }
+        }
+
+        if !batch.is_empty() {
+            let _ = sender.send(StreamEvent::RowBatch(batch)).await;
+        }
+
+        if stream_error.is_none() {
+            let _ = sender.send(StreamEvent::Done(row_count)).await;
+        }
+
+        if let Some(err) = stream_error {
+            return Err(EngineError::execution_error(err));
+        }
+
+        Ok(())
+    }
+
+    /// Order of a page without sort column: the primary key, else the
+    /// `rowid` of tables (views have none).
+    async fn table_implicit_order(
+        session: &SqliteSession,
+        namespace: &Namespace,
+        table: &str,
+    ) -> ImplicitOrder {
+        let schema = session
+            .attached_alias(namespace)
+            .unwrap_or_else(|| "main".to_string());
+        let sql = format!(
+            "SELECT m.type, p.name FROM {}.sqlite_master m \
+             LEFT JOIN pragma_table_info(m.name, ?) p ON p.pk > 0 \
+             WHERE m.name = ? ORDER BY p.pk",
+            Self::quote_ident(&schema)
+        );
+        let query = sqlx::query_as::<_, (String, Option<String>)>(&sql)
+            .bind(&schema)
+            .bind(table);
+        let rows = {
+            let mut tx_guard = session.transaction_conn.lock().await;
+            if let Some(ref mut conn) = *tx_guard {
+                query.fetch_all(&mut **conn).await
+            } else {
+                query.fetch_all(&session.pool).await
+            }
+        };
+
+        match rows {
+            Ok(rows) => {
+                let is_table = rows.first().is_some_and(|(kind, _)| kind == "table");
+                let primary_key = rows.into_iter().filter_map(|(_, name)| name).collect();
+                ImplicitOrder::new(primary_key, is_table.then_some("rowid"))
+            }
+            Err(e) => {
+                tracing::debug!("No implicit order for {}: {}", table, e);
+                ImplicitOrder::Unordered
+            }
+        }
+    }
+
+    /// `INSERT ... ON CONFLICT (keys) DO UPDATE SET ...` (SQLite 3.24+).
+    fn upsert_sql(table_name: &str, columns: &UpsertColumns) -> String {
+        let quoted = |cols: &[String]| {
+            cols.iter()
+                .map(|c| Self::quote_ident(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let set_clauses = columns
+            .update
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} = excluded.{}",
+                    Self::quote_ident(c),
+                    Self::quote_ident(c)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT ({}) DO UPDATE SET {}",
+            table_name,
+            quoted(&columns.insert),
+            vec!["?"; columns.insert.len()].join(", "),
+            quoted(&columns.conflict),
+            set_clauses
+        )
+    }
+
+    fn build_connect_options(config: &ConnectionConfig) -> SqliteConnectOptions {
+        use std::str::FromStr;
+
+        let path = &config.host;
+
+        let conn_str = if path == ":memory:" {
+            "sqlite::memory:".to_string()
+        } else {
+            format!("sqlite:{}", path)
+        };
+
+        let options = SqliteConnectOptions::from_str(&conn_str)
+            .unwrap_or_else(|_| SqliteConnectOptions::new().filename(path))
+            .busy_timeout(std::time::Duration::from_secs(30));
+
+        // Read-only sessions open with SQLITE_OPEN_READONLY so writes fail in
+        // SQLite itself. Switching to WAL is a write, so the file keeps
+        // whatever journal mode it already has.
+        if config.read_only {
+            options.read_only(true)
+        } else {
+            options
+                .create_if_missing(true)
+                .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal)
+        }
+    }
+
+    fn bind_param<'q>(
+        query: sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+        value: &'q Value,
+    ) -> sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>> {
+        match value {
+            Value::Null => query.bind(Option::<String>::None),
+            Value::Bool(b) => query.bind(b),
+            Value::Int(i) => query.bind(i),
+            Value::Float(f) => query.bind(f),
+            Value::Text(s) => query.bind(s.as_str()),
+            Value::Bytes(b) => query.bind(b.as_slice()),
+            Value::Json(j) => query.bind(j.to_string()),
+            Value::Array(_) => query.bind(Option::<String>::None),
+        }
+    }
+
+    /// Extracts a value from a SqliteRow by inspecting its actual runtime
+    /// storage class. Used for columns SQLx could not type statically — it
+    /// reports those as `NULL`, e.g. expressions or columns declared without
+    /// a type — and as the recovery path when a dispatched decoder fails.
+    /// SQLite is dynamically typed, so the storage class is read per value
+    /// rather than per column.
+    fn extract_value(row: &SqliteRow, idx: usize) -> Value {
+        let raw = match row.try_get_raw(idx) {
+            Ok(raw) => raw,
+            Err(_) => return Value::Null,
+        };
+        if raw.is_null() {
+            return Value::Null;
+        }
+
+        // For a non-NULL value, `type_info()` reflects the real storage class.
+        let decoded = match raw.type_info().name() {
+            "INTEGER" => row.try_get::<i64, _>(idx).map(Value::Int),
+            "REAL" => row.try_get::<f64, _>(idx).map(Value::Float),
+            "BLOB" => row.try_get::<Vec<u8>, _>(idx).map(Value::Bytes),
+            _ => row.try_get::<String, _>(idx).map(Value::Text),
+        };
+        decoded.unwrap_or(Value::Null)
+    }
+
+    fn get_column_info(row: &SqliteRow) -> Vec<ColumnInfo> {
+        row.columns()
+            .iter()
+            .map(|col| ColumnInfo {
+                name: col.name().into(),
+                data_type: col.type_info().name().into(),
+                nullable: true, // SQLite doesn't easily expose nullability from row metadata
+                case_insensitive: false,
+            })
+            .collect()
+    }
+    fn validate_path(path: &str) -> EngineResult<()> {
+        let path = path.trim();
+
+        if path == ":memory:" || path == "sqlite::memory:" {
+            return Ok(());
+        }
+
+        if path.eq_ignore_ascii_case("localhost") {
+            return Err(EngineError::connection_failed(
+                "Invalid SQLite path: 'localhost'. Please select a valid file path.".to_string(),
+            ));
+        }
+
+        if path.is_empty() {
+            return Err(EngineError::connection_failed(
+                "SQLite path cannot be empty.".to_string(),
+            ));
         }
 
         let path_lower = path.to_lowercase();
@@ -317,7 +844,7 @@ impl DataEngine for SqliteDriver {
 
     async fn test_connection(&self, config: &ConnectionConfig) -> EngineResult<()> {
         Self::validate_path(&config.host)?;
-        let pool = Self::create_pool(config, 1, 0, 10, true).await?;
+        let pool = Self::create_pool(config, 1, 0, 10, true, AttachedDatabases::default()).await?;
         pool.close().await;
         Ok(())
     }
@@ -328,6 +855,7 @@ impl DataEngine for SqliteDriver {
         let max_connections = config.pool_max_connections.unwrap_or(5);
         let min_connections = config.pool_min_connections.unwrap_or(0);
         let acquire_timeout = config.pool_acquire_timeout_secs.unwrap_or(30);
+        let attached = AttachedDatabases::default();
 
         let pool = Self::create_pool(
             config,
@@ -335,11 +863,17 @@ impl DataEngine for SqliteDriver {
             min_connections,
             acquire_timeout as u64,
             false,
+            Arc::clone(&attached),
         )
         .await?;
 
         let session_id = SessionId::new();
-        let session = Arc::new(SqliteSession::new(pool, config.host.clone()));
+        let session = Arc::new(SqliteSession::new(
+            pool,
+            config.host.clone(),
+            query_timeout(config.query_timeout_secs),
+            attached,
+        ));
 
         let mut sessions = self.sessions.write().await;
         sessions.insert(session_id, session);
@@ -364,30 +898,33 @@ impl DataEngine for SqliteDriver {
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<Duration> {
         let session = self.get_session(session).await?;
-        sqlx::query("SELECT 1")
+        let start = Instant::now();
+        sqlx::query("PRAGMA user_version")
             .execute(&session.pool)
             .await
             .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
-        Ok(())
+        Ok(start.elapsed())
     }
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
         let sqlite_session = self.get_session(session).await?;
 
-        // SQLite has one database per file — expose the filename as the namespace.
-        let db_name = if sqlite_session.db_path == ":memory:" {
-            "memory".to_string()
-        } else {
-            std::path::Path::new(&sqlite_session.db_path)
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("main")
-                .to_string()
-        };
+        // SQLite has one database per file — expose the filename as the
+        // namespace, followed by the attached databases under their alias.
+        let mut namespaces = vec![Namespace::new(sqlite_session.main_namespace())];
+        let attached = sqlite_session
+            .attached
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        namespaces.extend(attached.iter().map(|db| Namespace::new(db.alias.clone())));
+        Ok(namespaces)
+    }
 
-        Ok(vec![Namespace::new(db_name)])
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        let sqlite_session = self.get_session(session).await?;
+        Ok(vec![sqlite_session.main_namespace()])
     }
 
     async fn list_collections(
@@ -398,18 +935,21 @@ impl DataEngine for SqliteDriver {
     ) -> EngineResult<CollectionList> {
         let sqlite_session = self.get_session(session).await?;
         let pool = &sqlite_session.pool;
+        let prefix = sqlite_session.schema_prefix(namespace);
 
         let search_pattern = options.search.as_ref().map(|s| format!("%{}%", s));
 
-        let count_query = r#"
+        let count_query = format!(
+            r#"
             SELECT COUNT(*)
-            FROM sqlite_master
+            FROM {prefix}sqlite_master
             WHERE type = 'table'
             AND name NOT LIKE 'sqlite_%'
             AND ($1 IS NULL OR name LIKE $2)
-        "#;
+        "#
+        );
 
-        let count_row: (i64,) = sqlx::query_as(count_query)
+        let count_row: (i64,) = sqlx::query_as(&count_query)
             .bind(&search_pattern)
             .bind(&search_pattern)
             .fetch_one(pool)
@@ -418,15 +958,16 @@ impl DataEngine for SqliteDriver {
 
         let total_count = count_row.0;
 
-        let mut query_str = r#"
+        let mut query_str = format!(
+            r#"
             SELECT name, type
-            FROM sqlite_master
+            FROM {prefix}sqlite_master
             WHERE type IN ('table', 'view')
             AND name NOT LIKE 'sqlite_%'
             AND ($1 IS NULL OR name LIKE $2)
             ORDER BY name
         "#
-        .to_string();
+        );
 
         if let Some(limit) = options.page_size {
             query_str.push_str(&format!(" LIMIT {}", limit));
@@ -454,6 +995,7 @@ impl DataEngine for SqliteDriver {
                     namespace: namespace.clone(),
                     name,
                     collection_type,
+                    partition_of: None,
                 }
             })
             .collect();
@@ -461,6 +1003,7 @@ impl DataEngine for SqliteDriver {
         Ok(CollectionList {
             collections,
             total_count: total_count as u32,
+            next_cursor: None,
         })
     }
 
@@ -616,107 +1159,91 @@ impl DataEngine for SqliteDriver {
         ))
     }
 
-    async fn execute_stream(
+    async fn attach_database(
         &self,
         session: SessionId,
-        query: &str,
-        query_id: QueryId,
-        sender: StreamSender,
+        file_path: &str,
+        alias: &str,
     ) -> EngineResult<()> {
-        self.execute_stream_in_namespace(session, None, query, query_id, sender)
-            .await
-    }
+        validate_attach_alias(alias)?;
+        let sqlite_session = self.get_session(session).await?;
 
-    async fn execute_stream_in_namespace(
-        &self,
-        session: SessionId,
-        _namespace: Option<Namespace>,
-        query: &str,
-        query_id: QueryId,
-        sender: StreamSender,
-    ) -> EngineResult<()> {
-        if let Some(danger) = safety::classify_sqlite_dangerous(query) {
-            return Err(EngineError::not_supported(danger.reason()));
+        if alias == sqlite_session.main_namespace()
+            || sqlite_session
+                .attached
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .any(|db| db.alias.eq_ignore_ascii_case(alias))
+        {
+            return Err(EngineError::validation(format!(
+                "A database named '{}' is already open in this session",
+                alias
+            )));
         }
-        let sqlite_session = self.get_session(session).await?;
+        if sqlite_session.transaction_conn.lock().await.is_some() {
+            return Err(EngineError::transaction_error(
+                "Cannot attach a database while a transaction is active",
+            ));
+        }
+
+        // A plain `:memory:` would give each pooled connection its own empty
+        // database; a named shared-cache URI makes them all see the same one.
+        let target = if file_path == ":memory:" {
+            format!(
+                "file:qoredb-{}-{}?mode=memory&cache=shared",
+                session.0, alias
+            )
+        } else {
+            file_path.to_string()
+        };
 
         let mut conn = sqlite_session
             .pool
             .acquire()
             .await
             .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+        sqlx::query(&attach_sql(&target, alias))
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
 
-        let returns_rows = safety::returns_rows(self.driver_id(), query)
-            .unwrap_or_else(|_| safety::is_select_prefix(query));
-
-        if !returns_rows {
-            let result = self
-                .execute_in_namespace(session, None, query, query_id)
-                .await?;
-            let _ = sender
-                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
-                .await;
-            return Ok(());
-        }
-
-        let mut stream = sqlx::query(query).fetch(&mut *conn);
-        let mut columns_sent = false;
-        let mut decoders: Vec<SqliteDecoder> = Vec::new();
-        let mut row_count = 0;
-        let mut stream_error: Option<String> = None;
-        let mut batch = Vec::with_capacity(500);
-
-        while let Some(item) = stream.next().await {
-            match item {
-                Ok(sqlite_row) => {
-                    if !columns_sent {
-                        let columns = Self::get_column_info(&sqlite_row);
-                        decoders = build_decoders(sqlite_row.columns());
-                        if sender.send(StreamEvent::Columns(columns)).await.is_err() {
-                            break;
-                        }
-                        columns_sent = true;
-                    }
-
-                    let row = convert_row_with_decoders(&sqlite_row, &decoders);
-                    batch.push(row);
-                    row_count += 1;
-
-                    if batch.len() >= 500 {
-                        if sender
-                            .send(StreamEvent::RowBatch(std::mem::replace(
-                                &mut batch,
-                                Vec::with_capacity(500),
-                            )))
-                            .await
-                            .is_err()
-                        {
-                            break;
-                        }
-                    }
-                }
-                Err(e) => {
-                    let error_msg = e.to_string();
-                    let _ = sender.send(StreamEvent::Error(error_msg.clone())).await;
-                    stream_error = Some(error_msg);
-                    break;
-                }
-            }
-        }
-
-        if !batch.is_empty() {
-            let _ = sender.send(StreamEvent::RowBatch(batch)).await;
-        }
-
-        if stream_error.is_none() {
-            let _ = sender.send(StreamEvent::Done(row_count)).await;
-        }
+        sqlite_session
+            .attached
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(AttachedDatabase {
+                alias: alias.to_string(),
+                target,
+            });
+        Ok(())
+    }
 
-        if let Some(err) = stream_error {
-            return Err(EngineError::execution_error(err));
-        }
+    async fn execute_stream(
+        &self,
+        session: SessionId,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        self.execute_stream_in_namespace(session, None, query, query_id, sender)
+            .await
+    }
 
-        Ok(())
+    async fn execute_stream_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_detached_timeout(
+            timeout,
+            self.stream_in_namespace(session, namespace, query, query_id, sender),
+        )
+        .await
     }
 
     async fn execute(
@@ -732,159 +1259,41 @@ impl DataEngine for SqliteDriver {
     async fn execute_in_namespace(
         &self,
         session: SessionId,
-        _namespace: Option<Namespace>,
+        namespace: Option<Namespace>,
         query: &str,
-        _query_id: QueryId,
+        query_id: QueryId,
     ) -> EngineResult<QueryResult> {
-        // Block ATTACH DATABASE (mounts arbitrary files past the read-only
-        // flag) and the specific destructive PRAGMA assignments
-        // (`writable_schema`, `journal_mode = OFF`, `foreign_keys = OFF`).
-        // Read-only PRAGMA inspections used by the UI remain allowed. See
-        // audit B3-C4 / B3-C5.
-        if let Some(danger) = safety::classify_sqlite_dangerous(query) {
-            return Err(EngineError::not_supported(danger.reason()));
-        }
-        let sqlite_session = self.get_session(session).await?;
-        let start = Instant::now();
-
-        let returns_rows = safety::returns_rows(self.driver_id(), query)
-            .unwrap_or_else(|_| safety::is_select_prefix(query));
-
-        let mut tx_guard = sqlite_session.transaction_conn.lock().await;
-
-        let result = if let Some(ref mut conn) = *tx_guard {
-            if returns_rows {
-                let sqlite_rows: Vec<SqliteRow> = sqlx::query(query)
-                    .fetch_all(&mut **conn)
-                    .await
-                    .map_err(|e| {
-                    let msg = e.to_string();
-                    if msg.contains("syntax") {
-                        EngineError::syntax_error(msg)
-                    } else {
-                        EngineError::execution_error(msg)
-                    }
-                })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                if sqlite_rows.is_empty() {
-                    Ok(QueryResult {
-                        columns: Vec::new(),
-                        rows: Vec::new(),
-                        affected_rows: None,
-                        execution_time_ms,
-                    })
-                } else {
-                    let columns = Self::get_column_info(&sqlite_rows[0]);
-                    let decoders = build_decoders(sqlite_rows[0].columns());
-                    let rows: Vec<QRow> = sqlite_rows
-                        .iter()
-                        .map(|r| convert_row_with_decoders(r, &decoders))
-                        .collect();
-
-                    Ok(QueryResult {
-                        columns,
-                        rows,
-                        affected_rows: None,
-                        execution_time_ms,
-                    })
-                }
-            } else {
-                let result = sqlx::query(query).execute(&mut **conn).await.map_err(|e| {
-                    let msg = e.to_string();
-                    if msg.contains("syntax") {
-                        EngineError::syntax_error(msg)
-                    } else {
-                        EngineError::execution_error(msg)
-                    }
-                })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                Ok(QueryResult::with_affected_rows(
-                    result.rows_affected(),
-                    execution_time_ms,
-                ))
-            }
-        } else {
-            // Use pool
-            let mut conn = sqlite_session
-                .pool
-                .acquire()
-                .await
-                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
-
-            if returns_rows {
-                let sqlite_rows: Vec<SqliteRow> = sqlx::query(query)
-                    .fetch_all(&mut *conn)
-                    .await
-                    .map_err(|e| {
-                        let msg = e.to_string();
-                        if msg.contains("syntax") {
-                            EngineError::syntax_error(msg)
-                        } else {
-                            EngineError::execution_error(msg)
-                        }
-                    })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                if sqlite_rows.is_empty() {
-                    Ok(QueryResult {
-                        columns: Vec::new(),
-                        rows: Vec::new(),
-                        affected_rows: None,
-                        execution_time_ms,
-                    })
-                } else {
-                    let columns = Self::get_column_info(&sqlite_rows[0]);
-                    let decoders = build_decoders(sqlite_rows[0].columns());
-                    let rows: Vec<QRow> = sqlite_rows
-                        .iter()
-                        .map(|r| convert_row_with_decoders(r, &decoders))
-                        .collect();
-
-                    Ok(QueryResult {
-                        columns,
-                        rows,
-                        affected_rows: None,
-                        execution_time_ms,
-                    })
-                }
-            } else {
-                let result = sqlx::query(query).execute(&mut *conn).await.map_err(|e| {
-                    let msg = e.to_string();
-                    if msg.contains("syntax") {
-                        EngineError::syntax_error(msg)
-                    } else {
-                        EngineError::execution_error(msg)
-                    }
-                })?;
-
-                let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
-
-                Ok(QueryResult::with_affected_rows(
-                    result.rows_affected(),
-                    execution_time_ms,
-                ))
-            }
-        };
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_detached_timeout(
+            timeout,
+            self.run_in_namespace(session, namespace, query, query_id),
+        )
+        .await
+    }
 
-        result
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        _query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let timeout = self.get_session(session).await?.query_timeout;
+        with_detached_timeout(timeout, self.run_with_params(session, query, params)).await
     }
 
     async fn describe_table(
         &self,
         session: SessionId,
-        _namespace: &Namespace,
+        namespace: &Namespace,
         table: &str,
     ) -> EngineResult<TableSchema> {
         let sqlite_session = self.get_session(session).await?;
         let pool = &sqlite_session.pool;
+        let prefix = sqlite_session.schema_prefix(namespace);
 
         let table_ident = Self::quote_ident(table);
-        let pragma_query = format!("PRAGMA table_info({})", table_ident);
+        let pragma_query = format!("PRAGMA {}table_info({})", prefix, table_ident);
 
         let column_rows: Vec<(i64, String, String, i64, Option<String>, i64)> =
             sqlx::query_as(&pragma_query)
@@ -907,6 +1316,11 @@ impl DataEngine for SqliteDriver {
                     default_value: dflt_value,
                     is_primary_key,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 }
             })
             .collect();
@@ -920,7 +1334,7 @@ impl DataEngine for SqliteDriver {
             }
         }
 
-        let fk_query = format!("PRAGMA foreign_key_list({})", table_ident);
+        let fk_query = format!("PRAGMA {}foreign_key_list({})", prefix, table_ident);
         let fk_rows: Vec<(i64, i64, String, String, String, String, String, String)> =
             sqlx::query_as(&fk_query)
                 .fetch_all(pool)
@@ -944,7 +1358,7 @@ impl DataEngine for SqliteDriver {
             )
             .collect();
 
-        let index_query = format!("PRAGMA index_list({})", table_ident);
+        let index_query = format!("PRAGMA {}index_list({})", prefix, table_ident);
         let index_list: Vec<(i64, String, i64, String, i64)> = sqlx::query_as(&index_query)
             .fetch_all(pool)
             .await
@@ -952,7 +1366,11 @@ impl DataEngine for SqliteDriver {
 
         let mut indexes: Vec<TableIndex> = Vec::new();
         for (_seq, index_name, is_unique, _origin, _partial) in index_list {
-            let index_info_query = format!("PRAGMA index_info({})", Self::quote_ident(&index_name));
+            let index_info_query = format!(
+                "PRAGMA {}index_info({})",
+                prefix,
+                Self::quote_ident(&index_name)
+            );
             let index_cols: Vec<(i64, i64, String)> = sqlx::query_as(&index_info_query)
                 .fetch_all(pool)
                 .await
@@ -987,43 +1405,104 @@ impl DataEngine for SqliteDriver {
             },
             foreign_keys,
             row_count_estimate,
+            row_count_is_estimate: false,
             indexes,
+            partitioning: None,
+            row_security: None,
         })
     }
 
-    async fn preview_table(
+    async fn get_table_ddl(
         &self,
         session: SessionId,
-        _namespace: &Namespace,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        let sqlite_session = self.get_session(session).await?;
+        let prefix = sqlite_session.schema_prefix(namespace);
+
+        // sqlite_master keeps the original statements verbatim; the table
+        // comes first, then its indexes and triggers in creation order.
+        let query = format!(
+            "SELECT sql FROM {prefix}sqlite_master \
+             WHERE tbl_name = ? AND sql IS NOT NULL \
+             ORDER BY CASE type WHEN 'table' THEN 0 WHEN 'index' THEN 1 ELSE 2 END, rowid"
+        );
+        let statements: Vec<String> = sqlx::query_scalar(&query)
+            .bind(table)
+            .fetch_all(&sqlite_session.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        if statements.is_empty() {
+            return Err(EngineError::execution_error("Table not found"));
+        }
+
+        Ok(statements.iter().map(|sql| format!("{sql};\n")).collect())
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        spec.validate()?;
+        let sqlite_session = self.get_session(session).await?;
+        let index_namespace = Namespace {
+            database: namespace.database.clone(),
+            schema: sqlite_session.attached_alias(namespace),
+        };
+        let sql = generate_create_index(SqlDialect::Sqlite, &index_namespace, table, spec);
+        sqlx::query(&sql)
+            .execute(&sqlite_session.pool)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn preview_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
         table: &str,
         limit: u32,
     ) -> EngineResult<QueryResult> {
-        let query = format!("SELECT * FROM {} LIMIT {}", Self::quote_ident(table), limit);
+        let prefix = self.get_session(session).await?.schema_prefix(namespace);
+        let query = format!(
+            "SELECT * FROM {}{} LIMIT {}",
+            prefix,
+            Self::quote_ident(table),
+            limit
+        );
         self.execute(session, &query, QueryId::new()).await
     }
 
     async fn query_table(
         &self,
         session: SessionId,
-        _namespace: &Namespace,
+        namespace: &Namespace,
         table: &str,
         options: TableQueryOptions,
     ) -> EngineResult<PaginatedQueryResult> {
         let sqlite_session = self.get_session(session).await?;
         let start = Instant::now();
 
+        let prefix = sqlite_session.schema_prefix(namespace);
         let table_ident = Self::quote_ident(table);
+        let table_ref = format!("{}{}", prefix, table_ident);
 
         let page = options.effective_page();
         let page_size = options.effective_page_size();
         let offset = options.offset();
+        let page_columns = PageColumns::new(SqlDialect::Sqlite, &options)?;
 
         let mut where_clauses: Vec<String> = Vec::new();
         let mut bind_values: Vec<Value> = Vec::new();
 
         if let Some(filters) = &options.filters {
             for filter in filters {
-                let col_ident = Self::quote_ident(&filter.column);
+                let col_ident = page_columns.expr(&filter.column);
 
                 let clause = match filter.operator {
                     FilterOperator::Eq => {
@@ -1093,7 +1572,7 @@ impl DataEngine for SqliteDriver {
 
         if let Some(ref search_term) = options.search {
             if !search_term.trim().is_empty() {
-                let pragma_query = format!("PRAGMA table_info({})", table_ident);
+                let pragma_query = format!("PRAGMA {}table_info({})", prefix, table_ident);
                 let columns_rows: Vec<(i64, String, String, i64, Option<String>, i64)> =
                     sqlx::query_as(&pragma_query)
                         .fetch_all(&sqlite_session.pool)
@@ -1134,18 +1613,14 @@ impl DataEngine for SqliteDriver {
             format!(" WHERE {}", where_clauses.join(" AND "))
         };
 
-        let order_sql = if let Some(sort_col) = &options.sort_column {
-            let sort_ident = Self::quote_ident(sort_col);
-            let direction = match options.sort_direction.unwrap_or_default() {
-                SortDirection::Asc => "ASC",
-                SortDirection::Desc => "DESC",
-            };
-            format!(" ORDER BY {} {}", sort_ident, direction)
+        let implicit_order = if options.sort_column.is_none() {
+            Self::table_implicit_order(&sqlite_session, namespace, table).await
         } else {
-            String::new()
+            ImplicitOrder::Unordered
         };
+        let order_sql = options.order_by_sql(&implicit_order, |c| page_columns.expr(c));
 
-        let count_sql = format!("SELECT COUNT(*) AS cnt FROM {}{}", table_ident, where_sql);
+        let count_sql = format!("SELECT COUNT(*) AS cnt FROM {}{}", table_ref, where_sql);
         let mut count_query = sqlx::query(&count_sql);
         for val in &bind_values {
             count_query = Self::bind_param(count_query, val);
@@ -1168,8 +1643,13 @@ impl DataEngine for SqliteDriver {
 
         // Execute data query with pagination
         let data_sql = format!(
-            "SELECT * FROM {}{}{} LIMIT {} OFFSET {}",
-            table_ident, where_sql, order_sql, page_size, offset
+            "SELECT {} FROM {}{}{} LIMIT {} OFFSET {}",
+            page_columns.select_list(&table_ref),
+            table_ref,
+            where_sql,
+            order_sql,
+            page_size,
+            offset
         );
 
         let mut data_query = sqlx::query(&data_sql);
@@ -1191,7 +1671,7 @@ impl DataEngine for SqliteDriver {
 
         let result = if sqlite_rows.is_empty() {
             // No rows means no driver column metadata — fetch it from PRAGMA instead.
-            let pragma_col_sql = format!("PRAGMA table_info({})", table_ident);
+            let pragma_col_sql = format!("PRAGMA {}table_info({})", prefix, table_ident);
             let pragma_rows: Vec<(i64, String, String, i64, Option<String>, i64)> =
                 sqlx::query_as(&pragma_col_sql)
                     .fetch_all(&sqlite_session.pool)
@@ -1204,6 +1684,7 @@ impl DataEngine for SqliteDriver {
                     name: name.as_str().into(),
                     data_type: data_type.as_str().into(),
                     nullable: *notnull == 0,
+                    case_insensitive: false,
                 })
                 .collect();
 
@@ -1289,6 +1770,17 @@ impl DataEngine for SqliteDriver {
         })
     }
 
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        column_stats::column_stats(self, SqlDialect::Sqlite, session, namespace, table, column)
+            .await
+    }
+
     async fn cancel(&self, _session: SessionId, _query_id: Option<QueryId>) -> EngineResult<()> {
         // Cancellation requires `sqlite3_interrupt` on the same connection running the query —
         // the pool architecture can't reach the busy connection from another thread.
@@ -1301,6 +1793,12 @@ impl DataEngine for SqliteDriver {
         CancelSupport::None
     }
 
+    /// Writes serialize on the single database file, so queries are queued
+    /// one at a time regardless of the pool size.
+    fn default_query_concurrency(&self, _config: &ConnectionConfig) -> usize {
+        1
+    }
+
     async fn begin_transaction(&self, session: SessionId) -> EngineResult<()> {
         let sqlite_session = self.get_session(session).await?;
         let mut tx = sqlite_session.transaction_conn.lock().await;
@@ -1369,6 +1867,12 @@ impl DataEngine for SqliteDriver {
         true
     }
 
+    /// SQLite transactions are always serializable; accepting the level
+    /// lets apps that require it declare so explicitly.
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        &[IsolationLevel::Serializable]
+    }
+
     fn supports_streaming(&self) -> bool {
         true
     }
@@ -1377,6 +1881,45 @@ impl DataEngine for SqliteDriver {
         true
     }
 
+    /// SQLite has no structured plan format; the `EXPLAIN QUERY PLAN` rows
+    /// are linked into a tree through their `parent` ids.
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        if analyze {
+            return Err(EngineError::not_supported(
+                "SQLite has no EXPLAIN ANALYZE; only the query plan is available",
+            ));
+        }
+        let sql = format!("EXPLAIN QUERY PLAN {}", explain::explain_target(query));
+        let result = self
+            .execute_in_namespace(session, namespace, &sql, QueryId::new())
+            .await?;
+
+        // Columns: id, parent, notused, detail
+        let rows: Vec<(i64, i64, String)> = result
+            .rows
+            .iter()
+            .filter_map(|row| match row.values.as_slice() {
+                [Value::Int(id), Value::Int(parent), _, Value::Text(detail)] => {
+                    Some((*id, *parent, detail.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        let (raw, root) = explain::sqlite_plan(&rows);
+        Ok(ExplainPlan {
+            raw,
+            format: ExplainFormat::Text,
+            root: Some(root),
+            analyzed: false,
+        })
+    }
+
     async fn insert_row(
         &self,
         session: SessionId,
@@ -1428,6 +1971,47 @@ impl DataEngine for SqliteDriver {
         ))
     }
 
+    /// SQLite does not report which branch ran, so `action` is always `None`.
+    async fn upsert_row(
+        &self,
+        session: SessionId,
+        _namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        let sqlite_session = self.get_session(session).await?;
+        let columns = UpsertColumns::new(data, conflict_columns)?;
+        let sql = Self::upsert_sql(&Self::quote_ident(table), &columns);
+
+        let mut query = sqlx::query(&sql);
+        for k in &columns.insert {
+            query = Self::bind_param(query, &data.columns[k]);
+        }
+
+        let start = Instant::now();
+        let mut tx_guard = sqlite_session.transaction_conn.lock().await;
+        let result = if let Some(ref mut conn) = *tx_guard {
+            query.execute(&mut **conn).await
+        } else {
+            query.execute(&sqlite_session.pool).await
+        };
+
+        let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        Ok(UpsertResult {
+            result: QueryResult::with_affected_rows(
+                result.rows_affected(),
+                start.elapsed().as_micros() as f64 / 1000.0,
+            ),
+            action: None,
+        })
+    }
+
+    fn supports_upsert(&self) -> bool {
+        true
+    }
+
     async fn update_row(
         &self,
         session: SessionId,
@@ -1556,10 +2140,41 @@ impl DataEngine for SqliteDriver {
         ))
     }
 
+    /// SQLite has no `TRUNCATE`; an unfiltered `DELETE` takes the same
+    /// truncate fast path and still reports the removed row count.
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        _namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        let sqlite_session = self.get_session(session).await?;
+        let sql = format!("DELETE FROM {}", Self::quote_ident(table));
+
+        let start = Instant::now();
+        let mut tx_guard = sqlite_session.transaction_conn.lock().await;
+        let result = if let Some(ref mut conn) = *tx_guard {
+            sqlx::query(&sql).execute(&mut **conn).await
+        } else {
+            sqlx::query(&sql).execute(&sqlite_session.pool).await
+        };
+
+        let result = result.map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        Ok(QueryResult::with_affected_rows(
+            result.rows_affected(),
+            start.elapsed().as_micros() as f64 / 1000.0,
+        ))
+    }
+
     fn supports_mutations(&self) -> bool {
         true
     }
 
+    fn supports_computed_columns(&self) -> bool {
+        true
+    }
+
     fn supports_maintenance(&self) -> bool {
         true
     }
@@ -1681,6 +2296,7 @@ impl DataEngine for SqliteDriver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qore_core::types::{ColumnFilter, ComputedColumn, DescribeTableOptions};
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -1689,29 +2305,82 @@ mod tests {
         let dir = tempdir().unwrap();
         let db_path = dir.path().join("test.db");
 
-        let config = ConnectionConfig {
-            driver: "sqlite".to_string(),
-            host: db_path.to_string_lossy().to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::local_for_tests("sqlite", &db_path.to_string_lossy());
+
+        let session_id = driver.connect(&config).await.unwrap();
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ping_reports_latency() {
+        let driver = SqliteDriver::new();
+        let config = ConnectionConfig::sqlite_memory_for_tests();
 
         let session_id = driver.connect(&config).await.unwrap();
+        let latency = driver.ping(session_id).await.unwrap();
+        assert!(latency > Duration::ZERO);
+
+        let err = driver.ping(SessionId::new()).await.unwrap_err();
+        assert!(matches!(err, EngineError::SessionNotFound { .. }), "{err}");
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_params_binds_literally() {
+        let driver = SqliteDriver::new();
+        let dir = tempdir().unwrap();
+        let config = ConnectionConfig::local_for_tests(
+            "sqlite",
+            &dir.path().join("params.db").to_string_lossy(),
+        );
+        let session_id = driver.connect(&config).await.unwrap();
+        driver
+            .execute(
+                session_id,
+                "CREATE TABLE people (id INTEGER PRIMARY KEY, name TEXT)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let hostile = Value::Text("O'Brien'); DROP TABLE people; --".to_string());
+        let inserted = driver
+            .execute_with_params(
+                session_id,
+                "INSERT INTO people (id, name) VALUES (?, ?)",
+                &[Value::Int(1), hostile.clone()],
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(inserted.affected_rows, Some(1));
+
+        let found = driver
+            .execute_with_params(
+                session_id,
+                "SELECT id, name FROM people WHERE name = ?",
+                std::slice::from_ref(&hostile),
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(found.rows.len(), 1);
+        assert!(matches!(
+            &found.rows[0].values[1],
+            Value::Text(name) if name == "O'Brien'); DROP TABLE people; --"
+        ));
+
+        let missing = driver
+            .execute_with_params(
+                session_id,
+                "SELECT id FROM people WHERE name = ?",
+                &[Value::Text("x' OR '1'='1".to_string())],
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert!(missing.rows.is_empty());
+
         driver.disconnect(session_id).await.unwrap();
     }
 
@@ -1719,27 +2388,7 @@ mod tests {
     async fn test_memory_database() {
         let driver = SqliteDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "sqlite".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::sqlite_memory_for_tests();
 
         let session_id = driver.connect(&config).await.unwrap();
 
@@ -1779,27 +2428,7 @@ mod tests {
     async fn test_transactions() {
         let driver = SqliteDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "sqlite".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::sqlite_memory_for_tests();
 
         let session_id = driver.connect(&config).await.unwrap();
 
@@ -1846,27 +2475,7 @@ mod tests {
     async fn test_untyped_column_decodes_runtime_value() {
         let driver = SqliteDriver::new();
 
-        let config = ConnectionConfig {
-            driver: "sqlite".to_string(),
-            host: ":memory:".to_string(),
-            port: 0,
-            username: String::new(),
-            password: String::new(),
-            database: None,
-            ssl: false,
-            ssl_mode: None,
-            environment: "development".to_string(),
-            read_only: false,
-            ssh_tunnel: None,
-            pool_acquire_timeout_secs: None,
-            pool_max_connections: None,
-            pool_min_connections: None,
-            proxy: None,
-            mssql_auth: None,
-            clickhouse_cluster: None,
-            search_auth_mode: None,
-            ssl_ca_cert: None,
-        };
+        let config = ConnectionConfig::sqlite_memory_for_tests();
 
         let session_id = driver.connect(&config).await.unwrap();
 
@@ -1898,4 +2507,527 @@ mod tests {
 
         driver.disconnect(session_id).await.unwrap();
     }
+
+    #[test]
+    fn test_upsert_sql() {
+        let data = RowData::new()
+            .with_column("id", Value::Int(1))
+            .with_column("name", Value::Text("a".into()));
+        let columns = UpsertColumns::new(&data, &["id".to_string()]).unwrap();
+        assert_eq!(
+            SqliteDriver::upsert_sql("\"users\"", &columns),
+            "INSERT INTO \"users\" (\"id\", \"name\") VALUES (?, ?) \
+             ON CONFLICT (\"id\") DO UPDATE SET \"name\" = excluded.\"name\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upsert_row_round_trip() {
+        let driver = SqliteDriver::new();
+
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+
+        let session_id = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::new("main");
+        driver
+            .execute(
+                session_id,
+                "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, visits INTEGER)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let conflict = ["id".to_string()];
+        let first = RowData::new()
+            .with_column("id", Value::Int(1))
+            .with_column("name", Value::Text("ada".into()))
+            .with_column("visits", Value::Int(1));
+        let result = driver
+            .upsert_row(session_id, &namespace, "users", &first, &conflict)
+            .await
+            .unwrap();
+        assert_eq!(result.result.affected_rows, Some(1));
+
+        let second = RowData::new()
+            .with_column("id", Value::Int(1))
+            .with_column("name", Value::Text("grace".into()))
+            .with_column("visits", Value::Null);
+        driver
+            .upsert_row(session_id, &namespace, "users", &second, &conflict)
+            .await
+            .unwrap();
+
+        let result = driver
+            .execute(
+                session_id,
+                "SELECT id, name, visits FROM users",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+        assert!(matches!(&result.rows[0].values[1], Value::Text(s) if s == "grace"));
+        assert!(matches!(&result.rows[0].values[2], Value::Null));
+
+        let missing_key = RowData::new().with_column("name", Value::Text("x".into()));
+        assert!(driver
+            .upsert_row(session_id, &namespace, "users", &missing_key, &conflict)
+            .await
+            .is_err());
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_table_pages_follow_primary_key() {
+        let driver = SqliteDriver::new();
+
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+
+        let session_id = driver.connect(&config).await.unwrap();
+        let namespace = Namespace::new("main");
+        driver
+            .execute(
+                session_id,
+                "CREATE TABLE codes (code TEXT PRIMARY KEY, n INTEGER)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        // Insertion (rowid) order differs from key order.
+        driver
+            .execute(
+                session_id,
+                "INSERT INTO codes VALUES ('e', 1), ('a', 2), ('g', 3), ('c', 4), \
+                 ('b', 5), ('f', 6), ('d', 7)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let mut codes = Vec::new();
+        for page in 1..=3 {
+            let options = TableQueryOptions {
+                page: Some(page),
+                page_size: Some(3),
+                ..Default::default()
+            };
+            let result = driver
+                .query_table(session_id, &namespace, "codes", options)
+                .await
+                .unwrap();
+            assert_eq!(result.total_rows, 7);
+            for row in &result.result.rows {
+                match &row.values[0] {
+                    Value::Text(code) => codes.push(code.clone()),
+                    other => panic!("unexpected code value: {:?}", other),
+                }
+            }
+        }
+        assert_eq!(codes, ["a", "b", "c", "d", "e", "f", "g"]);
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_explain_query_builds_plan_tree() {
+        let driver = SqliteDriver::new();
+
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+
+        let session_id = driver.connect(&config).await.unwrap();
+        driver
+            .execute(
+                session_id,
+                "CREATE TABLE codes (code TEXT PRIMARY KEY, n INTEGER)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let plan = driver
+            .explain_query(session_id, None, "SELECT * FROM codes ORDER BY n;", false)
+            .await
+            .unwrap();
+        assert_eq!(plan.format, ExplainFormat::Text);
+        assert!(plan.raw.contains("codes"));
+        // The scan and the sort for ORDER BY
+        assert_eq!(plan.root.unwrap().children.len(), 2);
+
+        let analyzed = driver
+            .explain_query(session_id, None, "SELECT * FROM codes", true)
+            .await;
+        assert!(matches!(analyzed, Err(EngineError::NotSupported { .. })));
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_attach_file_database() {
+        let driver = SqliteDriver::new();
+        let dir = tempdir().unwrap();
+        let main_path = dir.path().join("main.db");
+        let ref_path = dir.path().join("ref.sqlite");
+        let ref_path = ref_path.to_string_lossy().to_string();
+
+        let seed = driver
+            .connect(&ConnectionConfig::local_for_tests("sqlite", &ref_path))
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE codes (code TEXT PRIMARY KEY, label TEXT)",
+            "INSERT INTO codes VALUES ('FR', 'France'), ('DE', 'Germany')",
+        ] {
+            driver.execute(seed, sql, QueryId::new()).await.unwrap();
+        }
+        driver.disconnect(seed).await.unwrap();
+
+        let session_id = driver
+            .connect(&attach_test_config(&main_path.to_string_lossy()))
+            .await
+            .unwrap();
+        driver
+            .execute(
+                session_id,
+                "CREATE TABLE users (id INTEGER PRIMARY KEY, country TEXT)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        driver
+            .attach_database(session_id, &ref_path, "ref")
+            .await
+            .unwrap();
+
+        let namespaces = driver.list_namespaces(session_id).await.unwrap();
+        let names: Vec<_> = namespaces.iter().map(|ns| ns.database.as_str()).collect();
+        assert_eq!(names, vec!["main", "ref"]);
+
+        let list = driver
+            .list_collections(
+                session_id,
+                &Namespace::new("ref".to_string()),
+                CollectionListOptions::default(),
+            )
+            .await
+            .unwrap();
+        let tables: Vec<_> = list.collections.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(tables, vec!["codes"]);
+
+        let schema = driver
+            .describe_table(session_id, &Namespace::new("ref".to_string()), "codes")
+            .await
+            .unwrap();
+        assert_eq!(schema.columns.len(), 2);
+
+        driver
+            .execute(
+                session_id,
+                "INSERT INTO users (country) VALUES ('FR')",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        let joined = driver
+            .execute(
+                session_id,
+                "SELECT c.label FROM users u JOIN ref.codes c ON c.code = u.country",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(joined.rows.len(), 1);
+        assert!(matches!(&joined.rows[0].values[0], Value::Text(label) if label == "France"));
+
+        let duplicate = driver.attach_database(session_id, &ref_path, "REF").await;
+        assert!(matches!(
+            duplicate,
+            Err(EngineError::ValidationError { .. })
+        ));
+        for alias in ["main", "bad alias", "x\"; DROP", ""] {
+            let invalid = driver.attach_database(session_id, &ref_path, alias).await;
+            assert!(matches!(invalid, Err(EngineError::ValidationError { .. })));
+        }
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_attach_memory_database() {
+        let driver = SqliteDriver::new();
+        let session_id = driver
+            .connect(&ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        driver
+            .attach_database(session_id, ":memory:", "scratch")
+            .await
+            .unwrap();
+
+        for sql in [
+            "CREATE TABLE scratch.notes (body TEXT)",
+            "INSERT INTO scratch.notes VALUES ('a'), ('b')",
+        ] {
+            driver
+                .execute(session_id, sql, QueryId::new())
+                .await
+                .unwrap();
+        }
+
+        let list = driver
+            .list_collections(
+                session_id,
+                &Namespace::new("scratch".to_string()),
+                CollectionListOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(list.total_count, 1);
+        assert_eq!(list.collections[0].name, "notes");
+
+        let page = driver
+            .query_table(
+                session_id,
+                &Namespace::new("scratch".to_string()),
+                "notes",
+                TableQueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total_rows, 2);
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_only_session_rejects_writes() {
+        let driver = SqliteDriver::new();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("ro.db").to_string_lossy().to_string();
+
+        let writer = driver
+            .connect(&ConnectionConfig::local_for_tests("sqlite", &path))
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE items (id INTEGER PRIMARY KEY)",
+            "INSERT INTO items VALUES (1)",
+        ] {
+            driver.execute(writer, sql, QueryId::new()).await.unwrap();
+        }
+        driver.disconnect(writer).await.unwrap();
+
+        let mut config = ConnectionConfig::local_for_tests("sqlite", &path);
+        config.read_only = true;
+        let reader = driver.connect(&config).await.unwrap();
+
+        let result = driver
+            .execute(reader, "SELECT COUNT(*) FROM items", QueryId::new())
+            .await
+            .unwrap();
+        assert!(matches!(result.rows[0].values[0], Value::Int(1)));
+        assert!(driver
+            .execute(reader, "INSERT INTO items VALUES (2)", QueryId::new())
+            .await
+            .is_err());
+
+        driver.disconnect(reader).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_table_ddl_recreates_table() {
+        let driver = SqliteDriver::new();
+        let dir = tempdir().unwrap();
+        let source_path = dir.path().join("source.db").to_string_lossy().to_string();
+        let target_path = dir.path().join("target.db").to_string_lossy().to_string();
+        let namespace = Namespace::new("main");
+
+        let source = driver
+            .connect(&ConnectionConfig::local_for_tests("sqlite", &source_path))
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE items (id INTEGER PRIMARY KEY, code TEXT NOT NULL UNIQUE, price REAL DEFAULT 0)",
+            "CREATE INDEX idx_items_price ON items (price)",
+        ] {
+            driver.execute(source, sql, QueryId::new()).await.unwrap();
+        }
+        let ddl = driver
+            .get_table_ddl(source, &namespace, "items")
+            .await
+            .unwrap();
+        assert!(driver
+            .get_table_ddl(source, &namespace, "missing")
+            .await
+            .is_err());
+        driver.disconnect(source).await.unwrap();
+
+        assert!(ddl.starts_with("CREATE TABLE items"), "{ddl}");
+        assert!(ddl.contains("CREATE INDEX idx_items_price"), "{ddl}");
+
+        let target = driver
+            .connect(&ConnectionConfig::local_for_tests("sqlite", &target_path))
+            .await
+            .unwrap();
+        for statement in ddl.split(";\n").filter(|s| !s.trim().is_empty()) {
+            driver
+                .execute(target, statement, QueryId::new())
+                .await
+                .unwrap();
+        }
+        let schema = driver
+            .describe_table(target, &namespace, "items")
+            .await
+            .unwrap();
+        assert_eq!(schema.columns.len(), 3);
+        assert!(schema
+            .indexes
+            .iter()
+            .any(|index| index.name == "idx_items_price"));
+        driver.disconnect(target).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_databases_returns_the_single_file() {
+        let driver = SqliteDriver::new();
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("shop.db");
+        let session_id = driver
+            .connect(&attach_test_config(&db_path.to_string_lossy()))
+            .await
+            .unwrap();
+
+        let databases = driver.list_databases(session_id).await.unwrap();
+        assert_eq!(databases, vec!["shop".to_string()]);
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_truncate_table_removes_every_row() {
+        let driver = SqliteDriver::new();
+        let session_id = driver
+            .connect(&ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, label TEXT)",
+            "INSERT INTO events (label) VALUES ('a'), ('b'), ('c')",
+        ] {
+            driver
+                .execute(session_id, sql, QueryId::new())
+                .await
+                .unwrap();
+        }
+        let namespace = Namespace::new("main");
+
+        let result = driver
+            .truncate_table(session_id, &namespace, "events")
+            .await
+            .unwrap();
+        assert_eq!(result.affected_rows, Some(3));
+
+        let count = driver
+            .execute(session_id, "SELECT COUNT(*) FROM events", QueryId::new())
+            .await
+            .unwrap();
+        assert!(matches!(count.rows[0].values[0], Value::Int(0)));
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_table_filters_and_sorts_on_computed_column() {
+        let driver = SqliteDriver::new();
+        let session_id = driver
+            .connect(&ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE orders (id INTEGER PRIMARY KEY, data TEXT)",
+            r#"INSERT INTO orders (data) VALUES
+                ('{"status": "paid", "total": 30}'),
+                ('{"status": "open", "total": 10}'),
+                ('{"status": "paid", "total": 20}')"#,
+        ] {
+            driver
+                .execute(session_id, sql, QueryId::new())
+                .await
+                .unwrap();
+        }
+        let computed = |alias: &str, json_path: &str| ComputedColumn {
+            alias: alias.to_string(),
+            source_column: "data".to_string(),
+            json_path: json_path.to_string(),
+        };
+        let options = TableQueryOptions {
+            sort_column: Some("total".to_string()),
+            filters: Some(vec![ColumnFilter {
+                column: "status".to_string(),
+                operator: FilterOperator::Eq,
+                value: Value::Text("paid".to_string()),
+                options: Default::default(),
+            }]),
+            computed_columns: Some(vec![
+                computed("status", "$.status"),
+                computed("total", "total"),
+            ]),
+            ..Default::default()
+        };
+
+        let page = driver
+            .query_table(session_id, &Namespace::new("main"), "orders", options)
+            .await
+            .unwrap();
+        assert_eq!(page.total_rows, 2);
+        let names: Vec<&str> = page
+            .result
+            .columns
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, ["id", "data", "status", "total"]);
+        let ids: Vec<&Value> = page.result.rows.iter().map(|r| &r.values[0]).collect();
+        assert!(matches!(ids[..], [Value::Int(3), Value::Int(1)]));
+
+        driver.disconnect(session_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_describe_table_row_count_is_exact() {
+        let driver = SqliteDriver::new();
+        let session_id = driver
+            .connect(&ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, label TEXT)",
+            "INSERT INTO events (label) VALUES ('a'), ('b'), ('c')",
+        ] {
+            driver
+                .execute(session_id, sql, QueryId::new())
+                .await
+                .unwrap();
+        }
+        let namespace = Namespace::new("main");
+
+        // SQLite has no statistics to estimate from, so even a fast
+        // estimate is an exact COUNT(*).
+        let schema = driver
+            .describe_table_with_options(
+                session_id,
+                &namespace,
+                "events",
+                &DescribeTableOptions {
+                    fast_estimate: true,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(schema.row_count_estimate, Some(3));
+        assert!(!schema.row_count_is_estimate);
+
+        driver.disconnect(session_id).await.unwrap();
+    }
 }
@@ -18,38 +18,198 @@
 //!
 //! Uses `bb8::Pool<bb8_tiberius::ConnectionManager>` for async connection pooling,
 //! following the same conceptual pattern as PostgreSQL's `PgPool`.
-
-use std::collections::HashMap;
+//!
+//! `lock_timeout_ms` is applied with `SET LOCK_TIMEOUT` on every new pooled
+//! connection. SQL Server has no session-level statement timeout, so
+//! `statement_timeout_ms` is enforced client-side: a statement running past it
+//! is killed like one exceeding `query_timeout_secs`.
+//! `default_isolation_level` is applied the same way with
+//! `SET TRANSACTION ISOLATION LEVEL`, which is session-scoped in T-SQL and
+//! therefore also covers the dedicated connection opened by
+//! `begin_transaction`.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use bb8::Pool;
 use bb8_tiberius::ConnectionManager;
-use tiberius::{AuthMethod, Client, ColumnData, Config, EncryptionLevel};
+use tiberius::{AuthMethod, Client, ColumnData, Config, EncryptionLevel, Query};
 use tokio::net::TcpStream;
 use tokio::sync::{Mutex, RwLock};
 use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
 
+use crate::column_stats;
+use crate::explain;
+use crate::query_timeout::{query_timeout, with_query_timeout};
+use crate::role_switch::{self, RoleSwitch, StatementRunner};
+use crate::scheduled_jobs;
+use crate::server_info;
+use crate::server_log;
 use qore_core::error::{EngineError, EngineResult};
 use qore_core::traits::{DataEngine, StreamEvent, StreamSender};
 use qore_core::types::{
-    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnInfo,
-    ConnectionConfig, FilterOperator, ForeignKey, MaintenanceMessage, MaintenanceMessageLevel,
-    MaintenanceOperationInfo, MaintenanceOperationType, MaintenanceRequest, MaintenanceResult,
-    MssqlAuthMode, Namespace, PaginatedQueryResult, QueryId, QueryResult, Routine,
-    RoutineDefinition, RoutineList, RoutineListOptions, RoutineOperationResult, RoutineType,
-    Row as QRow, RowData, SessionId, SortDirection, TableColumn, TableIndex, TableQueryOptions,
-    TableSchema, Trigger, TriggerDefinition, TriggerEvent, TriggerList, TriggerListOptions,
-    TriggerOperationResult, TriggerTiming, Value,
+    CancelSupport, Collection, CollectionList, CollectionListOptions, CollectionType, ColumnFilter,
+    ColumnInfo, ColumnStats, ConnectionConfig, ExplainFormat, ExplainPlan, FilterOperator,
+    ForeignKey, ImplicitOrder, IndexSpec, IsolationLevel, JobScheduler, MaintenanceMessage,
+    MaintenanceMessageLevel, MaintenanceOperationInfo, MaintenanceOperationType,
+    MaintenanceRequest, MaintenanceResult, MssqlAuthMode, Namespace, PaginatedQueryResult,
+    PoolStats, QueryId, QueryResult, Routine, RoutineDefinition, RoutineList, RoutineListOptions,
+    RoutineOperationResult, RoutineType, Row as QRow, RowData, ScheduledJob, ScheduledJobList,
+    ScheduledJobOperationResult, ServerInfo, ServerLogChunk, ServerLogLine, ServerLogSource,
+    SessionId, TableColumn, TableIndex, TableQueryOptions, TableSchema, TlsVerifyMode, Trigger,
+    TriggerDefinition, TriggerEvent, TriggerList, TriggerListOptions, TriggerOperationResult,
+    TriggerTiming, UpsertAction, UpsertColumns, UpsertResult, Value,
 };
+use qore_sql::generator::{generate_create_index, SqlDialect};
 use qore_sql::safety;
 
 type MssqlPool = Pool<ConnectionManager>;
 type MssqlClient = Client<Compat<TcpStream>>;
 
+/// Columns of a table with their identity, computed, sparse and column-set
+/// flags. Schema and table are bound as `@P1`/`@P2`.
+const DESCRIBE_COLUMNS_SQL: &str =
+    "SELECT c.COLUMN_NAME, c.DATA_TYPE, c.IS_NULLABLE, c.COLUMN_DEFAULT, \
+     COLUMNPROPERTY(sc.object_id, c.COLUMN_NAME, 'IsIdentity') AS IS_IDENTITY, \
+     CAST(ISNULL(sc.is_computed, 0) AS INT) AS IS_COMPUTED, \
+     cc.definition AS COMPUTED_DEFINITION, \
+     CAST(ISNULL(sc.is_sparse, 0) AS INT) AS IS_SPARSE, \
+     CAST(ISNULL(sc.is_column_set, 0) AS INT) AS IS_COLUMN_SET \
+     FROM INFORMATION_SCHEMA.COLUMNS c \
+     LEFT JOIN sys.columns sc \
+     ON sc.object_id = OBJECT_ID(QUOTENAME(c.TABLE_SCHEMA) + '.' + QUOTENAME(c.TABLE_NAME)) \
+     AND sc.name = c.COLUMN_NAME \
+     LEFT JOIN sys.computed_columns cc \
+     ON cc.object_id = sc.object_id AND cc.column_id = sc.column_id \
+     WHERE c.TABLE_SCHEMA = @P1 AND c.TABLE_NAME = @P2 \
+     ORDER BY c.ORDINAL_POSITION";
+
+/// Computed columns of a table; they reject explicit values on INSERT/UPDATE.
+const COMPUTED_COLUMNS_SQL: &str = "SELECT name FROM sys.computed_columns \
+     WHERE object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2))";
+
+/// Primary key columns of a table in key order. Schema and table are bound as
+/// `@P1`/`@P2`.
+const PK_COLUMNS_SQL: &str = "SELECT c.name AS column_name \
+     FROM sys.indexes i \
+     JOIN sys.index_columns ic ON i.object_id = ic.object_id AND i.index_id = ic.index_id \
+     JOIN sys.columns c ON ic.object_id = c.object_id AND ic.column_id = c.column_id \
+     JOIN sys.tables t ON i.object_id = t.object_id \
+     JOIN sys.schemas s ON t.schema_id = s.schema_id \
+     WHERE i.is_primary_key = 1 AND s.name = @P1 AND t.name = @P2 \
+     ORDER BY ic.key_ordinal";
+
+/// Column definitions for `get_table_ddl`, read from `sys.columns` so type
+/// lengths, identity seeds and named defaults survive. Schema and table are
+/// bound as `@P1`/`@P2`.
+const DDL_COLUMNS_SQL: &str = "SELECT c.name, t.name AS type_name, \
+     CAST(c.max_length AS INT), CAST(c.precision AS INT), CAST(c.scale AS INT), \
+     CAST(c.is_nullable AS INT), CAST(c.is_identity AS INT), \
+     CAST(ISNULL(idc.seed_value, 1) AS BIGINT), CAST(ISNULL(idc.increment_value, 1) AS BIGINT), \
+     dc.name AS default_name, dc.definition AS default_definition, \
+     cc.definition AS computed_definition, CAST(ISNULL(cc.is_persisted, 0) AS INT) \
+     FROM sys.columns c \
+     JOIN sys.types t ON t.user_type_id = c.user_type_id \
+     LEFT JOIN sys.identity_columns idc ON idc.object_id = c.object_id AND idc.column_id = c.column_id \
+     LEFT JOIN sys.default_constraints dc \
+     ON dc.parent_object_id = c.object_id AND dc.parent_column_id = c.column_id \
+     LEFT JOIN sys.computed_columns cc ON cc.object_id = c.object_id AND cc.column_id = c.column_id \
+     WHERE c.object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2)) \
+     ORDER BY c.column_id";
+
+/// Index key and included columns for `get_table_ddl`, one row per column,
+/// covering primary keys and unique constraints as well as plain indexes.
+const DDL_INDEX_COLUMNS_SQL: &str = "SELECT i.name, CAST(i.is_primary_key AS INT), \
+     CAST(i.is_unique_constraint AS INT), CAST(i.is_unique AS INT), i.type_desc, \
+     i.filter_definition, c.name, CAST(ic.is_descending_key AS INT), CAST(ic.is_included_column AS INT) \
+     FROM sys.indexes i \
+     JOIN sys.index_columns ic ON ic.object_id = i.object_id AND ic.index_id = i.index_id \
+     JOIN sys.columns c ON c.object_id = ic.object_id AND c.column_id = ic.column_id \
+     WHERE i.object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2)) \
+     ORDER BY i.index_id, ic.is_included_column, ic.key_ordinal, ic.index_column_id";
+
+/// Foreign key columns for `get_table_ddl`, in constraint column order.
+const DDL_FOREIGN_KEYS_SQL: &str = "SELECT fk.name, pc.name, rs.name, rt.name, rc.name, \
+     fk.delete_referential_action_desc, fk.update_referential_action_desc \
+     FROM sys.foreign_keys fk \
+     JOIN sys.foreign_key_columns fkc ON fkc.constraint_object_id = fk.object_id \
+     JOIN sys.columns pc ON pc.object_id = fkc.parent_object_id AND pc.column_id = fkc.parent_column_id \
+     JOIN sys.tables rt ON rt.object_id = fkc.referenced_object_id \
+     JOIN sys.schemas rs ON rs.schema_id = rt.schema_id \
+     JOIN sys.columns rc ON rc.object_id = fkc.referenced_object_id AND rc.column_id = fkc.referenced_column_id \
+     WHERE fk.parent_object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2)) \
+     ORDER BY fk.name, fkc.constraint_column_id";
+
+/// Check constraints for `get_table_ddl`.
+const DDL_CHECKS_SQL: &str = "SELECT name, definition FROM sys.check_constraints \
+     WHERE parent_object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2)) \
+     ORDER BY name";
+
+/// Issues the session setup statements on each connection the pool opens.
+#[derive(Debug)]
+struct SessionSetup {
+    statements: Vec<String>,
+}
+
+impl bb8::CustomizeConnection<MssqlClient, bb8_tiberius::Error> for SessionSetup {
+    fn on_acquire<'a>(
+        &'a self,
+        conn: &'a mut MssqlClient,
+    ) -> Pin<Box<dyn Future<Output = Result<(), bb8_tiberius::Error>> + Send + 'a>> {
+        Box::pin(async move {
+            for stmt in &self.statements {
+                conn.simple_query(stmt.as_str())
+                    .await?
+                    .into_results()
+                    .await?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Which server certificates an encrypted connection accepts.
+#[derive(Debug, PartialEq, Eq)]
+enum CertTrust {
+    /// Certificates signed by the system trust store
+    System,
+    /// Certificates signed by the given CA file
+    Ca(String),
+    /// Any certificate, without verification
+    All,
+}
+
+/// Tiberius always checks the host name when it verifies a certificate, so
+/// `CaOnly` verifies as much as `Full`.
+fn cert_trust(config: &ConnectionConfig) -> EngineResult<CertTrust> {
+    if config
+        .tls
+        .as_ref()
+        .is_some_and(|tls| tls.client_cert_path.is_some() || tls.client_key_path.is_some())
+    {
+        return Err(EngineError::not_supported(
+            "SQL Server connections do not support TLS client certificates",
+        ));
+    }
+    Ok(match config.tls_verify_mode() {
+        TlsVerifyMode::None => CertTrust::All,
+        TlsVerifyMode::Full | TlsVerifyMode::CaOnly => match config.tls_ca_cert_path() {
+            Some(ca) => CertTrust::Ca(ca.to_string()),
+            None => CertTrust::System,
+        },
+    })
+}
+
 pub struct SqlServerSession {
     pool: MssqlPool,
+    /// Single reserved connection for `KILL` and health checks, so both keep
+    /// working when every connection of `pool` is busy.
+    control_pool: MssqlPool,
     /// Dedicated connection for transactions (same pattern as PostgreSQL).
     transaction_conn: Mutex<Option<MssqlClient>>,
     /// Active query tracking: query_id → SPID for cancellation via KILL.
@@ -79,9 +239,312 @@ impl SqlServerDriver {
             .ok_or_else(|| EngineError::session_not_found(session.0.to_string()))
     }
 
+    /// Runs an `msdb` Agent procedure (`sp_update_job`, `sp_start_job`).
+    async fn run_agent_procedure(
+        &self,
+        session: SessionId,
+        sql: String,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+
+        let start = Instant::now();
+        conn.simple_query(&sql)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_results()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let elapsed = start.elapsed().as_millis() as f64;
+
+        Ok(ScheduledJobOperationResult {
+            success: true,
+            executed_command: sql,
+            message: None,
+            execution_time_ms: elapsed,
+        })
+    }
+
     /// SQL Server uses square brackets for identifier quoting.
     fn quote_ident(name: &str) -> String {
-        format!("[{}]", name.replace(']', "]]"))
+        safety::quote_ident(SqlDialect::SqlServer, name)
+    }
+
+    /// `execute_in_namespace` without the query timeout.
+    async fn run_in_namespace(
+        &self,
+        session: SessionId,
+        _namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let mssql_session = self.get_session(session).await?;
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+
+        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+
+        let start = Instant::now();
+
+        if let Some(ref mut tx_conn) = *tx_guard {
+            // Record SPID so `cancel(query_id)` can KILL this session; otherwise cancel silently no-ops (audit B3-C3).
+            let spid = fetch_spid(tx_conn).await?;
+            register_active_query(&mssql_session, query_id, spid).await;
+            let result = if returns_rows {
+                execute_select(tx_conn, query, start).await
+            } else {
+                execute_dml(tx_conn, query, start).await
+            };
+            unregister_active_query(&mssql_session, query_id).await;
+            result
+        } else {
+            drop(tx_guard);
+            let mut conn = mssql_session.pool.get().await.map_err(|e| {
+                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+            })?;
+
+            let spid = fetch_spid(&mut conn).await?;
+            register_active_query(&mssql_session, query_id, spid).await;
+            let result = if returns_rows {
+                execute_select(&mut conn, query, start).await
+            } else {
+                execute_dml(&mut conn, query, start).await
+            };
+            unregister_active_query(&mssql_session, query_id).await;
+            result
+        }
+    }
+
+    /// `execute_with_params` without the query timeout.
+    async fn run_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let mssql_session = self.get_session(session).await?;
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+        let start = Instant::now();
+
+        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+        if let Some(ref mut tx_conn) = *tx_guard {
+            let spid = fetch_spid(tx_conn).await?;
+            register_active_query(&mssql_session, query_id, spid).await;
+            let result = execute_bound(tx_conn, query, params, returns_rows, start).await;
+            unregister_active_query(&mssql_session, query_id).await;
+            result
+        } else {
+            drop(tx_guard);
+            let mut conn = mssql_session.pool.get().await.map_err(|e| {
+                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+            })?;
+            let spid = fetch_spid(&mut conn).await?;
+            register_active_query(&mssql_session, query_id, spid).await;
+            let result = execute_bound(&mut conn, query, params, returns_rows, start).await;
+            unregister_active_query(&mssql_session, query_id).await;
+            result
+        }
+    }
+
+    /// `execute_stream_in_namespace` without the query timeout.
+    async fn stream_in_namespace(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        query_id: QueryId,
+        sender: StreamSender,
+    ) -> EngineResult<()> {
+        let mssql_session = self.get_session(session).await?;
+
+        let returns_rows = safety::returns_rows(self.driver_id(), query)
+            .unwrap_or_else(|_| safety::is_select_prefix(query));
+
+        if !returns_rows {
+            let result = self
+                .run_in_namespace(session, namespace, query, query_id)
+                .await?;
+            let _ = sender
+                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
+                .await;
+            return Ok(());
+        }
+
+        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+
+        if let Some(ref mut tx_conn) = *tx_guard {
+            let spid = fetch_spid(tx_conn).await?;
+            register_active_query(&mssql_session, query_id, spid).await;
+            let result = stream_select_results(tx_conn, query, &sender).await;
+            unregister_active_query(&mssql_session, query_id).await;
+            result
+        } else {
+            drop(tx_guard);
+            let mut conn = mssql_session.pool.get().await.map_err(|e| {
+                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+            })?;
+            let spid = fetch_spid(&mut conn).await?;
+            register_active_query(&mssql_session, query_id, spid).await;
+            let result = stream_select_results(&mut conn, query, &sender).await;
+            unregister_active_query(&mssql_session, query_id).await;
+            result
+        }
+    }
+
+    /// Names of the computed columns of `schema.table`, read on the
+    /// transaction connection when one is open.
+    async fn computed_columns(
+        &self,
+        session: SessionId,
+        schema: &str,
+        table: &str,
+    ) -> EngineResult<HashSet<String>> {
+        let mssql_session = self.get_session(session).await?;
+        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+        if let Some(ref mut tx_conn) = *tx_guard {
+            fetch_computed_columns(tx_conn, schema, table).await
+        } else {
+            drop(tx_guard);
+            let mut conn = mssql_session.pool.get().await.map_err(|e| {
+                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+            })?;
+            fetch_computed_columns(&mut conn, schema, table).await
+        }
+    }
+
+    /// `WHERE` predicate for one table filter. Comparison values are appended
+    /// to `params` and referenced as `@P{n}`, never written into the SQL.
+    fn filter_sql(filter: &ColumnFilter, params: &mut Vec<Value>) -> EngineResult<String> {
+        let col = Self::quote_ident(&filter.column);
+        let bind = |params: &mut Vec<Value>| {
+            params.push(filter.value.clone());
+            format!("@P{}", params.len())
+        };
+        Ok(match filter.operator {
+            FilterOperator::Eq => format!("{} = {}", col, bind(params)),
+            FilterOperator::Neq => format!("{} != {}", col, bind(params)),
+            FilterOperator::Gt => format!("{} > {}", col, bind(params)),
+            FilterOperator::Gte => format!("{} >= {}", col, bind(params)),
+            FilterOperator::Lt => format!("{} < {}", col, bind(params)),
+            FilterOperator::Lte => format!("{} <= {}", col, bind(params)),
+            FilterOperator::Like => {
+                // CAST to NVARCHAR so substring search works on every column
+                // type (numbers, booleans, dates…), not just text columns.
+                format!("CAST({} AS NVARCHAR(MAX)) LIKE {}", col, bind(params))
+            }
+            FilterOperator::IsNull => format!("{} IS NULL", col),
+            FilterOperator::IsNotNull => format!("{} IS NOT NULL", col),
+            FilterOperator::Regex => {
+                // SQL Server has no native POSIX regex without CLR — fall back to PATINDEX
+                // with the raw pattern so wildcards still work. Flags are server-side ignored.
+                let pattern = filter.value.as_text().ok_or_else(|| {
+                    EngineError::syntax_error("regex operator requires a string value in 'value'")
+                })?;
+                format!(
+                    "PATINDEX('%{}%', CAST({} AS NVARCHAR(MAX))) > 0",
+                    pattern.replace('\'', "''"),
+                    col
+                )
+            }
+            FilterOperator::Text => {
+                // CONTAINS() requires a full-text catalog + index on the column; absence
+                // surfaces as a server error. UI must verify `index_type = fulltext` first.
+                let term = filter.value.as_text().ok_or_else(|| {
+                    EngineError::syntax_error("text operator requires a string value in 'value'")
+                })?;
+                format!(
+                    "CONTAINS({}, '\"{}\"')",
+                    col,
+                    term.replace('\'', "''").replace('"', "\"\"")
+                )
+            }
+        })
+    }
+
+    /// `USE` statement switching to the namespace's database, if it names one.
+    fn use_database_sql(namespace: Option<&Namespace>) -> Option<String> {
+        namespace
+            .filter(|ns| !ns.database.is_empty())
+            .map(|ns| format!("USE {}", Self::quote_ident(&ns.database)))
+    }
+
+    /// `[col] = @P{n}`, with `value` appended to `params`.
+    fn bound_eq(col: &str, value: &Value, params: &mut Vec<Value>) -> String {
+        params.push(value.clone());
+        format!("{} = @P{}", Self::quote_ident(col), params.len())
+    }
+
+    /// `INSERT` with inlined values, leaving out the `computed` columns.
+    fn insert_sql(table_ref: &str, data: &RowData, computed: &HashSet<String>) -> String {
+        let mut keys: Vec<&String> = data
+            .columns
+            .keys()
+            .filter(|k| !computed.contains(*k))
+            .collect();
+        keys.sort();
+
+        if keys.is_empty() {
+            return format!("INSERT INTO {} DEFAULT VALUES", table_ref);
+        }
+        let cols_str = keys
+            .iter()
+            .map(|k| Self::quote_ident(k))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let vals_str = keys
+            .iter()
+            .map(|k| format_filter_value(data.columns.get(*k).unwrap()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table_ref, cols_str, vals_str
+        )
+    }
+
+    /// `MERGE` upsert on the conflict columns. `HOLDLOCK` keeps two concurrent
+    /// upserts of the same key from both taking the insert branch, and
+    /// `OUTPUT $action` reports `INSERT` or `UPDATE`.
+    fn upsert_sql(table_ref: &str, columns: &UpsertColumns) -> String {
+        let quoted = |cols: &[String], prefix: &str| {
+            cols.iter()
+                .map(|c| format!("{}{}", prefix, Self::quote_ident(c)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let params = (1..=columns.insert.len())
+            .map(|i| format!("@P{}", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let on_clauses = columns
+            .conflict
+            .iter()
+            .map(|c| format!("t.{} = s.{}", Self::quote_ident(c), Self::quote_ident(c)))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let set_clauses = columns
+            .update
+            .iter()
+            .map(|c| format!("t.{} = s.{}", Self::quote_ident(c), Self::quote_ident(c)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "MERGE INTO {} WITH (HOLDLOCK) AS t USING (VALUES ({})) AS s ({}) ON {} \
+             WHEN MATCHED THEN UPDATE SET {} \
+             WHEN NOT MATCHED THEN INSERT ({}) VALUES ({}) \
+             OUTPUT $action;",
+            table_ref,
+            params,
+            quoted(&columns.insert, ""),
+            on_clauses,
+            set_clauses,
+            quoted(&columns.insert, ""),
+            quoted(&columns.insert, "s."),
+        )
     }
 
     fn build_config(config: &ConnectionConfig) -> EngineResult<Config> {
@@ -121,16 +584,49 @@ impl SqlServerDriver {
                 tib_config.database(db);
             }
         }
-        tib_config.encryption(if config.ssl {
-            EncryptionLevel::Required
+        if config.ssl {
+            tib_config.encryption(EncryptionLevel::Required);
+            match cert_trust(config)? {
+                CertTrust::System => {}
+                CertTrust::Ca(path) => tib_config.trust_cert_ca(path),
+                CertTrust::All => tib_config.trust_cert(),
+            }
         } else {
-            EncryptionLevel::NotSupported
-        });
-        tib_config.trust_cert();
+            tib_config.encryption(EncryptionLevel::NotSupported);
+        }
         Ok(tib_config)
     }
 
-    /// Create a raw tiberius Client (not pooled) for test_connection.
+    /// Session settings issued right after connect (see module docs).
+    fn session_setup_statements(config: &ConnectionConfig) -> Vec<String> {
+        let mut statements = Vec::new();
+        if let Some(ms) = config.lock_timeout_ms {
+            statements.push(format!("SET LOCK_TIMEOUT {ms}"));
+        }
+        if let Some(level) = config.default_isolation_level {
+            statements.push(format!(
+                "SET TRANSACTION ISOLATION LEVEL {}",
+                level.as_sql()
+            ));
+        }
+        statements
+    }
+
+    /// Client-side limit for a statement: the shorter of `query_timeout_secs`
+    /// and `statement_timeout_ms`.
+    fn execution_timeout(config: &ConnectionConfig) -> Option<Duration> {
+        let statement = config
+            .statement_timeout_ms
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis);
+        match (query_timeout(config.query_timeout_secs), statement) {
+            (Some(query), Some(statement)) => Some(query.min(statement)),
+            (query, statement) => query.or(statement),
+        }
+    }
+
+    /// Create a raw tiberius Client (not pooled), for test_connection and the
+    /// dedicated connection of a transaction.
     async fn connect_raw(config: &ConnectionConfig) -> EngineResult<MssqlClient> {
         let tib_config = Self::build_config(config)?;
         let tcp = TcpStream::connect(tib_config.get_addr())
@@ -143,10 +639,20 @@ impl SqlServerDriver {
             })?;
         tcp.set_nodelay(true).ok();
 
-        let client = Client::connect(tib_config, tcp.compat_write())
+        let mut client = Client::connect(tib_config, tcp.compat_write())
             .await
             .map_err(|e| EngineError::connection_failed(e.to_string()))?;
 
+        for stmt in Self::session_setup_statements(config) {
+            client
+                .simple_query(stmt)
+                .await
+                .map_err(|e| EngineError::connection_failed(e.to_string()))?
+                .into_results()
+                .await
+                .map_err(|e| EngineError::connection_failed(e.to_string()))?;
+        }
+
         Ok(client)
     }
 
@@ -160,12 +666,33 @@ impl SqlServerDriver {
         Pool::builder()
             .max_size(max_size)
             .connection_timeout(std::time::Duration::from_secs(timeout_secs))
+            .connection_customizer(Box::new(SessionSetup {
+                statements: Self::session_setup_statements(config),
+            }))
             .build(mgr)
             .await
             .map_err(|e| {
                 EngineError::connection_failed(format!("Failed to create connection pool: {e}"))
             })
     }
+
+    /// One-connection pool reserved for `cancel` and `ping`, without the
+    /// session setup statements.
+    async fn create_control_pool(config: &ConnectionConfig) -> EngineResult<MssqlPool> {
+        let tib_config = Self::build_config(config)?;
+        let mgr = ConnectionManager::new(tib_config);
+        let timeout_secs = config.pool_acquire_timeout_secs.unwrap_or(15) as u64;
+
+        Pool::builder()
+            .max_size(1)
+            .min_idle(Some(1))
+            .connection_timeout(std::time::Duration::from_secs(timeout_secs))
+            .build(mgr)
+            .await
+            .map_err(|e| {
+                EngineError::connection_failed(format!("Failed to create control connection: {e}"))
+            })
+    }
 }
 
 impl Default for SqlServerDriver {
@@ -183,10 +710,8 @@ fn convert_column_data(data: &ColumnData<'_>) -> Value {
         ColumnData::I64(Some(v)) => Value::Int(*v),
         ColumnData::F32(Some(v)) => Value::Float(*v as f64),
         ColumnData::F64(Some(v)) => Value::Float(*v),
-        ColumnData::Numeric(Some(n)) => {
-            let val = n.value() as f64 / 10f64.powi(n.scale() as i32);
-            Value::Float(val)
-        }
+        // Kept as text: DECIMAL(38, x) does not fit an f64 without rounding.
+        ColumnData::Numeric(Some(n)) => Value::Text(format_numeric(n.value(), n.scale())),
         ColumnData::String(Some(s)) => Value::Text(s.to_string()),
         ColumnData::Guid(Some(g)) => Value::Text(format!("{}", g)),
         ColumnData::Binary(Some(b)) => Value::Bytes(b.to_vec()),
@@ -202,6 +727,20 @@ fn convert_column_data(data: &ColumnData<'_>) -> Value {
     }
 }
 
+/// Renders the unscaled integer `value` with `scale` fractional digits,
+/// e.g. `(-5, 2)` as `-0.05`.
+fn format_numeric(value: i128, scale: u8) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    let scale = scale as usize;
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    format!("{sign}{int_part}.{frac_part}")
+}
+
 /// Convert a tiberius Row to a QoreDB Row.
 /// Uses chrono conversion for date/time types via `row.try_get`.
 fn convert_row(row: &tiberius::Row) -> QRow {
@@ -251,14 +790,22 @@ fn get_column_info(columns: &[tiberius::Column]) -> Vec<ColumnInfo> {
             name: col.name().into(),
             data_type: format!("{:?}", col.column_type()).into(),
             nullable: true,
+            case_insensitive: false,
         })
         .collect()
 }
 
-/// Classify a SQL Server error into syntax or execution error.
+/// Classify a SQL Server error into permission, syntax or execution error.
+/// Error 229/230 read "The SELECT permission was denied on the object ...";
+/// statement-level ones (262) read "... permission denied in database ...".
 fn classify_error(msg: String) -> EngineError {
     let lower = msg.to_lowercase();
-    if lower.contains("syntax") || lower.contains("incorrect syntax") || lower.contains("parse") {
+    if lower.contains("permission was denied") || lower.contains("permission denied") {
+        EngineError::permission_denied(msg)
+    } else if lower.contains("syntax")
+        || lower.contains("incorrect syntax")
+        || lower.contains("parse")
+    {
         EngineError::syntax_error(msg)
     } else {
         EngineError::execution_error(msg)
@@ -293,11 +840,13 @@ impl DataEngine for SqlServerDriver {
 
     async fn connect(&self, config: &ConnectionConfig) -> EngineResult<SessionId> {
         let pool = Self::create_pool(config).await?;
+        let control_pool = Self::create_control_pool(config).await?;
         let database = config.database.clone().unwrap_or_default();
 
         let session_id = SessionId::new();
         let session = Arc::new(SqlServerSession {
             pool,
+            control_pool,
             transaction_conn: Mutex::new(None),
             active_queries: Mutex::new(HashMap::new()),
             database,
@@ -318,10 +867,11 @@ impl DataEngine for SqlServerDriver {
         Ok(())
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         let mssql_session = self.get_session(session).await?;
+        let start = Instant::now();
         let mut conn = mssql_session
-            .pool
+            .control_pool
             .get()
             .await
             .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
@@ -333,7 +883,45 @@ impl DataEngine for SqlServerDriver {
             .into_results()
             .await
             .map_err(|e| EngineError::connection_failed(format!("Ping failed: {e}")))?;
-        Ok(())
+        Ok(start.elapsed())
+    }
+
+    /// Transactions run on a dedicated connection opened outside the pool,
+    /// so an open transaction does not show up in `in_use`.
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        let mssql_session = self.get_session(session).await?;
+        let state = mssql_session.pool.state();
+        Ok(Some(PoolStats {
+            size: state.connections,
+            idle: state.idle_connections,
+            in_use: state.connections.saturating_sub(state.idle_connections),
+            max: mssql_session.pool.config().max_size,
+        }))
+    }
+
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+
+        let rows = conn
+            .simple_query(
+                "SELECT @@VERSION, CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128))",
+            )
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_first_result()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let row = rows
+            .first()
+            .ok_or_else(|| EngineError::execution_error("Version query returned no row"))?;
+
+        Ok(server_info::sqlserver_server_info(
+            row.get::<&str, _>(0).unwrap_or_default(),
+            row.get::<&str, _>(1).unwrap_or_default(),
+        ))
     }
 
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
@@ -374,6 +962,26 @@ impl DataEngine for SqlServerDriver {
         Ok(namespaces)
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+
+        let rows = conn
+            .simple_query("SELECT name FROM sys.databases ORDER BY name")
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_first_result()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| row.get::<&str, _>(0).map(str::to_string))
+            .collect())
+    }
+
     async fn list_collections(
         &self,
         session: SessionId,
@@ -470,6 +1078,7 @@ impl DataEngine for SqlServerDriver {
                         namespace: namespace.clone(),
                         name: n.to_string(),
                         collection_type,
+                        partition_of: None,
                     }
                 })
             })
@@ -478,6 +1087,7 @@ impl DataEngine for SqlServerDriver {
         Ok(CollectionList {
             collections,
             total_count: total_count as u32,
+            next_cursor: None,
         })
     }
 
@@ -494,13 +1104,8 @@ impl DataEngine for SqlServerDriver {
         let schema = namespace.schema.as_deref().unwrap_or("dbo");
 
         // Bind schema/table as `@P1`/`@P2` rather than interpolating — same defence as list_collections (audit B3-C2).
-        let col_sql = "SELECT COLUMN_NAME, DATA_TYPE, IS_NULLABLE, COLUMN_DEFAULT, \
-             COLUMNPROPERTY(OBJECT_ID(QUOTENAME(TABLE_SCHEMA) + '.' + QUOTENAME(TABLE_NAME)), COLUMN_NAME, 'IsIdentity') AS IS_IDENTITY \
-             FROM INFORMATION_SCHEMA.COLUMNS \
-             WHERE TABLE_SCHEMA = @P1 AND TABLE_NAME = @P2 \
-             ORDER BY ORDINAL_POSITION";
         let col_stream = conn
-            .query(col_sql, &[&schema, &table])
+            .query(DESCRIBE_COLUMNS_SQL, &[&schema, &table])
             .await
             .map_err(|e| EngineError::execution_error(e.to_string()))?;
         let col_rows = col_stream
@@ -516,6 +1121,10 @@ impl DataEngine for SqlServerDriver {
                 let is_nullable: &str = row.get::<&str, _>(2).unwrap_or("YES");
                 let default_value: Option<&str> = row.get(3);
                 let is_identity: i32 = row.get(4).unwrap_or(0);
+                let is_computed: i32 = row.get(5).unwrap_or(0);
+                let computed_definition: Option<&str> = row.get(6);
+                let is_sparse: i32 = row.get(7).unwrap_or(0);
+                let is_column_set: i32 = row.get(8).unwrap_or(0);
                 TableColumn {
                     name: name.to_string(),
                     data_type: data_type.to_string(),
@@ -523,20 +1132,17 @@ impl DataEngine for SqlServerDriver {
                     default_value: default_value.map(|s| s.to_string()),
                     is_primary_key: false,
                     is_auto_increment: is_identity == 1,
+                    is_computed: is_computed == 1,
+                    computed_definition: computed_definition.map(|s| s.to_string()),
+                    is_sparse: is_sparse == 1,
+                    is_column_set: is_column_set == 1,
+                    allowed_values: None,
                 }
             })
             .collect();
 
-        let pk_sql = "SELECT c.name AS column_name \
-             FROM sys.indexes i \
-             JOIN sys.index_columns ic ON i.object_id = ic.object_id AND i.index_id = ic.index_id \
-             JOIN sys.columns c ON ic.object_id = c.object_id AND ic.column_id = c.column_id \
-             JOIN sys.tables t ON i.object_id = t.object_id \
-             JOIN sys.schemas s ON t.schema_id = s.schema_id \
-             WHERE i.is_primary_key = 1 AND s.name = @P1 AND t.name = @P2 \
-             ORDER BY ic.key_ordinal";
         let pk_stream = conn
-            .query(pk_sql, &[&schema, &table])
+            .query(PK_COLUMNS_SQL, &[&schema, &table])
             .await
             .map_err(|e| EngineError::execution_error(e.to_string()))?;
         let pk_rows = pk_stream
@@ -633,47 +1239,270 @@ impl DataEngine for SqlServerDriver {
             entry.0.push(col_name.to_string());
         }
 
-        let indexes: Vec<TableIndex> = index_map
-            .into_iter()
-            .map(|(name, (cols, is_unique, is_primary))| TableIndex {
-                name,
-                columns: cols,
-                is_unique,
-                is_primary,
-                index_type: None,
-            })
-            .collect();
-
-        let count_sql = "SELECT SUM(p.rows) AS row_count \
-             FROM sys.partitions p \
-             JOIN sys.tables t ON p.object_id = t.object_id \
-             JOIN sys.schemas s ON t.schema_id = s.schema_id \
-             WHERE s.name = @P1 AND t.name = @P2 AND p.index_id IN (0, 1)";
-        let count_stream = conn
-            .query(count_sql, &[&schema, &table])
+        let indexes: Vec<TableIndex> = index_map
+            .into_iter()
+            .map(|(name, (cols, is_unique, is_primary))| TableIndex {
+                name,
+                columns: cols,
+                is_unique,
+                is_primary,
+                index_type: None,
+            })
+            .collect();
+
+        let count_sql = "SELECT SUM(p.rows) AS row_count \
+             FROM sys.partitions p \
+             JOIN sys.tables t ON p.object_id = t.object_id \
+             JOIN sys.schemas s ON t.schema_id = s.schema_id \
+             WHERE s.name = @P1 AND t.name = @P2 AND p.index_id IN (0, 1)";
+        let count_stream = conn
+            .query(count_sql, &[&schema, &table])
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let count_rows = count_stream
+            .into_first_result()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let row_count_estimate: Option<u64> = count_rows
+            .first()
+            .and_then(|row| row.get::<i64, _>(0))
+            .map(|c| c.max(0) as u64);
+
+        Ok(TableSchema {
+            columns,
+            primary_key: if pk_columns.is_empty() {
+                None
+            } else {
+                Some(pk_columns)
+            },
+            foreign_keys,
+            row_count_estimate,
+            row_count_is_estimate: true,
+            indexes,
+            partitioning: None,
+            row_security: None,
+        })
+    }
+
+    async fn get_table_ddl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+        let schema = namespace.schema.as_deref().unwrap_or("dbo");
+
+        let mut result_sets = Vec::new();
+        for sql in [
+            DDL_COLUMNS_SQL,
+            DDL_INDEX_COLUMNS_SQL,
+            DDL_FOREIGN_KEYS_SQL,
+            DDL_CHECKS_SQL,
+        ] {
+            let rows = conn
+                .query(sql, &[&schema, &table])
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?
+                .into_first_result()
+                .await
+                .map_err(|e| EngineError::execution_error(e.to_string()))?;
+            result_sets.push(rows);
+        }
+        let [column_rows, index_rows, fk_rows, check_rows] =
+            <[Vec<tiberius::Row>; 4]>::try_from(result_sets)
+                .map_err(|_| EngineError::internal("Unexpected result set count"))?;
+        if column_rows.is_empty() {
+            return Err(EngineError::execution_error("Table not found"));
+        }
+
+        let mut lines: Vec<String> = column_rows
+            .iter()
+            .map(|row| {
+                let name = Self::quote_ident(row.get::<&str, _>(0).unwrap_or(""));
+                if let Some(expr) = row.get::<&str, _>(11) {
+                    let persisted = row.get::<i32, _>(12).unwrap_or(0) == 1;
+                    return format!(
+                        "{name} AS {expr}{}",
+                        if persisted { " PERSISTED" } else { "" }
+                    );
+                }
+
+                let mut def = format!(
+                    "{name} {}",
+                    format_mssql_type(
+                        row.get::<&str, _>(1).unwrap_or(""),
+                        row.get::<i32, _>(2).unwrap_or(0),
+                        row.get::<i32, _>(3).unwrap_or(0),
+                        row.get::<i32, _>(4).unwrap_or(0),
+                    )
+                );
+                if row.get::<i32, _>(6).unwrap_or(0) == 1 {
+                    def.push_str(&format!(
+                        " IDENTITY({}, {})",
+                        row.get::<i64, _>(7).unwrap_or(1),
+                        row.get::<i64, _>(8).unwrap_or(1)
+                    ));
+                }
+                if row.get::<i32, _>(5).unwrap_or(1) == 0 {
+                    def.push_str(" NOT NULL");
+                }
+                if let (Some(default_name), Some(default)) =
+                    (row.get::<&str, _>(9), row.get::<&str, _>(10))
+                {
+                    def.push_str(&format!(
+                        " CONSTRAINT {} DEFAULT {default}",
+                        Self::quote_ident(default_name)
+                    ));
+                }
+                def
+            })
+            .collect();
+
+        let mut indexes: Vec<MssqlIndexDdl> = Vec::new();
+        for row in &index_rows {
+            let name = row.get::<&str, _>(0).unwrap_or("");
+            if indexes.last().map(|index| index.name.as_str()) != Some(name) {
+                indexes.push(MssqlIndexDdl {
+                    name: name.to_string(),
+                    is_primary_key: row.get::<i32, _>(1).unwrap_or(0) == 1,
+                    is_unique_constraint: row.get::<i32, _>(2).unwrap_or(0) == 1,
+                    is_unique: row.get::<i32, _>(3).unwrap_or(0) == 1,
+                    type_desc: row.get::<&str, _>(4).unwrap_or("").to_string(),
+                    filter: row.get::<&str, _>(5).map(|s| s.to_string()),
+                    keys: Vec::new(),
+                    included: Vec::new(),
+                });
+            }
+            let Some(index) = indexes.last_mut() else {
+                continue;
+            };
+            let column = Self::quote_ident(row.get::<&str, _>(6).unwrap_or(""));
+            if row.get::<i32, _>(8).unwrap_or(0) == 1 {
+                index.included.push(column);
+            } else if row.get::<i32, _>(7).unwrap_or(0) == 1 {
+                index.keys.push(format!("{column} DESC"));
+            } else {
+                index.keys.push(format!("{column} ASC"));
+            }
+        }
+
+        for index in indexes
+            .iter()
+            .filter(|index| index.is_primary_key || index.is_unique_constraint)
+        {
+            lines.push(format!(
+                "CONSTRAINT {} {} {} ({})",
+                Self::quote_ident(&index.name),
+                if index.is_primary_key {
+                    "PRIMARY KEY"
+                } else {
+                    "UNIQUE"
+                },
+                index.type_desc,
+                index.keys.join(", ")
+            ));
+        }
+
+        let mut foreign_keys: Vec<(String, Vec<String>, String, Vec<String>, String)> = Vec::new();
+        for row in &fk_rows {
+            let name = row.get::<&str, _>(0).unwrap_or("");
+            if foreign_keys.last().map(|fk| fk.0.as_str()) != Some(name) {
+                let mut actions = String::new();
+                for (clause, col) in [("ON DELETE", 5usize), ("ON UPDATE", 6)] {
+                    let action = row.get::<&str, _>(col).unwrap_or("NO_ACTION");
+                    if action != "NO_ACTION" {
+                        actions.push_str(&format!(" {clause} {}", action.replace('_', " ")));
+                    }
+                }
+                foreign_keys.push((
+                    name.to_string(),
+                    Vec::new(),
+                    format!(
+                        "{}.{}",
+                        Self::quote_ident(row.get::<&str, _>(2).unwrap_or("dbo")),
+                        Self::quote_ident(row.get::<&str, _>(3).unwrap_or(""))
+                    ),
+                    Vec::new(),
+                    actions,
+                ));
+            }
+            if let Some(fk) = foreign_keys.last_mut() {
+                fk.1.push(Self::quote_ident(row.get::<&str, _>(1).unwrap_or("")));
+                fk.3.push(Self::quote_ident(row.get::<&str, _>(4).unwrap_or("")));
+            }
+        }
+        for (name, columns, referenced_table, referenced_columns, actions) in foreign_keys {
+            lines.push(format!(
+                "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {referenced_table} ({}){actions}",
+                Self::quote_ident(&name),
+                columns.join(", "),
+                referenced_columns.join(", ")
+            ));
+        }
+
+        for row in &check_rows {
+            lines.push(format!(
+                "CONSTRAINT {} CHECK {}",
+                Self::quote_ident(row.get::<&str, _>(0).unwrap_or("")),
+                row.get::<&str, _>(1).unwrap_or("")
+            ));
+        }
+
+        let qualified = format!("{}.{}", Self::quote_ident(schema), Self::quote_ident(table));
+        let mut ddl = format!(
+            "CREATE TABLE {qualified} (\n    {}\n);\n",
+            lines.join(",\n    ")
+        );
+        // XML, spatial and columnstore indexes have their own syntax and are
+        // left out.
+        for index in indexes.iter().filter(|index| {
+            !index.is_primary_key
+                && !index.is_unique_constraint
+                && matches!(index.type_desc.as_str(), "CLUSTERED" | "NONCLUSTERED")
+        }) {
+            ddl.push_str(&format!(
+                "CREATE {}{} INDEX {} ON {qualified} ({})",
+                if index.is_unique { "UNIQUE " } else { "" },
+                index.type_desc,
+                Self::quote_ident(&index.name),
+                index.keys.join(", ")
+            ));
+            if !index.included.is_empty() {
+                ddl.push_str(&format!(" INCLUDE ({})", index.included.join(", ")));
+            }
+            if let Some(filter) = &index.filter {
+                ddl.push_str(&format!(" WHERE {filter}"));
+            }
+            ddl.push_str(";\n");
+        }
+        Ok(ddl)
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        spec.validate()?;
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+        let sql = generate_create_index(SqlDialect::SqlServer, namespace, table, spec);
+        conn.simple_query(&sql)
             .await
-            .map_err(|e| EngineError::execution_error(e.to_string()))?;
-        let count_rows = count_stream
-            .into_first_result()
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_results()
             .await
             .map_err(|e| EngineError::execution_error(e.to_string()))?;
-
-        let row_count_estimate: Option<u64> = count_rows
-            .first()
-            .and_then(|row| row.get::<i64, _>(0))
-            .map(|c| c.max(0) as u64);
-
-        Ok(TableSchema {
-            columns,
-            primary_key: if pk_columns.is_empty() {
-                None
-            } else {
-                Some(pk_columns)
-            },
-            foreign_keys,
-            row_count_estimate,
-            indexes,
-        })
+        Ok(())
     }
 
     async fn preview_table(
@@ -1193,45 +2022,211 @@ impl DataEngine for SqlServerDriver {
     async fn execute_in_namespace(
         &self,
         session: SessionId,
-        _namespace: Option<Namespace>,
+        namespace: Option<Namespace>,
         query: &str,
         query_id: QueryId,
     ) -> EngineResult<QueryResult> {
-        let mssql_session = self.get_session(session).await?;
-        let returns_rows = safety::returns_rows(self.driver_id(), query)
-            .unwrap_or_else(|_| safety::is_select_prefix(query));
+        let timeout = Self::execution_timeout(&self.get_session(session).await?.config);
+        with_query_timeout(
+            timeout,
+            self.run_in_namespace(session, namespace, query, query_id),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
+    }
 
-        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let timeout = Self::execution_timeout(&self.get_session(session).await?.config);
+        with_query_timeout(
+            timeout,
+            self.run_with_params(session, query, params, query_id),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
+    }
 
-        let start = Instant::now();
+    async fn execute_as_role(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        role: &str,
+        query: &str,
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        let switch = RoleSwitch::sqlserver(role)?;
+        let mssql_session = self.get_session(session).await?;
 
-        if let Some(ref mut tx_conn) = *tx_guard {
-            // Record SPID so `cancel(query_id)` can KILL this session; otherwise cancel silently no-ops (audit B3-C3).
-            let spid = fetch_spid(tx_conn).await?;
-            register_active_query(&mssql_session, query_id, spid).await;
-            let result = if returns_rows {
-                execute_select(tx_conn, query, start).await
-            } else {
-                execute_dml(tx_conn, query, start).await
-            };
-            unregister_active_query(&mssql_session, query_id).await;
-            result
-        } else {
-            drop(tx_guard);
-            let mut conn = mssql_session.pool.get().await.map_err(|e| {
-                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        // Dedicated connection, closed on drop: the impersonation context can
+        // never leak back into the pool, even if REVERT fails.
+        let mut conn = Self::connect_raw(&mssql_session.config)
+            .await
+            .map_err(|e| {
+                EngineError::connection_failed(format!(
+                    "Failed to acquire dedicated connection: {e}"
+                ))
             })?;
 
-            let spid = fetch_spid(&mut conn).await?;
-            register_active_query(&mssql_session, query_id, spid).await;
-            let result = if returns_rows {
-                execute_select(&mut conn, query, start).await
-            } else {
-                execute_dml(&mut conn, query, start).await
-            };
-            unregister_active_query(&mssql_session, query_id).await;
-            result
+        // A fresh connection starts in the login's default database.
+        if let Some(sql) = Self::use_database_sql(namespace.as_ref()) {
+            conn.simple_query(sql)
+                .await
+                .map_err(|e| classify_error(e.to_string()))?
+                .into_results()
+                .await
+                .map_err(|e| classify_error(e.to_string()))?;
+        }
+
+        let spid = fetch_spid(&mut conn).await?;
+        register_active_query(&mssql_session, query_id, spid).await;
+        let mut runner = MssqlRoleRunner {
+            conn: &mut conn,
+            driver_id: self.driver_id(),
+        };
+        let execution = role_switch::execute_as_role(&mut runner, &switch, query).await;
+        unregister_active_query(&mssql_session, query_id).await;
+
+        if let Some(e) = execution.reset_error {
+            tracing::warn!("Failed to revert impersonation: {}", e);
+        }
+        execution.result
+    }
+
+    fn supports_execute_as_role(&self) -> bool {
+        true
+    }
+
+    async fn list_scheduled_jobs(&self, session: SessionId) -> EngineResult<ScheduledJobList> {
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+
+        let presence = conn
+            .simple_query(scheduled_jobs::SQLSERVER_AGENT_PRESENCE_SQL)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_row()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+        let agent_visible = presence
+            .and_then(|row| row.get::<i32, _>(0))
+            .is_some_and(|visible| visible == 1);
+        if !agent_visible {
+            return Ok(ScheduledJobList::unavailable());
         }
+
+        let rows = conn
+            .simple_query(scheduled_jobs::SQLSERVER_AGENT_JOBS_SQL)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_first_result()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let text = |row: &tiberius::Row, idx: usize| row.get::<&str, _>(idx).map(str::to_string);
+        let jobs = rows
+            .iter()
+            .map(|row| ScheduledJob {
+                id: text(row, 0).unwrap_or_default(),
+                name: text(row, 1).unwrap_or_default(),
+                database: None,
+                schedule: text(row, 2),
+                command: text(row, 3),
+                last_run: text(row, 4),
+                next_run: text(row, 5),
+                enabled: row.get::<i32, _>(6) == Some(1),
+            })
+            .collect();
+
+        Ok(ScheduledJobList {
+            scheduler: Some(JobScheduler::SqlServerAgent),
+            jobs,
+        })
+    }
+
+    async fn set_scheduled_job_enabled(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        _database: Option<&str>,
+        enabled: bool,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        let sql = scheduled_jobs::sqlserver_job_toggle_sql(job_id, enabled)?;
+        self.run_agent_procedure(session, sql).await
+    }
+
+    async fn run_scheduled_job(
+        &self,
+        session: SessionId,
+        job_id: &str,
+        _database: Option<&str>,
+    ) -> EngineResult<ScheduledJobOperationResult> {
+        let sql = scheduled_jobs::sqlserver_job_start_sql(job_id)?;
+        self.run_agent_procedure(session, sql).await
+    }
+
+    fn supports_scheduled_jobs(&self) -> bool {
+        true
+    }
+
+    async fn read_server_log(
+        &self,
+        session: SessionId,
+        lines: u32,
+        after: Option<&str>,
+    ) -> EngineResult<ServerLogChunk> {
+        let sql = server_log::sqlserver_error_log_sql(lines, after)?;
+        let mssql_session = self.get_session(session).await?;
+        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+            EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+        })?;
+
+        let privileged = conn
+            .simple_query(server_log::SQLSERVER_ERROR_LOG_PRIVILEGE_SQL)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_row()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .and_then(|row| row.get::<i32, _>(0))
+            .is_some_and(|privileged| privileged == 1);
+        if !privileged {
+            return Err(server_log::insufficient_privileges(
+                ServerLogSource::SqlServerErrorLog,
+            ));
+        }
+
+        let rows = conn
+            .simple_query(sql)
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?
+            .into_first_result()
+            .await
+            .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+        let log_lines = rows
+            .iter()
+            .map(|row| ServerLogLine {
+                logged_at: row.get::<&str, _>(0).map(str::to_string),
+                level: None,
+                message: row.get::<&str, _>(1).unwrap_or_default().to_string(),
+            })
+            .collect();
+        Ok(server_log::timestamped_chunk(
+            ServerLogSource::SqlServerErrorLog,
+            log_lines,
+            after,
+        ))
+    }
+
+    fn supports_server_log(&self) -> bool {
+        true
     }
 
     async fn execute_stream(
@@ -1253,40 +2248,13 @@ impl DataEngine for SqlServerDriver {
         query_id: QueryId,
         sender: StreamSender,
     ) -> EngineResult<()> {
-        let mssql_session = self.get_session(session).await?;
-
-        let returns_rows = safety::returns_rows(self.driver_id(), query)
-            .unwrap_or_else(|_| safety::is_select_prefix(query));
-
-        if !returns_rows {
-            let result = self
-                .execute_in_namespace(session, namespace, query, query_id)
-                .await?;
-            let _ = sender
-                .send(StreamEvent::Done(result.affected_rows.unwrap_or(0)))
-                .await;
-            return Ok(());
-        }
-
-        let mut tx_guard = mssql_session.transaction_conn.lock().await;
-
-        if let Some(ref mut tx_conn) = *tx_guard {
-            let spid = fetch_spid(tx_conn).await?;
-            register_active_query(&mssql_session, query_id, spid).await;
-            let result = stream_select_results(tx_conn, query, &sender).await;
-            unregister_active_query(&mssql_session, query_id).await;
-            result
-        } else {
-            drop(tx_guard);
-            let mut conn = mssql_session.pool.get().await.map_err(|e| {
-                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
-            })?;
-            let spid = fetch_spid(&mut conn).await?;
-            register_active_query(&mssql_session, query_id, spid).await;
-            let result = stream_select_results(&mut conn, query, &sender).await;
-            unregister_active_query(&mssql_session, query_id).await;
-            result
-        }
+        let timeout = Self::execution_timeout(&self.get_session(session).await?.config);
+        with_query_timeout(
+            timeout,
+            self.stream_in_namespace(session, namespace, query, query_id, sender),
+            self.cancel(session, Some(query_id)),
+        )
+        .await
     }
 
     async fn query_table(
@@ -1312,69 +2280,11 @@ impl DataEngine for SqlServerDriver {
 
         let mut where_clauses: Vec<String> = Vec::new();
 
+        let mut params: Vec<Value> = Vec::new();
+
         if let Some(filters) = &options.filters {
             for filter in filters {
-                let col = Self::quote_ident(&filter.column);
-                let clause = match filter.operator {
-                    FilterOperator::Eq => {
-                        format!("{} = {}", col, format_filter_value(&filter.value))
-                    }
-                    FilterOperator::Neq => {
-                        format!("{} != {}", col, format_filter_value(&filter.value))
-                    }
-                    FilterOperator::Gt => {
-                        format!("{} > {}", col, format_filter_value(&filter.value))
-                    }
-                    FilterOperator::Gte => {
-                        format!("{} >= {}", col, format_filter_value(&filter.value))
-                    }
-                    FilterOperator::Lt => {
-                        format!("{} < {}", col, format_filter_value(&filter.value))
-                    }
-                    FilterOperator::Lte => {
-                        format!("{} <= {}", col, format_filter_value(&filter.value))
-                    }
-                    FilterOperator::Like => {
-                        // CAST to NVARCHAR so substring search works on every column
-                        // type (numbers, booleans, dates…), not just text columns.
-                        format!(
-                            "CAST({} AS NVARCHAR(MAX)) LIKE {}",
-                            col,
-                            format_filter_value(&filter.value)
-                        )
-                    }
-                    FilterOperator::IsNull => format!("{} IS NULL", col),
-                    FilterOperator::IsNotNull => format!("{} IS NOT NULL", col),
-                    FilterOperator::Regex => {
-                        // SQL Server has no native POSIX regex without CLR — fall back to PATINDEX
-                        // with the raw pattern so wildcards still work. Flags are server-side ignored.
-                        let pattern = filter.value.as_text().ok_or_else(|| {
-                            EngineError::syntax_error(
-                                "regex operator requires a string value in 'value'",
-                            )
-                        })?;
-                        format!(
-                            "PATINDEX('%{}%', CAST({} AS NVARCHAR(MAX))) > 0",
-                            pattern.replace('\'', "''"),
-                            col
-                        )
-                    }
-                    FilterOperator::Text => {
-                        // CONTAINS() requires a full-text catalog + index on the column; absence
-                        // surfaces as a server error. UI must verify `index_type = fulltext` first.
-                        let term = filter.value.as_text().ok_or_else(|| {
-                            EngineError::syntax_error(
-                                "text operator requires a string value in 'value'",
-                            )
-                        })?;
-                        format!(
-                            "CONTAINS({}, '\"{}\"')",
-                            col,
-                            term.replace('\'', "''").replace('"', "\"\"")
-                        )
-                    }
-                };
-                where_clauses.push(clause);
+                where_clauses.push(Self::filter_sql(filter, &mut params)?);
             }
         }
 
@@ -1423,19 +2333,38 @@ impl DataEngine for SqlServerDriver {
             format!(" WHERE {}", where_clauses.join(" AND "))
         };
 
-        let order_sql = if let Some(ref sort_col) = options.sort_column {
-            let direction = match options.sort_direction.unwrap_or_default() {
-                SortDirection::Asc => "ASC",
-                SortDirection::Desc => "DESC",
-            };
-            format!(" ORDER BY {} {}", Self::quote_ident(sort_col), direction)
+        // Unsorted pages follow the primary key. SQL Server exposes no row id,
+        // so a heap keeps `(SELECT NULL)`, which OFFSET requires.
+        let implicit_order = if options.sort_column.is_none() {
+            match conn.query(PK_COLUMNS_SQL, &[&schema, &table]).await {
+                Ok(stream) => match stream.into_first_result().await {
+                    Ok(rows) => ImplicitOrder::new(
+                        rows.iter()
+                            .filter_map(|row| row.get::<&str, _>(0).map(str::to_string))
+                            .collect(),
+                        None,
+                    ),
+                    Err(e) => {
+                        tracing::debug!("No implicit order for {}: {}", table_ref, e);
+                        ImplicitOrder::Unordered
+                    }
+                },
+                Err(e) => {
+                    tracing::debug!("No implicit order for {}: {}", table_ref, e);
+                    ImplicitOrder::Unordered
+                }
+            }
         } else {
-            " ORDER BY (SELECT NULL)".to_string()
+            ImplicitOrder::Unordered
         };
+        let mut order_sql = options.order_by_sql(&implicit_order, Self::quote_ident);
+        if order_sql.is_empty() {
+            order_sql = " ORDER BY (SELECT NULL)".to_string();
+        }
 
         let count_sql = format!("SELECT COUNT(*) FROM {}{}", table_ref, where_sql);
-        let count_stream = conn
-            .simple_query(&count_sql)
+        let count_stream = bound_query(count_sql, &params)
+            .query(&mut conn)
             .await
             .map_err(|e| EngineError::execution_error(e.to_string()))?;
         let count_rows = count_stream
@@ -1454,8 +2383,8 @@ impl DataEngine for SqlServerDriver {
             table_ref, where_sql, order_sql, offset, page_size
         );
 
-        let data_stream = conn
-            .simple_query(&data_sql)
+        let data_stream = bound_query(data_sql, &params)
+            .query(&mut conn)
             .await
             .map_err(|e| EngineError::execution_error(e.to_string()))?;
         let data_rows = data_stream
@@ -1494,16 +2423,33 @@ impl DataEngine for SqlServerDriver {
     ) -> EngineResult<QueryResult> {
         let schema = namespace.schema.as_deref().unwrap_or("dbo");
         let limit = limit.max(1).min(50);
-        let value_sql = format_filter_value(value);
         let query = format!(
-            "SELECT TOP {} * FROM {}.{} WHERE {} = {}",
+            "SELECT TOP {} * FROM {}.{} WHERE {} = @P1",
             limit,
             Self::quote_ident(schema),
             Self::quote_ident(&foreign_key.referenced_table),
             Self::quote_ident(&foreign_key.referenced_column),
-            value_sql
         );
-        self.execute(session, &query, QueryId::new()).await
+        self.execute_with_params(session, &query, std::slice::from_ref(value), QueryId::new())
+            .await
+    }
+
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        column_stats::column_stats(
+            self,
+            SqlDialect::SqlServer,
+            session,
+            namespace,
+            table,
+            column,
+        )
+        .await
     }
 
     async fn create_database(
@@ -1615,43 +2561,92 @@ impl DataEngine for SqlServerDriver {
         Ok(())
     }
 
-    fn supports_transactions(&self) -> bool {
-        true
+    fn supports_transactions(&self) -> bool {
+        true
+    }
+
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        &[
+            IsolationLevel::ReadUncommitted,
+            IsolationLevel::ReadCommitted,
+            IsolationLevel::RepeatableRead,
+            IsolationLevel::Serializable,
+            IsolationLevel::Snapshot,
+        ]
+    }
+
+    async fn insert_row(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+    ) -> EngineResult<QueryResult> {
+        let schema = namespace.schema.as_deref().unwrap_or("dbo");
+        let table_ref = format!("{}.{}", Self::quote_ident(schema), Self::quote_ident(table));
+
+        let computed = self.computed_columns(session, schema, table).await?;
+        let sql = Self::insert_sql(&table_ref, data, &computed);
+
+        self.execute(session, &sql, QueryId::new()).await
     }
 
-    async fn insert_row(
+    async fn upsert_row(
         &self,
         session: SessionId,
         namespace: &Namespace,
         table: &str,
         data: &RowData,
-    ) -> EngineResult<QueryResult> {
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        let mssql_session = self.get_session(session).await?;
+
         let schema = namespace.schema.as_deref().unwrap_or("dbo");
         let table_ref = format!("{}.{}", Self::quote_ident(schema), Self::quote_ident(table));
 
-        let mut keys: Vec<&String> = data.columns.keys().collect();
-        keys.sort();
+        let computed = self.computed_columns(session, schema, table).await?;
+        let data = RowData {
+            columns: data
+                .columns
+                .iter()
+                .filter(|(col, _)| !computed.contains(*col))
+                .map(|(col, val)| (col.clone(), val.clone()))
+                .collect(),
+        };
+        let columns = UpsertColumns::new(&data, conflict_columns)?;
+        let sql = Self::upsert_sql(&table_ref, &columns);
+
+        let mut query = Query::new(sql);
+        for k in &columns.insert {
+            bind_value(&mut query, &data.columns[k]);
+        }
 
-        let sql = if keys.is_empty() {
-            format!("INSERT INTO {} DEFAULT VALUES", table_ref)
+        let start = Instant::now();
+        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+        let action = if let Some(ref mut tx_conn) = *tx_guard {
+            run_upsert(tx_conn, query).await?
         } else {
-            let cols_str = keys
-                .iter()
-                .map(|k| Self::quote_ident(k))
-                .collect::<Vec<_>>()
-                .join(", ");
-            let vals_str = keys
-                .iter()
-                .map(|k| format_filter_value(data.columns.get(*k).unwrap()))
-                .collect::<Vec<_>>()
-                .join(", ");
-            format!(
-                "INSERT INTO {} ({}) VALUES ({})",
-                table_ref, cols_str, vals_str
-            )
+            drop(tx_guard);
+            let mut conn = mssql_session.pool.get().await.map_err(|e| {
+                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+            })?;
+            run_upsert(&mut conn, query).await?
         };
+        let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
 
-        self.execute(session, &sql, QueryId::new()).await
+        let action = match action.as_deref() {
+            Some("INSERT") => Some(UpsertAction::Inserted),
+            Some("UPDATE") => Some(UpsertAction::Updated),
+            _ => None,
+        };
+        Ok(UpsertResult {
+            result: QueryResult::with_affected_rows(u64::from(action.is_some()), execution_time_ms),
+            action,
+        })
+    }
+
+    fn supports_upsert(&self) -> bool {
+        true
     }
 
     async fn update_row(
@@ -1674,16 +2669,22 @@ impl DataEngine for SqlServerDriver {
         let schema = namespace.schema.as_deref().unwrap_or("dbo");
         let table_ref = format!("{}.{}", Self::quote_ident(schema), Self::quote_ident(table));
 
+        let computed = self.computed_columns(session, schema, table).await?;
+        let mut params: Vec<Value> = Vec::new();
         let set_clauses: Vec<String> = data
             .columns
             .iter()
-            .map(|(col, val)| format!("{} = {}", Self::quote_ident(col), format_filter_value(val)))
+            .filter(|(col, _)| !computed.contains(*col))
+            .map(|(col, val)| Self::bound_eq(col, val, &mut params))
             .collect();
+        if set_clauses.is_empty() {
+            return Ok(QueryResult::with_affected_rows(0, 0.0));
+        }
 
         let where_clauses: Vec<String> = primary_key
             .columns
             .iter()
-            .map(|(col, val)| format!("{} = {}", Self::quote_ident(col), format_filter_value(val)))
+            .map(|(col, val)| Self::bound_eq(col, val, &mut params))
             .collect();
 
         let sql = format!(
@@ -1693,7 +2694,8 @@ impl DataEngine for SqlServerDriver {
             where_clauses.join(" AND ")
         );
 
-        self.execute(session, &sql, QueryId::new()).await
+        self.execute_with_params(session, &sql, &params, QueryId::new())
+            .await
     }
 
     async fn delete_row(
@@ -1712,10 +2714,11 @@ impl DataEngine for SqlServerDriver {
         let schema = namespace.schema.as_deref().unwrap_or("dbo");
         let table_ref = format!("{}.{}", Self::quote_ident(schema), Self::quote_ident(table));
 
+        let mut params: Vec<Value> = Vec::new();
         let where_clauses: Vec<String> = primary_key
             .columns
             .iter()
-            .map(|(col, val)| format!("{} = {}", Self::quote_ident(col), format_filter_value(val)))
+            .map(|(col, val)| Self::bound_eq(col, val, &mut params))
             .collect();
 
         let sql = format!(
@@ -1724,6 +2727,23 @@ impl DataEngine for SqlServerDriver {
             where_clauses.join(" AND ")
         );
 
+        self.execute_with_params(session, &sql, &params, QueryId::new())
+            .await
+    }
+
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        let schema = namespace.schema.as_deref().unwrap_or("dbo");
+        let sql = format!(
+            "TRUNCATE TABLE {}.{}",
+            Self::quote_ident(schema),
+            Self::quote_ident(table)
+        );
+
         self.execute(session, &sql, QueryId::new()).await
     }
 
@@ -1739,6 +2759,35 @@ impl DataEngine for SqlServerDriver {
         true
     }
 
+    /// The showplan of `query`, read on the transaction connection when one is
+    /// open so uncommitted changes are planned as the session sees them.
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        _namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        let mssql_session = self.get_session(session).await?;
+        let mut tx_guard = mssql_session.transaction_conn.lock().await;
+        let raw = if let Some(ref mut tx_conn) = *tx_guard {
+            fetch_showplan(tx_conn, query, analyze).await?
+        } else {
+            drop(tx_guard);
+            let mut conn = mssql_session.pool.get().await.map_err(|e| {
+                EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
+            })?;
+            fetch_showplan(&mut conn, query, analyze).await?
+        };
+
+        Ok(ExplainPlan {
+            root: explain::parse_showplan_xml(&raw),
+            raw,
+            format: ExplainFormat::Xml,
+            analyzed: analyze,
+        })
+    }
+
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         let mssql_session = self.get_session(session).await?;
 
@@ -1764,7 +2813,7 @@ impl DataEngine for SqlServerDriver {
             ));
         }
 
-        let mut conn = mssql_session.pool.get().await.map_err(|e| {
+        let mut conn = mssql_session.control_pool.get().await.map_err(|e| {
             EngineError::connection_failed(format!("Failed to acquire connection: {e}"))
         })?;
 
@@ -1977,6 +3026,68 @@ async fn execute_dml(
     Ok(QueryResult::with_affected_rows(affected, execution_time_ms))
 }
 
+/// Runs `sql` as an `sp_executesql` call with `params` bound to its `@PN`
+/// placeholders.
+async fn execute_bound(
+    conn: &mut MssqlClient,
+    sql: &str,
+    params: &[Value],
+    returns_rows: bool,
+    start: Instant,
+) -> EngineResult<QueryResult> {
+    let query = bound_query(sql, params);
+
+    if !returns_rows {
+        let result = query
+            .execute(conn)
+            .await
+            .map_err(|e| classify_error(e.to_string()))?;
+        let affected = result.rows_affected().iter().sum::<u64>();
+        let execution_time_ms = start.elapsed().as_micros() as f64 / 1000.0;
+        return Ok(QueryResult::with_affected_rows(affected, execution_time_ms));
+    }
+
+    let result_set = query
+        .query(conn)
+        .await
+        .map_err(|e| classify_error(e.to_string()))?
+        .into_first_result()
+        .await
+        .map_err(|e| classify_error(e.to_string()))?;
+    let columns = if let Some(first) = result_set.first() {
+        get_column_info(first.columns())
+    } else {
+        Vec::new()
+    };
+    let rows: Vec<QRow> = result_set.iter().map(convert_row).collect();
+
+    Ok(QueryResult {
+        columns,
+        rows,
+        affected_rows: None,
+        execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+    })
+}
+
+struct MssqlRoleRunner<'a> {
+    conn: &'a mut MssqlClient,
+    driver_id: &'a str,
+}
+
+#[async_trait]
+impl StatementRunner for MssqlRoleRunner<'_> {
+    async fn run(&mut self, sql: &str) -> EngineResult<QueryResult> {
+        let start = Instant::now();
+        let returns_rows = safety::returns_rows(self.driver_id, sql)
+            .unwrap_or_else(|_| safety::is_select_prefix(sql));
+        if returns_rows {
+            execute_select(self.conn, sql, start).await
+        } else {
+            execute_dml(self.conn, sql, start).await
+        }
+    }
+}
+
 /// Fetches the SQL Server SPID of the connection. We track it so that a later
 /// `cancel(query_id)` can issue `KILL <spid>` on the right session — without
 /// this lookup, `active_queries` would stay empty and cancel would silently
@@ -2009,6 +3120,110 @@ async fn unregister_active_query(session: &SqlServerSession, query_id: QueryId)
     active.remove(&query_id);
 }
 
+/// Binds a Value as the next `@PN` parameter of `query`.
+/// `sql` with `params` bound to `@P1`, `@P2`, ...
+fn bound_query<'a>(sql: impl Into<Cow<'a, str>>, params: &'a [Value]) -> Query<'a> {
+    let mut query = Query::new(sql);
+    for value in params {
+        bind_value(&mut query, value);
+    }
+    query
+}
+
+fn bind_value<'a>(query: &mut Query<'a>, value: &'a Value) {
+    match value {
+        Value::Null | Value::Array(_) => query.bind(Option::<&str>::None),
+        Value::Bool(b) => query.bind(*b),
+        Value::Int(i) => query.bind(*i),
+        Value::Float(f) => query.bind(*f),
+        Value::Text(s) => query.bind(s.as_str()),
+        Value::Bytes(b) => query.bind(b.as_slice()),
+        Value::Json(j) => query.bind(j.to_string()),
+    }
+}
+
+async fn fetch_computed_columns(
+    conn: &mut MssqlClient,
+    schema: &str,
+    table: &str,
+) -> EngineResult<HashSet<String>> {
+    let rows = conn
+        .query(COMPUTED_COLUMNS_SQL, &[&schema, &table])
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?
+        .into_first_result()
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| row.get::<&str, _>(0).map(str::to_string))
+        .collect())
+}
+
+/// Showplan XML of `query`. `SHOWPLAN_XML` compiles the query without
+/// running it; `STATISTICS XML` runs it and adds the actual plan as an extra
+/// result set. Either option has to be set in a batch of its own.
+async fn fetch_showplan(
+    conn: &mut MssqlClient,
+    query: &str,
+    analyze: bool,
+) -> EngineResult<String> {
+    let option = if analyze {
+        "STATISTICS XML"
+    } else {
+        "SHOWPLAN_XML"
+    };
+    conn.simple_query(format!("SET {} ON", option))
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?
+        .into_results()
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+
+    let results = match conn.simple_query(query).await {
+        Ok(stream) => stream.into_results().await,
+        Err(e) => Err(e),
+    };
+    // Reset even when the query failed: the connection goes back to the pool
+    // or stays the transaction connection.
+    conn.simple_query(format!("SET {} OFF", option))
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?
+        .into_results()
+        .await
+        .map_err(|e| EngineError::execution_error(e.to_string()))?;
+    let results = results.map_err(|e| classify_error(e.to_string()))?;
+
+    results
+        .iter()
+        .flatten()
+        .filter_map(|row| {
+            row.cells()
+                .next()
+                .map(|(_, data)| convert_column_data(data))
+        })
+        .find_map(|value| match value {
+            Value::Text(xml) if xml.contains("<ShowPlanXML") => Some(xml),
+            _ => None,
+        })
+        .ok_or_else(|| EngineError::execution_error("SQL Server returned no execution plan"))
+}
+
+/// Runs a parameterised upsert and returns the `$action` it output.
+async fn run_upsert(conn: &mut MssqlClient, query: Query<'_>) -> EngineResult<Option<String>> {
+    let rows = query
+        .query(conn)
+        .await
+        .map_err(|e| classify_error(e.to_string()))?
+        .into_first_result()
+        .await
+        .map_err(|e| classify_error(e.to_string()))?;
+    Ok(rows
+        .first()
+        .and_then(|row| row.get::<&str, _>(0))
+        .map(str::to_string))
+}
+
 /// Format a Value as a SQL literal for inline queries.
 fn format_filter_value(value: &Value) -> String {
     match value {
@@ -2029,9 +3244,56 @@ fn format_filter_value(value: &Value) -> String {
     }
 }
 
+/// Renders a `sys.types` name with the length, precision or scale it needs
+/// in a column definition. `max_length` is in bytes and `-1` for `(max)`.
+fn format_mssql_type(type_name: &str, max_length: i32, precision: i32, scale: i32) -> String {
+    let length = |divisor: i32| {
+        if max_length == -1 {
+            "max".to_string()
+        } else {
+            (max_length / divisor).to_string()
+        }
+    };
+    match type_name {
+        "varchar" | "char" | "varbinary" | "binary" => format!("{type_name}({})", length(1)),
+        "nvarchar" | "nchar" => format!("{type_name}({})", length(2)),
+        "decimal" | "numeric" => format!("{type_name}({precision}, {scale})"),
+        "datetime2" | "datetimeoffset" | "time" => format!("{type_name}({scale})"),
+        _ => type_name.to_string(),
+    }
+}
+
+/// A `sys.indexes` entry grouped from `DDL_INDEX_COLUMNS_SQL` rows.
+struct MssqlIndexDdl {
+    name: String,
+    is_primary_key: bool,
+    is_unique_constraint: bool,
+    is_unique: bool,
+    type_desc: String,
+    filter: Option<String>,
+    keys: Vec<String>,
+    included: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qore_core::types::TlsConfig;
+
+    #[test]
+    fn test_classify_error() {
+        let err = classify_error(
+            "The SELECT permission was denied on the object 'orders', database 'shop', schema 'dbo'."
+                .to_string(),
+        );
+        assert!(matches!(err, EngineError::PermissionDenied { .. }));
+        let err = classify_error("CREATE TABLE permission denied in database 'shop'.".to_string());
+        assert!(matches!(err, EngineError::PermissionDenied { .. }));
+        let err = classify_error("Incorrect syntax near 'FORM'.".to_string());
+        assert!(matches!(err, EngineError::SyntaxError { .. }));
+        let err = classify_error("Invalid object name 'nope'.".to_string());
+        assert!(matches!(err, EngineError::ExecutionError { .. }));
+    }
 
     #[test]
     fn test_quote_ident() {
@@ -2057,6 +3319,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_numeric_keeps_full_precision() {
+        use tiberius::numeric::Numeric;
+
+        // DECIMAL(38, 10)
+        let n = Numeric::new_with_scale(12_345_678_901_234_567_890_123_456_781_234_567_890, 10);
+        assert!(matches!(
+            convert_column_data(&ColumnData::Numeric(Some(n))),
+            Value::Text(ref s) if s == "1234567890123456789012345678.1234567890"
+        ));
+
+        assert_eq!(format_numeric(-5, 2), "-0.05");
+        assert_eq!(format_numeric(1999, 2), "19.99");
+        assert_eq!(format_numeric(42, 0), "42");
+        assert_eq!(format_numeric(0, 3), "0.000");
+    }
+
+    #[test]
+    fn test_format_mssql_type() {
+        assert_eq!(format_mssql_type("nvarchar", 80, 0, 0), "nvarchar(40)");
+        assert_eq!(format_mssql_type("varchar", -1, 0, 0), "varchar(max)");
+        assert_eq!(format_mssql_type("decimal", 9, 10, 2), "decimal(10, 2)");
+        assert_eq!(format_mssql_type("datetime2", 8, 27, 7), "datetime2(7)");
+        assert_eq!(format_mssql_type("int", 4, 10, 0), "int");
+    }
+
     fn base_config() -> ConnectionConfig {
         ConnectionConfig {
             driver: "sqlserver".to_string(),
@@ -2078,9 +3366,56 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 
+    #[test]
+    fn test_execution_timeout() {
+        let mut config = base_config();
+        assert_eq!(SqlServerDriver::execution_timeout(&config), None);
+
+        config.statement_timeout_ms = Some(1500);
+        assert_eq!(
+            SqlServerDriver::execution_timeout(&config),
+            Some(Duration::from_millis(1500))
+        );
+
+        config.query_timeout_secs = Some(1);
+        assert_eq!(
+            SqlServerDriver::execution_timeout(&config),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_session_setup_statements() {
+        let mut config = base_config();
+        assert!(SqlServerDriver::session_setup_statements(&config).is_empty());
+
+        config.statement_timeout_ms = Some(30000);
+        config.lock_timeout_ms = Some(5000);
+        assert_eq!(
+            SqlServerDriver::session_setup_statements(&config),
+            vec!["SET LOCK_TIMEOUT 5000"]
+        );
+
+        config.default_isolation_level = Some(IsolationLevel::Snapshot);
+        assert_eq!(
+            SqlServerDriver::session_setup_statements(&config),
+            vec![
+                "SET LOCK_TIMEOUT 5000",
+                "SET TRANSACTION ISOLATION LEVEL SNAPSHOT"
+            ]
+        );
+    }
+
     #[test]
     fn test_build_config() {
         let config = base_config();
@@ -2088,6 +3423,38 @@ mod tests {
         assert!(tib_config.is_ok());
     }
 
+    #[test]
+    fn test_cert_trust_from_tls_config() {
+        let mut config = base_config();
+        config.ssl = true;
+        assert_eq!(cert_trust(&config).unwrap(), CertTrust::All);
+
+        config.environment = "production".to_string();
+        assert_eq!(cert_trust(&config).unwrap(), CertTrust::System);
+
+        config.tls = Some(TlsConfig {
+            ca_cert_path: Some("/etc/certs/ca.pem".to_string()),
+            ..TlsConfig::default()
+        });
+        assert_eq!(
+            cert_trust(&config).unwrap(),
+            CertTrust::Ca("/etc/certs/ca.pem".to_string())
+        );
+        assert!(SqlServerDriver::build_config(&config).is_ok());
+
+        config.tls = Some(TlsConfig {
+            verify_mode: Some(TlsVerifyMode::None),
+            ..TlsConfig::default()
+        });
+        assert_eq!(cert_trust(&config).unwrap(), CertTrust::All);
+
+        config.tls = Some(TlsConfig {
+            client_cert_path: Some("/etc/certs/client.pem".to_string()),
+            ..TlsConfig::default()
+        });
+        assert!(SqlServerDriver::build_config(&config).is_err());
+    }
+
     #[test]
     fn build_config_accepts_legacy_none_auth_mode() {
         let mut config = base_config();
@@ -2142,4 +3509,180 @@ mod tests {
         config.mssql_auth = Some(MssqlAuthMode::WindowsIntegrated);
         assert!(SqlServerDriver::build_config(&config).is_ok());
     }
+
+    #[test]
+    fn test_upsert_sql() {
+        let data = RowData::new()
+            .with_column("id", Value::Int(1))
+            .with_column("tenant", Value::Int(7))
+            .with_column("name", Value::Text("a".into()));
+        let columns = UpsertColumns::new(&data, &["tenant".to_string(), "id".to_string()]).unwrap();
+        assert_eq!(
+            SqlServerDriver::upsert_sql("[dbo].[users]", &columns),
+            "MERGE INTO [dbo].[users] WITH (HOLDLOCK) AS t \
+             USING (VALUES (@P1, @P2, @P3)) AS s ([id], [name], [tenant]) \
+             ON t.[tenant] = s.[tenant] AND t.[id] = s.[id] \
+             WHEN MATCHED THEN UPDATE SET t.[name] = s.[name] \
+             WHEN NOT MATCHED THEN INSERT ([id], [name], [tenant]) \
+             VALUES (s.[id], s.[name], s.[tenant]) OUTPUT $action;"
+        );
+    }
+
+    #[test]
+    fn test_describe_columns_reads_computed_and_sparse_flags() {
+        assert!(DESCRIBE_COLUMNS_SQL.contains("LEFT JOIN sys.computed_columns cc"));
+        assert!(DESCRIBE_COLUMNS_SQL.contains("cc.definition AS COMPUTED_DEFINITION"));
+        assert!(DESCRIBE_COLUMNS_SQL.contains("sc.is_sparse"));
+        assert!(DESCRIBE_COLUMNS_SQL.contains("sc.is_column_set"));
+        assert!(DESCRIBE_COLUMNS_SQL.contains("WHERE c.TABLE_SCHEMA = @P1 AND c.TABLE_NAME = @P2"));
+        assert!(COMPUTED_COLUMNS_SQL.contains("QUOTENAME(@P1) + '.' + QUOTENAME(@P2)"));
+    }
+
+    #[test]
+    fn test_filter_values_are_bound_not_inlined() {
+        let filter = |operator, value| ColumnFilter {
+            column: "name".to_string(),
+            operator,
+            value,
+            options: Default::default(),
+        };
+        let mut params = Vec::new();
+
+        let eq = SqlServerDriver::filter_sql(
+            &filter(FilterOperator::Eq, Value::Text("x' OR 1=1 --".to_string())),
+            &mut params,
+        );
+        let like = SqlServerDriver::filter_sql(
+            &filter(FilterOperator::Like, Value::Text("%it's%".to_string())),
+            &mut params,
+        );
+        assert_eq!(eq.unwrap(), "[name] = @P1");
+        assert_eq!(like.unwrap(), "CAST([name] AS NVARCHAR(MAX)) LIKE @P2");
+        let bound: Vec<_> = params.iter().filter_map(Value::as_text).collect();
+        assert_eq!(bound, ["x' OR 1=1 --", "%it's%"]);
+
+        assert_eq!(
+            SqlServerDriver::bound_eq("id", &Value::Int(7), &mut params),
+            "[id] = @P3"
+        );
+    }
+
+    #[test]
+    fn test_insert_sql_skips_computed_columns() {
+        let data = RowData::new()
+            .with_column("price", Value::Int(10))
+            .with_column("qty", Value::Int(3))
+            .with_column("total", Value::Int(30));
+        let computed = HashSet::from(["total".to_string()]);
+        assert_eq!(
+            SqlServerDriver::insert_sql("[dbo].[lines]", &data, &computed),
+            "INSERT INTO [dbo].[lines] ([price], [qty]) VALUES (10, 3)"
+        );
+
+        let only_computed = RowData::new().with_column("total", Value::Int(30));
+        assert_eq!(
+            SqlServerDriver::insert_sql("[dbo].[lines]", &only_computed, &computed),
+            "INSERT INTO [dbo].[lines] DEFAULT VALUES"
+        );
+    }
+
+    #[test]
+    fn test_use_database_sql() {
+        assert_eq!(
+            SqlServerDriver::use_database_sql(Some(&Namespace::new("sales]db"))).as_deref(),
+            Some("USE [sales]]db]")
+        );
+        assert_eq!(
+            SqlServerDriver::use_database_sql(Some(&Namespace::new(""))),
+            None
+        );
+        assert_eq!(SqlServerDriver::use_database_sql(None), None);
+    }
+
+    /// Live-gated: set `QOREDB_TEST_MSSQL_HOST` (plus `QOREDB_TEST_MSSQL_USER` /
+    /// `QOREDB_TEST_MSSQL_PASSWORD`, default `sa` / `MyPassword123!`) to run
+    /// against a disposable server. Connects to `master`.
+    fn live_config() -> Option<ConnectionConfig> {
+        let host = std::env::var("QOREDB_TEST_MSSQL_HOST").ok()?;
+        let mut config = base_config();
+        config.host = host;
+        config.database = Some("master".to_string());
+        if let Ok(user) = std::env::var("QOREDB_TEST_MSSQL_USER") {
+            config.username = user;
+        }
+        if let Ok(password) = std::env::var("QOREDB_TEST_MSSQL_PASSWORD") {
+            config.password = password;
+        }
+        Some(config)
+    }
+
+    #[tokio::test]
+    async fn test_execute_as_role_runs_in_namespace_database() {
+        let Some(config) = live_config() else {
+            return;
+        };
+
+        let driver = SqlServerDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        let result = driver
+            .execute_as_role(
+                session,
+                Some(Namespace::new("tempdb")),
+                "guest",
+                "SELECT DB_NAME()",
+                QueryId::new(),
+            )
+            .await;
+        driver.disconnect(session).await.unwrap();
+
+        assert!(matches!(
+            &result.unwrap().rows[0].values[0],
+            Value::Text(name) if name == "tempdb"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_discards_rows_written_in_transaction() {
+        let Some(config) = live_config() else {
+            return;
+        };
+
+        let driver = SqlServerDriver::new();
+        let session = driver.connect(&config).await.unwrap();
+        for sql in [
+            "IF OBJECT_ID('dbo.qoredb_tx_rollback') IS NOT NULL DROP TABLE dbo.qoredb_tx_rollback",
+            "CREATE TABLE dbo.qoredb_tx_rollback (id int PRIMARY KEY)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+
+        let count_sql = "SELECT COUNT(*) FROM dbo.qoredb_tx_rollback";
+        driver.begin_transaction(session).await.unwrap();
+        driver
+            .execute(
+                session,
+                "INSERT INTO dbo.qoredb_tx_rollback (id) VALUES (1)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        let inside = driver
+            .execute(session, count_sql, QueryId::new())
+            .await
+            .unwrap();
+        driver.rollback(session).await.unwrap();
+        let after = driver
+            .execute(session, count_sql, QueryId::new())
+            .await
+            .unwrap();
+
+        driver
+            .execute(session, "DROP TABLE dbo.qoredb_tx_rollback", QueryId::new())
+            .await
+            .unwrap();
+        driver.disconnect(session).await.unwrap();
+
+        assert!(matches!(inside.rows[0].values[0], Value::Int(1)));
+        assert!(matches!(after.rows[0].values[0], Value::Int(0)));
+    }
 }
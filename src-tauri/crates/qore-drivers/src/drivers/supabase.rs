@@ -10,16 +10,20 @@
 
 use async_trait::async_trait;
 
+use crate::column_stats;
 use crate::drivers::pg_compat::{self, SessionMap};
 use qore_core::error::EngineResult;
 use qore_core::traits::{DataEngine, StreamSender};
 use qore_core::types::{
-    CancelSupport, CollectionList, CollectionListOptions, ConnectionConfig, ForeignKey,
+    CancelSupport, CollectionList, CollectionListOptions, ColumnStats, ConnectionConfig,
+    DescribeTableOptions, ExplainPlan, ForeignKey, IndexSpec, IsolationLevel,
     MaintenanceOperationInfo, MaintenanceRequest, MaintenanceResult, Namespace,
-    PaginatedQueryResult, QueryId, QueryResult, RoutineDefinition, RoutineList, RoutineListOptions,
-    RoutineOperationResult, RoutineType, RowData, SessionId, TableQueryOptions, TableSchema,
-    TriggerDefinition, TriggerList, TriggerListOptions, TriggerOperationResult, Value,
+    PaginatedQueryResult, PoolStats, QueryId, QueryResult, RoutineDefinition, RoutineList,
+    RoutineListOptions, RoutineOperationResult, RoutineType, RowData, ServerInfo, SessionId,
+    TableQueryOptions, TableSchema, TriggerDefinition, TriggerList, TriggerListOptions,
+    TriggerOperationResult, UpsertResult, Value,
 };
+use qore_sql::generator::SqlDialect;
 
 pub struct SupabaseDriver {
     sessions: SessionMap,
@@ -65,14 +69,28 @@ impl DataEngine for SupabaseDriver {
         pg_compat::disconnect(&self.sessions, session).await
     }
 
-    async fn ping(&self, session: SessionId) -> EngineResult<()> {
+    async fn ping(&self, session: SessionId) -> EngineResult<std::time::Duration> {
         pg_compat::ping(&self.sessions, session).await
     }
 
+    async fn pool_stats(&self, session: SessionId) -> EngineResult<Option<PoolStats>> {
+        pg_compat::pool_stats(&self.sessions, session)
+            .await
+            .map(Some)
+    }
+
+    async fn server_info(&self, session: SessionId) -> EngineResult<ServerInfo> {
+        pg_compat::server_info(&self.sessions, session).await
+    }
+
     async fn list_namespaces(&self, session: SessionId) -> EngineResult<Vec<Namespace>> {
         pg_compat::list_namespaces_default(&self.sessions, session).await
     }
 
+    async fn list_databases(&self, session: SessionId) -> EngineResult<Vec<String>> {
+        pg_compat::list_databases(&self.sessions, session).await
+    }
+
     async fn list_collections(
         &self,
         session: SessionId,
@@ -88,7 +106,50 @@ impl DataEngine for SupabaseDriver {
         namespace: &Namespace,
         table: &str,
     ) -> EngineResult<TableSchema> {
-        pg_compat::describe_table_core(&self.sessions, session, namespace, table, true).await
+        self.describe_table_with_options(
+            session,
+            namespace,
+            table,
+            &DescribeTableOptions::default(),
+        )
+        .await
+    }
+
+    async fn describe_table_with_options(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        options: &DescribeTableOptions,
+    ) -> EngineResult<TableSchema> {
+        pg_compat::describe_table_core(
+            &self.sessions,
+            session,
+            namespace,
+            table,
+            true,
+            options.fast_estimate,
+        )
+        .await
+    }
+
+    async fn get_table_ddl(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<String> {
+        pg_compat::table_ddl(&self.sessions, session, namespace, table).await
+    }
+
+    async fn create_index(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        spec: &IndexSpec,
+    ) -> EngineResult<()> {
+        pg_compat::create_index(&self.sessions, session, namespace, table, spec).await
     }
 
     async fn execute(
@@ -126,6 +187,24 @@ impl DataEngine for SupabaseDriver {
         .await
     }
 
+    async fn execute_with_params(
+        &self,
+        session: SessionId,
+        query: &str,
+        params: &[Value],
+        query_id: QueryId,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::execute_with_params(
+            &self.sessions,
+            self.driver_id(),
+            session,
+            query,
+            params,
+            query_id,
+        )
+        .await
+    }
+
     async fn execute_stream(
         &self,
         session: SessionId,
@@ -172,16 +251,19 @@ impl DataEngine for SupabaseDriver {
         table: &str,
         limit: u32,
     ) -> EngineResult<QueryResult> {
-        let schema = namespace.schema.as_deref().unwrap_or("public");
-        let query = format!(
-            "SELECT * FROM {}.{} LIMIT {}",
-            pg_compat::quote_ident(schema),
-            pg_compat::quote_ident(table),
-            limit
-        );
+        let query =
+            pg_compat::preview_table_sql(&self.sessions, session, namespace, table, limit).await?;
         self.execute(session, &query, QueryId::new()).await
     }
 
+    async fn geometry_to_geojson(
+        &self,
+        session: SessionId,
+        geometry: &str,
+    ) -> EngineResult<String> {
+        pg_compat::geometry_to_geojson(&self.sessions, session, geometry).await
+    }
+
     async fn query_table(
         &self,
         session: SessionId,
@@ -211,6 +293,24 @@ impl DataEngine for SupabaseDriver {
         .await
     }
 
+    async fn column_stats(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        column: &str,
+    ) -> EngineResult<ColumnStats> {
+        column_stats::column_stats(
+            self,
+            SqlDialect::Postgres,
+            session,
+            namespace,
+            table,
+            column,
+        )
+        .await
+    }
+
     async fn cancel(&self, session: SessionId, query_id: Option<QueryId>) -> EngineResult<()> {
         pg_compat::cancel(&self.sessions, session, query_id).await
     }
@@ -235,6 +335,10 @@ impl DataEngine for SupabaseDriver {
         true
     }
 
+    fn supported_isolation_levels(&self) -> &'static [IsolationLevel] {
+        pg_compat::ISOLATION_LEVELS
+    }
+
     async fn insert_row(
         &self,
         session: SessionId,
@@ -245,6 +349,29 @@ impl DataEngine for SupabaseDriver {
         pg_compat::insert_row(&self.sessions, session, namespace, table, data).await
     }
 
+    async fn upsert_row(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+        data: &RowData,
+        conflict_columns: &[String],
+    ) -> EngineResult<UpsertResult> {
+        pg_compat::upsert_row(
+            &self.sessions,
+            session,
+            namespace,
+            table,
+            data,
+            conflict_columns,
+        )
+        .await
+    }
+
+    fn supports_upsert(&self) -> bool {
+        true
+    }
+
     async fn update_row(
         &self,
         session: SessionId,
@@ -266,10 +393,23 @@ impl DataEngine for SupabaseDriver {
         pg_compat::delete_row(&self.sessions, session, namespace, table, primary_key).await
     }
 
+    async fn truncate_table(
+        &self,
+        session: SessionId,
+        namespace: &Namespace,
+        table: &str,
+    ) -> EngineResult<QueryResult> {
+        pg_compat::truncate_table(&self.sessions, session, namespace, table).await
+    }
+
     fn supports_mutations(&self) -> bool {
         true
     }
 
+    fn supports_computed_columns(&self) -> bool {
+        true
+    }
+
     fn supports_routines(&self) -> bool {
         true
     }
@@ -415,6 +555,24 @@ impl DataEngine for SupabaseDriver {
     fn supports_explain(&self) -> bool {
         true
     }
+
+    async fn explain_query(
+        &self,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        analyze: bool,
+    ) -> EngineResult<ExplainPlan> {
+        pg_compat::explain_query(
+            &self.sessions,
+            self.driver_id(),
+            session,
+            namespace,
+            query,
+            analyze,
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -442,6 +600,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 
@@ -0,0 +1,478 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Execution plans for `DataEngine::explain_query`: the EXPLAIN statements
+//! of each dialect and parsers turning their structured output (PostgreSQL
+//! and MySQL JSON, SQL Server showplan XML) into an `ExplainNode` tree.
+//!
+//! Parsers are lenient: an unknown shape yields no tree, never an error, so
+//! the raw plan is still returned.
+
+use qore_core::types::{ExplainFormat, ExplainNode, ExplainPlan, QueryResult, Value};
+use serde_json::Value as JsonValue;
+
+/// `query` without surrounding whitespace and trailing semicolons, ready to
+/// be prefixed with an EXPLAIN clause.
+pub fn explain_target(query: &str) -> &str {
+    query.trim().trim_end_matches(';').trim_end()
+}
+
+pub fn pg_explain_sql(query: &str, analyze: bool) -> String {
+    let options = if analyze {
+        "ANALYZE, FORMAT JSON"
+    } else {
+        "FORMAT JSON"
+    };
+    format!("EXPLAIN ({}) {}", options, explain_target(query))
+}
+
+/// Plain `EXPLAIN` / `EXPLAIN ANALYZE` for engines with text plans only.
+pub fn text_explain_sql(query: &str, analyze: bool) -> String {
+    let keyword = if analyze {
+        "EXPLAIN ANALYZE"
+    } else {
+        "EXPLAIN"
+    };
+    format!("{} {}", keyword, explain_target(query))
+}
+
+/// MySQL only has a JSON plan without ANALYZE; `EXPLAIN ANALYZE` prints a
+/// text tree.
+pub fn mysql_explain_sql(query: &str, analyze: bool) -> String {
+    if analyze {
+        format!("EXPLAIN ANALYZE {}", explain_target(query))
+    } else {
+        format!("EXPLAIN FORMAT=JSON {}", explain_target(query))
+    }
+}
+
+/// MariaDB's `ANALYZE FORMAT=JSON` keeps the JSON plan when the query is
+/// run.
+pub fn mariadb_explain_sql(query: &str, analyze: bool) -> String {
+    if analyze {
+        format!("ANALYZE FORMAT=JSON {}", explain_target(query))
+    } else {
+        format!("EXPLAIN FORMAT=JSON {}", explain_target(query))
+    }
+}
+
+/// The plan text of an EXPLAIN result: the last column of every row, one
+/// line each. Covers single-column plans and DuckDB's key/value pairs.
+pub fn plan_text(result: &QueryResult) -> String {
+    let mut lines = Vec::with_capacity(result.rows.len());
+    for row in &result.rows {
+        match row.values.last() {
+            Some(Value::Text(text)) => lines.push(text.clone()),
+            Some(Value::Json(json)) => {
+                lines.push(serde_json::to_string_pretty(json).unwrap_or_default())
+            }
+            _ => {}
+        }
+    }
+    lines.join("\n")
+}
+
+/// A plan the driver cannot parse, returned as text only.
+pub fn text_plan(raw: String, analyzed: bool) -> ExplainPlan {
+    ExplainPlan {
+        raw,
+        format: ExplainFormat::Text,
+        root: None,
+        analyzed,
+    }
+}
+
+/// A JSON plan, with the tree built by `parse` when the JSON has the
+/// expected shape.
+pub fn json_plan(
+    raw: String,
+    analyzed: bool,
+    parse: fn(&str) -> Option<ExplainNode>,
+) -> ExplainPlan {
+    ExplainPlan {
+        root: parse(&raw),
+        raw,
+        format: ExplainFormat::Json,
+        analyzed,
+    }
+}
+
+fn number(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::Number(n) => n.as_f64(),
+        // MySQL reports costs as strings
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+// ==================== PostgreSQL ====================
+
+/// Parses `EXPLAIN (FORMAT JSON)` output.
+pub fn parse_pg_plan(raw: &str) -> Option<ExplainNode> {
+    let doc: JsonValue = serde_json::from_str(raw).ok()?;
+    pg_node(doc.get(0)?.get("Plan")?)
+}
+
+fn pg_node(plan: &JsonValue) -> Option<ExplainNode> {
+    let mut node = ExplainNode::new(plan.get("Node Type")?.as_str()?);
+    node.relation = plan
+        .get("Relation Name")
+        .and_then(JsonValue::as_str)
+        .map(
+            |name| match plan.get("Schema").and_then(JsonValue::as_str) {
+                Some(schema) => format!("{}.{}", schema, name),
+                None => name.to_string(),
+            },
+        );
+    node.estimated_rows = plan.get("Plan Rows").and_then(number);
+    node.estimated_cost = plan.get("Total Cost").and_then(number);
+    // Actual values are per loop
+    let loops = plan.get("Actual Loops").and_then(number).unwrap_or(1.0);
+    node.actual_rows = plan
+        .get("Actual Rows")
+        .and_then(number)
+        .map(|rows| rows * loops);
+    node.actual_time_ms = plan
+        .get("Actual Total Time")
+        .and_then(number)
+        .map(|ms| ms * loops);
+    if let Some(plans) = plan.get("Plans").and_then(JsonValue::as_array) {
+        node.children = plans.iter().filter_map(pg_node).collect();
+    }
+    Some(node)
+}
+
+// ==================== MySQL / MariaDB ====================
+
+/// Parses `EXPLAIN FORMAT=JSON` output, and MariaDB's `ANALYZE FORMAT=JSON`
+/// whose `r_*` fields carry the actual values.
+pub fn parse_mysql_plan(raw: &str) -> Option<ExplainNode> {
+    let doc: JsonValue = serde_json::from_str(raw).ok()?;
+    doc.get("query_block").map(mysql_query_block)
+}
+
+fn mysql_query_block(block: &JsonValue) -> ExplainNode {
+    let mut node = ExplainNode::new("Query block");
+    node.estimated_cost = block
+        .pointer("/cost_info/query_cost")
+        .or_else(|| block.get("cost"))
+        .and_then(number);
+    node.actual_time_ms = block.get("r_total_time_ms").and_then(number);
+    node.children = mysql_children(block);
+    node
+}
+
+fn mysql_table(table: &JsonValue) -> ExplainNode {
+    let access_type = table
+        .get("access_type")
+        .and_then(JsonValue::as_str)
+        .unwrap_or_default();
+    let node_type = match access_type {
+        "ALL" => "Table scan",
+        "index" => "Index scan",
+        "range" => "Index range scan",
+        "ref" | "eq_ref" | "ref_or_null" | "const" | "system" => "Index lookup",
+        "" => "Table",
+        other => other,
+    };
+    let mut node = ExplainNode::new(node_type);
+    node.relation = table
+        .get("table_name")
+        .and_then(JsonValue::as_str)
+        .map(str::to_string);
+    node.estimated_rows = table
+        .get("rows_produced_per_join")
+        .or_else(|| table.get("rows"))
+        .and_then(number);
+    node.estimated_cost = table
+        .pointer("/cost_info/prefix_cost")
+        .or_else(|| table.get("cost"))
+        .and_then(number);
+    let loops = table.get("r_loops").and_then(number).unwrap_or(1.0);
+    node.actual_rows = table
+        .get("r_rows")
+        .and_then(number)
+        .map(|rows| rows * loops);
+    node.actual_time_ms = table.get("r_total_time_ms").and_then(number);
+    node.children = mysql_children(table);
+    node
+}
+
+fn mysql_children(value: &JsonValue) -> Vec<ExplainNode> {
+    let mut children = Vec::new();
+    let Some(object) = value.as_object() else {
+        return children;
+    };
+    for (key, value) in object {
+        match key.as_str() {
+            "table" => children.push(mysql_table(value)),
+            "query_block" => children.push(mysql_query_block(value)),
+            "nested_loop" => {
+                let mut node = ExplainNode::new("Nested loop");
+                if let Some(items) = value.as_array() {
+                    node.children = items.iter().flat_map(mysql_children).collect();
+                }
+                children.push(node);
+            }
+            "ordering_operation"
+            | "grouping_operation"
+            | "duplicates_removal"
+            | "windowing"
+            | "union_result"
+            | "materialized_from_subquery"
+            | "block-nl-join" => {
+                let mut node = ExplainNode::new(operation_name(key));
+                node.children = mysql_children(value);
+                children.push(node);
+            }
+            "query_specifications"
+            | "attached_subqueries"
+            | "optimized_away_subqueries"
+            | "subqueries" => {
+                if let Some(items) = value.as_array() {
+                    children.extend(items.iter().flat_map(mysql_children));
+                }
+            }
+            _ => {}
+        }
+    }
+    children
+}
+
+/// `ordering_operation` -> `Ordering operation`
+fn operation_name(key: &str) -> String {
+    let words = key.replace(['_', '-'], " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => words,
+    }
+}
+
+// ==================== SQL Server ====================
+
+/// Parses showplan XML (`SET SHOWPLAN_XML` / `SET STATISTICS XML`). Only
+/// the plan of the first statement is kept.
+pub fn parse_showplan_xml(raw: &str) -> Option<ExplainNode> {
+    let tag = regex::Regex::new(r#"<(/?)(\w+)((?:\s+[\w:]+="[^"]*")*)\s*(/?)>"#).unwrap();
+    let attribute = regex::Regex::new(r#"([\w:]+)="([^"]*)""#).unwrap();
+
+    let mut stack: Vec<ExplainNode> = Vec::new();
+    for caps in tag.captures_iter(raw) {
+        let closing = !caps[1].is_empty();
+        let self_closing = !caps[4].is_empty();
+        let attr = |name: &str| {
+            attribute
+                .captures_iter(&caps[3])
+                .find(|a| &a[1] == name)
+                .map(|a| a[2].to_string())
+        };
+
+        match &caps[2] {
+            "RelOp" if closing => {
+                let node = stack.pop()?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => return Some(node),
+                }
+            }
+            "RelOp" => {
+                let mut node = ExplainNode::new(attr("PhysicalOp").unwrap_or_default());
+                node.estimated_rows = attr("EstimateRows").and_then(|v| v.parse().ok());
+                node.estimated_cost =
+                    attr("EstimatedTotalSubtreeCost").and_then(|v| v.parse().ok());
+                if self_closing {
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(node),
+                        None => return Some(node),
+                    }
+                } else {
+                    stack.push(node);
+                }
+            }
+            // One element per thread: rows add up, elapsed time overlaps
+            "RunTimeCountersPerThread" if !closing => {
+                if let Some(node) = stack.last_mut() {
+                    if let Some(rows) = attr("ActualRows").and_then(|v| v.parse::<f64>().ok()) {
+                        node.actual_rows = Some(node.actual_rows.unwrap_or(0.0) + rows);
+                    }
+                    if let Some(ms) = attr("ActualElapsedms").and_then(|v| v.parse::<f64>().ok()) {
+                        node.actual_time_ms = Some(node.actual_time_ms.unwrap_or(0.0).max(ms));
+                    }
+                }
+            }
+            "Object" if !closing => {
+                if let Some(node) = stack.last_mut().filter(|n| n.relation.is_none()) {
+                    node.relation = attr("Table").map(|table| {
+                        let table = table.trim_matches(['[', ']']);
+                        match attr("Schema") {
+                            Some(schema) => {
+                                format!("{}.{}", schema.trim_matches(['[', ']']), table)
+                            }
+                            None => table.to_string(),
+                        }
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// ==================== SQLite ====================
+
+/// Builds the tree of `EXPLAIN QUERY PLAN` rows, given as
+/// `(id, parent, detail)`. Returns the indented text and the tree.
+pub fn sqlite_plan(rows: &[(i64, i64, String)]) -> (String, ExplainNode) {
+    fn children(
+        rows: &[(i64, i64, String)],
+        parent: i64,
+        depth: usize,
+        raw: &mut Vec<String>,
+    ) -> Vec<ExplainNode> {
+        rows.iter()
+            .filter(|(_, p, _)| *p == parent)
+            .map(|(id, _, detail)| {
+                raw.push(format!("{}{}", "  ".repeat(depth), detail));
+                let mut node = ExplainNode::new(detail.clone());
+                node.children = children(rows, *id, depth + 1, raw);
+                node
+            })
+            .collect()
+    }
+
+    let mut raw = Vec::with_capacity(rows.len());
+    let mut root = ExplainNode::new("QUERY PLAN");
+    root.children = children(rows, 0, 0, &mut raw);
+    (raw.join("\n"), root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_sql() {
+        assert_eq!(
+            pg_explain_sql("SELECT 1;\n", true),
+            "EXPLAIN (ANALYZE, FORMAT JSON) SELECT 1"
+        );
+        assert_eq!(
+            mysql_explain_sql(" SELECT 1 ; ", false),
+            "EXPLAIN FORMAT=JSON SELECT 1"
+        );
+    }
+
+    #[test]
+    fn test_parse_pg_plan() {
+        let raw = r#"[{"Plan": {"Node Type": "Nested Loop", "Total Cost": 12.5,
+            "Plan Rows": 10, "Actual Rows": 4, "Actual Loops": 1, "Actual Total Time": 0.2,
+            "Plans": [
+                {"Node Type": "Seq Scan", "Relation Name": "users", "Plan Rows": 10,
+                 "Total Cost": 1.1, "Actual Rows": 2, "Actual Loops": 2, "Actual Total Time": 0.05},
+                {"Node Type": "Index Scan", "Relation Name": "orders", "Plan Rows": 1,
+                 "Total Cost": 0.3}
+            ]}, "Execution Time": 0.3}]"#;
+        let root = parse_pg_plan(raw).unwrap();
+        assert_eq!(root.node_type, "Nested Loop");
+        assert_eq!(root.estimated_cost, Some(12.5));
+        assert_eq!(root.children.len(), 2);
+
+        let scan = &root.children[0];
+        assert_eq!(scan.relation.as_deref(), Some("users"));
+        assert_eq!(scan.actual_rows, Some(4.0));
+        assert_eq!(scan.actual_time_ms, Some(0.1));
+        assert_eq!(root.children[1].actual_rows, None);
+
+        assert!(parse_pg_plan("Seq Scan on users").is_none());
+    }
+
+    #[test]
+    fn test_parse_mysql_plan() {
+        let raw = r#"{"query_block": {"select_id": 1, "cost_info": {"query_cost": "3.20"},
+            "ordering_operation": {"using_filesort": true,
+                "nested_loop": [
+                    {"table": {"table_name": "u", "access_type": "ALL",
+                        "rows_produced_per_join": 5, "cost_info": {"prefix_cost": "1.25"}}},
+                    {"table": {"table_name": "o", "access_type": "ref",
+                        "rows_produced_per_join": 7, "cost_info": {"prefix_cost": "3.20"}}}
+                ]}}}"#;
+        let root = parse_mysql_plan(raw).unwrap();
+        assert_eq!(root.estimated_cost, Some(3.2));
+
+        let ordering = &root.children[0];
+        assert_eq!(ordering.node_type, "Ordering operation");
+        let join = &ordering.children[0];
+        assert_eq!(join.node_type, "Nested loop");
+        assert_eq!(join.children[0].node_type, "Table scan");
+        assert_eq!(join.children[0].relation.as_deref(), Some("u"));
+        assert_eq!(join.children[1].node_type, "Index lookup");
+        assert_eq!(join.children[1].estimated_rows, Some(7.0));
+    }
+
+    #[test]
+    fn test_parse_mariadb_analyze_plan() {
+        let raw = r#"{"query_block": {"select_id": 1, "r_total_time_ms": 0.4,
+            "table": {"table_name": "t", "access_type": "ALL", "rows": 100,
+                "r_loops": 1, "r_rows": 42, "r_total_time_ms": 0.3}}}"#;
+        let root = parse_mysql_plan(raw).unwrap();
+        let table = &root.children[0];
+        assert_eq!(table.estimated_rows, Some(100.0));
+        assert_eq!(table.actual_rows, Some(42.0));
+        assert_eq!(table.actual_time_ms, Some(0.3));
+    }
+
+    #[test]
+    fn test_parse_showplan_xml() {
+        let raw = r#"<ShowPlanXML xmlns="http://schemas.microsoft.com/sqlserver/2004/07/showplan"><BatchSequence><Batch><Statements><StmtSimple StatementText="SELECT ..."><QueryPlan>
+            <RelOp NodeId="0" PhysicalOp="Nested Loops" LogicalOp="Inner Join" EstimateRows="3" EstimatedTotalSubtreeCost="0.0065">
+              <RunTimeInformation><RunTimeCountersPerThread Thread="0" ActualRows="2" ActualElapsedms="1" /></RunTimeInformation>
+              <NestedLoops>
+                <RelOp NodeId="1" PhysicalOp="Clustered Index Scan" EstimateRows="3" EstimatedTotalSubtreeCost="0.0032">
+                  <RunTimeInformation>
+                    <RunTimeCountersPerThread Thread="1" ActualRows="1" ActualElapsedms="0" />
+                    <RunTimeCountersPerThread Thread="2" ActualRows="2" ActualElapsedms="1" />
+                  </RunTimeInformation>
+                  <IndexScan><Object Database="[db]" Schema="[dbo]" Table="[users]" Index="[PK_users]" /></IndexScan>
+                </RelOp>
+                <RelOp NodeId="2" PhysicalOp="Clustered Index Seek" EstimateRows="1" EstimatedTotalSubtreeCost="0.0031">
+                  <IndexScan><Object Database="[db]" Schema="[dbo]" Table="[orders]" /></IndexScan>
+                </RelOp>
+              </NestedLoops>
+            </RelOp></QueryPlan></StmtSimple></Statements></Batch></BatchSequence></ShowPlanXML>"#;
+        let root = parse_showplan_xml(raw).unwrap();
+        assert_eq!(root.node_type, "Nested Loops");
+        assert_eq!(root.estimated_cost, Some(0.0065));
+        assert_eq!(root.relation, None);
+        assert_eq!(root.actual_rows, Some(2.0));
+        assert_eq!(root.children.len(), 2);
+
+        let scan = &root.children[0];
+        assert_eq!(scan.relation.as_deref(), Some("dbo.users"));
+        assert_eq!(scan.actual_rows, Some(3.0));
+        assert_eq!(root.children[1].relation.as_deref(), Some("dbo.orders"));
+        assert_eq!(root.children[1].actual_rows, None);
+    }
+
+    #[test]
+    fn test_sqlite_plan() {
+        let rows = vec![
+            (2, 0, "SCAN users".to_string()),
+            (
+                5,
+                0,
+                "SEARCH orders USING INDEX idx_user (user_id=?)".to_string(),
+            ),
+            (9, 0, "USE TEMP B-TREE FOR ORDER BY".to_string()),
+            (12, 5, "CORRELATED SCALAR SUBQUERY 1".to_string()),
+        ];
+        let (raw, root) = sqlite_plan(&rows);
+        assert_eq!(root.children.len(), 3);
+        assert_eq!(
+            root.children[1].children[0].node_type,
+            "CORRELATED SCALAR SUBQUERY 1"
+        );
+        assert!(raw.contains("\n  CORRELATED SCALAR SUBQUERY 1\n"));
+    }
+}
@@ -18,6 +18,8 @@ use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
 use qore_core::types::{Namespace, Value};
+use qore_sql::generator::SqlDialect;
+use qore_sql::safety;
 
 /// Cache TTL for table capabilities
 const CAPABILITY_CACHE_TTL: Duration = Duration::from_secs(300);
@@ -230,7 +232,7 @@ pub trait FulltextSearchStrategy: Send + Sync {
 
 /// Identifier quoting for dialects that delimit with double quotes (PostgreSQL, SQLite).
 fn quote_identifier_double_quote(name: &str) -> String {
-    format!("\"{}\"", name.replace('"', "\"\""))
+    safety::quote_ident(SqlDialect::Postgres, name)
 }
 
 /// LIKE escaping for dialects that use backslash as the escape character (PostgreSQL, MySQL).
@@ -574,7 +576,7 @@ impl MySqlSearchStrategy {
     }
 
     fn quote_identifier(name: &str) -> String {
-        format!("`{}`", name.replace('`', "``"))
+        safety::quote_ident(SqlDialect::MySql, name)
     }
 
     fn escape_fulltext(term: &str) -> String {
@@ -1181,7 +1183,7 @@ impl SqlServerSearchStrategy {
     }
 
     fn quote_identifier(name: &str) -> String {
-        format!("[{}]", name.replace(']', "]]"))
+        safety::quote_ident(SqlDialect::SqlServer, name)
     }
 
     fn escape_like_pattern(term: &str) -> String {
@@ -7,14 +7,21 @@
 //! SSH tunneling, and query tracking.
 
 pub mod clickhouse_safety;
+pub mod column_stats;
 pub mod drivers;
+pub mod explain;
 pub mod fulltext_strategy;
 pub mod mongo_pipeline;
 pub mod mongo_safety;
 pub mod proxy;
 pub mod query_manager;
+pub mod query_timeout;
 pub mod redis_safety;
+pub mod role_switch;
+pub mod scheduled_jobs;
 pub mod schema_export;
+pub mod server_info;
+pub mod server_log;
 pub mod session_manager;
 pub mod ssh_tunnel;
 
@@ -135,6 +135,10 @@ pub struct PipelineStage {
 /// user verbatim; format it with a stable message so the UI can rely on it.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PipelineError {
+    /// The pipeline text could not be parsed as JSON.
+    InvalidJson {
+        message: String,
+    },
     NotAnArray,
     TooManyStages {
         got: usize,
@@ -174,6 +178,9 @@ pub enum PipelineError {
 impl PipelineError {
     pub fn user_message(&self) -> String {
         match self {
+            PipelineError::InvalidJson { message } => {
+                format!("pipeline is not valid JSON: {message}")
+            }
             PipelineError::NotAnArray => "pipeline must be a JSON array of stage objects".to_string(),
             PipelineError::TooManyStages { got, max } => {
                 format!("pipeline has {got} stages, maximum allowed is {max}")
@@ -212,6 +219,13 @@ pub struct ValidatedPipeline {
     pub class: MongoQueryClass,
 }
 
+impl ValidatedPipeline {
+    /// The `$out` / `$merge` stage, when the pipeline writes.
+    pub fn write_stage(&self) -> Option<&PipelineStage> {
+        self.stages.iter().find(|stage| stage.kind.is_write())
+    }
+}
+
 /// Validates a JSON pipeline value. Accepts either:
 ///   - a JSON array of stage objects, or
 ///   - a wrapper object like `{"operation": "aggregate", "pipeline": [...]}`:
@@ -221,6 +235,15 @@ pub fn validate_pipeline(value: &JsonValue) -> Result<ValidatedPipeline, Pipelin
     validate_stages_array(stages_value)
 }
 
+/// Parses and validates the JSON text of a pipeline. Unlike
+/// [`validate_pipeline`], only a bare array of stages is accepted.
+pub fn parse_pipeline(json: &str) -> Result<ValidatedPipeline, PipelineError> {
+    let value: JsonValue = serde_json::from_str(json).map_err(|e| PipelineError::InvalidJson {
+        message: e.to_string(),
+    })?;
+    validate_stages_array(&value)
+}
+
 /// Recursively rejects any forbidden server-side execution operator
 /// (`$function`, `$accumulator`, `$where`) appearing anywhere in a filter or
 /// projection document. The aggregation validator already calls this for
@@ -547,6 +570,9 @@ mod tests {
     #[test]
     fn user_message_is_non_empty_for_each_variant() {
         let cases = [
+            PipelineError::InvalidJson {
+                message: "EOF".into(),
+            },
             PipelineError::NotAnArray,
             PipelineError::TooManyStages { got: 100, max: 50 },
             PipelineError::InvalidStageShape { index: 0 },
@@ -575,4 +601,36 @@ mod tests {
             assert!(!case.user_message().is_empty());
         }
     }
+
+    #[test]
+    fn parse_pipeline_reads_stage_array() {
+        let result = parse_pipeline(r#"[{"$match": {"a": 1}}, {"$limit": 5}]"#).unwrap();
+        assert_eq!(result.stages.len(), 2);
+        assert_eq!(result.stages[1].kind, StageKind::Limit);
+        assert!(result.write_stage().is_none());
+    }
+
+    #[test]
+    fn parse_pipeline_rejects_invalid_json_and_wrappers() {
+        assert!(matches!(
+            parse_pipeline("[{\"$match\": "),
+            Err(PipelineError::InvalidJson { .. })
+        ));
+        assert_eq!(
+            parse_pipeline(r#"{"pipeline": [{"$match": {}}]}"#).unwrap_err(),
+            PipelineError::NotAnArray
+        );
+        assert_eq!(
+            parse_pipeline(r#"[{"$match": {}}, 3]"#).unwrap_err(),
+            PipelineError::InvalidStageShape { index: 1 }
+        );
+    }
+
+    #[test]
+    fn write_stage_finds_out_and_merge() {
+        let out = parse_pipeline(r#"[{"$match": {}}, {"$out": "archive"}]"#).unwrap();
+        assert_eq!(out.write_stage().unwrap().operator, "$out");
+        let merge = parse_pipeline(r#"[{"$merge": {"into": "target"}}]"#).unwrap();
+        assert_eq!(merge.write_stage().unwrap().kind, StageKind::Merge);
+    }
 }
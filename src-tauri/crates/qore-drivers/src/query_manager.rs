@@ -3,17 +3,91 @@
 //! Query Manager
 //!
 //! Tracks active queries per session and provides query IDs for cancellation.
+//!
+//! Sessions can also be given a concurrency limit: queries beyond it wait in
+//! a per-session FIFO queue (visible through [`QueryManager::query_state`])
+//! and are dispatched as running queries release their [`QuerySlot`].
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
-use tokio::sync::RwLock;
+use serde::Serialize;
+use tokio::sync::{oneshot, OwnedSemaphorePermit, RwLock, Semaphore};
 
 use qore_core::types::{QueryId, SessionId};
 
+pub const QUEUED_QUERY_CANCELLED: &str = "Query cancelled while queued";
+
+/// Where a registered query stands with respect to its session's limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum QueryState {
+    Running,
+    /// Waiting for a slot; `position` is 1 for the next query to dispatch.
+    Queued {
+        position: usize,
+    },
+}
+
+/// Permission to execute one query on a limited session. Dropping it frees
+/// the slot for the next queued query.
+pub struct QuerySlot {
+    permit: Option<OwnedSemaphorePermit>,
+    retire: Arc<AtomicUsize>,
+}
+
+impl Drop for QuerySlot {
+    fn drop(&mut self) {
+        let Some(permit) = self.permit.take() else {
+            return;
+        };
+        // The limit was lowered while this query ran: keep the slot closed.
+        if self
+            .retire
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            permit.forget();
+        }
+    }
+}
+
+struct QueuedQuery {
+    query_id: QueryId,
+    cancel: oneshot::Sender<()>,
+}
+
+struct SessionSlots {
+    limit: usize,
+    semaphore: Arc<Semaphore>,
+    /// Slots to close as running queries finish after the limit was lowered.
+    retire: Arc<AtomicUsize>,
+    queue: VecDeque<QueuedQuery>,
+}
+
 pub struct QueryManager {
     active: RwLock<HashMap<QueryId, SessionId>>,
     by_session: RwLock<HashMap<SessionId, HashSet<QueryId>>>,
     last_by_session: RwLock<HashMap<SessionId, QueryId>>,
+    // std Mutex: also touched from `Drop` when a queued wait is abandoned.
+    slots: Mutex<HashMap<SessionId, SessionSlots>>,
+}
+
+/// Removes a waiting query from its session queue however the wait ends.
+struct Dequeue<'a> {
+    manager: &'a QueryManager,
+    session_id: SessionId,
+    query_id: QueryId,
+}
+
+impl Drop for Dequeue<'_> {
+    fn drop(&mut self) {
+        let mut slots = self.manager.lock_slots();
+        if let Some(session) = slots.get_mut(&self.session_id) {
+            session.queue.retain(|q| q.query_id != self.query_id);
+        }
+    }
 }
 
 impl QueryManager {
@@ -22,7 +96,148 @@ impl QueryManager {
             active: RwLock::new(HashMap::new()),
             by_session: RwLock::new(HashMap::new()),
             last_by_session: RwLock::new(HashMap::new()),
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock_slots(&self) -> std::sync::MutexGuard<'_, HashMap<SessionId, SessionSlots>> {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Caps the number of queries running at once on `session_id`. Raising
+    /// the limit dispatches queued queries immediately; lowering it closes
+    /// idle slots now and busy ones as their queries finish.
+    pub fn set_session_limit(&self, session_id: SessionId, limit: usize) {
+        let limit = limit.max(1);
+        let mut slots = self.lock_slots();
+        let Some(session) = slots.get_mut(&session_id) else {
+            slots.insert(
+                session_id,
+                SessionSlots {
+                    limit,
+                    semaphore: Arc::new(Semaphore::new(limit)),
+                    retire: Arc::new(AtomicUsize::new(0)),
+                    queue: VecDeque::new(),
+                },
+            );
+            return;
+        };
+
+        if limit > session.limit {
+            let mut extra = limit - session.limit;
+            // Cancel pending retirements before opening new slots.
+            let retiring = session.retire.load(Ordering::SeqCst).min(extra);
+            session.retire.fetch_sub(retiring, Ordering::SeqCst);
+            extra -= retiring;
+            session.semaphore.add_permits(extra);
+        } else {
+            let excess = session.limit - limit;
+            let closed = session.semaphore.forget_permits(excess);
+            session.retire.fetch_add(excess - closed, Ordering::SeqCst);
+        }
+        session.limit = limit;
+    }
+
+    pub fn session_limit(&self, session_id: SessionId) -> Option<usize> {
+        self.lock_slots().get(&session_id).map(|s| s.limit)
+    }
+
+    /// Drops the limit of a closed session; its queued queries are cancelled.
+    pub fn clear_session_limit(&self, session_id: SessionId) {
+        // Dropping the senders wakes every waiter with a cancellation.
+        self.lock_slots().remove(&session_id);
+    }
+
+    /// Waits for a free slot on `session_id`. Returns `Ok(None)` when the
+    /// session has no limit, and an error when the query is cancelled (or the
+    /// session closed) while queued.
+    pub async fn acquire_slot(
+        &self,
+        session_id: SessionId,
+        query_id: QueryId,
+    ) -> Result<Option<QuerySlot>, String> {
+        let (semaphore, retire, cancelled) = {
+            let mut slots = self.lock_slots();
+            let Some(session) = slots.get_mut(&session_id) else {
+                return Ok(None);
+            };
+            let (cancel, cancelled) = oneshot::channel();
+            session.queue.push_back(QueuedQuery { query_id, cancel });
+            (
+                Arc::clone(&session.semaphore),
+                Arc::clone(&session.retire),
+                cancelled,
+            )
+        };
+        let _dequeue = Dequeue {
+            manager: self,
+            session_id,
+            query_id,
+        };
+
+        tokio::select! {
+            permit = semaphore.acquire_owned() => match permit {
+                Ok(permit) => Ok(Some(QuerySlot {
+                    permit: Some(permit),
+                    retire,
+                })),
+                Err(_) => Err(QUEUED_QUERY_CANCELLED.to_string()),
+            },
+            _ = cancelled => Err(QUEUED_QUERY_CANCELLED.to_string()),
+        }
+    }
+
+    /// Cancels a query that is still waiting for a slot. Returns `false` when
+    /// it is not queued (already running, finished or unknown).
+    pub fn cancel_queued(&self, query_id: QueryId) -> bool {
+        let mut slots = self.lock_slots();
+        for session in slots.values_mut() {
+            if let Some(idx) = session.queue.iter().position(|q| q.query_id == query_id) {
+                if let Some(queued) = session.queue.remove(idx) {
+                    let _ = queued.cancel.send(());
+                }
+                return true;
+            }
         }
+        false
+    }
+
+    /// Queued queries of a session, next to dispatch first.
+    pub fn queued_for_session(&self, session_id: SessionId) -> Vec<QueryId> {
+        self.lock_slots()
+            .get(&session_id)
+            .map(|s| s.queue.iter().map(|q| q.query_id).collect())
+            .unwrap_or_default()
+    }
+
+    /// Registered queries of a session that are no longer waiting for a slot.
+    pub async fn running_for_session(&self, session_id: SessionId) -> Vec<QueryId> {
+        let queued = self.queued_for_session(session_id);
+        let by_session = self.by_session.read().await;
+        by_session
+            .get(&session_id)
+            .map(|ids| {
+                ids.iter()
+                    .filter(|id| !queued.contains(id))
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// `None` when the query is not registered.
+    pub async fn query_state(&self, query_id: QueryId) -> Option<QueryState> {
+        let session_id = self.session_for(query_id).await?;
+        let position = self.lock_slots().get(&session_id).and_then(|s| {
+            s.queue
+                .iter()
+                .position(|q| q.query_id == query_id)
+                .map(|idx| idx + 1)
+        });
+        Some(match position {
+            Some(position) => QueryState::Queued { position },
+            None => QueryState::Running,
+        })
     }
 
     pub async fn register(&self, session_id: SessionId) -> QueryId {
@@ -164,4 +379,130 @@ mod tests {
 
         assert!(err.contains("already"));
     }
+
+    #[tokio::test]
+    async fn unlimited_session_runs_immediately() {
+        let manager = QueryManager::new();
+        let session = SessionId::new();
+        let query_id = manager.register(session).await;
+
+        let slot = manager.acquire_slot(session, query_id).await.unwrap();
+        assert!(slot.is_none());
+        assert_eq!(
+            manager.query_state(query_id).await,
+            Some(QueryState::Running)
+        );
+    }
+
+    #[tokio::test]
+    async fn queries_beyond_limit_are_queued_and_dispatched_in_order() {
+        let manager = Arc::new(QueryManager::new());
+        let session = SessionId::new();
+        manager.set_session_limit(session, 1);
+
+        let first = manager.register(session).await;
+        let running = manager.acquire_slot(session, first).await.unwrap();
+        assert!(running.is_some());
+
+        let (order_tx, mut order_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut queued = Vec::new();
+        for _ in 0..3 {
+            let query_id = manager.register(session).await;
+            let manager = Arc::clone(&manager);
+            let order_tx = order_tx.clone();
+            tokio::spawn(async move {
+                let _slot = manager.acquire_slot(session, query_id).await.unwrap();
+                order_tx.send(query_id).unwrap();
+            });
+            // Let the task enqueue before spawning the next one.
+            while manager.query_state(query_id).await == Some(QueryState::Running) {
+                tokio::task::yield_now().await;
+            }
+            queued.push(query_id);
+        }
+
+        assert_eq!(manager.queued_for_session(session), queued);
+        assert_eq!(
+            manager.query_state(queued[2]).await,
+            Some(QueryState::Queued { position: 3 })
+        );
+        assert_eq!(manager.query_state(first).await, Some(QueryState::Running));
+
+        drop(running);
+        let mut dispatched = Vec::new();
+        for _ in 0..3 {
+            dispatched.push(order_rx.recv().await.unwrap());
+        }
+        assert_eq!(dispatched, queued);
+        assert!(manager.queued_for_session(session).is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancel_queued_rejects_waiting_query() {
+        let manager = Arc::new(QueryManager::new());
+        let session = SessionId::new();
+        manager.set_session_limit(session, 1);
+
+        let first = manager.register(session).await;
+        let _running = manager.acquire_slot(session, first).await.unwrap();
+
+        let waiting = manager.register(session).await;
+        let task = {
+            let manager = Arc::clone(&manager);
+            tokio::spawn(async move { manager.acquire_slot(session, waiting).await })
+        };
+        while manager.queued_for_session(session).is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(manager.cancel_queued(waiting));
+        let result = task.await.unwrap();
+        assert_eq!(result.err().as_deref(), Some(QUEUED_QUERY_CANCELLED));
+        assert!(!manager.cancel_queued(first));
+    }
+
+    #[tokio::test]
+    async fn running_for_session_skips_queued_and_other_sessions() {
+        let manager = Arc::new(QueryManager::new());
+        let session = SessionId::new();
+        manager.set_session_limit(session, 1);
+
+        let first = manager.register(session).await;
+        let _running = manager.acquire_slot(session, first).await.unwrap();
+        let waiting = manager.register(session).await;
+        let task = {
+            let manager = Arc::clone(&manager);
+            tokio::spawn(async move { manager.acquire_slot(session, waiting).await })
+        };
+        while manager.queued_for_session(session).is_empty() {
+            tokio::task::yield_now().await;
+        }
+        manager.register(SessionId::new()).await;
+
+        assert_eq!(manager.running_for_session(session).await, [first]);
+        assert!(manager.cancel_queued(waiting));
+        let _ = task.await;
+    }
+
+    #[tokio::test]
+    async fn lowering_limit_retires_busy_slots() {
+        let manager = QueryManager::new();
+        let session = SessionId::new();
+        manager.set_session_limit(session, 2);
+
+        let a = manager.acquire_slot(session, QueryId::new()).await.unwrap();
+        let b = manager.acquire_slot(session, QueryId::new()).await.unwrap();
+        manager.set_session_limit(session, 1);
+        assert_eq!(manager.session_limit(session), Some(1));
+
+        drop(a);
+        // Only one slot remains: it is still held by `b`.
+        let next = QueryId::new();
+        let pending = manager.acquire_slot(session, next);
+        tokio::pin!(pending);
+        assert!(futures::poll!(pending.as_mut()).is_pending());
+
+        drop(b);
+        assert!(pending.await.unwrap().is_some());
+    }
 }
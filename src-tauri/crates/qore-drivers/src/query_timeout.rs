@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Client-side query timeout (`ConnectionConfig::query_timeout_secs`).
+//!
+//! On expiry the driver's own cancel path runs first (`pg_cancel_backend`,
+//! `KILL`, ...), then the query gets a short grace period to unwind so its
+//! connection and active-query bookkeeping are released normally.
+
+use std::future::Future;
+use std::time::Duration;
+
+use qore_core::error::{EngineError, EngineResult};
+
+/// How long a cancelled query may take to return before it is dropped.
+const CANCEL_GRACE: Duration = Duration::from_secs(5);
+
+/// The configured timeout, `None` when unset or zero.
+pub fn query_timeout(secs: Option<u64>) -> Option<Duration> {
+    secs.filter(|secs| *secs > 0).map(Duration::from_secs)
+}
+
+/// Runs `query`, cancelling it through `cancel` once it exceeds `limit`.
+///
+/// A query that still completes successfully during the grace period keeps
+/// its result: its effects are committed, so reporting a timeout would lie.
+pub async fn with_query_timeout<T>(
+    limit: Option<Duration>,
+    query: impl Future<Output = EngineResult<T>>,
+    cancel: impl Future<Output = EngineResult<()>>,
+) -> EngineResult<T> {
+    let Some(limit) = limit else {
+        return query.await;
+    };
+    tokio::pin!(query);
+    if let Ok(result) = tokio::time::timeout(limit, &mut query).await {
+        return result;
+    }
+
+    if let Err(e) = cancel.await {
+        tracing::warn!("Failed to cancel timed out query: {}", e);
+    }
+    match tokio::time::timeout(CANCEL_GRACE, query).await {
+        Ok(Ok(result)) => Ok(result),
+        _ => Err(timeout_error(limit)),
+    }
+}
+
+/// Like [`with_query_timeout`] for drivers without server-side cancel: the
+/// caller stops waiting, the query may keep running in the background.
+pub async fn with_detached_timeout<T>(
+    limit: Option<Duration>,
+    query: impl Future<Output = EngineResult<T>>,
+) -> EngineResult<T> {
+    match limit {
+        Some(limit) => tokio::time::timeout(limit, query)
+            .await
+            .unwrap_or_else(|_| Err(timeout_error(limit))),
+        None => query.await,
+    }
+}
+
+fn timeout_error(limit: Duration) -> EngineError {
+    EngineError::Timeout {
+        timeout_ms: limit.as_millis() as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_query_timeout() {
+        assert_eq!(query_timeout(None), None);
+        assert_eq!(query_timeout(Some(0)), None);
+        assert_eq!(query_timeout(Some(30)), Some(Duration::from_secs(30)));
+    }
+
+    #[tokio::test]
+    async fn test_fast_query_is_not_cancelled() {
+        let cancelled = AtomicBool::new(false);
+        let result = with_query_timeout(Some(Duration::from_secs(5)), async { Ok(7) }, async {
+            cancelled.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .await;
+        assert!(matches!(result, Ok(7)));
+        assert!(!cancelled.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_slow_query_is_cancelled() {
+        let cancelled = AtomicBool::new(false);
+        let query = async {
+            while !cancelled.load(Ordering::SeqCst) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            Err::<(), _>(EngineError::Cancelled)
+        };
+        let result = with_query_timeout(Some(Duration::from_millis(50)), query, async {
+            cancelled.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .await;
+        assert!(matches!(
+            result,
+            Err(EngineError::Timeout { timeout_ms: 50 })
+        ));
+        assert!(cancelled.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_detached_timeout_stops_waiting() {
+        let query = async {
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+            Ok(())
+        };
+        let result = with_detached_timeout(Some(Duration::from_millis(50)), query).await;
+        assert!(matches!(
+            result,
+            Err(EngineError::Timeout { timeout_ms: 50 })
+        ));
+    }
+}
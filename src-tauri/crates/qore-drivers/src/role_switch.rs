@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs a statement as another role (`SET ROLE` / `EXECUTE AS USER`) to see
+//! what that role can read, e.g. when debugging RLS policies and grants.
+//!
+//! The switch happens inside a transaction that is always rolled back, and
+//! the role is reset whether the statement succeeds or fails.
+
+use async_trait::async_trait;
+
+use crate::drivers::pg_compat::quote_ident;
+use qore_core::error::{EngineError, EngineResult};
+use qore_core::types::QueryResult;
+
+/// Longest role name accepted (SQL Server `sysname`; Postgres truncates at 63).
+pub const MAX_ROLE_NAME_LEN: usize = 128;
+
+pub fn validate_role_name(role: &str) -> EngineResult<()> {
+    if role.trim().is_empty() {
+        return Err(EngineError::validation("Role name cannot be empty"));
+    }
+    if role.trim() != role {
+        return Err(EngineError::validation(
+            "Role name cannot start or end with whitespace",
+        ));
+    }
+    if role.chars().count() > MAX_ROLE_NAME_LEN {
+        return Err(EngineError::validation(format!(
+            "Role name exceeds {} characters",
+            MAX_ROLE_NAME_LEN
+        )));
+    }
+    if role.chars().any(char::is_control) {
+        return Err(EngineError::validation(
+            "Role name cannot contain control characters",
+        ));
+    }
+    Ok(())
+}
+
+/// Statements wrapped around the query to run it as another role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleSwitch {
+    /// Run in order before the query; the query is skipped if one fails
+    pub enter: Vec<String>,
+    /// Run in order after the query, whatever its outcome
+    pub exit: Vec<String>,
+}
+
+impl RoleSwitch {
+    pub fn postgres(role: &str) -> EngineResult<Self> {
+        validate_role_name(role)?;
+        Ok(Self {
+            enter: vec![
+                "BEGIN".to_string(),
+                format!("SET ROLE {}", quote_ident(role)),
+            ],
+            // ROLLBACK first: RESET ROLE is refused inside a failed transaction.
+            exit: vec!["ROLLBACK".to_string(), "RESET ROLE".to_string()],
+        })
+    }
+
+    pub fn sqlserver(role: &str) -> EngineResult<Self> {
+        validate_role_name(role)?;
+        Ok(Self {
+            enter: vec![
+                "BEGIN TRANSACTION".to_string(),
+                format!("EXECUTE AS USER = N'{}'", role.replace('\'', "''")),
+            ],
+            exit: vec![
+                "REVERT".to_string(),
+                "IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION".to_string(),
+            ],
+        })
+    }
+}
+
+/// Runs one statement on the connection the role switch is pinned to.
+#[async_trait]
+pub trait StatementRunner: Send {
+    async fn run(&mut self, sql: &str) -> EngineResult<QueryResult>;
+}
+
+#[derive(Debug)]
+pub struct RoleExecution {
+    pub result: EngineResult<QueryResult>,
+    /// First error raised while resetting the role. When set, the connection
+    /// may still carry the role and must not go back to the pool.
+    pub reset_error: Option<EngineError>,
+}
+
+pub async fn execute_as_role<R: StatementRunner>(
+    runner: &mut R,
+    switch: &RoleSwitch,
+    query: &str,
+) -> RoleExecution {
+    let mut entered = Ok(());
+    for sql in &switch.enter {
+        if let Err(e) = runner.run(sql).await {
+            entered = Err(e);
+            break;
+        }
+    }
+
+    let result = match entered {
+        Ok(()) => runner.run(query).await,
+        Err(e) => Err(e),
+    };
+
+    let mut reset_error = None;
+    for sql in &switch.exit {
+        if let Err(e) = runner.run(sql).await {
+            reset_error.get_or_insert(e);
+        }
+    }
+
+    RoleExecution {
+        result,
+        reset_error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingRunner {
+        statements: Vec<String>,
+        fail_on: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl StatementRunner for RecordingRunner {
+        async fn run(&mut self, sql: &str) -> EngineResult<QueryResult> {
+            self.statements.push(sql.to_string());
+            if self.fail_on == Some(sql) {
+                return Err(EngineError::execution_error("permission denied"));
+            }
+            Ok(QueryResult::empty())
+        }
+    }
+
+    #[test]
+    fn test_postgres_wraps_query_in_set_role() {
+        let switch = RoleSwitch::postgres("report\"ing").unwrap();
+        assert_eq!(switch.enter, vec!["BEGIN", "SET ROLE \"report\"\"ing\""]);
+        assert_eq!(switch.exit, vec!["ROLLBACK", "RESET ROLE"]);
+    }
+
+    #[test]
+    fn test_sqlserver_wraps_query_in_execute_as() {
+        let switch = RoleSwitch::sqlserver("o'brien").unwrap();
+        assert_eq!(
+            switch.enter,
+            vec!["BEGIN TRANSACTION", "EXECUTE AS USER = N'o''brien'"]
+        );
+        assert_eq!(
+            switch.exit,
+            vec!["REVERT", "IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION"]
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_role_names() {
+        for role in ["", "  ", " reporting", "report\0ing", "line\nbreak"] {
+            assert!(RoleSwitch::postgres(role).is_err(), "{role:?}");
+        }
+        assert!(RoleSwitch::sqlserver(&"r".repeat(MAX_ROLE_NAME_LEN + 1)).is_err());
+        assert!(RoleSwitch::postgres("reporting-ro").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_resets_role_after_success() {
+        let switch = RoleSwitch::postgres("reporting").unwrap();
+        let mut runner = RecordingRunner::default();
+
+        let execution = execute_as_role(&mut runner, &switch, "SELECT * FROM orders").await;
+
+        assert!(execution.result.is_ok());
+        assert!(execution.reset_error.is_none());
+        assert_eq!(
+            runner.statements,
+            vec![
+                "BEGIN",
+                "SET ROLE \"reporting\"",
+                "SELECT * FROM orders",
+                "ROLLBACK",
+                "RESET ROLE"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resets_role_after_query_error() {
+        let switch = RoleSwitch::postgres("reporting").unwrap();
+        let mut runner = RecordingRunner {
+            fail_on: Some("SELECT * FROM salaries"),
+            ..Default::default()
+        };
+
+        let execution = execute_as_role(&mut runner, &switch, "SELECT * FROM salaries").await;
+
+        assert!(execution.result.is_err());
+        assert!(execution.reset_error.is_none());
+        assert_eq!(
+            runner.statements[runner.statements.len() - 2..],
+            ["ROLLBACK", "RESET ROLE"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skips_query_but_resets_when_role_switch_fails() {
+        let switch = RoleSwitch::sqlserver("ghost").unwrap();
+        let mut runner = RecordingRunner {
+            fail_on: Some("EXECUTE AS USER = N'ghost'"),
+            ..Default::default()
+        };
+
+        let execution = execute_as_role(&mut runner, &switch, "SELECT 1").await;
+
+        assert!(execution.result.is_err());
+        assert!(!runner.statements.iter().any(|s| s == "SELECT 1"));
+        assert_eq!(
+            runner.statements[runner.statements.len() - 2..],
+            ["REVERT", "IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reports_reset_failure() {
+        let switch = RoleSwitch::postgres("reporting").unwrap();
+        let mut runner = RecordingRunner {
+            fail_on: Some("RESET ROLE"),
+            ..Default::default()
+        };
+
+        let execution = execute_as_role(&mut runner, &switch, "SELECT 1").await;
+
+        assert!(execution.result.is_ok());
+        assert!(execution.reset_error.is_some());
+    }
+}
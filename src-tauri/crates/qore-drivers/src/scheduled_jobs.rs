@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Introspection and control statements for the schedulers drivers expose as
+//! scheduled jobs: pg_cron, MySQL events and SQL Server Agent.
+//!
+//! Each scheduler is probed first, so a server without it lists no jobs
+//! instead of failing on a missing table.
+
+use qore_core::error::{EngineError, EngineResult};
+
+// pg_cron
+
+/// Whether `cron.job` (and the 1.4+ `cron.job_run_details`) exist in the
+/// current database. pg_cron only creates them where the extension lives.
+pub const PG_CRON_PRESENCE_SQL: &str = "SELECT to_regclass('cron.job') IS NOT NULL, \
+     to_regclass('cron.job_run_details') IS NOT NULL";
+
+/// Listing query for `cron.job`, or `None` when pg_cron is not installed in
+/// this database. `jobname` is read through `to_jsonb` because it only
+/// exists since pg_cron 1.3.
+pub fn pg_cron_jobs_sql(has_job_table: bool, has_run_details: bool) -> Option<String> {
+    if !has_job_table {
+        return None;
+    }
+    let last_run = if has_run_details {
+        "(SELECT max(d.start_time)::text FROM cron.job_run_details d WHERE d.jobid = j.jobid)"
+    } else {
+        "NULL::text"
+    };
+    Some(format!(
+        "SELECT j.jobid::text, COALESCE(to_jsonb(j) ->> 'jobname', j.jobid::text), \
+         j.database, j.schedule, j.command, {last_run}, j.active \
+         FROM cron.job j ORDER BY j.jobid"
+    ))
+}
+
+pub const PG_CRON_ALTER_JOB_SQL: &str = "SELECT cron.alter_job(job_id := $1, active := $2)";
+
+pub const PG_CRON_JOB_COMMAND_SQL: &str = "SELECT command, database FROM cron.job WHERE jobid = $1";
+
+pub fn parse_pg_cron_job_id(job_id: &str) -> EngineResult<i64> {
+    job_id
+        .parse()
+        .map_err(|_| EngineError::validation(format!("Invalid pg_cron job id '{job_id}'")))
+}
+
+// MySQL events
+
+/// Events of every schema. MySQL does not expose the next run of a recurring
+/// event, only the instant of a pending one-time event.
+pub const MYSQL_EVENTS_SQL: &str = r#"
+    SELECT
+        CAST(EVENT_NAME AS CHAR),
+        CAST(EVENT_SCHEMA AS CHAR),
+        CAST(CASE WHEN EVENT_TYPE = 'ONE TIME' THEN CONCAT('AT ', EXECUTE_AT)
+             ELSE CONCAT('EVERY ', INTERVAL_VALUE, ' ', INTERVAL_FIELD) END AS CHAR),
+        CAST(EVENT_DEFINITION AS CHAR),
+        CAST(LAST_EXECUTED AS CHAR),
+        CAST(CASE WHEN EVENT_TYPE = 'ONE TIME' AND LAST_EXECUTED IS NULL
+             THEN EXECUTE_AT END AS CHAR),
+        CAST(STATUS AS CHAR)
+    FROM information_schema.EVENTS
+    ORDER BY EVENT_SCHEMA, EVENT_NAME
+"#;
+
+pub fn mysql_event_toggle_sql(
+    database: Option<&str>,
+    event_name: &str,
+    enabled: bool,
+) -> EngineResult<String> {
+    let database = database
+        .filter(|db| !db.is_empty())
+        .ok_or_else(|| EngineError::validation("The event's database is required"))?;
+    Ok(format!(
+        "ALTER EVENT `{}`.`{}` {}",
+        database.replace('`', "``"),
+        event_name.replace('`', "``"),
+        if enabled { "ENABLE" } else { "DISABLE" }
+    ))
+}
+
+// SQL Server Agent
+
+/// 1 when `msdb.dbo.sysjobs` is visible: absent on Azure SQL Database, and
+/// hidden from logins without an SQLAgent role.
+pub const SQLSERVER_AGENT_PRESENCE_SQL: &str =
+    "SELECT CASE WHEN OBJECT_ID('msdb.dbo.sysjobs') IS NULL THEN 0 ELSE 1 END";
+
+/// Agent jobs with their first enabled schedule, first step and latest
+/// activity.
+pub const SQLSERVER_AGENT_JOBS_SQL: &str = "SELECT CONVERT(nvarchar(36), j.job_id), j.name, \
+     sc.name, st.command, \
+     CONVERT(nvarchar(19), a.start_execution_date, 120), \
+     CONVERT(nvarchar(19), a.next_scheduled_run_date, 120), \
+     CAST(j.enabled AS int) \
+     FROM msdb.dbo.sysjobs j \
+     OUTER APPLY (SELECT TOP 1 s.name FROM msdb.dbo.sysjobschedules js \
+                  JOIN msdb.dbo.sysschedules s ON s.schedule_id = js.schedule_id \
+                  WHERE js.job_id = j.job_id AND s.enabled = 1 ORDER BY s.name) sc \
+     OUTER APPLY (SELECT TOP 1 s.command FROM msdb.dbo.sysjobsteps s \
+                  WHERE s.job_id = j.job_id ORDER BY s.step_id) st \
+     OUTER APPLY (SELECT TOP 1 ja.start_execution_date, ja.next_scheduled_run_date \
+                  FROM msdb.dbo.sysjobactivity ja WHERE ja.job_id = j.job_id \
+                  ORDER BY ja.session_id DESC) a \
+     ORDER BY j.name";
+
+fn parse_agent_job_id(job_id: &str) -> EngineResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(job_id)
+        .map_err(|_| EngineError::validation(format!("Invalid Agent job id '{job_id}'")))
+}
+
+pub fn sqlserver_job_toggle_sql(job_id: &str, enabled: bool) -> EngineResult<String> {
+    let job_id = parse_agent_job_id(job_id)?;
+    Ok(format!(
+        "EXEC msdb.dbo.sp_update_job @job_id = '{}', @enabled = {}",
+        job_id,
+        u8::from(enabled)
+    ))
+}
+
+pub fn sqlserver_job_start_sql(job_id: &str) -> EngineResult<String> {
+    let job_id = parse_agent_job_id(job_id)?;
+    Ok(format!("EXEC msdb.dbo.sp_start_job @job_id = '{}'", job_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pg_cron_jobs_query() {
+        let sql = pg_cron_jobs_sql(true, true).unwrap();
+        assert!(sql.contains("FROM cron.job j"));
+        assert!(sql.contains("FROM cron.job_run_details d WHERE d.jobid = j.jobid"));
+
+        let sql = pg_cron_jobs_sql(true, false).unwrap();
+        assert!(!sql.contains("job_run_details"));
+        assert!(sql.contains("NULL::text"));
+    }
+
+    #[test]
+    fn test_pg_cron_not_installed() {
+        assert_eq!(pg_cron_jobs_sql(false, false), None);
+        assert_eq!(pg_cron_jobs_sql(false, true), None);
+    }
+
+    #[test]
+    fn test_pg_cron_job_id() {
+        assert_eq!(parse_pg_cron_job_id("42").unwrap(), 42);
+        assert!(parse_pg_cron_job_id("42; DROP TABLE cron.job").is_err());
+    }
+
+    #[test]
+    fn test_mysql_events_query() {
+        assert!(MYSQL_EVENTS_SQL.contains("FROM information_schema.EVENTS"));
+        assert_eq!(
+            mysql_event_toggle_sql(Some("app`db"), "nightly", false).unwrap(),
+            "ALTER EVENT `app``db`.`nightly` DISABLE"
+        );
+        assert_eq!(
+            mysql_event_toggle_sql(Some("app"), "nightly", true).unwrap(),
+            "ALTER EVENT `app`.`nightly` ENABLE"
+        );
+        assert!(mysql_event_toggle_sql(None, "nightly", true).is_err());
+    }
+
+    #[test]
+    fn test_sqlserver_agent_queries() {
+        assert!(SQLSERVER_AGENT_PRESENCE_SQL.contains("OBJECT_ID('msdb.dbo.sysjobs')"));
+        assert!(SQLSERVER_AGENT_JOBS_SQL.contains("FROM msdb.dbo.sysjobs j"));
+
+        let id = "6f9619ff-8b86-d011-b42d-00c04fc964ff";
+        assert_eq!(
+            sqlserver_job_toggle_sql(id, true).unwrap(),
+            format!("EXEC msdb.dbo.sp_update_job @job_id = '{id}', @enabled = 1")
+        );
+        assert_eq!(
+            sqlserver_job_start_sql(id).unwrap(),
+            format!("EXEC msdb.dbo.sp_start_job @job_id = '{id}'")
+        );
+        assert!(sqlserver_job_start_sql("x'; DROP DATABASE msdb; --").is_err());
+    }
+}
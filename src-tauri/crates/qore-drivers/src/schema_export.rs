@@ -3,10 +3,12 @@
 //! Schema DDL Generator
 //!
 //! Generates CREATE TABLE statements from TableSchema metadata,
-//! using SqlDialect for driver-specific formatting.
+//! using SqlDialect for driver-specific formatting, and text ER diagrams
+//! (Mermaid `erDiagram`, DBML) from the same metadata.
 
-use qore_core::types::{Namespace, TableSchema};
+use qore_core::types::{ForeignKey, Namespace, TableSchema};
 use qore_sql::generator::SqlDialect;
+use serde::{Deserialize, Serialize};
 
 /// Generates a complete CREATE TABLE DDL statement from a TableSchema,
 /// followed by CREATE INDEX statements for non-primary indexes.
@@ -101,10 +103,226 @@ pub fn generate_create_table_ddl(
     output
 }
 
+/// Text format of an exported ER diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErDiagramFormat {
+    /// Mermaid `erDiagram` block (renders in GitHub/GitLab markdown)
+    Mermaid,
+    /// DBML, as consumed by dbdiagram.io
+    Dbml,
+}
+
+/// Cardinality of a relationship, inferred from the referencing column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cardinality {
+    /// The referencing column is unique (sole PK column or single-column
+    /// unique index), so each parent row has at most one child.
+    one_to_one: bool,
+    /// The referencing column is nullable, so a child may have no parent.
+    optional_parent: bool,
+}
+
+fn infer_cardinality(schema: &TableSchema, fk: &ForeignKey) -> Cardinality {
+    let sole_pk = schema
+        .primary_key
+        .as_ref()
+        .is_some_and(|pk| pk.len() == 1 && pk[0] == fk.column);
+    let unique_index = schema
+        .indexes
+        .iter()
+        .any(|idx| idx.is_unique && idx.columns.len() == 1 && idx.columns[0] == fk.column);
+    let optional_parent = schema
+        .columns
+        .iter()
+        .find(|c| c.name == fk.column)
+        .is_some_and(|c| c.nullable);
+
+    Cardinality {
+        one_to_one: sole_pk || unique_index,
+        optional_parent,
+    }
+}
+
+fn is_pk_column(schema: &TableSchema, column: &str) -> bool {
+    match schema.primary_key {
+        Some(ref pk) if !pk.is_empty() => pk.iter().any(|c| c == column),
+        _ => schema
+            .columns
+            .iter()
+            .any(|c| c.name == column && c.is_primary_key),
+    }
+}
+
+/// Generates an ER diagram for `tables` (name, schema pairs).
+///
+/// Relationships come from `TableSchema::foreign_keys`, virtual relations
+/// included. References to tables outside `tables` are skipped so the output
+/// stays self-contained (DBML rejects refs to undeclared tables).
+pub fn generate_er_diagram(tables: &[(String, TableSchema)], format: ErDiagramFormat) -> String {
+    match format {
+        ErDiagramFormat::Mermaid => generate_mermaid(tables),
+        ErDiagramFormat::Dbml => generate_dbml(tables),
+    }
+}
+
+/// Mermaid entity and attribute tokens only allow word characters and `-`
+/// (plus brackets/parentheses in types).
+fn mermaid_token(value: &str, allow_parens: bool) -> String {
+    let token: String = value
+        .trim()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric()
+                || ch == '_'
+                || ch == '-'
+                || (allow_parens && matches!(ch, '(' | ')' | '[' | ']'))
+            {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if token.is_empty() {
+        "_".to_string()
+    } else {
+        token
+    }
+}
+
+fn generate_mermaid(tables: &[(String, TableSchema)]) -> String {
+    let mut output = String::from("erDiagram\n");
+
+    for (name, schema) in tables {
+        output.push_str(&format!("    {} {{\n", mermaid_token(name, false)));
+        for col in &schema.columns {
+            let mut keys: Vec<&str> = Vec::new();
+            if is_pk_column(schema, &col.name) {
+                keys.push("PK");
+            }
+            if schema.foreign_keys.iter().any(|fk| fk.column == col.name) {
+                keys.push("FK");
+            }
+            let mut line = format!(
+                "        {} {}",
+                mermaid_token(&col.data_type, true),
+                mermaid_token(&col.name, false)
+            );
+            if !keys.is_empty() {
+                line.push(' ');
+                line.push_str(&keys.join(", "));
+            }
+            output.push_str(&line);
+            output.push('\n');
+        }
+        output.push_str("    }\n");
+    }
+
+    for (name, schema) in tables {
+        for fk in &schema.foreign_keys {
+            if !tables.iter().any(|(t, _)| *t == fk.referenced_table) {
+                continue;
+            }
+            let cardinality = infer_cardinality(schema, fk);
+            let parent_side = if cardinality.optional_parent {
+                "|o"
+            } else {
+                "||"
+            };
+            let child_side = if cardinality.one_to_one { "o|" } else { "o{" };
+            let label = if fk.is_virtual {
+                format!("{} (virtual)", fk.column)
+            } else {
+                fk.column.clone()
+            };
+            output.push_str(&format!(
+                "    {} {}--{} {} : \"{}\"\n",
+                mermaid_token(&fk.referenced_table, false),
+                parent_side,
+                child_side,
+                mermaid_token(name, false),
+                label.replace('"', "'"),
+            ));
+        }
+    }
+
+    output
+}
+
+fn dbml_ident(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\\\""))
+}
+
+fn generate_dbml(tables: &[(String, TableSchema)]) -> String {
+    let mut output = String::new();
+
+    for (name, schema) in tables {
+        output.push_str(&format!("Table {} {{\n", dbml_ident(name)));
+
+        let composite_pk = schema
+            .primary_key
+            .as_ref()
+            .filter(|pk| pk.len() > 1)
+            .cloned();
+
+        for col in &schema.columns {
+            let mut settings: Vec<&str> = Vec::new();
+            if composite_pk.is_none() && is_pk_column(schema, &col.name) {
+                settings.push("pk");
+            }
+            if col.is_auto_increment {
+                settings.push("increment");
+            }
+            if !col.nullable {
+                settings.push("not null");
+            }
+            let mut line = format!("  {} {}", dbml_ident(&col.name), dbml_ident(&col.data_type));
+            if !settings.is_empty() {
+                line.push_str(&format!(" [{}]", settings.join(", ")));
+            }
+            output.push_str(&line);
+            output.push('\n');
+        }
+
+        if let Some(pk) = composite_pk {
+            let cols: Vec<String> = pk.iter().map(|c| dbml_ident(c)).collect();
+            output.push_str("\n  indexes {\n");
+            output.push_str(&format!("    ({}) [pk]\n", cols.join(", ")));
+            output.push_str("  }\n");
+        }
+
+        output.push_str("}\n\n");
+    }
+
+    for (name, schema) in tables {
+        for fk in &schema.foreign_keys {
+            if !tables.iter().any(|(t, _)| *t == fk.referenced_table) {
+                continue;
+            }
+            let cardinality = infer_cardinality(schema, fk);
+            let op = if cardinality.one_to_one { "-" } else { ">" };
+            if fk.is_virtual {
+                output.push_str("// virtual relation\n");
+            }
+            output.push_str(&format!(
+                "Ref: {}.{} {} {}.{}\n",
+                dbml_ident(name),
+                dbml_ident(&fk.column),
+                op,
+                dbml_ident(&fk.referenced_table),
+                dbml_ident(&fk.referenced_column),
+            ));
+        }
+    }
+
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use qore_core::types::{ForeignKey, TableColumn, TableIndex};
+    use qore_core::types::{TableColumn, TableIndex};
 
     #[test]
     fn test_basic_create_table() {
@@ -117,6 +335,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "name".to_string(),
@@ -125,12 +348,20 @@ mod tests {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             primary_key: Some(vec!["id".to_string()]),
             foreign_keys: vec![],
             row_count_estimate: None,
+            row_count_is_estimate: false,
             indexes: vec![],
+            partitioning: None,
+            row_security: None,
         };
 
         let namespace = Namespace {
@@ -157,6 +388,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "user_id".to_string(),
@@ -165,6 +401,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             primary_key: Some(vec!["id".to_string()]),
@@ -178,6 +419,7 @@ mod tests {
                 is_virtual: false,
             }],
             row_count_estimate: None,
+            row_count_is_estimate: false,
             indexes: vec![TableIndex {
                 name: "idx_user_id".to_string(),
                 columns: vec!["user_id".to_string()],
@@ -185,6 +427,8 @@ mod tests {
                 is_primary: false,
                 index_type: None,
             }],
+            partitioning: None,
+            row_security: None,
         };
 
         let namespace = Namespace {
@@ -209,6 +453,11 @@ mod tests {
                 default_value: None,
                 is_primary_key: true,
                 is_auto_increment: false,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
             }],
             primary_key: Some(vec!["id".to_string()]),
             foreign_keys: vec![ForeignKey {
@@ -221,7 +470,10 @@ mod tests {
                 is_virtual: true,
             }],
             row_count_estimate: None,
+            row_count_is_estimate: false,
             indexes: vec![],
+            partitioning: None,
+            row_security: None,
         };
 
         let namespace = Namespace {
@@ -233,4 +485,160 @@ mod tests {
 
         assert!(!ddl.contains("FOREIGN KEY"));
     }
+
+    fn column(name: &str, data_type: &str, nullable: bool, is_pk: bool) -> TableColumn {
+        TableColumn {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable,
+            default_value: None,
+            is_primary_key: is_pk,
+            is_auto_increment: false,
+            is_computed: false,
+            computed_definition: None,
+            is_sparse: false,
+            is_column_set: false,
+            allowed_values: None,
+        }
+    }
+
+    /// `users(id PK, email)` and `orders(id PK, user_id NOT NULL -> users.id)`
+    fn users_orders() -> Vec<(String, TableSchema)> {
+        let users = TableSchema {
+            columns: vec![
+                column("id", "integer", false, true),
+                column("email", "character varying(255)", true, false),
+            ],
+            primary_key: Some(vec!["id".to_string()]),
+            foreign_keys: vec![],
+            row_count_estimate: None,
+            row_count_is_estimate: false,
+            indexes: vec![],
+            partitioning: None,
+            row_security: None,
+        };
+        let orders = TableSchema {
+            columns: vec![
+                column("id", "integer", false, true),
+                column("user_id", "integer", false, false),
+            ],
+            primary_key: Some(vec!["id".to_string()]),
+            foreign_keys: vec![ForeignKey {
+                column: "user_id".to_string(),
+                referenced_table: "users".to_string(),
+                referenced_column: "id".to_string(),
+                referenced_schema: None,
+                referenced_database: None,
+                constraint_name: Some("fk_user".to_string()),
+                is_virtual: false,
+            }],
+            row_count_estimate: None,
+            row_count_is_estimate: false,
+            indexes: vec![],
+            partitioning: None,
+            row_security: None,
+        };
+        vec![("users".to_string(), users), ("orders".to_string(), orders)]
+    }
+
+    #[test]
+    fn test_mermaid_er_diagram() {
+        let diagram = generate_er_diagram(&users_orders(), ErDiagramFormat::Mermaid);
+
+        assert_eq!(
+            diagram,
+            "erDiagram\n\
+             \x20   users {\n\
+             \x20       integer id PK\n\
+             \x20       character_varying(255) email\n\
+             \x20   }\n\
+             \x20   orders {\n\
+             \x20       integer id PK\n\
+             \x20       integer user_id FK\n\
+             \x20   }\n\
+             \x20   users ||--o{ orders : \"user_id\"\n"
+        );
+    }
+
+    #[test]
+    fn test_dbml_er_diagram() {
+        let diagram = generate_er_diagram(&users_orders(), ErDiagramFormat::Dbml);
+
+        assert_eq!(
+            diagram,
+            "Table \"users\" {\n\
+             \x20 \"id\" \"integer\" [pk, not null]\n\
+             \x20 \"email\" \"character varying(255)\"\n\
+             }\n\
+             \n\
+             Table \"orders\" {\n\
+             \x20 \"id\" \"integer\" [pk, not null]\n\
+             \x20 \"user_id\" \"integer\" [not null]\n\
+             }\n\
+             \n\
+             Ref: \"orders\".\"user_id\" > \"users\".\"id\"\n"
+        );
+    }
+
+    #[test]
+    fn test_er_diagram_cardinality_and_virtual_relations() {
+        let mut tables = users_orders();
+        {
+            let orders = &mut tables[1].1;
+            orders.columns[1].nullable = true;
+            orders.indexes.push(TableIndex {
+                name: "uq_user_id".to_string(),
+                columns: vec!["user_id".to_string()],
+                is_unique: true,
+                is_primary: false,
+                index_type: None,
+            });
+            orders.foreign_keys[0].is_virtual = true;
+            // Dangling reference: the target is not part of the diagram.
+            orders.foreign_keys.push(ForeignKey {
+                column: "id".to_string(),
+                referenced_table: "archive".to_string(),
+                referenced_column: "id".to_string(),
+                referenced_schema: None,
+                referenced_database: None,
+                constraint_name: None,
+                is_virtual: false,
+            });
+        }
+
+        let mermaid = generate_er_diagram(&tables, ErDiagramFormat::Mermaid);
+        assert!(mermaid.contains("    users |o--o| orders : \"user_id (virtual)\"\n"));
+        assert!(!mermaid.contains("archive"));
+
+        let dbml = generate_er_diagram(&tables, ErDiagramFormat::Dbml);
+        assert!(
+            dbml.contains("// virtual relation\nRef: \"orders\".\"user_id\" - \"users\".\"id\"\n")
+        );
+        assert!(!dbml.contains("archive"));
+    }
+
+    #[test]
+    fn test_dbml_composite_primary_key() {
+        let schema = TableSchema {
+            columns: vec![
+                column("order_id", "integer", false, true),
+                column("line_no", "integer", false, true),
+            ],
+            primary_key: Some(vec!["order_id".to_string(), "line_no".to_string()]),
+            foreign_keys: vec![],
+            row_count_estimate: None,
+            row_count_is_estimate: false,
+            indexes: vec![],
+            partitioning: None,
+            row_security: None,
+        };
+
+        let dbml = generate_er_diagram(
+            &[("order_lines".to_string(), schema)],
+            ErDiagramFormat::Dbml,
+        );
+
+        assert!(dbml.contains("\"order_id\" \"integer\" [not null]"));
+        assert!(dbml.contains("(\"order_id\", \"line_no\") [pk]"));
+    }
 }
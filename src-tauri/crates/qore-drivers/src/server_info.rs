@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Server version parsing shared by the SQL drivers. Each driver fetches the
+//! raw version strings; the product flavor, numeric version and the features
+//! it unlocks are derived here.
+
+use qore_core::types::{ServerFeatures, ServerFlavor, ServerInfo};
+
+/// From `SELECT VERSION(), @@version_comment`. Percona keeps the MySQL
+/// version string and only names itself in the comment.
+pub fn mysql_server_info(version: &str, version_comment: &str) -> ServerInfo {
+    let lower = version.to_ascii_lowercase();
+    let comment = version_comment.to_ascii_lowercase();
+    let flavor = if lower.contains("mariadb") || comment.contains("mariadb") {
+        ServerFlavor::Mariadb
+    } else if lower.contains("percona") || comment.contains("percona") {
+        ServerFlavor::Percona
+    } else {
+        ServerFlavor::Mysql
+    };
+
+    // MariaDB behind replication-compatible handshakes reports `5.5.5-10.x`.
+    let numeric = match flavor {
+        ServerFlavor::Mariadb => version.strip_prefix("5.5.5-").unwrap_or(version),
+        _ => version,
+    };
+    server_info(version, parse_version(numeric), flavor)
+}
+
+/// From `server_version` and `server_version_num`. Since PostgreSQL 10 the
+/// number is `major * 10000 + minor`, before it `major * 10000 + minor * 100
+/// + patch` with a two-part major.
+pub fn postgres_server_info(version: &str, version_num: u32) -> ServerInfo {
+    let parts = if version_num >= 100_000 {
+        (version_num / 10_000, version_num % 10_000, 0)
+    } else {
+        (
+            version_num / 10_000,
+            version_num / 100 % 100,
+            version_num % 100,
+        )
+    };
+    server_info(version, parts, ServerFlavor::Postgres)
+}
+
+/// From `@@VERSION` and `SERVERPROPERTY('ProductVersion')`. Only the first
+/// line of `@@VERSION` is kept; the rest is build and OS detail.
+pub fn sqlserver_server_info(version: &str, product_version: &str) -> ServerInfo {
+    let version = version.lines().next().unwrap_or_default().trim();
+    server_info(
+        version,
+        parse_version(product_version),
+        ServerFlavor::SqlServer,
+    )
+}
+
+fn server_info(
+    version: &str,
+    (major, minor, patch): (u32, u32, u32),
+    flavor: ServerFlavor,
+) -> ServerInfo {
+    ServerInfo {
+        version: version.to_string(),
+        major,
+        minor,
+        patch,
+        flavor,
+        features: features(flavor, (major, minor, patch)),
+    }
+}
+
+/// Leading `major.minor.patch` of a version string; missing or non-numeric
+/// parts read as 0.
+pub fn parse_version(version: &str) -> (u32, u32, u32) {
+    let numeric = version
+        .trim()
+        .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .next()
+        .unwrap_or_default();
+    let mut parts = numeric.split('.').map(|p| p.parse().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+fn features(flavor: ServerFlavor, version: (u32, u32, u32)) -> ServerFeatures {
+    let at_least = |min: (u32, u32, u32)| version >= min;
+    match flavor {
+        ServerFlavor::Mysql | ServerFlavor::Percona => ServerFeatures {
+            window_functions: at_least((8, 0, 0)),
+            cte: at_least((8, 0, 0)),
+            json_functions: at_least((5, 7, 8)),
+        },
+        ServerFlavor::Mariadb => ServerFeatures {
+            window_functions: at_least((10, 2, 0)),
+            cte: at_least((10, 2, 1)),
+            json_functions: at_least((10, 2, 3)),
+        },
+        ServerFlavor::Postgres => ServerFeatures {
+            window_functions: at_least((8, 4, 0)),
+            cte: at_least((8, 4, 0)),
+            json_functions: at_least((9, 3, 0)),
+        },
+        // Window ORDER BY since 2012 (11), CTEs since 2005 (9), JSON since 2016 (13)
+        ServerFlavor::SqlServer => ServerFeatures {
+            window_functions: at_least((11, 0, 0)),
+            cte: at_least((9, 0, 0)),
+            json_functions: at_least((13, 0, 0)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_mysql_flavors() {
+        let mysql = mysql_server_info("8.0.36", "MySQL Community Server - GPL");
+        assert_eq!(mysql.flavor, ServerFlavor::Mysql);
+        assert_eq!((mysql.major, mysql.minor, mysql.patch), (8, 0, 36));
+        assert!(mysql.features.window_functions && mysql.features.cte);
+
+        let mariadb = mysql_server_info(
+            "10.11.6-MariaDB-1:10.11.6+maria~ubu2204",
+            "mariadb.org binary distribution",
+        );
+        assert_eq!(mariadb.flavor, ServerFlavor::Mariadb);
+        assert_eq!((mariadb.major, mariadb.minor, mariadb.patch), (10, 11, 6));
+        assert!(mariadb.features.json_functions);
+
+        let legacy = mysql_server_info("5.5.5-10.1.48-MariaDB", "");
+        assert_eq!(legacy.flavor, ServerFlavor::Mariadb);
+        assert_eq!((legacy.major, legacy.minor), (10, 1));
+        assert!(!legacy.features.window_functions);
+
+        let percona = mysql_server_info(
+            "8.0.35-27",
+            "Percona Server (GPL), Release 27, Revision 2f8eeab2",
+        );
+        assert_eq!(percona.flavor, ServerFlavor::Percona);
+        assert_eq!((percona.major, percona.minor, percona.patch), (8, 0, 35));
+
+        let old = mysql_server_info("5.6.51-log", "MySQL Community Server (GPL)");
+        assert_eq!(old.flavor, ServerFlavor::Mysql);
+        assert_eq!(old.features, ServerFeatures::default());
+    }
+
+    #[test]
+    fn parses_postgres_version_num() {
+        let pg16 = postgres_server_info("16.2 (Debian 16.2-1.pgdg120+2)", 160002);
+        assert_eq!((pg16.major, pg16.minor, pg16.patch), (16, 2, 0));
+        assert_eq!(pg16.flavor, ServerFlavor::Postgres);
+        assert!(pg16.features.json_functions);
+
+        let pg96 = postgres_server_info("9.6.24", 90624);
+        assert_eq!((pg96.major, pg96.minor, pg96.patch), (9, 6, 24));
+    }
+
+    #[test]
+    fn parses_sqlserver_product_version() {
+        let info = sqlserver_server_info(
+            "Microsoft SQL Server 2022 (RTM) - 16.0.1000.6 (X64) \n\tOct  8 2022 05:58:25 \n\tCopyright (C) 2022 Microsoft Corporation\n",
+            "16.0.1000.6",
+        );
+        assert_eq!(
+            info.version,
+            "Microsoft SQL Server 2022 (RTM) - 16.0.1000.6 (X64)"
+        );
+        assert_eq!((info.major, info.minor, info.patch), (16, 0, 1000));
+        assert_eq!(info.flavor, ServerFlavor::SqlServer);
+        assert!(info.features.json_functions);
+
+        let old = sqlserver_server_info("Microsoft SQL Server 2008 R2", "10.50.6000.34");
+        assert!(old.features.cte && !old.features.window_functions);
+    }
+}
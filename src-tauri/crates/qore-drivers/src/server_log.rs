@@ -0,0 +1,465 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reading the server log through SQL: MySQL's `performance_schema.error_log`,
+//! the PostgreSQL log file through `pg_read_binary_file`, and SQL Server's
+//! error log through `xp_readerrorlog`.
+//!
+//! Every mechanism needs elevated privileges. Drivers check them first so a
+//! missing grant is reported as such rather than as a failing query.
+
+use std::fmt;
+
+use qore_core::error::{EngineError, EngineResult};
+use qore_core::types::{ServerLogChunk, ServerLogLine, ServerLogSource};
+
+/// Most lines returned by one read.
+pub const MAX_LOG_LINES: u32 = 5_000;
+
+/// The mechanism used for `driver_id`, or `None` when its server log is not
+/// readable through SQL (MariaDB, CockroachDB, managed Postgres services).
+pub fn log_source_for_driver(driver_id: &str) -> Option<ServerLogSource> {
+    match driver_id {
+        "postgres" => Some(ServerLogSource::PgLogFile),
+        "mysql" => Some(ServerLogSource::MysqlErrorLog),
+        "sqlserver" => Some(ServerLogSource::SqlServerErrorLog),
+        _ => None,
+    }
+}
+
+/// Row limit of a read: the requested tail length, or a full batch when
+/// reading what was logged after a cursor.
+pub fn log_read_limit(lines: u32, after: Option<&str>) -> u32 {
+    match after {
+        Some(_) => MAX_LOG_LINES,
+        None => lines.clamp(1, MAX_LOG_LINES),
+    }
+}
+
+pub fn insufficient_privileges(source: ServerLogSource) -> EngineError {
+    let requirement = match source {
+        ServerLogSource::PgLogFile => {
+            "requires a superuser, or membership in pg_read_server_files and pg_monitor"
+        }
+        ServerLogSource::MysqlErrorLog => "requires SELECT on performance_schema.error_log",
+        ServerLogSource::SqlServerErrorLog => {
+            "requires membership in the sysadmin or securityadmin server role"
+        }
+    };
+    EngineError::permission_denied(format!(
+        "Insufficient privileges to read the server log: {requirement}"
+    ))
+}
+
+/// Chunk of lines read from a timestamped log table, fetched newest first
+/// when tailing and oldest first after a cursor. The cursor is the newest
+/// timestamp, or `after` again when nothing new was logged.
+pub fn timestamped_chunk(
+    source: ServerLogSource,
+    mut lines: Vec<ServerLogLine>,
+    after: Option<&str>,
+) -> ServerLogChunk {
+    if after.is_none() {
+        lines.reverse();
+    }
+    let cursor = lines
+        .last()
+        .and_then(|line| line.logged_at.clone())
+        .or_else(|| after.map(str::to_string));
+    ServerLogChunk {
+        source,
+        lines,
+        cursor,
+    }
+}
+
+// MySQL
+
+pub const MYSQL_ERROR_LOG_TAIL_SQL: &str = "SELECT CAST(LOGGED AS CHAR), CAST(PRIO AS CHAR), \
+     CAST(DATA AS CHAR) FROM performance_schema.error_log ORDER BY LOGGED DESC LIMIT ?";
+
+pub const MYSQL_ERROR_LOG_AFTER_SQL: &str = "SELECT CAST(LOGGED AS CHAR), CAST(PRIO AS CHAR), \
+     CAST(DATA AS CHAR) FROM performance_schema.error_log WHERE LOGGED > ? \
+     ORDER BY LOGGED LIMIT ?";
+
+/// `ER_DBACCESS_DENIED_ERROR`, `ER_TABLEACCESS_DENIED_ERROR`,
+/// `ER_SPECIFIC_ACCESS_DENIED_ERROR`
+const MYSQL_ACCESS_DENIED: [u16; 3] = [1044, 1142, 1227];
+/// `ER_NO_SUCH_TABLE`: `error_log` only exists since MySQL 8.0.22
+const MYSQL_NO_SUCH_TABLE: u16 = 1146;
+
+pub fn mysql_error_log_error(error_number: Option<u16>, message: String) -> EngineError {
+    match error_number {
+        Some(n) if MYSQL_ACCESS_DENIED.contains(&n) => {
+            insufficient_privileges(ServerLogSource::MysqlErrorLog)
+        }
+        Some(MYSQL_NO_SUCH_TABLE) => EngineError::not_supported(
+            "Reading the server log requires MySQL 8.0.22 or later (performance_schema.error_log)",
+        ),
+        _ => EngineError::execution_error(message),
+    }
+}
+
+pub fn from_mysql_error(e: sqlx::Error) -> EngineError {
+    let error_number = e
+        .as_database_error()
+        .and_then(|db| db.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>())
+        .map(|db| db.number());
+    mysql_error_log_error(error_number, e.to_string())
+}
+
+// PostgreSQL
+
+/// `pg_read_binary_file` needs pg_read_server_files, `pg_current_logfile`
+/// pg_monitor.
+pub const PG_LOG_PRIVILEGE_SQL: &str = "SELECT rolsuper OR \
+     (pg_catalog.pg_has_role('pg_read_server_files', 'USAGE') \
+     AND pg_catalog.pg_has_role('pg_monitor', 'USAGE')) \
+     FROM pg_catalog.pg_roles WHERE rolname = current_user";
+
+/// NULL when the logging collector is off.
+pub const PG_CURRENT_LOGFILE_SQL: &str = "SELECT pg_catalog.pg_current_logfile()";
+
+pub const PG_LOG_FILE_SIZE_SQL: &str = "SELECT size FROM pg_catalog.pg_stat_file($1)";
+
+/// Read as bytes: a read window can split a multi-byte character.
+pub const PG_READ_LOG_SQL: &str = "SELECT pg_catalog.pg_read_binary_file($1, $2, $3)";
+
+/// Bytes read per requested line when tailing.
+const PG_TAIL_BYTES_PER_LINE: i64 = 256;
+/// Upper bound of one read, so a burst of logging is returned in batches.
+pub const PG_MAX_READ_BYTES: i64 = 1024 * 1024;
+
+/// Position in a log file, as `<offset>:<file>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgLogCursor {
+    pub file: String,
+    pub offset: i64,
+}
+
+impl PgLogCursor {
+    pub fn parse(cursor: &str) -> EngineResult<Self> {
+        cursor
+            .split_once(':')
+            .and_then(|(offset, file)| {
+                let offset = offset.parse().ok().filter(|o: &i64| *o >= 0)?;
+                (!file.is_empty()).then(|| Self {
+                    file: file.to_string(),
+                    offset,
+                })
+            })
+            .ok_or_else(|| EngineError::validation(format!("Invalid log cursor '{cursor}'")))
+    }
+}
+
+impl fmt::Display for PgLogCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.offset, self.file)
+    }
+}
+
+/// Byte range of the log file to read.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PgLogRead {
+    pub offset: i64,
+    pub length: i64,
+    /// The range starts mid-file: its first line is likely cut
+    pub skip_partial_line: bool,
+}
+
+/// Tails the last `lines` lines of `file`, or resumes from `after`. After a
+/// rotation or truncation the new file is read from its start.
+pub fn pg_log_read(file: &str, size: i64, lines: u32, after: Option<&PgLogCursor>) -> PgLogRead {
+    match after {
+        Some(cursor) if cursor.file == file && cursor.offset <= size => PgLogRead {
+            offset: cursor.offset,
+            length: (size - cursor.offset).min(PG_MAX_READ_BYTES),
+            skip_partial_line: false,
+        },
+        Some(_) => PgLogRead {
+            offset: 0,
+            length: size.min(PG_MAX_READ_BYTES),
+            skip_partial_line: false,
+        },
+        None => {
+            let length = (i64::from(lines.clamp(1, MAX_LOG_LINES)) * PG_TAIL_BYTES_PER_LINE)
+                .min(PG_MAX_READ_BYTES)
+                .min(size);
+            PgLogRead {
+                offset: size - length,
+                length,
+                skip_partial_line: length < size,
+            }
+        }
+    }
+}
+
+/// Splits a read into complete lines. Returns them with the number of bytes
+/// consumed; a trailing line still being written is left for the next read.
+pub fn split_pg_log(bytes: &[u8], skip_partial_line: bool) -> (Vec<ServerLogLine>, usize) {
+    let start = if skip_partial_line {
+        match bytes.iter().position(|b| *b == b'\n') {
+            Some(i) => i + 1,
+            None => return (Vec::new(), 0),
+        }
+    } else {
+        0
+    };
+    let end = match bytes[start..].iter().rposition(|b| *b == b'\n') {
+        Some(i) => start + i + 1,
+        None => return (Vec::new(), start),
+    };
+
+    let lines = String::from_utf8_lossy(&bytes[start..end])
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(pg_log_line)
+        .collect();
+    (lines, end)
+}
+
+const PG_SEVERITIES: [&str; 12] = [
+    "DEBUG5", "DEBUG4", "DEBUG3", "DEBUG2", "DEBUG1", "LOG", "INFO", "NOTICE", "WARNING", "ERROR",
+    "FATAL", "PANIC",
+];
+
+/// Splits a `stderr` log line around its `SEVERITY:  ` marker. The timestamp
+/// is read from the default `log_line_prefix` (`%m [%p] `).
+fn pg_log_line(line: &str) -> ServerLogLine {
+    let marker = PG_SEVERITIES
+        .iter()
+        .filter_map(|severity| {
+            line.find(&format!("{severity}:  "))
+                .map(|at| (at, *severity))
+        })
+        .min_by_key(|(at, _)| *at);
+
+    let Some((at, severity)) = marker else {
+        return ServerLogLine {
+            logged_at: None,
+            level: None,
+            message: line.to_string(),
+        };
+    };
+
+    let prefix = &line[..at];
+    let logged_at = prefix
+        .get(..10)
+        .filter(|date| date.as_bytes()[4] == b'-' && date.as_bytes()[7] == b'-')
+        .map(|_| {
+            prefix
+                .split_whitespace()
+                .take(3)
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+    ServerLogLine {
+        logged_at,
+        level: Some(severity.to_string()),
+        message: line[at + severity.len() + 3..].to_string(),
+    }
+}
+
+// SQL Server
+
+pub const SQLSERVER_ERROR_LOG_PRIVILEGE_SQL: &str =
+    "SELECT CASE WHEN IS_SRVROLEMEMBER('sysadmin') = 1 \
+     OR IS_SRVROLEMEMBER('securityadmin') = 1 THEN 1 ELSE 0 END";
+
+/// Reads the current error log (`xp_readerrorlog 0, 1`) through a table
+/// variable so it can be filtered and limited server-side.
+pub fn sqlserver_error_log_sql(lines: u32, after: Option<&str>) -> EngineResult<String> {
+    let limit = log_read_limit(lines, after);
+    let (filter, order) = match after {
+        Some(cursor) => {
+            // Cursors are `CONVERT(..., 121)` timestamps: `yyyy-mm-dd hh:mi:ss.mmm`
+            let valid = cursor.len() <= 23
+                && cursor
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, '-' | ':' | ' ' | '.'));
+            if !valid {
+                return Err(EngineError::validation(format!(
+                    "Invalid log cursor '{cursor}'"
+                )));
+            }
+            (
+                format!("WHERE LogDate > CONVERT(datetime, '{cursor}', 121) "),
+                "ASC",
+            )
+        }
+        None => (String::new(), "DESC"),
+    };
+    Ok(format!(
+        "SET NOCOUNT ON; \
+         DECLARE @log TABLE (LogDate datetime, ProcessInfo nvarchar(64), Text nvarchar(max)); \
+         INSERT INTO @log EXEC master.dbo.xp_readerrorlog 0, 1; \
+         SELECT TOP ({limit}) CONVERT(nvarchar(23), LogDate, 121), Text FROM @log \
+         {filter}ORDER BY LogDate {order}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(logged_at: &str, message: &str) -> ServerLogLine {
+        ServerLogLine {
+            logged_at: Some(logged_at.to_string()),
+            level: None,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_source_per_driver() {
+        assert_eq!(
+            log_source_for_driver("postgres"),
+            Some(ServerLogSource::PgLogFile)
+        );
+        assert_eq!(
+            log_source_for_driver("mysql"),
+            Some(ServerLogSource::MysqlErrorLog)
+        );
+        assert_eq!(
+            log_source_for_driver("sqlserver"),
+            Some(ServerLogSource::SqlServerErrorLog)
+        );
+        for driver in [
+            "mariadb",
+            "cockroachdb",
+            "neon",
+            "supabase",
+            "sqlite",
+            "mongodb",
+        ] {
+            assert_eq!(log_source_for_driver(driver), None, "{driver}");
+        }
+    }
+
+    #[test]
+    fn test_privilege_errors() {
+        for source in [
+            ServerLogSource::PgLogFile,
+            ServerLogSource::MysqlErrorLog,
+            ServerLogSource::SqlServerErrorLog,
+        ] {
+            let message = insufficient_privileges(source).sanitized_message();
+            assert!(message.contains("Insufficient privileges to read the server log"));
+        }
+
+        let denied = mysql_error_log_error(
+            Some(1142),
+            "SELECT command denied to user 'app'@'%' for table 'error_log'".to_string(),
+        );
+        assert!(denied
+            .to_string()
+            .contains("requires SELECT on performance_schema.error_log"));
+        assert!(matches!(
+            mysql_error_log_error(Some(1146), "Table doesn't exist".to_string()),
+            EngineError::NotSupported { .. }
+        ));
+        assert!(matches!(
+            mysql_error_log_error(None, "connection reset".to_string()),
+            EngineError::ExecutionError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_read_limit() {
+        assert_eq!(log_read_limit(0, None), 1);
+        assert_eq!(log_read_limit(200, None), 200);
+        assert_eq!(log_read_limit(1_000_000, None), MAX_LOG_LINES);
+        assert_eq!(log_read_limit(200, Some("cursor")), MAX_LOG_LINES);
+    }
+
+    #[test]
+    fn test_timestamped_chunk() {
+        let tail = timestamped_chunk(
+            ServerLogSource::MysqlErrorLog,
+            vec![line("12:02", "newest"), line("12:01", "oldest")],
+            None,
+        );
+        assert_eq!(tail.lines[0].message, "oldest");
+        assert_eq!(tail.cursor.as_deref(), Some("12:02"));
+
+        let idle = timestamped_chunk(ServerLogSource::MysqlErrorLog, Vec::new(), Some("12:02"));
+        assert!(idle.lines.is_empty());
+        assert_eq!(idle.cursor.as_deref(), Some("12:02"));
+    }
+
+    #[test]
+    fn test_pg_log_cursor() {
+        let cursor = PgLogCursor::parse("1024:log/postgresql-2024-05-01.log").unwrap();
+        assert_eq!(cursor.offset, 1024);
+        assert_eq!(cursor.file, "log/postgresql-2024-05-01.log");
+        assert_eq!(cursor.to_string(), "1024:log/postgresql-2024-05-01.log");
+
+        for invalid in ["", "1024", "x:log/a.log", "-1:log/a.log", "12:"] {
+            assert!(PgLogCursor::parse(invalid).is_err(), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_pg_log_read_window() {
+        let tail = pg_log_read("a.log", 100_000, 10, None);
+        assert_eq!(
+            tail,
+            PgLogRead {
+                offset: 100_000 - 2_560,
+                length: 2_560,
+                skip_partial_line: true,
+            }
+        );
+        assert!(!pg_log_read("a.log", 100, 10, None).skip_partial_line);
+
+        let cursor = PgLogCursor {
+            file: "a.log".to_string(),
+            offset: 90_000,
+        };
+        let resumed = pg_log_read("a.log", 100_000, 10, Some(&cursor));
+        assert_eq!((resumed.offset, resumed.length), (90_000, 10_000));
+
+        // Rotated, then truncated
+        assert_eq!(pg_log_read("b.log", 500, 10, Some(&cursor)).offset, 0);
+        assert_eq!(pg_log_read("a.log", 500, 10, Some(&cursor)).offset, 0);
+    }
+
+    #[test]
+    fn test_split_pg_log() {
+        let chunk = b"ut line\n2024-05-01 10:00:00.123 UTC [42] LOG:  checkpoint starting: time\n\
+                      2024-05-01 10:00:01.001 UTC [43] ERROR:  relation \"x\" does not exist\n\
+                      2024-05-01 10:00:02";
+        let (lines, consumed) = split_pg_log(chunk, true);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0].logged_at.as_deref(),
+            Some("2024-05-01 10:00:00.123 UTC")
+        );
+        assert_eq!(lines[0].level.as_deref(), Some("LOG"));
+        assert_eq!(lines[0].message, "checkpoint starting: time");
+        assert_eq!(lines[1].level.as_deref(), Some("ERROR"));
+        assert_eq!(&chunk[consumed..], b"2024-05-01 10:00:02");
+
+        let (lines, consumed) = split_pg_log(b"\tcontinued statement\n", false);
+        assert_eq!(lines[0].level, None);
+        assert_eq!(lines[0].message, "\tcontinued statement");
+        assert_eq!(consumed, 21);
+
+        assert_eq!(split_pg_log(b"no newline yet", false).1, 0);
+    }
+
+    #[test]
+    fn test_sqlserver_error_log_sql() {
+        let tail = sqlserver_error_log_sql(50, None).unwrap();
+        assert!(tail.contains("EXEC master.dbo.xp_readerrorlog 0, 1"));
+        assert!(tail.contains("SELECT TOP (50)"));
+        assert!(tail.ends_with("ORDER BY LogDate DESC"));
+
+        let follow = sqlserver_error_log_sql(50, Some("2024-05-01 10:00:00.123")).unwrap();
+        assert!(
+            follow.contains("WHERE LogDate > CONVERT(datetime, '2024-05-01 10:00:00.123', 121)")
+        );
+        assert!(follow.ends_with("ORDER BY LogDate ASC"));
+
+        assert!(sqlserver_error_log_sql(50, Some("2024' OR 1=1 --")).is_err());
+    }
+}
@@ -8,11 +8,12 @@
 //! automatic SSH tunnel reconnection.
 
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
-use tokio::time::{timeout, Duration};
+use tokio::time::{sleep, timeout, Duration, Instant};
 use tracing::instrument;
 
 use crate::proxy::ProxyTunnel;
@@ -39,6 +40,82 @@ fn enforce_ssh_host_key_policy(config: &ConnectionConfig) -> EngineResult<()> {
     Ok(())
 }
 
+/// Rejects a connection-level default isolation level the driver cannot apply.
+fn enforce_isolation_level_support(
+    driver: &dyn DataEngine,
+    config: &ConnectionConfig,
+) -> EngineResult<()> {
+    if let Some(level) = config.default_isolation_level {
+        if !driver.supported_isolation_levels().contains(&level) {
+            return Err(EngineError::validation(format!(
+                "Isolation level {} is not supported by {}",
+                level.as_sql(),
+                driver.driver_name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Error fragments meaning the server is not up yet rather than
+/// misconfigured: refused/reset sockets, "starting up" replies, and pool
+/// timeouts (sqlx keeps retrying refused sockets until its acquire timeout).
+const NOT_READY_MARKERS: &[&str] = &[
+    "connection refused",
+    "os error 111",
+    "os error 61",
+    "os error 10061",
+    "connection reset",
+    "the database system is starting up",
+    "server is starting",
+    "not yet accepting connections",
+    "pool timed out",
+];
+
+/// Whether a failed connect is worth retrying while waiting for the server
+/// to come up. Authentication, TLS and validation errors never are.
+fn is_not_ready_error(err: &EngineError) -> bool {
+    match err {
+        EngineError::ConnectionFailed { message } | EngineError::ProxyError { message } => {
+            let message = message.to_lowercase();
+            NOT_READY_MARKERS.iter().any(|m| message.contains(m))
+        }
+        _ => false,
+    }
+}
+
+/// Runs `attempt` until it succeeds, fails with a non-retryable error, or
+/// `max_wait` elapses, backing off exponentially between attempts. With no
+/// `max_wait` the first result is returned as-is.
+async fn retry_until_ready<T, F, Fut>(max_wait: Option<Duration>, mut attempt: F) -> EngineResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = EngineResult<T>>,
+{
+    let Some(max_wait) = max_wait else {
+        return attempt().await;
+    };
+    let deadline = Instant::now() + max_wait;
+    let mut backoff = SessionManager::READY_INITIAL_BACKOFF;
+    let mut attempts = 1u32;
+
+    loop {
+        match attempt().await {
+            Err(err) if is_not_ready_error(&err) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(err);
+                }
+                tracing::debug!(attempts, error = %err, "Server not ready, retrying connection");
+                sleep(backoff.min(remaining)).await;
+                backoff = (backoff * 2).min(SessionManager::READY_MAX_BACKOFF);
+                attempts += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
 /// Connection health status for a single session.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -82,6 +159,10 @@ impl SessionManager {
     #[cfg(feature = "tauri")]
     const HEALTH_CHECK_INTERVAL_SECS: u64 = 30;
     const RECONNECT_THRESHOLD: u32 = 2;
+    const READY_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+    const READY_MAX_BACKOFF: Duration = Duration::from_secs(2);
+    /// Upper bound on `ConnectionConfig::wait_for_ready_secs`.
+    const MAX_WAIT_FOR_READY_SECS: u64 = 300;
 
     pub fn new(registry: Arc<DriverRegistry>) -> Self {
         Self {
@@ -90,6 +171,18 @@ impl SessionManager {
         }
     }
 
+    fn ready_wait(config: &ConnectionConfig) -> Option<Duration> {
+        config
+            .wait_for_ready_secs
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::from_secs(secs.min(Self::MAX_WAIT_FOR_READY_SECS)))
+    }
+
+    /// Overall timeout for a connect/test, extended by the readiness wait.
+    fn timeout_with_wait(base_ms: u64, wait: Option<Duration>) -> u64 {
+        base_ms + wait.map_or(0, |w| w.as_millis() as u64)
+    }
+
     /// Tests a connection without persisting it
     #[instrument(
         skip(self, config),
@@ -108,6 +201,9 @@ impl SessionManager {
             .registry
             .get(&config.driver)
             .ok_or_else(|| EngineError::driver_not_found(&config.driver))?;
+        enforce_isolation_level_support(driver.as_ref(), config)?;
+        let ready_wait = Self::ready_wait(config);
+        let timeout_ms = Self::timeout_with_wait(Self::TEST_TIMEOUT_MS, ready_wait);
 
         let test_future = async {
             if let Some(ref proxy_config) = config.proxy {
@@ -123,12 +219,16 @@ impl SessionManager {
                             .await?;
                     tunneled_config.host = "127.0.0.1".to_string();
                     tunneled_config.port = tunnel.local_port();
-                    let result = driver.test_connection(&tunneled_config).await;
+                    let result =
+                        retry_until_ready(ready_wait, || driver.test_connection(&tunneled_config))
+                            .await;
                     let _ = proxy_tunnel.close().await;
                     return result;
                 }
 
-                let result = driver.test_connection(&tunneled_config).await;
+                let result =
+                    retry_until_ready(ready_wait, || driver.test_connection(&tunneled_config))
+                        .await;
                 let _ = proxy_tunnel.close().await;
                 return result;
             }
@@ -139,17 +239,16 @@ impl SessionManager {
                 tunneled_config.host = "127.0.0.1".to_string();
                 tunneled_config.port = tunnel.local_port();
                 // Tunnel drops at end of scope, which closes the forwarding.
-                return driver.test_connection(&tunneled_config).await;
+                return retry_until_ready(ready_wait, || driver.test_connection(&tunneled_config))
+                    .await;
             }
 
-            driver.test_connection(config).await
+            retry_until_ready(ready_wait, || driver.test_connection(config)).await
         };
 
-        match timeout(Duration::from_millis(Self::TEST_TIMEOUT_MS), test_future).await {
+        match timeout(Duration::from_millis(timeout_ms), test_future).await {
             Ok(result) => result,
-            Err(_) => Err(EngineError::Timeout {
-                timeout_ms: Self::TEST_TIMEOUT_MS,
-            }),
+            Err(_) => Err(EngineError::Timeout { timeout_ms }),
         }
     }
 
@@ -171,6 +270,9 @@ impl SessionManager {
             .registry
             .get(&config.driver)
             .ok_or_else(|| EngineError::driver_not_found(&config.driver))?;
+        enforce_isolation_level_support(driver.as_ref(), &config)?;
+        let ready_wait = Self::ready_wait(&config);
+        let timeout_ms = Self::timeout_with_wait(Self::CONNECT_TIMEOUT_MS, ready_wait);
 
         let connect_future = async {
             let (mut effective_config, proxy_tunnel) = if let Some(ref proxy_config) = config.proxy
@@ -197,19 +299,20 @@ impl SessionManager {
                 None
             };
 
-            let session_id = match driver.connect(&effective_config).await {
-                Ok(id) => id,
-                Err(e) => {
-                    // Close tunnels explicitly on failure so the port is released immediately.
-                    if let Some(mut tun) = tunnel {
-                        let _ = tun.close().await;
-                    }
-                    if let Some(mut pt) = proxy_tunnel {
-                        let _ = pt.close().await;
+            let session_id =
+                match retry_until_ready(ready_wait, || driver.connect(&effective_config)).await {
+                    Ok(id) => id,
+                    Err(e) => {
+                        // Close tunnels explicitly on failure so the port is released immediately.
+                        if let Some(mut tun) = tunnel {
+                            let _ = tun.close().await;
+                        }
+                        if let Some(mut pt) = proxy_tunnel {
+                            let _ = pt.close().await;
+                        }
+                        return Err(e);
                     }
-                    return Err(e);
-                }
-            };
+                };
 
             let suffix = match (proxy_tunnel.is_some(), tunnel.is_some()) {
                 (true, true) => " (Proxy+SSH)",
@@ -242,16 +345,9 @@ impl SessionManager {
             Ok(session_id)
         };
 
-        match timeout(
-            Duration::from_millis(Self::CONNECT_TIMEOUT_MS),
-            connect_future,
-        )
-        .await
-        {
+        match timeout(Duration::from_millis(timeout_ms), connect_future).await {
             Ok(result) => result,
-            Err(_) => Err(EngineError::Timeout {
-                timeout_ms: Self::CONNECT_TIMEOUT_MS,
-            }),
+            Err(_) => Err(EngineError::Timeout { timeout_ms }),
         }
     }
 
@@ -306,6 +402,15 @@ impl SessionManager {
             .collect()
     }
 
+    /// Returns the configuration a session was opened with.
+    pub async fn get_config(&self, session_id: SessionId) -> EngineResult<ConnectionConfig> {
+        let sessions = self.sessions.read().await;
+        sessions
+            .get(&session_id)
+            .map(|s| s.config.clone())
+            .ok_or_else(|| EngineError::session_not_found(session_id.0.to_string()))
+    }
+
     pub async fn get_session_info(&self, session_id: SessionId) -> Option<String> {
         let sessions = self.sessions.read().await;
         sessions.get(&session_id).map(|s| s.display_name.clone())
@@ -376,8 +481,9 @@ impl SessionManager {
         Ok(session.health)
     }
 
-    /// Pings a session to check connectivity (with timeout).
-    pub async fn ping(&self, session_id: SessionId) -> EngineResult<()> {
+    /// Pings a session to check connectivity (with timeout) and returns the
+    /// round-trip latency.
+    pub async fn ping(&self, session_id: SessionId) -> EngineResult<Duration> {
         let driver = self.get_driver(session_id).await?;
         match timeout(
             Duration::from_millis(Self::PING_TIMEOUT_MS),
@@ -585,7 +691,8 @@ impl SessionManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use qore_core::types::{SshAuth, SshTunnelConfig};
+    use qore_core::types::{IsolationLevel, SshAuth, SshTunnelConfig};
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     fn config_with(environment: &str, ssh: Option<SshTunnelConfig>) -> ConnectionConfig {
         ConnectionConfig {
@@ -608,6 +715,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         }
     }
 
@@ -652,4 +766,94 @@ mod tests {
         let config = config_with("production", None);
         assert!(enforce_ssh_host_key_policy(&config).is_ok());
     }
+
+    #[test]
+    fn validates_default_isolation_level_against_driver() {
+        let driver = crate::drivers::postgres::PostgresDriver::new();
+        let mut config = config_with("development", None);
+        assert!(enforce_isolation_level_support(&driver, &config).is_ok());
+
+        config.default_isolation_level = Some(IsolationLevel::Serializable);
+        assert!(enforce_isolation_level_support(&driver, &config).is_ok());
+
+        config.default_isolation_level = Some(IsolationLevel::Snapshot);
+        assert!(enforce_isolation_level_support(&driver, &config).is_err());
+    }
+
+    #[test]
+    fn classifies_not_ready_errors() {
+        assert!(is_not_ready_error(&EngineError::connection_failed(
+            "error communicating with database: Connection refused (os error 111)"
+        )));
+        assert!(is_not_ready_error(&EngineError::connection_failed(
+            "FATAL: the database system is starting up"
+        )));
+        assert!(!is_not_ready_error(&EngineError::auth_failed(
+            "password authentication failed for user \"u\""
+        )));
+        assert!(!is_not_ready_error(&EngineError::connection_failed(
+            "database \"app\" does not exist"
+        )));
+    }
+
+    #[tokio::test]
+    async fn retries_until_delayed_endpoint_is_ready() {
+        let attempts = AtomicU32::new(0);
+        let result = retry_until_ready(Some(Duration::from_secs(5)), || {
+            let n = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if n < 2 {
+                    Err(EngineError::connection_failed(
+                        "Connection refused (os error 111)",
+                    ))
+                } else {
+                    Ok(n)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn auth_failure_fails_fast_without_retry() {
+        let attempts = AtomicU32::new(0);
+        let started = Instant::now();
+        let result: EngineResult<()> = retry_until_ready(Some(Duration::from_secs(5)), || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(EngineError::auth_failed("password authentication failed")) }
+        })
+        .await;
+        assert!(matches!(
+            result,
+            Err(EngineError::AuthenticationFailed { .. })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(started.elapsed() < SessionManager::READY_INITIAL_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn gives_up_when_wait_elapses() {
+        let attempts = AtomicU32::new(0);
+        let result: EngineResult<()> = retry_until_ready(Some(Duration::from_millis(600)), || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(EngineError::connection_failed("Connection refused")) }
+        })
+        .await;
+        assert!(matches!(result, Err(EngineError::ConnectionFailed { .. })));
+        assert!(attempts.load(Ordering::SeqCst) > 1);
+    }
+
+    #[tokio::test]
+    async fn without_wait_fails_on_first_refusal() {
+        let attempts = AtomicU32::new(0);
+        let result: EngineResult<()> = retry_until_ready(None, || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(EngineError::connection_failed("Connection refused")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
 }
@@ -20,9 +20,9 @@ use serde::Deserialize;
 use tokio::sync::Mutex;
 
 use qore_core::{CollectionListOptions, Namespace, SessionId};
+use qore_service::paths::{config_dir, PROJECT_ID, QUERY_TIMEOUT_MS};
 use qore_service::vault::backend::KeyringProvider;
 use qore_service::vault::VaultStorage;
-use qore_service::paths::{config_dir, PROJECT_ID, QUERY_TIMEOUT_MS};
 use qore_service::ServiceContext;
 
 #[derive(Clone)]
@@ -118,9 +118,13 @@ impl QoreMcp {
             .map_err(|e| e.sanitized_message())?;
         config.read_only = true;
 
-        let session = qore_service::connection::connect(&self.ctx.session_manager, config)
-            .await
-            .map_err(|e| e.sanitized())?;
+        let session = qore_service::connection::connect(
+            &self.ctx.session_manager,
+            &self.ctx.query_manager,
+            config,
+        )
+        .await
+        .map_err(|e| e.sanitized())?;
         self.sessions
             .lock()
             .await
@@ -164,6 +168,7 @@ impl QoreMcp {
             false,
             None,
             None,
+            None,
             |_, _| {},
         )
         .await;
@@ -205,6 +210,7 @@ impl QoreMcp {
             search: req.search.clone(),
             page: None,
             page_size: None,
+            cursor: None,
         };
         let list = driver
             .list_collections(session, &namespace, options)
@@ -60,6 +60,7 @@ pub async fn invoke(
             Ok(
                 match qore_service::connection::disconnect(
                     &state.ctx.session_manager,
+                    &state.ctx.query_manager,
                     &state.ctx.query_rate_limiter,
                     session,
                 )
@@ -118,6 +119,7 @@ pub async fn invoke(
                     .get("page_size")
                     .and_then(Value::as_u64)
                     .map(|p| p as u32),
+                cursor: args.get("cursor").and_then(Value::as_str).map(String::from),
             };
             let driver = match state.ctx.session_manager.get_driver(session).await {
                 Ok(d) => d,
@@ -271,6 +273,7 @@ async fn execute_query(state: &AppState, args: &Value) -> Json<Value> {
         bypass_limits,
         None,
         None,
+        None,
         |_, _| {},
     )
     .await;
@@ -84,6 +84,7 @@ pub async fn execute_query(
             bypass_limits,
             None,
             Some(tx),
+            None,
             |_, _| {},
         )
         .await;
@@ -95,6 +96,9 @@ pub async fn execute_query(
             StreamEvent::Row(row) => ("row", json!(row)),
             StreamEvent::RowBatch(rows) => ("rows", json!(rows)),
             StreamEvent::Error(message) => ("error", json!(message)),
+            StreamEvent::Truncated { returned, cap } => {
+                ("truncated", json!({ "returned": returned, "cap": cap }))
+            }
             StreamEvent::Done(affected) => ("done", json!(affected)),
         };
         Ok::<_, std::convert::Infallible>(Event::default().event(name).data(data.to_string()))
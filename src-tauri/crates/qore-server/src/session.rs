@@ -37,7 +37,7 @@ pub async fn connect_saved(
     let config = saved
         .to_connection_config(&creds)
         .map_err(|e| e.sanitized_message())?;
-    qore_service::connection::connect(&state.ctx.session_manager, config)
+    qore_service::connection::connect(&state.ctx.session_manager, &state.ctx.query_manager, config)
         .await
         .map_err(|e| e.sanitized())
 }
@@ -32,11 +32,19 @@ async fn main() {
         clickhouse_cluster: None,
         search_auth_mode: None,
         ssl_ca_cert: None,
+        tls: None,
+        extra_params: None,
+        statement_timeout_ms: None,
+        lock_timeout_ms: None,
+        default_isolation_level: None,
+        wait_for_ready_secs: None,
+        query_timeout_secs: None,
     };
 
-    let session = qore_service::connection::connect(&ctx.session_manager, config)
-        .await
-        .expect("connect");
+    let session =
+        qore_service::connection::connect(&ctx.session_manager, &ctx.query_manager, config)
+            .await
+            .expect("connect");
     println!("connected (no Tauri): {session:?}");
 
     for q in [
@@ -54,6 +62,7 @@ async fn main() {
 
     let _ = qore_service::connection::disconnect(
         &ctx.session_manager,
+        &ctx.query_manager,
         &ctx.query_rate_limiter,
         session,
     )
@@ -93,6 +102,9 @@ async fn run(
                     StreamEvent::Columns(c) => println!("  [stream] columns: {}", c.len()),
                     StreamEvent::Row(_) => println!("  [stream] row"),
                     StreamEvent::RowBatch(b) => println!("  [stream] batch: {} rows", b.len()),
+                    StreamEvent::Truncated { returned, cap } => {
+                        println!("  [stream] truncated: {returned} rows (cap {cap})")
+                    }
                     StreamEvent::Done(n) => println!("  [stream] done: {n} rows"),
                     StreamEvent::Error(e) => println!("  [stream] error: {e}"),
                 }
@@ -121,6 +133,7 @@ async fn run(
         false,
         None,
         stream_sender,
+        None,
         |_, _| {},
     )
     .await;
@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bulk CSV Import
+//!
+//! Loads a CSV file into an existing table. Drivers with a server bulk path
+//! (`supports_copy_import`) receive the file as a byte stream; the others get
+//! batched multi-row `INSERT`s inside a single transaction, so a bad row
+//! leaves the table as it was.
+
+use std::path::Path;
+
+use qore_core::{
+    BulkImportOptions, DataEngine, EngineError, EngineResult, Namespace, QueryId, SessionId, Value,
+};
+use qore_sql::generator::SqlDialect;
+
+/// Imports the CSV at `path` into `table` and returns the number of rows
+/// loaded. With `options.header` the first line names the target columns;
+/// otherwise the file must follow the table's column order.
+pub async fn import_csv(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    path: &Path,
+    options: &BulkImportOptions,
+) -> EngineResult<u64> {
+    let delimiter = delimiter_byte(&options.delimiter)?;
+    if options.batch_size == 0 {
+        return Err(EngineError::validation("Batch size must be at least 1"));
+    }
+
+    let columns = if options.header {
+        header_columns(path, delimiter)?
+    } else {
+        let schema = driver.describe_table(session, namespace, table).await?;
+        if schema.columns.is_empty() {
+            return Err(EngineError::execution_error("Table not found"));
+        }
+        schema.columns.into_iter().map(|c| c.name).collect()
+    };
+
+    if driver.supports_copy_import() {
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| EngineError::validation(format!("Failed to open CSV file: {e}")))?;
+        return driver
+            .copy_in_csv(session, namespace, table, &columns, Box::new(file), options)
+            .await;
+    }
+
+    insert_batches(
+        driver, session, namespace, table, path, &columns, delimiter, options,
+    )
+    .await
+}
+
+fn delimiter_byte(delimiter: &str) -> EngineResult<u8> {
+    match delimiter.as_bytes() {
+        [b] if b.is_ascii() && !matches!(b, b'"' | b'\r' | b'\n') => Ok(*b),
+        _ => Err(EngineError::validation(
+            "CSV delimiter must be a single ASCII character",
+        )),
+    }
+}
+
+fn reader(path: &Path, delimiter: u8, header: bool) -> EngineResult<csv::Reader<std::fs::File>> {
+    csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(header)
+        .flexible(true)
+        .from_path(path)
+        .map_err(|e| EngineError::validation(format!("Failed to open CSV file: {e}")))
+}
+
+fn header_columns(path: &Path, delimiter: u8) -> EngineResult<Vec<String>> {
+    let mut reader = reader(path, delimiter, true)?;
+    let headers = reader
+        .headers()
+        .map_err(|e| EngineError::validation(format!("Invalid CSV header: {e}")))?;
+    let columns: Vec<String> = headers.iter().map(|h| h.trim().to_string()).collect();
+    if columns.iter().all(|c| c.is_empty()) {
+        return Err(EngineError::validation("CSV header is empty"));
+    }
+    Ok(columns)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_batches(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    path: &Path,
+    columns: &[String],
+    delimiter: u8,
+    options: &BulkImportOptions,
+) -> EngineResult<u64> {
+    let dialect = SqlDialect::from_driver_id(driver.driver_id())
+        .ok_or_else(|| EngineError::not_supported("Bulk import is not supported by this driver"))?;
+    let prefix = format!(
+        "INSERT INTO {} ({}) VALUES ",
+        dialect.qualified_table(namespace, table),
+        columns
+            .iter()
+            .map(|c| dialect.quote_ident(c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut reader = reader(path, delimiter, options.header)?;
+
+    driver.begin_transaction(session).await?;
+    let mut imported = 0u64;
+    let mut batch: Vec<String> = Vec::with_capacity(options.batch_size);
+    let result: EngineResult<()> = async {
+        for record in reader.records() {
+            let record =
+                record.map_err(|e| EngineError::validation(format!("Invalid CSV row: {e}")))?;
+            if record.len() != columns.len() {
+                let line = record.position().map(|p| p.line()).unwrap_or_default();
+                return Err(EngineError::validation(format!(
+                    "Row {} has {} fields, expected {}",
+                    line,
+                    record.len(),
+                    columns.len()
+                )));
+            }
+
+            let values: Vec<String> = record
+                .iter()
+                .map(|field| dialect.format_value(&field_value(field, options)))
+                .collect();
+            batch.push(format!("({})", values.join(", ")));
+
+            if batch.len() >= options.batch_size {
+                imported += flush(driver, session, &prefix, &mut batch).await?;
+            }
+        }
+        imported += flush(driver, session, &prefix, &mut batch).await?;
+        Ok(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            driver.commit(session).await?;
+            Ok(imported)
+        }
+        Err(e) => {
+            let _ = driver.rollback(session).await;
+            Err(e)
+        }
+    }
+}
+
+fn field_value(field: &str, options: &BulkImportOptions) -> Value {
+    let is_null = match &options.null_string {
+        Some(null_string) => field == null_string,
+        None => field.is_empty(),
+    };
+    if is_null {
+        Value::Null
+    } else {
+        Value::Text(field.to_string())
+    }
+}
+
+async fn flush(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    prefix: &str,
+    batch: &mut Vec<String>,
+) -> EngineResult<u64> {
+    if batch.is_empty() {
+        return Ok(0);
+    }
+    let sql = format!("{}{}", prefix, batch.join(", "));
+    let rows = batch.len() as u64;
+    batch.clear();
+    driver.execute(session, &sql, QueryId::new()).await?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::ConnectionConfig;
+    use qore_drivers::drivers::sqlite::SqliteDriver;
+    use tempfile::TempDir;
+
+    async fn sqlite_with_table() -> (SqliteDriver, SessionId) {
+        let driver = SqliteDriver::new();
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        driver
+            .execute(
+                session,
+                "CREATE TABLE people (id INTEGER PRIMARY KEY, name TEXT)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        (driver, session)
+    }
+
+    async fn count(driver: &SqliteDriver, session: SessionId) -> i64 {
+        let result = driver
+            .execute(session, "SELECT count(*) FROM people", QueryId::new())
+            .await
+            .unwrap();
+        match result.rows[0].values[0] {
+            Value::Int(n) => n,
+            ref other => panic!("unexpected count {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn inserts_rows_in_batches() {
+        let (driver, session) = sqlite_with_table().await;
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("people.csv");
+        std::fs::write(&path, "id,name\n1,alice\n2,\"bob, jr\"\n3,\n").unwrap();
+
+        let options = BulkImportOptions {
+            batch_size: 2,
+            ..BulkImportOptions::default()
+        };
+        let imported = import_csv(
+            &driver,
+            session,
+            &Namespace::new("main"),
+            "people",
+            &path,
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(imported, 3);
+        assert_eq!(count(&driver, session).await, 3);
+
+        let result = driver
+            .execute(
+                session,
+                "SELECT name FROM people WHERE id = 3",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(result.rows[0].values[0], Value::Null));
+    }
+
+    #[tokio::test]
+    async fn column_count_mismatch_rolls_back() {
+        let (driver, session) = sqlite_with_table().await;
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("people.csv");
+        std::fs::write(&path, "1,alice\n2,bob,extra\n").unwrap();
+
+        let options = BulkImportOptions {
+            header: false,
+            batch_size: 1,
+            ..BulkImportOptions::default()
+        };
+        let err = import_csv(
+            &driver,
+            session,
+            &Namespace::new("main"),
+            "people",
+            &path,
+            &options,
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("Row 2 has 3 fields, expected 2"),
+            "{err}"
+        );
+        assert_eq!(count(&driver, session).await, 0);
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use qore_core::{ConnectionConfig, MssqlAuthMode, SessionId, SshAuth};
+use qore_drivers::query_manager::QueryManager;
 use qore_drivers::session_manager::SessionManager;
 
 use crate::error::ServiceError;
@@ -15,20 +16,29 @@ pub async fn test_connection(
     Ok(())
 }
 
+/// Opens a session and applies the driver's default query concurrency limit
+/// (pool size, or 1 for single-connection drivers).
 pub async fn connect(
     session_manager: &SessionManager,
+    query_manager: &QueryManager,
     config: ConnectionConfig,
 ) -> Result<SessionId, ServiceError> {
     let config = normalize_config(config).map_err(ServiceError::Message)?;
-    Ok(session_manager.connect(config).await?)
+    let session = session_manager.connect(config).await?;
+    let driver = session_manager.get_driver(session).await?;
+    let config = session_manager.get_config(session).await?;
+    query_manager.set_session_limit(session, driver.default_query_concurrency(&config));
+    Ok(session)
 }
 
 pub async fn disconnect(
     session_manager: &SessionManager,
+    query_manager: &QueryManager,
     query_rate_limiter: &QueryRateLimiter,
     session: SessionId,
 ) -> Result<(), ServiceError> {
     session_manager.disconnect(session).await?;
+    query_manager.clear_session_limit(session);
     query_rate_limiter.forget(&session.0.to_string());
     Ok(())
 }
@@ -14,9 +14,11 @@ use qore_drivers::query_manager::QueryManager;
 use qore_drivers::session_manager::SessionManager;
 
 use crate::cache::QueryCache;
+use crate::display_transforms::DisplayTransformStore;
 use crate::interceptor::InterceptorPipeline;
 use crate::license::LicenseManager;
 use crate::policy::SafetyPolicy;
+use crate::query_history::QueryHistoryStore;
 use crate::ratelimit::QueryRateLimiter;
 use crate::vault::backend::default_provider;
 use crate::vault::VaultLock;
@@ -28,9 +30,11 @@ pub struct ServiceContext {
     pub query_manager: Arc<QueryManager>,
     pub query_rate_limiter: Arc<QueryRateLimiter>,
     pub query_cache: Arc<QueryCache>,
+    pub query_history: Arc<QueryHistoryStore>,
     pub policy: SafetyPolicy,
     pub interceptor: Arc<InterceptorPipeline>,
     pub virtual_relations: Arc<VirtualRelationStore>,
+    pub display_transforms: Arc<DisplayTransformStore>,
     pub vault_lock: VaultLock,
     pub license_manager: LicenseManager,
 }
@@ -66,6 +70,10 @@ impl ServiceContext {
         let virtual_relations = Arc::new(VirtualRelationStore::new(
             data_dir.join("virtual_relations"),
         ));
+        let display_transforms = Arc::new(DisplayTransformStore::new(
+            data_dir.join("display_transforms"),
+        ));
+        let query_history = Arc::new(QueryHistoryStore::new(data_dir.join("query_history")));
 
         let _ = vault_lock.auto_unlock_if_no_password();
         let license_manager = LicenseManager::new(default_provider());
@@ -76,9 +84,11 @@ impl ServiceContext {
             query_manager,
             query_rate_limiter: Arc::new(QueryRateLimiter::with_defaults()),
             query_cache: Arc::new(QueryCache::new()),
+            query_history,
             policy,
             interceptor,
             virtual_relations,
+            display_transforms,
             vault_lock,
             license_manager,
         }
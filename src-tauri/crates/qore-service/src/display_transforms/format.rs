@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use qore_core::types::{QueryResult, Value};
+
+use super::types::{DisplayTransform, DisplayTransformKind, FormattedColumn};
+
+const BYTE_UNITS: [&str; 6] = ["KB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Formats one value, or `None` when the transform doesn't apply to it.
+pub fn format_value(kind: &DisplayTransformKind, value: &Value) -> Option<String> {
+    match kind {
+        DisplayTransformKind::EpochSeconds => {
+            let date = DateTime::<Utc>::from_timestamp(as_integer(value)?, 0)?;
+            Some(date.to_rfc3339_opts(SecondsFormat::Secs, true))
+        }
+        DisplayTransformKind::EpochMillis => {
+            let date = DateTime::<Utc>::from_timestamp_millis(as_integer(value)?)?;
+            Some(date.to_rfc3339_opts(SecondsFormat::Millis, true))
+        }
+        DisplayTransformKind::HumanBytes => {
+            let bytes = as_integer(value)?;
+            if bytes < 0 {
+                return None;
+            }
+            if bytes < 1024 {
+                return Some(format!("{} B", bytes));
+            }
+            let mut size = bytes as f64 / 1024.0;
+            let mut unit = 0;
+            while size >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+                size /= 1024.0;
+                unit += 1;
+            }
+            Some(format!("{:.1} {}", size, BYTE_UNITS[unit]))
+        }
+        DisplayTransformKind::EnumLabels { labels } => {
+            labels.get(&as_integer(value)?.to_string()).cloned()
+        }
+    }
+}
+
+/// Integer view of a numeric cell; text holding an integer counts too, since
+/// some drivers return BIGINT / DECIMAL as strings.
+fn as_integer(value: &Value) -> Option<i64> {
+    match value {
+        Value::Int(i) => Some(*i),
+        Value::Float(f) if f.is_finite() && f.fract() == 0.0 => Some(*f as i64),
+        Value::Text(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Formatted representations for the transformed columns of `result`. The
+/// result itself is left untouched.
+pub fn format_result(
+    transforms: &[DisplayTransform],
+    result: &QueryResult,
+) -> Vec<FormattedColumn> {
+    transforms
+        .iter()
+        .filter_map(|transform| {
+            let index = result
+                .columns
+                .iter()
+                .position(|c| c.name.as_str() == transform.column)?;
+            let values = result
+                .rows
+                .iter()
+                .map(|row| {
+                    row.values
+                        .get(index)
+                        .and_then(|value| format_value(&transform.transform, value))
+                })
+                .collect();
+            Some(FormattedColumn {
+                column: transform.column.clone(),
+                values,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::types::{ColumnInfo, Row};
+    use std::collections::HashMap;
+
+    fn transform(column: &str, kind: DisplayTransformKind) -> DisplayTransform {
+        DisplayTransform {
+            database: "app".to_string(),
+            schema: None,
+            table: "files".to_string(),
+            column: column.to_string(),
+            transform: kind,
+        }
+    }
+
+    #[test]
+    fn formats_epoch_seconds_and_millis() {
+        let seconds = DisplayTransformKind::EpochSeconds;
+        assert_eq!(
+            format_value(&seconds, &Value::Int(1_700_000_000)).as_deref(),
+            Some("2023-11-14T22:13:20Z")
+        );
+        assert_eq!(
+            format_value(&seconds, &Value::Text("0".into())).as_deref(),
+            Some("1970-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            format_value(
+                &DisplayTransformKind::EpochMillis,
+                &Value::Int(1_700_000_000_123)
+            )
+            .as_deref(),
+            Some("2023-11-14T22:13:20.123Z")
+        );
+        assert_eq!(format_value(&seconds, &Value::Null), None);
+        assert_eq!(format_value(&seconds, &Value::Text("soon".into())), None);
+    }
+
+    #[test]
+    fn formats_human_bytes() {
+        let bytes = DisplayTransformKind::HumanBytes;
+        let cases = [
+            (512, "512 B"),
+            (1024, "1.0 KB"),
+            (1536, "1.5 KB"),
+            (5 * 1024 * 1024, "5.0 MB"),
+            (3 * 1024_i64.pow(3) / 2, "1.5 GB"),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(
+                format_value(&bytes, &Value::Int(raw)).as_deref(),
+                Some(expected)
+            );
+        }
+        assert_eq!(format_value(&bytes, &Value::Int(-1)), None);
+    }
+
+    #[test]
+    fn maps_enum_labels() {
+        let kind = DisplayTransformKind::EnumLabels {
+            labels: HashMap::from([
+                ("0".to_string(), "pending".to_string()),
+                ("1".to_string(), "active".to_string()),
+            ]),
+        };
+        assert_eq!(
+            format_value(&kind, &Value::Int(1)).as_deref(),
+            Some("active")
+        );
+        assert_eq!(format_value(&kind, &Value::Int(7)), None);
+    }
+
+    #[test]
+    fn formats_result_columns_and_keeps_raw_values() {
+        let column = |name: &str| ColumnInfo {
+            name: name.into(),
+            data_type: "INTEGER".into(),
+            nullable: true,
+            case_insensitive: false,
+        };
+        let result = QueryResult {
+            columns: vec![column("name"), column("created_at"), column("size")],
+            rows: vec![
+                Row {
+                    values: vec![Value::Text("a.txt".into()), Value::Int(0), Value::Int(2048)],
+                },
+                Row {
+                    values: vec![Value::Text("b.txt".into()), Value::Null, Value::Int(10)],
+                },
+            ],
+            affected_rows: None,
+            execution_time_ms: 0.0,
+        };
+        let transforms = [
+            transform("created_at", DisplayTransformKind::EpochSeconds),
+            transform("size", DisplayTransformKind::HumanBytes),
+            transform("missing", DisplayTransformKind::HumanBytes),
+        ];
+
+        let formatted = format_result(&transforms, &result);
+        assert_eq!(
+            formatted,
+            vec![
+                FormattedColumn {
+                    column: "created_at".to_string(),
+                    values: vec![Some("1970-01-01T00:00:00Z".to_string()), None],
+                },
+                FormattedColumn {
+                    column: "size".to_string(),
+                    values: vec![Some("2.0 KB".to_string()), Some("10 B".to_string())],
+                },
+            ]
+        );
+        // Raw values stay as stored, for editing and filtering.
+        assert!(matches!(result.rows[0].values[1], Value::Int(0)));
+        assert!(matches!(result.rows[0].values[2], Value::Int(2048)));
+    }
+}
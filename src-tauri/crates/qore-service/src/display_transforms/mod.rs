@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod format;
+pub mod store;
+pub mod types;
+
+pub use format::{format_result, format_value};
+pub use store::DisplayTransformStore;
+pub use types::*;
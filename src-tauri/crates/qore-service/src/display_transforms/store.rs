@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use super::types::{DisplayTransform, DisplayTransformsConfig};
+
+/// In-memory store for column display transforms, with JSON persistence per
+/// connection.
+pub struct DisplayTransformStore {
+    data_dir: PathBuf,
+    cache: RwLock<HashMap<String, DisplayTransformsConfig>>,
+}
+
+impl DisplayTransformStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        let _ = std::fs::create_dir_all(&data_dir);
+        Self {
+            data_dir,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn file_path(&self, connection_id: &str) -> PathBuf {
+        self.data_dir.join(format!("{}.json", connection_id))
+    }
+
+    fn ensure_loaded(&self, connection_id: &str) -> DisplayTransformsConfig {
+        {
+            let cache = self.cache.read().unwrap();
+            if let Some(config) = cache.get(connection_id) {
+                return config.clone();
+            }
+        }
+        let path = self.file_path(connection_id);
+        let config = if path.exists() {
+            let content = std::fs::read_to_string(&path).unwrap_or_default();
+            serde_json::from_str(&content).unwrap_or_default()
+        } else {
+            DisplayTransformsConfig::default()
+        };
+        let mut cache = self.cache.write().unwrap();
+        cache.insert(connection_id.to_string(), config.clone());
+        config
+    }
+
+    fn save(&self, connection_id: &str, config: &DisplayTransformsConfig) -> Result<(), String> {
+        let path = self.file_path(connection_id);
+        let content = serde_json::to_string_pretty(config)
+            .map_err(|e| format!("Failed to serialize display transforms: {}", e))?;
+        std::fs::write(&path, content)
+            .map_err(|e| format!("Failed to write display transforms: {}", e))?;
+        let mut cache = self.cache.write().unwrap();
+        cache.insert(connection_id.to_string(), config.clone());
+        Ok(())
+    }
+
+    pub fn list(&self, connection_id: &str) -> Vec<DisplayTransform> {
+        self.ensure_loaded(connection_id).transforms
+    }
+
+    pub fn for_table(
+        &self,
+        connection_id: &str,
+        database: &str,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<DisplayTransform> {
+        self.ensure_loaded(connection_id)
+            .transforms
+            .into_iter()
+            .filter(|t| t.targets(database, schema, table))
+            .collect()
+    }
+
+    /// Adds the transform, replacing any existing one on the same column.
+    pub fn set(&self, connection_id: &str, transform: DisplayTransform) -> Result<(), String> {
+        let mut config = self.ensure_loaded(connection_id);
+        config.transforms.retain(|t| {
+            !(t.targets(
+                &transform.database,
+                transform.schema.as_deref(),
+                &transform.table,
+            ) && t.column == transform.column)
+        });
+        config.transforms.push(transform);
+        self.save(connection_id, &config)
+    }
+
+    pub fn remove(
+        &self,
+        connection_id: &str,
+        database: &str,
+        schema: Option<&str>,
+        table: &str,
+        column: &str,
+    ) -> Result<(), String> {
+        let mut config = self.ensure_loaded(connection_id);
+        let original_len = config.transforms.len();
+        config
+            .transforms
+            .retain(|t| !(t.targets(database, schema, table) && t.column == column));
+        if config.transforms.len() == original_len {
+            return Err("Display transform not found".to_string());
+        }
+        self.save(connection_id, &config)
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// How a column's values are rendered for display. Display-only: the raw
+/// `Value` used for editing and filtering is never replaced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DisplayTransformKind {
+    /// Unix timestamp in seconds → ISO 8601 (UTC).
+    EpochSeconds,
+    /// Unix timestamp in milliseconds → ISO 8601 (UTC).
+    EpochMillis,
+    /// Byte count → human-readable size (`1.5 KB`, `2.0 MB`, 1024-based).
+    HumanBytes,
+    /// Integer code → label, keyed by the integer's decimal form.
+    EnumLabels { labels: HashMap<String, String> },
+}
+
+/// A display transform bound to one column, stored locally per connection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayTransform {
+    pub database: String,
+    pub schema: Option<String>,
+    pub table: String,
+    pub column: String,
+    pub transform: DisplayTransformKind,
+}
+
+impl DisplayTransform {
+    pub fn targets(&self, database: &str, schema: Option<&str>, table: &str) -> bool {
+        self.database == database && self.schema.as_deref() == schema && self.table == table
+    }
+}
+
+/// Formatted values of one column, aligned with the result rows. `None`
+/// where the transform doesn't apply (NULL, wrong type, unmapped code).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormattedColumn {
+    pub column: String,
+    pub values: Vec<Option<String>>,
+}
+
+/// On-disk format for display transforms, one file per connection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayTransformsConfig {
+    pub version: u32,
+    pub transforms: Vec<DisplayTransform>,
+}
+
+impl Default for DisplayTransformsConfig {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            transforms: Vec::new(),
+        }
+    }
+}
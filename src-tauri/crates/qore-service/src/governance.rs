@@ -75,6 +75,8 @@ mod tests {
             max_result_rows: max_rows,
             max_concurrent_queries: None,
             query_rate_limit_enabled: true,
+            attach_allowed_dir: None,
+            environment_guards: Default::default(),
         }
     }
 
@@ -391,13 +391,24 @@ impl AuditStore {
         format: super::export::AuditExportFormat,
         from_disk: bool,
     ) -> std::io::Result<String> {
-        let entries = if from_disk {
-            self.get_entries_from_disk(0, 0, None, None, None, None, None, None, None, None)?
-        } else {
-            self.entries.read().iter().cloned().collect()
-        };
+        let entries = self.entries_for_export(from_disk)?;
         Ok(super::export::export_entries(&entries, format))
     }
+
+    /// Export audit log entries as a signed, HMAC-chained JSON document (see
+    /// `signed_export`). `from_disk` behaves as in `export_format`.
+    pub fn export_signed(&self, from_disk: bool, key: &[u8]) -> std::io::Result<String> {
+        let entries = self.entries_for_export(from_disk)?;
+        Ok(super::signed_export::to_signed_json(&entries, key)?)
+    }
+
+    fn entries_for_export(&self, from_disk: bool) -> std::io::Result<Vec<AuditLogEntry>> {
+        if from_disk {
+            self.get_entries_from_disk(0, 0, None, None, None, None, None, None, None, None)
+        } else {
+            Ok(self.entries.read().iter().cloned().collect())
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
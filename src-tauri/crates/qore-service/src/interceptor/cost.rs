@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Query Cost Check
+//!
+//! Opt-in pre-execution stage: before a SELECT runs, the driver is asked for
+//! its estimated plan (`EXPLAIN`, never `EXPLAIN ANALYZE`) and a warning is
+//! raised when the planner expects to read more rows than the configured
+//! limit. Drivers without explain support and plans without row estimates
+//! pass silently.
+
+use qore_core::traits::DataEngine;
+use qore_core::types::{ExplainNode, ExplainPlan, Namespace, SessionId};
+use tracing::debug;
+
+use super::types::{QueryContext, QueryOperationType, SafetyCheckResult};
+
+/// Rule id reported when the cost check warns
+pub const COST_CHECK_RULE_ID: &str = "builtin-warn-estimated-rows";
+
+/// Runs `EXPLAIN` for `context.query` and compares the largest row estimate
+/// of the plan with `row_limit`. Only SELECT statements are checked.
+pub async fn check_cost(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    context: &QueryContext,
+    row_limit: u64,
+) -> SafetyCheckResult {
+    if context.operation_type != QueryOperationType::Select || !driver.supports_explain() {
+        return SafetyCheckResult::allowed();
+    }
+
+    match driver
+        .explain_query(session, namespace, &context.query, false)
+        .await
+    {
+        Ok(plan) => evaluate_plan(&plan, row_limit),
+        Err(e) => {
+            debug!("Cost check skipped, EXPLAIN failed: {}", e);
+            SafetyCheckResult::allowed()
+        }
+    }
+}
+
+/// Warns when any operator of `plan` is estimated to produce more than
+/// `row_limit` rows.
+pub fn evaluate_plan(plan: &ExplainPlan, row_limit: u64) -> SafetyCheckResult {
+    match plan.root.as_ref().and_then(max_estimated_rows) {
+        Some(rows) if rows > row_limit as f64 => SafetyCheckResult::warning(
+            COST_CHECK_RULE_ID.to_string(),
+            format!(
+                "The planner estimates {} rows, above the limit of {}",
+                rows.round() as u64,
+                row_limit
+            ),
+        ),
+        _ => SafetyCheckResult::allowed(),
+    }
+}
+
+fn max_estimated_rows(node: &ExplainNode) -> Option<f64> {
+    node.children
+        .iter()
+        .filter_map(max_estimated_rows)
+        .chain(node.estimated_rows)
+        .reduce(f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::types::ExplainFormat;
+
+    fn plan(scan_rows: f64) -> ExplainPlan {
+        let mut scan = ExplainNode::new("Seq Scan");
+        scan.relation = Some("orders".to_string());
+        scan.estimated_rows = Some(scan_rows);
+        let mut limit = ExplainNode::new("Limit");
+        limit.estimated_rows = Some(100.0);
+        limit.children.push(scan);
+        ExplainPlan {
+            raw: String::new(),
+            format: ExplainFormat::Json,
+            root: Some(limit),
+            analyzed: false,
+        }
+    }
+
+    #[test]
+    fn warns_when_plan_exceeds_row_limit() {
+        let result = evaluate_plan(&plan(5_000_000.0), 1_000_000);
+        assert!(result.allowed);
+        assert_eq!(result.triggered_rule.as_deref(), Some(COST_CHECK_RULE_ID));
+        assert!(result.message.unwrap().contains("5000000"));
+    }
+
+    #[test]
+    fn silent_below_row_limit() {
+        let result = evaluate_plan(&plan(20_000.0), 1_000_000);
+        assert!(result.allowed);
+        assert!(result.triggered_rule.is_none());
+    }
+
+    #[test]
+    fn silent_without_estimates() {
+        let mut plan = plan(0.0);
+        plan.root = None;
+        assert!(evaluate_plan(&plan, 10).triggered_rule.is_none());
+    }
+}
@@ -83,6 +83,8 @@ fn to_csv(entries: &[AuditLogEntry]) -> String {
         "error",
         "execution_time_ms",
         "row_count",
+        "affected_rows",
+        "touched_columns",
         "safety_rule",
     ];
     if writer.write_record(header).is_err() {
@@ -105,6 +107,15 @@ fn to_csv(entries: &[AuditLogEntry]) -> String {
             entry.error.clone().unwrap_or_default(),
             format!("{:.3}", entry.execution_time_ms),
             entry.row_count.map(|n| n.to_string()).unwrap_or_default(),
+            entry
+                .affected_rows
+                .map(|n| n.to_string())
+                .unwrap_or_default(),
+            entry
+                .touched_columns
+                .as_ref()
+                .map(|c| c.join(";"))
+                .unwrap_or_default(),
             entry.safety_rule.clone().unwrap_or_default(),
         ];
 
@@ -140,6 +151,8 @@ mod tests {
             error: None,
             execution_time_ms: 1.234,
             row_count: Some(1),
+            affected_rows: None,
+            touched_columns: None,
             blocked: false,
             safety_rule: None,
             driver_id: "postgres".to_string(),
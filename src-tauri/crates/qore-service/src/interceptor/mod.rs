@@ -11,12 +11,14 @@
 //! The frontend only displays and configures what the backend provides.
 
 pub mod audit;
+pub mod cost;
 pub mod export;
 pub mod fingerprint;
 pub mod pipeline;
 pub mod profiling;
 pub mod redaction;
 pub mod safety;
+pub mod signed_export;
 pub mod types;
 
 pub use audit::{AuditStats, AuditStore};
@@ -25,4 +27,5 @@ pub use fingerprint::fingerprint_query;
 pub use pipeline::InterceptorPipeline;
 pub use profiling::ProfilingStore;
 pub use safety::SafetyEngine;
+pub use signed_export::{signing_key, verify_audit_export};
 pub use types::*;
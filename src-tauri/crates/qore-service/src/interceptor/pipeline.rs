@@ -14,12 +14,14 @@ use tracing::{debug, info};
 
 use super::audit::{AuditStats, AuditStore};
 use super::profiling::ProfilingStore;
-use super::safety::SafetyEngine;
+use super::safety::{classify_operation, classify_sql_operation, SafetyEngine};
 use super::types::{
     AuditLogEntry, BuiltinRuleOverride, Environment, InterceptorConfig, ProfilingMetrics,
     QueryContext, QueryExecutionResult, QueryOperationType, SafetyCheckResult, SafetyRule,
-    SlowQueryEntry,
+    SafetySimulation, SlowQueryEntry, TableProfile,
 };
+use qore_core::traits::DataEngine;
+use qore_core::types::{Namespace, SessionId};
 use qore_sql::safety::SqlSafetyAnalysis;
 
 pub struct InterceptorPipeline {
@@ -120,6 +122,25 @@ impl InterceptorPipeline {
         self.safety.check(context)
     }
 
+    /// Pre-execution cost check: when enabled, warns if the estimated plan of
+    /// a SELECT reads more rows than the configured limit
+    pub async fn check_cost(
+        &self,
+        driver: &dyn DataEngine,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        context: &QueryContext,
+    ) -> SafetyCheckResult {
+        let (enabled, row_limit) = {
+            let config = self.config.read();
+            (config.cost_check_enabled, config.cost_check_row_limit)
+        };
+        if !enabled {
+            return SafetyCheckResult::allowed();
+        }
+        super::cost::check_cost(driver, session, namespace, context, row_limit).await
+    }
+
     /// Build query context from execution parameters
     pub fn build_context(
         &self,
@@ -134,11 +155,11 @@ impl InterceptorPipeline {
         is_mongo_mutation: bool,
     ) -> QueryContext {
         let (operation_type, is_mutation, is_dangerous) = if let Some(analysis) = sql_analysis {
-            let op = self.classify_sql_operation(query);
+            let op = classify_sql_operation(query);
             (op, analysis.is_mutation, analysis.is_dangerous)
         } else {
             // MongoDB or unknown driver: SQL analysis is unavailable.
-            let op = self.classify_operation(query, driver_id);
+            let op = classify_operation(query, driver_id);
             (op, is_mongo_mutation, false)
         };
 
@@ -153,50 +174,7 @@ impl InterceptorPipeline {
             is_dangerous,
             acknowledged,
             read_only,
-        }
-    }
-
-    fn classify_sql_operation(&self, query: &str) -> QueryOperationType {
-        let query_upper = query.trim().to_uppercase();
-        let first_word = query_upper.split_whitespace().next().unwrap_or("");
-
-        match first_word {
-            "SELECT" => QueryOperationType::Select,
-            "INSERT" => QueryOperationType::Insert,
-            "UPDATE" => QueryOperationType::Update,
-            "DELETE" => QueryOperationType::Delete,
-            "CREATE" => QueryOperationType::Create,
-            "ALTER" => QueryOperationType::Alter,
-            "DROP" => QueryOperationType::Drop,
-            "TRUNCATE" => QueryOperationType::Truncate,
-            "GRANT" => QueryOperationType::Grant,
-            "REVOKE" => QueryOperationType::Revoke,
-            "EXEC" | "EXECUTE" | "CALL" => QueryOperationType::Execute,
-            _ => QueryOperationType::Other,
-        }
-    }
-
-    /// Classify operation for non-SQL (MongoDB) queries
-    fn classify_operation(&self, query: &str, driver_id: &str) -> QueryOperationType {
-        if driver_id.eq_ignore_ascii_case("mongodb") {
-            let query_lower = query.to_lowercase();
-            if query_lower.contains("find") || query_lower.contains("aggregate") {
-                QueryOperationType::Select
-            } else if query_lower.contains("insert") {
-                QueryOperationType::Insert
-            } else if query_lower.contains("update") {
-                QueryOperationType::Update
-            } else if query_lower.contains("delete") || query_lower.contains("remove") {
-                QueryOperationType::Delete
-            } else if query_lower.contains("drop") {
-                QueryOperationType::Drop
-            } else if query_lower.contains("create") {
-                QueryOperationType::Create
-            } else {
-                QueryOperationType::Other
-            }
-        } else {
-            self.classify_sql_operation(query)
+            touched_columns: None,
         }
     }
 
@@ -232,6 +210,8 @@ impl InterceptorPipeline {
         entry.error = result.error.clone();
         entry.execution_time_ms = result.execution_time_ms;
         entry.row_count = result.row_count;
+        entry.affected_rows = result.affected_rows;
+        entry.touched_columns = context.touched_columns.clone();
         entry.blocked = blocked;
         entry.safety_rule = safety_rule.map(|s| s.to_string());
 
@@ -290,12 +270,33 @@ impl InterceptorPipeline {
             .map_err(|e| format!("Failed to read audit log: {}", e))
     }
 
-    pub fn get_profiling_metrics(&self) -> ProfilingMetrics {
-        self.profiling.get_metrics()
+    /// Export audit log as a signed, tamper-evident JSON document keyed with
+    /// `key`.
+    pub fn export_audit_signed(&self, from_disk: bool, key: &[u8]) -> Result<String, String> {
+        self.audit
+            .export_signed(from_disk, key)
+            .map_err(|e| format!("Failed to read audit log: {}", e))
+    }
+
+    pub fn get_profiling_metrics(&self, namespace: Option<&str>) -> ProfilingMetrics {
+        self.profiling.get_metrics(namespace)
+    }
+
+    pub fn get_slow_queries(
+        &self,
+        limit: usize,
+        offset: usize,
+        namespace: Option<&str>,
+    ) -> Vec<SlowQueryEntry> {
+        self.profiling.get_slow_queries(limit, offset, namespace)
     }
 
-    pub fn get_slow_queries(&self, limit: usize, offset: usize) -> Vec<SlowQueryEntry> {
-        self.profiling.get_slow_queries(limit, offset)
+    pub fn get_profiling_by_table(
+        &self,
+        namespace: Option<&str>,
+        limit: usize,
+    ) -> Vec<TableProfile> {
+        self.profiling.get_by_table(namespace, limit)
     }
 
     pub fn clear_slow_queries(&self) {
@@ -314,6 +315,16 @@ impl InterceptorPipeline {
         self.safety.get_rules()
     }
 
+    /// Dry-run the safety rules against a query without executing or auditing it
+    pub fn simulate_safety_rules(
+        &self,
+        query: &str,
+        driver_id: &str,
+        namespace: Option<&Namespace>,
+    ) -> SafetySimulation {
+        self.safety.simulate_query(query, driver_id, namespace)
+    }
+
     pub fn add_safety_rule(&self, rule: SafetyRule) -> Result<(), String> {
         self.safety.add_rule(rule.clone())?;
 
@@ -359,3 +370,57 @@ fn upsert_builtin_override(overrides: &mut Vec<BuiltinRuleOverride>, rule_id: &s
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::redaction::{set_redaction_enabled, test_lock};
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn update_affected_rows_are_persisted() {
+        let _guard = test_lock();
+        set_redaction_enabled(true);
+        let tmp = TempDir::new().unwrap();
+        let pipeline = InterceptorPipeline::new(tmp.path().to_path_buf());
+
+        let mut context = pipeline.build_context(
+            "sess-x",
+            "UPDATE users SET email = 'a@example.com' WHERE active = false",
+            "postgres",
+            Environment::Development,
+            false,
+            false,
+            Some("app"),
+            None,
+            false,
+        );
+        context.touched_columns = Some(vec!["email".to_string()]);
+
+        pipeline.post_execute(
+            &context,
+            &QueryExecutionResult {
+                success: true,
+                error: None,
+                execution_time_ms: 4.2,
+                row_count: Some(10_000),
+                affected_rows: Some(10_000),
+            },
+            false,
+            None,
+        );
+
+        let entries = pipeline
+            .audit
+            .get_entries_from_disk(0, 0, None, None, None, None, None, None, None, None)
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].operation_type, QueryOperationType::Update);
+        assert_eq!(entries[0].affected_rows, Some(10_000));
+        assert_eq!(entries[0].row_count, Some(10_000));
+        assert_eq!(
+            entries[0].touched_columns.as_deref(),
+            Some(&["email".to_string()][..])
+        );
+    }
+}
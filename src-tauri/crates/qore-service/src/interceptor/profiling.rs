@@ -3,25 +3,53 @@
 //! Profiling Store
 //!
 //! Extended profiling metrics for query performance analysis.
-//! Tracks execution times, percentiles, and slow queries.
+//! Tracks execution times, percentiles, and slow queries. Each timing sample
+//! keeps the target table parsed from the query, so metrics can be narrowed
+//! to a namespace (one schema per tenant) or broken down per table.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use parking_lot::RwLock;
 
 use chrono::Utc;
 use tracing::{debug, info};
 
-use super::types::{Environment, ProfilingMetrics, QueryOperationType, SlowQueryEntry};
+use super::types::{
+    Environment, ProfilingMetrics, QueryOperationType, SlowQueryEntry, TableProfile,
+};
 
 /// Maximum number of execution times to track for percentile calculation
 const MAX_EXECUTION_TIMES: usize = 10000;
 
+/// One recorded execution, kept for percentiles and filtered breakdowns
+#[derive(Debug, Clone)]
+struct TimingSample {
+    execution_time_ms: f64,
+    success: bool,
+    blocked: bool,
+    operation_type: QueryOperationType,
+    environment: Environment,
+    database: Option<String>,
+    schema: Option<String>,
+    table: Option<String>,
+}
+
+impl TimingSample {
+    /// A namespace matches the connection database or the schema the query
+    /// qualified its table with.
+    fn in_namespace(&self, namespace: Option<&str>) -> bool {
+        match namespace {
+            None => true,
+            Some(ns) => self.database.as_deref() == Some(ns) || self.schema.as_deref() == Some(ns),
+        }
+    }
+}
+
 /// Profiling store with performance metrics
 pub struct ProfilingStore {
     metrics: RwLock<ProfilingMetrics>,
-    /// Execution times for percentile calculation (insertion order)
-    execution_times: RwLock<VecDeque<f64>>,
+    /// Timing samples for percentile calculation (insertion order)
+    execution_times: RwLock<VecDeque<TimingSample>>,
     slow_queries: RwLock<VecDeque<SlowQueryEntry>>,
     /// Slow query threshold in milliseconds
     slow_threshold_ms: RwLock<u64>,
@@ -84,6 +112,12 @@ impl ProfilingStore {
         }
 
         let threshold = *self.slow_threshold_ms.read();
+        let (schema, table) = match query {
+            Some(query) => extract_table(query, operation_type, driver_id)
+                .map(|(schema, table)| (schema, Some(table)))
+                .unwrap_or_default(),
+            None => (None, None),
+        };
 
         {
             let mut metrics = self.metrics.write();
@@ -126,7 +160,16 @@ impl ProfilingStore {
             if times.len() >= MAX_EXECUTION_TIMES {
                 times.pop_front();
             }
-            times.push_back(execution_time_ms);
+            times.push_back(TimingSample {
+                execution_time_ms,
+                success,
+                blocked,
+                operation_type,
+                environment,
+                database: database.map(|s| s.to_string()),
+                schema: schema.clone(),
+                table: table.clone(),
+            });
         }
 
         if execution_time_ms >= threshold as f64 {
@@ -136,6 +179,8 @@ impl ProfilingStore {
                     execution_time_ms,
                     environment,
                     database,
+                    schema,
+                    table,
                     row_count,
                     driver_id,
                 );
@@ -149,12 +194,15 @@ impl ProfilingStore {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn record_slow_query(
         &self,
         query: &str,
         execution_time_ms: f64,
         environment: Environment,
         database: Option<&str>,
+        schema: Option<String>,
+        table: Option<String>,
         row_count: Option<i64>,
         driver_id: &str,
     ) {
@@ -167,6 +215,8 @@ impl ProfilingStore {
             execution_time_ms,
             environment,
             database: database.map(|s| s.to_string()),
+            schema,
+            table,
             row_count,
             driver_id: driver_id.to_string(),
         };
@@ -187,38 +237,130 @@ impl ProfilingStore {
             return;
         }
 
-        let mut sorted: Vec<f64> = times.iter().copied().collect();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
-        let len = sorted.len();
-        let p50_idx = len * 50 / 100;
-        let p95_idx = len * 95 / 100;
-        let p99_idx = len * 99 / 100;
+        let sorted = sorted_times(times.iter());
 
         let mut metrics = self.metrics.write();
-        metrics.p50_execution_time_ms = sorted.get(p50_idx).copied().unwrap_or(0.0);
-        metrics.p95_execution_time_ms = sorted.get(p95_idx).copied().unwrap_or(0.0);
-        metrics.p99_execution_time_ms = sorted.get(p99_idx).copied().unwrap_or(0.0);
+        metrics.p50_execution_time_ms = percentile(&sorted, 50);
+        metrics.p95_execution_time_ms = percentile(&sorted, 95);
+        metrics.p99_execution_time_ms = percentile(&sorted, 99);
     }
 
-    pub fn get_metrics(&self) -> ProfilingMetrics {
-        self.update_percentiles();
+    /// Running totals, or with `namespace` set, metrics recomputed from the
+    /// retained samples that belong to it.
+    pub fn get_metrics(&self, namespace: Option<&str>) -> ProfilingMetrics {
+        let Some(namespace) = namespace else {
+            self.update_percentiles();
+            return self.metrics.read().clone();
+        };
+
+        let threshold = *self.slow_threshold_ms.read() as f64;
+        let times = self.execution_times.read();
+        let samples: Vec<&TimingSample> = times
+            .iter()
+            .filter(|sample| sample.in_namespace(Some(namespace)))
+            .collect();
+
+        let mut metrics = ProfilingMetrics::new();
+        metrics.period_start = self.metrics.read().period_start;
+        for sample in &samples {
+            metrics.total_queries += 1;
+            if sample.blocked {
+                metrics.blocked_queries += 1;
+            } else if sample.success {
+                metrics.successful_queries += 1;
+            } else {
+                metrics.failed_queries += 1;
+            }
+            metrics.total_execution_time_ms += sample.execution_time_ms;
+            metrics.min_execution_time_ms =
+                metrics.min_execution_time_ms.min(sample.execution_time_ms);
+            metrics.max_execution_time_ms =
+                metrics.max_execution_time_ms.max(sample.execution_time_ms);
+            if sample.execution_time_ms >= threshold {
+                metrics.slow_query_count += 1;
+            }
+
+            let op_key = format!("{:?}", sample.operation_type).to_lowercase();
+            *metrics.by_operation_type.entry(op_key).or_insert(0) += 1;
+
+            let env_key = format!("{:?}", sample.environment).to_lowercase();
+            *metrics.by_environment.entry(env_key).or_insert(0) += 1;
+        }
+        if metrics.total_queries > 0 {
+            metrics.avg_execution_time_ms =
+                metrics.total_execution_time_ms / metrics.total_queries as f64;
+        }
 
-        let metrics = self.metrics.read();
-        metrics.clone()
+        let sorted = sorted_times(samples.into_iter());
+        metrics.p50_execution_time_ms = percentile(&sorted, 50);
+        metrics.p95_execution_time_ms = percentile(&sorted, 95);
+        metrics.p99_execution_time_ms = percentile(&sorted, 99);
+        metrics
     }
 
-    pub fn get_slow_queries(&self, limit: usize, offset: usize) -> Vec<SlowQueryEntry> {
+    pub fn get_slow_queries(
+        &self,
+        limit: usize,
+        offset: usize,
+        namespace: Option<&str>,
+    ) -> Vec<SlowQueryEntry> {
         let slow_queries = self.slow_queries.read();
         slow_queries
             .iter()
             .rev()
+            .filter(|entry| match namespace {
+                None => true,
+                Some(ns) => {
+                    entry.database.as_deref() == Some(ns) || entry.schema.as_deref() == Some(ns)
+                }
+            })
             .skip(offset)
             .take(limit)
             .cloned()
             .collect()
     }
 
+    /// Per-table percentiles over the retained samples, slowest (by p95) first.
+    pub fn get_by_table(&self, namespace: Option<&str>, limit: usize) -> Vec<TableProfile> {
+        let times = self.execution_times.read();
+        let mut groups: HashMap<(Option<&str>, &str), Vec<&TimingSample>> = HashMap::new();
+        for sample in times.iter().filter(|sample| sample.in_namespace(namespace)) {
+            if let Some(table) = sample.table.as_deref() {
+                groups
+                    .entry((sample.schema.as_deref(), table))
+                    .or_default()
+                    .push(sample);
+            }
+        }
+
+        let mut profiles: Vec<TableProfile> = groups
+            .into_iter()
+            .map(|((schema, table), samples)| {
+                let sorted = sorted_times(samples.into_iter());
+                let total: f64 = sorted.iter().sum();
+                TableProfile {
+                    schema: schema.map(|s| s.to_string()),
+                    table: table.to_string(),
+                    query_count: sorted.len() as u64,
+                    avg_execution_time_ms: total / sorted.len() as f64,
+                    p50_execution_time_ms: percentile(&sorted, 50),
+                    p95_execution_time_ms: percentile(&sorted, 95),
+                    p99_execution_time_ms: percentile(&sorted, 99),
+                    max_execution_time_ms: sorted.last().copied().unwrap_or(0.0),
+                }
+            })
+            .collect();
+
+        profiles.sort_by(|a, b| {
+            b.p95_execution_time_ms
+                .partial_cmp(&a.p95_execution_time_ms)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.table.cmp(&b.table))
+        });
+        profiles.truncate(limit);
+        profiles
+    }
+
     pub fn clear_slow_queries(&self) {
         self.slow_queries.write().clear();
         info!("Slow queries cleared");
@@ -240,10 +382,229 @@ impl ProfilingStore {
         }
 
         let export = ProfilingExport {
-            metrics: self.get_metrics(),
+            metrics: self.get_metrics(None),
             slow_queries: self.slow_queries.read().iter().cloned().collect(),
         };
 
         serde_json::to_string_pretty(&export).unwrap_or_else(|_| "{}".to_string())
     }
 }
+
+fn sorted_times<'a>(samples: impl Iterator<Item = &'a TimingSample>) -> Vec<f64> {
+    let mut sorted: Vec<f64> = samples.map(|sample| sample.execution_time_ms).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    sorted
+}
+
+/// Nearest-rank percentile of an ascending slice; 0.0 when empty.
+fn percentile(sorted: &[f64], pct: usize) -> f64 {
+    let idx = (sorted.len() * pct / 100).min(sorted.len().saturating_sub(1));
+    sorted.get(idx).copied().unwrap_or(0.0)
+}
+
+/// Target table of a SELECT/INSERT/UPDATE/DELETE as `(schema, table)`.
+///
+/// A keyword scan rather than a full parse, since it runs for every recorded
+/// query: the table is the first name after `FROM`, `INTO` or `UPDATE` outside
+/// parentheses. Derived tables and non-SQL drivers yield `None`.
+pub(super) fn extract_table(
+    query: &str,
+    operation_type: QueryOperationType,
+    driver_id: &str,
+) -> Option<(Option<String>, String)> {
+    if matches!(driver_id.to_ascii_lowercase().as_str(), "mongodb" | "redis") {
+        return None;
+    }
+    let keyword = match operation_type {
+        QueryOperationType::Select | QueryOperationType::Delete => "FROM",
+        QueryOperationType::Insert => "INTO",
+        QueryOperationType::Update => "UPDATE",
+        _ => return None,
+    };
+
+    let words = scan_words(query);
+    let start = words
+        .iter()
+        .position(|(depth, parts)| *depth == 0 && is_keyword(parts, keyword))?;
+    let (depth, parts) = words[start + 1..].iter().find(|(_, parts)| {
+        !["ONLY", "LOW_PRIORITY", "IGNORE"]
+            .iter()
+            .any(|modifier| is_keyword(parts, modifier))
+    })?;
+    if *depth != 0 {
+        return None;
+    }
+
+    let (table, qualifiers) = parts.split_last()?;
+    Some((qualifiers.last().cloned(), table.clone()))
+}
+
+fn is_keyword(parts: &[String], keyword: &str) -> bool {
+    matches!(parts, [word] if word.eq_ignore_ascii_case(keyword))
+}
+
+/// Splits `query` into dotted names with their parenthesis depth. Quoted
+/// identifiers lose their quotes; string literals and comments are skipped.
+fn scan_words(query: &str) -> Vec<(usize, Vec<String>)> {
+    let mut words = Vec::new();
+    let mut parts: Vec<String> = Vec::new();
+    let mut part = String::new();
+    let mut depth = 0usize;
+    let mut chars = query.chars().peekable();
+
+    fn flush(
+        words: &mut Vec<(usize, Vec<String>)>,
+        parts: &mut Vec<String>,
+        part: &mut String,
+        depth: usize,
+    ) {
+        if !part.is_empty() {
+            parts.push(std::mem::take(part));
+        }
+        if !parts.is_empty() {
+            words.push((depth, std::mem::take(parts)));
+        }
+    }
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\'' => {
+                flush(&mut words, &mut parts, &mut part, depth);
+                for next in chars.by_ref() {
+                    if next == '\'' {
+                        break;
+                    }
+                }
+            }
+            '"' | '`' | '[' => {
+                let close = if ch == '[' { ']' } else { ch };
+                for next in chars.by_ref() {
+                    if next == close {
+                        break;
+                    }
+                    part.push(next);
+                }
+            }
+            '.' => {
+                if !part.is_empty() {
+                    parts.push(std::mem::take(&mut part));
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                flush(&mut words, &mut parts, &mut part, depth);
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '(' => {
+                flush(&mut words, &mut parts, &mut part, depth);
+                depth += 1;
+            }
+            ')' => {
+                flush(&mut words, &mut parts, &mut part, depth);
+                depth = depth.saturating_sub(1);
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '$' => part.push(c),
+            _ => flush(&mut words, &mut parts, &mut part, depth),
+        }
+    }
+    flush(&mut words, &mut parts, &mut part, depth);
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(query: &str) -> Option<(Option<String>, String)> {
+        let operation = super::super::safety::classify_sql_operation(query);
+        extract_table(query, operation, "postgres")
+    }
+
+    fn qualified(schema: &str, name: &str) -> Option<(Option<String>, String)> {
+        Some((Some(schema.to_string()), name.to_string()))
+    }
+
+    fn bare(name: &str) -> Option<(Option<String>, String)> {
+        Some((None, name.to_string()))
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let sorted: Vec<f64> = (1..=100).map(|n| n as f64).collect();
+        assert_eq!(percentile(&sorted, 50), 51.0);
+        assert_eq!(percentile(&sorted, 95), 96.0);
+        assert_eq!(percentile(&sorted, 99), 100.0);
+        assert_eq!(percentile(&[7.0], 99), 7.0);
+        assert_eq!(percentile(&[], 50), 0.0);
+    }
+
+    #[test]
+    fn extracts_target_table() {
+        assert_eq!(
+            table("SELECT id FROM tenant_a.users WHERE id = 1"),
+            qualified("tenant_a", "users")
+        );
+        assert_eq!(
+            table("select (select max(id) from audit) from \"Orders\" o"),
+            bare("Orders")
+        );
+        assert_eq!(
+            table("INSERT INTO `shop`.`items` (name) VALUES ('from x')"),
+            qualified("shop", "items")
+        );
+        assert_eq!(
+            table("UPDATE ONLY public.accounts SET balance = 0"),
+            qualified("public", "accounts")
+        );
+        assert_eq!(
+            table("DELETE FROM [dbo].[logs] -- FROM other\nWHERE id = 1"),
+            qualified("dbo", "logs")
+        );
+        assert_eq!(table("SELECT * FROM (SELECT 1) t"), None);
+        assert_eq!(table("SELECT 1"), None);
+        assert_eq!(table("DROP TABLE users"), None);
+        assert_eq!(
+            extract_table("db.users.find({})", QueryOperationType::Select, "mongodb"),
+            None
+        );
+    }
+
+    #[test]
+    fn breakdown_filters_by_namespace_and_sorts_slowest_first() {
+        let store = ProfilingStore::new(1000, 10);
+        let record = |ms: f64, query: &str| {
+            let operation = super::super::safety::classify_sql_operation(query);
+            store.record(
+                ms,
+                true,
+                false,
+                operation,
+                Environment::Production,
+                Some(query),
+                Some("app"),
+                None,
+                "postgres",
+            );
+        };
+        record(5.0, "SELECT * FROM tenant_a.users");
+        record(15.0, "SELECT * FROM tenant_a.users");
+        record(40.0, "UPDATE tenant_a.orders SET paid = true");
+        record(90.0, "SELECT * FROM tenant_b.users");
+
+        let tables = store.get_by_table(Some("tenant_a"), 10);
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].table, "orders");
+        assert_eq!(tables[1].table, "users");
+        assert_eq!(tables[1].query_count, 2);
+        assert_eq!(tables[1].avg_execution_time_ms, 10.0);
+        assert_eq!(tables[1].p50_execution_time_ms, 15.0);
+
+        let metrics = store.get_metrics(Some("tenant_b"));
+        assert_eq!(metrics.total_queries, 1);
+        assert_eq!(metrics.p99_execution_time_ms, 90.0);
+        assert_eq!(store.get_metrics(Some("app")).total_queries, 4);
+    }
+}
@@ -5,13 +5,18 @@
 //! Enforces safety rules to prevent dangerous or unauthorized queries.
 //! Supports both built-in and custom rules.
 
-use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use parking_lot::{Mutex, RwLock};
 use regex::Regex;
 use tracing::{debug, info, warn};
 
+use qore_core::types::Namespace;
+
 use super::types::{
-    BuiltinRuleOverride, Environment, QueryContext, QueryOperationType, SafetyAction,
-    SafetyCheckResult, SafetyRule,
+    BuiltinRuleOverride, Environment, QueryContext, QueryOperationType, RuleMatchSpan,
+    SafetyAction, SafetyCheckResult, SafetyRule, SafetySimulation, SimulatedRuleMatch,
 };
 
 fn get_builtin_rules() -> Vec<SafetyRule> {
@@ -87,13 +92,98 @@ fn get_builtin_rules() -> Vec<SafetyRule> {
     ]
 }
 
+/// Classify a SQL statement by its leading keyword
+pub(super) fn classify_sql_operation(query: &str) -> QueryOperationType {
+    let query_upper = query.trim().to_uppercase();
+    let first_word = query_upper.split_whitespace().next().unwrap_or("");
+
+    match first_word {
+        "SELECT" => QueryOperationType::Select,
+        "INSERT" => QueryOperationType::Insert,
+        "UPDATE" => QueryOperationType::Update,
+        "DELETE" => QueryOperationType::Delete,
+        "CREATE" => QueryOperationType::Create,
+        "ALTER" => QueryOperationType::Alter,
+        "DROP" => QueryOperationType::Drop,
+        "TRUNCATE" => QueryOperationType::Truncate,
+        "GRANT" => QueryOperationType::Grant,
+        "REVOKE" => QueryOperationType::Revoke,
+        "EXEC" | "EXECUTE" | "CALL" => QueryOperationType::Execute,
+        _ => QueryOperationType::Other,
+    }
+}
+
+/// Classify operation for non-SQL (MongoDB) queries
+pub(super) fn classify_operation(query: &str, driver_id: &str) -> QueryOperationType {
+    if driver_id.eq_ignore_ascii_case("mongodb") {
+        let query_lower = query.to_lowercase();
+        if query_lower.contains("find") || query_lower.contains("aggregate") {
+            QueryOperationType::Select
+        } else if query_lower.contains("insert") {
+            QueryOperationType::Insert
+        } else if query_lower.contains("update") {
+            QueryOperationType::Update
+        } else if query_lower.contains("delete") || query_lower.contains("remove") {
+            QueryOperationType::Delete
+        } else if query_lower.contains("drop") {
+            QueryOperationType::Drop
+        } else if query_lower.contains("create") {
+            QueryOperationType::Create
+        } else {
+            QueryOperationType::Other
+        }
+    } else {
+        classify_sql_operation(query)
+    }
+}
+
+/// Buckets kept before idle ones are dropped
+const MAX_THROTTLE_BUCKETS: usize = 1024;
+
+/// Token bucket of a throttle rule for one session. It holds up to
+/// `per_minute` tokens and refills continuously at `per_minute` per minute.
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(per_minute: u32, now: Instant) -> Self {
+        Self {
+            tokens: per_minute as f64,
+            updated_at: now,
+        }
+    }
+
+    fn try_take(&mut self, per_minute: u32, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        let capacity = per_minute as f64;
+        self.tokens = (self.tokens + elapsed * capacity / 60.0).min(capacity);
+        self.updated_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// True once the bucket has had a minute to refill completely.
+    fn is_idle(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.updated_at).as_secs() >= 60
+    }
+}
+
 /// Safety rules engine
 pub struct SafetyEngine {
     /// Built-in rules (always present, can be disabled)
     builtin_rules: RwLock<Vec<SafetyRule>>,
     custom_rules: RwLock<Vec<SafetyRule>>,
     enabled: RwLock<bool>,
-    pattern_cache: RwLock<std::collections::HashMap<String, Regex>>,
+    pattern_cache: RwLock<HashMap<String, Regex>>,
+    /// Throttle buckets keyed by (session id, rule id)
+    throttle_buckets: Mutex<HashMap<(String, String), TokenBucket>>,
 }
 
 impl SafetyEngine {
@@ -102,7 +192,8 @@ impl SafetyEngine {
             builtin_rules: RwLock::new(get_builtin_rules()),
             custom_rules: RwLock::new(Vec::new()),
             enabled: RwLock::new(true),
-            pattern_cache: RwLock::new(std::collections::HashMap::new()),
+            pattern_cache: RwLock::new(HashMap::new()),
+            throttle_buckets: Mutex::new(HashMap::new()),
         }
     }
 
@@ -130,11 +221,7 @@ impl SafetyEngine {
             return Err("Cannot add built-in rules".to_string());
         }
 
-        if let Some(ref pattern) = rule.pattern {
-            if let Err(e) = Regex::new(pattern) {
-                return Err(format!("Invalid regex pattern: {}", e));
-            }
-        }
+        validate_rule(&rule)?;
 
         let mut custom = self.custom_rules.write();
 
@@ -150,11 +237,7 @@ impl SafetyEngine {
     }
 
     pub fn update_rule(&self, rule: SafetyRule) -> Result<(), String> {
-        if let Some(ref pattern) = rule.pattern {
-            if let Err(e) = Regex::new(pattern) {
-                return Err(format!("Invalid regex pattern: {}", e));
-            }
-        }
+        validate_rule(&rule)?;
 
         if rule.builtin {
             // Built-in rules only expose `enabled` for mutation.
@@ -169,6 +252,9 @@ impl SafetyEngine {
         let mut custom = self.custom_rules.write();
 
         if let Some(existing) = custom.iter_mut().find(|r| r.id == rule.id) {
+            self.throttle_buckets
+                .lock()
+                .retain(|(_, rule_id), _| *rule_id != rule.id);
             *existing = rule;
             self.pattern_cache.write().clear();
             Ok(())
@@ -190,6 +276,9 @@ impl SafetyEngine {
         }
 
         self.pattern_cache.write().clear();
+        self.throttle_buckets
+            .lock()
+            .retain(|(_, id), _| id != rule_id);
 
         Ok(())
     }
@@ -244,15 +333,20 @@ impl SafetyEngine {
             return None;
         }
 
-        // Empty `operations` means match-all.
-        if !rule.operations.is_empty() && !rule.operations.contains(&context.operation_type) {
-            return None;
-        }
+        self.match_rule(rule, context.operation_type, &context.query)?;
 
-        if let Some(ref pattern_str) = rule.pattern {
-            if !self.matches_pattern(pattern_str, &context.query) {
+        if let SafetyAction::Throttle { per_minute } = rule.action {
+            if self.take_throttle_token(&context.session_id, &rule.id, per_minute) {
                 return None;
             }
+            return Some(SafetyCheckResult::throttled(
+                rule.id.clone(),
+                per_minute,
+                format!(
+                    "{}: more than {} matching queries per minute",
+                    rule.name, per_minute
+                ),
+            ));
         }
 
         let message = format!("{}: {}", rule.name, rule.description);
@@ -266,34 +360,121 @@ impl SafetyEngine {
                     SafetyCheckResult::needs_confirmation(rule.id.clone(), message)
                 }
             }
-            SafetyAction::Warn => SafetyCheckResult::warning(rule.id.clone(), message),
+            SafetyAction::Warn | SafetyAction::Throttle { .. } => {
+                SafetyCheckResult::warning(rule.id.clone(), message)
+            }
         })
     }
 
-    fn matches_pattern(&self, pattern: &str, query: &str) -> bool {
-        {
-            let cache = self.pattern_cache.read();
-            if let Some(regex) = cache.get(pattern) {
-                return regex.is_match(query);
-            }
+    /// Takes one token from the bucket of `rule_id` for `session_id`.
+    fn take_throttle_token(&self, session_id: &str, rule_id: &str, per_minute: u32) -> bool {
+        let now = Instant::now();
+        let mut buckets = self.throttle_buckets.lock();
+        if buckets.len() >= MAX_THROTTLE_BUCKETS {
+            buckets.retain(|_, bucket| !bucket.is_idle(now));
+        }
+        buckets
+            .entry((session_id.to_string(), rule_id.to_string()))
+            .or_insert_with(|| TokenBucket::full(per_minute, now))
+            .try_take(per_minute, now)
+    }
+
+    /// Dry run: lists every enabled rule whose operations and pattern match
+    /// `query`, regardless of environment or the global enabled flag.
+    /// Nothing is executed and no audit entry is written.
+    pub fn simulate_query(
+        &self,
+        query: &str,
+        driver_id: &str,
+        namespace: Option<&Namespace>,
+    ) -> SafetySimulation {
+        let operation_type = classify_operation(query, driver_id);
+
+        let builtin = self.builtin_rules.read();
+        let custom = self.custom_rules.read();
+        let matches = builtin
+            .iter()
+            .chain(custom.iter())
+            .filter(|r| r.enabled)
+            .filter_map(|rule| {
+                let span = self.match_rule(rule, operation_type, query)?;
+                Some(SimulatedRuleMatch {
+                    rule_id: rule.id.clone(),
+                    rule_name: rule.name.clone(),
+                    action: rule.action,
+                    environments: rule.environments.clone(),
+                    span,
+                })
+            })
+            .collect();
+
+        SafetySimulation {
+            operation_type,
+            database: namespace.map(|ns| ns.database.clone()),
+            matches,
+        }
+    }
+
+    /// Operation and pattern test shared by `check` and `simulate_query`.
+    /// Returns the matched span (`None` for pattern-less rules) on a match.
+    fn match_rule(
+        &self,
+        rule: &SafetyRule,
+        operation_type: QueryOperationType,
+        query: &str,
+    ) -> Option<Option<RuleMatchSpan>> {
+        // Empty `operations` means match-all.
+        if !rule.operations.is_empty() && !rule.operations.contains(&operation_type) {
+            return None;
+        }
+
+        match rule.pattern {
+            Some(ref pattern_str) => self.find_pattern(pattern_str, query).map(Some),
+            None => Some(None),
+        }
+    }
+
+    fn find_pattern(&self, pattern: &str, query: &str) -> Option<RuleMatchSpan> {
+        let regex = self.compiled_pattern(pattern)?;
+        regex.find(query).map(|m| RuleMatchSpan {
+            start: m.start(),
+            end: m.end(),
+            text: m.as_str().to_string(),
+        })
+    }
+
+    fn compiled_pattern(&self, pattern: &str) -> Option<Regex> {
+        if let Some(regex) = self.pattern_cache.read().get(pattern) {
+            return Some(regex.clone());
         }
 
         match Regex::new(&format!("(?i){}", pattern)) {
             Ok(regex) => {
-                let matches = regex.is_match(query);
                 self.pattern_cache
                     .write()
-                    .insert(pattern.to_string(), regex);
-                matches
+                    .insert(pattern.to_string(), regex.clone());
+                Some(regex)
             }
             Err(e) => {
                 warn!("Invalid regex pattern '{}': {}", pattern, e);
-                false
+                None
             }
         }
     }
 }
 
+fn validate_rule(rule: &SafetyRule) -> Result<(), String> {
+    if let Some(ref pattern) = rule.pattern {
+        if let Err(e) = Regex::new(pattern) {
+            return Err(format!("Invalid regex pattern: {}", e));
+        }
+    }
+    if rule.action == (SafetyAction::Throttle { per_minute: 0 }) {
+        return Err("Throttle rules need a limit of at least one query per minute".to_string());
+    }
+    Ok(())
+}
+
 impl Default for SafetyEngine {
     fn default() -> Self {
         Self::new()
@@ -316,6 +497,7 @@ mod tests {
             is_dangerous: op.is_destructive(),
             acknowledged: false,
             read_only: false,
+            touched_columns: None,
         }
     }
 
@@ -359,4 +541,141 @@ mod tests {
         assert!(!result.allowed);
         assert!(result.requires_confirmation);
     }
+
+    #[test]
+    fn test_simulate_delete_without_where() {
+        let engine = SafetyEngine::new();
+        let query = "delete from users";
+
+        let simulation = engine.simulate_query(query, "postgres", None);
+        assert_eq!(simulation.operation_type, QueryOperationType::Delete);
+
+        let rule = simulation
+            .matches
+            .iter()
+            .find(|m| m.rule_id == "builtin-confirm-delete-no-where")
+            .expect("DELETE without WHERE should match");
+        assert_eq!(rule.action, SafetyAction::RequireConfirmation);
+        let span = rule.span.as_ref().expect("pattern rule reports a span");
+        assert_eq!((span.start, span.end), (0, query.len()));
+        assert_eq!(span.text, query);
+
+        let with_where = engine.simulate_query("DELETE FROM users WHERE id = 1", "postgres", None);
+        assert!(!with_where
+            .matches
+            .iter()
+            .any(|m| m.rule_id == "builtin-confirm-delete-no-where"));
+    }
+
+    #[test]
+    fn test_simulate_reports_case_insensitive_span() {
+        let engine = SafetyEngine::new();
+        engine
+            .add_rule(SafetyRule {
+                id: "custom-no-sleep".to_string(),
+                name: "No sleep".to_string(),
+                description: String::new(),
+                enabled: true,
+                environments: vec![Environment::Production],
+                operations: vec![],
+                action: SafetyAction::Warn,
+                pattern: Some("pg_sleep".to_string()),
+                builtin: false,
+            })
+            .unwrap();
+
+        let simulation = engine.simulate_query("SELECT PG_SLEEP(5)", "postgres", None);
+        let rule = simulation
+            .matches
+            .iter()
+            .find(|m| m.rule_id == "custom-no-sleep")
+            .expect("pattern should match regardless of case");
+        let span = rule.span.as_ref().unwrap();
+        assert_eq!((span.start, span.end), (7, 15));
+        assert_eq!(span.text, "PG_SLEEP");
+    }
+
+    #[test]
+    fn test_simulate_skips_disabled_rules() {
+        let engine = SafetyEngine::new();
+        let mut rule = engine
+            .get_rules()
+            .into_iter()
+            .find(|r| r.id == "builtin-confirm-delete-no-where")
+            .unwrap();
+        rule.enabled = false;
+        engine.update_rule(rule).unwrap();
+
+        let simulation = engine.simulate_query("DELETE FROM users", "postgres", None);
+        assert!(!simulation
+            .matches
+            .iter()
+            .any(|m| m.rule_id == "builtin-confirm-delete-no-where"));
+    }
+
+    fn throttle_rule(per_minute: u32) -> SafetyRule {
+        SafetyRule {
+            id: "custom-throttle-select-star".to_string(),
+            name: "Throttle SELECT *".to_string(),
+            description: String::new(),
+            enabled: true,
+            environments: vec![Environment::Staging],
+            operations: vec![QueryOperationType::Select],
+            action: SafetyAction::Throttle { per_minute },
+            pattern: Some(r"select\s+\*".to_string()),
+            builtin: false,
+        }
+    }
+
+    #[test]
+    fn test_throttle_rejects_queries_past_limit() {
+        let engine = SafetyEngine::new();
+        engine.add_rule(throttle_rule(3)).unwrap();
+        let context = make_context(
+            Environment::Staging,
+            QueryOperationType::Select,
+            "SELECT * FROM events",
+        );
+
+        let results: Vec<SafetyCheckResult> = (0..5).map(|_| engine.check(&context)).collect();
+        assert!(results[..3].iter().all(|r| r.allowed));
+        assert!(results[..3].iter().all(|r| r.triggered_rule.is_none()));
+        for result in &results[3..] {
+            assert!(!result.allowed);
+            assert_eq!(result.action, SafetyAction::Throttle { per_minute: 3 });
+            assert_eq!(
+                result.triggered_rule.as_deref(),
+                Some("custom-throttle-select-star")
+            );
+        }
+
+        let mut other_session = context.clone();
+        other_session.session_id = "other".to_string();
+        assert!(engine.check(&other_session).allowed);
+
+        let unmatched = make_context(
+            Environment::Staging,
+            QueryOperationType::Select,
+            "SELECT id FROM events",
+        );
+        assert!(engine.check(&unmatched).allowed);
+    }
+
+    #[test]
+    fn test_token_bucket_refills_over_time() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::full(3, start);
+        assert!((0..3).all(|_| bucket.try_take(3, start)));
+        assert!(!bucket.try_take(3, start));
+
+        let later = start + std::time::Duration::from_secs(20);
+        assert!(bucket.try_take(3, later));
+        assert!(!bucket.try_take(3, later));
+    }
+
+    #[test]
+    fn test_throttle_rule_needs_positive_limit() {
+        let engine = SafetyEngine::new();
+        assert!(engine.add_rule(throttle_rule(0)).is_err());
+    }
 }
@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tamper-Evident Audit Exports
+//!
+//! A signed export is a JSON document with the audit entries and a manifest.
+//! Each entry carries an HMAC-SHA256 over the previous entry's hash and its
+//! own serialized form, so inserting, removing, reordering or editing an
+//! entry breaks the chain; the manifest pins the entry count and the final
+//! digest under its own HMAC, so truncating the tail is caught as well.
+//!
+//! The HMAC key is a random secret kept in the vault credential provider:
+//! only installs sharing that vault can produce or verify a signature.
+
+use std::path::Path;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::types::AuditLogEntry;
+use crate::vault::backend::CredentialProvider;
+use qore_core::error::{EngineError, EngineResult};
+
+const SIGNING_KEY_SERVICE: &str = "qoredb";
+const SIGNING_KEY_NAME: &str = "__audit_signing_key__";
+const SIGNING_KEY_LEN: usize = 32;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const FORMAT_VERSION: u32 = 1;
+const ALGORITHM: &str = "HMAC-SHA256";
+/// Previous hash fed to the first entry of a chain
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditExportManifest {
+    pub version: u32,
+    pub algorithm: String,
+    pub created_at: DateTime<Utc>,
+    pub entry_count: usize,
+    /// Hash of the last entry, or the genesis hash for an empty export
+    pub final_digest: String,
+    /// HMAC over the fields above
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedAuditEntry {
+    /// The entry as exported. Kept as raw JSON so verification hashes exactly
+    /// what is in the file.
+    pub entry: serde_json::Value,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedAuditExport {
+    pub manifest: AuditExportManifest,
+    pub entries: Vec<SignedAuditEntry>,
+}
+
+/// Audit signing key from the vault, generated and stored on first use.
+pub fn signing_key(provider: &dyn CredentialProvider) -> EngineResult<Vec<u8>> {
+    if provider.has_credential(SIGNING_KEY_SERVICE, SIGNING_KEY_NAME)? {
+        let encoded = provider.get_password(SIGNING_KEY_SERVICE, SIGNING_KEY_NAME)?;
+        return BASE64
+            .decode(encoded)
+            .map_err(|e| EngineError::internal(format!("Corrupt audit signing key: {e}")));
+    }
+
+    let mut key = vec![0u8; SIGNING_KEY_LEN];
+    OsRng.fill_bytes(&mut key);
+    provider.set_password(SIGNING_KEY_SERVICE, SIGNING_KEY_NAME, &BASE64.encode(&key))?;
+    Ok(key)
+}
+
+/// Chains `entries` in order and builds the signed manifest. Fails when an
+/// entry cannot be serialized, rather than exporting without it.
+pub fn sign_entries(
+    entries: &[AuditLogEntry],
+    key: &[u8],
+) -> serde_json::Result<SignedAuditExport> {
+    let mut previous = GENESIS_HASH.to_string();
+    let mut signed = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let entry = serde_json::to_value(entry)?;
+        let hash = to_hex(&chain_mac(key, &previous, &entry).finalize().into_bytes());
+        previous.clone_from(&hash);
+        signed.push(SignedAuditEntry { entry, hash });
+    }
+
+    let mut manifest = AuditExportManifest {
+        version: FORMAT_VERSION,
+        algorithm: ALGORITHM.to_string(),
+        created_at: Utc::now(),
+        entry_count: signed.len(),
+        final_digest: previous,
+        mac: String::new(),
+    };
+    manifest.mac = to_hex(&manifest_mac(key, &manifest).finalize().into_bytes());
+    Ok(SignedAuditExport {
+        manifest,
+        entries: signed,
+    })
+}
+
+/// Pretty-printed signed export, ready to be saved by the frontend.
+pub fn to_signed_json(entries: &[AuditLogEntry], key: &[u8]) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&sign_entries(entries, key)?)
+}
+
+/// Recomputes the chain of `export`. False on any mismatch with the stored
+/// hashes or the manifest.
+pub fn verify_export(export: &SignedAuditExport, key: &[u8]) -> bool {
+    let manifest = &export.manifest;
+    if manifest.version != FORMAT_VERSION
+        || manifest.algorithm != ALGORITHM
+        || manifest.entry_count != export.entries.len()
+        || !verify_hex(manifest_mac(key, manifest), &manifest.mac)
+    {
+        return false;
+    }
+
+    let mut previous = GENESIS_HASH;
+    for signed in &export.entries {
+        if !verify_hex(chain_mac(key, previous, &signed.entry), &signed.hash) {
+            return false;
+        }
+        previous = &signed.hash;
+    }
+    previous == manifest.final_digest
+}
+
+/// Reads a signed export from `path` and verifies it. Errors when the file
+/// cannot be read or is not a signed export; `Ok(false)` means tampered.
+pub fn verify_audit_export(path: &Path, key: &[u8]) -> EngineResult<bool> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| EngineError::internal(format!("Failed to read audit export: {e}")))?;
+    let export: SignedAuditExport = serde_json::from_str(&content)
+        .map_err(|e| EngineError::validation(format!("Not a signed audit export: {e}")))?;
+    Ok(verify_export(&export, key))
+}
+
+fn new_mac(key: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length")
+}
+
+fn chain_mac(key: &[u8], previous: &str, entry: &serde_json::Value) -> HmacSha256 {
+    let mut mac = new_mac(key);
+    mac.update(previous.as_bytes());
+    mac.update(&serde_json::to_vec(entry).unwrap_or_default());
+    mac
+}
+
+fn manifest_mac(key: &[u8], manifest: &AuditExportManifest) -> HmacSha256 {
+    let mut mac = new_mac(key);
+    mac.update(
+        format!(
+            "{}\n{}\n{}\n{}\n{}",
+            manifest.version,
+            manifest.algorithm,
+            manifest.entry_count,
+            manifest.created_at.to_rfc3339(),
+            manifest.final_digest
+        )
+        .as_bytes(),
+    );
+    mac
+}
+
+/// Constant-time check of `mac` against a hex-encoded tag.
+fn verify_hex(mac: HmacSha256, tag: &str) -> bool {
+    from_hex(tag).is_some_and(|tag| mac.verify_slice(&tag).is_ok())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::{Environment, QueryOperationType};
+    use super::*;
+    use crate::vault::backend::MockProvider;
+
+    fn sample(id: &str) -> AuditLogEntry {
+        AuditLogEntry {
+            id: id.to_string(),
+            timestamp: Utc::now(),
+            session_id: "sess-1".to_string(),
+            query: "DELETE FROM orders WHERE id = 1".to_string(),
+            query_preview: "DELETE FROM orders WHERE id = 1".to_string(),
+            environment: Environment::Production,
+            operation_type: QueryOperationType::Delete,
+            database: Some("shop".to_string()),
+            success: true,
+            error: None,
+            execution_time_ms: 1.234,
+            row_count: None,
+            affected_rows: Some(1),
+            touched_columns: None,
+            blocked: false,
+            safety_rule: None,
+            driver_id: "postgres".to_string(),
+            fingerprint: None,
+        }
+    }
+
+    fn write_export(dir: &Path, key: &[u8]) -> std::path::PathBuf {
+        let entries = vec![sample("a"), sample("b"), sample("c")];
+        let path = dir.join("audit.json");
+        std::fs::write(&path, to_signed_json(&entries, key).unwrap()).unwrap();
+        path
+    }
+
+    fn rewrite(path: &Path, edit: impl FnOnce(&mut SignedAuditExport)) {
+        let mut export: SignedAuditExport =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        edit(&mut export);
+        std::fs::write(path, serde_json::to_string_pretty(&export).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn untouched_export_verifies() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = signing_key(&MockProvider::new()).unwrap();
+        let path = write_export(dir.path(), &key);
+
+        assert!(verify_audit_export(&path, &key).unwrap());
+        assert!(!verify_audit_export(&path, b"another key").unwrap());
+    }
+
+    #[test]
+    fn modified_entry_fails_verification() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = signing_key(&MockProvider::new()).unwrap();
+        let path = write_export(dir.path(), &key);
+
+        rewrite(&path, |export| {
+            export.entries[1].entry["query_preview"] = "SELECT 1".into();
+        });
+        assert!(!verify_audit_export(&path, &key).unwrap());
+    }
+
+    #[test]
+    fn inserted_or_deleted_entry_fails_verification() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = signing_key(&MockProvider::new()).unwrap();
+
+        let path = write_export(dir.path(), &key);
+        rewrite(&path, |export| {
+            export.entries.remove(1);
+            export.manifest.entry_count -= 1;
+        });
+        assert!(!verify_audit_export(&path, &key).unwrap());
+
+        let path = write_export(dir.path(), &key);
+        rewrite(&path, |export| {
+            let copy = export.entries[0].clone();
+            export.entries.insert(1, copy);
+            export.manifest.entry_count += 1;
+        });
+        assert!(!verify_audit_export(&path, &key).unwrap());
+
+        let path = write_export(dir.path(), &key);
+        rewrite(&path, |export| {
+            export.entries.pop();
+            export.manifest.entry_count -= 1;
+        });
+        assert!(!verify_audit_export(&path, &key).unwrap());
+    }
+
+    #[test]
+    fn truncation_with_rewritten_manifest_fails_verification() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = signing_key(&MockProvider::new()).unwrap();
+        let path = write_export(dir.path(), &key);
+
+        rewrite(&path, |export| {
+            export.entries.pop();
+            export.manifest.entry_count = export.entries.len();
+            export.manifest.final_digest = export.entries.last().unwrap().hash.clone();
+        });
+        assert!(!verify_audit_export(&path, &key).unwrap());
+    }
+
+    #[test]
+    fn signing_key_is_created_once() {
+        let provider = MockProvider::new();
+        let key = signing_key(&provider).unwrap();
+        assert_eq!(key.len(), SIGNING_KEY_LEN);
+        assert_eq!(signing_key(&provider).unwrap(), key);
+    }
+}
@@ -75,6 +75,9 @@ pub enum SafetyAction {
     Warn,
     /// Require explicit user confirmation
     RequireConfirmation,
+    /// Allow `per_minute` matching queries per session and minute, and
+    /// reject the rest
+    Throttle { per_minute: u32 },
 }
 
 /// A custom safety rule for blocking or warning on certain queries
@@ -144,6 +147,16 @@ impl SafetyCheckResult {
         }
     }
 
+    pub fn throttled(rule_id: String, per_minute: u32, message: String) -> Self {
+        Self {
+            allowed: false,
+            action: SafetyAction::Throttle { per_minute },
+            triggered_rule: Some(rule_id),
+            message: Some(message),
+            requires_confirmation: false,
+        }
+    }
+
     pub fn warning(rule_id: String, message: String) -> Self {
         Self {
             allowed: true,
@@ -155,6 +168,34 @@ impl SafetyCheckResult {
     }
 }
 
+/// Byte range of the query text matched by a rule pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleMatchSpan {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// A rule that would fire during a dry run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedRuleMatch {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub action: SafetyAction,
+    /// Environments in which the rule is enforced
+    pub environments: Vec<Environment>,
+    /// Matched span, `None` for rules without a pattern
+    pub span: Option<RuleMatchSpan>,
+}
+
+/// Result of simulating the safety rules against a query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafetySimulation {
+    pub operation_type: QueryOperationType,
+    pub database: Option<String>,
+    pub matches: Vec<SimulatedRuleMatch>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditLogEntry {
     pub id: String,
@@ -175,6 +216,13 @@ pub struct AuditLogEntry {
     /// Number of rows affected/returned
     #[serde(default)]
     pub row_count: Option<i64>,
+    /// Rows inserted, updated or deleted, when the driver reports it
+    #[serde(default)]
+    pub affected_rows: Option<u64>,
+    /// Columns written by a row mutation. Names only: values are never
+    /// logged.
+    #[serde(default)]
+    pub touched_columns: Option<Vec<String>>,
     #[serde(default)]
     pub blocked: bool,
     #[serde(default)]
@@ -219,6 +267,8 @@ impl AuditLogEntry {
             error: None,
             execution_time_ms: 0.0,
             row_count: None,
+            affected_rows: None,
+            touched_columns: None,
             blocked: false,
             safety_rule: None,
             driver_id,
@@ -276,11 +326,30 @@ pub struct SlowQueryEntry {
     pub environment: Environment,
     #[serde(default)]
     pub database: Option<String>,
+    /// Schema qualifying the target table, when the query names one
+    #[serde(default)]
+    pub schema: Option<String>,
+    /// Target table parsed from the query
+    #[serde(default)]
+    pub table: Option<String>,
     #[serde(default)]
     pub row_count: Option<i64>,
     pub driver_id: String,
 }
 
+/// Execution-time breakdown for one table over the retained timing samples
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableProfile {
+    pub schema: Option<String>,
+    pub table: String,
+    pub query_count: u64,
+    pub avg_execution_time_ms: f64,
+    pub p50_execution_time_ms: f64,
+    pub p95_execution_time_ms: f64,
+    pub p99_execution_time_ms: f64,
+    pub max_execution_time_ms: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterceptorConfig {
     #[serde(default = "default_true")]
@@ -307,6 +376,12 @@ pub struct InterceptorConfig {
     /// `[REDACTED]` on top of the driver-specific rules.
     #[serde(default)]
     pub redaction_patterns: Vec<String>,
+    /// Run `EXPLAIN` before SELECT statements and warn when the estimated
+    /// row count exceeds `cost_check_row_limit`
+    #[serde(default)]
+    pub cost_check_enabled: bool,
+    #[serde(default = "default_cost_check_row_limit")]
+    pub cost_check_row_limit: u64,
 }
 
 /// Persisted enabled state for built-in rules
@@ -328,6 +403,10 @@ fn default_max_slow_queries() -> usize {
     100
 }
 
+fn default_cost_check_row_limit() -> u64 {
+    1_000_000
+}
+
 impl Default for InterceptorConfig {
     fn default() -> Self {
         Self {
@@ -341,6 +420,8 @@ impl Default for InterceptorConfig {
             builtin_rule_overrides: Vec::new(),
             redact_enabled: true,
             redaction_patterns: Vec::new(),
+            cost_check_enabled: false,
+            cost_check_row_limit: default_cost_check_row_limit(),
         }
     }
 }
@@ -360,6 +441,9 @@ pub struct QueryContext {
     /// Whether user has acknowledged dangerous query
     pub acknowledged: bool,
     pub read_only: bool,
+    /// Columns written by a row mutation (`insert_row`, `update_row`, ...),
+    /// recorded in the audit log by name only
+    pub touched_columns: Option<Vec<String>>,
 }
 
 /// Result of query execution for post-processing
@@ -371,6 +455,8 @@ pub struct QueryExecutionResult {
     pub execution_time_ms: f64,
     /// Number of rows affected/returned
     pub row_count: Option<i64>,
+    /// Rows inserted, updated or deleted, when the driver reports it
+    pub affected_rows: Option<u64>,
 }
 
 #[cfg(test)]
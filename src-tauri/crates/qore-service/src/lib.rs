@@ -3,9 +3,11 @@
 //! QoreService — Tauri-free service layer shared by every QorePlatform surface
 //! (desktop, CLI, MCP, server).
 
+pub mod bulk_import;
 pub mod cache;
 pub mod connection;
 pub mod context;
+pub mod display_transforms;
 pub mod error;
 pub mod governance;
 pub mod interceptor;
@@ -15,8 +17,10 @@ pub mod mutation;
 pub mod paths;
 pub mod policy;
 pub mod query;
+pub mod query_history;
 pub mod ratelimit;
 pub mod sensitive;
+pub mod table_copy;
 pub mod vault;
 pub mod virtual_relations;
 
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use super::status::LicenseTier;
 
-fn public_key_bytes() -> [u8; 32] {
+pub(crate) fn public_key_bytes() -> [u8; 32] {
     let b64 = env!("PUBLIC_KEY_BASE64");
     let bytes = BASE64
         .decode(b64)
@@ -71,10 +71,7 @@ pub fn decode_license(license_key: &str) -> Result<LicensePayload, LicenseError>
 /// Full verification: signature + expiration + tier check.
 /// Uses the embedded production public key.
 pub fn verify_license(license_key: &str) -> Result<LicensePayload, LicenseError> {
-    let payload = decode_license(license_key)?;
-    check_tier(&payload)?;
-    check_expiration(&payload)?;
-    Ok(payload)
+    verify_license_with_key(license_key, &public_key_bytes())
 }
 
 /// Decodes and verifies signature with a specific public key.
@@ -130,10 +127,19 @@ pub fn decode_license_with_key(
 pub fn verify_license_with_key(
     license_key: &str,
     public_key_bytes: &[u8; 32],
+) -> Result<LicensePayload, LicenseError> {
+    verify_license_at(license_key, public_key_bytes, Utc::now())
+}
+
+/// Full verification with a specific public key, judging expiration at `now`.
+pub fn verify_license_at(
+    license_key: &str,
+    public_key_bytes: &[u8; 32],
+    now: DateTime<Utc>,
 ) -> Result<LicensePayload, LicenseError> {
     let payload = decode_license_with_key(license_key, public_key_bytes)?;
     check_tier(&payload)?;
-    check_expiration(&payload)?;
+    check_expiration(&payload, now)?;
     Ok(payload)
 }
 
@@ -144,11 +150,11 @@ fn check_tier(payload: &LicensePayload) -> Result<(), LicenseError> {
     }
 }
 
-fn check_expiration(payload: &LicensePayload) -> Result<(), LicenseError> {
+fn check_expiration(payload: &LicensePayload, now: DateTime<Utc>) -> Result<(), LicenseError> {
     // A missing `expires_at` denotes a perpetual license.
     if let Some(ref expires_at) = payload.expires_at {
         if let Ok(exp) = expires_at.parse::<DateTime<Utc>>() {
-            if Utc::now() > exp {
+            if now > exp {
                 return Err(LicenseError::Expired);
             }
         }
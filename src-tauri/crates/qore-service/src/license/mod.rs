@@ -3,16 +3,39 @@
 pub mod key;
 pub mod status;
 
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
 use crate::vault::backend::CredentialProvider;
-use key::{decode_license, verify_license, LicenseError};
+use key::{
+    decode_license_with_key, public_key_bytes, verify_license_at, LicenseError, LicensePayload,
+};
 use status::{LicenseStatus, LicenseTier};
 
 const LICENSE_SERVICE: &str = "com.qoredb.license";
 const LICENSE_USERNAME: &str = "license_key";
+const LAST_VERIFIED_USERNAME: &str = "license_last_verified";
+
+/// How long an expired license keeps its tier after its last successful
+/// verification, so a machine that stays offline past `expires_at` does not
+/// lose Pro features abruptly.
+pub const LICENSE_GRACE_PERIOD_DAYS: i64 = 14;
+
+/// Last successful verification, bound to the key it was recorded for so a
+/// different key can never inherit it.
+#[derive(Serialize, Deserialize)]
+struct VerificationRecord {
+    payment_id: String,
+    verified_at: DateTime<Utc>,
+}
+
+type Clock = Box<dyn Fn() -> DateTime<Utc> + Send + Sync>;
 
 pub struct LicenseManager {
     provider: Box<dyn CredentialProvider>,
     cached_status: LicenseStatus,
+    public_key: [u8; 32],
+    clock: Clock,
     /// Dev-only: override the effective tier without a real license key.
     /// Only compiled in debug builds — cannot exist in release binaries.
     #[cfg(debug_assertions)]
@@ -21,9 +44,19 @@ pub struct LicenseManager {
 
 impl LicenseManager {
     pub fn new(provider: Box<dyn CredentialProvider>) -> Self {
+        Self::with_key_and_clock(provider, public_key_bytes(), Box::new(Utc::now))
+    }
+
+    fn with_key_and_clock(
+        provider: Box<dyn CredentialProvider>,
+        public_key: [u8; 32],
+        clock: Clock,
+    ) -> Self {
         let mut manager = Self {
             provider,
             cached_status: LicenseStatus::default(),
+            public_key,
+            clock,
             #[cfg(debug_assertions)]
             dev_override_tier: None,
         };
@@ -44,12 +77,7 @@ impl LicenseManager {
             return LicenseStatus {
                 tier,
                 email: Some("dev@qoredb.local".to_string()),
-                payment_id: None,
-                issued_at: None,
-                expires_at: None,
-                is_expired: false,
-                seats: None,
-                is_founder: false,
+                ..LicenseStatus::default()
             };
         }
         self.cached_status.clone()
@@ -69,22 +97,15 @@ impl LicenseManager {
 
     /// Validates the key, persists it in the keyring, and updates the cached status.
     pub fn activate(&mut self, license_key: &str) -> Result<LicenseStatus, LicenseError> {
-        let payload = verify_license(license_key)?;
+        let now = (self.clock)();
+        let payload = verify_license_at(license_key, &self.public_key, now)?;
 
         self.provider
             .set_password(LICENSE_SERVICE, LICENSE_USERNAME, license_key)
             .map_err(|e| LicenseError::Storage(e.to_string()))?;
+        self.record_verification(&payload, now);
 
-        let status = LicenseStatus {
-            tier: payload.tier,
-            email: Some(payload.email),
-            payment_id: Some(payload.payment_id),
-            issued_at: Some(payload.issued_at),
-            expires_at: payload.expires_at,
-            is_expired: false,
-            seats: payload.seats,
-            is_founder: payload.is_founder,
-        };
+        let status = status_from_payload(payload);
         self.cached_status = status.clone();
         Ok(status)
     }
@@ -94,6 +115,9 @@ impl LicenseManager {
         self.provider
             .delete_password(LICENSE_SERVICE, LICENSE_USERNAME)
             .map_err(|e| LicenseError::Storage(e.to_string()))?;
+        let _ = self
+            .provider
+            .delete_password(LICENSE_SERVICE, LAST_VERIFIED_USERNAME);
         self.cached_status = LicenseStatus::default();
         Ok(())
     }
@@ -108,32 +132,32 @@ impl LicenseManager {
             Err(_) => return,
         };
 
-        match verify_license(&stored_key) {
+        let now = (self.clock)();
+        match verify_license_at(&stored_key, &self.public_key, now) {
             Ok(payload) => {
-                self.cached_status = LicenseStatus {
-                    tier: payload.tier,
-                    email: Some(payload.email),
-                    payment_id: Some(payload.payment_id),
-                    issued_at: Some(payload.issued_at),
-                    expires_at: payload.expires_at,
-                    is_expired: false,
-                    seats: payload.seats,
-                    is_founder: payload.is_founder,
-                };
+                self.record_verification(&payload, now);
+                self.cached_status = status_from_payload(payload);
             }
             Err(LicenseError::Expired) => {
-                // Expose payload metadata for the UI while forcing the tier
-                // back to Core so gated features remain locked.
-                if let Ok(payload) = decode_license(&stored_key) {
+                // Expose payload metadata for the UI. The tier survives only
+                // inside the grace window; otherwise it is forced back to Core
+                // so gated features remain locked.
+                if let Ok(payload) = decode_license_with_key(&stored_key, &self.public_key) {
+                    let grace_expires_at = self.grace_expires_at(&payload);
+                    let in_grace_period = grace_expires_at.is_some_and(|end| now < end);
+                    let tier = if in_grace_period {
+                        payload.tier
+                    } else {
+                        LicenseTier::Core
+                    };
                     self.cached_status = LicenseStatus {
-                        tier: LicenseTier::Core,
-                        email: Some(payload.email),
-                        payment_id: Some(payload.payment_id),
-                        issued_at: Some(payload.issued_at),
-                        expires_at: payload.expires_at,
+                        tier,
                         is_expired: true,
-                        seats: payload.seats,
-                        is_founder: payload.is_founder,
+                        in_grace_period,
+                        grace_expires_at: grace_expires_at
+                            .filter(|_| in_grace_period)
+                            .map(|end| end.to_rfc3339()),
+                        ..status_from_payload(payload)
                     };
                 }
             }
@@ -142,13 +166,69 @@ impl LicenseManager {
                 let _ = self
                     .provider
                     .delete_password(LICENSE_SERVICE, LICENSE_USERNAME);
+                let _ = self
+                    .provider
+                    .delete_password(LICENSE_SERVICE, LAST_VERIFIED_USERNAME);
+            }
+        }
+    }
+
+    fn record_verification(&self, payload: &LicensePayload, now: DateTime<Utc>) {
+        let record = VerificationRecord {
+            payment_id: payload.payment_id.clone(),
+            verified_at: now,
+        };
+        if let Ok(json) = serde_json::to_string(&record) {
+            if let Err(e) =
+                self.provider
+                    .set_password(LICENSE_SERVICE, LAST_VERIFIED_USERNAME, &json)
+            {
+                tracing::warn!("Failed to record license verification time: {e}");
             }
         }
     }
+
+    /// End of the grace window for an expired payload, if this exact key was
+    /// ever verified while still valid.
+    fn grace_expires_at(&self, payload: &LicensePayload) -> Option<DateTime<Utc>> {
+        let json = self
+            .provider
+            .get_password(LICENSE_SERVICE, LAST_VERIFIED_USERNAME)
+            .ok()?;
+        let record: VerificationRecord = serde_json::from_str(&json).ok()?;
+        if record.payment_id != payload.payment_id {
+            return None;
+        }
+        let expires_at = payload
+            .expires_at
+            .as_deref()?
+            .parse::<DateTime<Utc>>()
+            .ok()?;
+        if record.verified_at > expires_at {
+            return None;
+        }
+        Some(record.verified_at + Duration::days(LICENSE_GRACE_PERIOD_DAYS))
+    }
+}
+
+fn status_from_payload(payload: LicensePayload) -> LicenseStatus {
+    LicenseStatus {
+        tier: payload.tier,
+        email: Some(payload.email),
+        payment_id: Some(payload.payment_id),
+        issued_at: Some(payload.issued_at),
+        expires_at: payload.expires_at,
+        is_expired: false,
+        seats: payload.seats,
+        is_founder: payload.is_founder,
+        in_grace_period: false,
+        grace_expires_at: None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::key::test_helpers::{create_test_license, dev_keypair};
     use super::*;
     use crate::vault::backend::MockProvider;
 
@@ -179,7 +259,98 @@ mod tests {
         assert_eq!(mgr.status().tier, LicenseTier::Core);
     }
 
-    // NOTE: Full activate/deactivate roundtrip tests require the production
-    // public key to be set, or use verify_license_with_key directly.
-    // See key.rs tests for signature verification coverage.
+    const EXPIRES_AT: &str = "2030-06-01T00:00:00.000Z";
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        rfc3339.parse().unwrap()
+    }
+
+    fn license(payment_id: &str) -> String {
+        let (signing_key, _) = dev_keypair();
+        create_test_license(
+            &signing_key,
+            &LicensePayload {
+                email: "grace@example.com".into(),
+                tier: LicenseTier::Pro,
+                issued_at: "2030-01-01T00:00:00.000Z".into(),
+                expires_at: Some(EXPIRES_AT.into()),
+                payment_id: payment_id.into(),
+                seats: None,
+                is_founder: false,
+            },
+        )
+    }
+
+    /// Manager over `provider` whose clock is frozen at `now`.
+    fn manager_at(provider: &MockProvider, now: DateTime<Utc>) -> LicenseManager {
+        let (_, public_key) = dev_keypair();
+        LicenseManager::with_key_and_clock(
+            Box::new(provider.clone()),
+            public_key,
+            Box::new(move || now),
+        )
+    }
+
+    #[test]
+    fn expired_license_keeps_tier_within_grace_period() {
+        let provider = MockProvider::new();
+        let verified_at = at("2030-05-30T00:00:00Z");
+        manager_at(&provider, verified_at)
+            .activate(&license("pi_grace"))
+            .unwrap();
+
+        let offline = manager_at(&provider, at("2030-06-05T00:00:00Z"));
+        let status = offline.status();
+        assert!(status.is_expired);
+        assert!(status.in_grace_period);
+        assert_eq!(status.tier, LicenseTier::Pro);
+        let grace_end = verified_at + Duration::days(LICENSE_GRACE_PERIOD_DAYS);
+        assert_eq!(status.grace_expires_at, Some(grace_end.to_rfc3339()));
+
+        let lapsed = manager_at(&provider, grace_end + Duration::seconds(1));
+        assert!(lapsed.status().is_expired);
+        assert!(!lapsed.status().in_grace_period);
+        assert!(lapsed.status().grace_expires_at.is_none());
+        assert_eq!(lapsed.status().tier, LicenseTier::Core);
+    }
+
+    #[test]
+    fn never_verified_license_gets_no_grace() {
+        let provider = MockProvider::new();
+        let mut mgr = manager_at(&provider, at("2030-06-02T00:00:00Z"));
+        assert!(matches!(
+            mgr.activate(&license("pi_late")),
+            Err(LicenseError::Expired)
+        ));
+
+        // A stored key whose verification record belongs to another license
+        // does not inherit that record's grace either.
+        manager_at(&provider, at("2030-05-30T00:00:00Z"))
+            .activate(&license("pi_other"))
+            .unwrap();
+        provider
+            .set_password(LICENSE_SERVICE, LICENSE_USERNAME, &license("pi_late"))
+            .unwrap();
+        let mgr = manager_at(&provider, at("2030-06-02T00:00:00Z"));
+        assert!(!mgr.status().in_grace_period);
+        assert_eq!(mgr.status().tier, LicenseTier::Core);
+    }
+
+    #[test]
+    fn corrupt_key_never_enters_grace() {
+        let provider = MockProvider::new();
+        manager_at(&provider, at("2030-05-30T00:00:00Z"))
+            .activate(&license("pi_grace"))
+            .unwrap();
+        provider
+            .set_password(LICENSE_SERVICE, LICENSE_USERNAME, "garbage-key")
+            .unwrap();
+
+        let mgr = manager_at(&provider, at("2030-06-02T00:00:00Z"));
+        assert!(!mgr.status().in_grace_period);
+        assert_eq!(mgr.status().tier, LicenseTier::Core);
+        assert!(provider
+            .get_password(LICENSE_SERVICE, LAST_VERIFIED_USERNAME)
+            .is_err());
+    }
 }
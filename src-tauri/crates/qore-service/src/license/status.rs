@@ -42,6 +42,12 @@ pub struct LicenseStatus {
     pub seats: Option<u32>,
     #[serde(default)]
     pub is_founder: bool,
+    /// Expired, but verified recently enough to keep its tier for now.
+    #[serde(default)]
+    pub in_grace_period: bool,
+    /// RFC 3339 end of the grace period, set while `in_grace_period`.
+    #[serde(default)]
+    pub grace_expires_at: Option<String>,
 }
 
 impl Default for LicenseStatus {
@@ -55,6 +61,8 @@ impl Default for LicenseStatus {
             is_expired: false,
             seats: None,
             is_founder: false,
+            in_grace_period: false,
+            grace_expires_at: None,
         }
     }
 }
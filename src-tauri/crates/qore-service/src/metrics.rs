@@ -1,11 +1,73 @@
 // SPDX-License-Identifier: Apache-2.0
 
-//! Lightweight in-memory metrics for dev builds.
+//! Lightweight in-memory runtime metrics.
+//!
+//! Collection is always compiled in but off until the user opts in from
+//! Settings (on by default in dev builds). While off, every `record_*` call
+//! is a single relaxed atomic load.
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::OnceLock;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+use qore_drivers::query_manager::QueryManager;
+use qore_drivers::session_manager::SessionManager;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Serializes the tests that toggle the global flag.
+#[cfg(test)]
+pub(crate) static TEST_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// User setting for runtime metrics, persisted under the app data directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsConfig {
+    pub metrics_enabled: bool,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            metrics_enabled: cfg!(debug_assertions),
+        }
+    }
+}
+
+fn config_path() -> PathBuf {
+    crate::paths::app_data_dir().join("metrics_config.json")
+}
+
+impl MetricsConfig {
+    /// Loads the persisted config, falling back to defaults.
+    pub fn load() -> Self {
+        std::fs::read_to_string(config_path())
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persists the config to the app data directory.
+    pub fn save(&self) -> Result<(), String> {
+        let raw = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        crate::paths::atomic_write(&config_path(), raw.as_bytes()).map_err(|e| e.to_string())
+    }
+
+    /// Turns collection on or off to match the setting.
+    pub fn apply(&self) {
+        set_enabled(self.metrics_enabled);
+    }
+}
 
 #[derive(Default)]
 struct QueryMetrics {
@@ -24,6 +86,9 @@ fn metrics() -> &'static QueryMetrics {
 }
 
 pub fn record_query(duration_ms: f64, success: bool) {
+    if !is_enabled() {
+        return;
+    }
     let duration_ms = duration_ms.max(0.0) as u64;
     let metrics = metrics();
     metrics.total.fetch_add(1, Ordering::Relaxed);
@@ -49,10 +114,16 @@ pub fn record_query(duration_ms: f64, success: bool) {
 }
 
 pub fn record_cancel() {
+    if !is_enabled() {
+        return;
+    }
     metrics().cancelled.fetch_add(1, Ordering::Relaxed);
 }
 
 pub fn record_timeout() {
+    if !is_enabled() {
+        return;
+    }
     metrics().timeouts.fetch_add(1, Ordering::Relaxed);
 }
 
@@ -91,12 +162,59 @@ pub fn snapshot() -> QueryMetricsSnapshot {
     }
 }
 
+/// Connections of every open session's pool, summed.
+#[derive(Debug, Default, Serialize)]
+pub struct PoolUsage {
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuntimeMetrics {
+    pub queries: QueryMetricsSnapshot,
+    pub active_sessions: usize,
+    pub running_queries: usize,
+    pub pool: PoolUsage,
+}
+
+/// Query counters along with the live state of the session and query
+/// managers. Sessions whose driver has no pool add nothing to `pool`.
+pub async fn collect(
+    session_manager: &SessionManager,
+    query_manager: &QueryManager,
+) -> RuntimeMetrics {
+    let sessions = session_manager.list_sessions().await;
+    let mut pool = PoolUsage::default();
+    for (session, _) in &sessions {
+        let Ok(driver) = session_manager.get_driver(*session).await else {
+            continue;
+        };
+        if let Ok(Some(stats)) = driver.pool_stats(*session).await {
+            pool.size += stats.size;
+            pool.idle += stats.idle;
+            pool.in_use += stats.in_use;
+            pool.max += stats.max;
+        }
+    }
+
+    RuntimeMetrics {
+        queries: snapshot(),
+        active_sessions: sessions.len(),
+        running_queries: query_manager.count_active().await,
+        pool,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_metrics_flow() {
+        let _lock = TEST_LOCK.blocking_lock();
+        set_enabled(true);
         // Use deltas against the initial snapshot — other tests may run in parallel.
         let initial = snapshot();
 
@@ -121,5 +239,33 @@ mod tests {
         record_query(99999.0, true);
         let s5 = snapshot();
         assert!(s5.max_ms.unwrap() >= 99999);
+        set_enabled(false);
+    }
+
+    #[test]
+    fn test_disabled_metrics_record_nothing() {
+        let _lock = TEST_LOCK.blocking_lock();
+        set_enabled(false);
+        let initial = snapshot();
+
+        record_query(100.0, false);
+        record_cancel();
+        record_timeout();
+
+        let after = snapshot();
+        assert_eq!(after.total, initial.total);
+        assert_eq!(after.failed, initial.failed);
+        assert_eq!(after.cancelled, initial.cancelled);
+        assert_eq!(after.timeouts, initial.timeouts);
+    }
+
+    #[test]
+    fn test_config_defaults_to_build_profile() {
+        assert_eq!(
+            MetricsConfig::default().metrics_enabled,
+            cfg!(debug_assertions)
+        );
+        let config: MetricsConfig = serde_json::from_str(r#"{"metricsEnabled":true}"#).unwrap();
+        assert!(config.metrics_enabled);
     }
 }
@@ -2,7 +2,10 @@
 
 use std::sync::Arc;
 
-use qore_core::{DataEngine, SessionId};
+use qore_core::{
+    ColumnFilter, DataEngine, EngineError, EngineResult, FilterOperator, Namespace, RowData,
+    SessionId, TableQueryOptions, Value,
+};
 use qore_drivers::session_manager::SessionManager;
 
 use crate::interceptor::{
@@ -14,6 +17,9 @@ const MUTATIONS_NOT_SUPPORTED: &str = "Mutations are not supported by this drive
 const DANGEROUS_BLOCKED: &str = "Dangerous query blocked: confirmation required";
 const SAFETY_RULE_BLOCKED: &str = "Query blocked by safety rule";
 
+/// A delete matching more rows than this needs an explicit `confirmed_count`.
+const DELETE_CONFIRMATION_THRESHOLD: u64 = 1;
+
 pub struct MutationPreflight {
     pub driver: Arc<dyn DataEngine>,
     pub context: QueryContext,
@@ -73,13 +79,14 @@ pub async fn preflight(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => format!(
+            SafetyAction::Block | SafetyAction::Throttle { .. } => format!(
                 "{}: {}",
                 SAFETY_RULE_BLOCKED,
                 safety_result.message.unwrap_or_default()
@@ -107,3 +114,124 @@ pub async fn preflight(
         safety_warning,
     })
 }
+
+/// Counts the rows `primary_key` matches before a delete and rejects the
+/// delete when it would remove more than one row, unless `confirmed_count`
+/// equals that count. The count goes through `query_table`, so it uses the
+/// same predicate as `delete_row`: equality, or `IS NULL` for a null key
+/// value. Drivers that cannot count are let through unchecked.
+pub async fn check_delete_count(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    primary_key: &RowData,
+    confirmed_count: Option<u64>,
+) -> EngineResult<()> {
+    let filters: Vec<ColumnFilter> = primary_key
+        .columns
+        .iter()
+        .map(|(column, value)| ColumnFilter {
+            column: column.clone(),
+            operator: if matches!(value, Value::Null) {
+                FilterOperator::IsNull
+            } else {
+                FilterOperator::Eq
+            },
+            value: value.clone(),
+            options: Default::default(),
+        })
+        .collect();
+    if filters.is_empty() {
+        return Ok(());
+    }
+
+    let options = TableQueryOptions {
+        page_size: Some(1),
+        filters: Some(filters),
+        ..Default::default()
+    };
+    let matched = match driver.query_table(session, namespace, table, options).await {
+        Ok(page) => page.total_rows,
+        Err(EngineError::NotSupported { .. }) => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    if matched > DELETE_CONFIRMATION_THRESHOLD && confirmed_count != Some(matched) {
+        return Err(EngineError::validation(format!(
+            "Delete would remove {matched} rows; pass confirmed_count = {matched} to proceed"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::{ConnectionConfig, QueryId};
+    use qore_drivers::drivers::sqlite::SqliteDriver;
+
+    async fn seeded_sqlite() -> (SqliteDriver, SessionId) {
+        let driver = SqliteDriver::new();
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        for sql in [
+            "CREATE TABLE orders (id INTEGER, customer TEXT)",
+            "INSERT INTO orders VALUES (1, 'ada'), (2, 'bob'), (3, 'bob'), (4, 'bob')",
+            "INSERT INTO orders VALUES (5, NULL), (6, NULL)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (driver, session)
+    }
+
+    async fn check(
+        driver: &SqliteDriver,
+        session: SessionId,
+        key: RowData,
+        confirmed_count: Option<u64>,
+    ) -> EngineResult<()> {
+        check_delete_count(
+            driver,
+            session,
+            &Namespace::new("main"),
+            "orders",
+            &key,
+            confirmed_count,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn single_row_delete_needs_no_confirmation() {
+        let (driver, session) = seeded_sqlite().await;
+        let key = RowData::new().with_column("id", Value::Int(2));
+        assert!(check(&driver, session, key, None).await.is_ok());
+
+        let missing = RowData::new().with_column("id", Value::Int(99));
+        assert!(check(&driver, session, missing, None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn multi_row_delete_requires_matching_count() {
+        let (driver, session) = seeded_sqlite().await;
+        let key = || RowData::new().with_column("customer", Value::Text("bob".to_string()));
+
+        let err = check(&driver, session, key(), None).await.unwrap_err();
+        assert!(matches!(err, EngineError::ValidationError { .. }));
+        assert!(err.to_string().contains("3 rows"));
+
+        assert!(check(&driver, session, key(), Some(2)).await.is_err());
+        assert!(check(&driver, session, key(), Some(3)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn null_key_counts_null_rows() {
+        let (driver, session) = seeded_sqlite().await;
+        let key = || RowData::new().with_column("customer", Value::Null);
+
+        let err = check(&driver, session, key(), None).await.unwrap_err();
+        assert!(err.to_string().contains("2 rows"));
+        assert!(check(&driver, session, key(), Some(2)).await.is_ok());
+    }
+}
@@ -7,7 +7,51 @@
 
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+
+use crate::interceptor::Environment;
+
+/// What preflight does with dangerous SQL (DROP, TRUNCATE, ALTER, UPDATE or
+/// DELETE without WHERE). Ordered from most to least permissive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DangerousSqlAction {
+    Allow,
+    /// Run it and report a warning
+    Warn,
+    /// Run it once the user acknowledged it
+    Confirm,
+    Block,
+}
+
+/// Dangerous SQL handling for each connection environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnvironmentGuards {
+    pub development: DangerousSqlAction,
+    pub staging: DangerousSqlAction,
+    pub production: DangerousSqlAction,
+}
+
+impl Default for EnvironmentGuards {
+    fn default() -> Self {
+        Self {
+            development: DangerousSqlAction::Warn,
+            staging: DangerousSqlAction::Confirm,
+            production: DangerousSqlAction::Block,
+        }
+    }
+}
+
+impl EnvironmentGuards {
+    pub fn for_environment(&self, environment: Environment) -> DangerousSqlAction {
+        match environment {
+            Environment::Development => self.development,
+            Environment::Staging => self.staging,
+            Environment::Production => self.production,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SafetyPolicy {
@@ -25,6 +69,13 @@ pub struct SafetyPolicy {
     /// Anti-loop guardrail: cap the query rate per session (defaults to on).
     #[serde(default = "default_query_rate_limit")]
     pub query_rate_limit_enabled: bool,
+    /// Directory database files may be attached from (None = attaching
+    /// files is disabled; in-memory databases are always allowed)
+    #[serde(default)]
+    pub attach_allowed_dir: Option<PathBuf>,
+    /// Dangerous SQL handling per connection environment
+    #[serde(default)]
+    pub environment_guards: EnvironmentGuards,
 }
 
 fn default_query_rate_limit() -> bool {
@@ -68,6 +119,24 @@ impl SafetyPolicy {
             max_result_rows: None,
             max_concurrent_queries: None,
             query_rate_limit_enabled: true,
+            attach_allowed_dir: None,
+            environment_guards: EnvironmentGuards::default(),
+        }
+    }
+
+    /// Effective handling of dangerous SQL on `environment`. The production
+    /// flags only ever tighten the production guard.
+    pub fn dangerous_sql_action(&self, environment: Environment) -> DangerousSqlAction {
+        let action = self.environment_guards.for_environment(environment);
+        if environment != Environment::Production {
+            return action;
+        }
+        if self.prod_block_dangerous_sql {
+            DangerousSqlAction::Block
+        } else if self.prod_require_confirmation {
+            action.max(DangerousSqlAction::Confirm)
+        } else {
+            action
         }
     }
 
@@ -90,6 +159,48 @@ impl SafetyPolicy {
         if let Some(value) = env_bool_opt("QOREDB_QUERY_RATE_LIMIT") {
             self.query_rate_limit_enabled = value;
         }
+        if let Ok(value) = std::env::var("QOREDB_ATTACH_ALLOWED_DIR") {
+            self.attach_allowed_dir = Some(PathBuf::from(value.trim()));
+        }
+    }
+
+    /// Resolves a database file to attach, rejecting anything outside
+    /// `attach_allowed_dir`. The file itself may not exist yet (SQLite creates
+    /// it), but its parent directory must.
+    pub fn resolve_attach_path(&self, file_path: &str) -> Result<PathBuf, String> {
+        if file_path == ":memory:" {
+            return Ok(PathBuf::from(file_path));
+        }
+        let allowed_dir = self.attach_allowed_dir.as_ref().ok_or_else(|| {
+            "Attaching database files is disabled; set attach_allowed_dir in the safety policy"
+                .to_string()
+        })?;
+        let allowed_dir = allowed_dir
+            .canonicalize()
+            .map_err(|e| format!("Invalid attach_allowed_dir: {}", e))?;
+
+        let path = Path::new(file_path);
+        let file_name = match path.components().next_back() {
+            Some(Component::Normal(name)) => name,
+            _ => return Err(format!("Invalid database file path: {}", file_path)),
+        };
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => return Err("Database file path must be absolute".to_string()),
+        };
+        let resolved = parent
+            .canonicalize()
+            .map_err(|e| format!("Invalid database file path {}: {}", file_path, e))?
+            .join(file_name);
+
+        if !resolved.starts_with(&allowed_dir) {
+            return Err(format!(
+                "Database file {} is outside the allowed directory {}",
+                file_path,
+                allowed_dir.display()
+            ));
+        }
+        Ok(resolved)
     }
 
     pub fn load() -> Self {
@@ -135,6 +246,85 @@ mod tests {
         assert!(policy.max_result_rows.is_none());
         assert!(policy.max_concurrent_queries.is_none());
         assert!(policy.query_rate_limit_enabled);
+        assert!(policy.attach_allowed_dir.is_none());
+        assert_eq!(policy.environment_guards, EnvironmentGuards::default());
+    }
+
+    #[test]
+    fn test_dangerous_sql_action_per_environment() {
+        let mut policy = SafetyPolicy::defaults();
+        assert_eq!(
+            policy.dangerous_sql_action(Environment::Production),
+            DangerousSqlAction::Block
+        );
+        assert_eq!(
+            policy.dangerous_sql_action(Environment::Development),
+            DangerousSqlAction::Warn
+        );
+
+        policy.environment_guards.production = DangerousSqlAction::Allow;
+        assert_eq!(
+            policy.dangerous_sql_action(Environment::Production),
+            DangerousSqlAction::Confirm
+        );
+        policy.prod_require_confirmation = false;
+        assert_eq!(
+            policy.dangerous_sql_action(Environment::Production),
+            DangerousSqlAction::Allow
+        );
+        policy.prod_block_dangerous_sql = true;
+        assert_eq!(
+            policy.dangerous_sql_action(Environment::Production),
+            DangerousSqlAction::Block
+        );
+
+        let stored: SafetyPolicy = serde_json::from_str(
+            r#"{"prod_require_confirmation": true, "prod_block_dangerous_sql": false,
+                "environment_guards": {"development": "allow"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            stored.environment_guards.development,
+            DangerousSqlAction::Allow
+        );
+        assert_eq!(
+            stored.environment_guards.production,
+            DangerousSqlAction::Block
+        );
+    }
+
+    #[test]
+    fn test_resolve_attach_path() {
+        let root = tempfile::tempdir().unwrap();
+        let allowed = root.path().join("allowed");
+        std::fs::create_dir(&allowed).unwrap();
+        let inside = allowed.join("ref.sqlite");
+        let outside = root.path().join("other.sqlite");
+
+        let mut policy = SafetyPolicy::defaults();
+        assert!(policy.resolve_attach_path(":memory:").is_ok());
+        assert!(policy
+            .resolve_attach_path(inside.to_str().unwrap())
+            .is_err());
+
+        policy.attach_allowed_dir = Some(allowed.clone());
+        let resolved = policy
+            .resolve_attach_path(inside.to_str().unwrap())
+            .unwrap();
+        assert_eq!(resolved, allowed.canonicalize().unwrap().join("ref.sqlite"));
+
+        assert!(policy
+            .resolve_attach_path(outside.to_str().unwrap())
+            .is_err());
+        let traversal = allowed.join("..").join("other.sqlite");
+        assert!(policy
+            .resolve_attach_path(traversal.to_str().unwrap())
+            .is_err());
+        let dotdot = allowed.join("..");
+        assert!(policy
+            .resolve_attach_path(dotdot.to_str().unwrap())
+            .is_err());
+        assert!(policy.resolve_attach_path("ref.sqlite").is_err());
     }
 
     #[test]
@@ -3,13 +3,16 @@
 use std::sync::Arc;
 
 use qore_core::{
-    DataEngine, EngineError, Namespace, PaginatedQueryResult, QueryId, QueryResult, SessionId,
-    StreamEvent, StreamSender, TableQueryOptions, TableSchema,
+    CollectionListOptions, DataEngine, DescribeTableOptions, EngineError, EngineResult, Namespace,
+    PaginatedQueryResult, QueryId, QueryResult, SessionId, StreamEvent, StreamSender,
+    TableQueryOptions, TableSchema,
 };
 use qore_drivers::query_manager::QueryManager;
 use qore_drivers::session_manager::SessionManager;
 use qore_drivers::{mongo_safety, redis_safety};
 use qore_sql::safety as sql_safety;
+use qore_sql::suggest::{self, MissingObject, MissingObjectKind};
+use serde::Serialize;
 
 use crate::cache::QueryCache;
 use crate::error::ServiceError;
@@ -18,7 +21,7 @@ use crate::interceptor::{
     map_environment, Environment, InterceptorPipeline, QueryContext, QueryExecutionResult,
     SafetyAction,
 };
-use crate::policy::SafetyPolicy;
+use crate::policy::{DangerousSqlAction, SafetyPolicy};
 use crate::ratelimit::QueryRateLimiter;
 use crate::virtual_relations::VirtualRelationStore;
 
@@ -29,6 +32,15 @@ const SQL_PARSE_BLOCKED: &str = "Operation blocked: SQL parser could not classif
 const RATE_LIMIT_BLOCKED: &str =
     "Operation blocked: query rate limit exceeded — too many queries in a short time";
 const SAFETY_RULE_BLOCKED: &str = "Query blocked by safety rule";
+const EXPENSIVE_QUERY_BLOCKED: &str = "Expensive query: confirmation required";
+/// Reported as the safety warning when the environment guard lets dangerous
+/// SQL through with a warning
+pub const DANGEROUS_SQL_WARNING_RULE_ID: &str = "policy-warn-dangerous-sql";
+
+/// Upper bound on the schema lookup behind a "did you mean" hint.
+const SUGGESTION_LOOKUP_TIMEOUT_MS: u64 = 2_000;
+/// Tables whose columns are searched for a missing-column hint.
+const MAX_SUGGESTION_TABLES: usize = 4;
 
 fn is_mongo_mutation(query: &str) -> bool {
     matches!(
@@ -93,9 +105,12 @@ pub async fn describe_table(
     namespace: &Namespace,
     table: &str,
     connection_id: Option<&str>,
+    options: &DescribeTableOptions,
 ) -> Result<TableSchema, ServiceError> {
     let driver = session_manager.get_driver(session).await?;
-    let mut schema = driver.describe_table(session, namespace, table).await?;
+    let mut schema = driver
+        .describe_table_with_options(session, namespace, table, options)
+        .await?;
 
     if let Some(conn_id) = connection_id {
         let virtual_fks = vr_store.get_foreign_keys_for_table(
@@ -214,6 +229,15 @@ pub async fn query_table(
         .map_err(ServiceError::Message)?;
 
     let driver = session_manager.get_driver(session).await?;
+    let has_computed = options
+        .computed_columns
+        .as_ref()
+        .is_some_and(|columns| !columns.is_empty());
+    if has_computed && !driver.supports_computed_columns() {
+        return Err(ServiceError::Engine(EngineError::not_supported(
+            "Computed columns are not supported by this driver",
+        )));
+    }
 
     match governance::with_timeout(
         policy,
@@ -334,24 +358,27 @@ pub async fn preflight(
         }
     }
 
-    if is_production {
-        let is_dangerous = if is_sql_driver {
-            sql_analysis
-                .as_ref()
-                .map(|a| a.is_dangerous)
-                .unwrap_or(false)
-        } else if is_redis_driver {
-            is_redis_dangerous(query)
-        } else {
-            false
-        };
-        if is_dangerous {
-            if policy.prod_block_dangerous_sql {
-                return Err(DANGEROUS_BLOCKED_POLICY.to_string());
+    let guarded_dangerous = if is_sql_driver {
+        sql_analysis
+            .as_ref()
+            .map(|a| a.is_dangerous)
+            .unwrap_or(false)
+    } else if is_redis_driver {
+        is_redis_dangerous(query)
+    } else {
+        false
+    };
+    let mut guard_warning = None;
+    if guarded_dangerous {
+        match policy.dangerous_sql_action(environment) {
+            DangerousSqlAction::Block => return Err(DANGEROUS_BLOCKED_POLICY.to_string()),
+            DangerousSqlAction::Confirm if !acknowledged => {
+                return Err(DANGEROUS_BLOCKED.to_string())
             }
-            if policy.prod_require_confirmation && !acknowledged {
-                return Err(DANGEROUS_BLOCKED.to_string());
+            DangerousSqlAction::Warn => {
+                guard_warning = Some(DANGEROUS_SQL_WARNING_RULE_ID.to_string());
             }
+            DangerousSqlAction::Confirm | DangerousSqlAction::Allow => {}
         }
     }
 
@@ -394,13 +421,14 @@ pub async fn preflight(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => format!(
+            SafetyAction::Block | SafetyAction::Throttle { .. } => format!(
                 "{}: {}",
                 SAFETY_RULE_BLOCKED,
                 safety_result.message.unwrap_or_default()
@@ -415,11 +443,28 @@ pub async fn preflight(
         return Err(error_msg);
     }
 
-    let safety_warning = if matches!(safety_result.action, SafetyAction::Warn) {
+    let mut safety_warning = if matches!(safety_result.action, SafetyAction::Warn) {
         safety_result.triggered_rule.clone()
     } else {
         None
-    };
+    }
+    .or(guard_warning);
+
+    // The estimate is shown before anything runs; the query goes through
+    // once the user acknowledges it.
+    let cost_result = interceptor
+        .check_cost(driver.as_ref(), session, namespace.cloned(), &context)
+        .await;
+    if cost_result.triggered_rule.is_some() {
+        if !acknowledged {
+            return Err(format!(
+                "{}: {}",
+                EXPENSIVE_QUERY_BLOCKED,
+                cost_result.message.unwrap_or_default()
+            ));
+        }
+        safety_warning = safety_warning.or(cost_result.triggered_rule);
+    }
 
     Ok(Preflight {
         driver,
@@ -434,6 +479,102 @@ pub async fn preflight(
     })
 }
 
+/// Best-effort "did you mean" hint: on a missing table/column error, looks up
+/// similarly named tables or columns and appends the closest one. The error
+/// is returned unchanged when nothing is close or the lookup fails.
+pub async fn suggest_missing_object(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: Option<&Namespace>,
+    query: &str,
+    error: String,
+) -> String {
+    use tokio::time::{timeout, Duration};
+
+    let Some(missing) = suggest::classify_missing_object(&error) else {
+        return error;
+    };
+    let lookup = missing_object_candidates(driver, session, namespace, query, &missing);
+    let candidates =
+        match timeout(Duration::from_millis(SUGGESTION_LOOKUP_TIMEOUT_MS), lookup).await {
+            Ok(Ok(candidates)) => candidates,
+            _ => return error,
+        };
+    match suggest::closest_match(&missing.name, candidates.iter().map(String::as_str)) {
+        Some(candidate) => suggest::with_suggestion(&error, candidate),
+        None => error,
+    }
+}
+
+async fn missing_object_candidates(
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: Option<&Namespace>,
+    query: &str,
+    missing: &MissingObject,
+) -> EngineResult<Vec<String>> {
+    let namespace = match namespace {
+        Some(namespace) => namespace.clone(),
+        None => default_namespace(driver, session).await?,
+    };
+
+    match missing.kind {
+        MissingObjectKind::Table => {
+            let options = CollectionListOptions {
+                search: None,
+                page: None,
+                page_size: None,
+                cursor: None,
+            };
+            let list = driver
+                .list_collections(session, &namespace, options)
+                .await?;
+            Ok(list.collections.into_iter().map(|c| c.name).collect())
+        }
+        MissingObjectKind::Column => {
+            let tables = match &missing.table {
+                Some(table) => vec![(None, table.clone())],
+                None => suggest::referenced_tables(driver.driver_id(), query),
+            };
+            let mut columns = Vec::new();
+            for (qualifier, table) in tables.into_iter().take(MAX_SUGGESTION_TABLES) {
+                // Schema-less drivers (MySQL) qualify tables with the database.
+                let table_namespace = match (qualifier, &namespace.schema) {
+                    (Some(schema), Some(_)) => Namespace {
+                        database: namespace.database.clone(),
+                        schema: Some(schema),
+                    },
+                    (Some(database), None) => Namespace {
+                        database,
+                        schema: None,
+                    },
+                    (None, _) => namespace.clone(),
+                };
+                // CTEs and other non-tables just contribute no candidates.
+                if let Ok(schema) = driver
+                    .describe_table(session, &table_namespace, &table)
+                    .await
+                {
+                    columns.extend(schema.columns.into_iter().map(|c| c.name));
+                }
+            }
+            Ok(columns)
+        }
+    }
+}
+
+/// Namespace used when the query ran without one: the conventional default
+/// schema when listed, otherwise the first namespace.
+async fn default_namespace(driver: &dyn DataEngine, session: SessionId) -> EngineResult<Namespace> {
+    let namespaces = driver.list_namespaces(session).await?;
+    namespaces
+        .iter()
+        .find(|ns| matches!(ns.schema.as_deref(), Some("public" | "dbo" | "main")))
+        .or_else(|| namespaces.first())
+        .cloned()
+        .ok_or_else(|| EngineError::validation("No namespace available"))
+}
+
 pub struct ExecuteOutcome {
     pub success: bool,
     pub result: Option<QueryResult>,
@@ -445,6 +586,71 @@ pub struct ExecuteOutcome {
     pub truncated_total: Option<u64>,
 }
 
+/// Row cap applied to a streamed result; the query is cancelled once more
+/// rows than `cap` arrive.
+struct RowCap {
+    cap: u64,
+    driver: Arc<dyn DataEngine>,
+    session: SessionId,
+    query_id: QueryId,
+}
+
+#[derive(Default)]
+struct RelayOutcome {
+    /// Count carried by the final `Done` event, if the stream completed.
+    final_count: Option<u64>,
+    truncated: bool,
+}
+
+/// Forwards streamed events to `sender`. With a `row_cap`, stops after `cap`
+/// rows: sends `Truncated` and `Done`, then cancels the query and drops the
+/// driver's receiver so it stops producing rows.
+async fn relay_stream(
+    mut events: tokio::sync::mpsc::Receiver<StreamEvent>,
+    sender: StreamSender,
+    row_cap: Option<RowCap>,
+) -> RelayOutcome {
+    let mut outcome = RelayOutcome::default();
+    let mut returned = 0u64;
+    while let Some(event) = events.recv().await {
+        let rows = match &event {
+            StreamEvent::Row(_) => 1,
+            StreamEvent::RowBatch(batch) => batch.len() as u64,
+            _ => 0,
+        };
+        if let Some(row_cap) = row_cap.as_ref().filter(|c| returned + rows > c.cap) {
+            if let StreamEvent::RowBatch(mut batch) = event {
+                batch.truncate((row_cap.cap - returned) as usize);
+                if !batch.is_empty() {
+                    let _ = sender.send(StreamEvent::RowBatch(batch)).await;
+                }
+            }
+            let cap = row_cap.cap;
+            let _ = sender
+                .send(StreamEvent::Truncated { returned: cap, cap })
+                .await;
+            let _ = sender.send(StreamEvent::Done(cap)).await;
+            drop(events);
+            let _ = row_cap
+                .driver
+                .cancel(row_cap.session, Some(row_cap.query_id))
+                .await;
+            return RelayOutcome {
+                final_count: Some(cap),
+                truncated: true,
+            };
+        }
+        returned += rows;
+        if let StreamEvent::Done(count) = &event {
+            outcome.final_count = Some(*count);
+        }
+        if sender.send(event).await.is_err() {
+            break;
+        }
+    }
+    outcome
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn execute(
     query_manager: &QueryManager,
@@ -464,15 +670,66 @@ pub async fn execute(
     bypass_limits: bool,
     sql_statements: Option<Vec<String>>,
     stream_sender: Option<StreamSender>,
+    max_rows: Option<u64>,
     mut on_complete: impl FnMut(&QueryExecutionResult, Option<&QueryResult>),
 ) -> ExecuteOutcome {
     use tokio::time::{timeout, Duration};
 
+    // Held until this function returns; queued queries wait here for a slot
+    // on limited sessions, before the timeout and timing start.
+    let _slot = match query_manager.acquire_slot(session, query_id).await {
+        Ok(slot) => slot,
+        Err(msg) => {
+            query_manager.finish(query_id).await;
+            let exec_result = QueryExecutionResult {
+                success: false,
+                error: Some(msg.clone()),
+                execution_time_ms: 0.0,
+                row_count: None,
+                affected_rows: None,
+            };
+            interceptor.post_execute(context, &exec_result, false, safety_warning);
+            on_complete(&exec_result, None);
+            if let Some(sender) = stream_sender {
+                let _ = sender.send(StreamEvent::Error(msg.clone())).await;
+            }
+            return ExecuteOutcome {
+                success: false,
+                result: None,
+                extra_results: Vec::new(),
+                error: Some(msg),
+                truncated: None,
+                truncated_total: None,
+            };
+        }
+    };
+
     if let Some(sender) = stream_sender {
         let error_sender = sender.clone();
+        // Events pass through a relay so the count from `Done` reaches the audit log.
+        let (relay_sender, relay_events) = tokio::sync::mpsc::channel(sender.max_capacity());
+        // The policy's row limit is the default cap and also bounds an
+        // explicit one, unless limits are bypassed.
+        let policy_cap = policy.max_result_rows.filter(|_| !bypass_limits);
+        let row_cap = match (max_rows, policy_cap) {
+            (Some(requested), Some(limit)) => Some(requested.min(limit)),
+            (requested, limit) => requested.or(limit),
+        }
+        .map(|cap| RowCap {
+            cap,
+            driver: Arc::clone(&driver),
+            session,
+            query_id,
+        });
+        let relay = tokio::spawn(relay_stream(relay_events, sender, row_cap));
         let start_time = std::time::Instant::now();
-        let execution =
-            driver.execute_stream_in_namespace(session, namespace.clone(), query, query_id, sender);
+        let execution = driver.execute_stream_in_namespace(
+            session,
+            namespace.clone(),
+            query,
+            query_id,
+            relay_sender,
+        );
 
         let result = if let Some(timeout_value) = effective_timeout {
             match timeout(Duration::from_millis(timeout_value), execution).await {
@@ -488,6 +745,7 @@ pub async fn execute(
                         error: Some(format!("Operation timed out after {}ms", timeout_value)),
                         execution_time_ms: duration_ms,
                         row_count: None,
+                        affected_rows: None,
                     };
                     interceptor.post_execute(context, &exec_result, false, safety_warning);
                     on_complete(&exec_result, None);
@@ -511,13 +769,22 @@ pub async fn execute(
         let duration_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
         query_manager.finish(query_id).await;
 
+        let relay = relay.await.unwrap_or_default();
+        let final_count = relay.final_count;
+        // Cancelling a capped query can surface as a driver error; the rows
+        // up to the cap were delivered, so it still counts as a success.
+        let result = result.or_else(|e| if relay.truncated { Ok(()) } else { Err(e) });
+
         return match result {
             Ok(_) => {
                 let exec_result = QueryExecutionResult {
                     success: true,
                     error: None,
                     execution_time_ms: duration_ms,
-                    row_count: None,
+                    row_count: final_count
+                        .filter(|_| !is_mutation)
+                        .map(|count| count as i64),
+                    affected_rows: final_count.filter(|_| is_mutation),
                 };
                 interceptor.post_execute(context, &exec_result, false, safety_warning);
                 on_complete(&exec_result, None);
@@ -533,16 +800,25 @@ pub async fn execute(
                     result: None,
                     extra_results: Vec::new(),
                     error: None,
-                    truncated: None,
+                    truncated: relay.truncated.then_some(true),
                     truncated_total: None,
                 }
             }
             Err(e) => {
+                let error = suggest_missing_object(
+                    driver.as_ref(),
+                    session,
+                    namespace.as_ref(),
+                    query,
+                    e.sanitized_message(),
+                )
+                .await;
                 let exec_result = QueryExecutionResult {
                     success: false,
-                    error: Some(e.sanitized_message()),
+                    error: Some(error.clone()),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 };
                 interceptor.post_execute(context, &exec_result, false, safety_warning);
                 on_complete(&exec_result, None);
@@ -551,7 +827,7 @@ pub async fn execute(
                     success: false,
                     result: None,
                     extra_results: Vec::new(),
-                    error: Some(e.sanitized_message()),
+                    error: Some(error),
                     truncated: None,
                     truncated_total: None,
                 }
@@ -605,6 +881,7 @@ pub async fn execute(
                     error: Some(format!("Operation timed out after {}ms", timeout_value)),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 };
                 interceptor.post_execute(context, &exec_result, false, safety_warning);
                 on_complete(&exec_result, None);
@@ -647,7 +924,12 @@ pub async fn execute(
                 success: true,
                 error: None,
                 execution_time_ms: duration_ms,
-                row_count: primary.affected_rows.map(|a| a as i64),
+                row_count: if primary.columns.is_empty() {
+                    primary.affected_rows.map(|a| a as i64)
+                } else {
+                    Some(primary.rows.len() as i64)
+                },
+                affected_rows: primary.affected_rows,
             };
             interceptor.post_execute(context, &exec_result, false, safety_warning);
             on_complete(&exec_result, Some(&primary));
@@ -679,11 +961,20 @@ pub async fn execute(
         Err(e) => {
             crate::metrics::record_query(duration_ms, false);
 
+            let error = suggest_missing_object(
+                driver.as_ref(),
+                session,
+                namespace.as_ref(),
+                query,
+                e.sanitized_message(),
+            )
+            .await;
             let exec_result = QueryExecutionResult {
                 success: false,
-                error: Some(e.sanitized_message()),
+                error: Some(error.clone()),
                 execution_time_ms: duration_ms,
                 row_count: None,
+                affected_rows: None,
             };
             interceptor.post_execute(context, &exec_result, false, safety_warning);
             on_complete(&exec_result, None);
@@ -692,7 +983,7 @@ pub async fn execute(
                 success: false,
                 result: None,
                 extra_results: Vec::new(),
-                error: Some(e.sanitized_message()),
+                error: Some(error),
                 truncated: None,
                 truncated_total: None,
             }
@@ -702,3 +993,434 @@ pub async fn execute(
     query_manager.finish(query_id).await;
     outcome
 }
+
+/// Outcome of one statement of a batch.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchStatementResult {
+    pub index: usize,
+    pub statement: String,
+    pub success: bool,
+    pub result: Option<QueryResult>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct BatchOutcome {
+    /// Statements that ran, in order; the last one failed when
+    /// `failed_index` is set.
+    pub results: Vec<BatchStatementResult>,
+    pub failed_index: Option<usize>,
+    /// Whether the batch ran in its own transaction, and so was rolled back
+    /// on failure.
+    pub transactional: bool,
+}
+
+/// Runs `statements` in order, wrapped in one transaction when the session
+/// supports it, and stops at the first failure. The transaction is rolled
+/// back on failure and committed otherwise; without one, statements that ran
+/// before the failure stay applied.
+pub async fn execute_batch(
+    query_manager: &QueryManager,
+    driver: &dyn DataEngine,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    statements: &[String],
+) -> EngineResult<BatchOutcome> {
+    let transactional = driver.supports_transactions_for_session(session).await;
+    if transactional {
+        driver.begin_transaction(session).await?;
+    }
+
+    let mut outcome = BatchOutcome {
+        transactional,
+        ..Default::default()
+    };
+    for (index, statement) in statements.iter().enumerate() {
+        let query_id = query_manager.register(session).await;
+        let result = driver
+            .execute_in_namespace(session, namespace.clone(), statement, query_id)
+            .await;
+        query_manager.finish(query_id).await;
+
+        let failed = result.is_err();
+        outcome.results.push(BatchStatementResult {
+            index,
+            statement: statement.clone(),
+            success: !failed,
+            error: result.as_ref().err().map(|e| e.sanitized_message()),
+            result: result.ok(),
+        });
+        if failed {
+            outcome.failed_index = Some(index);
+            break;
+        }
+    }
+
+    if transactional {
+        if outcome.failed_index.is_some() {
+            if let Err(e) = driver.rollback(session).await {
+                tracing::warn!("Failed to roll back batch: {}", e);
+            }
+        } else {
+            driver.commit(session).await?;
+        }
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::ConnectionConfig;
+    use qore_drivers::drivers::sqlite::SqliteDriver;
+
+    fn sqlite_config(environment: &str) -> ConnectionConfig {
+        ConnectionConfig {
+            environment: environment.to_string(),
+            ..ConnectionConfig::sqlite_memory_for_tests()
+        }
+    }
+
+    async fn seeded_sqlite() -> (SqliteDriver, SessionId) {
+        let driver = SqliteDriver::new();
+        let config = sqlite_config("development");
+        let session = driver.connect(&config).await.unwrap();
+        driver
+            .execute(
+                session,
+                "CREATE TABLE users (id INTEGER, email TEXT)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        (driver, session)
+    }
+
+    async fn failing_error(driver: &SqliteDriver, session: SessionId, query: &str) -> String {
+        let error = driver
+            .execute(session, query, QueryId::new())
+            .await
+            .unwrap_err()
+            .sanitized_message();
+        suggest_missing_object(driver, session, None, query, error).await
+    }
+
+    #[tokio::test]
+    async fn suggests_similar_table_and_column_names() {
+        let (driver, session) = seeded_sqlite().await;
+
+        let error = failing_error(&driver, session, "SELECT * FROM usrs").await;
+        assert!(error.ends_with("(did you mean 'users'?)"), "{error}");
+
+        let error = failing_error(&driver, session, "SELECT emial FROM users").await;
+        assert!(error.ends_with("(did you mean 'email'?)"), "{error}");
+
+        let error = failing_error(&driver, session, "SELECT * FROM invoices").await;
+        assert!(!error.contains("did you mean"), "{error}");
+    }
+
+    async fn user_count(driver: &SqliteDriver, session: SessionId) -> i64 {
+        let result = driver
+            .execute(session, "SELECT COUNT(*) FROM users", QueryId::new())
+            .await
+            .unwrap();
+        match result.rows[0].values[0] {
+            qore_core::Value::Int(count) => count,
+            ref other => panic!("unexpected count {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_commits_every_statement() {
+        let (driver, session) = seeded_sqlite().await;
+        let statements = qore_sql::script::split_script(
+            "sqlite",
+            "INSERT INTO users VALUES (1, 'a;b'); INSERT INTO users VALUES (2, 'c');",
+        );
+        let outcome = execute_batch(&QueryManager::new(), &driver, session, None, &statements)
+            .await
+            .unwrap();
+
+        assert!(outcome.transactional);
+        assert_eq!(outcome.failed_index, None);
+        assert_eq!(outcome.results.len(), 2);
+        assert!(outcome.results.iter().all(|r| r.success));
+        assert_eq!(user_count(&driver, session).await, 2);
+    }
+
+    #[tokio::test]
+    async fn batch_rolls_back_at_first_failure() {
+        let (driver, session) = seeded_sqlite().await;
+        let statements = qore_sql::script::split_script(
+            "sqlite",
+            "INSERT INTO users VALUES (1, 'a');\n\
+             INSERT INTO invoices VALUES (1);\n\
+             INSERT INTO users VALUES (2, 'b');",
+        );
+        let outcome = execute_batch(&QueryManager::new(), &driver, session, None, &statements)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.failed_index, Some(1));
+        assert_eq!(outcome.results.len(), 2);
+        assert!(outcome.results[0].success);
+        assert_eq!(
+            outcome.results[1].statement,
+            "INSERT INTO invoices VALUES (1)"
+        );
+        assert!(outcome.results[1].error.is_some());
+        assert_eq!(user_count(&driver, session).await, 0);
+    }
+
+    /// Streams `query` through `execute` with a row cap and collects every
+    /// event the caller receives.
+    async fn stream_capped(
+        driver: Arc<dyn DataEngine>,
+        session: SessionId,
+        query: &str,
+        max_rows: u64,
+    ) -> (ExecuteOutcome, Vec<StreamEvent>) {
+        let tmp = tempfile::tempdir().unwrap();
+        let interceptor = InterceptorPipeline::new(tmp.path().to_path_buf());
+        let query_manager = QueryManager::new();
+        let policy = SafetyPolicy {
+            prod_require_confirmation: false,
+            prod_block_dangerous_sql: false,
+            max_query_duration_ms: None,
+            max_result_rows: None,
+            max_concurrent_queries: None,
+            query_rate_limit_enabled: false,
+            attach_allowed_dir: None,
+            environment_guards: Default::default(),
+        };
+        let context = interceptor.build_context(
+            "test",
+            query,
+            driver.driver_id(),
+            Environment::Development,
+            false,
+            false,
+            None,
+            None,
+            false,
+        );
+        let query_id = query_manager.register(session).await;
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(1024);
+        let outcome = execute(
+            &query_manager,
+            &QueryCache::new(),
+            &interceptor,
+            &policy,
+            driver,
+            &context,
+            session,
+            None,
+            query,
+            query_id,
+            false,
+            None,
+            None,
+            None,
+            false,
+            None,
+            Some(sender),
+            Some(max_rows),
+            |_, _| {},
+        )
+        .await;
+        let mut events = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            events.push(event);
+        }
+        (outcome, events)
+    }
+
+    fn streamed_rows(events: &[StreamEvent]) -> usize {
+        events
+            .iter()
+            .map(|event| match event {
+                StreamEvent::Row(_) => 1,
+                StreamEvent::RowBatch(batch) => batch.len(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn stream_stops_at_row_cap() {
+        let (driver, session) = seeded_sqlite().await;
+        driver
+            .execute(
+                session,
+                "INSERT INTO users (id, email) \
+                 WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1200) \
+                 SELECT i, 'user' || i || '@example.com' FROM n",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        let driver: Arc<dyn DataEngine> = Arc::new(driver);
+
+        let (outcome, events) =
+            stream_capped(Arc::clone(&driver), session, "SELECT * FROM users", 700).await;
+        assert!(outcome.success, "{:?}", outcome.error);
+        assert_eq!(outcome.truncated, Some(true));
+        assert_eq!(streamed_rows(&events), 700);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            StreamEvent::Truncated {
+                returned: 700,
+                cap: 700
+            }
+        )));
+        assert!(matches!(events.last(), Some(StreamEvent::Done(700))));
+
+        let (outcome, events) = stream_capped(driver, session, "SELECT * FROM users", 1200).await;
+        assert_eq!(outcome.truncated, None);
+        assert_eq!(streamed_rows(&events), 1200);
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, StreamEvent::Truncated { .. })));
+    }
+
+    #[tokio::test]
+    async fn execution_is_counted_only_when_metrics_enabled() {
+        let _lock = crate::metrics::TEST_LOCK.lock().await;
+        let (driver, session) = seeded_sqlite().await;
+        let driver: Arc<dyn DataEngine> = Arc::new(driver);
+
+        crate::metrics::set_enabled(false);
+        let initial = crate::metrics::snapshot();
+        stream_capped(Arc::clone(&driver), session, "SELECT * FROM users", 10).await;
+        assert_eq!(crate::metrics::snapshot().total, initial.total);
+
+        crate::metrics::set_enabled(true);
+        stream_capped(Arc::clone(&driver), session, "SELECT * FROM users", 10).await;
+        stream_capped(driver, session, "SELECT * FROM missing_table", 10).await;
+        crate::metrics::set_enabled(false);
+
+        let after = crate::metrics::snapshot();
+        // Other tests may execute queries meanwhile, hence lower bounds.
+        assert!(after.total >= initial.total + 2);
+        assert!(after.failed > initial.failed);
+    }
+
+    /// Live: the cap stops a large PostgreSQL result instead of draining it.
+    #[tokio::test]
+    async fn postgres_stream_stops_at_row_cap() {
+        let Ok(host) = std::env::var("QOREDB_TEST_PG_HOST") else {
+            return;
+        };
+        let config = ConnectionConfig {
+            driver: "postgres".to_string(),
+            host,
+            port: 5432,
+            username: std::env::var("QOREDB_TEST_PG_USER").unwrap_or_else(|_| "postgres".into()),
+            password: std::env::var("QOREDB_TEST_PG_PASSWORD")
+                .unwrap_or_else(|_| "postgres".into()),
+            database: Some("postgres".to_string()),
+            ssl: false,
+            ssl_mode: None,
+            environment: "development".to_string(),
+            read_only: false,
+            ssh_tunnel: None,
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        };
+        let driver: Arc<dyn DataEngine> =
+            Arc::new(qore_drivers::drivers::postgres::PostgresDriver::new());
+        let session = driver.connect(&config).await.unwrap();
+
+        let (outcome, events) = stream_capped(
+            Arc::clone(&driver),
+            session,
+            "SELECT g FROM generate_series(1, 10000000) AS g",
+            1000,
+        )
+        .await;
+        assert!(outcome.success, "{:?}", outcome.error);
+        assert_eq!(outcome.truncated, Some(true));
+        assert_eq!(streamed_rows(&events), 1000);
+        assert!(matches!(events.last(), Some(StreamEvent::Done(1000))));
+
+        driver.disconnect(session).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn leaves_unrelated_errors_untouched() {
+        let (driver, session) = seeded_sqlite().await;
+        let error = "syntax error at or near \"SELEC\"".to_string();
+        assert_eq!(
+            suggest_missing_object(&driver, session, None, "SELEC 1", error.clone()).await,
+            error
+        );
+    }
+
+    #[tokio::test]
+    async fn environment_guard_blocks_drop_on_production_only() {
+        let mut registry = qore_core::DriverRegistry::new();
+        registry.register(Arc::new(SqliteDriver::new()));
+        let session_manager = SessionManager::new(Arc::new(registry));
+        let tmp = tempfile::tempdir().unwrap();
+        let interceptor = InterceptorPipeline::new(tmp.path().to_path_buf());
+        let limiter = QueryRateLimiter::new(100.0, 100.0);
+        let policy = SafetyPolicy {
+            prod_require_confirmation: true,
+            prod_block_dangerous_sql: false,
+            max_query_duration_ms: None,
+            max_result_rows: None,
+            max_concurrent_queries: None,
+            query_rate_limit_enabled: false,
+            attach_allowed_dir: None,
+            environment_guards: Default::default(),
+        };
+
+        let mut outcomes = Vec::new();
+        for environment in ["production", "development"] {
+            let session = session_manager
+                .connect(sqlite_config(environment))
+                .await
+                .unwrap();
+            outcomes.push(
+                preflight(
+                    &session_manager,
+                    &limiter,
+                    &interceptor,
+                    &policy,
+                    session,
+                    &session.0.to_string(),
+                    "DROP TABLE users",
+                    None,
+                    false,
+                )
+                .await,
+            );
+        }
+
+        let Err(blocked) = &outcomes[0] else {
+            panic!("DROP must be blocked on production");
+        };
+        assert_eq!(blocked, DANGEROUS_BLOCKED_POLICY);
+        let Ok(allowed) = &outcomes[1] else {
+            panic!("DROP must only warn on development");
+        };
+        assert_eq!(
+            allowed.safety_warning.as_deref(),
+            Some(DANGEROUS_SQL_WARNING_RULE_ID)
+        );
+    }
+}
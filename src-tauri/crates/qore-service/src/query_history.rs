@@ -0,0 +1,403 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Query History Store
+//!
+//! Keeps the queries a user submitted so they can be searched and run again.
+//! Unlike the interceptor audit log, which records every execution for
+//! security review, history is a convenience feature: it has its own toggle
+//! and cap, and clearing it leaves the audit log untouched.
+//!
+//! Entries live in memory as a FIFO bounded by `max_entries` and are mirrored
+//! to `history.jsonl`. The file is compacted from memory once it holds a
+//! quarter more lines than the cap.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use qore_core::Namespace;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, warn};
+use uuid::Uuid;
+
+/// A query submitted by the user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryHistoryEntry {
+    pub id: String,
+    pub timestamp: DateTime<Utc>,
+    /// Saved connection the query ran on, when the caller knows it
+    pub connection_id: Option<String>,
+    pub session_id: String,
+    pub namespace: Option<Namespace>,
+    pub query: String,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_ms: f64,
+}
+
+impl QueryHistoryEntry {
+    pub fn new(session_id: String, query: String) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            connection_id: None,
+            session_id,
+            namespace: None,
+            query,
+            success: true,
+            error: None,
+            duration_ms: 0.0,
+        }
+    }
+}
+
+/// User-configurable history behaviour, persisted next to the history file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryHistoryConfig {
+    pub enabled: bool,
+    /// Oldest entries are dropped beyond this count.
+    pub max_entries: usize,
+}
+
+impl Default for QueryHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_entries: 1000,
+        }
+    }
+}
+
+impl QueryHistoryConfig {
+    /// Clamps user-supplied values into safe ranges.
+    pub fn clamp(&mut self) {
+        self.max_entries = self.max_entries.clamp(10, 50_000);
+    }
+}
+
+/// Filter for `QueryHistoryStore::search`. Results are newest first.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct QueryHistoryFilter {
+    /// Case-insensitive substring of the query text
+    pub search: Option<String>,
+    pub connection_id: Option<String>,
+    /// Maximum number of entries (default: 100)
+    pub limit: Option<usize>,
+}
+
+const DEFAULT_SEARCH_LIMIT: usize = 100;
+
+pub struct QueryHistoryStore {
+    entries: RwLock<VecDeque<QueryHistoryEntry>>,
+    config: RwLock<QueryHistoryConfig>,
+    log_path: PathBuf,
+    config_path: PathBuf,
+    /// Lines currently in the history file, including evicted entries
+    file_line_count: AtomicUsize,
+}
+
+impl QueryHistoryStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        if let Err(e) = fs::create_dir_all(&data_dir) {
+            error!("Failed to create query history directory: {}", e);
+        }
+
+        let config_path = data_dir.join("history.json");
+        let mut config: QueryHistoryConfig = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        config.clamp();
+
+        let store = Self {
+            entries: RwLock::new(VecDeque::new()),
+            config: RwLock::new(config),
+            log_path: data_dir.join("history.jsonl"),
+            config_path,
+            file_line_count: AtomicUsize::new(0),
+        };
+        store.load_entries();
+        store
+    }
+
+    fn load_entries(&self) {
+        let file = match File::open(&self.log_path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                warn!("Failed to load query history: {}", e);
+                return;
+            }
+        };
+
+        let max = self.config.read().max_entries;
+        let mut entries = self.entries.write();
+        let mut line_count = 0;
+        for line in BufReader::new(file).lines().map_while(Result::ok) {
+            line_count += 1;
+            if let Ok(entry) = serde_json::from_str::<QueryHistoryEntry>(&line) {
+                if entries.len() >= max {
+                    entries.pop_front();
+                }
+                entries.push_back(entry);
+            }
+        }
+        self.file_line_count.store(line_count, Ordering::Relaxed);
+        debug!("Loaded {} query history entries", entries.len());
+    }
+
+    pub fn config(&self) -> QueryHistoryConfig {
+        self.config.read().clone()
+    }
+
+    /// Replaces and persists the config, dropping entries beyond the new cap.
+    pub fn set_config(&self, mut config: QueryHistoryConfig) -> Result<(), String> {
+        config.clamp();
+        let raw = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+        crate::paths::atomic_write(&self.config_path, raw.as_bytes())
+            .map_err(|e| format!("Failed to save query history config: {}", e))?;
+
+        let max = config.max_entries;
+        *self.config.write() = config;
+        let mut entries = self.entries.write();
+        if entries.len() > max {
+            let excess = entries.len() - max;
+            entries.drain(..excess);
+            drop(entries);
+            self.compact();
+        }
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.read().enabled
+    }
+
+    /// Records `entry`, evicting the oldest one at the cap. A no-op while
+    /// history is disabled.
+    pub fn record(&self, entry: QueryHistoryEntry) {
+        let max = {
+            let config = self.config.read();
+            if !config.enabled {
+                return;
+            }
+            config.max_entries
+        };
+
+        {
+            let mut entries = self.entries.write();
+            while entries.len() >= max {
+                entries.pop_front();
+            }
+            entries.push_back(entry.clone());
+        }
+
+        if let Err(e) = self.append_to_file(&entry) {
+            error!("Failed to write query history entry: {}", e);
+        }
+        if self.file_line_count.load(Ordering::Relaxed) > max + max / 4 {
+            self.compact();
+        }
+    }
+
+    fn append_to_file(&self, entry: &QueryHistoryEntry) -> std::io::Result<()> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.log_path)?;
+        let mut writer = BufWriter::new(file);
+        writeln!(writer, "{}", serde_json::to_string(entry)?)?;
+        writer.flush()?;
+        self.file_line_count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Rewrites the history file from the in-memory entries.
+    fn compact(&self) {
+        let entries = self.entries.read();
+        let mut content = String::new();
+        for entry in entries.iter() {
+            match serde_json::to_string(entry) {
+                Ok(json) => {
+                    content.push_str(&json);
+                    content.push('\n');
+                }
+                Err(e) => warn!("Skipping unserializable history entry: {}", e),
+            }
+        }
+        match crate::paths::atomic_write(&self.log_path, content.as_bytes()) {
+            Ok(()) => self.file_line_count.store(entries.len(), Ordering::Relaxed),
+            Err(e) => error!("Failed to compact query history: {}", e),
+        }
+    }
+
+    pub fn search(&self, filter: &QueryHistoryFilter) -> Vec<QueryHistoryEntry> {
+        let needle = filter.search.as_deref().map(str::to_lowercase);
+        self.entries
+            .read()
+            .iter()
+            .rev()
+            .filter(|entry| {
+                entry_matches(entry, filter.connection_id.as_deref(), needle.as_deref())
+            })
+            .take(filter.limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.entries.write().clear();
+        if let Err(e) = File::create(&self.log_path) {
+            error!("Failed to clear query history file: {}", e);
+        }
+        self.file_line_count.store(0, Ordering::Relaxed);
+    }
+}
+
+fn entry_matches(
+    entry: &QueryHistoryEntry,
+    connection_id: Option<&str>,
+    needle: Option<&str>,
+) -> bool {
+    if let Some(id) = connection_id {
+        if entry.connection_id.as_deref() != Some(id) {
+            return false;
+        }
+    }
+    if let Some(needle) = needle {
+        if !entry.query.to_lowercase().contains(needle) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(connection_id: &str, query: &str) -> QueryHistoryEntry {
+        let mut entry = QueryHistoryEntry::new("sess-x".to_string(), query.to_string());
+        entry.connection_id = Some(connection_id.to_string());
+        entry
+    }
+
+    fn capped_store(dir: &TempDir, max_entries: usize) -> QueryHistoryStore {
+        let store = QueryHistoryStore::new(dir.path().to_path_buf());
+        store
+            .set_config(QueryHistoryConfig {
+                enabled: true,
+                max_entries,
+            })
+            .unwrap();
+        store
+    }
+
+    #[test]
+    fn records_and_reloads_entries() {
+        let dir = TempDir::new().unwrap();
+        let store = capped_store(&dir, 100);
+        store.record(entry("conn-a", "SELECT 1"));
+        let mut failed = entry("conn-a", "SELEC 2");
+        failed.success = false;
+        failed.error = Some("syntax error".to_string());
+        store.record(failed);
+
+        let reloaded = QueryHistoryStore::new(dir.path().to_path_buf());
+        let entries = reloaded.search(&QueryHistoryFilter::default());
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].query, "SELEC 2");
+        assert!(!entries[0].success);
+        assert_eq!(entries[1].query, "SELECT 1");
+        assert_eq!(reloaded.config().max_entries, 100);
+    }
+
+    #[test]
+    fn evicts_oldest_at_cap() {
+        let dir = TempDir::new().unwrap();
+        let store = capped_store(&dir, 10);
+        for i in 0..25 {
+            store.record(entry("conn-a", &format!("SELECT {i}")));
+        }
+
+        let entries = store.search(&QueryHistoryFilter::default());
+        assert_eq!(entries.len(), 10);
+        assert_eq!(entries[0].query, "SELECT 24");
+        assert_eq!(entries[9].query, "SELECT 15");
+
+        let reloaded = QueryHistoryStore::new(dir.path().to_path_buf());
+        assert_eq!(reloaded.len(), 10);
+        let lines = fs::read_to_string(dir.path().join("history.jsonl"))
+            .unwrap()
+            .lines()
+            .count();
+        assert!(lines <= 12, "history file was not compacted: {lines} lines");
+    }
+
+    #[test]
+    fn searches_by_substring_and_connection() {
+        let dir = TempDir::new().unwrap();
+        let store = capped_store(&dir, 100);
+        store.record(entry("conn-a", "SELECT * FROM orders"));
+        store.record(entry("conn-b", "select id from Orders where total > 10"));
+        store.record(entry("conn-a", "SELECT * FROM customers"));
+
+        let orders = store.search(&QueryHistoryFilter {
+            search: Some("ORDERS".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(orders.len(), 2);
+
+        let on_a = store.search(&QueryHistoryFilter {
+            search: Some("orders".to_string()),
+            connection_id: Some("conn-a".to_string()),
+            limit: None,
+        });
+        assert_eq!(on_a.len(), 1);
+        assert_eq!(on_a[0].query, "SELECT * FROM orders");
+
+        let limited = store.search(&QueryHistoryFilter {
+            limit: Some(1),
+            ..Default::default()
+        });
+        assert_eq!(limited[0].query, "SELECT * FROM customers");
+    }
+
+    #[test]
+    fn disabled_history_records_nothing() {
+        let dir = TempDir::new().unwrap();
+        let store = QueryHistoryStore::new(dir.path().to_path_buf());
+        store
+            .set_config(QueryHistoryConfig {
+                enabled: false,
+                max_entries: 100,
+            })
+            .unwrap();
+        store.record(entry("conn-a", "SELECT 1"));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn clear_empties_memory_and_file() {
+        let dir = TempDir::new().unwrap();
+        let store = capped_store(&dir, 100);
+        store.record(entry("conn-a", "SELECT 1"));
+        store.clear();
+        assert!(store.is_empty());
+        assert!(QueryHistoryStore::new(dir.path().to_path_buf()).is_empty());
+    }
+}
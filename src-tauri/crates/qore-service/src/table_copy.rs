@@ -0,0 +1,407 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-connection Table Copy
+//!
+//! Streams the rows of a table on one connection into a table on another,
+//! optionally creating the destination from the source columns first. Each
+//! batch is committed on its own: a failing batch is rolled back and the
+//! copy stops, keeping the batches already committed.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::time::timeout;
+
+use qore_core::{
+    CopyTableOptions, DataEngine, EngineError, EngineResult, Namespace, QueryId, Row, SessionId,
+    StreamEvent, TableSchema, Value,
+};
+use qore_sql::generator::SqlDialect;
+use qore_sql::type_mapper;
+
+/// Outcome of a table copy
+#[derive(Debug, Clone, Serialize)]
+pub struct CopyTableReport {
+    /// Rows committed on the destination
+    pub rows_copied: u64,
+    /// Why the copy stopped early; the batch in flight was rolled back
+    pub error: Option<String>,
+}
+
+/// Copies every row of `table` into `dest_table`. Failures before the first
+/// row moves (unsupported driver, missing table, DDL error) are returned as
+/// errors; failures mid-copy end up in the report next to the rows copied.
+#[allow(clippy::too_many_arguments)]
+pub async fn copy_table(
+    source: Arc<dyn DataEngine>,
+    source_session: SessionId,
+    source_ns: &Namespace,
+    table: &str,
+    dest: &dyn DataEngine,
+    dest_session: SessionId,
+    dest_ns: &Namespace,
+    dest_table: &str,
+    options: &CopyTableOptions,
+) -> EngineResult<CopyTableReport> {
+    if options.batch_size == 0 {
+        return Err(EngineError::validation("Batch size must be at least 1"));
+    }
+    let source_dialect = dialect_for(source.driver_id())?;
+    let dest_dialect = dialect_for(dest.driver_id())?;
+    let source_qualified = qualified_table(source_dialect, source.driver_id(), source_ns, table);
+    let dest_qualified = qualified_table(dest_dialect, dest.driver_id(), dest_ns, dest_table);
+
+    if options.create {
+        let schema = source
+            .describe_table(source_session, source_ns, table)
+            .await?;
+        if schema.columns.is_empty() {
+            return Err(EngineError::execution_error("Table not found"));
+        }
+        let ddl = create_table_sql(&schema, dest_dialect, dest.driver_id(), &dest_qualified)?;
+        dest.execute(dest_session, &ddl, QueryId::new()).await?;
+    }
+
+    let transactional = dest.supports_transactions_for_session(dest_session).await;
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
+    let query_id = QueryId::new();
+
+    let mut source_task = tokio::spawn({
+        let source = Arc::clone(&source);
+        let namespace = Some(source_ns.clone());
+        let query = format!("SELECT * FROM {source_qualified}");
+        async move {
+            source
+                .execute_stream_in_namespace(source_session, namespace, &query, query_id, sender)
+                .await
+        }
+    });
+
+    let mut prefix: Option<String> = None;
+    let mut pending: Vec<Row> = Vec::new();
+    let mut rows_copied = 0u64;
+    let mut failure: Option<String> = None;
+
+    loop {
+        let done = match receiver.recv().await {
+            Some(StreamEvent::Columns(columns)) => {
+                prefix = Some(format!(
+                    "INSERT INTO {} ({}) VALUES ",
+                    dest_qualified,
+                    columns
+                        .iter()
+                        .map(|c| dest_dialect.quote_ident(&c.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+                false
+            }
+            Some(StreamEvent::Row(row)) => {
+                pending.push(row);
+                false
+            }
+            Some(StreamEvent::RowBatch(batch)) => {
+                pending.extend(batch);
+                false
+            }
+            Some(StreamEvent::Error(err)) => {
+                failure = Some(err);
+                break;
+            }
+            Some(StreamEvent::Truncated { returned, .. }) => {
+                failure = Some(format!(
+                    "Source stopped streaming after {returned} rows (row cap reached)"
+                ));
+                break;
+            }
+            Some(StreamEvent::Done(_)) | None => true,
+        };
+
+        while pending.len() >= options.batch_size || (done && !pending.is_empty()) {
+            let Some(prefix) = prefix.as_deref() else {
+                failure = Some("Rows received before column metadata".to_string());
+                break;
+            };
+            let take = pending.len().min(options.batch_size);
+            let batch: Vec<Row> = pending.drain(..take).collect();
+            let sql = insert_sql(prefix, &batch, dest_dialect, dest.driver_id());
+            match insert_batch(dest, dest_session, &sql, transactional).await {
+                Ok(()) => rows_copied += batch.len() as u64,
+                Err(e) => {
+                    failure = Some(e.to_string());
+                    break;
+                }
+            }
+        }
+
+        if done || failure.is_some() {
+            break;
+        }
+    }
+
+    // Dropping the receiver unblocks a source still pushing rows.
+    drop(receiver);
+    if failure.is_some() {
+        let _ = source.cancel(source_session, Some(query_id)).await;
+        if timeout(Duration::from_secs(2), &mut source_task)
+            .await
+            .is_err()
+        {
+            source_task.abort();
+        }
+    } else {
+        source_task
+            .await
+            .map_err(|e| EngineError::internal(format!("Stream task failed: {e}")))??;
+    }
+
+    Ok(CopyTableReport {
+        rows_copied,
+        error: failure,
+    })
+}
+
+/// Dialect used for names and literals. DuckDB and the Postgres-compatible
+/// drivers share the Postgres syntax.
+fn dialect_for(driver_id: &str) -> EngineResult<SqlDialect> {
+    SqlDialect::from_driver_id(driver_id)
+        .or_else(|| match driver_id {
+            "duckdb" | "cockroachdb" | "neon" | "supabase" | "timescaledb" => {
+                Some(SqlDialect::Postgres)
+            }
+            _ => None,
+        })
+        .ok_or_else(|| EngineError::not_supported("Table copy is not supported by this driver"))
+}
+
+fn qualified_table(
+    dialect: SqlDialect,
+    driver_id: &str,
+    namespace: &Namespace,
+    table: &str,
+) -> String {
+    // DuckDB reads the schema from the database field when none is set.
+    if driver_id == "duckdb" && namespace.schema.is_none() {
+        let namespace = Namespace::with_schema(&namespace.database, &namespace.database);
+        return dialect.qualified_table(&namespace, table);
+    }
+    dialect.qualified_table(namespace, table)
+}
+
+fn create_table_sql(
+    schema: &TableSchema,
+    dialect: SqlDialect,
+    driver_id: &str,
+    qualified: &str,
+) -> EngineResult<String> {
+    let mut definitions = Vec::with_capacity(schema.columns.len() + 1);
+    for column in &schema.columns {
+        let data_type = type_mapper::map_type(&column.data_type, driver_id).ok_or_else(|| {
+            EngineError::not_supported("Cannot create tables on the destination driver")
+        })?;
+        let not_null = if column.nullable { "" } else { " NOT NULL" };
+        definitions.push(format!(
+            "{} {}{}",
+            dialect.quote_ident(&column.name),
+            data_type,
+            not_null
+        ));
+    }
+
+    let primary_key: Vec<String> = schema
+        .columns
+        .iter()
+        .filter(|c| c.is_primary_key)
+        .map(|c| dialect.quote_ident(&c.name))
+        .collect();
+    if !primary_key.is_empty() {
+        definitions.push(format!("PRIMARY KEY ({})", primary_key.join(", ")));
+    }
+
+    Ok(format!(
+        "CREATE TABLE {} ({})",
+        qualified,
+        definitions.join(", ")
+    ))
+}
+
+fn insert_sql(prefix: &str, rows: &[Row], dialect: SqlDialect, driver_id: &str) -> String {
+    let tuples: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let values: Vec<String> = row
+                .values
+                .iter()
+                .map(|value| format_literal(value, dialect, driver_id))
+                .collect();
+            format!("({})", values.join(", "))
+        })
+        .collect();
+    format!("{}{}", prefix, tuples.join(", "))
+}
+
+/// DuckDB follows Postgres except for string escapes: it has no `E''`
+/// literals and reads `\xNN` in a blob string per byte.
+fn format_literal(value: &Value, dialect: SqlDialect, driver_id: &str) -> String {
+    if driver_id != "duckdb" {
+        return dialect.format_value(value);
+    }
+    match value {
+        Value::Text(s) => format!("'{}'", s.replace('\'', "''")),
+        Value::Json(json) => format!("'{}'", json.to_string().replace('\'', "''")),
+        Value::Bytes(bytes) => {
+            let escaped: String = bytes.iter().map(|b| format!("\\x{b:02X}")).collect();
+            format!("'{escaped}'::BLOB")
+        }
+        _ => dialect.format_value(value),
+    }
+}
+
+async fn insert_batch(
+    dest: &dyn DataEngine,
+    session: SessionId,
+    sql: &str,
+    transactional: bool,
+) -> EngineResult<()> {
+    if !transactional {
+        return dest.execute(session, sql, QueryId::new()).await.map(|_| ());
+    }
+
+    dest.begin_transaction(session).await?;
+    match dest.execute(session, sql, QueryId::new()).await {
+        Ok(_) => dest.commit(session).await,
+        Err(e) => {
+            let _ = dest.rollback(session).await;
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::ConnectionConfig;
+    use qore_drivers::drivers::duckdb::DuckDbDriver;
+    use qore_drivers::drivers::sqlite::SqliteDriver;
+
+    async fn sqlite_with_people() -> (Arc<SqliteDriver>, SessionId) {
+        let driver = Arc::new(SqliteDriver::new());
+        let session = driver
+            .connect(&ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE people (id INTEGER PRIMARY KEY, name TEXT NOT NULL, score REAL)",
+            "INSERT INTO people VALUES (1, 'Ada', 9.5), (2, 'O''Brien', NULL), (3, 'Grace', 7.0)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (driver, session)
+    }
+
+    async fn duckdb_names(driver: &DuckDbDriver, session: SessionId) -> Vec<String> {
+        let result = driver
+            .execute(
+                session,
+                "SELECT name FROM main.copied ORDER BY id",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        result
+            .rows
+            .iter()
+            .map(|row| match &row.values[0] {
+                Value::Text(name) => name.clone(),
+                other => panic!("unexpected name {other:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn copies_sqlite_table_into_duckdb() {
+        let (source, source_session) = sqlite_with_people().await;
+        let dest = DuckDbDriver::new();
+        let dest_session = dest
+            .connect(&ConnectionConfig::local_for_tests("duckdb", ":memory:"))
+            .await
+            .unwrap();
+
+        let options = CopyTableOptions {
+            create: true,
+            batch_size: 2,
+        };
+        let report = copy_table(
+            source,
+            source_session,
+            &Namespace::new("main"),
+            "people",
+            &dest,
+            dest_session,
+            &Namespace::new("main"),
+            "copied",
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.rows_copied, 3);
+        assert!(report.error.is_none(), "{:?}", report.error);
+        assert_eq!(
+            duckdb_names(&dest, dest_session).await,
+            ["Ada", "O'Brien", "Grace"]
+        );
+
+        let result = dest
+            .execute(
+                dest_session,
+                "SELECT score FROM main.copied WHERE id = 2",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(result.rows[0].values[0], Value::Null));
+    }
+
+    #[tokio::test]
+    async fn failing_batch_is_rolled_back() {
+        let (source, source_session) = sqlite_with_people().await;
+        let dest = DuckDbDriver::new();
+        let dest_session = dest
+            .connect(&ConnectionConfig::local_for_tests("duckdb", ":memory:"))
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE copied (id INTEGER PRIMARY KEY, name VARCHAR NOT NULL, score DOUBLE)",
+            "INSERT INTO copied VALUES (3, 'Existing', NULL)",
+        ] {
+            dest.execute(dest_session, sql, QueryId::new())
+                .await
+                .unwrap();
+        }
+
+        let options = CopyTableOptions {
+            create: false,
+            batch_size: 2,
+        };
+        let report = copy_table(
+            source,
+            source_session,
+            &Namespace::new("main"),
+            "people",
+            &dest,
+            dest_session,
+            &Namespace::new("main"),
+            "copied",
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.rows_copied, 2);
+        assert!(report.error.is_some());
+        assert_eq!(
+            duckdb_names(&dest, dest_session).await,
+            ["Ada", "O'Brien", "Existing"]
+        );
+    }
+}
@@ -0,0 +1,315 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vault Backup
+//!
+//! Portable export of saved connections together with their keyring
+//! credentials, for moving a setup between machines.
+//!
+//! The blob is `version || salt || nonce || ciphertext`. The payload is sealed
+//! with XChaCha20Poly1305 under an Argon2id key derived from the master
+//! password, the same primitives as `EncryptedFileProvider`. The header is
+//! bound as associated data, so a tampered version or salt fails decryption
+//! instead of being silently reinterpreted.
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    XChaCha20Poly1305, XNonce,
+};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::vault::credentials::SavedConnection;
+use crate::vault::encrypted_file::{derive_key, NONCE_LEN, SALT_LEN};
+use crate::vault::storage::{CredsJson, VaultStorage};
+use qore_core::error::{EngineError, EngineResult};
+
+/// Current blob format. Bump when the payload shape changes and keep
+/// decoding older versions in `import_connections`.
+pub const BACKUP_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 1 + SALT_LEN + NONCE_LEN;
+
+#[derive(Serialize, Deserialize)]
+struct BackupEntry {
+    connection: SavedConnection,
+    credentials: CredsJson,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupPayload {
+    connections: Vec<BackupEntry>,
+}
+
+/// Outcome of `import_connections`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportSummary {
+    pub imported: usize,
+    pub overwritten: usize,
+    pub skipped: usize,
+}
+
+/// Serializes every saved connection of `storage`, credentials included,
+/// into a single encrypted blob.
+pub fn export_connections(storage: &VaultStorage, master_password: &str) -> EngineResult<Vec<u8>> {
+    if master_password.is_empty() {
+        return Err(EngineError::validation("Backup password must not be empty"));
+    }
+
+    let mut entries = Vec::new();
+    for connection in storage.list_connections_full()? {
+        let credentials = storage.get_credentials(&connection.id)?;
+        entries.push(BackupEntry {
+            credentials: CredsJson::from(&credentials),
+            connection,
+        });
+    }
+
+    let plaintext = serde_json::to_vec(&BackupPayload {
+        connections: entries,
+    })
+    .map_err(|e| EngineError::internal(format!("Failed to serialize backup: {e}")))?;
+
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    header.push(BACKUP_VERSION);
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    header.extend_from_slice(&salt);
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    header.extend_from_slice(&nonce);
+
+    let ciphertext = cipher(master_password, &salt)?
+        .encrypt(
+            XNonce::from_slice(&nonce),
+            Payload {
+                msg: &plaintext,
+                aad: &header,
+            },
+        )
+        .map_err(|_| EngineError::internal("Backup encryption failed"))?;
+
+    let mut blob = header;
+    blob.extend_from_slice(&ciphertext);
+    Ok(blob)
+}
+
+/// Restores connections from a blob produced by `export_connections`,
+/// merging by connection id. Existing ids are replaced when `overwrite` is
+/// set and left untouched otherwise. Nothing is written unless the whole
+/// blob decrypts.
+pub fn import_connections(
+    storage: &VaultStorage,
+    blob: &[u8],
+    master_password: &str,
+    overwrite: bool,
+) -> EngineResult<ImportSummary> {
+    if blob.len() < HEADER_LEN {
+        return Err(EngineError::validation("Backup file is truncated"));
+    }
+    let (header, ciphertext) = blob.split_at(HEADER_LEN);
+    if header[0] != BACKUP_VERSION {
+        return Err(EngineError::validation(format!(
+            "Unsupported backup version {}",
+            header[0]
+        )));
+    }
+    let salt = &header[1..1 + SALT_LEN];
+    let nonce = &header[1 + SALT_LEN..];
+
+    let plaintext = cipher(master_password, salt)?
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| {
+            EngineError::auth_failed(
+                "Backup could not be decrypted: wrong master password or corrupted file",
+            )
+        })?;
+    let payload: BackupPayload = serde_json::from_slice(&plaintext)
+        .map_err(|e| EngineError::internal(format!("Failed to parse backup: {e}")))?;
+
+    let existing: Vec<String> = storage
+        .list_connections_full()?
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+
+    let mut summary = ImportSummary::default();
+    for entry in payload.connections {
+        let mut connection = entry.connection;
+        let exists = existing.contains(&connection.id);
+        if exists && !overwrite {
+            summary.skipped += 1;
+            continue;
+        }
+
+        connection.project_id = storage.project_id().to_string();
+        storage.save_connection(&connection, &entry.credentials.into())?;
+        if exists {
+            summary.overwritten += 1;
+        } else {
+            summary.imported += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+fn cipher(master_password: &str, salt: &[u8]) -> EngineResult<XChaCha20Poly1305> {
+    let key = derive_key(master_password, salt)?;
+    XChaCha20Poly1305::new_from_slice(&key)
+        .map_err(|e| EngineError::internal(format!("Cipher init failed: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensitive::Sensitive;
+    use crate::vault::backend::MockProvider;
+    use crate::vault::credentials::{Environment, StoredCredentials};
+    use crate::vault::VaultLock;
+    use tempfile::TempDir;
+
+    const MASTER_PASSWORD: &str = "Hunter2-master!";
+
+    fn storage(dir: &TempDir, project_id: &str) -> VaultStorage {
+        VaultStorage::new(
+            project_id,
+            dir.path().to_path_buf(),
+            Box::new(MockProvider::new()),
+        )
+    }
+
+    fn connection(id: &str, name: &str) -> SavedConnection {
+        SavedConnection {
+            id: id.to_string(),
+            name: name.to_string(),
+            driver: "postgres".to_string(),
+            environment: Environment::Development,
+            read_only: false,
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "qoredb".to_string(),
+            database: Some("testdb".to_string()),
+            ssl: false,
+            ssl_mode: None,
+            ssh_tunnel: None,
+            project_id: "source".to_string(),
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        }
+    }
+
+    fn credentials(password: &str) -> StoredCredentials {
+        StoredCredentials {
+            db_password: Sensitive::new(password.to_string()),
+            ssh_password: None,
+            ssh_key_passphrase: Some(Sensitive::new("key-pass".to_string())),
+            proxy_password: None,
+            extra_secrets: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn export_import_roundtrip() -> EngineResult<()> {
+        let mut lock = VaultLock::new(Box::new(MockProvider::new()));
+        lock.setup_master_password(MASTER_PASSWORD)?;
+
+        let source_dir = TempDir::new().unwrap();
+        let source = storage(&source_dir, "source");
+        source.save_connection(&connection("conn_a", "alpha"), &credentials("secret-a"))?;
+        source.save_connection(&connection("conn_b", "beta"), &credentials("secret-b"))?;
+
+        lock.lock();
+        lock.verify_master_password(MASTER_PASSWORD).await?;
+        let blob = export_connections(&source, MASTER_PASSWORD)?;
+        assert_eq!(blob[0], BACKUP_VERSION);
+
+        let target_dir = TempDir::new().unwrap();
+        let target = storage(&target_dir, "target");
+        let summary = import_connections(&target, &blob, MASTER_PASSWORD, false)?;
+        assert_eq!(summary.imported, 2);
+
+        let restored = target.get_connection("conn_b")?;
+        assert_eq!(restored.name, "beta");
+        assert_eq!(restored.project_id, "target");
+        let creds = target.get_credentials("conn_b")?;
+        assert_eq!(creds.db_password.expose(), "secret-b");
+        assert_eq!(
+            creds
+                .ssh_key_passphrase
+                .as_ref()
+                .map(|s| s.expose().as_str()),
+            Some("key-pass")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn import_rejects_wrong_password() -> EngineResult<()> {
+        let mut lock = VaultLock::new(Box::new(MockProvider::new()));
+        lock.setup_master_password(MASTER_PASSWORD)?;
+        assert!(lock
+            .verify_master_password("Wrong-password-1!")
+            .await
+            .is_err());
+
+        let source_dir = TempDir::new().unwrap();
+        let source = storage(&source_dir, "source");
+        source.save_connection(&connection("conn_a", "alpha"), &credentials("secret-a"))?;
+        let blob = export_connections(&source, MASTER_PASSWORD)?;
+
+        let target_dir = TempDir::new().unwrap();
+        let target = storage(&target_dir, "target");
+        let err = import_connections(&target, &blob, "Wrong-password-1!", true).unwrap_err();
+        assert!(matches!(err, EngineError::AuthenticationFailed { .. }));
+        assert!(target.list_connections_full()?.is_empty());
+
+        let mut tampered = blob.clone();
+        tampered[0] = BACKUP_VERSION + 1;
+        assert!(import_connections(&target, &tampered, MASTER_PASSWORD, true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn import_skips_or_overwrites_duplicates() -> EngineResult<()> {
+        let source_dir = TempDir::new().unwrap();
+        let source = storage(&source_dir, "source");
+        source.save_connection(&connection("conn_a", "from-backup"), &credentials("new"))?;
+        let blob = export_connections(&source, MASTER_PASSWORD)?;
+
+        let target_dir = TempDir::new().unwrap();
+        let target = storage(&target_dir, "target");
+        target.save_connection(&connection("conn_a", "local"), &credentials("old"))?;
+
+        let summary = import_connections(&target, &blob, MASTER_PASSWORD, false)?;
+        assert_eq!((summary.imported, summary.skipped), (0, 1));
+        assert_eq!(target.get_connection("conn_a")?.name, "local");
+
+        let summary = import_connections(&target, &blob, MASTER_PASSWORD, true)?;
+        assert_eq!(summary.overwritten, 1);
+        assert_eq!(target.get_connection("conn_a")?.name, "from-backup");
+        assert_eq!(
+            target.get_credentials("conn_a")?.db_password.expose(),
+            "new"
+        );
+        Ok(())
+    }
+}
@@ -4,10 +4,14 @@
 //!
 //! Represents a saved database connection with credentials.
 
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use qore_core::error::{EngineError, EngineResult};
-use qore_core::types::{ConnectionConfig, MssqlAuthMode, SshTunnelConfig};
+use qore_core::types::{
+    ConnectionConfig, IsolationLevel, MssqlAuthMode, SshTunnelConfig, TlsConfig,
+};
 
 /// Environment classification for connections
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -70,6 +74,33 @@ pub struct SavedConnection {
     /// legacy connections.
     #[serde(default)]
     pub ssl_ca_cert: Option<String>,
+    /// TLS certificates and verification mode. `None` on legacy connections.
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// Driver-specific settings (see `ConnectionConfig::extra_params`).
+    /// `None` on legacy connections. Never holds the [`SECRET_EXTRA_PARAMS`]:
+    /// those are kept in [`StoredCredentials::extra_secrets`].
+    #[serde(
+        default,
+        serialize_with = "serialize_public_params",
+        deserialize_with = "deserialize_public_params"
+    )]
+    pub extra_params: Option<HashMap<String, String>>,
+    /// Default statement timeout applied at session setup (milliseconds).
+    #[serde(default)]
+    pub statement_timeout_ms: Option<u64>,
+    /// Default lock-wait timeout applied at session setup (milliseconds).
+    #[serde(default)]
+    pub lock_timeout_ms: Option<u64>,
+    /// Default transaction isolation level applied at session setup.
+    #[serde(default)]
+    pub default_isolation_level: Option<IsolationLevel>,
+    /// Retry window for servers that are still starting (seconds).
+    #[serde(default)]
+    pub wait_for_ready_secs: Option<u64>,
+    /// Per-query timeout enforced by the driver (seconds).
+    #[serde(default)]
+    pub query_timeout_secs: Option<u64>,
     pub project_id: String,
 }
 
@@ -113,6 +144,52 @@ pub struct ProxyInfo {
 
 use crate::sensitive::Sensitive;
 
+/// `extra_params` keys holding secrets (DuckDB httpfs S3 credentials). They
+/// are stored with the credentials, not in the connection metadata file.
+pub const SECRET_EXTRA_PARAMS: &[&str] = &["s3_secret_access_key", "s3_session_token"];
+
+/// Splits driver settings into the ones saved with the connection metadata
+/// and the secret ones that go to [`StoredCredentials::extra_secrets`].
+pub fn split_extra_params(
+    params: Option<HashMap<String, String>>,
+) -> (
+    Option<HashMap<String, String>>,
+    HashMap<String, Sensitive<String>>,
+) {
+    let Some(params) = params else {
+        return (None, HashMap::new());
+    };
+    let (secrets, public): (HashMap<_, _>, HashMap<_, _>) = params
+        .into_iter()
+        .partition(|(key, _)| SECRET_EXTRA_PARAMS.contains(&key.as_str()));
+    let secrets = secrets
+        .into_iter()
+        .map(|(key, value)| (key, Sensitive::new(value)))
+        .collect();
+    (Some(public), secrets)
+}
+
+fn public_params(params: &HashMap<String, String>) -> HashMap<&String, &String> {
+    params
+        .iter()
+        .filter(|(key, _)| !SECRET_EXTRA_PARAMS.contains(&key.as_str()))
+        .collect()
+}
+
+fn serialize_public_params<S: Serializer>(
+    params: &Option<HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    params.as_ref().map(public_params).serialize(serializer)
+}
+
+fn deserialize_public_params<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, String>>, D::Error> {
+    let params = Option::<HashMap<String, String>>::deserialize(deserializer)?;
+    Ok(split_extra_params(params).0)
+}
+
 /// Credentials stored in the vault (never serialized to frontend)
 #[derive(Debug, Clone)]
 pub struct StoredCredentials {
@@ -120,6 +197,8 @@ pub struct StoredCredentials {
     pub ssh_password: Option<Sensitive<String>>,
     pub ssh_key_passphrase: Option<Sensitive<String>>,
     pub proxy_password: Option<Sensitive<String>>,
+    /// Secret driver settings, by `extra_params` key
+    pub extra_secrets: HashMap<String, Sensitive<String>>,
 }
 
 impl SavedConnection {
@@ -218,6 +297,14 @@ impl SavedConnection {
             None => None,
         };
 
+        let mut extra_params = self.extra_params.clone();
+        if !creds.extra_secrets.is_empty() {
+            let params = extra_params.get_or_insert_with(HashMap::new);
+            for (key, value) in &creds.extra_secrets {
+                params.insert(key.clone(), value.expose().clone());
+            }
+        }
+
         Ok(ConnectionConfig {
             driver: self.driver.clone(),
             host: self.host.clone(),
@@ -238,6 +325,13 @@ impl SavedConnection {
             clickhouse_cluster: self.clickhouse_cluster.clone(),
             search_auth_mode: self.search_auth_mode.clone(),
             ssl_ca_cert: self.ssl_ca_cert.clone(),
+            tls: self.tls.clone(),
+            extra_params,
+            statement_timeout_ms: self.statement_timeout_ms,
+            lock_timeout_ms: self.lock_timeout_ms,
+            default_isolation_level: self.default_isolation_level,
+            wait_for_ready_secs: self.wait_for_ready_secs,
+            query_timeout_secs: self.query_timeout_secs,
         })
     }
 }
@@ -280,6 +374,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
             project_id: "proj".to_string(),
         }
     }
@@ -296,6 +397,7 @@ mod tests {
             ssh_password: Some(Sensitive::new("sshpw".to_string())),
             ssh_key_passphrase: None,
             proxy_password: None,
+            extra_secrets: HashMap::new(),
         };
 
         let config = connection.to_connection_config(&creds)?;
@@ -318,6 +420,7 @@ mod tests {
             ssh_password: None,
             ssh_key_passphrase: Some(Sensitive::new("passphrase".to_string())),
             proxy_password: None,
+            extra_secrets: HashMap::new(),
         };
 
         let config = connection.to_connection_config(&creds)?;
@@ -346,6 +449,7 @@ mod tests {
             ssh_password: Some(Sensitive::new("sshpw".to_string())),
             ssh_key_passphrase: None,
             proxy_password: None,
+            extra_secrets: HashMap::new(),
         };
 
         let err = connection
@@ -362,6 +466,7 @@ mod tests {
             ssh_password: Some(Sensitive::new("sshpw".to_string())),
             ssh_key_passphrase: None,
             proxy_password: None,
+            extra_secrets: HashMap::new(),
         };
 
         let err = connection
@@ -382,6 +487,7 @@ mod tests {
             ssh_password: Some(Sensitive::new("sshpw".to_string())),
             ssh_key_passphrase: None,
             proxy_password: None,
+            extra_secrets: HashMap::new(),
         };
 
         let config = connection.to_connection_config(&creds)?;
@@ -401,6 +507,53 @@ mod tests {
         assert_eq!(parsed.mssql_auth, Some(MssqlAuthMode::WindowsNtlm));
     }
 
+    #[test]
+    fn s3_secrets_live_in_credentials_only() -> EngineResult<()> {
+        let params: HashMap<String, String> = [
+            ("s3_region", "eu-west-1"),
+            ("s3_access_key_id", "AKIA"),
+            ("s3_secret_access_key", "secret"),
+            ("s3_session_token", "token"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let (public, secrets) = split_extra_params(Some(params.clone()));
+
+        let mut connection = base_connection("password", "accept_new");
+        connection.driver = "duckdb".to_string();
+        connection.extra_params = public;
+        let json = serde_json::to_string(&connection).expect("serialize");
+        assert!(json.contains("s3_access_key_id"));
+        assert!(!json.contains("s3_secret_access_key") && !json.contains("secret\""));
+        assert!(!format!("{connection:?}").contains("token"));
+
+        let creds = StoredCredentials {
+            db_password: Sensitive::new(String::new()),
+            ssh_password: Some(Sensitive::new("sshpw".to_string())),
+            ssh_key_passphrase: None,
+            proxy_password: None,
+            extra_secrets: secrets,
+        };
+        let config = connection.to_connection_config(&creds)?;
+        assert_eq!(config.extra_params, Some(params));
+        Ok(())
+    }
+
+    #[test]
+    fn saved_connection_drops_secret_params_from_json() {
+        let mut connection = base_connection("password", "accept_new");
+        connection.extra_params = Some(HashMap::from([(
+            "s3_secret_access_key".to_string(),
+            "secret".to_string(),
+        )]));
+
+        let json = serde_json::to_string(&connection).expect("serialize");
+        assert!(!json.contains("secret\""));
+        let parsed: SavedConnection = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(parsed.extra_params, Some(HashMap::new()));
+    }
+
     #[test]
     fn saved_connection_accepts_legacy_json_without_mssql_auth() {
         let legacy = r#"{
@@ -23,9 +23,9 @@ use serde::{Deserialize, Serialize};
 use crate::vault::backend::{CredentialError, CredentialProvider};
 use qore_core::error::{EngineError, EngineResult};
 
-const SALT_LEN: usize = 16;
+pub(crate) const SALT_LEN: usize = 16;
 const KEY_LEN: usize = 32;
-const NONCE_LEN: usize = 24;
+pub(crate) const NONCE_LEN: usize = 24;
 
 #[derive(Serialize, Deserialize)]
 struct EncEntry {
@@ -182,7 +182,7 @@ impl CredentialProvider for EncryptedFileProvider {
     }
 }
 
-fn derive_key(passphrase: &str, salt: &[u8]) -> EngineResult<[u8; KEY_LEN]> {
+pub(crate) fn derive_key(passphrase: &str, salt: &[u8]) -> EngineResult<[u8; KEY_LEN]> {
     let params = Params::new(64 * 1024, 3, 1, Some(KEY_LEN))
         .map_err(|e| EngineError::internal(format!("Argon2 params: {e}")))?;
     let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
@@ -98,26 +98,46 @@ impl VaultLock {
     /// computed with the OWASP-2024 Argon2id profile (B5-H1).
     pub fn setup_master_password(&mut self, password: &str) -> EngineResult<()> {
         validate_password_strength(password)?;
+        self.store_master_hash(password)?;
+        self.mark_unlocked();
+        Ok(())
+    }
 
-        let salt = SaltString::generate(&mut OsRng);
-        let argon2 = hardened_argon2();
+    /// Replaces the master password. `old` must unlock the vault and `new`
+    /// must pass the strength rules; otherwise nothing is written.
+    ///
+    /// Saved credentials are protected by the provider itself (OS keyring,
+    /// or the `QORE_VAULT_KEY` file), not by a key derived from the master
+    /// password, so rotation only rewrites the stored hash. That is a single
+    /// provider write, committed by rename for the encrypted-file provider,
+    /// so a crash leaves either the old or the new password in place.
+    pub async fn change_master_password(&mut self, old: &str, new: &str) -> EngineResult<()> {
+        validate_password_strength(new)?;
+        if !self.has_master_password()? {
+            return Err(EngineError::validation("No master password is set"));
+        }
+        if !self.unlock(old).await? {
+            return Err(EngineError::auth_failed("Invalid password"));
+        }
 
-        let hash = argon2
+        self.store_master_hash(new)?;
+        self.mark_unlocked();
+        Ok(())
+    }
+
+    /// Hashes `password` with the hardened profile (B5-H1) and stores it as
+    /// the master password.
+    fn store_master_hash(&self, password: &str) -> EngineResult<()> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = hardened_argon2()
             .hash_password(password.as_bytes(), &salt)
             .map_err(|e| EngineError::internal(format!("Hashing error: {}", e)))?
             .to_string();
 
-        // Store the hash
         let (service, key) = self.master_key_params();
-
         self.provider
             .set_password(&service, &key, &hash)
-            .map_err(|e| {
-                EngineError::internal(format!("Failed to store master password: {}", e))
-            })?;
-
-        self.mark_unlocked();
-        Ok(())
+            .map_err(|e| EngineError::internal(format!("Failed to store master password: {}", e)))
     }
 
     /// Attempts to unlock the vault with the given password.
@@ -159,6 +179,21 @@ impl VaultLock {
         }
     }
 
+    /// Confirms `password` is the master password before a sensitive bulk
+    /// operation such as a backup export. Goes through `unlock`, so failures
+    /// count towards the back-off. Vaults without a master password accept
+    /// any input.
+    pub async fn verify_master_password(&mut self, password: &str) -> EngineResult<()> {
+        if !self.has_master_password()? {
+            return Ok(());
+        }
+        if self.unlock(password).await? {
+            Ok(())
+        } else {
+            Err(EngineError::auth_failed("Invalid password"))
+        }
+    }
+
     /// Locks the vault
     pub fn lock(&mut self) {
         self.is_unlocked = false;
@@ -309,6 +344,121 @@ mod tests {
         Ok(())
     }
 
+    const NEW_PASSWORD: &str = "Correct-horse-42";
+
+    #[tokio::test]
+    async fn change_master_password_rotates() -> EngineResult<()> {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let mut lock = VaultLock::new(Box::new(MockProvider::new()));
+        lock.setup_master_password(STRONG_PASSWORD)?;
+
+        lock.change_master_password(STRONG_PASSWORD, NEW_PASSWORD)
+            .await?;
+        assert!(lock.is_unlocked());
+
+        lock.lock();
+        assert!(!lock.unlock(STRONG_PASSWORD).await?);
+        assert!(lock.unlock(NEW_PASSWORD).await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn change_master_password_rejects_wrong_old_password() -> EngineResult<()> {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let provider = MockProvider::new();
+        let mut lock = VaultLock::new(Box::new(provider.clone()));
+        lock.setup_master_password(STRONG_PASSWORD)?;
+        let (service, key) = lock.master_key_params();
+        let hash = provider.get_password(&service, &key)?;
+        lock.lock();
+
+        let err = lock
+            .change_master_password("Wrong-password-1!", NEW_PASSWORD)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, EngineError::AuthenticationFailed { .. }));
+        assert!(lock.is_locked());
+        assert_eq!(provider.get_password(&service, &key)?, hash);
+
+        let err = lock
+            .change_master_password(STRONG_PASSWORD, "short")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("at least"));
+        assert_eq!(provider.get_password(&service, &key)?, hash);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn saved_credentials_survive_rotation() -> EngineResult<()> {
+        use crate::sensitive::Sensitive;
+        use crate::vault::credentials::{Environment, SavedConnection, StoredCredentials};
+        use crate::vault::VaultStorage;
+
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let provider = MockProvider::new();
+        let dir = tempfile::TempDir::new().unwrap();
+        let storage = VaultStorage::new(
+            "rotation",
+            dir.path().to_path_buf(),
+            Box::new(provider.clone()),
+        );
+        let mut lock = VaultLock::new(Box::new(provider));
+        lock.setup_master_password(STRONG_PASSWORD)?;
+
+        let connection = SavedConnection {
+            id: "conn_a".to_string(),
+            name: "alpha".to_string(),
+            driver: "postgres".to_string(),
+            environment: Environment::Development,
+            read_only: false,
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "qoredb".to_string(),
+            database: Some("testdb".to_string()),
+            ssl: false,
+            ssl_mode: None,
+            ssh_tunnel: None,
+            project_id: "rotation".to_string(),
+            pool_acquire_timeout_secs: None,
+            pool_max_connections: None,
+            pool_min_connections: None,
+            proxy: None,
+            mssql_auth: None,
+            clickhouse_cluster: None,
+            search_auth_mode: None,
+            ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
+        };
+        storage.save_connection(
+            &connection,
+            &StoredCredentials {
+                db_password: Sensitive::new("db-secret".to_string()),
+                ssh_password: None,
+                ssh_key_passphrase: None,
+                proxy_password: None,
+                extra_secrets: Default::default(),
+            },
+        )?;
+
+        lock.change_master_password(STRONG_PASSWORD, NEW_PASSWORD)
+            .await?;
+
+        let creds = storage.get_credentials("conn_a")?;
+        assert_eq!(creds.db_password.expose(), "db-secret");
+        let config = storage
+            .get_connection("conn_a")?
+            .to_connection_config(&creds)?;
+        assert_eq!(config.password, "db-secret");
+        Ok(())
+    }
+
     #[test]
     fn setup_rejects_short_password() {
         let _guard = env_lock().lock().expect("env lock poisoned");
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod backend;
+pub mod backup;
 pub mod credentials;
 pub mod encrypted_file;
 pub mod lock;
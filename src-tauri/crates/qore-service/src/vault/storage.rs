@@ -7,7 +7,7 @@
 
 use crate::vault::backend::CredentialProvider;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use uuid::Uuid;
@@ -40,6 +40,10 @@ impl VaultStorage {
         }
     }
 
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+
     /// Gets the keyring service name for this project
     fn service_name(&self) -> String {
         format!("{}_{}", SERVICE_PREFIX, self.project_id)
@@ -115,22 +119,8 @@ impl VaultStorage {
 
         let service = self.service_name();
 
-        let creds_json = serde_json::to_string(&CredsJson {
-            db_password: credentials.db_password.expose().clone(),
-            ssh_password: credentials
-                .ssh_password
-                .as_ref()
-                .map(|s| s.expose().clone()),
-            ssh_key_passphrase: credentials
-                .ssh_key_passphrase
-                .as_ref()
-                .map(|s| s.expose().clone()),
-            proxy_password: credentials
-                .proxy_password
-                .as_ref()
-                .map(|s| s.expose().clone()),
-        })
-        .map_err(|e| EngineError::internal(format!("Serialization error: {}", e)))?;
+        let creds_json = serde_json::to_string(&CredsJson::from(credentials))
+            .map_err(|e| EngineError::internal(format!("Serialization error: {}", e)))?;
 
         self.provider
             .set_password(&service, &self.credentials_key(&connection.id), &creds_json)
@@ -159,12 +149,7 @@ impl VaultStorage {
         let creds: CredsJson = serde_json::from_str(&creds_json)
             .map_err(|e| EngineError::internal(format!("Deserialization error: {}", e)))?;
 
-        Ok(StoredCredentials {
-            db_password: Sensitive::new(creds.db_password),
-            ssh_password: creds.ssh_password.map(Sensitive::new),
-            ssh_key_passphrase: creds.ssh_key_passphrase.map(Sensitive::new),
-            proxy_password: creds.proxy_password.map(Sensitive::new),
-        })
+        Ok(creds.into())
     }
 
     /// Deletes a saved connection
@@ -238,13 +223,57 @@ fn make_copy_name(base_name: &str, existing_names: &HashSet<String>) -> String {
     }
 }
 
+/// Plain-text credential shape persisted in the keyring and in backups.
 #[derive(Serialize, Deserialize)]
-struct CredsJson {
+pub(crate) struct CredsJson {
     db_password: String,
     ssh_password: Option<String>,
     ssh_key_passphrase: Option<String>,
     #[serde(default)]
     proxy_password: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    extra_secrets: HashMap<String, String>,
+}
+
+impl From<&StoredCredentials> for CredsJson {
+    fn from(credentials: &StoredCredentials) -> Self {
+        Self {
+            db_password: credentials.db_password.expose().clone(),
+            ssh_password: credentials
+                .ssh_password
+                .as_ref()
+                .map(|s| s.expose().clone()),
+            ssh_key_passphrase: credentials
+                .ssh_key_passphrase
+                .as_ref()
+                .map(|s| s.expose().clone()),
+            proxy_password: credentials
+                .proxy_password
+                .as_ref()
+                .map(|s| s.expose().clone()),
+            extra_secrets: credentials
+                .extra_secrets
+                .iter()
+                .map(|(key, value)| (key.clone(), value.expose().clone()))
+                .collect(),
+        }
+    }
+}
+
+impl From<CredsJson> for StoredCredentials {
+    fn from(creds: CredsJson) -> Self {
+        Self {
+            db_password: Sensitive::new(creds.db_password),
+            ssh_password: creds.ssh_password.map(Sensitive::new),
+            ssh_key_passphrase: creds.ssh_key_passphrase.map(Sensitive::new),
+            proxy_password: creds.proxy_password.map(Sensitive::new),
+            extra_secrets: creds
+                .extra_secrets
+                .into_iter()
+                .map(|(key, value)| (key, Sensitive::new(value)))
+                .collect(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -301,6 +330,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
         };
 
         let credentials = StoredCredentials {
@@ -308,6 +344,7 @@ mod tests {
             ssh_password: Some(Sensitive::new("ssh_secret".to_string())),
             ssh_key_passphrase: None,
             proxy_password: None,
+            extra_secrets: Default::default(),
         };
 
         storage.save_connection(&connection, &credentials)?;
@@ -2,6 +2,8 @@
 
 pub mod store;
 pub mod types;
+pub mod validation;
 
 pub use store::VirtualRelationStore;
 pub use types::*;
+pub use validation::check_relation;
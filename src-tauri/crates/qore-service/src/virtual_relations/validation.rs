@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Virtual Relation Validation
+//!
+//! Checks a relation against the live schema before it is stored, so a typo
+//! or a renamed column is reported when the relation is saved rather than
+//! surfacing later as a broken foreign key peek.
+
+use qore_core::{EngineError, EngineResult, Namespace, SessionId, TableSchema};
+use qore_drivers::session_manager::SessionManager;
+
+use super::types::VirtualRelation;
+use crate::error::ServiceError;
+
+/// Validates `relation` on `session` unless `force` is set. `force` exists for
+/// relations whose referenced database is not reachable from the session
+/// (e.g. cross-database links to a server that is not connected).
+pub async fn check_relation(
+    session_manager: &SessionManager,
+    session: Option<SessionId>,
+    relation: &VirtualRelation,
+    force: bool,
+) -> Result<(), ServiceError> {
+    if force {
+        return Ok(());
+    }
+    let session = session.ok_or_else(|| {
+        ServiceError::Message(
+            "A connected session is required to validate the relation; save with force to skip validation"
+                .to_string(),
+        )
+    })?;
+    let driver = session_manager.get_driver(session).await?;
+
+    let source_namespace = Namespace {
+        database: relation.source_database.clone(),
+        schema: relation.source_schema.clone(),
+    };
+    let referenced_namespace = Namespace {
+        database: relation
+            .referenced_database
+            .clone()
+            .unwrap_or_else(|| relation.source_database.clone()),
+        schema: relation
+            .referenced_schema
+            .clone()
+            .or_else(|| relation.source_schema.clone()),
+    };
+
+    let source = driver
+        .describe_table(session, &source_namespace, &relation.source_table)
+        .await?;
+    let referenced = driver
+        .describe_table(session, &referenced_namespace, &relation.referenced_table)
+        .await?;
+
+    validate_columns(relation, &source, &referenced).map_err(ServiceError::from)
+}
+
+/// Confirms both columns exist and that their types can be compared.
+pub fn validate_columns(
+    relation: &VirtualRelation,
+    source: &TableSchema,
+    referenced: &TableSchema,
+) -> EngineResult<()> {
+    let source_type = column_type(source, &relation.source_table, &relation.source_column);
+    let referenced_type = column_type(
+        referenced,
+        &relation.referenced_table,
+        &relation.referenced_column,
+    );
+
+    let (source_type, referenced_type) = match (source_type, referenced_type) {
+        (Ok(source_type), Ok(referenced_type)) => (source_type, referenced_type),
+        (source_type, referenced_type) => {
+            let missing: Vec<String> = [source_type.err(), referenced_type.err()]
+                .into_iter()
+                .flatten()
+                .collect();
+            return Err(EngineError::validation(missing.join("; ")));
+        }
+    };
+
+    match (type_family(source_type), type_family(referenced_type)) {
+        (Some(a), Some(b)) if a != b => Err(EngineError::validation(format!(
+            "Column types are incompatible: {}.{} is {} but {}.{} is {}",
+            relation.source_table,
+            relation.source_column,
+            source_type,
+            relation.referenced_table,
+            relation.referenced_column,
+            referenced_type
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn column_type<'a>(schema: &'a TableSchema, table: &str, column: &str) -> Result<&'a str, String> {
+    if schema.columns.is_empty() {
+        return Err(format!("Table '{table}' does not exist"));
+    }
+    schema
+        .columns
+        .iter()
+        .find(|c| c.name == column)
+        .map(|c| c.data_type.as_str())
+        .ok_or_else(|| format!("Column '{column}' does not exist in table '{table}'"))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum TypeFamily {
+    Numeric,
+    Text,
+    Uuid,
+    Temporal,
+    Boolean,
+    Binary,
+}
+
+/// Coarse type family used to reject obviously mismatched relations.
+/// Unrecognised types return `None` and are accepted.
+fn type_family(data_type: &str) -> Option<TypeFamily> {
+    let lower = data_type.to_ascii_lowercase();
+    let base = lower
+        .split('(')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .trim_end_matches(" unsigned");
+
+    match base {
+        "int" | "integer" | "smallint" | "bigint" | "tinyint" | "mediumint" | "int2" | "int4"
+        | "int8" | "serial" | "smallserial" | "bigserial" | "decimal" | "numeric" | "number"
+        | "real" | "float" | "float4" | "float8" | "double" | "double precision" | "money" => {
+            Some(TypeFamily::Numeric)
+        }
+        "char" | "character" | "varchar" | "character varying" | "text" | "nchar" | "nvarchar"
+        | "ntext" | "bpchar" | "citext" | "string" | "tinytext" | "mediumtext" | "longtext"
+        | "varchar2" | "nvarchar2" => Some(TypeFamily::Text),
+        "uuid" | "uniqueidentifier" => Some(TypeFamily::Uuid),
+        "date"
+        | "datetime"
+        | "datetime2"
+        | "datetimeoffset"
+        | "smalldatetime"
+        | "timestamptz"
+        | "timestamp"
+        | "timestamp without time zone"
+        | "timestamp with time zone" => Some(TypeFamily::Temporal),
+        "bool" | "boolean" | "bit" => Some(TypeFamily::Boolean),
+        "bytea" | "blob" | "binary" | "varbinary" | "image" => Some(TypeFamily::Binary),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qore_core::{ConnectionConfig, DataEngine, QueryId};
+    use qore_drivers::drivers::sqlite::SqliteDriver;
+
+    async fn seeded_sqlite() -> (SqliteDriver, SessionId) {
+        let driver = SqliteDriver::new();
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        for sql in [
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT)",
+            "CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER, note TEXT)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (driver, session)
+    }
+
+    fn relation(source_column: &str, referenced_column: &str) -> VirtualRelation {
+        VirtualRelation {
+            id: "vr_1".to_string(),
+            source_database: "main".to_string(),
+            source_schema: None,
+            source_table: "orders".to_string(),
+            source_column: source_column.to_string(),
+            referenced_table: "users".to_string(),
+            referenced_column: referenced_column.to_string(),
+            referenced_schema: None,
+            referenced_database: None,
+            label: None,
+        }
+    }
+
+    async fn validate(
+        driver: &SqliteDriver,
+        session: SessionId,
+        relation: &VirtualRelation,
+    ) -> EngineResult<()> {
+        let namespace = Namespace::new("main");
+        let source = driver
+            .describe_table(session, &namespace, &relation.source_table)
+            .await?;
+        let referenced = driver
+            .describe_table(session, &namespace, &relation.referenced_table)
+            .await?;
+        validate_columns(relation, &source, &referenced)
+    }
+
+    #[tokio::test]
+    async fn accepts_existing_compatible_columns() {
+        let (driver, session) = seeded_sqlite().await;
+        validate(&driver, session, &relation("user_id", "id"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_column() {
+        let (driver, session) = seeded_sqlite().await;
+        let err = validate(&driver, session, &relation("user_id", "user_id"))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Column 'user_id' does not exist in table 'users'"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_incompatible_types() {
+        let (driver, session) = seeded_sqlite().await;
+        let err = validate(&driver, session, &relation("note", "id"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("incompatible"), "{err}");
+    }
+}
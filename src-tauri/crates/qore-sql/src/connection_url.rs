@@ -2,7 +2,14 @@
 
 //! Connection URL / DSN parsing into normalized `PartialConnectionConfig`.
 //! Supports PostgreSQL, MySQL, MongoDB, Redis, SQL Server, CockroachDB.
-
+//! `build_url` goes the other way, from a `ConnectionConfig` to a URL.
+//!
+//! An SSH jump host may be given with the `sshHost`, `sshUser`, `sshPort` and
+//! `sshIdentityFile` query parameters on any scheme whose parser lists it in
+//! `tunnel_schemes`.
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use qore_core::{ConnectionConfig, SshAuth, SshHostKeyPolicy, SshTunnelConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use url::Url;
@@ -19,6 +26,9 @@ pub struct PartialConnectionConfig {
     pub database: Option<String>,
     pub ssl: Option<bool>,
     pub options: HashMap<String, String>,
+    /// SSH tunnel from the `ssh*` query parameters
+    #[serde(default)]
+    pub ssh_tunnel: Option<SshTunnelConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +71,11 @@ pub trait ConnectionUrlParser: Send + Sync {
     fn schemes(&self) -> &[&str];
     fn default_port(&self) -> u16;
     fn parse(&self, url: &Url) -> ParseResult<PartialConnectionConfig>;
+
+    /// Schemes that can be reached through an SSH tunnel.
+    fn tunnel_schemes(&self) -> &[&str] {
+        self.schemes()
+    }
 }
 
 pub struct PostgresUrlParser;
@@ -138,6 +153,12 @@ impl ConnectionUrlParser for MongoDbUrlParser {
         27017
     }
 
+    /// SRV lookups resolve to several hosts, which a single forwarded port
+    /// cannot cover.
+    fn tunnel_schemes(&self) -> &[&str] {
+        &["mongodb"]
+    }
+
     fn parse(&self, url: &Url) -> ParseResult<PartialConnectionConfig> {
         let is_srv = url.scheme() == "mongodb+srv";
 
@@ -362,7 +383,18 @@ impl ConnectionUrlParserRegistry {
             )
         })?;
 
-        parser.parse(&url)
+        let mut config = parser.parse(&url)?;
+        config
+            .options
+            .retain(|key, _| !is_ssh_query_key(&key.to_ascii_lowercase()));
+        if parser
+            .tunnel_schemes()
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(scheme))
+        {
+            config.ssh_tunnel = extract_ssh_tunnel(&url)?;
+        }
+        Ok(config)
     }
 
     pub fn supported_schemes(&self) -> Vec<&str> {
@@ -371,6 +403,29 @@ impl ConnectionUrlParserRegistry {
             .flat_map(|p| p.schemes().iter().copied())
             .collect()
     }
+
+    /// Every supported scheme with its driver and whether the `ssh*` query
+    /// parameters are honoured for it.
+    pub fn scheme_details(&self) -> Vec<UrlSchemeInfo> {
+        self.parsers
+            .iter()
+            .flat_map(|p| {
+                p.schemes().iter().map(|scheme| UrlSchemeInfo {
+                    scheme: scheme.to_string(),
+                    driver: p.driver_id().to_string(),
+                    supports_ssh_tunnel: p.tunnel_schemes().contains(scheme),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A URL scheme accepted by `parse_connection_url`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UrlSchemeInfo {
+    pub scheme: String,
+    pub driver: String,
+    pub supports_ssh_tunnel: bool,
 }
 
 fn percent_decode(s: &str) -> Result<String, std::str::Utf8Error> {
@@ -391,6 +446,56 @@ fn is_ssl_query_key(lower_key: &str) -> bool {
     lower_key.starts_with("ssl") || lower_key.starts_with("tls")
 }
 
+fn is_ssh_query_key(lower_key: &str) -> bool {
+    matches!(
+        lower_key,
+        "sshhost" | "sshuser" | "sshport" | "sshidentityfile"
+    )
+}
+
+/// Builds the SSH tunnel from the `ssh*` query parameters. Without `sshHost`
+/// there is no tunnel; the other parameters fall back to port 22, an empty
+/// user and key authentication with no key path, for the form to complete.
+fn extract_ssh_tunnel(url: &Url) -> ParseResult<Option<SshTunnelConfig>> {
+    let mut host = None;
+    let mut username = String::new();
+    let mut port = 22;
+    let mut identity_file = String::new();
+
+    for (key, value) in url.query_pairs() {
+        match key.to_ascii_lowercase().as_str() {
+            "sshhost" => host = Some(value.into_owned()).filter(|h| !h.is_empty()),
+            "sshuser" => username = value.into_owned(),
+            "sshport" => {
+                port = value.trim().parse().map_err(|_| {
+                    ParseError::new(
+                        ParseErrorCode::InvalidPort,
+                        format!("Invalid SSH port '{}'", value),
+                    )
+                })?;
+            }
+            "sshidentityfile" => identity_file = value.into_owned(),
+            _ => {}
+        }
+    }
+
+    Ok(host.map(|host| SshTunnelConfig {
+        host,
+        port,
+        username,
+        auth: SshAuth::Key {
+            private_key_path: identity_file,
+            passphrase: None,
+        },
+        host_key_policy: SshHostKeyPolicy::AcceptNew,
+        known_hosts_path: None,
+        proxy_jump: None,
+        connect_timeout_secs: 10,
+        keepalive_interval_secs: 30,
+        keepalive_count_max: 3,
+    }))
+}
+
 struct BaseConfig {
     host: Option<String>,
     port: Option<u16>,
@@ -415,6 +520,7 @@ impl BaseConfig {
             database: self.database,
             ssl,
             options,
+            ssh_tunnel: None,
         }
     }
 }
@@ -428,7 +534,10 @@ fn extract_base(
     let host = url.host_str().filter(|h| !h.is_empty()).map(String::from);
 
     if host.is_none() {
-        return Err(ParseError::new(ParseErrorCode::MissingHost, missing_host_msg));
+        return Err(ParseError::new(
+            ParseErrorCode::MissingHost,
+            missing_host_msg,
+        ));
     }
 
     let port = url.port().or(Some(default_port));
@@ -445,9 +554,7 @@ fn extract_base(
         .password()
         .map(percent_decode)
         .transpose()
-        .map_err(|_| {
-            ParseError::new(ParseErrorCode::InvalidUtf8, "Invalid password encoding")
-        })?;
+        .map_err(|_| ParseError::new(ParseErrorCode::InvalidUtf8, "Invalid password encoding"))?;
 
     let database = if decode_database {
         url.path()
@@ -539,6 +646,95 @@ pub fn parse_connection_url(url: &str) -> ParseResult<PartialConnectionConfig> {
     registry.parse(url)
 }
 
+/// Characters left as-is in URL userinfo and path segments (RFC 3986 unreserved).
+const URL_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// How the password appears in a URL produced by `build_url`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlPassword<'a> {
+    /// No password in the URL.
+    Omit,
+    /// The real password, percent-encoded.
+    Plain(&'a str),
+    /// Inserted verbatim, e.g. `${DB_PASSWORD}` for shell/dotenv expansion.
+    Placeholder(&'a str),
+}
+
+/// Builds a connection URL for `config`, the inverse of `parse_connection_url`.
+/// Returns `None` for drivers without a conventional URL form.
+pub fn build_url(config: &ConnectionConfig, password: UrlPassword<'_>) -> Option<String> {
+    let scheme = match config.driver.as_str() {
+        "postgres" | "cockroachdb" | "neon" | "timescaledb" | "supabase" => "postgresql",
+        "mysql" | "mariadb" => "mysql",
+        "sqlserver" => "sqlserver",
+        "mongodb" => "mongodb",
+        "redis" if config.ssl => "rediss",
+        "redis" => "redis",
+        "clickhouse" => "clickhouse",
+        "elasticsearch" | "opensearch" if config.ssl => "https",
+        "elasticsearch" | "opensearch" => "http",
+        // File-backed engines keep the path in `host`.
+        "sqlite" | "duckdb" => return Some(format!("{}://{}", config.driver, config.host)),
+        _ => return None,
+    };
+
+    let mut url = format!("{scheme}://");
+    if !config.username.is_empty() {
+        url.push_str(&encode_component(&config.username));
+        match password {
+            UrlPassword::Omit => {}
+            UrlPassword::Plain("") => {}
+            UrlPassword::Plain(password) => {
+                url.push(':');
+                url.push_str(&encode_component(password));
+            }
+            UrlPassword::Placeholder(placeholder) => {
+                url.push(':');
+                url.push_str(placeholder);
+            }
+        }
+        url.push('@');
+    }
+
+    if config.host.contains(':') && !config.host.starts_with('[') {
+        url.push_str(&format!("[{}]", config.host));
+    } else {
+        url.push_str(&config.host);
+    }
+    if config.port != 0 {
+        url.push_str(&format!(":{}", config.port));
+    }
+    if let Some(database) = config.database.as_deref().filter(|db| !db.is_empty()) {
+        url.push('/');
+        url.push_str(&encode_component(database));
+    }
+
+    let ssl_param = match scheme {
+        "postgresql" => config
+            .ssl_mode
+            .clone()
+            .or_else(|| config.ssl.then(|| "require".to_string()))
+            .map(|mode| ("sslmode", mode)),
+        "mysql" => config.ssl.then(|| ("ssl-mode", "REQUIRED".to_string())),
+        "sqlserver" => config.ssl.then(|| ("encrypt", "true".to_string())),
+        "mongodb" => config.ssl.then(|| ("tls", "true".to_string())),
+        _ => None,
+    };
+    if let Some((key, value)) = ssl_param {
+        url.push_str(&format!("?{key}={}", encode_component(&value)));
+    }
+
+    Some(url)
+}
+
+fn encode_component(value: &str) -> String {
+    utf8_percent_encode(value, URL_COMPONENT).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -831,4 +1027,56 @@ mod tests {
             Some(&"10".to_string())
         );
     }
+
+    #[test]
+    fn test_ssh_tunnel_full_params() {
+        let result = parse_connection_url(
+            "postgres://app@db.internal/mydb?sshHost=bastion&sshUser=deploy&sshPort=2222&sshIdentityFile=%2Fhome%2Fdeploy%2F.ssh%2Fid_ed25519&application_name=qoredb",
+        )
+        .unwrap();
+        let ssh = result.ssh_tunnel.expect("tunnel should be parsed");
+        assert_eq!(ssh.host, "bastion");
+        assert_eq!(ssh.username, "deploy");
+        assert_eq!(ssh.port, 2222);
+        match ssh.auth {
+            SshAuth::Key {
+                private_key_path, ..
+            } => assert_eq!(private_key_path, "/home/deploy/.ssh/id_ed25519"),
+            other => panic!("unexpected auth {other:?}"),
+        }
+        assert_eq!(result.host.as_deref(), Some("db.internal"));
+        assert!(!result.options.contains_key("sshHost"));
+        assert_eq!(
+            result.options.get("application_name"),
+            Some(&"qoredb".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ssh_tunnel_host_only_defaults_port() {
+        let result =
+            parse_connection_url("mysql://root@localhost/shop?sshHost=bastion&sshFoo=bar").unwrap();
+        let ssh = result.ssh_tunnel.expect("tunnel should be parsed");
+        assert_eq!(ssh.host, "bastion");
+        assert_eq!(ssh.port, 22);
+        assert_eq!(ssh.username, "");
+        assert_eq!(result.options.get("sshFoo"), Some(&"bar".to_string()));
+    }
+
+    #[test]
+    fn test_ssh_tunnel_absent() {
+        let result = parse_connection_url("postgres://user@localhost/mydb").unwrap();
+        assert!(result.ssh_tunnel.is_none());
+
+        let srv =
+            parse_connection_url("mongodb+srv://cluster.example.com/db?sshHost=bastion").unwrap();
+        assert!(srv.ssh_tunnel.is_none());
+    }
+
+    #[test]
+    fn test_ssh_tunnel_invalid_port() {
+        let err = parse_connection_url("postgres://localhost/db?sshHost=bastion&sshPort=abc")
+            .unwrap_err();
+        assert_eq!(err.code, ParseErrorCode::InvalidPort);
+    }
 }
@@ -6,7 +6,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use qore_core::{Namespace, RowData, Value};
+use qore_core::{
+    EngineError, EngineResult, IndexSpec, JsonPathSegment, Namespace, RowData, SortDirection,
+    TableQueryOptions, Value,
+};
 
 /// Type of sandbox change operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,17 +61,7 @@ impl SqlDialect {
 
     /// Quote an identifier per the dialect's escaping rules.
     pub fn quote_ident(&self, name: &str) -> String {
-        match self {
-            SqlDialect::Postgres | SqlDialect::Sqlite => {
-                format!("\"{}\"", name.replace('"', "\"\""))
-            }
-            SqlDialect::MySql => {
-                format!("`{}`", name.replace('`', "``"))
-            }
-            SqlDialect::SqlServer => {
-                format!("[{}]", name.replace(']', "]]"))
-            }
-        }
+        crate::safety::quote_ident(*self, name)
     }
 
     /// Format a fully-qualified table name (schema.table or database.table).
@@ -222,6 +215,36 @@ impl SqlDialect {
         }
     }
 
+    /// Expression extracting `path` from the JSON column `column` (already
+    /// quoted) as a scalar. Parenthesized so callers can append casts and
+    /// operators. Path keys are restricted by `ComputedColumn::path_segments`
+    /// and need no escaping.
+    pub fn json_extract(&self, column: &str, path: &[JsonPathSegment]) -> String {
+        match self {
+            SqlDialect::Postgres => {
+                let mut expr = column.to_string();
+                for (i, segment) in path.iter().enumerate() {
+                    let op = if i + 1 == path.len() { "->>" } else { "->" };
+                    match segment {
+                        JsonPathSegment::Key(key) => expr.push_str(&format!("{op}'{key}'")),
+                        JsonPathSegment::Index(index) => expr.push_str(&format!("{op}{index}")),
+                    }
+                }
+                format!("({expr})")
+            }
+            SqlDialect::MySql => format!(
+                "(JSON_UNQUOTE(JSON_EXTRACT({column}, '{}')))",
+                json_path_literal(path)
+            ),
+            SqlDialect::Sqlite => {
+                format!("(json_extract({column}, '{}'))", json_path_literal(path))
+            }
+            SqlDialect::SqlServer => {
+                format!("(JSON_VALUE({column}, '{}'))", json_path_literal(path))
+            }
+        }
+    }
+
     pub fn terminator(&self) -> &'static str {
         ";"
     }
@@ -263,25 +286,126 @@ pub fn generate_update(
     primary_key: &RowData,
     data: &HashMap<String, Value>,
 ) -> Result<String, String> {
-    if primary_key.columns.is_empty() {
-        return Err("Cannot generate UPDATE without primary key".to_string());
+    let (table, set_clause, where_clause) =
+        update_parts(dialect, namespace, table_name, primary_key, data)?;
+    Ok(format!(
+        "UPDATE {} SET {} WHERE {}",
+        table, set_clause, where_clause
+    ))
+}
+
+/// `generate_update` that also returns the updated rows: `RETURNING *` on
+/// PostgreSQL/SQLite, `OUTPUT inserted.*` on SQL Server. MySQL has no
+/// equivalent; callers re-select the row by primary key instead.
+pub fn generate_update_returning(
+    dialect: SqlDialect,
+    namespace: &Namespace,
+    table_name: &str,
+    primary_key: &RowData,
+    data: &HashMap<String, Value>,
+) -> Result<String, String> {
+    let (table, set_clause, where_clause) =
+        update_parts(dialect, namespace, table_name, primary_key, data)?;
+    match dialect {
+        SqlDialect::Postgres | SqlDialect::Sqlite => Ok(format!(
+            "UPDATE {} SET {} WHERE {} RETURNING *",
+            table, set_clause, where_clause
+        )),
+        SqlDialect::SqlServer => Ok(format!(
+            "UPDATE {} SET {} OUTPUT inserted.* WHERE {}",
+            table, set_clause, where_clause
+        )),
+        SqlDialect::MySql => Err(RETURNING_UNSUPPORTED.to_string()),
     }
+}
 
-    let table = dialect.qualified_table(namespace, table_name);
+pub fn generate_delete(
+    dialect: SqlDialect,
+    namespace: &Namespace,
+    table_name: &str,
+    primary_key: &RowData,
+) -> Result<String, String> {
+    let (table, where_clause) = delete_parts(dialect, namespace, table_name, primary_key)?;
+    Ok(format!("DELETE FROM {} WHERE {}", table, where_clause))
+}
 
-    let set_parts: Vec<String> = data
+/// `generate_delete` that also returns the deleted rows: `RETURNING *` on
+/// PostgreSQL/SQLite, `OUTPUT deleted.*` on SQL Server. MySQL has no
+/// equivalent; callers select the row by primary key before deleting.
+pub fn generate_delete_returning(
+    dialect: SqlDialect,
+    namespace: &Namespace,
+    table_name: &str,
+    primary_key: &RowData,
+) -> Result<String, String> {
+    let (table, where_clause) = delete_parts(dialect, namespace, table_name, primary_key)?;
+    match dialect {
+        SqlDialect::Postgres | SqlDialect::Sqlite => Ok(format!(
+            "DELETE FROM {} WHERE {} RETURNING *",
+            table, where_clause
+        )),
+        SqlDialect::SqlServer => Ok(format!(
+            "DELETE FROM {} OUTPUT deleted.* WHERE {}",
+            table, where_clause
+        )),
+        SqlDialect::MySql => Err(RETURNING_UNSUPPORTED.to_string()),
+    }
+}
+
+/// `CREATE [UNIQUE] INDEX` for `spec`. The index lands in the table's schema
+/// on PostgreSQL / SQL Server. SQLite names the index, not the table, with
+/// the schema, so `namespace.schema` there is the attached database alias.
+pub fn generate_create_index(
+    dialect: SqlDialect,
+    namespace: &Namespace,
+    table_name: &str,
+    spec: &IndexSpec,
+) -> String {
+    let index_name = match (dialect, &namespace.schema) {
+        (SqlDialect::Sqlite, Some(schema)) => format!(
+            "{}.{}",
+            dialect.quote_ident(schema),
+            dialect.quote_ident(&spec.name)
+        ),
+        _ => dialect.quote_ident(&spec.name),
+    };
+    let columns: Vec<String> = spec
+        .columns
         .iter()
-        .map(|(col, val)| {
-            format!(
-                "{} = {}",
-                dialect.quote_ident(col),
-                dialect.format_value(val)
-            )
+        .map(|c| {
+            let direction = match c.direction {
+                SortDirection::Asc => "ASC",
+                SortDirection::Desc => "DESC",
+            };
+            format!("{} {}", dialect.quote_ident(&c.name), direction)
         })
         .collect();
 
-    let where_parts: Vec<String> = primary_key
-        .columns
+    format!(
+        "CREATE {}INDEX {} ON {} ({})",
+        if spec.unique { "UNIQUE " } else { "" },
+        index_name,
+        dialect.qualified_table(namespace, table_name),
+        columns.join(", ")
+    )
+}
+
+const RETURNING_UNSUPPORTED: &str = "MySQL does not support RETURNING";
+
+fn update_parts(
+    dialect: SqlDialect,
+    namespace: &Namespace,
+    table_name: &str,
+    primary_key: &RowData,
+    data: &HashMap<String, Value>,
+) -> Result<(String, String, String), String> {
+    if primary_key.columns.is_empty() {
+        return Err("Cannot generate UPDATE without primary key".to_string());
+    }
+
+    let table = dialect.qualified_table(namespace, table_name);
+
+    let set_parts: Vec<String> = data
         .iter()
         .map(|(col, val)| {
             format!(
@@ -292,26 +416,28 @@ pub fn generate_update(
         })
         .collect();
 
-    Ok(format!(
-        "UPDATE {} SET {} WHERE {}",
+    Ok((
         table,
         set_parts.join(", "),
-        where_parts.join(" AND ")
+        pk_predicate(dialect, primary_key),
     ))
 }
 
-pub fn generate_delete(
+fn delete_parts(
     dialect: SqlDialect,
     namespace: &Namespace,
     table_name: &str,
     primary_key: &RowData,
-) -> Result<String, String> {
+) -> Result<(String, String), String> {
     if primary_key.columns.is_empty() {
         return Err("Cannot generate DELETE without primary key".to_string());
     }
 
     let table = dialect.qualified_table(namespace, table_name);
+    Ok((table, pk_predicate(dialect, primary_key)))
+}
 
+fn pk_predicate(dialect: SqlDialect, primary_key: &RowData) -> String {
     let where_parts: Vec<String> = primary_key
         .columns
         .iter()
@@ -323,12 +449,147 @@ pub fn generate_delete(
             )
         })
         .collect();
+    where_parts.join(" AND ")
+}
 
-    Ok(format!(
-        "DELETE FROM {} WHERE {}",
-        table,
-        where_parts.join(" AND ")
-    ))
+/// Aggregate query behind `DataEngine::column_stats`. Returns one row with
+/// `total_rows`, `null_count`, `distinct_count`, `min_value`, `max_value` and
+/// `avg_value`, which is NULL unless `numeric`.
+pub fn generate_column_stats(
+    dialect: SqlDialect,
+    namespace: &Namespace,
+    table_name: &str,
+    column: &str,
+    numeric: bool,
+) -> String {
+    let col = dialect.quote_ident(column);
+    let null_count = match dialect {
+        SqlDialect::Postgres | SqlDialect::Sqlite => {
+            format!("COUNT(*) FILTER (WHERE {} IS NULL)", col)
+        }
+        // No FILTER clause; SUM is NULL on an empty table.
+        SqlDialect::MySql => format!("COALESCE(SUM({} IS NULL), 0)", col),
+        SqlDialect::SqlServer => format!(
+            "COALESCE(SUM(CASE WHEN {} IS NULL THEN 1 ELSE 0 END), 0)",
+            col
+        ),
+    };
+    let avg = if !numeric {
+        "NULL".to_string()
+    } else {
+        match dialect {
+            SqlDialect::Postgres => format!("AVG({})::float8", col),
+            SqlDialect::SqlServer => format!("AVG(CAST({} AS FLOAT))", col),
+            SqlDialect::MySql | SqlDialect::Sqlite => format!("AVG({})", col),
+        }
+    };
+    format!(
+        "SELECT COUNT(*) AS total_rows, {null_count} AS null_count, \
+         COUNT(DISTINCT {col}) AS distinct_count, MIN({col}) AS min_value, \
+         MAX({col}) AS max_value, {avg} AS avg_value FROM {table}",
+        table = dialect.qualified_table(namespace, table_name),
+    )
+}
+
+/// `$."address"."city"`, `$."items"[0]`: the SQL/JSON path syntax shared by
+/// MySQL, SQLite and SQL Server.
+fn json_path_literal(path: &[JsonPathSegment]) -> String {
+    let mut literal = "$".to_string();
+    for segment in path {
+        match segment {
+            JsonPathSegment::Key(key) => literal.push_str(&format!(".\"{key}\"")),
+            JsonPathSegment::Index(index) => literal.push_str(&format!("[{index}]")),
+        }
+    }
+    literal
+}
+
+/// Column references of a `query_table` page. Computed column aliases resolve
+/// to their JSON extraction, converted columns to their conversion, any other
+/// name to a quoted identifier, so sort columns and filters can name either.
+pub struct PageColumns {
+    dialect: SqlDialect,
+    /// `(alias, expression)` in request order
+    computed: Vec<(String, String)>,
+    /// Every column of the table in order, set along with `converted`
+    table_columns: Vec<String>,
+    /// `(column, expression)` selected in place of the raw column
+    converted: Vec<(String, String)>,
+}
+
+impl PageColumns {
+    pub fn new(dialect: SqlDialect, options: &TableQueryOptions) -> EngineResult<Self> {
+        let mut computed = Vec::new();
+        for column in options.computed_columns.as_deref().unwrap_or_default() {
+            if column.alias.trim().is_empty() {
+                return Err(EngineError::validation("Computed column alias is empty"));
+            }
+            let source = dialect.quote_ident(&column.source_column);
+            let expr = dialect.json_extract(&source, &column.path_segments()?);
+            computed.push((column.alias.clone(), expr));
+        }
+        Ok(Self {
+            dialect,
+            computed,
+            table_columns: Vec::new(),
+            converted: Vec::new(),
+        })
+    }
+
+    /// Selects `expression` in place of each converted column, keeping its
+    /// name, e.g. `ST_AsText("geom") AS "geom"`. `table_columns` lists every
+    /// column of the table in order, since the page can no longer use `*`.
+    pub fn convert_columns(
+        &mut self,
+        table_columns: Vec<String>,
+        converted: Vec<(String, String)>,
+    ) {
+        if converted.is_empty() {
+            return;
+        }
+        self.table_columns = table_columns;
+        self.converted = converted;
+    }
+
+    /// SQL for `name` in a WHERE or ORDER BY clause.
+    pub fn expr(&self, name: &str) -> String {
+        self.computed
+            .iter()
+            .chain(&self.converted)
+            .find(|(alias, _)| alias == name)
+            .map_or_else(|| self.dialect.quote_ident(name), |(_, expr)| expr.clone())
+    }
+
+    /// Select list of the page: `*` without computed or converted columns,
+    /// else every column of `table_ref` followed by the computed ones.
+    pub fn select_list(&self, table_ref: &str) -> String {
+        if self.computed.is_empty() && self.converted.is_empty() {
+            return "*".to_string();
+        }
+        let mut list = if self.converted.is_empty() {
+            format!("{}.*", table_ref)
+        } else {
+            self.table_columns
+                .iter()
+                .map(|column| {
+                    let ident = self.dialect.quote_ident(column);
+                    match self.converted.iter().find(|(name, _)| name == column) {
+                        Some((_, expr)) => format!("{} AS {}", expr, ident),
+                        None => format!("{}.{}", table_ref, ident),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        for (alias, expr) in &self.computed {
+            list.push_str(&format!(
+                ", {} AS {}",
+                expr,
+                self.dialect.quote_ident(alias)
+            ));
+        }
+        list
+    }
 }
 
 /// Render a MongoDB shell-style operation string (for display only).
@@ -486,6 +747,60 @@ pub fn generate_migration_script(driver_id: &str, changes: &[SandboxChangeDto])
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qore_core::{ComputedColumn, IndexColumnSpec};
+
+    #[test]
+    fn test_generate_create_index() {
+        let spec = IndexSpec {
+            name: "idx_orders_user".to_string(),
+            columns: vec![
+                IndexColumnSpec {
+                    name: "user_id".to_string(),
+                    direction: SortDirection::Asc,
+                },
+                IndexColumnSpec {
+                    name: "created_at".to_string(),
+                    direction: SortDirection::Desc,
+                },
+            ],
+            unique: true,
+        };
+        let namespace = Namespace::with_schema("app", "sales");
+        assert_eq!(
+            generate_create_index(SqlDialect::Postgres, &namespace, "orders", &spec),
+            "CREATE UNIQUE INDEX \"idx_orders_user\" ON \"sales\".\"orders\" \
+             (\"user_id\" ASC, \"created_at\" DESC)"
+        );
+        assert_eq!(
+            generate_create_index(SqlDialect::MySql, &Namespace::new("app"), "orders", &spec),
+            "CREATE UNIQUE INDEX `idx_orders_user` ON `app`.`orders` \
+             (`user_id` ASC, `created_at` DESC)"
+        );
+        assert_eq!(
+            generate_create_index(
+                SqlDialect::Sqlite,
+                &Namespace::with_schema("app", "aux"),
+                "orders",
+                &spec
+            ),
+            "CREATE UNIQUE INDEX \"aux\".\"idx_orders_user\" ON \"orders\" \
+             (\"user_id\" ASC, \"created_at\" DESC)"
+        );
+    }
+
+    #[test]
+    fn test_generate_column_stats() {
+        assert_eq!(
+            generate_column_stats(SqlDialect::Sqlite, &Namespace::new("main"), "t", "v", true),
+            "SELECT COUNT(*) AS total_rows, COUNT(*) FILTER (WHERE \"v\" IS NULL) AS null_count, \
+             COUNT(DISTINCT \"v\") AS distinct_count, MIN(\"v\") AS min_value, \
+             MAX(\"v\") AS max_value, AVG(\"v\") AS avg_value FROM \"t\""
+        );
+        let mysql =
+            generate_column_stats(SqlDialect::MySql, &Namespace::new("app"), "t", "v", false);
+        assert!(mysql.contains("COALESCE(SUM(`v` IS NULL), 0) AS null_count"));
+        assert!(mysql.contains("NULL AS avg_value FROM `app`.`t`"));
+    }
 
     #[test]
     fn test_quote_ident_postgres() {
@@ -553,4 +868,126 @@ mod tests {
         assert!(sql.contains("DELETE FROM"));
         assert!(sql.contains("WHERE"));
     }
+
+    fn users_pk() -> RowData {
+        let mut pk = RowData::new();
+        pk.columns.insert("id".to_string(), Value::Int(1));
+        pk
+    }
+
+    fn users_update() -> HashMap<String, Value> {
+        HashMap::from([("name".to_string(), Value::Text("Jane".to_string()))])
+    }
+
+    #[test]
+    fn test_generate_update_returning_per_dialect() {
+        let namespace = Namespace::with_schema("mydb", "app");
+        let (pk, data) = (users_pk(), users_update());
+
+        assert_eq!(
+            generate_update_returning(SqlDialect::Postgres, &namespace, "users", &pk, &data)
+                .unwrap(),
+            "UPDATE \"app\".\"users\" SET \"name\" = 'Jane' WHERE \"id\" = 1 RETURNING *"
+        );
+        assert_eq!(
+            generate_update_returning(SqlDialect::Sqlite, &namespace, "users", &pk, &data).unwrap(),
+            "UPDATE \"users\" SET \"name\" = 'Jane' WHERE \"id\" = 1 RETURNING *"
+        );
+        assert_eq!(
+            generate_update_returning(SqlDialect::SqlServer, &namespace, "users", &pk, &data)
+                .unwrap(),
+            "UPDATE [app].[users] SET [name] = N'Jane' OUTPUT inserted.* WHERE [id] = 1"
+        );
+        assert!(
+            generate_update_returning(SqlDialect::MySql, &namespace, "users", &pk, &data).is_err()
+        );
+    }
+
+    #[test]
+    fn test_generate_delete_returning_per_dialect() {
+        let namespace = Namespace::with_schema("mydb", "app");
+        let pk = users_pk();
+
+        assert_eq!(
+            generate_delete_returning(SqlDialect::Postgres, &namespace, "users", &pk).unwrap(),
+            "DELETE FROM \"app\".\"users\" WHERE \"id\" = 1 RETURNING *"
+        );
+        assert_eq!(
+            generate_delete_returning(SqlDialect::Sqlite, &namespace, "users", &pk).unwrap(),
+            "DELETE FROM \"users\" WHERE \"id\" = 1 RETURNING *"
+        );
+        assert_eq!(
+            generate_delete_returning(SqlDialect::SqlServer, &namespace, "users", &pk).unwrap(),
+            "DELETE FROM [app].[users] OUTPUT deleted.* WHERE [id] = 1"
+        );
+        assert!(generate_delete_returning(SqlDialect::MySql, &namespace, "users", &pk).is_err());
+    }
+
+    fn computed_options(json_path: &str) -> TableQueryOptions {
+        TableQueryOptions {
+            computed_columns: Some(vec![ComputedColumn {
+                alias: "status".to_string(),
+                source_column: "data".to_string(),
+                json_path: json_path.to_string(),
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_json_extract_postgres() {
+        let columns =
+            PageColumns::new(SqlDialect::Postgres, &computed_options("$.status")).unwrap();
+        assert_eq!(columns.expr("status"), "(\"data\"->>'status')");
+        assert_eq!(columns.expr("id"), "\"id\"");
+        assert_eq!(
+            columns.select_list("\"public\".\"orders\""),
+            "\"public\".\"orders\".*, (\"data\"->>'status') AS \"status\""
+        );
+
+        let nested =
+            PageColumns::new(SqlDialect::Postgres, &computed_options("items[0].sku")).unwrap();
+        assert_eq!(nested.expr("status"), "(\"data\"->'items'->0->>'sku')");
+
+        assert_eq!(
+            PageColumns::new(SqlDialect::Postgres, &TableQueryOptions::default())
+                .unwrap()
+                .select_list("t"),
+            "*"
+        );
+    }
+
+    #[test]
+    fn test_converted_columns_keep_their_names() {
+        let mut columns =
+            PageColumns::new(SqlDialect::Postgres, &TableQueryOptions::default()).unwrap();
+        columns.convert_columns(
+            vec!["id".to_string(), "geom".to_string()],
+            vec![("geom".to_string(), "ST_AsText(\"geom\")".to_string())],
+        );
+        assert_eq!(
+            columns.select_list("\"public\".\"places\""),
+            "\"public\".\"places\".\"id\", ST_AsText(\"geom\") AS \"geom\""
+        );
+        assert_eq!(columns.expr("geom"), "ST_AsText(\"geom\")");
+        assert_eq!(columns.expr("id"), "\"id\"");
+    }
+
+    #[test]
+    fn test_json_extract_other_dialects() {
+        let options = computed_options("address.city");
+        assert_eq!(
+            PageColumns::new(SqlDialect::MySql, &options)
+                .unwrap()
+                .expr("status"),
+            "(JSON_UNQUOTE(JSON_EXTRACT(`data`, '$.\"address\".\"city\"')))"
+        );
+        assert_eq!(
+            PageColumns::new(SqlDialect::Sqlite, &options)
+                .unwrap()
+                .expr("status"),
+            "(json_extract(\"data\", '$.\"address\".\"city\"'))"
+        );
+        assert!(PageColumns::new(SqlDialect::Sqlite, &computed_options("a'b")).is_err());
+    }
 }
@@ -1,8 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
-//! QoreSQL — SQL safety classification, generation, connection URL parsing.
+//! QoreSQL — SQL safety classification, linting, generation, script splitting,
+//! connection URL parsing and `.env` export, missing-object suggestions, and
+//! column type mapping between drivers.
 
 pub mod clickhouse_safety;
+pub mod connection_env;
 pub mod connection_url;
 pub mod generator;
+mod keywords;
+pub mod lint;
 pub mod safety;
+pub mod script;
+pub mod suggest;
+pub mod type_mapper;
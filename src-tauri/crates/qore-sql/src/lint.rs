@@ -0,0 +1,344 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Static SQL validation: syntax errors with positions plus a small set of
+//! lint rules, without touching the database.
+
+use qore_core::types::SqlSyntaxError;
+use serde::Serialize;
+use sqlparser::{
+    ast::{
+        BinaryOperator, Expr, FunctionArg, FunctionArgExpr, FunctionArguments, Query, SelectItem,
+        SetExpr, Statement,
+    },
+    parser::Parser,
+};
+
+use crate::safety::dialect_for_driver;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    DeleteWithoutWhere,
+    UpdateWithoutWhere,
+    SelectStar,
+    /// A column wrapped in a function, cast or arithmetic inside a comparison,
+    /// which prevents index use
+    NonSargablePredicate,
+    /// `LIKE '%…'`, which cannot use a B-tree index
+    LeadingWildcardLike,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintWarning {
+    pub rule: LintRule,
+    pub message: String,
+    /// Zero-based index of the statement in the script
+    pub statement_index: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LintReport {
+    pub syntax_errors: Vec<SqlSyntaxError>,
+    pub warnings: Vec<LintWarning>,
+}
+
+/// Parses `sql` with the driver's dialect and runs the lint rules on every
+/// statement. A parse failure yields a single syntax error and no warnings.
+pub fn lint_sql(driver_id: &str, sql: &str) -> LintReport {
+    let mut report = LintReport::default();
+    if sql.trim().is_empty() {
+        return report;
+    }
+
+    let dialect = dialect_for_driver(driver_id);
+    let statements = match Parser::parse_sql(&*dialect, sql) {
+        Ok(statements) => statements,
+        Err(err) => {
+            report
+                .syntax_errors
+                .push(syntax_error_from_message(err.to_string()));
+            return report;
+        }
+    };
+
+    for (index, statement) in statements.iter().enumerate() {
+        let mut lint = StatementLint {
+            index,
+            warnings: &mut report.warnings,
+        };
+        lint.statement(statement);
+    }
+
+    report
+}
+
+/// Extracts the `at Line: L, Column: C` suffix sqlparser appends to its errors.
+pub fn syntax_error_from_message(message: String) -> SqlSyntaxError {
+    let position = message.rfind(" at Line: ").and_then(|start| {
+        let rest = &message[start + " at Line: ".len()..];
+        let (line, rest) = rest.split_once(", Column: ")?;
+        let column: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        Some((start, line.trim().parse().ok()?, column.parse().ok()?))
+    });
+
+    match position {
+        Some((start, line, column)) => SqlSyntaxError {
+            message: message[..start].to_string(),
+            line: Some(line),
+            column: Some(column),
+        },
+        None => SqlSyntaxError {
+            message,
+            line: None,
+            column: None,
+        },
+    }
+}
+
+/// Converts a 1-based character offset (as reported by Postgres) into a
+/// 1-based line and column.
+pub fn line_column_at(sql: &str, offset: usize) -> (u64, u64) {
+    let mut line = 1;
+    let mut column = 1;
+    for c in sql.chars().take(offset.saturating_sub(1)) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+struct StatementLint<'a> {
+    index: usize,
+    warnings: &'a mut Vec<LintWarning>,
+}
+
+impl StatementLint<'_> {
+    fn warn(&mut self, rule: LintRule, message: impl Into<String>) {
+        self.warnings.push(LintWarning {
+            rule,
+            message: message.into(),
+            statement_index: self.index,
+        });
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Query(query) => self.query(query),
+            Statement::Delete(delete) => match &delete.selection {
+                Some(selection) => self.predicate(selection),
+                None => self.warn(
+                    LintRule::DeleteWithoutWhere,
+                    "DELETE without WHERE removes every row of the table",
+                ),
+            },
+            Statement::Update(update) => match &update.selection {
+                Some(selection) => self.predicate(selection),
+                None => self.warn(
+                    LintRule::UpdateWithoutWhere,
+                    "UPDATE without WHERE changes every row of the table",
+                ),
+            },
+            _ => {}
+        }
+    }
+
+    fn query(&mut self, query: &Query) {
+        if let Some(with) = &query.with {
+            for cte in &with.cte_tables {
+                self.query(&cte.query);
+            }
+        }
+        self.set_expr(&query.body);
+    }
+
+    fn set_expr(&mut self, expr: &SetExpr) {
+        match expr {
+            SetExpr::Select(select) => {
+                let star = select.projection.iter().any(|item| {
+                    matches!(
+                        item,
+                        SelectItem::Wildcard(_) | SelectItem::QualifiedWildcard(..)
+                    )
+                });
+                if star {
+                    self.warn(
+                        LintRule::SelectStar,
+                        "SELECT * reads every column; list the columns you need",
+                    );
+                }
+                if let Some(selection) = &select.selection {
+                    self.predicate(selection);
+                }
+            }
+            SetExpr::Query(query) => self.query(query),
+            SetExpr::SetOperation { left, right, .. } => {
+                self.set_expr(left);
+                self.set_expr(right);
+            }
+            _ => {}
+        }
+    }
+
+    fn predicate(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Nested(inner) => self.predicate(inner),
+            Expr::UnaryOp { expr, .. } => self.predicate(expr),
+            Expr::BinaryOp { left, op, right } => match op {
+                BinaryOperator::And | BinaryOperator::Or => {
+                    self.predicate(left);
+                    self.predicate(right);
+                }
+                BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq => {
+                    for side in [left, right] {
+                        if is_wrapped_column(side) {
+                            self.warn(
+                                LintRule::NonSargablePredicate,
+                                format!(
+                                    "`{}` wraps a column, so an index on it cannot be used",
+                                    side
+                                ),
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
+                let pattern_sql = pattern.to_string();
+                if has_column(expr)
+                    && (pattern_sql.starts_with("'%") || pattern_sql.starts_with("'_"))
+                {
+                    self.warn(
+                        LintRule::LeadingWildcardLike,
+                        format!(
+                            "LIKE {} starts with a wildcard and scans the whole table",
+                            pattern_sql
+                        ),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A column reference hidden behind a function call, cast or arithmetic.
+fn is_wrapped_column(expr: &Expr) -> bool {
+    match expr {
+        Expr::Nested(inner) => is_wrapped_column(inner),
+        Expr::Function(_) | Expr::Cast { .. } | Expr::BinaryOp { .. } | Expr::UnaryOp { .. } => {
+            has_column(expr)
+        }
+        _ => false,
+    }
+}
+
+fn has_column(expr: &Expr) -> bool {
+    match expr {
+        Expr::Identifier(_) | Expr::CompoundIdentifier(_) => true,
+        Expr::Nested(inner) => has_column(inner),
+        Expr::UnaryOp { expr, .. } | Expr::Cast { expr, .. } => has_column(expr),
+        Expr::BinaryOp { left, right, .. } => has_column(left) || has_column(right),
+        Expr::Function(function) => match &function.args {
+            FunctionArguments::List(list) => list.args.iter().any(|arg| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr))
+                | FunctionArg::Named {
+                    arg: FunctionArgExpr::Expr(expr),
+                    ..
+                } => has_column(expr),
+                _ => false,
+            }),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(report: &LintReport) -> Vec<LintRule> {
+        report.warnings.iter().map(|w| w.rule).collect()
+    }
+
+    #[test]
+    fn syntax_error_reports_position() {
+        let report = lint_sql("postgres", "SELECT id\nFROM users\nWHERE id = = 1");
+        assert_eq!(report.syntax_errors.len(), 1);
+        let error = &report.syntax_errors[0];
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.column, Some(12));
+        assert!(!error.message.contains("Line:"));
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn syntax_error_message_without_position() {
+        let error = syntax_error_from_message("unexpected end of input".to_string());
+        assert_eq!(error.message, "unexpected end of input");
+        assert_eq!(error.line, None);
+        assert_eq!(error.column, None);
+    }
+
+    #[test]
+    fn delete_without_where_is_flagged() {
+        let report = lint_sql("postgres", "SELECT 1; DELETE FROM users");
+        assert!(report.syntax_errors.is_empty());
+        assert_eq!(rules(&report), vec![LintRule::DeleteWithoutWhere]);
+        assert_eq!(report.warnings[0].statement_index, 1);
+
+        let report = lint_sql("mysql", "DELETE FROM users WHERE id = 1");
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn update_without_where_is_flagged() {
+        let report = lint_sql("postgres", "UPDATE users SET active = false");
+        assert_eq!(rules(&report), vec![LintRule::UpdateWithoutWhere]);
+    }
+
+    #[test]
+    fn select_star_is_flagged_in_ctes_and_unions() {
+        let report = lint_sql(
+            "postgres",
+            "WITH u AS (SELECT * FROM users) SELECT id FROM u UNION SELECT t.* FROM t",
+        );
+        assert_eq!(
+            rules(&report),
+            vec![LintRule::SelectStar, LintRule::SelectStar]
+        );
+    }
+
+    #[test]
+    fn non_sargable_predicates_are_flagged() {
+        let report = lint_sql(
+            "postgres",
+            "SELECT id FROM users WHERE lower(email) = 'a@b.c' AND created_at > now() \
+             AND id + 1 = 5 AND name LIKE '%son'",
+        );
+        assert_eq!(
+            rules(&report),
+            vec![
+                LintRule::NonSargablePredicate,
+                LintRule::NonSargablePredicate,
+                LintRule::LeadingWildcardLike,
+            ]
+        );
+    }
+
+    #[test]
+    fn line_column_from_offset() {
+        assert_eq!(line_column_at("SELECT 1", 1), (1, 1));
+        assert_eq!(line_column_at("SELECT\n  foo", 10), (2, 3));
+    }
+}
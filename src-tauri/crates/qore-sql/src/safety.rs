@@ -13,6 +13,9 @@ use sqlparser::{
 use std::num::NonZeroUsize;
 use std::sync::{Mutex, OnceLock};
 
+use crate::generator::SqlDialect;
+use crate::keywords;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SqlSafetyAnalysis {
     pub is_mutation: bool,
@@ -173,6 +176,14 @@ fn returns_rows_uncached(driver_id: &str, trimmed: &str) -> Result<bool, String>
         return Ok(matches!(classify(trimmed), ClickHouseQueryClass::Read));
     }
 
+    if matches!(
+        driver_id.to_ascii_lowercase().as_str(),
+        "sqlserver" | "mssql"
+    ) && has_mssql_output_clause(trimmed)
+    {
+        return Ok(true);
+    }
+
     let dialect = dialect_for_driver(driver_id);
     let statements = Parser::parse_sql(&*dialect, trimmed).map_err(|err| err.to_string())?;
 
@@ -180,6 +191,13 @@ fn returns_rows_uncached(driver_id: &str, trimmed: &str) -> Result<bool, String>
     Ok(statement_returns_rows(first))
 }
 
+/// SQL Server DML with `OUTPUT inserted.*` / `OUTPUT deleted.*` returns rows.
+/// sqlparser doesn't model the clause on UPDATE/DELETE, so match it textually.
+fn has_mssql_output_clause(sql: &str) -> bool {
+    let upper = sql.to_ascii_uppercase();
+    upper.contains(" OUTPUT INSERTED.") || upper.contains(" OUTPUT DELETED.")
+}
+
 pub fn split_sql_statements(driver_id: &str, sql: &str) -> Result<Vec<String>, String> {
     let trimmed = sql.trim();
     if trimmed.is_empty() {
@@ -307,7 +325,7 @@ fn split_ch_statements(input: &str) -> Vec<String> {
     out
 }
 
-fn dialect_for_driver(driver_id: &str) -> Box<dyn Dialect> {
+pub(crate) fn dialect_for_driver(driver_id: &str) -> Box<dyn Dialect> {
     if driver_id.eq_ignore_ascii_case("postgres") || driver_id.eq_ignore_ascii_case("cockroachdb") {
         Box::new(PostgreSqlDialect {})
     } else if driver_id.eq_ignore_ascii_case("mysql") {
@@ -360,6 +378,12 @@ fn is_mutation_statement(statement: &Statement) -> bool {
 }
 
 fn statement_returns_rows(statement: &Statement) -> bool {
+    match statement {
+        Statement::Insert(insert) => return insert.returning.is_some(),
+        Statement::Update(update) => return update.returning.is_some(),
+        Statement::Delete(delete) => return delete.returning.is_some(),
+        _ => {}
+    }
     matches!(
         statement,
         Statement::Query(_)
@@ -671,6 +695,61 @@ fn has_copy_to_clause(upper: &str) -> bool {
     false
 }
 
+/// Quotes `name` as an identifier of `dialect`, doubling any embedded
+/// closing quote character. Always quotes, so the result is safe whatever
+/// the name; use [`needs_quoting`] to keep readable SQL for plain names.
+pub fn quote_ident(dialect: SqlDialect, name: &str) -> String {
+    match dialect {
+        SqlDialect::Postgres | SqlDialect::Sqlite => {
+            format!("\"{}\"", name.replace('"', "\"\""))
+        }
+        SqlDialect::MySql => format!("`{}`", name.replace('`', "``")),
+        SqlDialect::SqlServer => format!("[{}]", name.replace(']', "]]")),
+    }
+}
+
+/// Whether `name` has to be quoted to be read back as the same identifier:
+/// it is a reserved word of `dialect`, or breaks the rules for bare
+/// identifiers. PostgreSQL folds bare names to lowercase, so any uppercase
+/// letter needs quoting there too.
+pub fn needs_quoting(dialect: SqlDialect, name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return true;
+    };
+    let bare = match dialect {
+        SqlDialect::Postgres => {
+            matches!(first, 'a'..='z' | '_')
+                && chars.all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '$'))
+        }
+        SqlDialect::MySql => {
+            matches!(first, 'a'..='z' | 'A'..='Z' | '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+        }
+        SqlDialect::Sqlite => {
+            matches!(first, 'a'..='z' | 'A'..='Z' | '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        SqlDialect::SqlServer => {
+            matches!(first, 'a'..='z' | 'A'..='Z' | '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '@' | '#' | '$'))
+        }
+    };
+    !bare || is_reserved_word(dialect, name)
+}
+
+fn is_reserved_word(dialect: SqlDialect, name: &str) -> bool {
+    let words = match dialect {
+        SqlDialect::Postgres => keywords::POSTGRES,
+        SqlDialect::MySql => keywords::MYSQL,
+        SqlDialect::Sqlite => keywords::SQLITE,
+        SqlDialect::SqlServer => keywords::SQLSERVER,
+    };
+    words
+        .binary_search(&name.to_ascii_uppercase().as_str())
+        .is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -841,6 +920,32 @@ mod tests {
         assert_eq!(returns_rows("clickhouse", "EXPLAIN SELECT 1"), Ok(true));
     }
 
+    #[test]
+    fn returning_and_output_dml_returns_rows() {
+        assert_eq!(
+            returns_rows("postgres", "UPDATE users SET name = 'x' WHERE id = 1"),
+            Ok(false)
+        );
+        assert_eq!(
+            returns_rows(
+                "postgres",
+                "UPDATE users SET name = 'x' WHERE id = 1 RETURNING *"
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            returns_rows("sqlite", "DELETE FROM users WHERE id = 1 RETURNING *"),
+            Ok(true)
+        );
+        assert_eq!(
+            returns_rows(
+                "sqlserver",
+                "DELETE FROM [dbo].[users] OUTPUT deleted.* WHERE [id] = 1"
+            ),
+            Ok(true)
+        );
+    }
+
     #[test]
     fn clickhouse_split_respects_string_literals() {
         let stmts =
@@ -996,4 +1101,65 @@ mod tests {
         assert_eq!(classify_sqlite_dangerous("PRAGMA index_list(users)"), None);
         assert_eq!(classify_sqlite_dangerous("SELECT 1"), None);
     }
+
+    const ALL_DIALECTS: [SqlDialect; 4] = [
+        SqlDialect::Postgres,
+        SqlDialect::MySql,
+        SqlDialect::Sqlite,
+        SqlDialect::SqlServer,
+    ];
+
+    #[test]
+    fn keyword_lists_are_sorted_uppercase() {
+        for words in [
+            keywords::POSTGRES,
+            keywords::MYSQL,
+            keywords::SQLITE,
+            keywords::SQLSERVER,
+        ] {
+            assert!(words.windows(2).all(|w| w[0] < w[1]));
+            assert!(words.iter().all(|w| *w == w.to_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn reserved_words_need_quoting() {
+        for dialect in ALL_DIALECTS {
+            for name in ["order", "select", "SELECT", "Order"] {
+                assert!(needs_quoting(dialect, name), "{dialect:?} {name}");
+            }
+            assert!(!needs_quoting(dialect, "orders"), "{dialect:?}");
+            assert!(!needs_quoting(dialect, "customer_id"), "{dialect:?}");
+        }
+        assert!(needs_quoting(SqlDialect::Postgres, "user"));
+        assert!(!needs_quoting(SqlDialect::Sqlite, "user"));
+        assert!(needs_quoting(SqlDialect::SqlServer, "top"));
+        assert!(!needs_quoting(SqlDialect::Postgres, "top"));
+    }
+
+    #[test]
+    fn irregular_names_need_quoting() {
+        for dialect in ALL_DIALECTS {
+            for name in ["", "first name", "1st", "a-b", "a\"b", "a`b", "a]b", "café"] {
+                assert!(needs_quoting(dialect, name), "{dialect:?} {name}");
+            }
+        }
+        // Only PostgreSQL folds bare names, so only it must keep their case quoted.
+        assert!(needs_quoting(SqlDialect::Postgres, "CustomerId"));
+        assert!(!needs_quoting(SqlDialect::MySql, "CustomerId"));
+    }
+
+    #[test]
+    fn quote_ident_escapes_embedded_quotes() {
+        assert_eq!(quote_ident(SqlDialect::Postgres, "order"), "\"order\"");
+        assert_eq!(
+            quote_ident(SqlDialect::Postgres, "first \"name\""),
+            "\"first \"\"name\"\"\""
+        );
+        assert_eq!(quote_ident(SqlDialect::Sqlite, "a\"b"), "\"a\"\"b\"");
+        assert_eq!(quote_ident(SqlDialect::MySql, "select"), "`select`");
+        assert_eq!(quote_ident(SqlDialect::MySql, "a`b c"), "`a``b c`");
+        assert_eq!(quote_ident(SqlDialect::SqlServer, "order"), "[order]");
+        assert_eq!(quote_ident(SqlDialect::SqlServer, "a]b [c]"), "[a]]b [c]]]");
+    }
 }
@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Script splitting for batch execution.
+//!
+//! Unlike `safety::split_sql_statements`, the splitter here never parses:
+//! statements keep their original text, and syntax sqlparser does not know
+//! still splits. A terminator only counts outside string literals, quoted
+//! identifiers, comments and PostgreSQL dollar-quoted bodies. MySQL
+//! `DELIMITER` lines change the terminator for the statements that follow.
+
+/// Lexical features of a driver's SQL dialect that affect where statements end.
+#[derive(Debug, Clone, Copy, Default)]
+struct ScriptSyntax {
+    /// `$$ … $$` and `$tag$ … $tag$` bodies
+    dollar_quotes: bool,
+    /// `\'` inside string literals, `#` line comments, `` `ident` `` and `DELIMITER`
+    mysql: bool,
+    /// `[ident]` quoted identifiers
+    brackets: bool,
+}
+
+fn syntax_for_driver(driver_id: &str) -> ScriptSyntax {
+    match driver_id.to_ascii_lowercase().as_str() {
+        "postgres" | "cockroachdb" | "neon" | "supabase" | "timescaledb" | "duckdb" => {
+            ScriptSyntax {
+                dollar_quotes: true,
+                ..Default::default()
+            }
+        }
+        "mysql" | "mariadb" => ScriptSyntax {
+            mysql: true,
+            ..Default::default()
+        },
+        "sqlserver" | "mssql" => ScriptSyntax {
+            brackets: true,
+            ..Default::default()
+        },
+        _ => ScriptSyntax::default(),
+    }
+}
+
+/// Splits `script` into statements in source order. Statements are trimmed
+/// and keep their terminator-free original text; pieces holding only
+/// whitespace or comments are dropped.
+pub fn split_script(driver_id: &str, script: &str) -> Vec<String> {
+    let syntax = syntax_for_driver(driver_id);
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut delimiter = ";".to_string();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let line_start = i == 0 || bytes[i - 1] == b'\n';
+        if syntax.mysql && line_start && is_blank(&script[start..i], syntax) {
+            if let Some((new_delimiter, line_end)) = delimiter_command(script, i) {
+                delimiter = new_delimiter;
+                i = line_end;
+                start = line_end;
+                continue;
+            }
+        }
+
+        let next = bytes.get(i + 1).copied();
+        i = match bytes[i] {
+            b'\'' => skip_quoted(bytes, i, b'\'', syntax.mysql),
+            b'"' => skip_quoted(bytes, i, b'"', false),
+            b'`' if syntax.mysql => skip_quoted(bytes, i, b'`', false),
+            b'[' if syntax.brackets => skip_quoted(bytes, i, b']', false),
+            b'-' if next == Some(b'-') => skip_line(bytes, i),
+            b'#' if syntax.mysql => skip_line(bytes, i),
+            b'/' if next == Some(b'*') => skip_block_comment(bytes, i),
+            b'$' if syntax.dollar_quotes => skip_dollar_quoted(script, i).unwrap_or(i + 1),
+            _ if bytes[i..].starts_with(delimiter.as_bytes()) => {
+                push_statement(&mut statements, &script[start..i], syntax);
+                start = i + delimiter.len();
+                start
+            }
+            _ => i + 1,
+        };
+    }
+    push_statement(&mut statements, &script[start..], syntax);
+
+    statements
+}
+
+fn push_statement(statements: &mut Vec<String>, text: &str, syntax: ScriptSyntax) {
+    if !is_blank(text, syntax) {
+        statements.push(text.trim().to_string());
+    }
+}
+
+/// True when `text` holds nothing but whitespace and comments.
+fn is_blank(text: &str, syntax: ScriptSyntax) -> bool {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let next = bytes.get(i + 1).copied();
+        i = match bytes[i] {
+            b if b.is_ascii_whitespace() => i + 1,
+            b'-' if next == Some(b'-') => skip_line(bytes, i),
+            b'#' if syntax.mysql => skip_line(bytes, i),
+            b'/' if next == Some(b'*') => skip_block_comment(bytes, i),
+            _ => return false,
+        };
+    }
+    true
+}
+
+/// Recognizes a `DELIMITER <token>` line at `i` and returns the new
+/// delimiter with the offset just past the line.
+fn delimiter_command(script: &str, i: usize) -> Option<(String, usize)> {
+    let line_end = script[i..].find('\n').map_or(script.len(), |n| i + n + 1);
+    let line = script[i..line_end].trim();
+    let keyword = line.get(..9)?;
+    if !keyword.eq_ignore_ascii_case("DELIMITER") {
+        return None;
+    }
+    let rest = &line[9..];
+    if !rest.starts_with(|c: char| c.is_ascii_whitespace()) {
+        return None;
+    }
+    let delimiter = rest.split_whitespace().next()?;
+    Some((delimiter.to_string(), line_end))
+}
+
+/// Offset just past the literal opened by the quote at `i`. A doubled
+/// closing quote is an escaped quote, as is `\` followed by any byte when
+/// `backslash_escapes` is set.
+fn skip_quoted(bytes: &[u8], i: usize, close: u8, backslash_escapes: bool) -> usize {
+    let mut j = i + 1;
+    while j < bytes.len() {
+        if backslash_escapes && bytes[j] == b'\\' {
+            j += 2;
+        } else if bytes[j] == close {
+            if bytes.get(j + 1) == Some(&close) {
+                j += 2;
+            } else {
+                return j + 1;
+            }
+        } else {
+            j += 1;
+        }
+    }
+    bytes.len()
+}
+
+fn skip_line(bytes: &[u8], i: usize) -> usize {
+    bytes[i..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |n| i + n + 1)
+}
+
+fn skip_block_comment(bytes: &[u8], i: usize) -> usize {
+    bytes[i + 2..]
+        .windows(2)
+        .position(|w| w == b"*/")
+        .map_or(bytes.len(), |n| i + 2 + n + 2)
+}
+
+/// Offset just past the dollar-quoted body opened at `i`, or `None` when the
+/// `$` does not open one (a positional parameter like `$1`, or a `$` inside
+/// an identifier).
+fn skip_dollar_quoted(script: &str, i: usize) -> Option<usize> {
+    let bytes = script.as_bytes();
+    if i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_') {
+        return None;
+    }
+    let tag_len = bytes[i + 1..]
+        .iter()
+        .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))?;
+    if bytes[i + 1 + tag_len] != b'$' || bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+        return None;
+    }
+    let tag = &script[i..i + tag_len + 2];
+    let body_start = i + tag.len();
+    Some(
+        script[body_start..]
+            .find(tag)
+            .map_or(script.len(), |n| body_start + n + tag.len()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_semicolons_in_string_literals() {
+        let statements = split_script(
+            "postgres",
+            "INSERT INTO notes VALUES ('a;b', 'it''s; fine');\nSELECT 1;",
+        );
+        assert_eq!(
+            statements,
+            [
+                "INSERT INTO notes VALUES ('a;b', 'it''s; fine')",
+                "SELECT 1"
+            ]
+        );
+
+        let statements = split_script("mysql", r"SELECT 'it\'s; fine'; SELECT `a;b` FROM t");
+        assert_eq!(statements, [r"SELECT 'it\'s; fine'", "SELECT `a;b` FROM t"]);
+    }
+
+    #[test]
+    fn keeps_dollar_quoted_function_body_whole() {
+        let script = "CREATE FUNCTION bump() RETURNS trigger AS $body$\n\
+                      BEGIN\n  NEW.updated_at := now();\n  RETURN NEW;\nEND;\n\
+                      $body$ LANGUAGE plpgsql;\n\
+                      DO $$ BEGIN PERFORM 1; END $$;\n\
+                      SELECT $1::int;";
+        let statements = split_script("postgres", script);
+        assert_eq!(statements.len(), 3);
+        assert!(statements[0].ends_with("$body$ LANGUAGE plpgsql"));
+        assert_eq!(statements[1], "DO $$ BEGIN PERFORM 1; END $$");
+        assert_eq!(statements[2], "SELECT $1::int");
+    }
+
+    #[test]
+    fn follows_mysql_delimiter_changes() {
+        let script = "DELIMITER //\n\
+                      CREATE PROCEDURE p() BEGIN SELECT 1; SELECT 2; END //\n\
+                      DELIMITER ;\n\
+                      CALL p();";
+        let statements = split_script("mysql", script);
+        assert_eq!(
+            statements,
+            [
+                "CREATE PROCEDURE p() BEGIN SELECT 1; SELECT 2; END",
+                "CALL p()"
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_comment_only_pieces() {
+        let statements = split_script(
+            "sqlite",
+            "-- setup; first\nCREATE TABLE t (id INT); /* done; */\n-- trailing",
+        );
+        assert_eq!(statements, ["-- setup; first\nCREATE TABLE t (id INT)"]);
+    }
+}
@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! "Did you mean …?" hints for missing table/column errors: recognises the
+//! error shapes of the common SQL drivers and picks the closest known name by
+//! edit distance. The schema lookup itself lives with the caller.
+
+use sqlparser::ast::{ObjectName, ObjectNamePart, Query, SetExpr, Statement, TableFactor};
+use sqlparser::parser::Parser;
+
+use crate::safety::dialect_for_driver;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingObjectKind {
+    Table,
+    Column,
+}
+
+/// The object a driver reported as missing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingObject {
+    pub kind: MissingObjectKind,
+    /// Unqualified name as written in the query
+    pub name: String,
+    /// Table the column was looked up in, when the error names it
+    pub table: Option<String>,
+}
+
+/// `(start marker, end marker, kind)`, matched case-insensitively in order.
+/// An empty end marker takes the rest of the first line.
+const MISSING_OBJECT_PATTERNS: &[(&str, &str, MissingObjectKind)] = &[
+    // PostgreSQL
+    ("relation \"", "\" does not exist", MissingObjectKind::Table),
+    ("column \"", "\" does not exist", MissingObjectKind::Column),
+    ("column ", " does not exist", MissingObjectKind::Column),
+    // MySQL / MariaDB
+    ("table '", "' doesn't exist", MissingObjectKind::Table),
+    ("unknown column '", "'", MissingObjectKind::Column),
+    // SQLite
+    ("no such table: ", "", MissingObjectKind::Table),
+    ("no such column: ", "", MissingObjectKind::Column),
+    // SQL Server
+    ("invalid object name '", "'", MissingObjectKind::Table),
+    ("invalid column name '", "'", MissingObjectKind::Column),
+    // DuckDB
+    (
+        "table with name ",
+        " does not exist",
+        MissingObjectKind::Table,
+    ),
+    (
+        "referenced column \"",
+        "\" not found",
+        MissingObjectKind::Column,
+    ),
+];
+
+/// Recognises a missing table/column in a driver error message.
+pub fn classify_missing_object(message: &str) -> Option<MissingObject> {
+    // PostgreSQL names the table for INSERT/UPDATE target columns.
+    if let Some(column) = between(message, "column \"", "\" of relation \"") {
+        let table = between(message, "\" of relation \"", "\"").map(unqualified);
+        return Some(MissingObject {
+            kind: MissingObjectKind::Column,
+            name: unqualified(column),
+            table,
+        });
+    }
+
+    MISSING_OBJECT_PATTERNS
+        .iter()
+        .find_map(|(start, end, kind)| {
+            let raw = between(message, start, end)?;
+            let name = unqualified(raw);
+            (!name.is_empty()).then_some(MissingObject {
+                kind: *kind,
+                name,
+                table: None,
+            })
+        })
+}
+
+/// Case-insensitive slice of `message` between `start` and `end`.
+fn between<'a>(message: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    // ASCII lowercasing keeps byte offsets aligned with `message`.
+    let lower = message.to_ascii_lowercase();
+    let from = lower.find(start)? + start.len();
+    let rest = &lower[from..];
+    let len = if end.is_empty() {
+        rest.find(['\n', '\r']).unwrap_or(rest.len())
+    } else {
+        rest.find(end)?
+    };
+    let value = message[from..from + len].trim();
+    (!value.is_empty() && !value.contains(char::is_whitespace)).then_some(value)
+}
+
+/// Strips quoting and any schema/alias qualifier: `"public"."Users"` → `Users`.
+fn unqualified(raw: &str) -> String {
+    let last = raw.rsplit('.').next().unwrap_or(raw);
+    last.trim_matches(|c| matches!(c, '"' | '\'' | '`' | '[' | ']'))
+        .to_string()
+}
+
+/// Edit distance between `a` and `b`, by characters.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Picks the candidate closest to `name` (case-insensitive), allowing about
+/// one edit per three characters. Ties go to the alphabetically first name.
+pub fn closest_match<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let target = name.to_lowercase();
+    let max_distance = (target.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (levenshtein(&target, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Appends a "did you mean" hint to a driver error.
+pub fn with_suggestion(error: &str, candidate: &str) -> String {
+    format!("{error} (did you mean '{candidate}'?)")
+}
+
+/// Tables read or updated by `sql`, as `(schema, table)`. Used to narrow the
+/// candidates for a missing column. Unparseable SQL yields nothing.
+pub fn referenced_tables(driver_id: &str, sql: &str) -> Vec<(Option<String>, String)> {
+    let dialect = dialect_for_driver(driver_id);
+    let Ok(statements) = Parser::parse_sql(&*dialect, sql) else {
+        return Vec::new();
+    };
+
+    let mut tables = Vec::new();
+    for statement in &statements {
+        match statement {
+            Statement::Query(query) => collect_query_tables(query, &mut tables),
+            Statement::Update(update) => {
+                collect_factor_tables(&update.table.relation, &mut tables);
+            }
+            _ => {}
+        }
+    }
+    tables.dedup();
+    tables
+}
+
+fn collect_query_tables(query: &Query, tables: &mut Vec<(Option<String>, String)>) {
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            collect_query_tables(&cte.query, tables);
+        }
+    }
+    collect_set_expr_tables(&query.body, tables);
+}
+
+fn collect_set_expr_tables(set_expr: &SetExpr, tables: &mut Vec<(Option<String>, String)>) {
+    match set_expr {
+        SetExpr::Select(select) => {
+            for twj in &select.from {
+                collect_factor_tables(&twj.relation, tables);
+                for join in &twj.joins {
+                    collect_factor_tables(&join.relation, tables);
+                }
+            }
+        }
+        SetExpr::Query(query) => collect_query_tables(query, tables),
+        SetExpr::SetOperation { left, right, .. } => {
+            collect_set_expr_tables(left, tables);
+            collect_set_expr_tables(right, tables);
+        }
+        _ => {}
+    }
+}
+
+fn collect_factor_tables(factor: &TableFactor, tables: &mut Vec<(Option<String>, String)>) {
+    match factor {
+        TableFactor::Table { name, .. } => {
+            if let Some(table) = split_object_name(name) {
+                tables.push(table);
+            }
+        }
+        TableFactor::Derived { subquery, .. } => collect_query_tables(subquery, tables),
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => {
+            collect_factor_tables(&table_with_joins.relation, tables);
+            for join in &table_with_joins.joins {
+                collect_factor_tables(&join.relation, tables);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn split_object_name(name: &ObjectName) -> Option<(Option<String>, String)> {
+    let parts: Vec<String> = name
+        .0
+        .iter()
+        .filter_map(|part| match part {
+            ObjectNamePart::Identifier(ident) => Some(ident.value.clone()),
+            _ => None,
+        })
+        .collect();
+    let (table, qualifiers) = parts.split_last()?;
+    Some((qualifiers.last().cloned(), table.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str) -> MissingObject {
+        MissingObject {
+            kind: MissingObjectKind::Table,
+            name: name.to_string(),
+            table: None,
+        }
+    }
+
+    fn column(name: &str) -> MissingObject {
+        MissingObject {
+            kind: MissingObjectKind::Column,
+            name: name.to_string(),
+            table: None,
+        }
+    }
+
+    #[test]
+    fn classifies_driver_error_shapes() {
+        let cases = [
+            ("relation \"usrs\" does not exist", table("usrs")),
+            ("relation \"public.usrs\" does not exist", table("usrs")),
+            ("column \"nme\" does not exist", column("nme")),
+            ("column u.nme does not exist", column("nme")),
+            ("Table 'app.usrs' doesn't exist", table("usrs")),
+            ("Unknown column 'u.nme' in 'field list'", column("nme")),
+            ("no such table: usrs", table("usrs")),
+            ("no such column: nme", column("nme")),
+            ("Invalid object name 'dbo.usrs'.", table("usrs")),
+            ("Invalid column name 'nme'.", column("nme")),
+            (
+                "Catalog Error: Table with name usrs does not exist!",
+                table("usrs"),
+            ),
+            (
+                "Binder Error: Referenced column \"nme\" not found in FROM clause!",
+                column("nme"),
+            ),
+        ];
+        for (message, expected) in cases {
+            assert_eq!(
+                classify_missing_object(message),
+                Some(expected),
+                "for {message}"
+            );
+        }
+    }
+
+    #[test]
+    fn classifies_postgres_column_of_relation() {
+        let missing =
+            classify_missing_object("column \"emial\" of relation \"users\" does not exist")
+                .unwrap();
+        assert_eq!(missing.kind, MissingObjectKind::Column);
+        assert_eq!(missing.name, "emial");
+        assert_eq!(missing.table.as_deref(), Some("users"));
+    }
+
+    #[test]
+    fn ignores_unrelated_errors() {
+        assert!(classify_missing_object("syntax error at or near \"SELEC\"").is_none());
+        assert!(classify_missing_object("permission denied for table users").is_none());
+    }
+
+    #[test]
+    fn suggests_closest_table_from_candidates() {
+        let missing = classify_missing_object("relation \"usrs\" does not exist").unwrap();
+        let candidates = ["orders", "users", "user_roles", "sessions"];
+        assert_eq!(closest_match(&missing.name, candidates), Some("users"));
+        assert_eq!(
+            with_suggestion("relation \"usrs\" does not exist", "users"),
+            "relation \"usrs\" does not exist (did you mean 'users'?)"
+        );
+    }
+
+    #[test]
+    fn closest_match_is_case_insensitive_and_bounded() {
+        assert_eq!(closest_match("Users", ["users", "orders"]), Some("users"));
+        assert_eq!(
+            closest_match("custmer_id", ["customer_id", "id"]),
+            Some("customer_id")
+        );
+        assert_eq!(closest_match("xyz", ["users", "orders"]), None);
+        assert_eq!(closest_match("users", ["users"]), None);
+    }
+
+    #[test]
+    fn computes_levenshtein_distance() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("usrs", "users"), 1);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn extracts_referenced_tables() {
+        let tables = referenced_tables(
+            "postgres",
+            "SELECT u.nme FROM public.users u JOIN orders o ON o.user_id = u.id",
+        );
+        assert_eq!(
+            tables,
+            vec![
+                (Some("public".to_string()), "users".to_string()),
+                (None, "orders".to_string()),
+            ]
+        );
+        assert!(referenced_tables("postgres", "SELEC nope").is_empty());
+    }
+}
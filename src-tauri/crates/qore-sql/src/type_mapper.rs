@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Column type translation between SQL drivers, used when a table is copied
+//! from one connection into another. A source type is first reduced to a
+//! portable [`TypeFamily`], then rendered in the target driver's syntax.
+//! Anything unrecognised degrades to text, which every target can hold.
+
+/// Portable shape of a column type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeFamily {
+    Boolean,
+    SmallInt,
+    Integer,
+    BigInt,
+    Real,
+    Double,
+    /// Exact numeric with optional `(precision, scale)`
+    Decimal(Option<(u32, u32)>),
+    /// Bounded character string
+    Varchar(u32),
+    Text,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Binary,
+    Json,
+    Uuid,
+}
+
+/// Reduces a database-specific type name (`int4`, `varchar(255)`,
+/// `timestamp with time zone`, `NVARCHAR(MAX)`, ...) to its family.
+pub fn classify(data_type: &str) -> TypeFamily {
+    let lower = data_type.trim().to_lowercase();
+    if lower.ends_with("[]") || lower.starts_with("array") {
+        return TypeFamily::Json;
+    }
+
+    let (base, args) = match lower.find('(') {
+        Some(open) => {
+            let close = lower.rfind(')').unwrap_or(lower.len());
+            let args = lower.get(open + 1..close).unwrap_or_default();
+            let rest = lower.get(close + 1..).unwrap_or_default();
+            (format!("{}{}", lower[..open].trim_end(), rest), Some(args))
+        }
+        None => (lower.clone(), None),
+    };
+    let base = base.trim().trim_end_matches(" unsigned").trim();
+
+    match base {
+        "boolean" | "bool" => TypeFamily::Boolean,
+        "bit" if matches!(args, None | Some("1")) => TypeFamily::Boolean,
+        "tinyint" if args == Some("1") => TypeFamily::Boolean,
+        "tinyint" | "smallint" | "int2" | "smallserial" | "serial2" | "utinyint" | "usmallint" => {
+            TypeFamily::SmallInt
+        }
+        "integer" | "int" | "int4" | "serial" | "serial4" | "mediumint" | "uinteger" => {
+            TypeFamily::Integer
+        }
+        "bigint" | "int8" | "bigserial" | "serial8" | "ubigint" | "hugeint" => TypeFamily::BigInt,
+        "real" | "float4" => TypeFamily::Real,
+        "float" | "double" | "double precision" | "float8" => TypeFamily::Double,
+        "numeric" | "decimal" | "money" | "smallmoney" => {
+            TypeFamily::Decimal(args.and_then(parse_precision))
+        }
+        "varchar" | "character varying" | "nvarchar" | "char" | "character" | "nchar"
+        | "bpchar" | "varchar2" => match args.and_then(|a| a.trim().parse().ok()) {
+            Some(len) => TypeFamily::Varchar(len),
+            None => TypeFamily::Text,
+        },
+        "date" => TypeFamily::Date,
+        "time" | "time without time zone" => TypeFamily::Time,
+        "timestamp"
+        | "timestamp without time zone"
+        | "datetime"
+        | "datetime2"
+        | "smalldatetime" => TypeFamily::Timestamp,
+        "timestamptz" | "timestamp with time zone" | "datetimeoffset" => TypeFamily::TimestampTz,
+        "bytea" | "blob" | "binary" | "varbinary" | "image" | "longblob" | "mediumblob"
+        | "tinyblob" => TypeFamily::Binary,
+        "json" | "jsonb" => TypeFamily::Json,
+        "uuid" | "uniqueidentifier" => TypeFamily::Uuid,
+        _ => TypeFamily::Text,
+    }
+}
+
+fn parse_precision(args: &str) -> Option<(u32, u32)> {
+    let mut parts = args.split(',').map(|p| p.trim().parse::<u32>());
+    let precision = parts.next()?.ok()?;
+    let scale = match parts.next() {
+        Some(scale) => scale.ok()?,
+        None => 0,
+    };
+    Some((precision, scale))
+}
+
+/// Renders `family` as a column type for `target_driver`. Returns `None`
+/// when the driver has no SQL DDL to create tables with.
+pub fn render(family: TypeFamily, target_driver: &str) -> Option<String> {
+    let target = Target::from_driver_id(target_driver)?;
+    let rendered = match (target, family) {
+        (
+            Target::Sqlite,
+            TypeFamily::Boolean | TypeFamily::SmallInt | TypeFamily::Integer | TypeFamily::BigInt,
+        ) => "INTEGER".to_string(),
+        (Target::Sqlite, TypeFamily::Real | TypeFamily::Double) => "REAL".to_string(),
+        (Target::Sqlite, TypeFamily::Decimal(_)) => "NUMERIC".to_string(),
+        (Target::Sqlite, TypeFamily::Binary) => "BLOB".to_string(),
+        (Target::Sqlite, _) => "TEXT".to_string(),
+
+        (Target::SqlServer, TypeFamily::Boolean) => "BIT".to_string(),
+        (_, TypeFamily::Boolean) => "BOOLEAN".to_string(),
+
+        (_, TypeFamily::SmallInt) => "SMALLINT".to_string(),
+        (Target::MySql, TypeFamily::Integer) => "INT".to_string(),
+        (_, TypeFamily::Integer) => "INTEGER".to_string(),
+        (_, TypeFamily::BigInt) => "BIGINT".to_string(),
+
+        (Target::MySql, TypeFamily::Real) => "FLOAT".to_string(),
+        (_, TypeFamily::Real) => "REAL".to_string(),
+        (Target::Postgres, TypeFamily::Double) => "DOUBLE PRECISION".to_string(),
+        (Target::SqlServer, TypeFamily::Double) => "FLOAT".to_string(),
+        (_, TypeFamily::Double) => "DOUBLE".to_string(),
+
+        (Target::Postgres, TypeFamily::Decimal(None)) => "NUMERIC".to_string(),
+        (_, TypeFamily::Decimal(None)) => "DECIMAL(38, 10)".to_string(),
+        (Target::Postgres, TypeFamily::Decimal(Some((p, s)))) => format!("NUMERIC({p}, {s})"),
+        (_, TypeFamily::Decimal(Some((p, s)))) => {
+            let precision = p.min(38);
+            format!("DECIMAL({precision}, {})", s.min(precision))
+        }
+
+        (Target::SqlServer, TypeFamily::Varchar(len)) if len <= 4000 => {
+            format!("NVARCHAR({len})")
+        }
+        (Target::SqlServer, TypeFamily::Varchar(_) | TypeFamily::Text | TypeFamily::Json) => {
+            "NVARCHAR(MAX)".to_string()
+        }
+        (Target::MySql, TypeFamily::Varchar(len)) if len <= 16_383 => format!("VARCHAR({len})"),
+        (Target::DuckDb, TypeFamily::Varchar(_) | TypeFamily::Text) => "VARCHAR".to_string(),
+        (Target::Postgres, TypeFamily::Varchar(len)) => format!("VARCHAR({len})"),
+        (_, TypeFamily::Varchar(_) | TypeFamily::Text) => "TEXT".to_string(),
+
+        (_, TypeFamily::Date) => "DATE".to_string(),
+        (_, TypeFamily::Time) => "TIME".to_string(),
+        (Target::MySql, TypeFamily::Timestamp) => "DATETIME(6)".to_string(),
+        (Target::SqlServer, TypeFamily::Timestamp) => "DATETIME2".to_string(),
+        (_, TypeFamily::Timestamp) => "TIMESTAMP".to_string(),
+        (Target::MySql, TypeFamily::TimestampTz) => "TIMESTAMP(6)".to_string(),
+        (Target::SqlServer, TypeFamily::TimestampTz) => "DATETIMEOFFSET".to_string(),
+        (_, TypeFamily::TimestampTz) => "TIMESTAMPTZ".to_string(),
+
+        (Target::Postgres, TypeFamily::Binary) => "BYTEA".to_string(),
+        (Target::SqlServer, TypeFamily::Binary) => "VARBINARY(MAX)".to_string(),
+        (Target::MySql, TypeFamily::Binary) => "LONGBLOB".to_string(),
+        (_, TypeFamily::Binary) => "BLOB".to_string(),
+
+        (Target::Postgres, TypeFamily::Json) => "JSONB".to_string(),
+        (_, TypeFamily::Json) => "JSON".to_string(),
+
+        (Target::MySql, TypeFamily::Uuid) => "CHAR(36)".to_string(),
+        (Target::SqlServer, TypeFamily::Uuid) => "UNIQUEIDENTIFIER".to_string(),
+        (_, TypeFamily::Uuid) => "UUID".to_string(),
+    };
+    Some(rendered)
+}
+
+/// Translates a source column type into the equivalent type on
+/// `target_driver`, or `None` when the target is not a SQL driver.
+pub fn map_type(data_type: &str, target_driver: &str) -> Option<String> {
+    render(classify(data_type), target_driver)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Postgres,
+    DuckDb,
+    MySql,
+    Sqlite,
+    SqlServer,
+}
+
+impl Target {
+    fn from_driver_id(driver_id: &str) -> Option<Self> {
+        match driver_id.to_lowercase().as_str() {
+            "postgres" | "postgresql" | "cockroachdb" | "neon" | "supabase" | "timescaledb" => {
+                Some(Target::Postgres)
+            }
+            "duckdb" => Some(Target::DuckDb),
+            "mysql" | "mariadb" => Some(Target::MySql),
+            "sqlite" => Some(Target::Sqlite),
+            "sqlserver" | "mssql" => Some(Target::SqlServer),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_parameterized_types() {
+        assert_eq!(classify("character varying(64)"), TypeFamily::Varchar(64));
+        assert_eq!(classify("NVARCHAR(MAX)"), TypeFamily::Text);
+        assert_eq!(
+            classify("numeric(10,2)"),
+            TypeFamily::Decimal(Some((10, 2)))
+        );
+        assert_eq!(
+            classify("timestamp(6) with time zone"),
+            TypeFamily::TimestampTz
+        );
+        assert_eq!(classify("int(11) unsigned"), TypeFamily::Integer);
+        assert_eq!(classify("tinyint(1)"), TypeFamily::Boolean);
+        assert_eq!(classify("integer[]"), TypeFamily::Json);
+        assert_eq!(classify("geometry"), TypeFamily::Text);
+    }
+
+    #[test]
+    fn maps_sqlite_types_to_duckdb() {
+        assert_eq!(map_type("INTEGER", "duckdb").as_deref(), Some("INTEGER"));
+        assert_eq!(map_type("TEXT", "duckdb").as_deref(), Some("VARCHAR"));
+        assert_eq!(map_type("REAL", "duckdb").as_deref(), Some("REAL"));
+        assert_eq!(map_type("BLOB", "duckdb").as_deref(), Some("BLOB"));
+    }
+
+    #[test]
+    fn renders_per_target_dialect() {
+        assert_eq!(map_type("jsonb", "mysql").as_deref(), Some("JSON"));
+        assert_eq!(
+            map_type("uuid", "sqlserver").as_deref(),
+            Some("UNIQUEIDENTIFIER")
+        );
+        assert_eq!(map_type("bool", "sqlite").as_deref(), Some("INTEGER"));
+        assert_eq!(
+            map_type("datetime", "postgres").as_deref(),
+            Some("TIMESTAMP")
+        );
+        assert_eq!(
+            map_type("float8", "cockroachdb").as_deref(),
+            Some("DOUBLE PRECISION")
+        );
+        assert_eq!(map_type("text", "mongodb"), None);
+    }
+}
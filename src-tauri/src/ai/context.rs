@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 use tracing::debug;
 
+use crate::ai::safety::redact_sample_rows;
 use crate::ai::types::{AiMessage, EditorContext};
 use crate::engine::types::{
     CollectionListOptions, Namespace, QueryResult, SessionId, TableSchema, Value,
@@ -89,10 +90,11 @@ pub fn dialect_for_driver(driver_id: &str) -> QueryDialect {
 /// Build the full schema context for an AI request.
 ///
 /// Fetches table/collection list and describes each (up to MAX_TABLES),
-/// prioritizing tables mentioned in the user prompt. When
-/// `include_sample_rows` is set (explicit user opt-in), up to
-/// [`SAMPLE_ROW_LIMIT`] redacted rows are appended for tables mentioned in
-/// the prompt.
+/// prioritizing tables mentioned in the user prompt. When `sample_rows` is
+/// set (explicit user opt-in), up to [`SAMPLE_ROW_LIMIT`] rows are appended
+/// for tables mentioned in the prompt, or for the first tables when the
+/// prompt is empty. Values of PII-looking columns, and of columns matching
+/// the patterns in `sample_rows`, are masked by [`redact_sample_rows`] first.
 pub async fn build_context(
     session_manager: &Arc<SessionManager>,
     session_id: SessionId,
@@ -101,7 +103,7 @@ pub async fn build_context(
     virtual_relations: &Arc<VirtualRelationStore>,
     connection_id: Option<&str>,
     user_prompt: &str,
-    include_sample_rows: bool,
+    sample_rows: Option<&[String]>,
 ) -> Result<SchemaContext, String> {
     let dialect = dialect_for_driver(driver_id);
     let driver = session_manager
@@ -113,6 +115,7 @@ pub async fn build_context(
         search: None,
         page: None,
         page_size: Some(200),
+        cursor: None,
     };
     let collection_list = driver
         .list_collections(session_id, namespace, options)
@@ -178,15 +181,18 @@ pub async fn build_context(
                     full_desc.push('\n');
                 }
 
-                if include_sample_rows
-                    && sampled_tables < MAX_SAMPLED_TABLES
-                    && prompt_lower.contains(&table_name.to_lowercase())
-                {
+                let sample_patterns = sample_rows.filter(|_| {
+                    sampled_tables < MAX_SAMPLED_TABLES
+                        && (prompt_lower.is_empty()
+                            || prompt_lower.contains(&table_name.to_lowercase()))
+                });
+                if let Some(patterns) = sample_patterns {
                     match driver
                         .preview_table(session_id, namespace, table_name, SAMPLE_ROW_LIMIT)
                         .await
                     {
-                        Ok(preview) if !preview.rows.is_empty() => {
+                        Ok(mut preview) if !preview.rows.is_empty() => {
+                            redact_sample_rows(&mut preview, patterns);
                             full_desc.push_str(&format_sample_rows(&preview));
                             sampled_tables += 1;
                         }
@@ -299,10 +305,10 @@ fn format_table_schema(table_name: &str, schema: &TableSchema, _driver_id: &str)
     out
 }
 
-/// Format sample rows for the schema context. Columns flagged by
-/// [`crate::redaction::is_sensitive_column`] are skipped entirely — neither name
-/// nor value reaches the provider. Values are truncated so a single TEXT column
-/// can't blow up the prompt.
+/// Format sample rows for the schema context, after [`redact_sample_rows`]
+/// has masked their values. Column names are redacted like in the schema
+/// description, and values are truncated so a single TEXT column can't blow
+/// up the prompt.
 fn format_sample_rows(result: &QueryResult) -> String {
     let mut out = String::new();
     out.push_str("  Sample rows:\n");
@@ -311,14 +317,13 @@ fn format_sample_rows(result: &QueryResult) -> String {
             .columns
             .iter()
             .enumerate()
-            .filter(|(_, col)| !crate::redaction::is_sensitive_column(&col.name))
             .map(|(i, col)| {
                 let value = row
                     .values
                     .get(i)
                     .map(format_sample_value)
                     .unwrap_or_else(|| "NULL".to_string());
-                format!("{}={}", col.name, value)
+                format!("{}={}", redact_column_name(&col.name), value)
             })
             .collect();
         writeln!(out, "    ({})", pairs.join(", ")).unwrap();
@@ -566,23 +571,26 @@ mod tests {
     }
 
     #[test]
-    fn sample_rows_skip_sensitive_columns_and_truncate() {
-        let result = QueryResult {
+    fn sample_rows_mask_sensitive_values_and_truncate() {
+        let mut result = QueryResult {
             columns: vec![
                 ColumnInfo {
                     name: "id".into(),
                     data_type: "INT".into(),
                     nullable: false,
+                    case_insensitive: false,
                 },
                 ColumnInfo {
                     name: "email".into(),
                     data_type: "VARCHAR".into(),
                     nullable: true,
+                    case_insensitive: false,
                 },
                 ColumnInfo {
                     name: "bio".into(),
                     data_type: "TEXT".into(),
                     nullable: true,
+                    case_insensitive: false,
                 },
             ],
             rows: vec![Row {
@@ -595,8 +603,10 @@ mod tests {
             affected_rows: None,
             execution_time_ms: 0.0,
         };
+        redact_sample_rows(&mut result, &[]);
         let out = format_sample_rows(&result);
         assert!(out.contains("id=1"));
+        assert!(out.contains("<redacted>=<redacted>"));
         assert!(!out.contains("alice@example.com"));
         assert!(!out.contains("email"));
         assert!(out.contains('…'));
@@ -623,6 +633,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "name".to_string(),
@@ -631,6 +646,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "email".to_string(),
@@ -639,11 +659,17 @@ mod tests {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             primary_key: Some(vec!["id".to_string()]),
             foreign_keys: vec![],
             row_count_estimate: None,
+            row_count_is_estimate: false,
             indexes: vec![TableIndex {
                 name: "idx_users_email".to_string(),
                 columns: vec!["email".to_string()],
@@ -651,6 +677,8 @@ mod tests {
                 is_primary: false,
                 index_type: None,
             }],
+            partitioning: None,
+            row_security: None,
         };
 
         let result = format_table_schema("users", &schema, "postgres");
@@ -679,6 +707,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: true,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "email".into(),
@@ -687,6 +720,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "password_hash".into(),
@@ -695,6 +733,11 @@ mod tests {
                     default_value: None,
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
                 TableColumn {
                     name: "api_key".into(),
@@ -703,11 +746,17 @@ mod tests {
                     default_value: Some("'sk-default'".into()),
                     is_primary_key: false,
                     is_auto_increment: false,
+                    is_computed: false,
+                    computed_definition: None,
+                    is_sparse: false,
+                    is_column_set: false,
+                    allowed_values: None,
                 },
             ],
             primary_key: Some(vec!["id".into()]),
             foreign_keys: vec![],
             row_count_estimate: None,
+            row_count_is_estimate: false,
             indexes: vec![TableIndex {
                 name: "idx_users_email".into(),
                 columns: vec!["email".into()],
@@ -715,6 +764,8 @@ mod tests {
                 is_primary: false,
                 index_type: None,
             }],
+            partitioning: None,
+            row_security: None,
         };
         let out = format_table_schema("users", &schema, "postgres");
         // Non-sensitive name kept
@@ -740,6 +791,11 @@ mod tests {
                 default_value: None,
                 is_primary_key: false,
                 is_auto_increment: false,
+                is_computed: false,
+                computed_definition: None,
+                is_sparse: false,
+                is_column_set: false,
+                allowed_values: None,
             }],
             primary_key: None,
             foreign_keys: vec![ForeignKey {
@@ -752,7 +808,10 @@ mod tests {
                 is_virtual: false,
             }],
             row_count_estimate: None,
+            row_count_is_estimate: false,
             indexes: vec![],
+            partitioning: None,
+            row_security: None,
         };
 
         let result = format_table_schema("orders", &schema, "postgres");
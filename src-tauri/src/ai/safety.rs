@@ -1,12 +1,47 @@
 // SPDX-License-Identifier: BUSL-1.1
 
-//! Safety validation for AI-generated queries.
+//! Safety validation for AI-generated queries, and redaction of the data
+//! sent to providers.
 //!
 //! Reuses the existing `sql_safety` module for SQL drivers and provides
 //! basic pattern-based checks for MongoDB.
 
 use super::types::SafetyInfo;
 use crate::engine::sql_safety;
+use crate::engine::types::{QueryResult, Value};
+
+/// Replacement for values that must not reach a provider.
+pub const REDACTED: &str = "<redacted>";
+
+/// Masks, in place, every value of the columns that look like PII: those
+/// flagged by [`crate::redaction::is_sensitive_column`] and those matching
+/// one of the user's `patterns`. Runs on sample rows before they are
+/// formatted into a prompt, so masked values never leave the process.
+pub fn redact_sample_rows(result: &mut QueryResult, patterns: &[String]) {
+    let masked: Vec<usize> = result
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(_, col)| {
+            crate::redaction::is_sensitive_column(&col.name)
+                || crate::redaction::matches_column_pattern(&col.name, patterns)
+        })
+        .map(|(i, _)| i)
+        .collect();
+    if masked.is_empty() {
+        return;
+    }
+
+    for row in &mut result.rows {
+        for &i in &masked {
+            if let Some(value) = row.values.get_mut(i) {
+                if !matches!(value, Value::Null) {
+                    *value = Value::Text(REDACTED.to_string());
+                }
+            }
+        }
+    }
+}
 
 /// Validate a generated query and return safety information.
 ///
@@ -149,6 +184,57 @@ fn validate_redis_query(query: &str) -> SafetyInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::types::{ColumnInfo, Row};
+
+    fn sample(columns: &[&str], values: Vec<Value>) -> QueryResult {
+        QueryResult {
+            columns: columns
+                .iter()
+                .map(|name| ColumnInfo {
+                    name: name.to_string(),
+                    data_type: "TEXT".into(),
+                    nullable: true,
+                    case_insensitive: false,
+                })
+                .collect(),
+            rows: vec![Row { values }],
+            affected_rows: None,
+            execution_time_ms: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_redact_sample_rows_masks_email_and_keeps_id() {
+        let mut result = sample(
+            &["id", "email"],
+            vec![Value::Int(42), Value::Text("alice@example.com".to_string())],
+        );
+        redact_sample_rows(&mut result, &[]);
+
+        let values = &result.rows[0].values;
+        assert!(matches!(values[0], Value::Int(42)));
+        assert!(matches!(&values[1], Value::Text(v) if v == REDACTED));
+    }
+
+    #[test]
+    fn test_redact_sample_rows_applies_user_patterns() {
+        let mut result = sample(
+            &["id", "full_name", "ssn", "note"],
+            vec![
+                Value::Int(1),
+                Value::Text("Alice Martin".to_string()),
+                Value::Null,
+                Value::Text("vip".to_string()),
+            ],
+        );
+        redact_sample_rows(&mut result, &["name".to_string()]);
+
+        let values = &result.rows[0].values;
+        assert!(matches!(&values[1], Value::Text(v) if v == REDACTED));
+        // NULL carries no data and stays NULL.
+        assert!(matches!(values[2], Value::Null));
+        assert!(matches!(&values[3], Value::Text(v) if v == "vip"));
+    }
 
     #[test]
     fn test_safe_select() {
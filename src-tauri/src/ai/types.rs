@@ -284,6 +284,10 @@ pub struct AiRequest {
     /// Opt-in: include redacted sample rows in the schema context
     #[serde(default)]
     pub include_sample_rows: bool,
+    /// Column-name patterns whose sample values are masked, on top of the
+    /// built-in PII list
+    #[serde(default)]
+    pub redact_patterns: Vec<String>,
     /// For FixError: the original query that failed
     pub original_query: Option<String>,
     /// For FixError: the error message
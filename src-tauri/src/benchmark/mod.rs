@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A/B query benchmark.
+//!
+//! Runs the same read-only query repeatedly on two sessions (typically a
+//! primary and a tuned replica) and compares their latency distributions.
+
+pub mod runner;
+pub mod types;
+
+pub use runner::{run_ab_benchmark, ActiveBenchmarks, BenchmarkTarget};
+pub use types::*;
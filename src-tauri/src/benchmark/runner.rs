@@ -0,0 +1,272 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A/B benchmark runner. Iterations alternate between the two sessions so
+//! that warm-up and background noise hit both sides evenly. Running jobs are
+//! tracked in `ActiveBenchmarks` so the user can stop a long comparison.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use parking_lot::Mutex;
+use tokio_util::sync::CancellationToken;
+
+use super::types::{AbBenchmarkReport, BenchmarkSide, LatencyStats};
+use crate::engine::error::{EngineError, EngineResult};
+use crate::engine::traits::DataEngine;
+use crate::engine::types::{Namespace, QueryId, SessionId};
+
+/// Registry of running benchmarks, keyed by benchmark id.
+#[derive(Default)]
+pub struct ActiveBenchmarks {
+    inner: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl ActiveBenchmarks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a job and returns its cancellation token. Fails when the id
+    /// is already in use.
+    pub fn register(&self, benchmark_id: &str) -> Result<CancellationToken, String> {
+        let mut inner = self.inner.lock();
+        if inner.contains_key(benchmark_id) {
+            return Err(format!("Benchmark '{}' is already running", benchmark_id));
+        }
+        let token = CancellationToken::new();
+        inner.insert(benchmark_id.to_string(), token.clone());
+        Ok(token)
+    }
+
+    pub fn deregister(&self, benchmark_id: &str) {
+        self.inner.lock().remove(benchmark_id);
+    }
+
+    /// Returns `true` when the job was found and signalled.
+    pub fn cancel(&self, benchmark_id: &str) -> bool {
+        match self.inner.lock().remove(benchmark_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// One side of the comparison.
+pub struct BenchmarkTarget {
+    pub driver: Arc<dyn DataEngine>,
+    pub session: SessionId,
+    pub namespace: Option<Namespace>,
+}
+
+struct SideRun {
+    samples_ms: Vec<f64>,
+    row_count: u64,
+}
+
+/// Runs `query` `iterations` times on each target and compares the timings.
+/// The caller is responsible for checking that the query is read-only.
+pub async fn run_ab_benchmark(
+    a: &BenchmarkTarget,
+    b: &BenchmarkTarget,
+    query: &str,
+    iterations: u32,
+    cancel: &CancellationToken,
+) -> EngineResult<AbBenchmarkReport> {
+    if iterations == 0 {
+        return Err(EngineError::validation(
+            "At least one iteration is required",
+        ));
+    }
+
+    let mut run_a = SideRun {
+        samples_ms: Vec::with_capacity(iterations as usize),
+        row_count: 0,
+    };
+    let mut run_b = SideRun {
+        samples_ms: Vec::with_capacity(iterations as usize),
+        row_count: 0,
+    };
+    let mut unstable_a = false;
+    let mut unstable_b = false;
+
+    for i in 0..iterations {
+        let order = if i % 2 == 0 {
+            [
+                (a, &mut run_a, &mut unstable_a),
+                (b, &mut run_b, &mut unstable_b),
+            ]
+        } else {
+            [
+                (b, &mut run_b, &mut unstable_b),
+                (a, &mut run_a, &mut unstable_a),
+            ]
+        };
+        for (target, run, unstable) in order {
+            let (elapsed_ms, rows) = time_once(target, query, cancel).await?;
+            if i > 0 && rows != run.row_count {
+                *unstable = true;
+            }
+            run.samples_ms.push(elapsed_ms);
+            run.row_count = rows;
+        }
+    }
+
+    let mut warnings = Vec::new();
+    let row_counts_match = run_a.row_count == run_b.row_count;
+    if !row_counts_match {
+        warnings.push(format!(
+            "Row counts differ: A returned {} rows, B returned {}",
+            run_a.row_count, run_b.row_count
+        ));
+    }
+    if unstable_a {
+        warnings.push("Row count on A changed between iterations".to_string());
+    }
+    if unstable_b {
+        warnings.push("Row count on B changed between iterations".to_string());
+    }
+
+    let side_a = build_side(a, run_a);
+    let side_b = build_side(b, run_b);
+    let median_ratio = (side_a.latency.median_ms > 0.0)
+        .then(|| side_b.latency.median_ms / side_a.latency.median_ms);
+
+    Ok(AbBenchmarkReport {
+        iterations,
+        a: side_a,
+        b: side_b,
+        row_counts_match,
+        median_ratio,
+        warnings,
+    })
+}
+
+async fn time_once(
+    target: &BenchmarkTarget,
+    query: &str,
+    cancel: &CancellationToken,
+) -> EngineResult<(f64, u64)> {
+    if cancel.is_cancelled() {
+        return Err(EngineError::Cancelled);
+    }
+
+    let query_id = QueryId::new();
+    let start = Instant::now();
+    let result = tokio::select! {
+        result = target.driver.execute_in_namespace(
+            target.session,
+            target.namespace.clone(),
+            query,
+            query_id,
+        ) => result?,
+        _ = cancel.cancelled() => {
+            let _ = target.driver.cancel(target.session, Some(query_id)).await;
+            return Err(EngineError::Cancelled);
+        }
+    };
+    let elapsed_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+    Ok((elapsed_ms, result.rows.len() as u64))
+}
+
+fn build_side(target: &BenchmarkTarget, run: SideRun) -> BenchmarkSide {
+    let latency = LatencyStats::from_samples(&run.samples_ms)
+        .expect("benchmark runs at least one iteration per side");
+    BenchmarkSide {
+        session_id: target.session.0.to_string(),
+        driver: target.driver.driver_id().to_string(),
+        latency,
+        samples_ms: run.samples_ms,
+        row_count: run.row_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::drivers::sqlite::SqliteDriver;
+    use crate::engine::types::ConnectionConfig;
+
+    async fn sqlite_target(rows: u32) -> BenchmarkTarget {
+        let driver: Arc<dyn DataEngine> = Arc::new(SqliteDriver::new());
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        driver
+            .execute(session, "CREATE TABLE items (id INTEGER)", QueryId::new())
+            .await
+            .unwrap();
+        for id in 0..rows {
+            let sql = format!("INSERT INTO items VALUES ({})", id);
+            driver.execute(session, &sql, QueryId::new()).await.unwrap();
+        }
+        BenchmarkTarget {
+            driver,
+            session,
+            namespace: None,
+        }
+    }
+
+    #[test]
+    fn test_latency_stats_nearest_rank() {
+        let stats = LatencyStats::from_samples(&[5.0, 1.0, 3.0, 2.0, 4.0]).unwrap();
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.median_ms, 3.0);
+        assert_eq!(stats.mean_ms, 3.0);
+        assert_eq!(stats.p95_ms, 5.0);
+        assert_eq!(stats.max_ms, 5.0);
+        assert!(LatencyStats::from_samples(&[]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ab_benchmark_two_sqlite_sessions() {
+        let a = sqlite_target(3).await;
+        let b = sqlite_target(3).await;
+
+        let report = run_ab_benchmark(
+            &a,
+            &b,
+            "SELECT id FROM items ORDER BY id",
+            4,
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.iterations, 4);
+        assert_eq!(report.a.samples_ms.len(), 4);
+        assert_eq!(report.b.samples_ms.len(), 4);
+        assert_eq!(report.a.row_count, 3);
+        assert!(report.row_counts_match);
+        assert!(report.warnings.is_empty());
+        assert!(report.a.latency.min_ms <= report.a.latency.p95_ms);
+        assert_eq!(report.b.driver, "sqlite");
+    }
+
+    #[tokio::test]
+    async fn test_ab_benchmark_warns_on_row_count_mismatch() {
+        let a = sqlite_target(3).await;
+        let b = sqlite_target(1).await;
+
+        let report = run_ab_benchmark(&a, &b, "SELECT id FROM items", 1, &CancellationToken::new())
+            .await
+            .unwrap();
+
+        assert!(!report.row_counts_match);
+        assert_eq!(report.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ab_benchmark_cancelled() {
+        let a = sqlite_target(1).await;
+        let b = sqlite_target(1).await;
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let result = run_ab_benchmark(&a, &b, "SELECT id FROM items", 3, &cancel).await;
+        assert!(matches!(result, Err(EngineError::Cancelled)));
+    }
+}
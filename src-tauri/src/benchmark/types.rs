@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+/// Latency distribution over the timed iterations of one side, in milliseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyStats {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+impl LatencyStats {
+    /// Nearest-rank statistics; `None` for an empty sample.
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let len = sorted.len();
+        let median_ms = if len % 2 == 0 {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+        } else {
+            sorted[len / 2]
+        };
+        let p95_idx = ((len as f64 * 0.95).ceil() as usize).clamp(1, len) - 1;
+
+        Some(Self {
+            min_ms: sorted[0],
+            median_ms,
+            mean_ms: sorted.iter().sum::<f64>() / len as f64,
+            p95_ms: sorted[p95_idx],
+            max_ms: sorted[len - 1],
+        })
+    }
+}
+
+/// Measurements for one of the two compared sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkSide {
+    pub session_id: String,
+    pub driver: String,
+    pub latency: LatencyStats,
+    /// Raw per-iteration timings, in execution order.
+    pub samples_ms: Vec<f64>,
+    /// Rows returned by the last iteration.
+    pub row_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbBenchmarkReport {
+    pub iterations: u32,
+    pub a: BenchmarkSide,
+    pub b: BenchmarkSide,
+    pub row_counts_match: bool,
+    /// `b.median / a.median`; below 1.0 means B is faster.
+    pub median_ratio: Option<f64>,
+    pub warnings: Vec<String>,
+}
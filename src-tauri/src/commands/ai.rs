@@ -106,7 +106,7 @@ use uuid::Uuid;
 #[cfg(feature = "pro")]
 use crate::ai::context;
 #[cfg(feature = "pro")]
-use crate::ai::provider::extract_query_from_response;
+use crate::ai::provider::{extract_query_from_response, AIProvider};
 #[cfg(feature = "pro")]
 use crate::ai::safety::validate_generated_query;
 #[cfg(feature = "pro")]
@@ -174,7 +174,7 @@ pub async fn ai_explain_result(
         &virtual_relations,
         None,
         &query,
-        false,
+        None,
     )
     .await?;
 
@@ -201,6 +201,9 @@ pub async fn ai_explain_result(
     })
 }
 
+/// With `include_sample_rows`, a few rows of the first tables are added to
+/// the context, PII-looking values and those of columns matching
+/// `redact_patterns` masked.
 #[cfg(feature = "pro")]
 #[tauri::command]
 pub async fn ai_summarize_schema(
@@ -208,6 +211,8 @@ pub async fn ai_summarize_schema(
     session_id: String,
     config: AiConfig,
     namespace: Option<Namespace>,
+    include_sample_rows: Option<bool>,
+    redact_patterns: Option<Vec<String>>,
 ) -> Result<AiResponse, String> {
     let (session_manager, ai_manager, virtual_relations) = {
         let s = state.lock().await;
@@ -226,6 +231,7 @@ pub async fn ai_summarize_schema(
     let driver_id = driver.driver_id().to_string();
 
     let ns = namespace.unwrap_or_else(|| Namespace::new("default"));
+    let redact_patterns = redact_patterns.unwrap_or_default();
 
     let schema_ctx = context::build_context(
         &session_manager,
@@ -235,7 +241,9 @@ pub async fn ai_summarize_schema(
         &virtual_relations,
         None,
         "",
-        false,
+        include_sample_rows
+            .unwrap_or(false)
+            .then_some(redact_patterns.as_slice()),
     )
     .await?;
 
@@ -296,7 +304,7 @@ pub async fn ai_generate_filters(
         &virtual_relations,
         None,
         &prompt,
-        false,
+        None,
     )
     .await?;
 
@@ -515,7 +523,9 @@ async fn stream_ai_request(
         &virtual_relations,
         request.connection_id.as_deref(),
         &request.prompt,
-        request.include_sample_rows,
+        request
+            .include_sample_rows
+            .then_some(request.redact_patterns.as_slice()),
     )
     .await?;
 
@@ -541,47 +551,69 @@ async fn stream_ai_request(
     messages.push(AiMessage::user(user_prompt));
 
     tokio::spawn(async move {
-        let (tx, mut rx) = tokio::sync::mpsc::channel::<AiStreamChunk>(64);
-        let rid = request_id.clone();
-        let event = event_name.clone();
-
-        let provider_handle =
-            tokio::spawn(
-                async move { provider.stream(&api_key, &messages, &config, tx, rid).await },
-            );
-
-        let mut full_response = String::new();
-        while let Some(chunk) = rx.recv().await {
-            full_response.push_str(&chunk.delta);
-            let _ = window.emit(&event, &chunk);
-        }
-
-        let stream_result = provider_handle.await;
-        let error = match stream_result {
-            Ok(Ok(())) => None,
-            Ok(Err(e)) => Some(e),
-            Err(e) => Some(format!("Stream task panicked: {}", e)),
-        };
-
-        let generated_query = extract_query_from_response(&full_response, &driver_id);
-        let safety_analysis = generated_query
-            .as_ref()
-            .map(|q| validate_generated_query(&driver_id, q));
-
-        let final_chunk = AiStreamChunk {
-            request_id: request_id.clone(),
-            delta: String::new(),
-            done: true,
-            error,
-            generated_query,
-            safety_analysis,
-        };
-        let _ = window.emit(&event_name, &final_chunk);
+        forward_stream(
+            provider,
+            api_key,
+            messages,
+            config,
+            request_id,
+            &driver_id,
+            |chunk| {
+                let _ = window.emit(&event_name, chunk);
+            },
+        )
+        .await;
     });
 
     Ok(())
 }
 
+/// Runs the provider stream and hands every chunk to `emit` as it arrives,
+/// then a final `done` chunk carrying the extracted query and its safety
+/// analysis, or the provider error.
+#[cfg(feature = "pro")]
+async fn forward_stream(
+    provider: Arc<dyn AIProvider>,
+    api_key: String,
+    messages: Vec<AiMessage>,
+    config: AiConfig,
+    request_id: String,
+    driver_id: &str,
+    mut emit: impl FnMut(&AiStreamChunk),
+) {
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<AiStreamChunk>(64);
+    let rid = request_id.clone();
+
+    let provider_handle =
+        tokio::spawn(async move { provider.stream(&api_key, &messages, &config, tx, rid).await });
+
+    let mut full_response = String::new();
+    while let Some(chunk) = rx.recv().await {
+        full_response.push_str(&chunk.delta);
+        emit(&chunk);
+    }
+
+    let error = match provider_handle.await {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(e),
+        Err(e) => Some(format!("Stream task panicked: {}", e)),
+    };
+
+    let generated_query = extract_query_from_response(&full_response, driver_id);
+    let safety_analysis = generated_query
+        .as_ref()
+        .map(|q| validate_generated_query(driver_id, q));
+
+    emit(&AiStreamChunk {
+        request_id,
+        delta: String::new(),
+        done: true,
+        error,
+        generated_query,
+        safety_analysis,
+    });
+}
+
 #[cfg(feature = "pro")]
 fn build_user_prompt(request: &AiRequest) -> String {
     let base = match &request.action {
@@ -627,3 +659,112 @@ fn build_user_prompt(request: &AiRequest) -> String {
         None => base,
     }
 }
+
+#[cfg(all(test, feature = "pro"))]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use tokio::sync::mpsc;
+
+    /// Streams a fixed list of deltas, then fails if `error` is set.
+    struct ScriptedProvider {
+        deltas: Vec<&'static str>,
+        error: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl AIProvider for ScriptedProvider {
+        fn provider_id(&self) -> &'static str {
+            "scripted"
+        }
+
+        async fn stream(
+            &self,
+            _api_key: &str,
+            _messages: &[AiMessage],
+            _config: &AiConfig,
+            sender: mpsc::Sender<AiStreamChunk>,
+            request_id: String,
+        ) -> Result<(), String> {
+            for delta in &self.deltas {
+                let chunk = AiStreamChunk {
+                    request_id: request_id.clone(),
+                    delta: delta.to_string(),
+                    done: false,
+                    error: None,
+                    generated_query: None,
+                    safety_analysis: None,
+                };
+                if sender.send(chunk).await.is_err() {
+                    return Ok(());
+                }
+                tokio::task::yield_now().await;
+            }
+            self.error.map_or(Ok(()), |e| Err(e.to_string()))
+        }
+    }
+
+    async fn run(provider: ScriptedProvider) -> Vec<AiStreamChunk> {
+        let config = AiConfig {
+            provider: AiProvider::Ollama,
+            model: None,
+            base_url: None,
+            max_tokens: None,
+            temperature: None,
+        };
+        let mut emitted = Vec::new();
+        forward_stream(
+            Arc::new(provider),
+            String::new(),
+            vec![AiMessage::user("count users")],
+            config,
+            "req-1".to_string(),
+            "postgres",
+            |chunk| emitted.push(chunk.clone()),
+        )
+        .await;
+        emitted
+    }
+
+    #[tokio::test]
+    async fn test_chunks_are_forwarded_in_order_before_done() {
+        let emitted = run(ScriptedProvider {
+            deltas: vec!["```sql\n", "SELECT COUNT(*)", " FROM users;", "\n```"],
+            error: None,
+        })
+        .await;
+
+        let deltas: Vec<&str> = emitted.iter().map(|c| c.delta.as_str()).collect();
+        assert_eq!(
+            deltas,
+            ["```sql\n", "SELECT COUNT(*)", " FROM users;", "\n```", ""]
+        );
+        assert!(emitted.iter().all(|c| c.request_id == "req-1"));
+        assert!(emitted[..4].iter().all(|c| !c.done));
+
+        let done = emitted.last().unwrap();
+        assert!(done.done);
+        assert!(done.error.is_none());
+        assert_eq!(
+            done.generated_query.as_deref(),
+            Some("SELECT COUNT(*) FROM users;")
+        );
+        assert!(done.safety_analysis.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_provider_error_ends_with_done_chunk() {
+        let emitted = run(ScriptedProvider {
+            deltas: vec!["partial"],
+            error: Some("rate limited"),
+        })
+        .await;
+
+        assert_eq!(emitted.len(), 2);
+        assert_eq!(emitted[0].delta, "partial");
+        let done = &emitted[1];
+        assert!(done.done);
+        assert_eq!(done.error.as_deref(), Some("rate limited"));
+        assert!(done.generated_query.is_none());
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A/B benchmark commands: time one read-only query on two sessions.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use tauri::State;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::parse_session_id;
+use crate::benchmark::{run_ab_benchmark, AbBenchmarkReport, BenchmarkTarget};
+use crate::engine::types::Namespace;
+
+const DEFAULT_ITERATIONS: u32 = 10;
+const MAX_ITERATIONS: u32 = 1_000;
+const READ_ONLY_REQUIRED: &str = "A/B benchmark only runs read-only queries";
+
+#[derive(Debug, Serialize)]
+pub struct AbBenchmarkResponse {
+    pub success: bool,
+    pub benchmark_id: String,
+    pub report: Option<AbBenchmarkReport>,
+    pub error: Option<String>,
+}
+
+/// Runs `query` `iterations` times on both sessions and compares latencies.
+/// Pass `benchmark_id` to be able to stop it with `cancel_ab_benchmark`.
+#[tauri::command]
+#[instrument(
+    skip(state, query),
+    fields(session_a = %session_a, session_b = %session_b, iterations = ?iterations)
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn ab_benchmark(
+    state: State<'_, crate::SharedState>,
+    session_a: String,
+    session_b: String,
+    query: String,
+    iterations: Option<u32>,
+    namespace_a: Option<Namespace>,
+    namespace_b: Option<Namespace>,
+    benchmark_id: Option<String>,
+) -> Result<AbBenchmarkResponse, String> {
+    let (session_manager, query_rate_limiter, interceptor, policy, active) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.interceptor),
+            state.policy.clone(),
+            Arc::clone(&state.active_benchmarks),
+        )
+    };
+
+    let benchmark_id = match benchmark_id {
+        Some(id) => {
+            Uuid::parse_str(&id).map_err(|e| format!("Invalid benchmark ID: {}", e))?;
+            id
+        }
+        None => Uuid::new_v4().to_string(),
+    };
+    let failed = |error: String| AbBenchmarkResponse {
+        success: false,
+        benchmark_id: benchmark_id.clone(),
+        report: None,
+        error: Some(error),
+    };
+
+    let mut targets = Vec::with_capacity(2);
+    for (session_id, namespace) in [(&session_a, namespace_a), (&session_b, namespace_b)] {
+        let session = parse_session_id(session_id)?;
+        // Same safety preflight as execute_query, plus a hard read-only rule:
+        // a benchmark replays the query many times on both connections.
+        let preflight = match qore_service::query::preflight(
+            &session_manager,
+            &query_rate_limiter,
+            &interceptor,
+            &policy,
+            session,
+            session_id,
+            &query,
+            namespace.as_ref(),
+            false,
+        )
+        .await
+        {
+            Ok(pf) => pf,
+            Err(msg) => return Ok(failed(msg)),
+        };
+        if preflight.is_mutation || preflight.is_dangerous {
+            return Ok(failed(READ_ONLY_REQUIRED.to_string()));
+        }
+        targets.push(BenchmarkTarget {
+            driver: preflight.driver,
+            session,
+            namespace,
+        });
+    }
+
+    let cancel = match active.register(&benchmark_id) {
+        Ok(token) => token,
+        Err(msg) => return Ok(failed(msg)),
+    };
+    let iterations = iterations
+        .unwrap_or(DEFAULT_ITERATIONS)
+        .clamp(1, MAX_ITERATIONS);
+
+    let result = run_ab_benchmark(&targets[0], &targets[1], &query, iterations, &cancel).await;
+    active.deregister(&benchmark_id);
+
+    match result {
+        Ok(report) => Ok(AbBenchmarkResponse {
+            success: true,
+            benchmark_id,
+            report: Some(report),
+            error: None,
+        }),
+        Err(e) => Ok(failed(e.sanitized_message())),
+    }
+}
+
+/// Stops a running A/B benchmark. Returns `true` if the job was found.
+#[tauri::command]
+pub async fn cancel_ab_benchmark(
+    state: State<'_, crate::SharedState>,
+    benchmark_id: String,
+) -> Result<bool, String> {
+    let active = Arc::clone(&state.lock().await.active_benchmarks);
+    Ok(active.cancel(&benchmark_id))
+}
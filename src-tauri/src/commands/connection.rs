@@ -10,7 +10,7 @@ use tracing::instrument;
 use uuid::Uuid;
 
 use super::SharedStateExt;
-use crate::engine::types::ConnectionConfig;
+use crate::engine::types::{ConnectionConfig, IsolationLevel, PoolStats, ServerInfo};
 use crate::vault::backend::KeyringProvider;
 use crate::vault::VaultStorage;
 
@@ -21,13 +21,39 @@ pub struct ConnectionResponse {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct PingResponse {
+    pub success: bool,
+    /// Round-trip latency in milliseconds
+    pub latency_ms: Option<f64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PoolStatsResponse {
+    pub success: bool,
+    /// `None` when the driver does not pool connections
+    pub stats: Option<PoolStats>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerInfoResponse {
+    pub success: bool,
+    pub info: Option<ServerInfo>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SessionListItem {
     pub id: String,
     pub display_name: String,
+    pub statement_timeout_ms: Option<u64>,
+    pub lock_timeout_ms: Option<u64>,
+    pub default_isolation_level: Option<IsolationLevel>,
 }
 
-fn load_saved_connection_config(
+pub(super) fn load_saved_connection_config(
     project_id: &str,
     connection_id: &str,
     storage_dir: PathBuf,
@@ -177,9 +203,15 @@ pub async fn connect(
         });
     }
 
-    let session_manager = state.session_manager().await;
+    let (session_manager, query_manager) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+        )
+    };
 
-    match qore_service::connection::connect(&session_manager, config).await {
+    match qore_service::connection::connect(&session_manager, &query_manager, config).await {
         Ok(session_id) => Ok(ConnectionResponse {
             success: true,
             session_id: Some(session_id.0.to_string()),
@@ -201,7 +233,7 @@ pub async fn connect_saved_connection(
     project_id: String,
     connection_id: String,
 ) -> Result<ConnectionResponse, String> {
-    let session_manager = {
+    let (session_manager, query_manager) = {
         let state = state.lock().await;
         if state.vault_lock.is_locked() {
             return Ok(ConnectionResponse {
@@ -210,7 +242,10 @@ pub async fn connect_saved_connection(
                 error: Some("Vault is locked".to_string()),
             });
         }
-        Arc::clone(&state.session_manager)
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+        )
     };
 
     let storage_dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
@@ -227,7 +262,7 @@ pub async fn connect_saved_connection(
             }
         };
 
-    match qore_service::connection::connect(&session_manager, config).await {
+    match qore_service::connection::connect(&session_manager, &query_manager, config).await {
         Ok(session_id) => {
             session_manager
                 .set_display_name(session_id, connection_name)
@@ -252,28 +287,40 @@ pub async fn disconnect(
     state: State<'_, crate::SharedState>,
     session_id: String,
 ) -> Result<ConnectionResponse, String> {
-    let (session_manager, query_rate_limiter) = {
+    let (session_manager, query_manager, query_rate_limiter, mutation_undo, listeners) = {
         let state = state.lock().await;
         (
             Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
             Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.mutation_undo),
+            Arc::clone(&state.notification_listeners),
         )
     };
 
     let uuid = Uuid::parse_str(&session_id).map_err(|e| format!("Invalid session ID: {}", e))?;
+    let session = crate::engine::types::SessionId(uuid);
+
+    // Listener connections come from the session pool, which waits for them
+    // to be returned before it closes.
+    listeners.cancel_session(session);
 
     match qore_service::connection::disconnect(
         &session_manager,
+        &query_manager,
         &query_rate_limiter,
-        crate::engine::types::SessionId(uuid),
+        session,
     )
     .await
     {
-        Ok(()) => Ok(ConnectionResponse {
-            success: true,
-            session_id: None,
-            error: None,
-        }),
+        Ok(()) => {
+            mutation_undo.clear_session(session);
+            Ok(ConnectionResponse {
+                success: true,
+                session_id: None,
+                error: None,
+            })
+        }
         Err(e) => Ok(ConnectionResponse {
             success: false,
             session_id: None,
@@ -290,13 +337,18 @@ pub async fn list_sessions(
 
     let sessions = session_manager.list_sessions().await;
 
-    Ok(sessions
-        .into_iter()
-        .map(|(id, name)| SessionListItem {
+    let mut items = Vec::with_capacity(sessions.len());
+    for (id, name) in sessions {
+        let config = session_manager.get_config(id).await.ok();
+        items.push(SessionListItem {
             id: id.0.to_string(),
             display_name: name,
-        })
-        .collect())
+            statement_timeout_ms: config.as_ref().and_then(|c| c.statement_timeout_ms),
+            lock_timeout_ms: config.as_ref().and_then(|c| c.lock_timeout_ms),
+            default_isolation_level: config.as_ref().and_then(|c| c.default_isolation_level),
+        });
+    }
+    Ok(items)
 }
 
 /// Checks the health of an active connection (on-demand ping).
@@ -311,7 +363,94 @@ pub async fn check_connection_health(
     let sid = crate::engine::types::SessionId(uuid);
 
     match session_manager.ping(sid).await {
-        Ok(()) => Ok("healthy".to_string()),
+        Ok(_) => Ok("healthy".to_string()),
         Err(e) => Ok(format!("unhealthy: {}", e)),
     }
 }
+
+/// Round-trip latency of an active session's cheapest no-op, for the
+/// connection status indicator. Unlike `test_connection` it reuses the session.
+#[tauri::command]
+pub async fn ping_session(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<PingResponse, String> {
+    let session_manager = state.session_manager().await;
+
+    let uuid = Uuid::parse_str(&session_id).map_err(|e| format!("Invalid session ID: {}", e))?;
+    let sid = crate::engine::types::SessionId(uuid);
+
+    match session_manager.ping(sid).await {
+        Ok(latency) => Ok(PingResponse {
+            success: true,
+            latency_ms: Some(latency.as_micros() as f64 / 1000.0),
+            error: None,
+        }),
+        Err(e) => Ok(PingResponse {
+            success: false,
+            latency_ms: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Connection pool occupancy of an active session.
+#[tauri::command]
+pub async fn get_pool_stats(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<PoolStatsResponse, String> {
+    let session_manager = state.session_manager().await;
+
+    let uuid = Uuid::parse_str(&session_id).map_err(|e| format!("Invalid session ID: {}", e))?;
+    let sid = crate::engine::types::SessionId(uuid);
+
+    let driver = session_manager
+        .get_driver(sid)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    match driver.pool_stats(sid).await {
+        Ok(stats) => Ok(PoolStatsResponse {
+            success: true,
+            stats,
+            error: None,
+        }),
+        Err(e) => Ok(PoolStatsResponse {
+            success: false,
+            stats: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Version and flavor of the server behind an active session, for gating
+/// version-dependent SQL features.
+#[tauri::command]
+pub async fn get_server_info(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<ServerInfoResponse, String> {
+    let session_manager = state.session_manager().await;
+
+    let uuid = Uuid::parse_str(&session_id).map_err(|e| format!("Invalid session ID: {}", e))?;
+    let sid = crate::engine::types::SessionId(uuid);
+
+    let driver = session_manager
+        .get_driver(sid)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    match driver.server_info(sid).await {
+        Ok(info) => Ok(ServerInfoResponse {
+            success: true,
+            info: Some(info),
+            error: None,
+        }),
+        Err(e) => Ok(ServerInfoResponse {
+            success: false,
+            info: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
@@ -1,12 +1,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
-//! Tauri commands for parsing database connection URLs into normalized configuration.
+//! Tauri commands for parsing database connection URLs into normalized configuration,
+//! and for exporting saved connections as environment variables.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tauri::{AppHandle, Manager, State};
 use tracing::instrument;
 
-use crate::engine::connection_url::{parse_connection_url, ParseErrorCode};
+use super::connection::load_saved_connection_config;
+use crate::engine::connection_env::{connection_env_vars, render_dotenv};
+use crate::engine::connection_url::{
+    parse_connection_url, ConnectionUrlParserRegistry, ParseErrorCode, UrlSchemeInfo,
+};
+use crate::engine::types::SshTunnelConfig;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ParseConnectionUrlResponse {
@@ -31,6 +38,8 @@ pub struct PartialConnectionConfigDto {
     pub ssl: Option<bool>,
     /// Additional driver-specific options
     pub options: HashMap<String, String>,
+    /// SSH tunnel from the `ssh*` query parameters
+    pub ssh_tunnel: Option<SshTunnelConfig>,
 }
 
 impl From<crate::engine::connection_url::PartialConnectionConfig> for PartialConnectionConfigDto {
@@ -44,6 +53,7 @@ impl From<crate::engine::connection_url::PartialConnectionConfig> for PartialCon
             database: config.database,
             ssl: config.ssl,
             options: config.options,
+            ssh_tunnel: config.ssh_tunnel,
         }
     }
 }
@@ -55,6 +65,12 @@ impl From<crate::engine::connection_url::PartialConnectionConfig> for PartialCon
 /// - MySQL: `mysql://`
 /// - MongoDB: `mongodb://` and `mongodb+srv://`
 /// - Redis: `redis://` and `rediss://` (TLS)
+/// - SQL Server: `mssql://` and `sqlserver://`
+/// - CockroachDB: `cockroachdb://` and `cockroach://`
+///
+/// `sshHost`, `sshUser`, `sshPort` and `sshIdentityFile` query parameters
+/// describe an SSH tunnel; `get_supported_url_schemes` lists the schemes that
+/// honour them.
 ///
 /// The parsed fields can be merged with explicit form values to create a complete
 /// ConnectionConfig. URL values are parsed first, then explicit values override them.
@@ -99,17 +115,85 @@ pub fn parse_url(url: String) -> ParseConnectionUrlResponse {
     }
 }
 
+/// Every URL scheme `parse_url` accepts, with its driver and whether it can
+/// carry SSH tunnel parameters.
+#[tauri::command]
+pub fn get_supported_url_schemes() -> Vec<UrlSchemeInfo> {
+    ConnectionUrlParserRegistry::new().scheme_details()
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConnectionEnvVar {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConnectionEnvResponse {
+    pub success: bool,
+    pub variables: Vec<ConnectionEnvVar>,
+    /// Ready-to-paste `.env` snippet
+    pub dotenv: Option<String>,
+    pub error: Option<String>,
+}
+
+impl ConnectionEnvResponse {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            variables: Vec::new(),
+            dotenv: None,
+            error: Some(message.into()),
+        }
+    }
+}
+
+/// Export a saved connection as env vars (`DATABASE_URL`, `PG*`, `MYSQL_*`).
+///
+/// Without `include_password` the password is replaced by `${DB_PASSWORD}`.
+/// Including it is a sensitive read and requires a freshly unlocked vault.
 #[tauri::command]
-pub fn get_supported_url_schemes() -> Vec<String> {
-    vec![
-        "postgres".to_string(),
-        "postgresql".to_string(),
-        "mysql".to_string(),
-        "mongodb".to_string(),
-        "mongodb+srv".to_string(),
-        "redis".to_string(),
-        "rediss".to_string(),
-    ]
+#[instrument(skip(app, state), fields(project_id = %project_id, connection_id = %connection_id))]
+pub async fn export_connection_env(
+    app: AppHandle,
+    state: State<'_, crate::SharedState>,
+    project_id: String,
+    connection_id: String,
+    include_password: bool,
+) -> Result<ConnectionEnvResponse, String> {
+    {
+        let state = state.lock().await;
+        if state.vault_lock.is_locked() {
+            return Ok(ConnectionEnvResponse::error("Vault is locked"));
+        }
+        if include_password && !state.vault_lock.is_fresh_authentication() {
+            return Ok(ConnectionEnvResponse::error(
+                "Vault session expired — re-unlock the vault to access credentials",
+            ));
+        }
+    }
+
+    let storage_dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    let config = match load_saved_connection_config(&project_id, &connection_id, storage_dir) {
+        Ok(config) => config,
+        Err(e) => return Ok(ConnectionEnvResponse::error(e)),
+    };
+
+    if include_password {
+        tracing::info!(connection_id = %connection_id, "vault credential read");
+    }
+
+    let vars = connection_env_vars(&config, include_password);
+    let dotenv = render_dotenv(&vars);
+    Ok(ConnectionEnvResponse {
+        success: true,
+        variables: vars
+            .into_iter()
+            .map(|(name, value)| ConnectionEnvVar { name, value })
+            .collect(),
+        dotenv: Some(dotenv),
+        error: None,
+    })
 }
 
 #[cfg(test)]
@@ -153,4 +237,18 @@ mod tests {
         assert_eq!(config.port, Some(6379));
         assert_eq!(config.database.as_deref(), Some("0"));
     }
+
+    #[test]
+    fn test_supported_schemes_flag_ssh_tunnel() {
+        let schemes = get_supported_url_schemes();
+        let tunnels = |scheme: &str| {
+            schemes
+                .iter()
+                .find(|info| info.scheme == scheme)
+                .map(|info| info.supports_ssh_tunnel)
+        };
+        assert_eq!(tunnels("postgres"), Some(true));
+        assert_eq!(tunnels("mssql"), Some(true));
+        assert_eq!(tunnels("mongodb+srv"), Some(false));
+    }
 }
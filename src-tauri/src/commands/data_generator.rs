@@ -19,7 +19,9 @@ use uuid::Uuid;
 use qore_sql::generator::SqlDialect;
 
 use super::parse_session_id;
-use crate::engine::types::{Namespace, TableColumn, TableQueryOptions, Value};
+use crate::engine::types::{
+    DescribeTableOptions, Namespace, TableColumn, TableQueryOptions, Value,
+};
 
 /// Rows per `INSERT` statement. SQLite caps a multi-row VALUES list at 500, so
 /// we chunk to stay portable across the supported dialects.
@@ -118,17 +120,22 @@ pub async fn generate_seed_data(
         &namespace,
         &table,
         connection_id.as_deref(),
+        &DescribeTableOptions::default(),
     )
     .await
     .map_err(|e| e.sanitized())?;
 
     // Columns the DB does not fill itself: skip those with a default
-    // (serial, now()…) and auto-increment / IDENTITY / rowid columns, which the
-    // database assigns and which may reject an explicit value.
+    // (serial, now()…), auto-increment / IDENTITY / rowid columns and computed
+    // columns, which the database assigns and which may reject an explicit
+    // value. SQL Server column sets are skipped too: they alias the sparse
+    // columns, and writing both in one INSERT fails.
     let target_columns: Vec<TableColumn> = schema
         .columns
         .iter()
-        .filter(|c| c.default_value.is_none() && !c.is_auto_increment)
+        .filter(|c| {
+            c.default_value.is_none() && !c.is_auto_increment && !c.is_computed && !c.is_column_set
+        })
         .cloned()
         .collect();
 
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Read-only DBA diagnostics (index usage, bloat, key metadata, ...).
+
+use serde::Serialize;
+use tauri::State;
+use tracing::instrument;
+
+use super::{parse_session_id, SharedStateExt};
+use crate::engine::types::{
+    BigKeysReport, BloatReport, KeyMetadata, Namespace, QueryId, QueryProgress, UnusedIndexReport,
+};
+
+const INDEX_USAGE_NOT_SUPPORTED: &str = "Index usage analysis is not supported by this driver";
+const BLOAT_NOT_SUPPORTED: &str = "Bloat analysis is not supported by this driver";
+const KEY_METADATA_NOT_SUPPORTED: &str = "Key metadata is not supported by this driver";
+const BIG_KEYS_NOT_SUPPORTED: &str = "Big keys analysis is not supported by this driver";
+const QUERY_PROGRESS_NOT_SUPPORTED: &str = "Query progress is not supported by this driver";
+
+const DEFAULT_BIG_KEYS_SAMPLE: u64 = 10_000;
+const MAX_BIG_KEYS_SAMPLE: u64 = 1_000_000;
+const DEFAULT_BIG_KEYS_TOP: usize = 20;
+const MAX_BIG_KEYS_TOP: usize = 500;
+
+#[derive(Debug, Serialize)]
+pub struct UnusedIndexesResponse {
+    pub success: bool,
+    pub report: Option<UnusedIndexReport>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BloatReportResponse {
+    pub success: bool,
+    pub report: Option<BloatReport>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QueryProgressResponse {
+    pub success: bool,
+    pub progress: Option<QueryProgress>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KeyMetadataResponse {
+    pub success: bool,
+    pub metadata: Option<KeyMetadata>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BigKeysResponse {
+    pub success: bool,
+    pub report: Option<BigKeysReport>,
+    pub error: Option<String>,
+}
+
+/// Lists indexes with no recorded scans in a namespace
+#[tauri::command]
+#[instrument(
+    skip(state),
+    fields(session_id = %session_id, database = %database, schema = ?schema)
+)]
+pub async fn unused_indexes(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    schema: Option<String>,
+) -> Result<UnusedIndexesResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().index_usage {
+        return Ok(UnusedIndexesResponse {
+            success: false,
+            report: None,
+            error: Some(INDEX_USAGE_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    let namespace = Namespace { database, schema };
+
+    match driver.unused_indexes(session, &namespace).await {
+        Ok(report) => Ok(UnusedIndexesResponse {
+            success: true,
+            report: Some(report),
+            error: None,
+        }),
+        Err(e) => Ok(UnusedIndexesResponse {
+            success: false,
+            report: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Estimated table and index bloat in a namespace, worst first
+#[tauri::command]
+#[instrument(
+    skip(state),
+    fields(session_id = %session_id, database = %database, schema = ?schema)
+)]
+pub async fn bloat_report(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    schema: Option<String>,
+) -> Result<BloatReportResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().bloat_report {
+        return Ok(BloatReportResponse {
+            success: false,
+            report: None,
+            error: Some(BLOAT_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    let namespace = Namespace { database, schema };
+
+    match driver.bloat_report(session, &namespace).await {
+        Ok(report) => Ok(BloatReportResponse {
+            success: true,
+            report: Some(report),
+            error: None,
+        }),
+        Err(e) => Ok(BloatReportResponse {
+            success: false,
+            report: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Memory usage, encoding, refcount, idle time and TTL of a single key
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, database = %database))]
+pub async fn redis_key_info(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    key: String,
+) -> Result<KeyMetadataResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().key_metadata {
+        return Ok(KeyMetadataResponse {
+            success: false,
+            metadata: None,
+            error: Some(KEY_METADATA_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    let namespace = Namespace::new(database);
+
+    match driver.key_metadata(session, &namespace, &key).await {
+        Ok(metadata) => Ok(KeyMetadataResponse {
+            success: true,
+            metadata: Some(metadata),
+            error: None,
+        }),
+        Err(e) => Ok(KeyMetadataResponse {
+            success: false,
+            metadata: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Samples the keyspace and reports the largest keys by memory, plus totals
+/// per type. Pass `query_id` to be able to stop it with `cancel_query`.
+#[tauri::command]
+#[instrument(
+    skip(state),
+    fields(session_id = %session_id, database = %database, sample_size = ?sample_size)
+)]
+pub async fn redis_big_keys(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    sample_size: Option<u64>,
+    top_n: Option<usize>,
+    query_id: Option<String>,
+) -> Result<BigKeysResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let query_id = match query_id {
+        Some(raw) => {
+            QueryId(uuid::Uuid::parse_str(&raw).map_err(|e| format!("Invalid query ID: {}", e))?)
+        }
+        None => QueryId::new(),
+    };
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().big_keys {
+        return Ok(BigKeysResponse {
+            success: false,
+            report: None,
+            error: Some(BIG_KEYS_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    let namespace = Namespace::new(database);
+    let sample_size = sample_size
+        .unwrap_or(DEFAULT_BIG_KEYS_SAMPLE)
+        .clamp(1, MAX_BIG_KEYS_SAMPLE);
+    let top_n = top_n
+        .unwrap_or(DEFAULT_BIG_KEYS_TOP)
+        .clamp(1, MAX_BIG_KEYS_TOP);
+
+    match driver
+        .big_keys(session, &namespace, sample_size, top_n, query_id)
+        .await
+    {
+        Ok(report) => Ok(BigKeysResponse {
+            success: true,
+            report: Some(report),
+            error: None,
+        }),
+        Err(e) => Ok(BigKeysResponse {
+            success: false,
+            report: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Phase and percent-complete of a running VACUUM, CREATE INDEX, ANALYZE,
+/// CLUSTER or COPY started as `query_id`
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, query_id = %query_id))]
+pub async fn query_progress(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    query_id: String,
+) -> Result<QueryProgressResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+    let query_id =
+        QueryId(uuid::Uuid::parse_str(&query_id).map_err(|e| format!("Invalid query ID: {}", e))?);
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().query_progress {
+        return Ok(QueryProgressResponse {
+            success: false,
+            progress: None,
+            error: Some(QUERY_PROGRESS_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    match driver.query_progress(session, query_id).await {
+        Ok(progress) => Ok(QueryProgressResponse {
+            success: true,
+            progress: Some(progress),
+            error: None,
+        }),
+        Err(e) => Ok(QueryProgressResponse {
+            success: false,
+            progress: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
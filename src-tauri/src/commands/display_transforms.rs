@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::State;
+
+use crate::display_transforms::DisplayTransform;
+
+#[derive(Debug, Serialize)]
+pub struct DisplayTransformsResponse {
+    pub success: bool,
+    pub transforms: Option<Vec<DisplayTransform>>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DisplayTransformMutationResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[tauri::command]
+pub async fn list_display_transforms(
+    state: State<'_, crate::SharedState>,
+    connection_id: String,
+) -> Result<DisplayTransformsResponse, String> {
+    let store = {
+        let state = state.lock().await;
+        Arc::clone(&state.display_transforms)
+    };
+    let transforms = store.list(&connection_id);
+    Ok(DisplayTransformsResponse {
+        success: true,
+        transforms: Some(transforms),
+        error: None,
+    })
+}
+
+#[tauri::command]
+pub async fn set_display_transform(
+    state: State<'_, crate::SharedState>,
+    connection_id: String,
+    transform: DisplayTransform,
+) -> Result<DisplayTransformMutationResponse, String> {
+    let store = {
+        let state = state.lock().await;
+        Arc::clone(&state.display_transforms)
+    };
+    match store.set(&connection_id, transform) {
+        Ok(()) => Ok(DisplayTransformMutationResponse {
+            success: true,
+            error: None,
+        }),
+        Err(e) => Ok(DisplayTransformMutationResponse {
+            success: false,
+            error: Some(e),
+        }),
+    }
+}
+
+#[tauri::command]
+pub async fn remove_display_transform(
+    state: State<'_, crate::SharedState>,
+    connection_id: String,
+    database: String,
+    schema: Option<String>,
+    table: String,
+    column: String,
+) -> Result<DisplayTransformMutationResponse, String> {
+    let store = {
+        let state = state.lock().await;
+        Arc::clone(&state.display_transforms)
+    };
+    match store.remove(
+        &connection_id,
+        &database,
+        schema.as_deref(),
+        &table,
+        &column,
+    ) {
+        Ok(()) => Ok(DisplayTransformMutationResponse {
+            success: true,
+            error: None,
+        }),
+        Err(e) => Ok(DisplayTransformMutationResponse {
+            success: false,
+            error: Some(e),
+        }),
+    }
+}
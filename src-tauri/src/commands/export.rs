@@ -3,10 +3,18 @@
 use std::sync::Arc;
 
 use tauri::State;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use super::parse_session_id;
-use crate::export::types::{ExportCancelResponse, ExportConfig, ExportStartResponse};
+use crate::engine::types::{CopyOptions, CopySource, Namespace};
+use crate::export::pipeline::validate_output_path;
+use crate::export::types::{
+    CopyExportResponse, ExportCancelResponse, ExportConfig, ExportStartResponse,
+};
+
+const COPY_CHANNEL_CAPACITY: usize = 16;
 
 fn parse_export_id(id: &str) -> Result<String, String> {
     Uuid::parse_str(id).map_err(|e| format!("Invalid export ID: {}", e))?;
@@ -86,3 +94,109 @@ pub async fn cancel_export(
         }),
     }
 }
+
+/// Exports a query or table through the driver's server-side COPY, writing
+/// the bytes exactly as the server formats them.
+#[tauri::command]
+pub async fn export_via_copy(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    source: CopySource,
+    options: CopyOptions,
+    output_path: String,
+) -> Result<CopyExportResponse, String> {
+    let (session_manager, query_rate_limiter, interceptor, policy) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.interceptor),
+            state.policy.clone(),
+        )
+    };
+
+    let failed = |error: String| CopyExportResponse {
+        success: false,
+        path: output_path.clone(),
+        bytes_written: 0,
+        error: Some(error),
+    };
+
+    let session = parse_session_id(&session_id)?;
+    if let Err(e) = validate_output_path(&output_path) {
+        return Ok(failed(e));
+    }
+
+    // COPY (query) accepts data-modifying statements with RETURNING, so the
+    // source goes through the same preflight as start_export and must read.
+    let checked_query = match &source {
+        CopySource::Query { query } => query.clone(),
+        CopySource::Table { table } => {
+            format!("SELECT * FROM \"{}\"", table.replace('"', "\"\""))
+        }
+    };
+    let preflight = qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session_id,
+        &checked_query,
+        Some(&namespace),
+        false,
+    )
+    .await?;
+    if preflight.is_mutation || preflight.is_dangerous {
+        return Ok(failed(
+            "COPY export only accepts read-only queries".to_string(),
+        ));
+    }
+    if !preflight.driver.capabilities().copy_export {
+        return Ok(failed(
+            "COPY export is not supported by this driver".to_string(),
+        ));
+    }
+
+    let mut file = match tokio::fs::File::create(&output_path).await {
+        Ok(file) => file,
+        Err(e) => return Ok(failed(format!("Failed to create output file: {}", e))),
+    };
+
+    let (sender, mut receiver) = mpsc::channel::<Vec<u8>>(COPY_CHANNEL_CAPACITY);
+    let copy = preflight
+        .driver
+        .copy_out(session, &namespace, &source, &options, sender);
+    let write = async move {
+        let mut bytes_written = 0u64;
+        while let Some(chunk) = receiver.recv().await {
+            file.write_all(&chunk).await?;
+            bytes_written += chunk.len() as u64;
+        }
+        file.flush().await?;
+        Ok::<u64, std::io::Error>(bytes_written)
+    };
+    let (copy_result, write_result) = tokio::join!(copy, write);
+
+    // A failed write drops the receiver, which surfaces as a cancelled COPY:
+    // report the I/O error first.
+    let outcome = match (write_result, copy_result) {
+        (Err(e), _) => Err(format!("Failed to write output file: {}", e)),
+        (Ok(_), Err(e)) => Err(e.sanitized_message()),
+        (Ok(bytes_written), Ok(())) => Ok(bytes_written),
+    };
+
+    match outcome {
+        Ok(bytes_written) => Ok(CopyExportResponse {
+            success: true,
+            path: output_path.clone(),
+            bytes_written,
+            error: None,
+        }),
+        Err(error) => {
+            let _ = tokio::fs::remove_file(&output_path).await;
+            Ok(failed(error))
+        }
+    }
+}
@@ -34,6 +34,56 @@ pub async fn list_federation_sources(
     Err(PRO_REQUIRED.to_string())
 }
 
+#[cfg(not(feature = "pro"))]
+#[tauri::command]
+pub async fn plan_federation_query(
+    _state: State<'_, SharedState>,
+    _query: String,
+    _alias_map: HashMap<String, String>,
+    _options: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    Err(PRO_REQUIRED.to_string())
+}
+
+#[cfg(not(feature = "pro"))]
+#[tauri::command]
+pub async fn register_virtual_table(
+    _state: State<'_, SharedState>,
+    _alias: String,
+    _session_id: String,
+    _query: String,
+    _namespace: Option<serde_json::Value>,
+    _options: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    Err(PRO_REQUIRED.to_string())
+}
+
+#[cfg(not(feature = "pro"))]
+#[tauri::command]
+pub async fn refresh_virtual_table(
+    _state: State<'_, SharedState>,
+    _alias: String,
+) -> Result<serde_json::Value, String> {
+    Err(PRO_REQUIRED.to_string())
+}
+
+#[cfg(not(feature = "pro"))]
+#[tauri::command]
+pub async fn list_virtual_tables(
+    _state: State<'_, SharedState>,
+) -> Result<Vec<serde_json::Value>, String> {
+    Err(PRO_REQUIRED.to_string())
+}
+
+#[cfg(not(feature = "pro"))]
+#[tauri::command]
+pub async fn drop_virtual_table(
+    _state: State<'_, SharedState>,
+    _alias: String,
+) -> Result<bool, String> {
+    Err(PRO_REQUIRED.to_string())
+}
+
 /// Response for federation queries. Extends QueryResponse with federation metadata.
 #[derive(Debug, Serialize)]
 pub struct FederationQueryResponse {
@@ -67,20 +117,26 @@ use std::sync::Arc;
 #[cfg(feature = "pro")]
 use tauri::ipc::{Channel, InvokeResponseBody};
 
+#[cfg(feature = "pro")]
+use tracing::instrument;
+
 #[cfg(feature = "pro")]
 use uuid::Uuid;
 
+#[cfg(feature = "pro")]
+use super::parse_session_id;
 #[cfg(feature = "pro")]
 use crate::commands::stream_msg::StreamDispatcher;
 #[cfg(feature = "pro")]
 use crate::engine::traits::StreamEvent;
 #[cfg(feature = "pro")]
-use super::parse_session_id;
+use crate::engine::types::Namespace;
 #[cfg(feature = "pro")]
 use crate::federation::manager;
 #[cfg(feature = "pro")]
 use crate::federation::types::{
-    AliasEntry, ConnectionAliasMap, FederationQueryOptions, FederationSource,
+    AliasEntry, ConnectionAliasMap, FederationPlanPreview, FederationQueryOptions,
+    FederationSource, VirtualTableInfo, VirtualTableOptions,
 };
 
 /// Executes a cross-database federation query.
@@ -118,9 +174,12 @@ pub async fn execute_federation_query(
         resolve_alias_map(&alias_map, &app_state.session_manager).await?
     };
 
-    let session_manager = {
+    let (session_manager, virtual_tables) = {
         let app_state = state.lock().await;
-        Arc::clone(&app_state.session_manager)
+        (
+            Arc::clone(&app_state.session_manager),
+            Arc::clone(&app_state.virtual_tables),
+        )
     };
 
     if streaming {
@@ -131,11 +190,13 @@ pub async fn execute_federation_query(
         let options_clone = options.clone();
         let resolved_map_clone = resolved_map.clone();
         let sm = Arc::clone(&session_manager);
+        let vt = Arc::clone(&virtual_tables);
 
         let handle = tokio::spawn(async move {
             manager::execute_federation_stream(
                 &query_clone,
                 &resolved_map_clone,
+                &vt,
                 &sm,
                 &options_clone,
                 tx,
@@ -169,7 +230,15 @@ pub async fn execute_federation_query(
             federation: Some(convert_metadata(&meta)),
         })
     } else {
-        match manager::execute_federation(&query, &resolved_map, &session_manager, &options).await {
+        match manager::execute_federation(
+            &query,
+            &resolved_map,
+            &virtual_tables,
+            &session_manager,
+            &options,
+        )
+        .await
+        {
             Ok((result, meta)) => Ok(FederationQueryResponse {
                 success: true,
                 result: Some(result),
@@ -218,6 +287,200 @@ pub async fn list_federation_sources(
     Ok(sources)
 }
 
+/// Response for a federation plan preview.
+#[cfg(feature = "pro")]
+#[derive(Debug, Serialize)]
+pub struct FederationPlanResponse {
+    pub success: bool,
+    pub plan: Option<FederationPlanPreview>,
+    pub error: Option<String>,
+}
+
+/// Plans a federation query without executing it and returns the sub-query
+/// each source connection would receive. Only `row_limit_per_source` is read
+/// from `options`.
+#[cfg(feature = "pro")]
+#[tauri::command]
+#[instrument(skip(state, query, alias_map, options), fields(query_len = query.len()))]
+pub async fn plan_federation_query(
+    state: State<'_, SharedState>,
+    query: String,
+    alias_map: HashMap<String, String>,
+    options: Option<FederationQueryOptions>,
+) -> Result<FederationPlanResponse, String> {
+    let (resolved_map, virtual_tables) = {
+        let app_state = state.lock().await;
+        (
+            resolve_alias_map(&alias_map, &app_state.session_manager).await?,
+            Arc::clone(&app_state.virtual_tables),
+        )
+    };
+    let row_limit = options.and_then(|options| options.row_limit_per_source);
+
+    match manager::plan_only(&query, &resolved_map, &virtual_tables, row_limit) {
+        Ok(plan) => Ok(FederationPlanResponse {
+            success: true,
+            plan: Some(plan),
+            error: None,
+        }),
+        Err(e) => Ok(FederationPlanResponse {
+            success: false,
+            plan: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Response for virtual table capture and refresh.
+#[cfg(feature = "pro")]
+#[derive(Debug, Serialize)]
+pub struct VirtualTableResponse {
+    pub success: bool,
+    pub table: Option<VirtualTableInfo>,
+    pub error: Option<String>,
+}
+
+#[cfg(feature = "pro")]
+impl VirtualTableResponse {
+    fn from_result(result: Result<VirtualTableInfo, String>) -> Self {
+        match result {
+            Ok(table) => Self {
+                success: true,
+                table: Some(table),
+                error: None,
+            },
+            Err(e) => Self {
+                success: false,
+                table: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+/// Runs `query` once on `session_id` and keeps the (capped) result as a
+/// virtual table, referenced by its bare `alias` in federated queries.
+/// Re-using an alias replaces the previous snapshot.
+#[cfg(feature = "pro")]
+#[tauri::command]
+#[instrument(skip(state, query, options), fields(session_id = %session_id, alias = %alias))]
+pub async fn register_virtual_table(
+    state: State<'_, SharedState>,
+    alias: String,
+    session_id: String,
+    query: String,
+    namespace: Option<Namespace>,
+    options: Option<VirtualTableOptions>,
+) -> Result<VirtualTableResponse, String> {
+    let result = capture_virtual_table(
+        &state,
+        &alias,
+        &session_id,
+        &query,
+        namespace,
+        options.unwrap_or_default(),
+    )
+    .await;
+    Ok(VirtualTableResponse::from_result(result))
+}
+
+/// Re-runs the query a virtual table was captured from and resets its TTL.
+#[cfg(feature = "pro")]
+#[tauri::command]
+#[instrument(skip(state), fields(alias = %alias))]
+pub async fn refresh_virtual_table(
+    state: State<'_, SharedState>,
+    alias: String,
+) -> Result<VirtualTableResponse, String> {
+    let virtual_tables = Arc::clone(&state.lock().await.virtual_tables);
+    let result = match virtual_tables.get(&alias) {
+        Ok(table) => {
+            let options = VirtualTableOptions {
+                max_rows: Some(table.max_rows),
+                ttl_secs: Some(table.ttl.map(|ttl| ttl.as_secs()).unwrap_or(0)),
+            };
+            capture_virtual_table(
+                &state,
+                &table.alias,
+                &table.session_id.0.to_string(),
+                &table.query,
+                table.namespace.clone(),
+                options,
+            )
+            .await
+        }
+        Err(e) => Err(e.sanitized_message()),
+    };
+    Ok(VirtualTableResponse::from_result(result))
+}
+
+#[cfg(feature = "pro")]
+#[tauri::command]
+pub async fn list_virtual_tables(
+    state: State<'_, SharedState>,
+) -> Result<Vec<VirtualTableInfo>, String> {
+    Ok(state.lock().await.virtual_tables.list())
+}
+
+/// Returns `true` when the virtual table existed.
+#[cfg(feature = "pro")]
+#[tauri::command]
+#[instrument(skip(state), fields(alias = %alias))]
+pub async fn drop_virtual_table(
+    state: State<'_, SharedState>,
+    alias: String,
+) -> Result<bool, String> {
+    Ok(state.lock().await.virtual_tables.remove(&alias))
+}
+
+#[cfg(feature = "pro")]
+async fn capture_virtual_table(
+    state: &State<'_, SharedState>,
+    alias: &str,
+    session_id: &str,
+    query: &str,
+    namespace: Option<Namespace>,
+    options: VirtualTableOptions,
+) -> Result<VirtualTableInfo, String> {
+    let (session_manager, query_rate_limiter, interceptor, policy, virtual_tables) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.interceptor),
+            state.policy.clone(),
+            Arc::clone(&state.virtual_tables),
+        )
+    };
+
+    let session = parse_session_id(session_id)?;
+
+    // Same safety preflight as execute_query: the capture runs on the live
+    // connection, so read-only and production guards apply.
+    qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        session_id,
+        query,
+        namespace.as_ref(),
+        false,
+    )
+    .await?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    virtual_tables
+        .register(driver, session, alias, query, namespace, &options)
+        .await
+        .map_err(|e| e.sanitized_message())
+}
+
 #[cfg(feature = "pro")]
 async fn resolve_alias_map(
     alias_map: &HashMap<String, String>,
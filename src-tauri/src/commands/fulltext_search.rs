@@ -10,18 +10,25 @@
 //! - Progressive result streaming
 
 use futures::stream::{self, StreamExt};
+use parking_lot::Mutex;
 use serde::Serialize;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, State};
 use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, instrument, warn};
+use uuid::Uuid;
 
 use crate::engine::fulltext_strategy::{
-    get_capability_cache, get_search_strategy, FulltextIndexInfo, SearchMethod, TableSearchOptions,
+    get_capability_cache, get_search_strategy, FulltextIndexInfo, FulltextSearchStrategy,
+    SearchMethod, TableSearchOptions,
+};
+use crate::engine::traits::DataEngine;
+use crate::engine::types::{
+    CollectionListOptions, CollectionType, Namespace, QueryId, SessionId, Value,
 };
-use crate::engine::types::{CollectionListOptions, CollectionType, Namespace, QueryId, Value};
 
 use super::{parse_session_id, SharedStateExt};
 
@@ -31,16 +38,21 @@ const MAX_PARALLEL_TABLES: usize = 5;
 /// Default timeout per table search (milliseconds)
 const DEFAULT_TABLE_TIMEOUT_MS: u64 = 5000;
 
+/// Event topic carrying the results of streamed searches.
+const FULLTEXT_SEARCH_EVENT: &str = "fulltext-search";
+
 #[derive(Debug, Clone, Serialize)]
 pub struct FulltextMatch {
     pub namespace: Namespace,
     pub table_name: String,
     pub column_name: String,
     pub value_preview: String,
+    /// Character offset of the first occurrence of the term in the value
+    pub match_offset: usize,
     pub row_preview: Vec<(String, Value)>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct SearchStats {
     pub native_fulltext_count: u32,
     pub pattern_match_count: u32,
@@ -81,6 +93,80 @@ pub struct FulltextSearchOptions {
     pub stream_results: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FulltextSearchEvent {
+    /// Matches of one table, sent as soon as that table is searched.
+    Matches { matches: Vec<FulltextMatch> },
+    /// Sent after each searched table.
+    Progress(SearchProgressEvent),
+    /// The search finished, reached `max_total_results` or was cancelled.
+    Done {
+        total_matches: u64,
+        tables_searched: u32,
+        search_time_ms: f64,
+        truncated: bool,
+        cancelled: bool,
+        stats: SearchStats,
+    },
+    /// The tables to search could not be listed.
+    Failed { error: String },
+}
+
+#[derive(Debug, Serialize)]
+pub struct FulltextSearchStreamResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl FulltextSearchStreamResponse {
+    fn failure(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Registry of streamed searches, keyed by search id.
+#[derive(Default)]
+pub struct FulltextSearches {
+    inner: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl FulltextSearches {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a search and returns its cancellation token. Fails when the
+    /// id is already in use.
+    fn register(&self, search_id: &str) -> Result<CancellationToken, String> {
+        let mut inner = self.inner.lock();
+        if inner.contains_key(search_id) {
+            return Err(format!("Search '{}' is already running", search_id));
+        }
+        let token = CancellationToken::new();
+        inner.insert(search_id.to_string(), token.clone());
+        Ok(token)
+    }
+
+    fn deregister(&self, search_id: &str) {
+        self.inner.lock().remove(search_id);
+    }
+
+    /// Returns `true` when the search was found and stopped.
+    pub fn cancel(&self, search_id: &str) -> bool {
+        match self.inner.lock().remove(search_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 fn is_text_type(data_type: &str) -> bool {
     let dt = data_type.to_lowercase();
     dt.contains("char")
@@ -140,7 +226,8 @@ fn value_to_preview(value: &Value, max_len: usize) -> String {
     }
 }
 
-fn value_contains(value: &Value, search_term: &str, case_sensitive: bool) -> bool {
+/// Character offset of the first occurrence of `search_term` in `value`.
+fn match_offset(value: &Value, search_term: &str, case_sensitive: bool) -> Option<usize> {
     let text = match value {
         Value::Text(t) => t.clone(),
         Value::Json(j) => j.to_string(),
@@ -149,86 +236,107 @@ fn value_contains(value: &Value, search_term: &str, case_sensitive: bool) -> boo
         Value::Bool(b) => b.to_string(),
         Value::Bytes(bytes) => String::from_utf8_lossy(bytes).to_string(),
         Value::Array(a) => format!("[{} items]", a.len()),
-        Value::Null => return false,
+        Value::Null => return None,
     };
 
-    if case_sensitive {
-        text.contains(search_term)
+    let (text, search_term) = if case_sensitive {
+        (text, search_term.to_string())
     } else {
-        text.to_lowercase().contains(&search_term.to_lowercase())
-    }
+        (text.to_lowercase(), search_term.to_lowercase())
+    };
+    text.find(&search_term)
+        .map(|position| text[..position].chars().count())
 }
 
 struct TableSearchResult {
+    /// `database.table`, for progress reporting
+    table: String,
     matches: Vec<FulltextMatch>,
     method: SearchMethod,
     timed_out: bool,
     error: Option<String>,
 }
 
-#[tauri::command]
-#[instrument(skip(state, app_handle), fields(session_id = %session_id, search_term_len = search_term.len()))]
-pub async fn fulltext_search(
-    state: State<'_, crate::SharedState>,
-    app_handle: AppHandle,
-    session_id: String,
-    search_term: String,
-    options: Option<FulltextSearchOptions>,
-) -> Result<FulltextSearchResponse, String> {
-    let start_time = std::time::Instant::now();
+/// A table to search and the columns whose values can match.
+struct SearchTarget {
+    namespace: Namespace,
+    table_name: String,
+    text_columns: Vec<String>,
+}
 
+/// Options resolved against their defaults and caps.
+struct SearchLimits {
+    table: TableSearchOptions,
+    max_total: u32,
+    max_parallel: usize,
+}
+
+impl SearchLimits {
+    fn new(search_term: &str, opts: &FulltextSearchOptions) -> Self {
+        Self {
+            table: TableSearchOptions {
+                search_term: search_term.to_string(),
+                case_sensitive: opts.case_sensitive.unwrap_or(false),
+                max_results: opts.max_results_per_table.unwrap_or(10).min(50),
+                timeout_ms: Some(
+                    opts.timeout_per_table_ms
+                        .unwrap_or(DEFAULT_TABLE_TIMEOUT_MS),
+                ),
+                prefer_native: true,
+            },
+            max_total: opts.max_total_results.unwrap_or(100).min(500),
+            max_parallel: opts.max_parallel.unwrap_or(MAX_PARALLEL_TABLES).min(10),
+        }
+    }
+}
+
+/// Returns the trimmed term, or why it cannot be searched.
+fn validate_search_term(search_term: &str) -> Result<&str, &'static str> {
     let search_term = search_term.trim();
     if search_term.is_empty() {
-        return Ok(empty_response("Search term cannot be empty"));
+        return Err("Search term cannot be empty");
     }
-
     if search_term.len() < 2 {
-        return Ok(empty_response("Search term must be at least 2 characters"));
+        return Err("Search term must be at least 2 characters");
     }
+    Ok(search_term)
+}
 
-    let opts = options.unwrap_or_default();
-    let max_per_table = opts.max_results_per_table.unwrap_or(10).min(50);
-    let max_total = opts.max_total_results.unwrap_or(100).min(500);
-    let case_sensitive = opts.case_sensitive.unwrap_or(false);
-    let table_timeout_ms = opts
-        .timeout_per_table_ms
-        .unwrap_or(DEFAULT_TABLE_TIMEOUT_MS);
-    let max_parallel = opts.max_parallel.unwrap_or(MAX_PARALLEL_TABLES).min(10);
-    let stream_results = opts.stream_results.unwrap_or(false);
-
-    let session_manager = state.session_manager().await;
-
-    let session = parse_session_id(&session_id)?;
-
-    let driver = match session_manager.get_driver(session).await {
-        Ok(d) => d,
-        Err(e) => {
-            return Ok(error_response(
-                &e.sanitized_message(),
-                start_time.elapsed().as_secs_f64() * 1000.0,
-            ));
+fn record_stats(stats: &mut SearchStats, result: &TableSearchResult) {
+    match result.method {
+        SearchMethod::NativeFulltext => stats.native_fulltext_count += 1,
+        SearchMethod::PatternMatch => stats.pattern_match_count += 1,
+        SearchMethod::Hybrid => {
+            stats.native_fulltext_count += 1;
+            stats.pattern_match_count += 1;
         }
-    };
+    }
 
-    let driver_id = driver.driver_id();
-    let search_strategy = get_search_strategy(driver_id);
-    let capability_cache = get_capability_cache();
+    if result.timed_out {
+        stats.timeout_count += 1;
+    }
 
-    let namespaces = if let Some(ns) = opts.namespaces {
-        ns
-    } else {
-        match driver.list_namespaces(session).await {
-            Ok(ns) => ns,
-            Err(e) => {
-                return Ok(error_response(
-                    &format!("Failed to list namespaces: {}", e),
-                    start_time.elapsed().as_secs_f64() * 1000.0,
-                ));
-            }
-        }
+    if result.error.is_some() {
+        stats.error_count += 1;
+    }
+}
+
+/// Lists the user tables that have at least one searchable column, limited
+/// to `opts.namespaces` and `opts.tables` when given.
+async fn list_search_targets(
+    driver: &Arc<dyn DataEngine>,
+    session: SessionId,
+    opts: &FulltextSearchOptions,
+) -> Result<Vec<SearchTarget>, String> {
+    let namespaces = match &opts.namespaces {
+        Some(ns) => ns.clone(),
+        None => driver
+            .list_namespaces(session)
+            .await
+            .map_err(|e| format!("Failed to list namespaces: {}", e))?,
     };
 
-    let mut tables_to_search: Vec<(Namespace, String, Vec<String>)> = Vec::new();
+    let mut targets = Vec::new();
 
     let is_sqlite = driver.driver_id().eq_ignore_ascii_case("sqlite");
 
@@ -286,11 +394,212 @@ pub async fn fulltext_search(
             };
 
             if !text_columns.is_empty() {
-                tables_to_search.push((namespace.clone(), collection.name, text_columns));
+                targets.push(SearchTarget {
+                    namespace: namespace.clone(),
+                    table_name: collection.name,
+                    text_columns,
+                });
             }
         }
     }
 
+    Ok(targets)
+}
+
+/// Searches one table, keeping at most `search_options.max_results` matches.
+async fn search_table(
+    driver: &Arc<dyn DataEngine>,
+    session: SessionId,
+    strategy: &dyn FulltextSearchStrategy,
+    target: SearchTarget,
+    search_options: &TableSearchOptions,
+) -> TableSearchResult {
+    let SearchTarget {
+        namespace,
+        table_name,
+        text_columns,
+    } = target;
+    let is_sqlite = driver.driver_id().eq_ignore_ascii_case("sqlite");
+    let max_per_table = search_options.max_results;
+    let table_timeout_ms = search_options
+        .timeout_ms
+        .unwrap_or(DEFAULT_TABLE_TIMEOUT_MS);
+    let capability_cache = get_capability_cache();
+
+    let text_column_set: HashSet<String> = text_columns.iter().map(|c| c.to_lowercase()).collect();
+    let capability = if let Some(cached) = capability_cache.get(&namespace, &table_name).await {
+        debug!(
+            "Using cached capability for {}.{}",
+            namespace.database, table_name
+        );
+        cached
+    } else {
+        let detected_indexes =
+            detect_fulltext_indexes(driver, session, strategy, &namespace, &table_name).await;
+
+        let capability = strategy.build_capability(&text_columns, &detected_indexes, None);
+
+        capability_cache
+            .set(&namespace, &table_name, capability.clone())
+            .await;
+
+        capability
+    };
+
+    let (query, method) =
+        strategy.build_search_query(&namespace, &table_name, &capability, search_options);
+    if is_sqlite {
+        debug!(
+            "SQLite search query for {}.{} ({} cols): {}",
+            namespace.database,
+            table_name,
+            text_columns.len(),
+            query
+        );
+    }
+
+    let query_id = QueryId::new();
+    let search_future =
+        driver.execute_in_namespace(session, Some(namespace.clone()), &query, query_id);
+
+    let timeout_duration = Duration::from_millis(table_timeout_ms);
+    let result = timeout(timeout_duration, search_future).await;
+    let table = format!("{}.{}", namespace.database, table_name);
+
+    match result {
+        Ok(Ok(query_result)) => {
+            if is_sqlite {
+                debug!(
+                    "SQLite search result {}.{}: {} rows, {} cols",
+                    namespace.database,
+                    table_name,
+                    query_result.rows.len(),
+                    query_result.columns.len()
+                );
+            }
+            let mut matches = Vec::new();
+
+            for row in query_result.rows {
+                for (idx, col_info) in query_result.columns.iter().enumerate() {
+                    if let Some(value) = row.values.get(idx) {
+                        let col_name: String = col_info.name.as_str().to_lowercase();
+                        let is_searchable = text_column_set.contains(&col_name)
+                            || is_text_type(&col_info.data_type);
+                        if !is_searchable {
+                            continue;
+                        }
+                        if let Some(match_offset) = match_offset(
+                            value,
+                            &search_options.search_term,
+                            search_options.case_sensitive,
+                        ) {
+                            let row_preview: Vec<(String, Value)> = query_result
+                                .columns
+                                .iter()
+                                .zip(row.values.iter())
+                                .map(|(c, v)| (c.name.to_string(), v.clone()))
+                                .collect();
+
+                            matches.push(FulltextMatch {
+                                namespace: namespace.clone(),
+                                table_name: table_name.clone(),
+                                column_name: col_info.name.to_string(),
+                                value_preview: value_to_preview(value, 100),
+                                match_offset,
+                                row_preview,
+                            });
+
+                            if matches.len() >= max_per_table as usize {
+                                break;
+                            }
+                        }
+                    }
+                }
+                if matches.len() >= max_per_table as usize {
+                    break;
+                }
+            }
+
+            TableSearchResult {
+                table,
+                matches,
+                method,
+                timed_out: false,
+                error: None,
+            }
+        }
+        Ok(Err(e)) => {
+            warn!(
+                "Search error in {}.{}: {}",
+                namespace.database, table_name, e
+            );
+            TableSearchResult {
+                table,
+                matches: vec![],
+                method,
+                timed_out: false,
+                error: Some(e.sanitized_message()),
+            }
+        }
+        Err(_) => {
+            warn!("Search timeout in {}.{}", namespace.database, table_name);
+            TableSearchResult {
+                table,
+                matches: vec![],
+                method,
+                timed_out: true,
+                error: None,
+            }
+        }
+    }
+}
+
+#[tauri::command]
+#[instrument(skip(state, app_handle), fields(session_id = %session_id, search_term_len = search_term.len()))]
+pub async fn fulltext_search(
+    state: State<'_, crate::SharedState>,
+    app_handle: AppHandle,
+    session_id: String,
+    search_term: String,
+    options: Option<FulltextSearchOptions>,
+) -> Result<FulltextSearchResponse, String> {
+    let start_time = std::time::Instant::now();
+
+    let search_term = match validate_search_term(&search_term) {
+        Ok(term) => term,
+        Err(msg) => return Ok(empty_response(msg)),
+    };
+
+    let opts = options.unwrap_or_default();
+    let limits = SearchLimits::new(search_term, &opts);
+    let stream_results = opts.stream_results.unwrap_or(false);
+
+    let session_manager = state.session_manager().await;
+
+    let session = parse_session_id(&session_id)?;
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => {
+            return Ok(error_response(
+                &e.sanitized_message(),
+                start_time.elapsed().as_secs_f64() * 1000.0,
+            ));
+        }
+    };
+
+    let search_strategy = get_search_strategy(driver.driver_id());
+
+    let tables_to_search = match list_search_targets(&driver, session, &opts).await {
+        Ok(targets) => targets,
+        Err(e) => {
+            return Ok(error_response(
+                &e,
+                start_time.elapsed().as_secs_f64() * 1000.0,
+            ));
+        }
+    };
+
     let total_tables = tables_to_search.len() as u32;
 
     if total_tables == 0 {
@@ -311,166 +620,33 @@ pub async fn fulltext_search(
         });
     }
 
-    let search_options = TableSearchOptions {
-        search_term: search_term.to_string(),
-        case_sensitive,
-        max_results: max_per_table,
-        timeout_ms: Some(table_timeout_ms),
-        prefer_native: true,
-    };
-
     let tables_searched_counter = Arc::new(std::sync::atomic::AtomicU32::new(0));
     let matches_found_counter = Arc::new(std::sync::atomic::AtomicU32::new(0));
 
     let driver_ref = &driver;
     let strategy_ref = search_strategy.as_ref();
-    let search_options_ref = &search_options;
-    let capability_cache_ref = &capability_cache;
+    let search_options_ref = &limits.table;
     let app_handle_ref = &app_handle;
     let session_id_ref = &session_id;
     let tables_searched_counter_ref = &tables_searched_counter;
     let matches_found_counter_ref = &matches_found_counter;
 
     let results: Vec<TableSearchResult> = stream::iter(tables_to_search)
-        .map(|(namespace, table_name, text_columns)| async move {
-            let text_column_set: HashSet<String> =
-                text_columns.iter().map(|c| c.to_lowercase()).collect();
-            let capability =
-                if let Some(cached) = capability_cache_ref.get(&namespace, &table_name).await {
-                    debug!(
-                        "Using cached capability for {}.{}",
-                        namespace.database, table_name
-                    );
-                    cached
-                } else {
-                    let detected_indexes = detect_fulltext_indexes(
-                        driver_ref,
-                        session,
-                        strategy_ref,
-                        &namespace,
-                        &table_name,
-                    )
-                    .await;
-
-                    let capability =
-                        strategy_ref.build_capability(&text_columns, &detected_indexes, None);
-
-                    capability_cache_ref
-                        .set(&namespace, &table_name, capability.clone())
-                        .await;
-
-                    capability
-                };
-
-            let (query, method) = strategy_ref.build_search_query(
-                &namespace,
-                &table_name,
-                &capability,
+        .map(|target| async move {
+            let table_result = search_table(
+                driver_ref,
+                session,
+                strategy_ref,
+                target,
                 search_options_ref,
-            );
-            if is_sqlite {
-                debug!(
-                    "SQLite search query for {}.{} ({} cols): {}",
-                    namespace.database,
-                    table_name,
-                    text_columns.len(),
-                    query
-                );
-            }
-
-            let query_id = QueryId::new();
-            let search_future =
-                driver_ref.execute_in_namespace(session, Some(namespace.clone()), &query, query_id);
-
-            let timeout_duration = Duration::from_millis(table_timeout_ms);
-            let result = timeout(timeout_duration, search_future).await;
+            )
+            .await;
 
             tables_searched_counter_ref.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-
-            let table_result = match result {
-                Ok(Ok(query_result)) => {
-                    if is_sqlite {
-                        debug!(
-                            "SQLite search result {}.{}: {} rows, {} cols",
-                            namespace.database,
-                            table_name,
-                            query_result.rows.len(),
-                            query_result.columns.len()
-                        );
-                    }
-                    let mut matches = Vec::new();
-
-                    for row in query_result.rows {
-                        for (idx, col_info) in query_result.columns.iter().enumerate() {
-                            if let Some(value) = row.values.get(idx) {
-                                let col_name: String = col_info.name.as_str().to_lowercase();
-                                let is_searchable = text_column_set.contains(&col_name)
-                                    || is_text_type(&col_info.data_type);
-                                if is_searchable
-                                    && value_contains(
-                                        value,
-                                        &search_options_ref.search_term,
-                                        case_sensitive,
-                                    )
-                                {
-                                    let row_preview: Vec<(String, Value)> = query_result
-                                        .columns
-                                        .iter()
-                                        .zip(row.values.iter())
-                                        .map(|(c, v)| (c.name.to_string(), v.clone()))
-                                        .collect();
-
-                                    matches.push(FulltextMatch {
-                                        namespace: namespace.clone(),
-                                        table_name: table_name.clone(),
-                                        column_name: col_info.name.to_string(),
-                                        value_preview: value_to_preview(value, 100),
-                                        row_preview,
-                                    });
-
-                                    if matches.len() >= max_per_table as usize {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                        if matches.len() >= max_per_table as usize {
-                            break;
-                        }
-                    }
-
-                    matches_found_counter_ref
-                        .fetch_add(matches.len() as u32, std::sync::atomic::Ordering::SeqCst);
-
-                    TableSearchResult {
-                        matches,
-                        method,
-                        timed_out: false,
-                        error: None,
-                    }
-                }
-                Ok(Err(e)) => {
-                    warn!(
-                        "Search error in {}.{}: {}",
-                        namespace.database, table_name, e
-                    );
-                    TableSearchResult {
-                        matches: vec![],
-                        method,
-                        timed_out: false,
-                        error: Some(e.sanitized_message()),
-                    }
-                }
-                Err(_) => {
-                    warn!("Search timeout in {}.{}", namespace.database, table_name);
-                    TableSearchResult {
-                        matches: vec![],
-                        method,
-                        timed_out: true,
-                        error: None,
-                    }
-                }
-            };
+            matches_found_counter_ref.fetch_add(
+                table_result.matches.len() as u32,
+                std::sync::atomic::Ordering::SeqCst,
+            );
 
             if stream_results {
                 let progress = SearchProgressEvent {
@@ -479,7 +655,7 @@ pub async fn fulltext_search(
                     total_tables,
                     matches_found: matches_found_counter_ref
                         .load(std::sync::atomic::Ordering::SeqCst),
-                    current_table: Some(format!("{}.{}", namespace.database, table_name)),
+                    current_table: Some(table_result.table.clone()),
                 };
                 let _ = app_handle_ref.emit(
                     &format!("fulltext_search_progress:{}", session_id_ref),
@@ -489,7 +665,7 @@ pub async fn fulltext_search(
 
             table_result
         })
-        .buffer_unordered(max_parallel)
+        .buffer_unordered(limits.max_parallel)
         .collect()
         .await;
 
@@ -503,25 +679,10 @@ pub async fn fulltext_search(
     let mut truncated = false;
 
     for result in results {
-        match result.method {
-            SearchMethod::NativeFulltext => stats.native_fulltext_count += 1,
-            SearchMethod::PatternMatch => stats.pattern_match_count += 1,
-            SearchMethod::Hybrid => {
-                stats.native_fulltext_count += 1;
-                stats.pattern_match_count += 1;
-            }
-        }
-
-        if result.timed_out {
-            stats.timeout_count += 1;
-        }
-
-        if result.error.is_some() {
-            stats.error_count += 1;
-        }
+        record_stats(&mut stats, &result);
 
         for m in result.matches {
-            if all_matches.len() >= max_total as usize {
+            if all_matches.len() >= limits.max_total as usize {
                 truncated = true;
                 break;
             }
@@ -557,6 +718,167 @@ pub async fn fulltext_search(
     })
 }
 
+/// Runs a search and reports through `emit` as each table finishes: its
+/// matches, then the progress so far, and finally `Done`. Stops early once
+/// `max_total_results` matches were reported or `cancel` fires; queries still
+/// in flight are dropped.
+pub async fn run_streamed_search(
+    driver: Arc<dyn DataEngine>,
+    session: SessionId,
+    search_term: &str,
+    opts: &FulltextSearchOptions,
+    cancel: &CancellationToken,
+    mut emit: impl FnMut(FulltextSearchEvent),
+) {
+    let start_time = std::time::Instant::now();
+    let limits = SearchLimits::new(search_term, opts);
+
+    let targets = match list_search_targets(&driver, session, opts).await {
+        Ok(targets) => targets,
+        Err(error) => {
+            emit(FulltextSearchEvent::Failed { error });
+            return;
+        }
+    };
+    let total_tables = targets.len() as u32;
+
+    let strategy = get_search_strategy(driver.driver_id());
+    let driver_ref = &driver;
+    let strategy_ref = strategy.as_ref();
+    let search_options_ref = &limits.table;
+    let mut results = std::pin::pin!(stream::iter(targets)
+        .map(|target| search_table(
+            driver_ref,
+            session,
+            strategy_ref,
+            target,
+            search_options_ref
+        ))
+        .buffer_unordered(limits.max_parallel));
+
+    let mut stats = SearchStats::default();
+    let mut tables_searched = 0;
+    let mut total_matches: u64 = 0;
+    let mut truncated = false;
+    let mut cancelled = false;
+
+    loop {
+        let result = tokio::select! {
+            biased;
+            _ = cancel.cancelled() => {
+                cancelled = true;
+                break;
+            }
+            next = results.next() => match next {
+                Some(result) => result,
+                None => break,
+            },
+        };
+        tables_searched += 1;
+        record_stats(&mut stats, &result);
+
+        let mut matches = result.matches;
+        let room = (limits.max_total as u64).saturating_sub(total_matches) as usize;
+        if matches.len() > room {
+            matches.truncate(room);
+            truncated = true;
+        }
+        total_matches += matches.len() as u64;
+        if !matches.is_empty() {
+            emit(FulltextSearchEvent::Matches { matches });
+        }
+        emit(FulltextSearchEvent::Progress(SearchProgressEvent {
+            tables_searched,
+            total_tables,
+            matches_found: total_matches as u32,
+            current_table: Some(result.table),
+        }));
+
+        if truncated {
+            break;
+        }
+    }
+
+    emit(FulltextSearchEvent::Done {
+        total_matches,
+        tables_searched,
+        search_time_ms: start_time.elapsed().as_secs_f64() * 1000.0,
+        truncated,
+        cancelled,
+        stats,
+    });
+}
+
+/// Starts a search that reports its results as `fulltext-search` events
+/// tagged with `search_id`, ending with a `done` or `failed` event. Stop it
+/// with `cancel_fulltext_search`.
+#[tauri::command]
+#[instrument(
+    skip(state, app_handle),
+    fields(session_id = %session_id, search_id = %search_id, search_term_len = search_term.len())
+)]
+pub async fn fulltext_search_stream(
+    state: State<'_, crate::SharedState>,
+    app_handle: AppHandle,
+    session_id: String,
+    search_id: String,
+    search_term: String,
+    options: Option<FulltextSearchOptions>,
+) -> Result<FulltextSearchStreamResponse, String> {
+    let (session_manager, searches) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.fulltext_searches),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+    Uuid::parse_str(&search_id).map_err(|e| format!("Invalid search ID: {}", e))?;
+
+    let search_term = match validate_search_term(&search_term) {
+        Ok(term) => term.to_string(),
+        Err(msg) => return Ok(FulltextSearchStreamResponse::failure(msg)),
+    };
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => return Ok(FulltextSearchStreamResponse::failure(e.sanitized_message())),
+    };
+
+    let cancel = match searches.register(&search_id) {
+        Ok(token) => token,
+        Err(msg) => return Ok(FulltextSearchStreamResponse::failure(msg)),
+    };
+
+    let opts = options.unwrap_or_default();
+    tauri::async_runtime::spawn(async move {
+        run_streamed_search(driver, session, &search_term, &opts, &cancel, |event| {
+            let payload = serde_json::json!({
+                "search_id": search_id,
+                "event": event,
+            });
+            let _ = app_handle.emit(FULLTEXT_SEARCH_EVENT, payload);
+        })
+        .await;
+        searches.deregister(&search_id);
+    });
+
+    Ok(FulltextSearchStreamResponse {
+        success: true,
+        error: None,
+    })
+}
+
+/// Stops a streamed search. Returns `true` if the search was found.
+#[tauri::command]
+pub async fn cancel_fulltext_search(
+    state: State<'_, crate::SharedState>,
+    search_id: String,
+) -> Result<bool, String> {
+    let searches = Arc::clone(&state.lock().await.fulltext_searches);
+    Ok(searches.cancel(&search_id))
+}
+
 async fn detect_fulltext_indexes(
     driver: &Arc<dyn crate::engine::traits::DataEngine>,
     session: crate::engine::types::SessionId,
@@ -628,3 +950,132 @@ fn error_response(error: &str, time_ms: f64) -> FulltextSearchResponse {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::drivers::sqlite::SqliteDriver;
+    use crate::engine::types::ConnectionConfig;
+
+    async fn sqlite_corpus() -> (Arc<dyn DataEngine>, SessionId) {
+        let driver: Arc<dyn DataEngine> = Arc::new(SqliteDriver::new());
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        for sql in [
+            "CREATE TABLE notes (id INTEGER, body TEXT)",
+            "INSERT INTO notes VALUES (1, 'an apple a day'), (2, 'pears only'), (3, 'Apple pie')",
+            "CREATE TABLE tags (name TEXT)",
+            "INSERT INTO tags VALUES ('pineapple'), ('banana')",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (driver, session)
+    }
+
+    #[tokio::test]
+    async fn streams_matches_table_by_table() {
+        let (driver, session) = sqlite_corpus().await;
+        let mut events = Vec::new();
+
+        run_streamed_search(
+            driver,
+            session,
+            "apple",
+            &FulltextSearchOptions::default(),
+            &CancellationToken::new(),
+            |event| events.push(event),
+        )
+        .await;
+
+        let mut matches: Vec<(String, String, usize)> = events
+            .iter()
+            .filter_map(|event| match event {
+                FulltextSearchEvent::Matches { matches } => Some(matches),
+                _ => None,
+            })
+            .flatten()
+            .map(|m| {
+                (
+                    m.table_name.clone(),
+                    m.value_preview.clone(),
+                    m.match_offset,
+                )
+            })
+            .collect();
+        matches.sort();
+        assert_eq!(
+            matches,
+            [
+                ("notes".to_string(), "Apple pie".to_string(), 0),
+                ("notes".to_string(), "an apple a day".to_string(), 3),
+                ("tags".to_string(), "pineapple".to_string(), 4),
+            ]
+        );
+
+        let progress = events
+            .iter()
+            .filter(|event| matches!(event, FulltextSearchEvent::Progress(_)))
+            .count();
+        assert_eq!(progress, 2);
+        assert!(matches!(
+            events.last(),
+            Some(FulltextSearchEvent::Done {
+                total_matches: 3,
+                tables_searched: 2,
+                truncated: false,
+                cancelled: false,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn stops_at_max_total_results_and_on_cancel() {
+        let (driver, session) = sqlite_corpus().await;
+        let options = FulltextSearchOptions {
+            max_total_results: Some(1),
+            max_parallel: Some(1),
+            ..Default::default()
+        };
+        let mut events = Vec::new();
+        run_streamed_search(
+            Arc::clone(&driver),
+            session,
+            "apple",
+            &options,
+            &CancellationToken::new(),
+            |event| events.push(event),
+        )
+        .await;
+        assert!(matches!(
+            events.last(),
+            Some(FulltextSearchEvent::Done {
+                total_matches: 1,
+                truncated: true,
+                ..
+            })
+        ));
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let mut events = Vec::new();
+        run_streamed_search(
+            driver,
+            session,
+            "apple",
+            &FulltextSearchOptions::default(),
+            &cancel,
+            |event| events.push(event),
+        )
+        .await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            FulltextSearchEvent::Done {
+                total_matches: 0,
+                cancelled: true,
+                ..
+            }
+        ));
+    }
+}
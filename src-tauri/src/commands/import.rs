@@ -266,13 +266,14 @@ pub async fn import_csv(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => format!(
+            SafetyAction::Block | SafetyAction::Throttle { .. } => format!(
                 "{}: {}",
                 SAFETY_RULE_BLOCKED,
                 safety_result.message.unwrap_or_default()
@@ -458,6 +459,7 @@ pub async fn import_csv(
             },
             execution_time_ms,
             row_count: Some(imported_rows as i64),
+            affected_rows: Some(imported_rows),
         },
         false,
         None,
@@ -3,13 +3,17 @@
 //! Commands for managing the Universal Query Interceptor system.
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::sync::Arc;
 use tauri::State;
 
+use crate::engine::types::Namespace;
 use crate::interceptor::{
-    AuditExportFormat, AuditLogEntry, AuditStats, Environment, InterceptorConfig, ProfilingMetrics,
-    QueryOperationType, SafetyRule, SlowQueryEntry,
+    signing_key, AuditExportFormat, AuditLogEntry, AuditStats, Environment, InterceptorConfig,
+    ProfilingMetrics, QueryOperationType, SafetyRule, SafetySimulation, SlowQueryEntry,
+    TableProfile,
 };
+use crate::vault::backend::default_provider;
 
 #[derive(Debug, Serialize)]
 pub struct InterceptorConfigResponse {
@@ -46,6 +50,13 @@ pub struct SlowQueriesResponse {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct TableProfilesResponse {
+    pub success: bool,
+    pub tables: Vec<TableProfile>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SafetyRulesResponse {
     pub success: bool,
@@ -59,6 +70,13 @@ pub struct GenericResponse {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct SafetySimulationResponse {
+    pub success: bool,
+    pub simulation: Option<SafetySimulation>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ExportResponse {
     pub success: bool,
@@ -66,6 +84,13 @@ pub struct ExportResponse {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct VerifyAuditExportResponse {
+    pub success: bool,
+    pub valid: Option<bool>,
+    pub error: Option<String>,
+}
+
 #[tauri::command]
 pub async fn get_interceptor_config(
     state: State<'_, crate::SharedState>,
@@ -223,6 +248,7 @@ pub async fn export_audit_log(
     state: State<'_, crate::SharedState>,
     format: Option<AuditExportFormat>,
     from_disk: Option<bool>,
+    signed: Option<bool>,
 ) -> Result<ExportResponse, String> {
     let interceptor = {
         let state = state.lock().await;
@@ -232,7 +258,18 @@ pub async fn export_audit_log(
     let format = format.unwrap_or_default();
     let from_disk = from_disk.unwrap_or(false);
 
-    match interceptor.export_audit_format(format, from_disk) {
+    // Signed exports are always the JSON document with its manifest; `format`
+    // does not apply.
+    let exported = if signed.unwrap_or(false) {
+        match signing_key(&*default_provider()) {
+            Ok(key) => interceptor.export_audit_signed(from_disk, &key),
+            Err(e) => Err(e.sanitized_message()),
+        }
+    } else {
+        interceptor.export_audit_format(format, from_disk)
+    };
+
+    match exported {
         Ok(data) => Ok(ExportResponse {
             success: true,
             data: Some(data),
@@ -252,6 +289,7 @@ pub async fn export_audit_log(
     _state: State<'_, crate::SharedState>,
     _format: Option<AuditExportFormat>,
     _from_disk: Option<bool>,
+    _signed: Option<bool>,
 ) -> Result<ExportResponse, String> {
     Ok(ExportResponse {
         success: false,
@@ -260,16 +298,38 @@ pub async fn export_audit_log(
     })
 }
 
+/// Recomputes the HMAC chain of a signed audit export saved at `path`.
+/// `valid` is false when any entry was inserted, removed or modified.
+#[tauri::command]
+pub async fn verify_audit_export(path: String) -> Result<VerifyAuditExportResponse, String> {
+    let verified = signing_key(&*default_provider())
+        .and_then(|key| crate::interceptor::verify_audit_export(Path::new(&path), &key));
+
+    match verified {
+        Ok(valid) => Ok(VerifyAuditExportResponse {
+            success: true,
+            valid: Some(valid),
+            error: None,
+        }),
+        Err(e) => Ok(VerifyAuditExportResponse {
+            success: false,
+            valid: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
 #[cfg(feature = "pro")]
 #[tauri::command]
 pub async fn get_profiling_metrics(
     state: State<'_, crate::SharedState>,
+    namespace: Option<String>,
 ) -> Result<ProfilingMetricsResponse, String> {
     let interceptor = {
         let state = state.lock().await;
         Arc::clone(&state.interceptor)
     };
-    let metrics = interceptor.get_profiling_metrics();
+    let metrics = interceptor.get_profiling_metrics(namespace.as_deref());
     Ok(ProfilingMetricsResponse {
         success: true,
         metrics: Some(metrics),
@@ -281,6 +341,7 @@ pub async fn get_profiling_metrics(
 #[tauri::command]
 pub async fn get_profiling_metrics(
     _state: State<'_, crate::SharedState>,
+    _namespace: Option<String>,
 ) -> Result<ProfilingMetricsResponse, String> {
     Ok(ProfilingMetricsResponse {
         success: false,
@@ -295,12 +356,17 @@ pub async fn get_slow_queries(
     state: State<'_, crate::SharedState>,
     limit: Option<usize>,
     offset: Option<usize>,
+    namespace: Option<String>,
 ) -> Result<SlowQueriesResponse, String> {
     let interceptor = {
         let state = state.lock().await;
         Arc::clone(&state.interceptor)
     };
-    let queries = interceptor.get_slow_queries(limit.unwrap_or(50), offset.unwrap_or(0));
+    let queries = interceptor.get_slow_queries(
+        limit.unwrap_or(50),
+        offset.unwrap_or(0),
+        namespace.as_deref(),
+    );
     Ok(SlowQueriesResponse {
         success: true,
         queries,
@@ -314,6 +380,7 @@ pub async fn get_slow_queries(
     _state: State<'_, crate::SharedState>,
     _limit: Option<usize>,
     _offset: Option<usize>,
+    _namespace: Option<String>,
 ) -> Result<SlowQueriesResponse, String> {
     Ok(SlowQueriesResponse {
         success: false,
@@ -322,6 +389,40 @@ pub async fn get_slow_queries(
     })
 }
 
+/// Slowest tables by p95 execution time, optionally within one namespace
+#[cfg(feature = "pro")]
+#[tauri::command]
+pub async fn get_profiling_by_table(
+    state: State<'_, crate::SharedState>,
+    namespace: Option<String>,
+    limit: Option<usize>,
+) -> Result<TableProfilesResponse, String> {
+    let interceptor = {
+        let state = state.lock().await;
+        Arc::clone(&state.interceptor)
+    };
+    let tables = interceptor.get_profiling_by_table(namespace.as_deref(), limit.unwrap_or(20));
+    Ok(TableProfilesResponse {
+        success: true,
+        tables,
+        error: None,
+    })
+}
+
+#[cfg(not(feature = "pro"))]
+#[tauri::command]
+pub async fn get_profiling_by_table(
+    _state: State<'_, crate::SharedState>,
+    _namespace: Option<String>,
+    _limit: Option<usize>,
+) -> Result<TableProfilesResponse, String> {
+    Ok(TableProfilesResponse {
+        success: false,
+        tables: vec![],
+        error: Some("Query profiling requires QoreDB Pro".into()),
+    })
+}
+
 #[cfg(feature = "pro")]
 #[tauri::command]
 pub async fn clear_slow_queries(
@@ -424,6 +525,28 @@ pub async fn get_safety_rules(
     })
 }
 
+/// Lists the safety rules a query would trigger, without executing it
+#[tauri::command]
+pub async fn simulate_safety_rules(
+    state: State<'_, crate::SharedState>,
+    query: String,
+    driver_id: String,
+    namespace: Option<Namespace>,
+) -> Result<SafetySimulationResponse, String> {
+    let interceptor = {
+        let state = state.lock().await;
+        Arc::clone(&state.interceptor)
+    };
+
+    let simulation = interceptor.simulate_safety_rules(&query, &driver_id, namespace.as_ref());
+
+    Ok(SafetySimulationResponse {
+        success: true,
+        simulation: Some(simulation),
+        error: None,
+    })
+}
+
 /// Adds a custom safety rule (Pro only)
 #[cfg(feature = "pro")]
 #[tauri::command]
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! SQL validation and linting without executing the query.
+
+use serde::Serialize;
+use tauri::State;
+use tracing::instrument;
+
+use super::{parse_session_id, SharedStateExt};
+use crate::engine::error::EngineError;
+use crate::engine::sql_lint::{lint_sql, LintWarning};
+use crate::engine::sql_safety;
+use crate::engine::types::{Namespace, SqlSyntaxError};
+
+#[derive(Debug, Serialize)]
+pub struct LintQueryResponse {
+    pub success: bool,
+    pub syntax_errors: Vec<SqlSyntaxError>,
+    pub warnings: Vec<LintWarning>,
+    /// The syntax check came from the server (PREPARE) rather than the parser
+    pub server_validated: bool,
+    pub error: Option<String>,
+}
+
+/// Reports syntax errors (with positions) and lint warnings for `query`.
+///
+/// The static parser for `dialect` always runs. When a session is given and
+/// its driver can validate server-side, a single statement is also checked
+/// with PREPARE/DEALLOCATE and the server's verdict replaces the parser's,
+/// since the server knows its own dialect better.
+#[tauri::command]
+#[instrument(skip(state, query, namespace), fields(dialect = %dialect, session_id = ?session_id))]
+pub async fn lint_query(
+    state: State<'_, crate::SharedState>,
+    query: String,
+    dialect: String,
+    session_id: Option<String>,
+    namespace: Option<Namespace>,
+) -> Result<LintQueryResponse, String> {
+    let report = lint_sql(&dialect, &query);
+    let mut response = LintQueryResponse {
+        success: true,
+        syntax_errors: report.syntax_errors,
+        warnings: report.warnings,
+        server_validated: false,
+        error: None,
+    };
+
+    let Some(session_id) = session_id else {
+        return Ok(response);
+    };
+    let session = parse_session_id(&session_id)?;
+    let driver = state
+        .session_manager()
+        .await
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().syntax_validation || query.trim().is_empty() {
+        return Ok(response);
+    }
+    // PREPARE takes exactly one statement.
+    let single_statement = sql_safety::split_sql_statements(driver.driver_id(), &query)
+        .map(|statements| statements.len() == 1)
+        .unwrap_or(true);
+    if !single_statement {
+        return Ok(response);
+    }
+
+    match driver.validate_syntax(session, namespace, &query).await {
+        Ok(error) => {
+            response.syntax_errors = error.into_iter().collect();
+            response.server_validated = true;
+        }
+        // Statement kinds the server cannot prepare keep the parser's verdict.
+        Err(EngineError::NotSupported { .. }) => {}
+        Err(e) => {
+            response.success = false;
+            response.error = Some(e.sanitized_message());
+        }
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Local result cache commands: pull a remote result into the local DuckDB
+//! cache, then query it offline.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use tauri::State;
+use tracing::instrument;
+
+use super::parse_session_id;
+use crate::engine::types::{Namespace, QueryResult};
+use crate::local_cache::LocalCacheEntry;
+
+#[derive(Debug, Serialize)]
+pub struct LocalCacheResponse {
+    pub success: bool,
+    pub entry: Option<LocalCacheEntry>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LocalCacheListResponse {
+    pub success: bool,
+    pub entries: Vec<LocalCacheEntry>,
+    /// Path of the cache database, so it can also be opened with the DuckDB driver.
+    pub path: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LocalCacheQueryResponse {
+    pub success: bool,
+    pub result: Option<QueryResult>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LocalCacheDropResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl LocalCacheResponse {
+    fn from_result(result: Result<LocalCacheEntry, String>) -> Self {
+        match result {
+            Ok(entry) => Self {
+                success: true,
+                entry: Some(entry),
+                error: None,
+            },
+            Err(e) => Self {
+                success: false,
+                entry: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+/// Streams a remote query into the local cache as table `local_name`.
+/// Re-using an existing name replaces its rows.
+#[tauri::command]
+#[instrument(skip(state, query), fields(session_id = %session_id, local_name = %local_name))]
+pub async fn cache_result_locally(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    query: String,
+    local_name: String,
+    namespace: Option<Namespace>,
+    max_rows: Option<u64>,
+) -> Result<LocalCacheResponse, String> {
+    let result = cache_remote(
+        &state,
+        &session_id,
+        CacheSource::Query { query, namespace },
+        &local_name,
+        max_rows,
+    )
+    .await;
+    Ok(LocalCacheResponse::from_result(result))
+}
+
+/// Re-runs the query a cached table was built from against `session_id`.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, local_name = %local_name))]
+pub async fn refresh_local_cache(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    local_name: String,
+    max_rows: Option<u64>,
+) -> Result<LocalCacheResponse, String> {
+    let result = cache_remote(
+        &state,
+        &session_id,
+        CacheSource::Refresh,
+        &local_name,
+        max_rows,
+    )
+    .await;
+    Ok(LocalCacheResponse::from_result(result))
+}
+
+/// Runs SQL against the local cache database only.
+#[tauri::command]
+#[instrument(skip(state, sql))]
+pub async fn query_local_cache(
+    state: State<'_, crate::SharedState>,
+    sql: String,
+) -> Result<LocalCacheQueryResponse, String> {
+    let store = Arc::clone(&state.lock().await.local_cache);
+    match store.query(&sql).await {
+        Ok(result) => Ok(LocalCacheQueryResponse {
+            success: true,
+            result: Some(result),
+            error: None,
+        }),
+        Err(e) => Ok(LocalCacheQueryResponse {
+            success: false,
+            result: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[tauri::command]
+#[instrument(skip(state))]
+pub async fn list_local_caches(
+    state: State<'_, crate::SharedState>,
+) -> Result<LocalCacheListResponse, String> {
+    let store = Arc::clone(&state.lock().await.local_cache);
+    let path = store.path().display().to_string();
+    match store.list().await {
+        Ok(entries) => Ok(LocalCacheListResponse {
+            success: true,
+            entries,
+            path,
+            error: None,
+        }),
+        Err(e) => Ok(LocalCacheListResponse {
+            success: false,
+            entries: Vec::new(),
+            path,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[tauri::command]
+#[instrument(skip(state), fields(local_name = %local_name))]
+pub async fn drop_local_cache(
+    state: State<'_, crate::SharedState>,
+    local_name: String,
+) -> Result<LocalCacheDropResponse, String> {
+    let store = Arc::clone(&state.lock().await.local_cache);
+    match store.remove(&local_name).await {
+        Ok(()) => Ok(LocalCacheDropResponse {
+            success: true,
+            error: None,
+        }),
+        Err(e) => Ok(LocalCacheDropResponse {
+            success: false,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+enum CacheSource {
+    Query {
+        query: String,
+        namespace: Option<Namespace>,
+    },
+    Refresh,
+}
+
+async fn cache_remote(
+    state: &State<'_, crate::SharedState>,
+    session_id: &str,
+    source: CacheSource,
+    local_name: &str,
+    max_rows: Option<u64>,
+) -> Result<LocalCacheEntry, String> {
+    let (session_manager, query_rate_limiter, interceptor, policy, store) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.interceptor),
+            state.policy.clone(),
+            Arc::clone(&state.local_cache),
+        )
+    };
+
+    let session = parse_session_id(session_id)?;
+
+    let (query, namespace) = match source {
+        CacheSource::Query { query, namespace } => (query, namespace),
+        CacheSource::Refresh => {
+            let entry = store
+                .get(local_name)
+                .await
+                .map_err(|e| e.sanitized_message())?;
+            (entry.source_query, entry.namespace)
+        }
+    };
+
+    // Same safety preflight as execute_query / export: the cached query runs
+    // on the remote connection, so read-only and production guards apply.
+    qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        session_id,
+        &query,
+        namespace.as_ref(),
+        false,
+    )
+    .await?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    store
+        .cache_query(driver, session, namespace, &query, local_name, max_rows)
+        .await
+        .map_err(|e| e.sanitized_message())
+}
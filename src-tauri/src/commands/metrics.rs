@@ -1,32 +1,58 @@
 // SPDX-License-Identifier: Apache-2.0
 
-//! Metrics commands (dev-only).
+//! Runtime metrics commands, available once enabled in Settings.
+
+use std::sync::Arc;
 
 use serde::Serialize;
+use tauri::State;
 
-use crate::metrics;
+use crate::metrics::{self, MetricsConfig};
 
 #[derive(Debug, Serialize)]
 pub struct MetricsResponse {
     pub success: bool,
-    pub metrics: Option<metrics::QueryMetricsSnapshot>,
+    pub metrics: Option<metrics::RuntimeMetrics>,
     pub error: Option<String>,
 }
 
-/// Returns current metrics snapshot (dev builds only).
+/// Returns query counters, active sessions, running queries and pool usage.
 #[tauri::command]
-pub async fn get_metrics() -> Result<MetricsResponse, String> {
-    if !cfg!(debug_assertions) {
+pub async fn get_metrics(state: State<'_, crate::SharedState>) -> Result<MetricsResponse, String> {
+    if !metrics::is_enabled() {
         return Ok(MetricsResponse {
             success: false,
             metrics: None,
-            error: Some("Metrics are only available in dev builds".to_string()),
+            error: Some("Metrics are disabled. Enable them in Settings".to_string()),
         });
     }
 
+    let (session_manager, query_manager) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+        )
+    };
+
     Ok(MetricsResponse {
         success: true,
-        metrics: Some(metrics::snapshot()),
+        metrics: Some(metrics::collect(&session_manager, &query_manager).await),
         error: None,
     })
 }
+
+#[tauri::command]
+pub async fn get_metrics_config() -> Result<MetricsConfig, String> {
+    Ok(MetricsConfig {
+        metrics_enabled: metrics::is_enabled(),
+    })
+}
+
+/// Persists the setting and turns collection on or off right away.
+#[tauri::command]
+pub async fn set_metrics_config(config: MetricsConfig) -> Result<MetricsConfig, String> {
+    config.save()?;
+    config.apply();
+    Ok(config)
+}
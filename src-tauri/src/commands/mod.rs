@@ -4,6 +4,7 @@
 
 pub mod ai;
 pub mod backup;
+pub mod benchmark;
 pub mod cache;
 pub mod confirmation;
 pub mod connection;
@@ -11,6 +12,8 @@ pub mod connection_url;
 #[cfg(feature = "pro")]
 pub mod contracts;
 pub mod data_generator;
+pub mod diagnostics;
+pub mod display_transforms;
 pub mod driver;
 pub mod export;
 pub mod federation;
@@ -20,6 +23,8 @@ pub mod import;
 pub mod instant_api;
 pub mod interceptor;
 pub mod license;
+pub mod lint;
+pub mod local_cache;
 pub mod logs;
 pub mod maintenance;
 pub mod metrics;
@@ -29,10 +34,13 @@ pub mod policy;
 pub mod query;
 pub mod routines;
 pub mod sandbox;
+pub mod scheduled_jobs;
 pub mod schema_export;
 pub mod sequences;
+pub mod server_log;
 pub mod share;
 pub mod snapshots;
+pub mod snippets;
 pub mod stream_msg;
 pub mod time_travel;
 pub mod triggers;
@@ -8,12 +8,19 @@ use tauri::{AppHandle, State};
 use tracing::instrument;
 
 use super::{parse_session_id, SharedStateExt};
-use crate::engine::types::{Namespace, QueryResult, RowData};
+use crate::engine::sql_generator::{
+    generate_delete_returning, generate_update_returning, SqlDialect,
+};
+use crate::engine::types::{Namespace, QueryId, QueryResult, RowData, UpsertAction};
 use crate::interceptor::QueryExecutionResult;
 use crate::time_travel::capture::{
-    build_changelog_entry, fetch_row_by_pk, merge_before_with_data, rowdata_to_json_map,
+    build_changelog_entry, fetch_row_by_pk, fetch_rows_by_pk, first_row_to_map,
+    merge_before_with_data, rowdata_to_json_map,
 };
 use crate::time_travel::ChangeOperation;
+use crate::undo::{UndoEntry, UndoOutcome};
+
+const KEY_MANAGEMENT_NOT_SUPPORTED: &str = "Key TTL and renaming are only supported for Redis";
 
 fn format_table_ref(database: &str, schema: &Option<String>, table: &str) -> String {
     if let Some(schema) = schema {
@@ -27,6 +34,58 @@ fn format_table_ref(database: &str, schema: &Option<String>, table: &str) -> Str
 pub struct MutationResponse {
     pub success: bool,
     pub result: Option<QueryResult>,
+    /// Rows as changed by the mutation, when `return_rows` was requested
+    pub changed_rows: Option<QueryResult>,
+    pub error: Option<String>,
+}
+
+/// `RETURNING` / `OUTPUT` variant of a mutation for the driver's dialect.
+/// `None` (MySQL, drivers without a generator dialect) means the changed rows
+/// are looked up by primary key instead, which misses rows changed by
+/// triggers or cascades and, for updates, reads back after the fact.
+fn returning_statement(
+    driver_id: &str,
+    build: impl FnOnce(SqlDialect) -> Result<String, String>,
+) -> Option<String> {
+    SqlDialect::from_driver_id(driver_id).and_then(|dialect| build(dialect).ok())
+}
+
+/// Splits a `RETURNING` result into the usual affected-count result and the
+/// returned rows.
+fn split_returned_rows(returned: QueryResult) -> (QueryResult, Option<QueryResult>) {
+    let summary = QueryResult {
+        columns: Vec::new(),
+        rows: Vec::new(),
+        affected_rows: Some(returned.rows.len() as u64),
+        execution_time_ms: returned.execution_time_ms,
+    };
+    (summary, Some(returned))
+}
+
+/// Column names touched by a mutation, recorded in the audit log without values.
+fn touched_columns(data: &RowData) -> Option<Vec<String>> {
+    let mut columns: Vec<String> = data.columns.keys().cloned().collect();
+    columns.sort();
+    Some(columns)
+}
+
+/// Primary key of the row after an update, which may itself change key columns.
+fn updated_primary_key(primary_key: &RowData, data: &RowData) -> RowData {
+    let mut key = primary_key.clone();
+    for (column, value) in key.columns.iter_mut() {
+        if let Some(updated) = data.columns.get(column) {
+            *value = updated.clone();
+        }
+    }
+    key
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpsertResponse {
+    pub success: bool,
+    pub result: Option<QueryResult>,
+    /// Whether the row was inserted or updated, when the driver reports it
+    pub action: Option<UpsertAction>,
     pub error: Option<String>,
 }
 
@@ -50,6 +109,7 @@ pub async fn insert_row(
     let interceptor = Arc::clone(&state_guard.interceptor);
     let changelog_store = Arc::clone(&state_guard.changelog_store);
     let query_cache = Arc::clone(&state_guard.query_cache);
+    let undo_store = Arc::clone(&state_guard.mutation_undo);
     drop(state_guard);
 
     let session = parse_session_id(&session_id)?;
@@ -75,16 +135,18 @@ pub async fn insert_row(
             return Ok(MutationResponse {
                 success: false,
                 result: None,
+                changed_rows: None,
                 error: Some(msg),
             });
         }
     };
     let qore_service::mutation::MutationPreflight {
         driver,
-        context: interceptor_context,
+        context: mut interceptor_context,
         environment,
         safety_warning,
     } = preflight;
+    interceptor_context.touched_columns = touched_columns(&data);
 
     let namespace = Namespace { database, schema };
 
@@ -99,11 +161,17 @@ pub async fn insert_row(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: result.affected_rows.map(|a| a as i64),
+                    affected_rows: result.affected_rows,
                 },
                 false,
                 safety_warning.as_deref(),
             );
 
+            undo_store.push(
+                session,
+                UndoEntry::for_insert(namespace.clone(), &table, &data),
+            );
+
             // Time-Travel: after-image equals the inserted data; PK is also the data row.
             if changelog_store.should_capture(&table, &environment) {
                 let after_image = rowdata_to_json_map(&data);
@@ -136,6 +204,7 @@ pub async fn insert_row(
             Ok(MutationResponse {
                 success: true,
                 result: Some(result),
+                changed_rows: None,
                 error: None,
             })
         }
@@ -148,6 +217,7 @@ pub async fn insert_row(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -155,6 +225,181 @@ pub async fn insert_row(
             Ok(MutationResponse {
                 success: false,
                 result: None,
+                changed_rows: None,
+                error: Some(e.sanitized_message()),
+            })
+        }
+    }
+}
+
+/// Inserts `data`, or updates the row matching it on `conflict_columns`.
+#[tauri::command]
+#[instrument(
+    skip(state, data),
+    fields(session_id = %session_id, database = %database, schema = ?schema, table = %table)
+)]
+pub async fn upsert_row(
+    app: AppHandle,
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    schema: Option<String>,
+    table: String,
+    data: RowData,
+    conflict_columns: Vec<String>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<UpsertResponse, String> {
+    let state_guard = state.lock().await;
+    let session_manager = Arc::clone(&state_guard.session_manager);
+    let interceptor = Arc::clone(&state_guard.interceptor);
+    let changelog_store = Arc::clone(&state_guard.changelog_store);
+    let query_cache = Arc::clone(&state_guard.query_cache);
+    drop(state_guard);
+    let session = parse_session_id(&session_id)?;
+
+    let query_preview = format!(
+        "INSERT INTO {} VALUES (...) ON CONFLICT ({}) DO UPDATE SET ...",
+        format_table_ref(&database, &schema, &table),
+        conflict_columns.join(", ")
+    );
+
+    let preflight = match qore_service::mutation::preflight(
+        &session_manager,
+        &interceptor,
+        session,
+        &session_id,
+        &query_preview,
+        &database,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(UpsertResponse {
+                success: false,
+                result: None,
+                action: None,
+                error: Some(msg),
+            });
+        }
+    };
+    let qore_service::mutation::MutationPreflight {
+        driver,
+        context: mut interceptor_context,
+        environment,
+        safety_warning,
+    } = preflight;
+    interceptor_context.touched_columns = touched_columns(&data);
+
+    if !driver.capabilities().upsert {
+        return Ok(UpsertResponse {
+            success: false,
+            result: None,
+            action: None,
+            error: Some("Upsert operations are not supported by this driver".to_string()),
+        });
+    }
+
+    let namespace = Namespace { database, schema };
+
+    // Time-Travel: the conflict columns identify the row; its before-image
+    // (if any) tells an insert from an update.
+    let conflict_key = RowData {
+        columns: data
+            .columns
+            .iter()
+            .filter(|(col, _)| conflict_columns.contains(*col))
+            .map(|(col, val)| (col.clone(), val.clone()))
+            .collect(),
+    };
+    let capture = changelog_store.should_capture(&table, &environment);
+    let before_image = if capture {
+        fetch_row_by_pk(&driver, session, &namespace, &table, &conflict_key).await
+    } else {
+        None
+    };
+
+    let start_time = std::time::Instant::now();
+    match driver
+        .upsert_row(session, &namespace, &table, &data, &conflict_columns)
+        .await
+    {
+        Ok(upsert) => {
+            let mut result = upsert.result;
+            result.execution_time_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+            interceptor.post_execute(
+                &interceptor_context,
+                &QueryExecutionResult {
+                    success: true,
+                    error: None,
+                    execution_time_ms: result.execution_time_ms,
+                    row_count: result.affected_rows.map(|a| a as i64),
+                    affected_rows: result.affected_rows,
+                },
+                false,
+                safety_warning.as_deref(),
+            );
+
+            if capture {
+                let (operation, after_image) = match &before_image {
+                    Some(before) => (
+                        ChangeOperation::Update,
+                        merge_before_with_data(before, &data),
+                    ),
+                    None => (ChangeOperation::Insert, rowdata_to_json_map(&data)),
+                };
+                let entry = build_changelog_entry(
+                    &session_id,
+                    driver.driver_id(),
+                    &namespace,
+                    &table,
+                    operation,
+                    &conflict_key,
+                    before_image,
+                    Some(after_image),
+                    None,
+                    &environment,
+                );
+                changelog_store.record(entry);
+            }
+
+            #[cfg(feature = "pro")]
+            crate::contracts::alert::schedule_post_mutation_check(
+                app.clone(),
+                session,
+                namespace.schema.clone(),
+                table.clone(),
+            );
+
+            if let Some(key) = session_manager.connection_key(session).await {
+                query_cache.invalidate_connection(&key);
+            }
+            Ok(UpsertResponse {
+                success: true,
+                result: Some(result),
+                action: upsert.action,
+                error: None,
+            })
+        }
+        Err(e) => {
+            let duration_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+            interceptor.post_execute(
+                &interceptor_context,
+                &QueryExecutionResult {
+                    success: false,
+                    error: Some(e.sanitized_message()),
+                    execution_time_ms: duration_ms,
+                    row_count: None,
+                    affected_rows: None,
+                },
+                false,
+                safety_warning.as_deref(),
+            );
+            Ok(UpsertResponse {
+                success: false,
+                result: None,
+                action: None,
                 error: Some(e.sanitized_message()),
             })
         }
@@ -176,12 +421,14 @@ pub async fn update_row(
     primary_key: RowData,
     data: RowData,
     acknowledged_dangerous: Option<bool>,
+    return_rows: Option<bool>,
 ) -> Result<MutationResponse, String> {
     let state_guard = state.lock().await;
     let session_manager = Arc::clone(&state_guard.session_manager);
     let interceptor = Arc::clone(&state_guard.interceptor);
     let changelog_store = Arc::clone(&state_guard.changelog_store);
     let query_cache = Arc::clone(&state_guard.query_cache);
+    let undo_store = Arc::clone(&state_guard.mutation_undo);
     drop(state_guard);
     let session = parse_session_id(&session_id)?;
 
@@ -206,32 +453,51 @@ pub async fn update_row(
             return Ok(MutationResponse {
                 success: false,
                 result: None,
+                changed_rows: None,
                 error: Some(msg),
             });
         }
     };
     let qore_service::mutation::MutationPreflight {
         driver,
-        context: interceptor_context,
+        context: mut interceptor_context,
         environment,
         safety_warning,
     } = preflight;
+    interceptor_context.touched_columns = touched_columns(&data);
 
     let namespace = Namespace { database, schema };
 
-    // Time-Travel: fetch before-image prior to the mutation.
+    // Before-image for undo and Time-Travel, fetched prior to the mutation.
+    let before_row = fetch_rows_by_pk(&driver, session, &namespace, &table, &primary_key).await;
     let before_image = if changelog_store.should_capture(&table, &environment) {
-        fetch_row_by_pk(&driver, session, &namespace, &table, &primary_key).await
+        before_row.as_ref().and_then(first_row_to_map)
+    } else {
+        None
+    };
+
+    let return_rows = return_rows.unwrap_or(false);
+    let returning_sql = if return_rows {
+        returning_statement(driver.driver_id(), |dialect| {
+            generate_update_returning(dialect, &namespace, &table, &primary_key, &data.columns)
+        })
     } else {
         None
     };
 
     let start_time = std::time::Instant::now();
-    match driver
-        .update_row(session, &namespace, &table, &primary_key, &data)
-        .await
-    {
-        Ok(mut result) => {
+    let outcome = match &returning_sql {
+        Some(sql) => driver
+            .execute_in_namespace(session, Some(namespace.clone()), sql, QueryId::new())
+            .await
+            .map(split_returned_rows),
+        None => driver
+            .update_row(session, &namespace, &table, &primary_key, &data)
+            .await
+            .map(|result| (result, None)),
+    };
+    match outcome {
+        Ok((mut result, mut changed_rows)) => {
             result.execution_time_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
             interceptor.post_execute(
                 &interceptor_context,
@@ -240,11 +506,26 @@ pub async fn update_row(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: result.affected_rows.map(|a| a as i64),
+                    affected_rows: result.affected_rows,
                 },
                 false,
                 safety_warning.as_deref(),
             );
 
+            if let Some(entry) = before_row.as_ref().and_then(|before| {
+                UndoEntry::for_update(namespace.clone(), &table, &primary_key, &data, before)
+            }) {
+                undo_store.push(session, entry);
+            }
+
+            // No RETURNING in this dialect: re-select the row by its (possibly
+            // updated) primary key.
+            if return_rows && changed_rows.is_none() {
+                let after_key = updated_primary_key(&primary_key, &data);
+                changed_rows =
+                    fetch_rows_by_pk(&driver, session, &namespace, &table, &after_key).await;
+            }
+
             if changelog_store.should_capture(&table, &environment) {
                 let after_image = before_image
                     .as_ref()
@@ -278,6 +559,7 @@ pub async fn update_row(
             Ok(MutationResponse {
                 success: true,
                 result: Some(result),
+                changed_rows,
                 error: None,
             })
         }
@@ -290,6 +572,7 @@ pub async fn update_row(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -297,6 +580,7 @@ pub async fn update_row(
             Ok(MutationResponse {
                 success: false,
                 result: None,
+                changed_rows: None,
                 error: Some(e.sanitized_message()),
             })
         }
@@ -317,12 +601,15 @@ pub async fn delete_row(
     table: String,
     primary_key: RowData,
     acknowledged_dangerous: Option<bool>,
+    return_rows: Option<bool>,
+    confirmed_count: Option<u64>,
 ) -> Result<MutationResponse, String> {
     let state_guard = state.lock().await;
     let session_manager = Arc::clone(&state_guard.session_manager);
     let interceptor = Arc::clone(&state_guard.interceptor);
     let changelog_store = Arc::clone(&state_guard.changelog_store);
     let query_cache = Arc::clone(&state_guard.query_cache);
+    let undo_store = Arc::clone(&state_guard.mutation_undo);
     drop(state_guard);
     let session = parse_session_id(&session_id)?;
 
@@ -347,32 +634,76 @@ pub async fn delete_row(
             return Ok(MutationResponse {
                 success: false,
                 result: None,
+                changed_rows: None,
                 error: Some(msg),
             });
         }
     };
     let qore_service::mutation::MutationPreflight {
         driver,
-        context: interceptor_context,
+        context: mut interceptor_context,
         environment,
         safety_warning,
     } = preflight;
+    interceptor_context.touched_columns = touched_columns(&primary_key);
 
     let namespace = Namespace { database, schema };
 
-    // Time-Travel: fetch before-image prior to the deletion.
+    // A key that is not unique must be confirmed with the exact row count.
+    if let Err(e) = qore_service::mutation::check_delete_count(
+        driver.as_ref(),
+        session,
+        &namespace,
+        &table,
+        &primary_key,
+        confirmed_count,
+    )
+    .await
+    {
+        return Ok(MutationResponse {
+            success: false,
+            result: None,
+            changed_rows: None,
+            error: Some(e.sanitized_message()),
+        });
+    }
+
+    // Before-image for undo and Time-Travel, fetched prior to the deletion.
+    let before_row = fetch_rows_by_pk(&driver, session, &namespace, &table, &primary_key).await;
     let before_image = if changelog_store.should_capture(&table, &environment) {
-        fetch_row_by_pk(&driver, session, &namespace, &table, &primary_key).await
+        before_row.as_ref().and_then(first_row_to_map)
+    } else {
+        None
+    };
+
+    let return_rows = return_rows.unwrap_or(false);
+    let returning_sql = if return_rows {
+        returning_statement(driver.driver_id(), |dialect| {
+            generate_delete_returning(dialect, &namespace, &table, &primary_key)
+        })
+    } else {
+        None
+    };
+    // No RETURNING in this dialect: report the row as it was before deletion.
+    let deleted_rows = if return_rows && returning_sql.is_none() {
+        before_row.clone()
     } else {
         None
     };
 
     let start_time = std::time::Instant::now();
-    match driver
-        .delete_row(session, &namespace, &table, &primary_key)
-        .await
-    {
-        Ok(mut result) => {
+    let outcome = match &returning_sql {
+        Some(sql) => driver
+            .execute_in_namespace(session, Some(namespace.clone()), sql, QueryId::new())
+            .await
+            .map(split_returned_rows),
+        None => driver
+            .delete_row(session, &namespace, &table, &primary_key)
+            .await
+            .map(|result| (result, deleted_rows)),
+    };
+    match outcome {
+        Ok((mut result, changed_rows)) => {
             result.execution_time_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
             interceptor.post_execute(
                 &interceptor_context,
@@ -381,11 +712,19 @@ pub async fn delete_row(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: result.affected_rows.map(|a| a as i64),
+                    affected_rows: result.affected_rows,
                 },
                 false,
                 safety_warning.as_deref(),
             );
 
+            if let Some(entry) = before_row
+                .as_ref()
+                .and_then(|before| UndoEntry::for_delete(namespace.clone(), &table, before))
+            {
+                undo_store.push(session, entry);
+            }
+
             if changelog_store.should_capture(&table, &environment) {
                 let entry = build_changelog_entry(
                     &session_id,
@@ -416,6 +755,7 @@ pub async fn delete_row(
             Ok(MutationResponse {
                 success: true,
                 result: Some(result),
+                changed_rows,
                 error: None,
             })
         }
@@ -428,6 +768,7 @@ pub async fn delete_row(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -435,12 +776,227 @@ pub async fn delete_row(
             Ok(MutationResponse {
                 success: false,
                 result: None,
+                changed_rows: None,
                 error: Some(e.sanitized_message()),
             })
         }
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct TruncateResponse {
+    pub success: bool,
+    pub result: Option<QueryResult>,
+    /// Row count estimated by `describe_table` before the truncation
+    pub removed_rows_estimate: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Removes every row of a table. Read-only sessions are refused and the
+/// statement must pass the safety rules, which block `TRUNCATE` in
+/// production unless acknowledged.
+#[tauri::command]
+#[instrument(
+    skip(state),
+    fields(session_id = %session_id, database = %database, schema = ?schema, table = %table)
+)]
+pub async fn truncate_table(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    schema: Option<String>,
+    table: String,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<TruncateResponse, String> {
+    let state_guard = state.lock().await;
+    let session_manager = Arc::clone(&state_guard.session_manager);
+    let interceptor = Arc::clone(&state_guard.interceptor);
+    let query_cache = Arc::clone(&state_guard.query_cache);
+    drop(state_guard);
+    let session = parse_session_id(&session_id)?;
+
+    let query_preview = format!(
+        "TRUNCATE TABLE {}",
+        format_table_ref(&database, &schema, &table)
+    );
+
+    let preflight = match qore_service::mutation::preflight(
+        &session_manager,
+        &interceptor,
+        session,
+        &session_id,
+        &query_preview,
+        &database,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(TruncateResponse {
+                success: false,
+                result: None,
+                removed_rows_estimate: None,
+                error: Some(msg),
+            });
+        }
+    };
+    let qore_service::mutation::MutationPreflight {
+        driver,
+        context: interceptor_context,
+        safety_warning,
+        ..
+    } = preflight;
+
+    let namespace = Namespace { database, schema };
+
+    // Best effort: a failed describe only loses the estimate.
+    let removed_rows_estimate = driver
+        .describe_table(session, &namespace, &table)
+        .await
+        .ok()
+        .and_then(|schema| schema.row_count_estimate);
+
+    let start_time = std::time::Instant::now();
+    let outcome = driver.truncate_table(session, &namespace, &table).await;
+    let duration_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+    interceptor.post_execute(
+        &interceptor_context,
+        &QueryExecutionResult {
+            success: outcome.is_ok(),
+            error: outcome.as_ref().err().map(|e| e.sanitized_message()),
+            execution_time_ms: duration_ms,
+            row_count: None,
+            affected_rows: outcome.as_ref().ok().and(removed_rows_estimate),
+        },
+        false,
+        safety_warning.as_deref(),
+    );
+
+    match outcome {
+        Ok(mut result) => {
+            result.execution_time_ms = duration_ms;
+            if let Some(key) = session_manager.connection_key(session).await {
+                query_cache.invalidate_connection(&key);
+            }
+            Ok(TruncateResponse {
+                success: true,
+                result: Some(result),
+                removed_rows_estimate,
+                error: None,
+            })
+        }
+        Err(e) => Ok(TruncateResponse {
+            success: false,
+            result: None,
+            removed_rows_estimate: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UndoMutationResponse {
+    pub success: bool,
+    pub undone: Option<UndoOutcome>,
+    pub error: Option<String>,
+}
+
+/// Reverts the session's most recent `insert_row` / `update_row` /
+/// `delete_row`. Refused once the edit is too old or the table changed shape.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id))]
+pub async fn undo_last_mutation(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<UndoMutationResponse, String> {
+    let state_guard = state.lock().await;
+    let session_manager = Arc::clone(&state_guard.session_manager);
+    let interceptor = Arc::clone(&state_guard.interceptor);
+    let query_cache = Arc::clone(&state_guard.query_cache);
+    let undo_store = Arc::clone(&state_guard.mutation_undo);
+    drop(state_guard);
+    let session = parse_session_id(&session_id)?;
+
+    let Some(last) = undo_store.peek(session) else {
+        return Ok(UndoMutationResponse {
+            success: false,
+            undone: None,
+            error: Some("Nothing to undo".to_string()),
+        });
+    };
+    let query_preview = format!(
+        "UNDO {} ON {}",
+        last.operation().to_uppercase(),
+        format_table_ref(
+            &last.namespace.database,
+            &last.namespace.schema,
+            &last.table
+        )
+    );
+
+    let preflight = match qore_service::mutation::preflight(
+        &session_manager,
+        &interceptor,
+        session,
+        &session_id,
+        &query_preview,
+        &last.namespace.database,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(UndoMutationResponse {
+                success: false,
+                undone: None,
+                error: Some(msg),
+            });
+        }
+    };
+    let qore_service::mutation::MutationPreflight {
+        driver,
+        context: interceptor_context,
+        safety_warning,
+        ..
+    } = preflight;
+
+    let start_time = std::time::Instant::now();
+    let outcome = crate::undo::undo_last(&undo_store, &driver, session).await;
+    interceptor.post_execute(
+        &interceptor_context,
+        &QueryExecutionResult {
+            success: outcome.is_ok(),
+            error: outcome.as_ref().err().cloned(),
+            execution_time_ms: start_time.elapsed().as_micros() as f64 / 1000.0,
+            row_count: outcome.as_ref().ok().map(|_| 1),
+            affected_rows: outcome.as_ref().ok().map(|_| 1),
+        },
+        false,
+        safety_warning.as_deref(),
+    );
+
+    match outcome {
+        Ok(undone) => {
+            if let Some(key) = session_manager.connection_key(session).await {
+                query_cache.invalidate_connection(&key);
+            }
+            Ok(UndoMutationResponse {
+                success: true,
+                undone: Some(undone),
+                error: None,
+            })
+        }
+        Err(e) => Ok(UndoMutationResponse {
+            success: false,
+            undone: None,
+            error: Some(e),
+        }),
+    }
+}
+
 #[tauri::command]
 pub async fn supports_mutations(
     state: State<'_, crate::SharedState>,
@@ -456,3 +1012,190 @@ pub async fn supports_mutations(
 
     Ok(driver.capabilities().mutations)
 }
+
+#[derive(Debug, Serialize)]
+pub struct KeyMutationResponse {
+    pub success: bool,
+    /// False when the key did not exist (or, for `redis_persist`, had no TTL)
+    pub changed: Option<bool>,
+    pub error: Option<String>,
+}
+
+enum KeyMutation {
+    Expire(u64),
+    Persist,
+    Rename(String),
+}
+
+/// Runs a key-level mutation through the same read-only and safety checks as
+/// row mutations. Drivers without key management are refused up front.
+async fn mutate_key(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    key: String,
+    mutation: KeyMutation,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<KeyMutationResponse, String> {
+    let state_guard = state.lock().await;
+    let session_manager = Arc::clone(&state_guard.session_manager);
+    let interceptor = Arc::clone(&state_guard.interceptor);
+    let query_cache = Arc::clone(&state_guard.query_cache);
+    drop(state_guard);
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+    if !driver.capabilities().key_management {
+        return Ok(KeyMutationResponse {
+            success: false,
+            changed: None,
+            error: Some(KEY_MANAGEMENT_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    let query_preview = match &mutation {
+        KeyMutation::Expire(seconds) => format!("EXPIRE {} {}", key, seconds),
+        KeyMutation::Persist => format!("PERSIST {}", key),
+        KeyMutation::Rename(new_key) => format!("RENAME {} {}", key, new_key),
+    };
+
+    let preflight = match qore_service::mutation::preflight(
+        &session_manager,
+        &interceptor,
+        session,
+        &session_id,
+        &query_preview,
+        &database,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(KeyMutationResponse {
+                success: false,
+                changed: None,
+                error: Some(msg),
+            });
+        }
+    };
+    let qore_service::mutation::MutationPreflight {
+        driver,
+        context: interceptor_context,
+        safety_warning,
+        ..
+    } = preflight;
+
+    let namespace = Namespace::new(database);
+    let start_time = std::time::Instant::now();
+    let outcome = match &mutation {
+        KeyMutation::Expire(seconds) => {
+            driver
+                .set_key_ttl(session, &namespace, &key, *seconds)
+                .await
+        }
+        KeyMutation::Persist => driver.persist_key(session, &namespace, &key).await,
+        KeyMutation::Rename(new_key) => driver
+            .rename_key(session, &namespace, &key, new_key)
+            .await
+            .map(|()| true),
+    };
+    interceptor.post_execute(
+        &interceptor_context,
+        &QueryExecutionResult {
+            success: outcome.is_ok(),
+            error: outcome.as_ref().err().map(|e| e.sanitized_message()),
+            execution_time_ms: start_time.elapsed().as_micros() as f64 / 1000.0,
+            row_count: None,
+            affected_rows: outcome.as_ref().ok().map(|&changed| u64::from(changed)),
+        },
+        false,
+        safety_warning.as_deref(),
+    );
+
+    match outcome {
+        Ok(changed) => {
+            if let Some(key) = session_manager.connection_key(session).await {
+                query_cache.invalidate_connection(&key);
+            }
+            Ok(KeyMutationResponse {
+                success: true,
+                changed: Some(changed),
+                error: None,
+            })
+        }
+        Err(e) => Ok(KeyMutationResponse {
+            success: false,
+            changed: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Makes a Redis key expire after `seconds` (`EXPIRE`).
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, database = %database))]
+pub async fn redis_set_ttl(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    key: String,
+    seconds: u64,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<KeyMutationResponse, String> {
+    mutate_key(
+        state,
+        session_id,
+        database,
+        key,
+        KeyMutation::Expire(seconds),
+        acknowledged_dangerous,
+    )
+    .await
+}
+
+/// Removes the expiration of a Redis key (`PERSIST`).
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, database = %database))]
+pub async fn redis_persist(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    key: String,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<KeyMutationResponse, String> {
+    mutate_key(
+        state,
+        session_id,
+        database,
+        key,
+        KeyMutation::Persist,
+        acknowledged_dangerous,
+    )
+    .await
+}
+
+/// Renames a Redis key (`RENAME`), replacing `new_key` if it exists.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, database = %database))]
+pub async fn redis_rename_key(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    database: String,
+    old_key: String,
+    new_key: String,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<KeyMutationResponse, String> {
+    mutate_key(
+        state,
+        session_id,
+        database,
+        old_key,
+        KeyMutation::Rename(new_key),
+        acknowledged_dangerous,
+    )
+    .await
+}
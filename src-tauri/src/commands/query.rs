@@ -3,35 +3,54 @@
 //! Commands for executing queries and exploring database schema.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
+use tokio_util::sync::CancellationToken;
 use tracing::{field, instrument};
 use uuid::Uuid;
 
 use super::{parse_session_id, SharedStateExt};
 use crate::commands::stream_msg::StreamDispatcher;
+use crate::display_transforms::{format_result, FormattedColumn};
 use crate::engine::{
-    sql_safety,
+    sql_safety, sql_script,
     types::{
-        CollectionList, CollectionListOptions, CreationOptions, EventList, EventListOptions,
-        ForeignKey, Namespace, PaginatedQueryResult, QueryId, QueryResult, RoutineList,
-        RoutineListOptions, RoutineType, SequenceList, SequenceListOptions,
-        TableQueryOptions, TriggerList, TriggerListOptions, Value,
+        BulkImportOptions, CollectionList, CollectionListOptions, ColumnStats, CopyTableOptions,
+        CreationOptions, DescribeTableOptions, EventList, EventListOptions, ExplainPlan,
+        ForeignKey, IndexSpec, Namespace, Notification, PaginatedQueryResult, QueryId, QueryResult,
+        RoutineList, RoutineListOptions, RoutineType, SequenceList, SequenceListOptions, SessionId,
+        TablePartitioning, TableQueryOptions, TriggerList, TriggerListOptions, Value,
     },
-    TableSchema,
+    QueryState, TableSchema,
 };
 use crate::interceptor::{map_environment, QueryContext, QueryExecutionResult, SafetyAction};
+use crate::local_cache::{LocalCacheEntry, RecentQuery};
 use crate::metrics;
 use crate::plugins::runtime::{
     HookContext as PluginHookContext, PluginHost, PostExecuteResult, QueryReadPayload,
 };
 use qore_service::governance;
+use qore_service::query::BatchStatementResult;
+use qore_service::query_history::{QueryHistoryConfig, QueryHistoryEntry, QueryHistoryFilter};
 use tauri::ipc::{Channel, InvokeResponseBody};
 
 const READ_ONLY_BLOCKED: &str = "Operation blocked: read-only mode";
 const DANGEROUS_BLOCKED: &str = "Dangerous query blocked: confirmation required";
 const TRANSACTIONS_NOT_SUPPORTED: &str = "Transactions are not supported by this driver";
 const SAFETY_RULE_BLOCKED: &str = "Query blocked by safety rule";
+const EXECUTE_AS_ROLE_NOT_SUPPORTED: &str =
+    "Executing as another role is not supported by this driver";
+const EXECUTE_AS_ROLE_SINGLE_STATEMENT: &str =
+    "Only a single statement can be executed as another role";
+const ROW_SECURITY_NOT_SUPPORTED: &str = "Row-level security is not supported by this driver";
+const BATCH_SQL_ONLY: &str = "Batch execution is only available for SQL drivers";
+const EXPLAIN_NOT_SUPPORTED: &str = "Explain plans are not supported by this driver";
+const AGGREGATION_NOT_SUPPORTED: &str = "Aggregation pipelines are not supported by this driver";
+const NOTIFICATIONS_NOT_SUPPORTED: &str = "Notifications are not supported by this driver";
+
+/// Event topic carrying the notifications of `listen` subscriptions.
+const NOTIFICATION_EVENT: &str = "db-notification";
 
 /// Past this, the `queryRead` payload is dropped and the plugin sees `None`.
 const QUERY_READ_MAX_PAYLOAD_BYTES: usize = 1024 * 1024;
@@ -91,6 +110,13 @@ pub struct NamespacesResponse {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct DatabasesResponse {
+    pub success: bool,
+    pub databases: Option<Vec<String>>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CollectionsResponse {
     pub success: bool,
@@ -119,6 +145,8 @@ pub async fn execute_query(
     timeout_ms: Option<u64>,
     stream: Option<bool>,
     bypass_limits: Option<bool>,
+    max_rows: Option<u64>,
+    connection_id: Option<String>,
     on_stream: Channel<InvokeResponseBody>,
 ) -> Result<QueryResponse, String> {
     let requested_bypass = bypass_limits.unwrap_or(false);
@@ -127,6 +155,8 @@ pub async fn execute_query(
         query_manager,
         query_rate_limiter,
         query_cache,
+        query_history,
+        result_cache,
         policy,
         interceptor,
         plugin_host,
@@ -138,6 +168,8 @@ pub async fn execute_query(
             Arc::clone(&state.query_manager),
             Arc::clone(&state.query_rate_limiter),
             Arc::clone(&state.query_cache),
+            Arc::clone(&state.query_history),
+            Arc::clone(&state.result_cache),
             state.policy.clone(),
             Arc::clone(&state.interceptor),
             Arc::clone(&state.plugin_host),
@@ -345,6 +377,7 @@ pub async fn execute_query(
         dispatch_plugin_post_execute(&plugin_host_for_complete, &plugin_ctx, exec, payload);
     };
 
+    let start_time = std::time::Instant::now();
     let outcome = qore_service::query::execute(
         &query_manager,
         &query_cache,
@@ -363,10 +396,35 @@ pub async fn execute_query(
         bypass_limits,
         sql_statements,
         stream_sender,
+        max_rows,
         on_complete,
     )
     .await;
 
+    // Single-result reads can later be cached locally by id (`cache_result`).
+    if outcome.success
+        && !is_mutation_for_context
+        && outcome.result.is_some()
+        && outcome.extra_results.is_empty()
+    {
+        result_cache.remember(RecentQuery {
+            query_id,
+            session,
+            namespace: namespace.clone(),
+            query: query.clone(),
+        });
+    }
+
+    if query_history.is_enabled() {
+        let mut entry = QueryHistoryEntry::new(session_id.clone(), query);
+        entry.connection_id = connection_id;
+        entry.namespace = namespace;
+        entry.success = outcome.success;
+        entry.error = outcome.error.clone();
+        entry.duration_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+        query_history.record(entry);
+    }
+
     Ok(QueryResponse {
         success: outcome.success,
         result: outcome.result,
@@ -378,6 +436,143 @@ pub async fn execute_query(
     })
 }
 
+/// Runs a single statement with `params` bound to its placeholders (`$1` on
+/// PostgreSQL, `?` on MySQL and SQLite, `@P1` on SQL Server) instead of
+/// inlining the values into the SQL.
+#[tauri::command]
+#[instrument(
+    skip(state, query, params),
+    fields(
+        session_id = %session_id,
+        query_len = query.len(),
+        params = params.len(),
+        driver = field::Empty
+    )
+)]
+pub async fn execute_parameterized(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    query: String,
+    params: Vec<Value>,
+    namespace: Option<Namespace>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<QueryResponse, String> {
+    let (session_manager, query_manager, query_rate_limiter, query_cache, policy, interceptor) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.query_cache),
+            state.policy.clone(),
+            Arc::clone(&state.interceptor),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let preflight = match qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session_id,
+        &query,
+        namespace.as_ref(),
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(QueryResponse {
+                extra_results: Vec::new(),
+                success: false,
+                result: None,
+                error: Some(msg),
+                query_id: None,
+                truncated: None,
+                truncated_total: None,
+            });
+        }
+    };
+    let driver = preflight.driver;
+    tracing::Span::current().record("driver", field::display(driver.driver_id()));
+
+    let query_id = query_manager.register(session).await;
+    let start_time = std::time::Instant::now();
+    let execution = driver.execute_with_params(session, &query, &params, query_id);
+    let result = match policy.max_query_duration_ms {
+        Some(limit) => {
+            match tokio::time::timeout(std::time::Duration::from_millis(limit), execution).await {
+                Ok(res) => res,
+                Err(_) => {
+                    let _ = driver.cancel(session, Some(query_id)).await;
+                    metrics::record_timeout();
+                    Err(crate::engine::EngineError::Timeout { timeout_ms: limit })
+                }
+            }
+        }
+        None => execution.await,
+    };
+    query_manager.finish(query_id).await;
+
+    let duration_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+    metrics::record_query(duration_ms, result.is_ok());
+    let exec_result = match &result {
+        Ok(r) => QueryExecutionResult {
+            success: true,
+            error: None,
+            execution_time_ms: duration_ms,
+            row_count: if r.columns.is_empty() {
+                r.affected_rows.map(|a| a as i64)
+            } else {
+                Some(r.rows.len() as i64)
+            },
+            affected_rows: r.affected_rows,
+        },
+        Err(e) => QueryExecutionResult {
+            success: false,
+            error: Some(e.sanitized_message()),
+            execution_time_ms: duration_ms,
+            row_count: None,
+            affected_rows: None,
+        },
+    };
+    interceptor.post_execute(
+        &preflight.context,
+        &exec_result,
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+    if preflight.is_mutation && result.is_ok() {
+        if let Some(key) = preflight.connection_key.as_deref() {
+            query_cache.invalidate_connection(key);
+        }
+    }
+
+    Ok(match result {
+        Ok(result) => QueryResponse {
+            extra_results: Vec::new(),
+            success: true,
+            result: Some(result),
+            error: None,
+            query_id: Some(query_id.0.to_string()),
+            truncated: None,
+            truncated_total: None,
+        },
+        Err(e) => QueryResponse {
+            extra_results: Vec::new(),
+            success: false,
+            result: None,
+            error: Some(e.sanitized_message()),
+            query_id: Some(query_id.0.to_string()),
+            truncated: None,
+            truncated_total: None,
+        },
+    })
+}
+
 #[tauri::command]
 #[instrument(
     skip(state),
@@ -434,6 +629,20 @@ pub async fn cancel_query(
     };
     let query_id_str = query_id.0.to_string();
 
+    // A query still waiting for a slot never reached the driver.
+    if query_manager.cancel_queued(query_id) {
+        metrics::record_cancel();
+        return Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: true,
+            result: None,
+            error: None,
+            query_id: Some(query_id_str),
+            truncated: None,
+            truncated_total: None,
+        });
+    }
+
     match driver.cancel(session, Some(query_id)).await {
         Ok(()) => {
             metrics::record_cancel();
@@ -459,6 +668,158 @@ pub async fn cancel_query(
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct CancelSessionQueriesResponse {
+    pub success: bool,
+    /// Queued and running queries that were cancelled
+    pub cancelled: usize,
+    /// Running queries left alone because the driver cannot cancel them
+    pub not_cancellable: usize,
+    pub error: Option<String>,
+}
+
+/// Cancels every queued and running query of a session, e.g. when its tab
+/// closes. Drivers without cancellation only lose their queued queries.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, driver = field::Empty))]
+pub async fn cancel_session_queries(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<CancelSessionQueriesResponse, String> {
+    let (session_manager, query_manager) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => {
+            return Ok(CancelSessionQueriesResponse {
+                success: false,
+                cancelled: 0,
+                not_cancellable: 0,
+                error: Some(e.sanitized_message()),
+            });
+        }
+    };
+    tracing::Span::current().record("driver", field::display(driver.driver_id()));
+
+    let mut cancelled = 0;
+    for query_id in query_manager.queued_for_session(session) {
+        if query_manager.cancel_queued(query_id) {
+            cancelled += 1;
+        }
+    }
+
+    let running = query_manager.running_for_session(session).await.len();
+    let mut not_cancellable = 0;
+    let mut error = None;
+    if running > 0 {
+        match driver.cancel(session, None).await {
+            Ok(()) => cancelled += running,
+            Err(crate::engine::EngineError::NotSupported { .. }) => not_cancellable = running,
+            Err(e) => error = Some(e.sanitized_message()),
+        }
+    }
+    for _ in 0..cancelled {
+        metrics::record_cancel();
+    }
+
+    Ok(CancelSessionQueriesResponse {
+        success: error.is_none(),
+        cancelled,
+        not_cancellable,
+        error,
+    })
+}
+
+#[derive(Debug, Serialize)]
+pub struct QueryQueueResponse {
+    pub success: bool,
+    /// Maximum queries running at once; `None` when the session is unlimited.
+    pub limit: Option<usize>,
+    /// Query ids waiting for a slot, next to run first.
+    pub queued: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Concurrency limit and pending queue of a session.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id))]
+pub async fn get_query_queue(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<QueryQueueResponse, String> {
+    let query_manager = Arc::clone(&state.lock().await.query_manager);
+    let session = parse_session_id(&session_id)?;
+
+    Ok(QueryQueueResponse {
+        success: true,
+        limit: query_manager.session_limit(session),
+        queued: query_manager
+            .queued_for_session(session)
+            .into_iter()
+            .map(|qid| qid.0.to_string())
+            .collect(),
+        error: None,
+    })
+}
+
+/// Whether a query is running or queued (with its position); `None` once
+/// it has finished or when the id is unknown.
+#[tauri::command]
+pub async fn get_query_state(
+    state: State<'_, crate::SharedState>,
+    query_id: String,
+) -> Result<Option<QueryState>, String> {
+    let query_manager = Arc::clone(&state.lock().await.query_manager);
+    let parsed = Uuid::parse_str(&query_id).map_err(|e| format!("Invalid query ID: {}", e))?;
+    Ok(query_manager.query_state(QueryId(parsed)).await)
+}
+
+/// Overrides the default concurrency limit of a session.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, limit = limit))]
+pub async fn set_query_concurrency(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    limit: usize,
+) -> Result<QueryQueueResponse, String> {
+    let (session_manager, query_manager) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    if !session_manager.session_exists(session).await {
+        return Ok(QueryQueueResponse {
+            success: false,
+            limit: None,
+            queued: Vec::new(),
+            error: Some("Session not found".to_string()),
+        });
+    }
+
+    query_manager.set_session_limit(session, limit);
+    Ok(QueryQueueResponse {
+        success: true,
+        limit: query_manager.session_limit(session),
+        queued: query_manager
+            .queued_for_session(session)
+            .into_iter()
+            .map(|qid| qid.0.to_string())
+            .collect(),
+        error: None,
+    })
+}
+
 #[tauri::command]
 pub async fn list_namespaces(
     state: State<'_, crate::SharedState>,
@@ -492,6 +853,40 @@ pub async fn list_namespaces(
     }
 }
 
+/// Lists every database of the server, not only the schemas of the current one
+#[tauri::command]
+pub async fn list_databases(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<DatabasesResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => {
+            return Ok(DatabasesResponse {
+                success: false,
+                databases: None,
+                error: Some(e.sanitized_message()),
+            });
+        }
+    };
+
+    match driver.list_databases(session).await {
+        Ok(databases) => Ok(DatabasesResponse {
+            success: true,
+            databases: Some(databases),
+            error: None,
+        }),
+        Err(e) => Ok(DatabasesResponse {
+            success: false,
+            databases: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
 #[tauri::command]
 pub async fn list_collections(
     state: State<'_, crate::SharedState>,
@@ -500,6 +895,7 @@ pub async fn list_collections(
     search: Option<String>,
     page: Option<u32>,
     page_size: Option<u32>,
+    cursor: Option<String>,
 ) -> Result<CollectionsResponse, String> {
     let session_manager = state.session_manager().await;
     let session = parse_session_id(&session_id)?;
@@ -519,6 +915,7 @@ pub async fn list_collections(
         search,
         page,
         page_size,
+        cursor,
     };
 
     match driver.list_collections(session, &namespace, options).await {
@@ -759,6 +1156,7 @@ pub async fn describe_table(
     namespace: Namespace,
     table: String,
     connection_id: Option<String>,
+    fast_estimate: Option<bool>,
 ) -> Result<TableSchemaResponse, String> {
     let (session_manager, vr_store) = {
         let state = state.lock().await;
@@ -776,6 +1174,9 @@ pub async fn describe_table(
         &namespace,
         &table,
         connection_id.as_deref(),
+        &DescribeTableOptions {
+            fast_estimate: fast_estimate.unwrap_or(false),
+        },
     )
     .await
     {
@@ -792,233 +1193,1412 @@ pub async fn describe_table(
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct TablePartitionsResponse {
+    pub success: bool,
+    /// `None` when the table is not partitioned
+    pub partitioning: Option<TablePartitioning>,
+    pub error: Option<String>,
+}
+
+/// Partition key, strategy and partition bounds of a partitioned table
 #[tauri::command]
-pub async fn preview_table(
+#[instrument(skip(state), fields(session_id = %session_id, table = %table))]
+pub async fn get_table_partitions(
     state: State<'_, crate::SharedState>,
     session_id: String,
     namespace: Namespace,
     table: String,
-    limit: u32,
-    bypass_cache: Option<bool>,
-) -> Result<QueryResponse, String> {
-    let (session_manager, query_manager, policy, query_cache) = {
-        let state = state.lock().await;
-        (
-            Arc::clone(&state.session_manager),
-            Arc::clone(&state.query_manager),
-            state.policy.clone(),
-            Arc::clone(&state.query_cache),
-        )
-    };
+) -> Result<TablePartitionsResponse, String> {
+    let session_manager = state.session_manager().await;
     let session = parse_session_id(&session_id)?;
 
-    match qore_service::query::preview_table(
-        &session_manager,
-        &query_manager,
-        &query_cache,
-        &policy,
-        session,
-        &namespace,
-        &table,
-        limit,
-        bypass_cache.unwrap_or(false),
-    )
-    .await
-    {
-        Ok(result) => Ok(QueryResponse {
-            extra_results: Vec::new(),
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().partitions {
+        return Ok(TablePartitionsResponse {
+            success: false,
+            partitioning: None,
+            error: Some("Table partitioning is not supported by this driver".to_string()),
+        });
+    }
+
+    match driver.table_partitioning(session, &namespace, &table).await {
+        Ok(partitioning) => Ok(TablePartitionsResponse {
             success: true,
-            result: Some(result),
+            partitioning,
             error: None,
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
         }),
-        Err(e) => Ok(QueryResponse {
-            extra_results: Vec::new(),
+        Err(e) => Ok(TablePartitionsResponse {
             success: false,
-            result: None,
-            error: Some(e.sanitized()),
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
+            partitioning: None,
+            error: Some(e.sanitized_message()),
         }),
     }
 }
 
 #[derive(Debug, Serialize)]
-pub struct PaginatedQueryResponse {
+pub struct TableDdlResponse {
     pub success: bool,
-    pub result: Option<PaginatedQueryResult>,
+    pub ddl: Option<String>,
     pub error: Option<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub truncated: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub truncated_total: Option<u64>,
-    /// `Some(true)` when this result was served from the query cache.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub cached: Option<bool>,
-    /// Age of the cached entry in milliseconds, when served from cache.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub cached_age_ms: Option<u64>,
 }
 
+/// `CREATE TABLE` statement of a table, followed by its index statements
 #[tauri::command]
-pub async fn query_table(
+#[instrument(skip(state), fields(session_id = %session_id, table = %table))]
+pub async fn get_table_ddl(
     state: State<'_, crate::SharedState>,
     session_id: String,
     namespace: Namespace,
     table: String,
-    options: TableQueryOptions,
-    bypass_cache: Option<bool>,
-) -> Result<PaginatedQueryResponse, String> {
-    let (session_manager, query_manager, policy, query_cache) = {
+) -> Result<TableDdlResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    match driver.get_table_ddl(session, &namespace, &table).await {
+        Ok(ddl) => Ok(TableDdlResponse {
+            success: true,
+            ddl: Some(ddl),
+            error: None,
+        }),
+        Err(e) => Ok(TableDdlResponse {
+            success: false,
+            ddl: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ColumnStatsResponse {
+    pub success: bool,
+    pub stats: Option<ColumnStats>,
+    pub error: Option<String>,
+}
+
+/// Row count, null count, distinct count and min/max/average of one column
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, table = %table, column = %column))]
+pub async fn get_column_stats(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    table: String,
+    column: String,
+) -> Result<ColumnStatsResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    match driver
+        .column_stats(session, &namespace, &table, &column)
+        .await
+    {
+        Ok(stats) => Ok(ColumnStatsResponse {
+            success: true,
+            stats: Some(stats),
+            error: None,
+        }),
+        Err(e) => Ok(ColumnStatsResponse {
+            success: false,
+            stats: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GeoJsonResponse {
+    pub success: bool,
+    pub geojson: Option<String>,
+    pub error: Option<String>,
+}
+
+/// GeoJSON form of a spatial value from a result grid (WKT, EWKT or hex EWKB)
+#[tauri::command]
+#[instrument(skip(state, geometry), fields(session_id = %session_id))]
+pub async fn geometry_to_geojson(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    geometry: String,
+) -> Result<GeoJsonResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    match driver.geometry_to_geojson(session, &geometry).await {
+        Ok(geojson) => Ok(GeoJsonResponse {
+            success: true,
+            geojson: Some(geojson),
+            error: None,
+        }),
+        Err(e) => Ok(GeoJsonResponse {
+            success: false,
+            geojson: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkImportResponse {
+    pub success: bool,
+    pub rows_imported: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Loads a CSV file into an existing table: `COPY ... FROM STDIN` on
+/// PostgreSQL, batched `INSERT`s in a transaction elsewhere.
+#[tauri::command]
+#[instrument(skip(state, options), fields(session_id = %session_id, table = %table))]
+pub async fn bulk_import_csv(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    table: String,
+    file_path: String,
+    options: Option<BulkImportOptions>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<BulkImportResponse, String> {
+    let (session_manager, interceptor, query_cache) = {
         let state = state.lock().await;
         (
             Arc::clone(&state.session_manager),
-            Arc::clone(&state.query_manager),
-            state.policy.clone(),
+            Arc::clone(&state.interceptor),
             Arc::clone(&state.query_cache),
         )
     };
     let session = parse_session_id(&session_id)?;
+    let options = options.unwrap_or_default();
 
-    match qore_service::query::query_table(
+    let query_preview = format!("COPY {} FROM '{}'", table, file_path);
+    let preflight = match qore_service::mutation::preflight(
         &session_manager,
-        &query_manager,
-        &query_cache,
-        &policy,
+        &interceptor,
+        session,
+        &session_id,
+        &query_preview,
+        &namespace.database,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(BulkImportResponse {
+                success: false,
+                rows_imported: None,
+                error: Some(msg),
+            });
+        }
+    };
+
+    let start_time = std::time::Instant::now();
+    let result = qore_service::bulk_import::import_csv(
+        preflight.driver.as_ref(),
         session,
         &namespace,
         &table,
-        options,
-        bypass_cache.unwrap_or(false),
+        std::path::Path::new(&file_path),
+        &options,
+    )
+    .await;
+    let execution_time_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.sanitized_message()),
+            execution_time_ms,
+            row_count: result.as_ref().ok().map(|rows| *rows as i64),
+            affected_rows: result.as_ref().ok().copied(),
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    match result {
+        Ok(rows) => {
+            if let Some(key) = session_manager.connection_key(session).await {
+                query_cache.invalidate_connection(&key);
+            }
+            Ok(BulkImportResponse {
+                success: true,
+                rows_imported: Some(rows),
+                error: None,
+            })
+        }
+        Err(e) => Ok(BulkImportResponse {
+            success: false,
+            rows_imported: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CopyTableResponse {
+    pub success: bool,
+    pub rows_copied: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Copies a table from one connection into another, for example to migrate
+/// it between database engines. With `options.create` the destination table
+/// is created from the source columns, translated by `engine::type_mapper`.
+/// A failing batch is rolled back; `rows_copied` counts the committed ones.
+#[tauri::command]
+#[instrument(
+    skip(state, options),
+    fields(source_session = %source_session, dest_session = %dest_session, table = %table)
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn copy_table(
+    state: State<'_, crate::SharedState>,
+    source_session: String,
+    source_ns: Namespace,
+    table: String,
+    dest_session: String,
+    dest_ns: Namespace,
+    dest_table: String,
+    options: Option<CopyTableOptions>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<CopyTableResponse, String> {
+    let (session_manager, interceptor, query_cache) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.interceptor),
+            Arc::clone(&state.query_cache),
+        )
+    };
+    let source = parse_session_id(&source_session)?;
+    let dest = parse_session_id(&dest_session)?;
+    let options = options.unwrap_or_default();
+
+    let source_driver = match session_manager.get_driver(source).await {
+        Ok(driver) => driver,
+        Err(e) => {
+            return Ok(CopyTableResponse {
+                success: false,
+                rows_copied: None,
+                error: Some(e.sanitized_message()),
+            });
+        }
+    };
+
+    let query_preview = format!("INSERT INTO {} SELECT * FROM {}", dest_table, table);
+    let preflight = match qore_service::mutation::preflight(
+        &session_manager,
+        &interceptor,
+        dest,
+        &dest_session,
+        &query_preview,
+        &dest_ns.database,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(CopyTableResponse {
+                success: false,
+                rows_copied: None,
+                error: Some(msg),
+            });
+        }
+    };
+
+    let start_time = std::time::Instant::now();
+    let result = qore_service::table_copy::copy_table(
+        source_driver,
+        source,
+        &source_ns,
+        &table,
+        preflight.driver.as_ref(),
+        dest,
+        &dest_ns,
+        &dest_table,
+        &options,
+    )
+    .await;
+    let execution_time_ms = start_time.elapsed().as_micros() as f64 / 1000.0;
+
+    let error = match &result {
+        Ok(report) => report.error.clone(),
+        Err(e) => Some(e.sanitized_message()),
+    };
+    let rows_copied = result.as_ref().ok().map(|report| report.rows_copied);
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: error.is_none(),
+            error: error.clone(),
+            execution_time_ms,
+            row_count: rows_copied.map(|rows| rows as i64),
+            affected_rows: rows_copied,
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    if rows_copied.unwrap_or(0) > 0 || options.create {
+        if let Some(key) = session_manager.connection_key(dest).await {
+            query_cache.invalidate_connection(&key);
+        }
+    }
+
+    Ok(CopyTableResponse {
+        success: error.is_none(),
+        rows_copied,
+        error,
+    })
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateIndexResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Creates an index (`CREATE INDEX`, or `createIndex` on MongoDB)
+#[tauri::command]
+#[instrument(skip(state, spec), fields(session_id = %session_id, table = %table))]
+pub async fn create_index(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    table: String,
+    spec: IndexSpec,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<CreateIndexResponse, String> {
+    let (session_manager, interceptor) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.interceptor),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    if let Err(e) = spec.validate() {
+        return Ok(CreateIndexResponse {
+            success: false,
+            error: Some(e.sanitized_message()),
+        });
+    }
+
+    let query_preview = format!("CREATE INDEX {} ON {}", spec.name, table);
+    let preflight = match qore_service::mutation::preflight(
+        &session_manager,
+        &interceptor,
+        session,
+        &session_id,
+        &query_preview,
+        &namespace.database,
+        acknowledged_dangerous.unwrap_or(false),
     )
     .await
     {
-        Ok((result, cached_age_ms)) => Ok(PaginatedQueryResponse {
+        Ok(pf) => pf,
+        Err(msg) => {
+            return Ok(CreateIndexResponse {
+                success: false,
+                error: Some(msg),
+            });
+        }
+    };
+
+    let start_time = std::time::Instant::now();
+    let result = preflight
+        .driver
+        .create_index(session, &namespace, &table, &spec)
+        .await;
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.sanitized_message()),
+            execution_time_ms: start_time.elapsed().as_micros() as f64 / 1000.0,
+            row_count: None,
+            affected_rows: None,
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    match result {
+        Ok(()) => Ok(CreateIndexResponse {
             success: true,
-            result: Some(result),
             error: None,
-            truncated: None,
-            truncated_total: None,
-            cached: cached_age_ms.map(|_| true),
-            cached_age_ms,
         }),
-        Err(e) => Ok(PaginatedQueryResponse {
+        Err(e) => Ok(CreateIndexResponse {
             success: false,
-            result: None,
-            error: Some(e.sanitized()),
-            truncated: None,
-            truncated_total: None,
-            cached: None,
-            cached_age_ms: None,
+            error: Some(e.sanitized_message()),
         }),
     }
 }
 
 #[tauri::command]
-pub async fn peek_foreign_key(
+pub async fn preview_table(
     state: State<'_, crate::SharedState>,
     session_id: String,
     namespace: Namespace,
-    foreign_key: ForeignKey,
-    value: Value,
-    limit: Option<u32>,
+    table: String,
+    limit: u32,
+    bypass_cache: Option<bool>,
 ) -> Result<QueryResponse, String> {
-    let (session_manager, query_manager, policy) = {
+    let (session_manager, query_manager, policy, query_cache) = {
         let state = state.lock().await;
         (
             Arc::clone(&state.session_manager),
             Arc::clone(&state.query_manager),
             state.policy.clone(),
+            Arc::clone(&state.query_cache),
         )
     };
-    let session = parse_session_id(&session_id)?;
-    // UX cap for the tooltip preview; policy may tighten further.
-    let requested = limit.unwrap_or(3).clamp(1, 25);
-    let limit = governance::clamp_rows(&policy, requested);
+    let session = parse_session_id(&session_id)?;
+
+    match qore_service::query::preview_table(
+        &session_manager,
+        &query_manager,
+        &query_cache,
+        &policy,
+        session,
+        &namespace,
+        &table,
+        limit,
+        bypass_cache.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(result) => Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: true,
+            result: Some(result),
+            error: None,
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        }),
+        Err(e) => Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: false,
+            result: None,
+            error: Some(e.sanitized()),
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaginatedQueryResponse {
+    pub success: bool,
+    pub result: Option<PaginatedQueryResult>,
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated_total: Option<u64>,
+    /// `Some(true)` when this result was served from the query cache.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached: Option<bool>,
+    /// Age of the cached entry in milliseconds, when served from cache.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_age_ms: Option<u64>,
+    /// Display-only renderings of columns with a configured transform; the
+    /// raw values in `result` are left untouched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_columns: Option<Vec<FormattedColumn>>,
+}
+
+#[tauri::command]
+pub async fn query_table(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    table: String,
+    options: TableQueryOptions,
+    bypass_cache: Option<bool>,
+    connection_id: Option<String>,
+) -> Result<PaginatedQueryResponse, String> {
+    let (session_manager, query_manager, policy, query_cache, display_transforms) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            state.policy.clone(),
+            Arc::clone(&state.query_cache),
+            Arc::clone(&state.display_transforms),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    match qore_service::query::query_table(
+        &session_manager,
+        &query_manager,
+        &query_cache,
+        &policy,
+        session,
+        &namespace,
+        &table,
+        options,
+        bypass_cache.unwrap_or(false),
+    )
+    .await
+    {
+        Ok((result, cached_age_ms)) => {
+            let formatted_columns = connection_id
+                .map(|connection_id| {
+                    display_transforms.for_table(
+                        &connection_id,
+                        &namespace.database,
+                        namespace.schema.as_deref(),
+                        &table,
+                    )
+                })
+                .filter(|transforms| !transforms.is_empty())
+                .map(|transforms| format_result(&transforms, &result.result));
+            Ok(PaginatedQueryResponse {
+                success: true,
+                result: Some(result),
+                error: None,
+                truncated: None,
+                truncated_total: None,
+                cached: cached_age_ms.map(|_| true),
+                cached_age_ms,
+                formatted_columns,
+            })
+        }
+        Err(e) => Ok(PaginatedQueryResponse {
+            success: false,
+            result: None,
+            error: Some(e.sanitized()),
+            truncated: None,
+            truncated_total: None,
+            cached: None,
+            cached_age_ms: None,
+            formatted_columns: None,
+        }),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CachedResultResponse {
+    pub success: bool,
+    /// Handle for `query_cached` and `refresh_cached_result`.
+    pub handle: Option<String>,
+    pub entry: Option<LocalCacheEntry>,
+    pub error: Option<String>,
+}
+
+impl CachedResultResponse {
+    fn from_result(result: Result<LocalCacheEntry, String>) -> Self {
+        match result {
+            Ok(entry) => Self {
+                success: true,
+                handle: Some(entry.name.clone()),
+                entry: Some(entry),
+                error: None,
+            },
+            Err(e) => Self {
+                success: false,
+                handle: None,
+                entry: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+/// Materializes the result of a recent editor query into the in-memory
+/// DuckDB result cache. The source query runs once more to load it; later
+/// `query_cached` calls never touch the source database.
+#[tauri::command]
+#[instrument(skip(state), fields(query_id = %query_id))]
+pub async fn cache_result(
+    state: State<'_, crate::SharedState>,
+    query_id: String,
+) -> Result<CachedResultResponse, String> {
+    let parsed = Uuid::parse_str(&query_id).map_err(|e| format!("Invalid query ID: {}", e))?;
+    let result = load_cached_result(&state, CachedResultSource::Query(QueryId(parsed))).await;
+    Ok(CachedResultResponse::from_result(result))
+}
+
+/// Re-runs the source query of a cached result, replacing its rows.
+#[tauri::command]
+#[instrument(skip(state), fields(handle = %handle))]
+pub async fn refresh_cached_result(
+    state: State<'_, crate::SharedState>,
+    handle: String,
+) -> Result<CachedResultResponse, String> {
+    let result = load_cached_result(&state, CachedResultSource::Refresh(handle)).await;
+    Ok(CachedResultResponse::from_result(result))
+}
+
+/// Sorts, filters and pages a cached result inside DuckDB.
+#[tauri::command]
+#[instrument(skip(state, options), fields(handle = %handle))]
+pub async fn query_cached(
+    state: State<'_, crate::SharedState>,
+    handle: String,
+    options: TableQueryOptions,
+) -> Result<PaginatedQueryResponse, String> {
+    let result_cache = Arc::clone(&state.lock().await.result_cache);
+
+    match result_cache.query(&handle, options).await {
+        Ok(result) => Ok(PaginatedQueryResponse {
+            success: true,
+            result: Some(result),
+            error: None,
+            truncated: None,
+            truncated_total: None,
+            cached: Some(true),
+            cached_age_ms: None,
+            formatted_columns: None,
+        }),
+        Err(e) => Ok(PaginatedQueryResponse {
+            success: false,
+            result: None,
+            error: Some(e.sanitized_message()),
+            truncated: None,
+            truncated_total: None,
+            cached: None,
+            cached_age_ms: None,
+            formatted_columns: None,
+        }),
+    }
+}
+
+enum CachedResultSource {
+    Query(QueryId),
+    Refresh(String),
+}
+
+/// (Re)loads a cached result. Loading runs the source query on the remote
+/// connection, so the usual read-only and production guards apply.
+async fn load_cached_result(
+    state: &State<'_, crate::SharedState>,
+    source: CachedResultSource,
+) -> Result<LocalCacheEntry, String> {
+    let (session_manager, query_rate_limiter, interceptor, policy, result_cache) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_rate_limiter),
+            Arc::clone(&state.interceptor),
+            state.policy.clone(),
+            Arc::clone(&state.result_cache),
+        )
+    };
+
+    let (session, query, namespace) = match &source {
+        CachedResultSource::Query(query_id) => {
+            let recent = result_cache
+                .recent(*query_id)
+                .ok_or_else(|| "No recent result for this query ID".to_string())?;
+            (recent.session, recent.query, recent.namespace)
+        }
+        CachedResultSource::Refresh(handle) => {
+            let entry = result_cache
+                .get(handle)
+                .await
+                .map_err(|e| e.sanitized_message())?;
+            let session = result_cache
+                .session_for(handle)
+                .map_err(|e| e.sanitized_message())?;
+            (session, entry.source_query, entry.namespace)
+        }
+    };
+
+    qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session.0.to_string(),
+        &query,
+        namespace.as_ref(),
+        false,
+    )
+    .await?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    match source {
+        CachedResultSource::Query(query_id) => {
+            let recent = RecentQuery {
+                query_id,
+                session,
+                namespace,
+                query,
+            };
+            result_cache.cache(driver, recent).await
+        }
+        CachedResultSource::Refresh(handle) => result_cache.refresh(driver, &handle).await,
+    }
+    .map_err(|e| e.sanitized_message())
+}
+
+#[tauri::command]
+pub async fn peek_foreign_key(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    foreign_key: ForeignKey,
+    value: Value,
+    limit: Option<u32>,
+) -> Result<QueryResponse, String> {
+    let (session_manager, query_manager, policy) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            state.policy.clone(),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+    // UX cap for the tooltip preview; policy may tighten further.
+    let requested = limit.unwrap_or(3).clamp(1, 25);
+    let limit = governance::clamp_rows(&policy, requested);
+
+    if foreign_key.referenced_table.trim().is_empty()
+        || foreign_key.referenced_column.trim().is_empty()
+        || matches!(value, Value::Null)
+    {
+        return Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: true,
+            result: Some(QueryResult {
+                columns: Vec::new(),
+                rows: Vec::new(),
+                affected_rows: None,
+                execution_time_ms: 0.0,
+            }),
+            error: None,
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        });
+    }
+
+    if let Err(msg) = governance::check_concurrent_limit(&policy, &query_manager).await {
+        return Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: false,
+            result: None,
+            error: Some(msg),
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        });
+    }
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => {
+            return Ok(QueryResponse {
+                extra_results: Vec::new(),
+                success: false,
+                result: None,
+                error: Some(e.sanitized_message()),
+                query_id: None,
+                truncated: None,
+                truncated_total: None,
+            });
+        }
+    };
+
+    let result = governance::with_timeout(
+        &policy,
+        driver.peek_foreign_key(session, &namespace, &foreign_key, &value, limit),
+    )
+    .await;
+
+    match result {
+        Ok(Ok(result)) => Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: true,
+            result: Some(result),
+            error: None,
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        }),
+        Ok(Err(e)) => Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: false,
+            result: None,
+            error: Some(e.sanitized_message()),
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        }),
+        Err(timeout_msg) => Ok(QueryResponse {
+            extra_results: Vec::new(),
+            success: false,
+            result: None,
+            error: Some(timeout_msg),
+            query_id: None,
+            truncated: None,
+            truncated_total: None,
+        }),
+    }
+}
+
+/// Runs a single statement as `role` (`SET ROLE` / `EXECUTE AS USER`) to see
+/// what that role can access. The statement runs in a transaction that is
+/// always rolled back, and the role is reset even when it fails.
+#[tauri::command]
+#[instrument(
+    skip(state, query),
+    fields(session_id = %session_id, role = %role, query_len = query.len())
+)]
+pub async fn execute_as_role(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    role: String,
+    query: String,
+    namespace: Option<Namespace>,
+    acknowledged_dangerous: Option<bool>,
+    query_id: Option<String>,
+) -> Result<QueryResponse, String> {
+    let (session_manager, query_manager, query_rate_limiter, policy, interceptor) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            Arc::clone(&state.query_rate_limiter),
+            state.policy.clone(),
+            Arc::clone(&state.interceptor),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let failure = |msg: String| QueryResponse {
+        extra_results: Vec::new(),
+        success: false,
+        result: None,
+        error: Some(msg),
+        query_id: None,
+        truncated: None,
+        truncated_total: None,
+    };
+
+    let preflight = match qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session_id,
+        &query,
+        namespace.as_ref(),
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => return Ok(failure(msg)),
+    };
+    let driver = preflight.driver;
+
+    if !driver.capabilities().execute_as_role {
+        return Ok(failure(EXECUTE_AS_ROLE_NOT_SUPPORTED.to_string()));
+    }
+
+    // A second statement could end the wrapping transaction (COMMIT) or undo
+    // the role switch before the reset runs.
+    if matches!(
+        sql_safety::split_sql_statements(driver.driver_id(), &query),
+        Ok(statements) if statements.len() > 1
+    ) {
+        return Ok(failure(EXECUTE_AS_ROLE_SINGLE_STATEMENT.to_string()));
+    }
+
+    if let Err(msg) = governance::check_concurrent_limit(&policy, &query_manager).await {
+        return Ok(failure(msg));
+    }
+
+    let query_id = if let Some(raw) = query_id {
+        let parsed = Uuid::parse_str(&raw).map_err(|e| format!("Invalid query ID: {}", e))?;
+        let qid = QueryId(parsed);
+        query_manager
+            .register_with_id(session, qid)
+            .await
+            .map_err(|e| format!("Failed to register query ID: {}", e))?;
+        qid
+    } else {
+        query_manager.register(session).await
+    };
+
+    let start = std::time::Instant::now();
+    let result = governance::with_timeout(
+        &policy,
+        driver.execute_as_role(session, namespace, &role, &query, query_id),
+    )
+    .await;
+    query_manager.finish(query_id).await;
+
+    let result = match result {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(e)) => Err(e.sanitized_message()),
+        Err(timeout_msg) => Err(timeout_msg),
+    };
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: result.is_ok(),
+            error: result.as_ref().err().cloned(),
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+            row_count: result.as_ref().ok().map(|r| r.rows.len() as i64),
+            affected_rows: None,
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    Ok(QueryResponse {
+        extra_results: Vec::new(),
+        success: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+        result: result.ok(),
+        query_id: Some(query_id.0.to_string()),
+        truncated: None,
+        truncated_total: None,
+    })
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchResponse {
+    pub success: bool,
+    pub results: Vec<BatchStatementResult>,
+    /// Index of the statement that failed; later statements did not run
+    pub failed_index: Option<usize>,
+    /// Whether the statements ran in one transaction that was rolled back
+    /// on failure
+    pub transactional: bool,
+    pub error: Option<String>,
+}
+
+/// Splits `script` into statements and runs them one after the other, in a
+/// single transaction where the driver supports it. Execution stops at the
+/// first failing statement, and the transaction is rolled back.
+#[tauri::command]
+#[instrument(
+    skip(state, script),
+    fields(session_id = %session_id, script_len = script.len())
+)]
+pub async fn execute_batch(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    script: String,
+    namespace: Option<Namespace>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<BatchResponse, String> {
+    let (session_manager, query_manager, query_rate_limiter, policy, interceptor) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            Arc::clone(&state.query_rate_limiter),
+            state.policy.clone(),
+            Arc::clone(&state.interceptor),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let failure = |msg: String| BatchResponse {
+        success: false,
+        results: Vec::new(),
+        failed_index: None,
+        transactional: false,
+        error: Some(msg),
+    };
+
+    let preflight = match qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session_id,
+        &script,
+        namespace.as_ref(),
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => return Ok(failure(msg)),
+    };
+    let driver = preflight.driver;
+
+    if !preflight.is_sql_driver {
+        return Ok(failure(BATCH_SQL_ONLY.to_string()));
+    }
+    let statements = sql_script::split_script(driver.driver_id(), &script);
+    if statements.is_empty() {
+        return Ok(failure("Empty SQL".to_string()));
+    }
+
+    if let Err(msg) = governance::check_concurrent_limit(&policy, &query_manager).await {
+        return Ok(failure(msg));
+    }
+
+    let start = std::time::Instant::now();
+    let outcome = qore_service::query::execute_batch(
+        &query_manager,
+        driver.as_ref(),
+        session,
+        namespace,
+        &statements,
+    )
+    .await;
+
+    let (outcome, error) = match outcome {
+        Ok(outcome) => {
+            let error = outcome.failed_index.map(|index| {
+                let cause = outcome.results[index].error.clone().unwrap_or_default();
+                format!("Statement {} failed: {}", index + 1, cause)
+            });
+            (outcome, error)
+        }
+        Err(e) => (Default::default(), Some(e.sanitized_message())),
+    };
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: error.is_none(),
+            error: error.clone(),
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+            row_count: None,
+            affected_rows: None,
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    Ok(BatchResponse {
+        success: error.is_none(),
+        results: outcome.results,
+        failed_index: outcome.failed_index,
+        transactional: outcome.transactional,
+        error,
+    })
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExplainResponse {
+    pub success: bool,
+    pub plan: Option<ExplainPlan>,
+    pub error: Option<String>,
+}
+
+/// Returns the execution plan of `query`. With `analyze` the query is
+/// actually run, so it goes through the same read-only, safety and audit
+/// path as `execute_query`; a plain plan only compiles it.
+#[tauri::command]
+#[instrument(
+    skip(state, query),
+    fields(session_id = %session_id, analyze = ?analyze, query_len = query.len())
+)]
+pub async fn explain_query(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    query: String,
+    namespace: Option<Namespace>,
+    analyze: Option<bool>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<ExplainResponse, String> {
+    let (session_manager, query_manager, query_rate_limiter, policy, interceptor) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            Arc::clone(&state.query_rate_limiter),
+            state.policy.clone(),
+            Arc::clone(&state.interceptor),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+    let analyze = analyze.unwrap_or(false);
+
+    let failure = |msg: String| ExplainResponse {
+        success: false,
+        plan: None,
+        error: Some(msg),
+    };
+
+    if !analyze {
+        let driver = session_manager
+            .get_driver(session)
+            .await
+            .map_err(|e| e.sanitized_message())?;
+        if !driver.capabilities().explain {
+            return Ok(failure(EXPLAIN_NOT_SUPPORTED.to_string()));
+        }
+        let result = driver
+            .explain_query(session, namespace, &query, false)
+            .await;
+        return Ok(match result {
+            Ok(plan) => ExplainResponse {
+                success: true,
+                plan: Some(plan),
+                error: None,
+            },
+            Err(e) => failure(e.sanitized_message()),
+        });
+    }
+
+    let preflight = match qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session_id,
+        &query,
+        namespace.as_ref(),
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => return Ok(failure(msg)),
+    };
+    let driver = preflight.driver;
+
+    if !driver.capabilities().explain {
+        return Ok(failure(EXPLAIN_NOT_SUPPORTED.to_string()));
+    }
+
+    if let Err(msg) = governance::check_concurrent_limit(&policy, &query_manager).await {
+        return Ok(failure(msg));
+    }
+    let query_id = query_manager.register(session).await;
+
+    let start = std::time::Instant::now();
+    let result = governance::with_timeout(
+        &policy,
+        driver.explain_query(session, namespace, &query, true),
+    )
+    .await;
+    query_manager.finish(query_id).await;
+
+    let result = match result {
+        Ok(Ok(plan)) => Ok(plan),
+        Ok(Err(e)) => Err(e.sanitized_message()),
+        Err(timeout_msg) => Err(timeout_msg),
+    };
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: result.is_ok(),
+            error: result.as_ref().err().cloned(),
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+            row_count: None,
+            affected_rows: None,
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    Ok(ExplainResponse {
+        success: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+        plan: result.ok(),
+    })
+}
+
+/// Runs an aggregation pipeline, a JSON array of stages, on `collection`.
+/// Checked and audited as the equivalent `aggregate` query, so `$out` and
+/// `$merge` are refused on read-only connections.
+#[tauri::command]
+#[instrument(
+    skip(state, pipeline),
+    fields(session_id = %session_id, collection = %collection, pipeline_len = pipeline.len())
+)]
+pub async fn run_aggregation(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    collection: String,
+    pipeline: String,
+    acknowledged_dangerous: Option<bool>,
+    query_id: Option<String>,
+) -> Result<QueryResponse, String> {
+    let (session_manager, query_manager, query_rate_limiter, policy, interceptor) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.query_manager),
+            Arc::clone(&state.query_rate_limiter),
+            state.policy.clone(),
+            Arc::clone(&state.interceptor),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let failure = |msg: String| QueryResponse {
+        extra_results: Vec::new(),
+        success: false,
+        result: None,
+        error: Some(msg),
+        query_id: None,
+        truncated: None,
+        truncated_total: None,
+    };
+
+    // The safety rules and the audit log see the same document `execute`
+    // would receive for this aggregation.
+    let stages = serde_json::from_str::<serde_json::Value>(&pipeline)
+        .unwrap_or_else(|_| serde_json::Value::String(pipeline.clone()));
+    let query = serde_json::json!({
+        "operation": "aggregate",
+        "database": namespace.database,
+        "collection": collection,
+        "pipeline": stages,
+    })
+    .to_string();
+
+    let preflight = match qore_service::query::preflight(
+        &session_manager,
+        &query_rate_limiter,
+        &interceptor,
+        &policy,
+        session,
+        &session_id,
+        &query,
+        Some(&namespace),
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(pf) => pf,
+        Err(msg) => return Ok(failure(msg)),
+    };
+    let driver = preflight.driver;
 
-    if foreign_key.referenced_table.trim().is_empty()
-        || foreign_key.referenced_column.trim().is_empty()
-        || matches!(value, Value::Null)
-    {
-        return Ok(QueryResponse {
-            extra_results: Vec::new(),
-            success: true,
-            result: Some(QueryResult {
-                columns: Vec::new(),
-                rows: Vec::new(),
-                affected_rows: None,
-                execution_time_ms: 0.0,
-            }),
-            error: None,
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
-        });
+    if !driver.capabilities().aggregation {
+        return Ok(failure(AGGREGATION_NOT_SUPPORTED.to_string()));
     }
 
     if let Err(msg) = governance::check_concurrent_limit(&policy, &query_manager).await {
-        return Ok(QueryResponse {
-            extra_results: Vec::new(),
-            success: false,
-            result: None,
-            error: Some(msg),
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
-        });
+        return Ok(failure(msg));
     }
 
-    let driver = match session_manager.get_driver(session).await {
-        Ok(d) => d,
-        Err(e) => {
-            return Ok(QueryResponse {
-                extra_results: Vec::new(),
-                success: false,
-                result: None,
-                error: Some(e.sanitized_message()),
-                query_id: None,
-                truncated: None,
-                truncated_total: None,
-            });
-        }
+    let query_id = if let Some(raw) = query_id {
+        let parsed = Uuid::parse_str(&raw).map_err(|e| format!("Invalid query ID: {}", e))?;
+        let qid = QueryId(parsed);
+        query_manager
+            .register_with_id(session, qid)
+            .await
+            .map_err(|e| format!("Failed to register query ID: {}", e))?;
+        qid
+    } else {
+        query_manager.register(session).await
     };
 
+    let start = std::time::Instant::now();
     let result = governance::with_timeout(
         &policy,
-        driver.peek_foreign_key(session, &namespace, &foreign_key, &value, limit),
+        driver.run_aggregation(session, &namespace, &collection, &pipeline),
     )
     .await;
+    query_manager.finish(query_id).await;
 
-    match result {
-        Ok(Ok(result)) => Ok(QueryResponse {
-            extra_results: Vec::new(),
+    let result = match result {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(e)) => Err(e.sanitized_message()),
+        Err(timeout_msg) => Err(timeout_msg),
+    };
+    interceptor.post_execute(
+        &preflight.context,
+        &QueryExecutionResult {
+            success: result.is_ok(),
+            error: result.as_ref().err().cloned(),
+            execution_time_ms: start.elapsed().as_micros() as f64 / 1000.0,
+            row_count: result.as_ref().ok().map(|r| r.rows.len() as i64),
+            affected_rows: None,
+        },
+        false,
+        preflight.safety_warning.as_deref(),
+    );
+
+    Ok(QueryResponse {
+        extra_results: Vec::new(),
+        success: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+        result: result.ok(),
+        query_id: Some(query_id.0.to_string()),
+        truncated: None,
+        truncated_total: None,
+    })
+}
+
+#[derive(Debug, Serialize)]
+pub struct RowSecurityResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Turns row-level security off for every statement of the session, so a
+/// superuser or BYPASSRLS role sees all rows, or back on with `bypass = false`.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id))]
+pub async fn set_row_security_bypass(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    bypass: bool,
+) -> Result<RowSecurityResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().row_security {
+        return Ok(RowSecurityResponse {
+            success: false,
+            error: Some(ROW_SECURITY_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    match driver.set_row_security_bypass(session, bypass).await {
+        Ok(()) => Ok(RowSecurityResponse {
             success: true,
-            result: Some(result),
             error: None,
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
         }),
-        Ok(Err(e)) => Ok(QueryResponse {
-            extra_results: Vec::new(),
+        Err(e) => Ok(RowSecurityResponse {
             success: false,
-            result: None,
             error: Some(e.sanitized_message()),
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
-        }),
-        Err(timeout_msg) => Ok(QueryResponse {
-            extra_results: Vec::new(),
-            success: false,
-            result: None,
-            error: Some(timeout_msg),
-            query_id: None,
-            truncated: None,
-            truncated_total: None,
         }),
     }
 }
@@ -1097,13 +2677,14 @@ pub async fn create_database(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => {
                 format!(
                     "{}: {}",
                     SAFETY_RULE_BLOCKED,
@@ -1150,6 +2731,7 @@ pub async fn create_database(
                     error: None,
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -1173,6 +2755,7 @@ pub async fn create_database(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -1263,13 +2846,14 @@ pub async fn drop_database(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => {
                 format!(
                     "{}: {}",
                     SAFETY_RULE_BLOCKED,
@@ -1314,6 +2898,7 @@ pub async fn drop_database(
                     error: None,
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -1337,6 +2922,7 @@ pub async fn drop_database(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: duration_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -1354,6 +2940,114 @@ pub async fn drop_database(
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct AttachDatabaseResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Attaches a database file (or `:memory:`) to the session under `alias`.
+/// Files must live under the safety policy's `attach_allowed_dir`.
+#[tauri::command]
+pub async fn attach_database(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    file_path: String,
+    alias: String,
+) -> Result<AttachDatabaseResponse, String> {
+    let (session_manager, policy) = {
+        let state = state.lock().await;
+        (Arc::clone(&state.session_manager), state.policy.clone())
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let failure = |error: String| AttachDatabaseResponse {
+        success: false,
+        error: Some(error),
+    };
+
+    // An attached file is writable regardless of the session's read-only flag.
+    if session_manager.is_read_only(session).await.unwrap_or(false) {
+        return Ok(failure(READ_ONLY_BLOCKED.to_string()));
+    }
+
+    let path = match policy.resolve_attach_path(&file_path) {
+        Ok(path) => path,
+        Err(e) => return Ok(failure(e)),
+    };
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => return Ok(failure(e.sanitized_message())),
+    };
+
+    match driver
+        .attach_database(session, &path.to_string_lossy(), &alias)
+        .await
+    {
+        Ok(()) => Ok(AttachDatabaseResponse {
+            success: true,
+            error: None,
+        }),
+        Err(e) => Ok(failure(e.sanitized_message())),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RegisterFileViewResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Exposes a Parquet, CSV or JSON file as a view named `view_name`, so it
+/// shows up in the schema browser and can be queried like a table. DuckDB
+/// only.
+#[tauri::command]
+pub async fn register_file_as_view(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    file_path: String,
+    view_name: String,
+) -> Result<RegisterFileViewResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let failure = |error: String| RegisterFileViewResponse {
+        success: false,
+        error: Some(error),
+    };
+
+    if session_manager.is_read_only(session).await.unwrap_or(false) {
+        return Ok(failure(READ_ONLY_BLOCKED.to_string()));
+    }
+
+    let path = std::path::Path::new(&file_path);
+    if !path.is_absolute() {
+        return Ok(failure("File path must be absolute".to_string()));
+    }
+    let path = match path.canonicalize() {
+        Ok(path) if path.is_file() => path,
+        Ok(_) => return Ok(failure(format!("Not a file: {}", file_path))),
+        Err(e) => return Ok(failure(format!("Invalid file path {}: {}", file_path, e))),
+    };
+
+    let driver = match session_manager.get_driver(session).await {
+        Ok(d) => d,
+        Err(e) => return Ok(failure(e.sanitized_message())),
+    };
+
+    match driver
+        .register_file_view(session, &path.to_string_lossy(), &view_name)
+        .await
+    {
+        Ok(()) => Ok(RegisterFileViewResponse {
+            success: true,
+            error: None,
+        }),
+        Err(e) => Ok(failure(e.sanitized_message())),
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct CreationOptionsResponse {
     pub success: bool,
@@ -1613,3 +3307,217 @@ pub async fn update_governance_limits(
         max_concurrent_queries: state.policy.max_concurrent_queries,
     })
 }
+
+/// Searches the query history, newest first. Matches `filter.search` as a
+/// case-insensitive substring of the query text.
+#[tauri::command]
+pub async fn search_history(
+    state: State<'_, crate::SharedState>,
+    filter: Option<QueryHistoryFilter>,
+) -> Result<Vec<QueryHistoryEntry>, String> {
+    let history = Arc::clone(&state.lock().await.query_history);
+    Ok(history.search(&filter.unwrap_or_default()))
+}
+
+#[tauri::command]
+pub async fn clear_history(state: State<'_, crate::SharedState>) -> Result<(), String> {
+    let history = Arc::clone(&state.lock().await.query_history);
+    history.clear();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_history_config(
+    state: State<'_, crate::SharedState>,
+) -> Result<QueryHistoryConfig, String> {
+    let history = Arc::clone(&state.lock().await.query_history);
+    Ok(history.config())
+}
+
+#[tauri::command]
+pub async fn set_history_config(
+    state: State<'_, crate::SharedState>,
+    config: QueryHistoryConfig,
+) -> Result<QueryHistoryConfig, String> {
+    let history = Arc::clone(&state.lock().await.query_history);
+    history.set_config(config)?;
+    Ok(history.config())
+}
+
+/// Active `listen` subscriptions, keyed by session and channel.
+#[derive(Default)]
+pub struct NotificationListeners {
+    inner: parking_lot::Mutex<HashMap<(SessionId, String), CancellationToken>>,
+}
+
+impl NotificationListeners {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a subscription and returns its cancellation token. Fails
+    /// when the session already listens on `channel`.
+    fn register(&self, session: SessionId, channel: &str) -> Result<CancellationToken, String> {
+        let mut inner = self.inner.lock();
+        let key = (session, channel.to_string());
+        if inner.contains_key(&key) {
+            return Err(format!("Already listening on '{}'", channel));
+        }
+        let token = CancellationToken::new();
+        inner.insert(key, token.clone());
+        Ok(token)
+    }
+
+    /// Returns `true` when the subscription was found and stopped.
+    pub fn cancel(&self, session: SessionId, channel: &str) -> bool {
+        match self.inner.lock().remove(&(session, channel.to_string())) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stops every subscription of `session`, e.g. before it disconnects.
+    pub fn cancel_session(&self, session: SessionId) {
+        self.inner.lock().retain(|(s, _), token| {
+            if *s == session {
+                token.cancel();
+            }
+            *s != session
+        });
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NotificationSubscriptionResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl NotificationSubscriptionResponse {
+    fn failure(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            error: Some(error.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NotificationEvent {
+    Received {
+        notification: Notification,
+    },
+    /// The subscription ended on its own (lost connection, LISTEN refused).
+    Failed {
+        error: String,
+    },
+}
+
+/// Subscribes the session to `channel` on a dedicated connection. Each
+/// notification is emitted as a `db-notification` event tagged with the
+/// session and channel, until `unlisten` or disconnect.
+#[tauri::command]
+#[instrument(skip(state, app), fields(session_id = %session_id, channel = %channel))]
+pub async fn listen(
+    state: State<'_, crate::SharedState>,
+    app: AppHandle,
+    session_id: String,
+    channel: String,
+) -> Result<NotificationSubscriptionResponse, String> {
+    let (session_manager, listeners) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.notification_listeners),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().notifications {
+        return Ok(NotificationSubscriptionResponse::failure(
+            NOTIFICATIONS_NOT_SUPPORTED,
+        ));
+    }
+
+    let cancel = match listeners.register(session, &channel) {
+        Ok(token) => token,
+        Err(msg) => return Ok(NotificationSubscriptionResponse::failure(msg)),
+    };
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(256);
+    let subscription = tauri::async_runtime::spawn({
+        let channel = channel.clone();
+        async move { driver.listen(session, &channel, sender).await }
+    });
+
+    tauri::async_runtime::spawn(async move {
+        let emit = |event: NotificationEvent| {
+            let payload = serde_json::json!({
+                "session_id": session_id,
+                "channel": channel,
+                "event": event,
+            });
+            let _ = app.emit(NOTIFICATION_EVENT, payload);
+        };
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                received = receiver.recv() => match received {
+                    Some(notification) => emit(NotificationEvent::Received { notification }),
+                    None => break,
+                },
+            }
+        }
+
+        // Dropping the receiver ends the driver loop, which releases the
+        // dedicated connection.
+        drop(receiver);
+        if !cancel.is_cancelled() {
+            let error = match subscription.await {
+                Ok(Err(e)) => e.sanitized_message(),
+                _ => "Notification subscription ended".to_string(),
+            };
+            listeners.cancel(session, &channel);
+            emit(NotificationEvent::Failed { error });
+        }
+    });
+
+    Ok(NotificationSubscriptionResponse {
+        success: true,
+        error: None,
+    })
+}
+
+/// Stops the session's subscription to `channel`.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, channel = %channel))]
+pub async fn unlisten(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    channel: String,
+) -> Result<NotificationSubscriptionResponse, String> {
+    let listeners = Arc::clone(&state.lock().await.notification_listeners);
+    let session = parse_session_id(&session_id)?;
+
+    if listeners.cancel(session, &channel) {
+        Ok(NotificationSubscriptionResponse {
+            success: true,
+            error: None,
+        })
+    } else {
+        Ok(NotificationSubscriptionResponse::failure(format!(
+            "Not listening on '{}'",
+            channel
+        )))
+    }
+}
@@ -177,13 +177,14 @@ pub async fn drop_routine(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => {
                 format!(
                     "{}: {}",
                     SAFETY_RULE_BLOCKED,
@@ -228,6 +229,7 @@ pub async fn drop_routine(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -246,6 +248,7 @@ pub async fn drop_routine(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: 0.0,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
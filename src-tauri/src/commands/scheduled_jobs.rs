@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Commands for listing and controlling scheduled jobs (pg_cron, MySQL
+//! events, SQL Server Agent).
+
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::State;
+use tracing::instrument;
+
+use super::{parse_session_id, SharedStateExt};
+use crate::engine::types::{ScheduledJobList, ScheduledJobOperationResult};
+use crate::interceptor::{map_environment, Environment, QueryExecutionResult, SafetyAction};
+
+const READ_ONLY_BLOCKED: &str = "Operation blocked: read-only mode";
+const SCHEDULED_JOBS_NOT_SUPPORTED: &str = "Scheduled jobs are not supported by this driver";
+const PRODUCTION_CONFIRMATION_REQUIRED: &str =
+    "Confirmation required: scheduled jobs are changed on a production connection";
+const DANGEROUS_BLOCKED: &str = "Dangerous query blocked: confirmation required";
+const SAFETY_RULE_BLOCKED: &str = "Query blocked by safety rule";
+
+#[derive(Debug, Serialize)]
+pub struct ScheduledJobsResponse {
+    pub success: bool,
+    pub data: Option<ScheduledJobList>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduledJobOperationResponse {
+    pub success: bool,
+    pub result: Option<ScheduledJobOperationResult>,
+    pub error: Option<String>,
+}
+
+impl ScheduledJobOperationResponse {
+    fn failure(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            result: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+enum JobOperation {
+    SetEnabled(bool),
+    RunNow,
+}
+
+/// Lists the jobs of the connection's scheduler. `data.scheduler` is `None`
+/// when the scheduler is not installed.
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id))]
+pub async fn list_scheduled_jobs(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+) -> Result<ScheduledJobsResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().scheduled_jobs {
+        return Ok(ScheduledJobsResponse {
+            success: false,
+            data: None,
+            error: Some(SCHEDULED_JOBS_NOT_SUPPORTED.to_string()),
+        });
+    }
+
+    match driver.list_scheduled_jobs(session).await {
+        Ok(list) => Ok(ScheduledJobsResponse {
+            success: true,
+            data: Some(list),
+            error: None,
+        }),
+        Err(e) => Ok(ScheduledJobsResponse {
+            success: false,
+            data: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, job_id = %job_id))]
+pub async fn set_scheduled_job_enabled(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    job_id: String,
+    database: Option<String>,
+    enabled: bool,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<ScheduledJobOperationResponse, String> {
+    run_job_operation(
+        state,
+        session_id,
+        job_id,
+        database,
+        JobOperation::SetEnabled(enabled),
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+}
+
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id, job_id = %job_id))]
+pub async fn run_scheduled_job(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    job_id: String,
+    database: Option<String>,
+    acknowledged_dangerous: Option<bool>,
+) -> Result<ScheduledJobOperationResponse, String> {
+    run_job_operation(
+        state,
+        session_id,
+        job_id,
+        database,
+        JobOperation::RunNow,
+        acknowledged_dangerous.unwrap_or(false),
+    )
+    .await
+}
+
+/// Read-only, production confirmation and safety rules, then the driver
+/// call, audited like any other statement.
+async fn run_job_operation(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    job_id: String,
+    database: Option<String>,
+    operation: JobOperation,
+    acknowledged: bool,
+) -> Result<ScheduledJobOperationResponse, String> {
+    let (session_manager, interceptor, policy) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.interceptor),
+            state.policy.clone(),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let read_only = session_manager
+        .is_read_only(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+    if read_only {
+        return Ok(ScheduledJobOperationResponse::failure(READ_ONLY_BLOCKED));
+    }
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().scheduled_jobs {
+        return Ok(ScheduledJobOperationResponse::failure(
+            SCHEDULED_JOBS_NOT_SUPPORTED,
+        ));
+    }
+
+    let environment = session_manager
+        .get_environment(session)
+        .await
+        .unwrap_or_else(|_| "development".to_string());
+    let interceptor_env = map_environment(&environment);
+
+    if matches!(interceptor_env, Environment::Production)
+        && policy.prod_require_confirmation
+        && !acknowledged
+    {
+        return Ok(ScheduledJobOperationResponse::failure(
+            PRODUCTION_CONFIRMATION_REQUIRED,
+        ));
+    }
+
+    let query_preview = match operation {
+        JobOperation::SetEnabled(true) => format!("ENABLE SCHEDULED JOB {}", job_id),
+        JobOperation::SetEnabled(false) => format!("DISABLE SCHEDULED JOB {}", job_id),
+        JobOperation::RunNow => format!("RUN SCHEDULED JOB {}", job_id),
+    };
+
+    let interceptor_context = interceptor.build_context(
+        &session_id,
+        &query_preview,
+        driver.driver_id(),
+        interceptor_env,
+        read_only,
+        acknowledged,
+        database.as_deref(),
+        None,
+        true,
+    );
+
+    let safety_result = interceptor.pre_execute(&interceptor_context);
+    if !safety_result.allowed {
+        interceptor.post_execute(
+            &interceptor_context,
+            &QueryExecutionResult {
+                success: false,
+                error: safety_result.message.clone(),
+                execution_time_ms: 0.0,
+                row_count: None,
+                affected_rows: None,
+            },
+            true,
+            safety_result.triggered_rule.as_deref(),
+        );
+
+        let error_msg = match safety_result.action {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => format!(
+                "{}: {}",
+                SAFETY_RULE_BLOCKED,
+                safety_result.message.unwrap_or_default()
+            ),
+            SafetyAction::RequireConfirmation => format!(
+                "{}: {}",
+                DANGEROUS_BLOCKED,
+                safety_result.message.unwrap_or_default()
+            ),
+            SafetyAction::Warn => "Warning triggered".to_string(),
+        };
+        return Ok(ScheduledJobOperationResponse::failure(error_msg));
+    }
+
+    let safety_warning = if matches!(safety_result.action, SafetyAction::Warn) {
+        safety_result.triggered_rule.clone()
+    } else {
+        None
+    };
+
+    let result = match operation {
+        JobOperation::SetEnabled(enabled) => {
+            driver
+                .set_scheduled_job_enabled(session, &job_id, database.as_deref(), enabled)
+                .await
+        }
+        JobOperation::RunNow => {
+            driver
+                .run_scheduled_job(session, &job_id, database.as_deref())
+                .await
+        }
+    };
+
+    interceptor.post_execute(
+        &interceptor_context,
+        &QueryExecutionResult {
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.sanitized_message()),
+            execution_time_ms: result.as_ref().map(|r| r.execution_time_ms).unwrap_or(0.0),
+            row_count: None,
+            affected_rows: None,
+        },
+        false,
+        safety_warning.as_deref(),
+    );
+
+    match result {
+        Ok(result) => Ok(ScheduledJobOperationResponse {
+            success: true,
+            result: Some(result),
+            error: None,
+        }),
+        Err(e) => Ok(ScheduledJobOperationResponse::failure(
+            e.sanitized_message(),
+        )),
+    }
+}
@@ -1,19 +1,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Exports the full DDL schema (tables, routines, triggers, events)
-//! of a database to a .sql file.
+//! of a database to a .sql file, or its tables and relationships as a
+//! text ER diagram (Mermaid / DBML).
 
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tauri::State;
 use tracing::instrument;
 
 use super::{parse_session_id, SharedStateExt};
-use crate::engine::schema_export::generate_create_table_ddl;
+use crate::engine::schema_export::{
+    generate_create_table_ddl, generate_er_diagram, ErDiagramFormat,
+};
 use crate::engine::sql_generator::SqlDialect;
 use crate::engine::types::{
-    CollectionListOptions, CollectionType, Namespace, RoutineListOptions, SequenceListOptions,
-    TriggerListOptions,
+    CollectionListOptions, CollectionType, DescribeTableOptions, Namespace, RoutineListOptions,
+    SequenceListOptions, TriggerListOptions,
 };
 
 #[derive(Debug, Deserialize)]
@@ -114,6 +118,7 @@ pub async fn export_schema(
                     search: None,
                     page: None,
                     page_size: Some(10000),
+                    cursor: None,
                 },
             )
             .await
@@ -378,6 +383,103 @@ pub async fn export_schema(
     })
 }
 
+#[derive(Debug, Serialize)]
+pub struct ErDiagramResponse {
+    pub success: bool,
+    pub diagram: Option<String>,
+    pub table_count: u32,
+    pub error: Option<String>,
+}
+
+/// Builds a Mermaid or DBML ER diagram of the tables in `namespace`.
+///
+/// Relationships include virtual relations when `connection_id` is given.
+#[tauri::command]
+#[instrument(
+    skip(state, namespace),
+    fields(session_id = %session_id, database = %namespace.database, format = ?format)
+)]
+pub async fn export_er_diagram(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    namespace: Namespace,
+    format: ErDiagramFormat,
+    connection_id: Option<String>,
+) -> Result<ErDiagramResponse, String> {
+    let (session_manager, vr_store) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.virtual_relations),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    let collections = match driver
+        .list_collections(
+            session,
+            &namespace,
+            CollectionListOptions {
+                search: None,
+                page: None,
+                page_size: Some(10000),
+                cursor: None,
+            },
+        )
+        .await
+    {
+        Ok(collections) => collections,
+        Err(e) => {
+            return Ok(ErDiagramResponse {
+                success: false,
+                diagram: None,
+                table_count: 0,
+                error: Some(e.sanitized_message()),
+            });
+        }
+    };
+
+    let mut tables = Vec::new();
+    for collection in collections
+        .collections
+        .iter()
+        .filter(|c| matches!(c.collection_type, CollectionType::Table))
+    {
+        match qore_service::query::describe_table(
+            &session_manager,
+            &vr_store,
+            session,
+            &namespace,
+            &collection.name,
+            connection_id.as_deref(),
+            &DescribeTableOptions::default(),
+        )
+        .await
+        {
+            Ok(table_schema) => tables.push((collection.name.clone(), table_schema)),
+            Err(e) => {
+                tracing::warn!(
+                    "ER diagram: failed to describe table {}: {}",
+                    collection.name,
+                    e.sanitized()
+                );
+            }
+        }
+    }
+
+    Ok(ErDiagramResponse {
+        success: true,
+        diagram: Some(generate_er_diagram(&tables, format)),
+        table_count: tables.len() as u32,
+        error: None,
+    })
+}
+
 /// Whitelist of root directories the frontend may write schema dumps to.
 /// Each entry is canonicalised on use so a symlink at `~/Documents` is
 /// resolved before the prefix check. Returning an empty `Vec` is fine — the
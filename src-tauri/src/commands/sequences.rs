@@ -155,13 +155,14 @@ pub async fn drop_sequence(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => {
                 format!(
                     "{}: {}",
                     SAFETY_RULE_BLOCKED,
@@ -205,6 +206,7 @@ pub async fn drop_sequence(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -223,6 +225,7 @@ pub async fn drop_sequence(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: 0.0,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Commands for reading and following the server log of a connection.
+//!
+//! Following polls the log in the background and emits the new lines as
+//! `server-log` events keyed by `follow_id`, until `stop_server_log_follow`
+//! is called or a read fails.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, State};
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{parse_session_id, SharedStateExt};
+use crate::engine::types::{ServerLogChunk, ServerLogLine};
+
+const SERVER_LOG_NOT_SUPPORTED: &str = "Reading the server log is not supported by this driver";
+const DEFAULT_LOG_LINES: u32 = 200;
+/// Delay between two reads of a followed log.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Event topic carrying the new lines of followed logs.
+const SERVER_LOG_EVENT: &str = "server-log";
+
+#[derive(Debug, Serialize)]
+pub struct ServerLogResponse {
+    pub success: bool,
+    pub data: Option<ServerLogChunk>,
+    pub error: Option<String>,
+}
+
+impl ServerLogResponse {
+    fn failure(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            data: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ServerLogEvent {
+    /// Lines logged since the previous event, oldest first.
+    Lines { lines: Vec<ServerLogLine> },
+    /// A read failed; the follow is stopped.
+    Failed { error: String },
+}
+
+/// Registry of followed logs, keyed by follow id.
+#[derive(Default)]
+pub struct ServerLogFollows {
+    inner: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl ServerLogFollows {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a follow and returns its cancellation token. Fails when the
+    /// id is already in use.
+    fn register(&self, follow_id: &str) -> Result<CancellationToken, String> {
+        let mut inner = self.inner.lock();
+        if inner.contains_key(follow_id) {
+            return Err(format!("Log follow '{}' is already running", follow_id));
+        }
+        let token = CancellationToken::new();
+        inner.insert(follow_id.to_string(), token.clone());
+        Ok(token)
+    }
+
+    fn deregister(&self, follow_id: &str) {
+        self.inner.lock().remove(follow_id);
+    }
+
+    /// Returns `true` when the follow was found and stopped.
+    pub fn cancel(&self, follow_id: &str) -> bool {
+        match self.inner.lock().remove(follow_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Reads the last `lines` lines of the server log, or the lines logged after
+/// `after` (the `cursor` of a previous read).
+#[tauri::command]
+#[instrument(skip(state), fields(session_id = %session_id))]
+pub async fn read_server_log(
+    state: State<'_, crate::SharedState>,
+    session_id: String,
+    lines: Option<u32>,
+    after: Option<String>,
+) -> Result<ServerLogResponse, String> {
+    let session_manager = state.session_manager().await;
+    let session = parse_session_id(&session_id)?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().server_log {
+        return Ok(ServerLogResponse::failure(SERVER_LOG_NOT_SUPPORTED));
+    }
+
+    match driver
+        .read_server_log(
+            session,
+            lines.unwrap_or(DEFAULT_LOG_LINES),
+            after.as_deref(),
+        )
+        .await
+    {
+        Ok(chunk) => Ok(ServerLogResponse {
+            success: true,
+            data: Some(chunk),
+            error: None,
+        }),
+        Err(e) => Ok(ServerLogResponse::failure(e.sanitized_message())),
+    }
+}
+
+/// Returns the last `lines` lines like `read_server_log`, then keeps polling
+/// and emits newer lines as `server-log` events tagged with `follow_id`.
+#[tauri::command]
+#[instrument(skip(state, app), fields(session_id = %session_id, follow_id = %follow_id))]
+pub async fn follow_server_log(
+    state: State<'_, crate::SharedState>,
+    app: AppHandle,
+    session_id: String,
+    follow_id: String,
+    lines: Option<u32>,
+) -> Result<ServerLogResponse, String> {
+    let (session_manager, follows) = {
+        let state = state.lock().await;
+        (
+            Arc::clone(&state.session_manager),
+            Arc::clone(&state.server_log_follows),
+        )
+    };
+    let session = parse_session_id(&session_id)?;
+    Uuid::parse_str(&follow_id).map_err(|e| format!("Invalid follow ID: {}", e))?;
+
+    let driver = session_manager
+        .get_driver(session)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+
+    if !driver.capabilities().server_log {
+        return Ok(ServerLogResponse::failure(SERVER_LOG_NOT_SUPPORTED));
+    }
+
+    // The first read surfaces missing privileges before anything is polled.
+    let lines = lines.unwrap_or(DEFAULT_LOG_LINES);
+    let chunk = match driver.read_server_log(session, lines, None).await {
+        Ok(chunk) => chunk,
+        Err(e) => return Ok(ServerLogResponse::failure(e.sanitized_message())),
+    };
+
+    let cancel = match follows.register(&follow_id) {
+        Ok(token) => token,
+        Err(msg) => return Ok(ServerLogResponse::failure(msg)),
+    };
+
+    let mut cursor = chunk.cursor.clone();
+    tauri::async_runtime::spawn(async move {
+        let emit = |event: ServerLogEvent| {
+            let payload = serde_json::json!({
+                "follow_id": follow_id,
+                "event": event,
+            });
+            let _ = app.emit(SERVER_LOG_EVENT, payload);
+        };
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(FOLLOW_POLL_INTERVAL) => {}
+            }
+            match driver
+                .read_server_log(session, lines, cursor.as_deref())
+                .await
+            {
+                Ok(_) if cancel.is_cancelled() => break,
+                Ok(chunk) => {
+                    cursor = chunk.cursor;
+                    if !chunk.lines.is_empty() {
+                        emit(ServerLogEvent::Lines { lines: chunk.lines });
+                    }
+                }
+                Err(e) => {
+                    emit(ServerLogEvent::Failed {
+                        error: e.sanitized_message(),
+                    });
+                    follows.deregister(&follow_id);
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(ServerLogResponse {
+        success: true,
+        data: Some(chunk),
+        error: None,
+    })
+}
+
+/// Stops following a server log. Returns `true` if the follow was found.
+#[tauri::command]
+pub async fn stop_server_log_follow(
+    state: State<'_, crate::SharedState>,
+    follow_id: String,
+) -> Result<bool, String> {
+    let follows = Arc::clone(&state.lock().await.server_log_follows);
+    Ok(follows.cancel(&follow_id))
+}
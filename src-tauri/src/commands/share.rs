@@ -16,6 +16,7 @@ fn extension_for_format(format: &crate::export::types::ExportFormat) -> &'static
     match format {
         crate::export::types::ExportFormat::Csv => "csv",
         crate::export::types::ExportFormat::Json => "json",
+        crate::export::types::ExportFormat::JsonLines => "jsonl",
         crate::export::types::ExportFormat::SqlInsert => "sql",
         crate::export::types::ExportFormat::Html => "html",
         crate::export::types::ExportFormat::Xlsx => "xlsx",
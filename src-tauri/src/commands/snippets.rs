@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Commands for the named query snippet library.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tauri::State;
+use tracing::instrument;
+
+use crate::engine::types::Value;
+use crate::snippets::{render, Snippet};
+
+#[derive(Debug, Serialize)]
+pub struct SnippetListResponse {
+    pub success: bool,
+    pub snippets: Vec<Snippet>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SnippetResponse {
+    pub success: bool,
+    pub snippet: Option<Snippet>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SnippetDeleteResponse {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RenderSnippetResponse {
+    pub success: bool,
+    /// The snippet body with every placeholder substituted
+    pub sql: Option<String>,
+    pub error: Option<String>,
+}
+
+#[tauri::command]
+pub async fn list_snippets(
+    state: State<'_, crate::SharedState>,
+) -> Result<SnippetListResponse, String> {
+    let store = Arc::clone(&state.lock().await.snippet_store);
+    Ok(SnippetListResponse {
+        success: true,
+        snippets: store.list(),
+        error: None,
+    })
+}
+
+/// Creates the snippet when `id` is empty, otherwise updates it.
+#[tauri::command]
+#[instrument(skip(state, snippet), fields(name = %snippet.name))]
+pub async fn save_snippet(
+    state: State<'_, crate::SharedState>,
+    snippet: Snippet,
+) -> Result<SnippetResponse, String> {
+    let store = Arc::clone(&state.lock().await.snippet_store);
+    match store.save(snippet) {
+        Ok(snippet) => Ok(SnippetResponse {
+            success: true,
+            snippet: Some(snippet),
+            error: None,
+        }),
+        Err(e) => Ok(SnippetResponse {
+            success: false,
+            snippet: None,
+            error: Some(e),
+        }),
+    }
+}
+
+#[tauri::command]
+pub async fn delete_snippet(
+    state: State<'_, crate::SharedState>,
+    id: String,
+) -> Result<SnippetDeleteResponse, String> {
+    let store = Arc::clone(&state.lock().await.snippet_store);
+    match store.delete(&id) {
+        Ok(()) => Ok(SnippetDeleteResponse {
+            success: true,
+            error: None,
+        }),
+        Err(e) => Ok(SnippetDeleteResponse {
+            success: false,
+            error: Some(e),
+        }),
+    }
+}
+
+/// Returns the snippet's SQL with `values` (or parameter defaults) escaped
+/// into its placeholders, ready to run. Fails when a placeholder has no value.
+#[tauri::command]
+#[instrument(skip(state, values))]
+pub async fn render_snippet(
+    state: State<'_, crate::SharedState>,
+    id: String,
+    values: HashMap<String, Value>,
+) -> Result<RenderSnippetResponse, String> {
+    let store = Arc::clone(&state.lock().await.snippet_store);
+    let rendered = store
+        .get(&id)
+        .ok_or_else(|| "Snippet not found".to_string())
+        .and_then(|snippet| render(&snippet, &values));
+    match rendered {
+        Ok(sql) => Ok(RenderSnippetResponse {
+            success: true,
+            sql: Some(sql),
+            error: None,
+        }),
+        Err(e) => Ok(RenderSnippetResponse {
+            success: false,
+            sql: None,
+            error: Some(e),
+        }),
+    }
+}
@@ -27,6 +27,8 @@ enum StreamMsg<'a> {
     Row(&'a Row),
     #[serde(rename = "e")]
     Error(&'a str),
+    #[serde(rename = "t")]
+    Truncated { returned: u64, cap: u64 },
     #[serde(rename = "d")]
     Done(u64),
 }
@@ -73,6 +75,10 @@ impl<'a> StreamDispatcher<'a> {
                 StreamEvent::Row(row) => StreamMsg::Row(row),
                 StreamEvent::RowBatch(batch) => StreamMsg::RowBatch(batch.as_slice()),
                 StreamEvent::Error(e) => StreamMsg::Error(e.as_str()),
+                StreamEvent::Truncated { returned, cap } => StreamMsg::Truncated {
+                    returned: *returned,
+                    cap: *cap,
+                },
                 StreamEvent::Done(a) => StreamMsg::Done(*a),
             };
             let mut buf = Vec::with_capacity(self.capacity_hint);
@@ -128,6 +134,12 @@ pub fn dispatch_via_emit(event: StreamEvent, window: &Window, query_id: &str) {
         StreamEvent::Error(e) => {
             let _ = window.emit(&format!("query_stream_error:{}", query_id), e);
         }
+        StreamEvent::Truncated { returned, cap } => {
+            let _ = window.emit(
+                &format!("query_stream_truncated:{}", query_id),
+                serde_json::json!({ "returned": returned, "cap": cap }),
+            );
+        }
         StreamEvent::Done(a) => {
             let _ = window.emit(&format!("query_stream_done:{}", query_id), a);
         }
@@ -181,13 +181,14 @@ pub async fn drop_trigger(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => {
                 format!(
                     "{}: {}",
                     SAFETY_RULE_BLOCKED,
@@ -231,6 +232,7 @@ pub async fn drop_trigger(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -249,6 +251,7 @@ pub async fn drop_trigger(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: 0.0,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -448,13 +451,14 @@ pub async fn drop_event(
                 error: safety_result.message.clone(),
                 execution_time_ms: 0.0,
                 row_count: None,
+                affected_rows: None,
             },
             true,
             safety_result.triggered_rule.as_deref(),
         );
 
         let error_msg = match safety_result.action {
-            SafetyAction::Block => {
+            SafetyAction::Block | SafetyAction::Throttle { .. } => {
                 format!(
                     "{}: {}",
                     SAFETY_RULE_BLOCKED,
@@ -495,6 +499,7 @@ pub async fn drop_event(
                     error: None,
                     execution_time_ms: result.execution_time_ms,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -513,6 +518,7 @@ pub async fn drop_event(
                     error: Some(e.sanitized_message()),
                     execution_time_ms: 0.0,
                     row_count: None,
+                    affected_rows: None,
                 },
                 false,
                 safety_warning.as_deref(),
@@ -2,15 +2,19 @@
 
 //! Commands for managing saved connections and vault lock.
 
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager, State};
 
 use crate::commands::workspace::SharedWorkspaceManager;
-use crate::engine::types::MssqlAuthMode;
+use crate::engine::types::{IsolationLevel, MssqlAuthMode, TlsConfig};
 use crate::observability::Sensitive;
 use crate::vault::backend::KeyringProvider;
+use crate::vault::backup::{self, ImportSummary};
 use crate::vault::credentials::{
-    Environment, ProxyInfo, SavedConnection, SshTunnelInfo, StoredCredentials,
+    split_extra_params, Environment, ProxyInfo, SavedConnection, SshTunnelInfo, StoredCredentials,
 };
 use crate::vault::storage::VaultStorage;
 use crate::workspace::connection_store::WorkspaceConnectionStore;
@@ -82,6 +86,20 @@ pub struct SaveConnectionInput {
     pub search_auth_mode: Option<String>,
     #[serde(default)]
     pub ssl_ca_cert: Option<String>,
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    #[serde(default)]
+    pub extra_params: Option<HashMap<String, String>>,
+    #[serde(default)]
+    pub statement_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub lock_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub default_isolation_level: Option<IsolationLevel>,
+    #[serde(default)]
+    pub wait_for_ready_secs: Option<u64>,
+    #[serde(default)]
+    pub query_timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -222,6 +240,7 @@ pub async fn save_connection(
         connect_timeout_secs: p.connect_timeout_secs,
     });
 
+    let (extra_params, extra_secrets) = split_extra_params(input.extra_params);
     let connection = SavedConnection {
         id: input.id.clone(),
         name: input.name,
@@ -243,6 +262,13 @@ pub async fn save_connection(
         clickhouse_cluster: input.clickhouse_cluster,
         search_auth_mode: input.search_auth_mode,
         ssl_ca_cert: input.ssl_ca_cert,
+        tls: input.tls,
+        extra_params,
+        statement_timeout_ms: input.statement_timeout_ms,
+        lock_timeout_ms: input.lock_timeout_ms,
+        default_isolation_level: input.default_isolation_level,
+        wait_for_ready_secs: input.wait_for_ready_secs,
+        query_timeout_secs: input.query_timeout_secs,
         project_id: input.project_id,
     };
 
@@ -260,6 +286,7 @@ pub async fn save_connection(
             .proxy
             .as_ref()
             .and_then(|p| p.password.clone().map(Sensitive::new)),
+        extra_secrets,
     };
 
     let result = if let Some(ws_store) = get_workspace_store(&ws_manager).await {
@@ -305,13 +332,17 @@ pub async fn list_saved_connections(
     drop(state);
 
     if let Some(ws_store) = get_workspace_store(&ws_manager).await {
-        return ws_store.list_connections().map_err(|e| e.sanitized_message());
+        return ws_store
+            .list_connections()
+            .map_err(|e| e.sanitized_message());
     }
 
     let storage_dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
     let storage = VaultStorage::new(&project_id, storage_dir, Box::new(KeyringProvider::new()));
 
-    storage.list_connections_full().map_err(|e| e.sanitized_message())
+    storage
+        .list_connections_full()
+        .map_err(|e| e.sanitized_message())
 }
 
 #[tauri::command]
@@ -466,3 +497,116 @@ pub async fn get_connection_credentials(
         }),
     }
 }
+
+#[derive(Debug, Serialize)]
+pub struct ExportConnectionsResponse {
+    pub success: bool,
+    /// Base64 of the encrypted, versioned backup blob
+    pub blob: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportConnectionsResponse {
+    pub success: bool,
+    pub summary: Option<ImportSummary>,
+    pub error: Option<String>,
+}
+
+/// Exports every saved connection, credentials included, as a single blob
+/// encrypted with the master password.
+#[tauri::command]
+pub async fn export_connections(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    project_id: String,
+    master_password: String,
+) -> Result<ExportConnectionsResponse, String> {
+    let mut app_state = state.lock().await;
+
+    // Bulk credential read: re-check the master password rather than relying
+    // on an unlock that may be hours old (cf. `get_connection_credentials`).
+    if let Err(e) = app_state
+        .vault_lock
+        .verify_master_password(&master_password)
+        .await
+    {
+        return Ok(ExportConnectionsResponse {
+            success: false,
+            blob: None,
+            error: Some(e.sanitized_message()),
+        });
+    }
+    drop(app_state);
+
+    let storage_dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e: tauri::Error| e.to_string())?;
+    let storage = VaultStorage::new(&project_id, storage_dir, Box::new(KeyringProvider::new()));
+
+    match backup::export_connections(&storage, &master_password) {
+        Ok(blob) => {
+            tracing::info!(project_id = %project_id, "vault connections exported");
+            Ok(ExportConnectionsResponse {
+                success: true,
+                blob: Some(STANDARD.encode(blob)),
+                error: None,
+            })
+        }
+        Err(e) => Ok(ExportConnectionsResponse {
+            success: false,
+            blob: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
+
+/// Imports connections from an `export_connections` blob, merging by id.
+#[tauri::command]
+pub async fn import_connections(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    project_id: String,
+    blob: String,
+    master_password: String,
+    overwrite: bool,
+) -> Result<ImportConnectionsResponse, String> {
+    let app_state = state.lock().await;
+
+    if app_state.vault_lock.is_locked() {
+        return Ok(ImportConnectionsResponse {
+            success: false,
+            summary: None,
+            error: Some("Vault is locked".to_string()),
+        });
+    }
+    drop(app_state);
+
+    let Ok(bytes) = STANDARD.decode(blob.trim()) else {
+        return Ok(ImportConnectionsResponse {
+            success: false,
+            summary: None,
+            error: Some("Backup file is not valid".to_string()),
+        });
+    };
+
+    let storage_dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e: tauri::Error| e.to_string())?;
+    let storage = VaultStorage::new(&project_id, storage_dir, Box::new(KeyringProvider::new()));
+
+    match backup::import_connections(&storage, &bytes, &master_password, overwrite) {
+        Ok(summary) => Ok(ImportConnectionsResponse {
+            success: true,
+            summary: Some(summary),
+            error: None,
+        }),
+        Err(e) => Ok(ImportConnectionsResponse {
+            success: false,
+            summary: None,
+            error: Some(e.sanitized_message()),
+        }),
+    }
+}
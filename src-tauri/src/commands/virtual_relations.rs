@@ -4,7 +4,8 @@ use serde::Serialize;
 use std::sync::Arc;
 use tauri::State;
 
-use crate::virtual_relations::VirtualRelation;
+use super::parse_session_id;
+use crate::virtual_relations::{check_relation, VirtualRelation};
 
 #[derive(Debug, Serialize)]
 pub struct VirtualRelationsResponse {
@@ -36,16 +37,33 @@ pub async fn list_virtual_relations(
     })
 }
 
+/// Both columns are checked against the live schema of `session_id` first;
+/// `force` skips the check, e.g. when the referenced database is not connected.
 #[tauri::command]
 pub async fn add_virtual_relation(
     state: State<'_, crate::SharedState>,
     connection_id: String,
     relation: VirtualRelation,
+    session_id: Option<String>,
+    force: Option<bool>,
 ) -> Result<VirtualRelationMutationResponse, String> {
-    let vr_store = {
+    let (vr_store, session_manager) = {
         let state = state.lock().await;
-        Arc::clone(&state.virtual_relations)
+        (
+            Arc::clone(&state.virtual_relations),
+            Arc::clone(&state.session_manager),
+        )
     };
+    let session = session_id.as_deref().map(parse_session_id).transpose()?;
+    if let Err(e) =
+        check_relation(&session_manager, session, &relation, force.unwrap_or(false)).await
+    {
+        return Ok(VirtualRelationMutationResponse {
+            success: false,
+            error: Some(e.sanitized()),
+        });
+    }
+
     match vr_store.add(&connection_id, relation) {
         Ok(()) => Ok(VirtualRelationMutationResponse {
             success: true,
@@ -58,16 +76,33 @@ pub async fn add_virtual_relation(
     }
 }
 
+/// Both columns are checked against the live schema of `session_id` first;
+/// `force` skips the check, e.g. when the referenced database is not connected.
 #[tauri::command]
 pub async fn update_virtual_relation(
     state: State<'_, crate::SharedState>,
     connection_id: String,
     relation: VirtualRelation,
+    session_id: Option<String>,
+    force: Option<bool>,
 ) -> Result<VirtualRelationMutationResponse, String> {
-    let vr_store = {
+    let (vr_store, session_manager) = {
         let state = state.lock().await;
-        Arc::clone(&state.virtual_relations)
+        (
+            Arc::clone(&state.virtual_relations),
+            Arc::clone(&state.session_manager),
+        )
     };
+    let session = session_id.as_deref().map(parse_session_id).transpose()?;
+    if let Err(e) =
+        check_relation(&session_manager, session, &relation, force.unwrap_or(false)).await
+    {
+        return Ok(VirtualRelationMutationResponse {
+            success: false,
+            error: Some(e.sanitized()),
+        });
+    }
+
     match vr_store.update(&connection_id, relation) {
         Ok(()) => Ok(VirtualRelationMutationResponse {
             success: true,
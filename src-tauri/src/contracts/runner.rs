@@ -477,8 +477,8 @@ mod tests {
         async fn disconnect(&self, _session: SessionId) -> EngineResult<()> {
             Ok(())
         }
-        async fn ping(&self, _session: SessionId) -> EngineResult<()> {
-            Ok(())
+        async fn ping(&self, _session: SessionId) -> EngineResult<std::time::Duration> {
+            Ok(std::time::Duration::ZERO)
         }
         async fn list_namespaces(&self, _session: SessionId) -> EngineResult<Vec<Namespace>> {
             Ok(Vec::new())
@@ -492,6 +492,7 @@ mod tests {
             Ok(CollectionList {
                 collections: Vec::new(),
                 total_count: 0,
+                next_cursor: None,
             })
         }
         async fn create_database(
@@ -561,6 +562,7 @@ mod tests {
                 name: col.into(),
                 data_type: "int".into(),
                 nullable: false,
+                case_insensitive: false,
             }],
             rows: vec![Row {
                 values: vec![value],
@@ -577,11 +579,13 @@ mod tests {
                     name: "violations".into(),
                     data_type: "int".into(),
                     nullable: false,
+                    case_insensitive: false,
                 },
                 ColumnInfo {
                     name: "total".into(),
                     data_type: "int".into(),
                     nullable: false,
+                    case_insensitive: false,
                 },
             ],
             rows: vec![Row { values: vec![a, b] }],
@@ -683,6 +687,7 @@ mod tests {
                     name: "email".into(),
                     data_type: "text".into(),
                     nullable: true,
+                    case_insensitive: false,
                 }],
                 rows: vec![
                     Row {
@@ -20,12 +20,24 @@ pub mod registry {
 pub mod sql_safety {
     pub use qore_sql::safety::*;
 }
+pub mod sql_lint {
+    pub use qore_sql::lint::*;
+}
 pub mod sql_generator {
     pub use qore_sql::generator::*;
 }
+pub mod sql_script {
+    pub use qore_sql::script::*;
+}
 pub mod connection_url {
     pub use qore_sql::connection_url::*;
 }
+pub mod connection_env {
+    pub use qore_sql::connection_env::*;
+}
+pub mod type_mapper {
+    pub use qore_sql::type_mapper::*;
+}
 pub mod drivers {
     pub use qore_drivers::drivers::*;
 }
@@ -61,5 +73,5 @@ pub use qore_core::error::EngineError;
 pub use qore_core::registry::DriverRegistry;
 pub use qore_core::traits::DataEngine;
 pub use qore_core::types::*;
-pub use qore_drivers::query_manager::QueryManager;
+pub use qore_drivers::query_manager::{QueryManager, QueryState};
 pub use qore_drivers::session_manager::SessionManager;
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Export Checkpoints
+//!
+//! A resumable export keeps a `<output>.checkpoint` file next to its output.
+//! It is rewritten after every flushed batch with the number of rows and bytes
+//! that are safely on disk, and removed once the export completes. Anything
+//! the file holds past `bytes_written` was not confirmed and is dropped on
+//! resume.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::sql_generator::SqlDialect;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportCheckpoint {
+    /// Ordered query the rows came from; a resume must run the same one
+    pub query: String,
+    pub rows_written: u64,
+    pub bytes_written: u64,
+}
+
+pub fn checkpoint_path(output_path: &str) -> PathBuf {
+    PathBuf::from(format!("{output_path}.checkpoint"))
+}
+
+pub async fn load_checkpoint(output_path: &str) -> Result<Option<ExportCheckpoint>, String> {
+    let path = checkpoint_path(output_path);
+    let content = match tokio::fs::read(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read export checkpoint: {}", e)),
+    };
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|e| format!("Invalid export checkpoint: {}", e))
+}
+
+/// Writes through a temporary file and renames it, so a crash mid-write
+/// leaves the previous checkpoint intact.
+pub async fn save_checkpoint(
+    output_path: &str,
+    checkpoint: &ExportCheckpoint,
+) -> Result<(), String> {
+    let path = checkpoint_path(output_path);
+    let tmp = path.with_extension("checkpoint.tmp");
+    let content = serde_json::to_vec(checkpoint).map_err(|e| e.to_string())?;
+    tokio::fs::write(&tmp, content)
+        .await
+        .map_err(|e| format!("Failed to write export checkpoint: {}", e))?;
+    tokio::fs::rename(&tmp, &path)
+        .await
+        .map_err(|e| format!("Failed to write export checkpoint: {}", e))
+}
+
+pub async fn remove_checkpoint(output_path: &str) -> Result<(), String> {
+    match tokio::fs::remove_file(checkpoint_path(output_path)).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to remove export checkpoint: {}", e)),
+    }
+}
+
+/// Wraps `query` so its rows come back in key order, which makes row `n` of
+/// one run row `n` of the next.
+pub fn ordered_query(dialect: SqlDialect, query: &str, key_columns: &[String]) -> String {
+    let query = query.trim().trim_end_matches(';').trim_end();
+    let order_by: Vec<String> = key_columns
+        .iter()
+        .map(|column| dialect.quote_ident(column))
+        .collect();
+    format!(
+        "SELECT * FROM ({}) AS qore_export ORDER BY {}",
+        query,
+        order_by.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_wrapped_query_by_key() {
+        let sql = ordered_query(
+            SqlDialect::Postgres,
+            "SELECT * FROM orders;",
+            &["tenant_id".to_string(), "id".to_string()],
+        );
+        assert_eq!(
+            sql,
+            r#"SELECT * FROM (SELECT * FROM orders) AS qore_export ORDER BY "tenant_id", "id""#
+        );
+    }
+
+    #[tokio::test]
+    async fn checkpoint_round_trips_and_is_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("out.csv").to_string_lossy().to_string();
+        assert_eq!(load_checkpoint(&output).await.unwrap(), None);
+
+        let checkpoint = ExportCheckpoint {
+            query: "SELECT 1".to_string(),
+            rows_written: 2000,
+            bytes_written: 48_000,
+        };
+        save_checkpoint(&output, &checkpoint).await.unwrap();
+        assert_eq!(load_checkpoint(&output).await.unwrap(), Some(checkpoint));
+
+        remove_checkpoint(&output).await.unwrap();
+        assert!(!checkpoint_path(&output).exists());
+        remove_checkpoint(&output).await.unwrap();
+    }
+}
@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod checkpoint;
 pub mod pipeline;
 pub mod types;
 pub mod writers;
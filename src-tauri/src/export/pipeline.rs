@@ -9,11 +9,15 @@ use tokio::sync::RwLock;
 use tokio::time::{timeout, Duration};
 use tokio_util::sync::CancellationToken;
 
+use crate::engine::sql_generator::SqlDialect;
 use crate::engine::traits::{DataEngine, StreamEvent};
 use crate::engine::types::{ColumnInfo, QueryId, SessionId};
 use crate::engine::SessionManager;
+use crate::export::checkpoint::{
+    load_checkpoint, ordered_query, remove_checkpoint, save_checkpoint, ExportCheckpoint,
+};
 use crate::export::types::{ExportConfig, ExportFormat, ExportProgress, ExportState};
-use crate::export::writers::create_writer;
+use crate::export::writers::{create_resume_writer, create_writer, ExportWriter};
 
 pub struct ExportPipeline {
     jobs: RwLock<HashMap<String, ExportJob>>,
@@ -35,7 +39,7 @@ impl ExportPipeline {
         session_manager: Arc<SessionManager>,
         session_id: SessionId,
         export_id: String,
-        config: ExportConfig,
+        mut config: ExportConfig,
         window: tauri::Window,
     ) -> Result<String, String> {
         if config.query.trim().is_empty() {
@@ -65,6 +69,8 @@ impl ExportPipeline {
             return Err("Streaming is not supported by this driver".to_string());
         }
 
+        let checkpoint = prepare_checkpoint(driver.as_ref(), session_id, &mut config).await?;
+
         let cancel = CancellationToken::new();
 
         {
@@ -90,6 +96,7 @@ impl ExportPipeline {
                 driver_id,
                 session_id,
                 config,
+                checkpoint,
                 export_id_for_task.clone(),
                 cancel,
                 window,
@@ -132,17 +139,13 @@ async fn run_export_task(
     driver_id: String,
     session_id: SessionId,
     config: ExportConfig,
+    checkpoint: Option<ExportCheckpoint>,
     export_id: String,
     cancel: CancellationToken,
     window: tauri::Window,
 ) -> Result<(), String> {
     let start_time = Instant::now();
-    let mut last_emit = Instant::now();
-    let mut rows_exported: u64 = 0;
-    let mut columns: Vec<ColumnInfo> = Vec::new();
-    let mut state = ExportState::Running;
-    let mut error: Option<String> = None;
-    let mut cancel_requested = false;
+    let base_bytes = checkpoint.as_ref().map_or(0, |c| c.bytes_written);
 
     emit_progress(
         &window,
@@ -157,16 +160,7 @@ async fn run_export_task(
         },
     );
 
-    let mut writer = match create_writer(
-        config.format.clone(),
-        &config.output_path,
-        config.include_headers,
-        config.table_name.clone(),
-        config.namespace.clone(),
-        &driver_id,
-    )
-    .await
-    {
+    let mut writer = match open_writer(&config, checkpoint.as_ref(), &driver_id).await {
         Ok(writer) => writer,
         Err(err) => {
             emit_progress(
@@ -184,6 +178,189 @@ async fn run_export_task(
         }
     };
 
+    emit_progress(
+        &window,
+        build_progress(
+            &export_id,
+            ExportState::Running,
+            checkpoint.as_ref().map_or(0, |c| c.rows_written),
+            base_bytes + writer.bytes_written(),
+            start_time,
+            None,
+        ),
+    );
+
+    let outcome = stream_to_writer(
+        driver,
+        session_id,
+        &config,
+        checkpoint,
+        writer.as_mut(),
+        &cancel,
+        |rows_exported, bytes_written| {
+            emit_progress(
+                &window,
+                build_progress(
+                    &export_id,
+                    ExportState::Running,
+                    rows_exported,
+                    bytes_written,
+                    start_time,
+                    None,
+                ),
+            );
+        },
+    )
+    .await;
+
+    emit_progress(
+        &window,
+        build_progress(
+            &export_id,
+            outcome.state,
+            outcome.rows_exported,
+            base_bytes + writer.bytes_written(),
+            start_time,
+            outcome.error,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Orders a checkpointed export by the primary key of `config.source_table`
+/// and returns the checkpoint to start from: the saved one when resuming, an
+/// empty one otherwise. Exports without a source table, or whose table has no
+/// key, run without checkpoints; asking to resume one of those is an error.
+async fn prepare_checkpoint(
+    driver: &dyn DataEngine,
+    session_id: SessionId,
+    config: &mut ExportConfig,
+) -> Result<Option<ExportCheckpoint>, String> {
+    let resume = config.resume;
+    let unresumable = |reason: &str| {
+        if resume {
+            Err(reason.to_string())
+        } else {
+            Ok(None)
+        }
+    };
+
+    let Some(table) = config
+        .source_table
+        .clone()
+        .filter(|name| !name.trim().is_empty())
+    else {
+        return unresumable("Resume requires the source table of the export");
+    };
+    if !config.format.supports_resume() {
+        return unresumable("Resume is only supported for CSV, JSON Lines and SQL INSERT exports");
+    }
+    let Some(dialect) = SqlDialect::from_driver_id(driver.driver_id()) else {
+        return unresumable("Resumable export is not supported for this driver");
+    };
+    let Some(namespace) = config.namespace.clone() else {
+        return unresumable("Resume requires the namespace of the source table");
+    };
+
+    let schema = driver
+        .describe_table(session_id, &namespace, &table)
+        .await
+        .map_err(|e| e.to_string())?;
+    let Some(key) = schema.primary_key.filter(|key| !key.is_empty()) else {
+        return unresumable("Resume requires a table with a primary key to order rows by");
+    };
+    config.query = ordered_query(dialect, &config.query, &key);
+
+    if !config.resume {
+        remove_checkpoint(&config.output_path).await?;
+        return Ok(Some(ExportCheckpoint {
+            query: config.query.clone(),
+            rows_written: 0,
+            bytes_written: 0,
+        }));
+    }
+
+    let checkpoint = load_checkpoint(&config.output_path)
+        .await?
+        .ok_or_else(|| "No checkpoint found for this export".to_string())?;
+    if checkpoint.query != config.query {
+        return Err("Checkpoint was written by a different query".to_string());
+    }
+    Ok(Some(checkpoint))
+}
+
+/// Creates the output file, or reopens it at the checkpoint when resuming.
+async fn open_writer(
+    config: &ExportConfig,
+    checkpoint: Option<&ExportCheckpoint>,
+    driver_id: &str,
+) -> Result<Box<dyn ExportWriter>, String> {
+    match checkpoint.filter(|_| config.resume) {
+        Some(checkpoint) => {
+            create_resume_writer(
+                config.format.clone(),
+                &config.output_path,
+                checkpoint.bytes_written,
+                &config.csv_options,
+                &config.sql_options,
+                config.table_name.clone(),
+                config.namespace.clone(),
+                driver_id,
+            )
+            .await
+        }
+        None => {
+            create_writer(
+                config.format.clone(),
+                &config.output_path,
+                config.include_headers,
+                config.pretty_json,
+                &config.csv_options,
+                &config.sql_options,
+                config.table_name.clone(),
+                config.namespace.clone(),
+                driver_id,
+            )
+            .await
+        }
+    }
+}
+
+/// Final state of [`stream_to_writer`].
+pub(crate) struct StreamOutcome {
+    pub state: ExportState,
+    pub rows_exported: u64,
+    pub error: Option<String>,
+}
+
+/// Runs `config.query` through `execute_stream_in_namespace` and feeds every
+/// row into `writer` as it arrives. Only the bounded event channel holds rows,
+/// so memory stays flat whatever the result size. `on_progress` receives
+/// `(rows_exported, bytes_written)` at most every 250 ms. The writer is
+/// flushed and finished before returning.
+///
+/// With a `checkpoint`, the rows it already covers are skipped and the
+/// checkpoint file is updated after every flushed batch, then removed once
+/// the export completes.
+pub(crate) async fn stream_to_writer(
+    driver: Arc<dyn DataEngine>,
+    session_id: SessionId,
+    config: &ExportConfig,
+    mut checkpoint: Option<ExportCheckpoint>,
+    writer: &mut dyn ExportWriter,
+    cancel: &CancellationToken,
+    mut on_progress: impl FnMut(u64, u64),
+) -> StreamOutcome {
+    let mut last_emit = Instant::now();
+    let base_bytes = checkpoint.as_ref().map_or(0, |c| c.bytes_written);
+    let mut rows_to_skip = checkpoint.as_ref().map_or(0, |c| c.rows_written);
+    let mut rows_exported: u64 = rows_to_skip;
+    let mut columns: Vec<ColumnInfo> = Vec::new();
+    let mut state = ExportState::Running;
+    let mut error: Option<String> = None;
+    let mut cancel_requested = false;
+
     let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
     let query = config.query.clone();
     let namespace = config.namespace.clone();
@@ -198,22 +375,10 @@ async fn run_export_task(
         }
     });
 
-    emit_progress(
-        &window,
-        build_progress(
-            &export_id,
-            ExportState::Running,
-            0,
-            writer.bytes_written(),
-            start_time,
-            None,
-        ),
-    );
-
     let batch_size = config.batch_size.unwrap_or(1000).max(1) as u64;
     let limit = config.limit;
 
-    loop {
+    'stream: loop {
         tokio::select! {
             _ = cancel.cancelled() => {
                 let _ = driver.cancel(session_id, Some(query_id)).await;
@@ -221,7 +386,7 @@ async fn run_export_task(
                 break;
             }
             event = receiver.recv() => {
-                match event {
+                let batch = match event {
                     Some(StreamEvent::Columns(cols)) => {
                         columns = cols;
                         if let Err(err) = writer.write_header(&columns).await {
@@ -229,100 +394,16 @@ async fn run_export_task(
                             error = Some(err);
                             break;
                         }
+                        continue;
                     }
-                    Some(StreamEvent::Row(row)) => {
-                        if let Err(err) = writer.write_row(&columns, &row).await {
-                            state = ExportState::Failed;
-                            error = Some(err);
-                            break;
-                        }
-                        rows_exported += 1;
-
-                        if rows_exported.is_multiple_of(batch_size) {
-                            if let Err(err) = writer.flush().await {
-                                state = ExportState::Failed;
-                                error = Some(err);
-                                break;
-                            }
-                        }
-
-                        if let Some(limit) = limit {
-                            if rows_exported >= limit {
-                                let _ = driver.cancel(session_id, Some(query_id)).await;
-                                cancel_requested = true;
-                                state = ExportState::Completed;
-                                break;
-                            }
-                        }
-
-                        if last_emit.elapsed() >= Duration::from_millis(250) {
-                            emit_progress(
-                                &window,
-                                build_progress(
-                                    &export_id,
-                                    ExportState::Running,
-                                    rows_exported,
-                                    writer.bytes_written(),
-                                    start_time,
-                                    None,
-                                ),
-                            );
-                            last_emit = Instant::now();
-                        }
-                    }
-                    Some(StreamEvent::RowBatch(batch)) => {
-                        let mut stop = false;
-                        for row in batch {
-                            if let Err(err) = writer.write_row(&columns, &row).await {
-                                state = ExportState::Failed;
-                                error = Some(err);
-                                stop = true;
-                                break;
-                            }
-                            rows_exported += 1;
-
-                            if rows_exported.is_multiple_of(batch_size) {
-                                if let Err(err) = writer.flush().await {
-                                    state = ExportState::Failed;
-                                    error = Some(err);
-                                    stop = true;
-                                    break;
-                                }
-                            }
-
-                            if let Some(limit) = limit {
-                                if rows_exported >= limit {
-                                    let _ = driver.cancel(session_id, Some(query_id)).await;
-                                    cancel_requested = true;
-                                    state = ExportState::Completed;
-                                    stop = true;
-                                    break;
-                                }
-                            }
-                        }
-                        if stop {
-                            break;
-                        }
-                        if last_emit.elapsed() >= Duration::from_millis(250) {
-                            emit_progress(
-                                &window,
-                                build_progress(
-                                    &export_id,
-                                    ExportState::Running,
-                                    rows_exported,
-                                    writer.bytes_written(),
-                                    start_time,
-                                    None,
-                                ),
-                            );
-                            last_emit = Instant::now();
-                        }
-                    }
+                    Some(StreamEvent::Row(row)) => vec![row],
+                    Some(StreamEvent::RowBatch(batch)) => batch,
                     Some(StreamEvent::Error(err)) => {
                         state = ExportState::Failed;
                         error = Some(err);
                         break;
                     }
+                    Some(StreamEvent::Truncated { .. }) => continue,
                     Some(StreamEvent::Done(_)) => {
                         state = ExportState::Completed;
                         break;
@@ -330,6 +411,51 @@ async fn run_export_task(
                     None => {
                         break;
                     }
+                };
+
+                for row in batch {
+                    if rows_to_skip > 0 {
+                        rows_to_skip -= 1;
+                        continue;
+                    }
+                    if let Err(err) = writer.write_row(&columns, &row).await {
+                        state = ExportState::Failed;
+                        error = Some(err);
+                        break 'stream;
+                    }
+                    rows_exported += 1;
+
+                    if rows_exported.is_multiple_of(batch_size) {
+                        let committed = match writer.flush().await {
+                            Ok(()) => {
+                                record_checkpoint(
+                                    &config.output_path,
+                                    checkpoint.as_mut(),
+                                    rows_exported,
+                                    base_bytes + writer.bytes_written(),
+                                )
+                                .await
+                            }
+                            Err(err) => Err(err),
+                        };
+                        if let Err(err) = committed {
+                            state = ExportState::Failed;
+                            error = Some(err);
+                            break 'stream;
+                        }
+                    }
+
+                    if limit.is_some_and(|limit| rows_exported >= limit) {
+                        let _ = driver.cancel(session_id, Some(query_id)).await;
+                        cancel_requested = true;
+                        state = ExportState::Completed;
+                        break 'stream;
+                    }
+                }
+
+                if last_emit.elapsed() >= Duration::from_millis(250) {
+                    on_progress(rows_exported, base_bytes + writer.bytes_written());
+                    last_emit = Instant::now();
                 }
             }
         }
@@ -356,10 +482,24 @@ async fn run_export_task(
         }
     }
 
-    if let Err(err) = writer.flush().await {
-        if error.is_none() {
-            state = ExportState::Failed;
-            error = Some(err);
+    match writer.flush().await {
+        // Whatever reached the file is a valid resume point, even when the
+        // export stopped early.
+        Ok(()) if state != ExportState::Completed => {
+            let _ = record_checkpoint(
+                &config.output_path,
+                checkpoint.as_mut(),
+                rows_exported,
+                base_bytes + writer.bytes_written(),
+            )
+            .await;
+        }
+        Ok(()) => {}
+        Err(err) => {
+            if error.is_none() {
+                state = ExportState::Failed;
+                error = Some(err);
+            }
         }
     }
 
@@ -370,19 +510,31 @@ async fn run_export_task(
         }
     }
 
-    emit_progress(
-        &window,
-        build_progress(
-            &export_id,
-            state,
-            rows_exported,
-            writer.bytes_written(),
-            start_time,
-            error,
-        ),
-    );
+    if checkpoint.is_some() && state == ExportState::Completed {
+        if let Err(err) = remove_checkpoint(&config.output_path).await {
+            tracing::warn!("{}", err);
+        }
+    }
 
-    Ok(())
+    StreamOutcome {
+        state,
+        rows_exported,
+        error,
+    }
+}
+
+async fn record_checkpoint(
+    output_path: &str,
+    checkpoint: Option<&mut ExportCheckpoint>,
+    rows_written: u64,
+    bytes_written: u64,
+) -> Result<(), String> {
+    let Some(checkpoint) = checkpoint else {
+        return Ok(());
+    };
+    checkpoint.rows_written = rows_written;
+    checkpoint.bytes_written = bytes_written;
+    save_checkpoint(output_path, checkpoint).await
 }
 
 fn build_progress(
@@ -416,7 +568,7 @@ fn emit_progress(window: &tauri::Window, progress: ExportProgress) {
 }
 
 /// Validate that an export output path is safe (absolute, no traversal, parent exists).
-fn validate_output_path(path: &str) -> Result<(), String> {
+pub(crate) fn validate_output_path(path: &str) -> Result<(), String> {
     let path = std::path::Path::new(path);
 
     if !path.is_absolute() {
@@ -444,6 +596,10 @@ fn validate_output_path(path: &str) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::drivers::sqlite::SqliteDriver;
+    use crate::engine::types::{ConnectionConfig, Namespace};
+    use crate::export::checkpoint::checkpoint_path;
+    use crate::export::types::{CsvOptions, SqlInsertOptions};
 
     #[test]
     fn rejects_relative_export_path() {
@@ -459,4 +615,314 @@ mod tests {
     fn accepts_valid_absolute_path() {
         assert!(validate_output_path("/tmp/export.csv").is_ok());
     }
+
+    struct FlushTracking {
+        inner: Box<dyn ExportWriter>,
+        flushed_bytes: Vec<u64>,
+    }
+
+    #[async_trait::async_trait]
+    impl ExportWriter for FlushTracking {
+        async fn write_header(&mut self, columns: &[ColumnInfo]) -> Result<(), String> {
+            self.inner.write_header(columns).await
+        }
+
+        async fn write_row(
+            &mut self,
+            columns: &[ColumnInfo],
+            row: &crate::engine::types::Row,
+        ) -> Result<(), String> {
+            self.inner.write_row(columns, row).await
+        }
+
+        async fn flush(&mut self) -> Result<(), String> {
+            self.inner.flush().await?;
+            self.flushed_bytes.push(self.inner.bytes_written());
+            Ok(())
+        }
+
+        async fn finish(&mut self) -> Result<(), String> {
+            self.inner.finish().await
+        }
+
+        fn bytes_written(&self) -> u64 {
+            self.inner.bytes_written()
+        }
+    }
+
+    async fn sqlite_session() -> (Arc<dyn DataEngine>, SessionId) {
+        let driver: Arc<dyn DataEngine> = Arc::new(SqliteDriver::new());
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        (driver, session)
+    }
+
+    fn csv_config(query: &str, output_path: &str) -> ExportConfig {
+        ExportConfig {
+            query: query.to_string(),
+            namespace: None,
+            output_path: output_path.to_string(),
+            format: ExportFormat::Csv,
+            table_name: None,
+            include_headers: true,
+            pretty_json: false,
+            csv_options: CsvOptions::default(),
+            sql_options: SqlInsertOptions::default(),
+            batch_size: Some(1000),
+            limit: None,
+            source_table: None,
+            resume: false,
+        }
+    }
+
+    const SEQUENCE_QUERY: &str = "WITH RECURSIVE seq(n) AS \
+        (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 100000) \
+        SELECT n, 'row-' || n AS label FROM seq";
+
+    #[tokio::test]
+    async fn streams_large_sqlite_result_to_csv() {
+        let (driver, session) = sqlite_session().await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("seq.csv").to_string_lossy().to_string();
+        let config = csv_config(SEQUENCE_QUERY, &path);
+
+        let inner = create_writer(
+            ExportFormat::Csv,
+            &path,
+            true,
+            false,
+            &CsvOptions::default(),
+            &SqlInsertOptions::default(),
+            None,
+            None,
+            "sqlite",
+        )
+        .await
+        .unwrap();
+        let mut writer = FlushTracking {
+            inner,
+            flushed_bytes: Vec::new(),
+        };
+        let outcome = stream_to_writer(
+            driver,
+            session,
+            &config,
+            None,
+            &mut writer,
+            &CancellationToken::new(),
+            |_, _| {},
+        )
+        .await;
+
+        assert_eq!(outcome.state, ExportState::Completed);
+        assert!(outcome.error.is_none());
+        assert_eq!(outcome.rows_exported, 100_000);
+
+        // Rows reach the file batch by batch while the query is still
+        // producing them, instead of all at once at the end.
+        assert!(writer.flushed_bytes.len() >= 100);
+        assert!(writer.flushed_bytes.windows(2).all(|w| w[0] <= w[1]));
+        assert!(writer.flushed_bytes[0] < writer.bytes_written() / 50);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 100_001);
+        assert_eq!(content.lines().last(), Some("100000,row-100000"));
+        assert_eq!(content.len() as u64, writer.bytes_written());
+    }
+
+    #[tokio::test]
+    async fn cancelled_stream_reports_cancelled() {
+        let (driver, session) = sqlite_session().await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("seq.csv").to_string_lossy().to_string();
+        let config = csv_config(SEQUENCE_QUERY, &path);
+
+        let mut writer = create_writer(
+            ExportFormat::Csv,
+            &path,
+            true,
+            false,
+            &CsvOptions::default(),
+            &SqlInsertOptions::default(),
+            None,
+            None,
+            "sqlite",
+        )
+        .await
+        .unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let outcome = stream_to_writer(
+            driver,
+            session,
+            &config,
+            None,
+            writer.as_mut(),
+            &cancel,
+            |_, _| {},
+        )
+        .await;
+
+        assert_eq!(outcome.state, ExportState::Cancelled);
+        assert!(outcome.rows_exported < 100_000);
+    }
+
+    /// Fails every call once `rows_left` rows went through, like a process
+    /// that dies mid-batch: the rows buffered since the last flush never
+    /// reach a checkpoint.
+    struct Crashing {
+        inner: Box<dyn ExportWriter>,
+        rows_left: u64,
+    }
+
+    #[async_trait::async_trait]
+    impl ExportWriter for Crashing {
+        async fn write_header(&mut self, columns: &[ColumnInfo]) -> Result<(), String> {
+            self.inner.write_header(columns).await
+        }
+
+        async fn write_row(
+            &mut self,
+            columns: &[ColumnInfo],
+            row: &crate::engine::types::Row,
+        ) -> Result<(), String> {
+            if self.rows_left == 0 {
+                return Err("simulated crash".to_string());
+            }
+            self.rows_left -= 1;
+            self.inner.write_row(columns, row).await
+        }
+
+        async fn flush(&mut self) -> Result<(), String> {
+            if self.rows_left == 0 {
+                return Err("simulated crash".to_string());
+            }
+            self.inner.flush().await
+        }
+
+        async fn finish(&mut self) -> Result<(), String> {
+            self.flush().await
+        }
+
+        fn bytes_written(&self) -> u64 {
+            self.inner.bytes_written()
+        }
+    }
+
+    async fn seed_items(driver: &Arc<dyn DataEngine>, session: SessionId) {
+        for sql in [
+            "CREATE TABLE items (id INTEGER PRIMARY KEY, label TEXT)",
+            "WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 5000) \
+             INSERT INTO items SELECT n, 'item-' || n FROM seq",
+            "CREATE TABLE logs (message TEXT)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+    }
+
+    fn items_config(output_path: &str) -> ExportConfig {
+        ExportConfig {
+            namespace: Some(Namespace::new("main")),
+            source_table: Some("items".to_string()),
+            ..csv_config("SELECT id, label FROM items", output_path)
+        }
+    }
+
+    #[tokio::test]
+    async fn resumes_interrupted_export_from_checkpoint() {
+        let (driver, session) = sqlite_session().await;
+        seed_items(&driver, session).await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("items.csv").to_string_lossy().to_string();
+
+        let mut config = items_config(&path);
+        let checkpoint = prepare_checkpoint(driver.as_ref(), session, &mut config)
+            .await
+            .unwrap();
+        assert!(config.query.ends_with(r#"ORDER BY "id""#));
+        let mut writer = Crashing {
+            inner: open_writer(&config, checkpoint.as_ref(), "sqlite")
+                .await
+                .unwrap(),
+            rows_left: 2500,
+        };
+        let outcome = stream_to_writer(
+            Arc::clone(&driver),
+            session,
+            &config,
+            checkpoint,
+            &mut writer,
+            &CancellationToken::new(),
+            |_, _| {},
+        )
+        .await;
+        assert_eq!(outcome.state, ExportState::Failed);
+
+        let saved = load_checkpoint(&path).await.unwrap().unwrap();
+        assert_eq!(saved.rows_written, 2000);
+        // A torn write past the checkpoint must not survive the resume.
+        {
+            use std::io::Write;
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .unwrap();
+            file.write_all(b"2001,ite").unwrap();
+        }
+
+        let mut config = ExportConfig {
+            resume: true,
+            ..items_config(&path)
+        };
+        let checkpoint = prepare_checkpoint(driver.as_ref(), session, &mut config)
+            .await
+            .unwrap();
+        let mut writer = open_writer(&config, checkpoint.as_ref(), "sqlite")
+            .await
+            .unwrap();
+        let outcome = stream_to_writer(
+            driver,
+            session,
+            &config,
+            checkpoint,
+            writer.as_mut(),
+            &CancellationToken::new(),
+            |_, _| {},
+        )
+        .await;
+        assert_eq!(outcome.state, ExportState::Completed);
+        assert!(outcome.error.is_none());
+        assert_eq!(outcome.rows_exported, 5000);
+
+        let expected: String = std::iter::once("id,label\n".to_string())
+            .chain((1..=5000).map(|n| format!("{n},item-{n}\n")))
+            .collect();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+        assert!(!checkpoint_path(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn rejects_resume_without_primary_key() {
+        let (driver, session) = sqlite_session().await;
+        seed_items(&driver, session).await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs.csv").to_string_lossy().to_string();
+
+        let mut config = ExportConfig {
+            source_table: Some("logs".to_string()),
+            resume: true,
+            ..items_config(&path)
+        };
+        let err = prepare_checkpoint(driver.as_ref(), session, &mut config)
+            .await
+            .unwrap_err();
+        assert!(err.contains("primary key"), "{err}");
+
+        // Without `resume` the same export simply runs without checkpoints.
+        config.resume = false;
+        let checkpoint = prepare_checkpoint(driver.as_ref(), session, &mut config)
+            .await
+            .unwrap();
+        assert!(checkpoint.is_none());
+    }
 }
@@ -9,12 +9,101 @@ use crate::engine::types::Namespace;
 pub enum ExportFormat {
     Csv,
     Json,
+    /// Newline-delimited JSON, one object per row
+    #[serde(rename = "jsonl")]
+    JsonLines,
     SqlInsert,
     Html,
     Xlsx,
     Parquet,
 }
 
+impl ExportFormat {
+    /// Whether an interrupted export can be continued by appending rows.
+    /// Formats with a header or closing trailer (JSON, HTML, XLSX, Parquet)
+    /// have to be written in one go.
+    pub fn supports_resume(&self) -> bool {
+        matches!(self, Self::Csv | Self::JsonLines | Self::SqlInsert)
+    }
+}
+
+/// When CSV fields are wrapped in quotes
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvQuoteStyle {
+    Always,
+    /// Only fields containing the delimiter, a quote or a line break
+    #[default]
+    Necessary,
+    /// Never quote; fields containing special characters produce invalid CSV
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvLineEnding {
+    #[default]
+    Lf,
+    Crlf,
+}
+
+/// CSV dialect. The default is comma separated, minimally quoted, LF
+/// terminated, without a byte order mark.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub quote_style: CsvQuoteStyle,
+    pub line_ending: CsvLineEnding,
+    /// Start the file with the UTF-8 byte order mark, which Excel needs to
+    /// detect the encoding
+    pub utf8_bom: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote_style: CsvQuoteStyle::Necessary,
+            line_ending: CsvLineEnding::Lf,
+            utf8_bom: false,
+        }
+    }
+}
+
+/// SQL INSERT exports group up to `batch_size` rows into one multi-row
+/// `INSERT` statement; 1 writes a statement per row.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SqlInsertOptions {
+    pub batch_size: u32,
+}
+
+impl Default for SqlInsertOptions {
+    fn default() -> Self {
+        Self { batch_size: 100 }
+    }
+}
+
+impl CsvOptions {
+    /// The delimiter as the single byte the CSV writer needs.
+    pub fn delimiter_byte(&self) -> Result<u8, String> {
+        let delimiter = self.delimiter;
+        if !delimiter.is_ascii() {
+            return Err(format!(
+                "CSV delimiter must be a single ASCII character, got '{delimiter}'"
+            ));
+        }
+        if matches!(delimiter, '"' | '\n' | '\r') {
+            return Err(format!(
+                "CSV delimiter cannot be {}",
+                delimiter.escape_default()
+            ));
+        }
+        Ok(delimiter as u8)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportConfig {
     pub query: String,
@@ -23,8 +112,24 @@ pub struct ExportConfig {
     pub format: ExportFormat,
     pub table_name: Option<String>,
     pub include_headers: bool,
+    /// Indent JSON exports; compact (one element per line) by default
+    #[serde(default)]
+    pub pretty_json: bool,
+    /// Delimiter, quoting and line ending of CSV exports
+    #[serde(default)]
+    pub csv_options: CsvOptions,
+    /// Rows per statement of SQL INSERT exports
+    #[serde(default)]
+    pub sql_options: SqlInsertOptions,
     pub batch_size: Option<u32>,
     pub limit: Option<u64>,
+    /// Table whose primary key orders the rows. When set, the export writes a
+    /// checkpoint after every batch and can be resumed after an interruption.
+    #[serde(default)]
+    pub source_table: Option<String>,
+    /// Continue from the checkpoint next to `output_path` instead of starting over
+    #[serde(default)]
+    pub resume: bool,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -53,6 +158,14 @@ pub struct ExportStartResponse {
     pub export_id: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CopyExportResponse {
+    pub success: bool,
+    pub path: String,
+    pub bytes_written: u64,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ExportCancelResponse {
     pub success: bool,
@@ -1,17 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use tokio::fs::File;
-use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 pub struct CountingWriter {
-    writer: BufWriter<File>,
+    writer: Box<dyn AsyncWrite + Send + Unpin>,
     bytes_written: u64,
 }
 
 impl CountingWriter {
-    pub fn new(writer: BufWriter<File>) -> Self {
+    /// Callers pass an already-buffered sink (file or stdout).
+    pub fn new(writer: impl AsyncWrite + Send + Unpin + 'static) -> Self {
         Self {
-            writer,
+            writer: Box::new(writer),
             bytes_written: 0,
         }
     }
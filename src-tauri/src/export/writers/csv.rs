@@ -1,38 +1,71 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use tokio::fs::File;
-use tokio::io::BufWriter;
+use csv::{QuoteStyle, Terminator, WriterBuilder};
+use tokio::io::AsyncWrite;
 
 use crate::engine::types::{ColumnInfo, Row, Value};
+use crate::export::types::{CsvLineEnding, CsvOptions, CsvQuoteStyle};
 use crate::export::writers::counting::CountingWriter;
 use crate::export::writers::ExportWriter;
 
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 pub struct CsvWriter {
     writer: CountingWriter,
+    /// Encodes one record at a time; the bytes then go through `writer`.
+    builder: WriterBuilder,
     include_headers: bool,
     header_written: bool,
+    /// Set until the byte order mark has been written
+    bom_pending: bool,
 }
 
 impl CsvWriter {
-    pub fn new(writer: BufWriter<File>, include_headers: bool) -> Self {
-        Self {
+    pub fn new(
+        writer: impl AsyncWrite + Send + Unpin + 'static,
+        include_headers: bool,
+        options: &CsvOptions,
+    ) -> Result<Self, String> {
+        let mut builder = WriterBuilder::new();
+        builder
+            .has_headers(false)
+            .delimiter(options.delimiter_byte()?)
+            .quote_style(match options.quote_style {
+                CsvQuoteStyle::Always => QuoteStyle::Always,
+                CsvQuoteStyle::Necessary => QuoteStyle::Necessary,
+                CsvQuoteStyle::Never => QuoteStyle::Never,
+            })
+            .terminator(match options.line_ending {
+                CsvLineEnding::Lf => Terminator::Any(b'\n'),
+                CsvLineEnding::Crlf => Terminator::CRLF,
+            });
+
+        Ok(Self {
             writer: CountingWriter::new(writer),
+            builder,
             include_headers,
             header_written: false,
-        }
+            bom_pending: options.utf8_bom,
+        })
+    }
+
+    fn encode_record<I, T>(&self, fields: I) -> Result<Vec<u8>, String>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let mut record = self.builder.from_writer(Vec::new());
+        record.write_record(fields).map_err(|e| e.to_string())?;
+        record.into_inner().map_err(|e| e.to_string())
     }
 
-    fn escape_csv(value: &str) -> String {
-        if value.contains(',')
-            || value.contains('"')
-            || value.contains('\n')
-            || value.contains('\r')
-        {
-            format!("\"{}\"", value.replace('"', "\"\""))
-        } else {
-            value.to_string()
+    async fn write_bom(&mut self) -> Result<(), String> {
+        if self.bom_pending {
+            self.writer.write_bytes(UTF8_BOM).await?;
+            self.bom_pending = false;
         }
+        Ok(())
     }
 
     fn format_value(value: &Value) -> String {
@@ -52,35 +85,27 @@ impl CsvWriter {
 #[async_trait::async_trait]
 impl ExportWriter for CsvWriter {
     async fn write_header(&mut self, columns: &[ColumnInfo]) -> Result<(), String> {
+        self.write_bom().await?;
         if !self.include_headers || self.header_written || columns.is_empty() {
             return Ok(());
         }
 
-        let header = columns
-            .iter()
-            .map(|col| Self::escape_csv(&col.name))
-            .collect::<Vec<_>>()
-            .join(",");
-
-        self.writer.write_line(&header).await?;
+        let header = self.encode_record(columns.iter().map(|col| col.name.as_str()))?;
+        self.writer.write_bytes(&header).await?;
         self.header_written = true;
         Ok(())
     }
 
     async fn write_row(&mut self, columns: &[ColumnInfo], row: &Row) -> Result<(), String> {
+        self.write_bom().await?;
         if columns.is_empty() {
             return Ok(());
         }
 
-        let mut fields = Vec::with_capacity(columns.len());
-        for idx in 0..columns.len() {
-            let value = row.values.get(idx).unwrap_or(&Value::Null);
-            let formatted = Self::format_value(value);
-            fields.push(Self::escape_csv(&formatted));
-        }
-
-        let line = fields.join(",");
-        self.writer.write_line(&line).await
+        let fields = (0..columns.len())
+            .map(|idx| Self::format_value(row.values.get(idx).unwrap_or(&Value::Null)));
+        let line = self.encode_record(fields)?;
+        self.writer.write_bytes(&line).await
     }
 
     async fn flush(&mut self) -> Result<(), String> {
@@ -88,6 +113,7 @@ impl ExportWriter for CsvWriter {
     }
 
     async fn finish(&mut self) -> Result<(), String> {
+        self.write_bom().await?;
         self.flush().await
     }
 
@@ -95,3 +121,157 @@ impl ExportWriter for CsvWriter {
         self.writer.bytes_written()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::fs::File;
+    use tokio::io::BufWriter;
+
+    fn columns() -> Vec<ColumnInfo> {
+        ["id", "name", "note"]
+            .iter()
+            .map(|name| ColumnInfo {
+                name: (*name).into(),
+                data_type: "text".into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect()
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                values: vec![
+                    Value::Int(1),
+                    Value::Text("Müller; Anna".into()),
+                    Value::Text("said \"hi\"".into()),
+                ],
+            },
+            Row {
+                values: vec![Value::Int(2), Value::Text("tab\there".into()), Value::Null],
+            },
+        ]
+    }
+
+    async fn export(options: &CsvOptions) -> String {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.csv");
+        let file = File::create(&path).await.unwrap();
+        let mut writer = CsvWriter::new(BufWriter::new(file), true, options).unwrap();
+        let columns = columns();
+        writer.write_header(&columns).await.unwrap();
+        for row in rows() {
+            writer.write_row(&columns, &row).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.len() as u64, writer.bytes_written());
+        content
+    }
+
+    fn parse(content: &str, delimiter: u8) -> Vec<Vec<String>> {
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .delimiter(delimiter)
+            .from_reader(content.as_bytes())
+            .records()
+            .map(|record| record.unwrap().iter().map(String::from).collect())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn default_options_write_comma_separated_lf() {
+        let content = export(&CsvOptions::default()).await;
+        assert_eq!(
+            content,
+            "id,name,note\n1,Müller; Anna,\"said \"\"hi\"\"\"\n2,tab\there,\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn semicolon_delimited_roundtrip() {
+        let options = CsvOptions {
+            delimiter: ';',
+            line_ending: CsvLineEnding::Crlf,
+            ..CsvOptions::default()
+        };
+        let content = export(&options).await;
+        assert!(content.starts_with("id;name;note\r\n1;\"Müller; Anna\";"));
+
+        let records = parse(&content, b';');
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], vec!["1", "Müller; Anna", "said \"hi\""]);
+        assert_eq!(records[2], vec!["2", "tab\there", ""]);
+    }
+
+    #[tokio::test]
+    async fn tab_separated_roundtrip() {
+        let options = CsvOptions {
+            delimiter: '\t',
+            quote_style: CsvQuoteStyle::Always,
+            ..CsvOptions::default()
+        };
+        let content = export(&options).await;
+        assert!(content.starts_with("\"id\"\t\"name\"\t\"note\"\n"));
+
+        let records = parse(&content, b'\t');
+        assert_eq!(records[0], vec!["id", "name", "note"]);
+        assert_eq!(records[2], vec!["2", "tab\there", ""]);
+    }
+
+    #[tokio::test]
+    async fn utf8_bom_precedes_header_when_enabled() {
+        let content = export(&CsvOptions::default()).await;
+        assert!(content.starts_with("id,"));
+
+        let options = CsvOptions {
+            utf8_bom: true,
+            ..CsvOptions::default()
+        };
+        let content = export(&options).await;
+        assert_eq!(&content.as_bytes()[..3], UTF8_BOM);
+        assert!(content[3..].starts_with("id,name,note\n"));
+    }
+
+    #[tokio::test]
+    async fn exact_decimal_keeps_every_digit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.csv");
+        let file = File::create(&path).await.unwrap();
+        let mut writer =
+            CsvWriter::new(BufWriter::new(file), false, &CsvOptions::default()).unwrap();
+        let columns = vec![ColumnInfo {
+            name: "amount".into(),
+            data_type: "Decimaln".into(),
+            nullable: true,
+            case_insensitive: false,
+        }];
+        let row = Row {
+            values: vec![Value::Text(
+                "-1234567890123456789012345678.0123456789".into(),
+            )],
+        };
+        writer.write_row(&columns, &row).await.unwrap();
+        writer.finish().await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "-1234567890123456789012345678.0123456789\n"
+        );
+    }
+
+    #[test]
+    fn rejects_multibyte_delimiter() {
+        let options = CsvOptions {
+            delimiter: '§',
+            ..CsvOptions::default()
+        };
+        let Err(err) = CsvWriter::new(tokio::io::sink(), true, &options) else {
+            panic!("multibyte delimiter must be rejected");
+        };
+        assert!(err.contains("single ASCII character"), "{err}");
+    }
+}
@@ -1,8 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use tokio::fs::File;
-use tokio::io::BufWriter;
+use tokio::io::AsyncWrite;
 
 use crate::engine::types::{ColumnInfo, Row, Value};
 use crate::export::writers::counting::CountingWriter;
@@ -15,7 +14,7 @@ pub struct HtmlWriter {
 }
 
 impl HtmlWriter {
-    pub fn new(writer: BufWriter<File>) -> Self {
+    pub fn new(writer: impl AsyncWrite + Send + Unpin + 'static) -> Self {
         Self {
             writer: CountingWriter::new(writer),
             header_written: false,
@@ -1,27 +1,65 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use tokio::fs::File;
-use tokio::io::BufWriter;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::value::RawValue;
+use tokio::io::AsyncWrite;
 
 use crate::engine::types::{ColumnInfo, Row, Value};
 use crate::export::writers::counting::CountingWriter;
-use crate::export::writers::ExportWriter;
+use crate::export::writers::{exact_decimal, ExportWriter};
 
+/// A cell of an exported row. Exact decimals are written as raw JSON numbers,
+/// keeping every digit a `serde_json::Number` (f64) would round away.
+pub(super) enum JsonCell {
+    Value(serde_json::Value),
+    Decimal(Box<RawValue>),
+}
+
+impl Serialize for JsonCell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            JsonCell::Value(value) => value.serialize(serializer),
+            JsonCell::Decimal(raw) => raw.serialize(serializer),
+        }
+    }
+}
+
+/// One row as an object keyed by column name, in column order. A repeated
+/// column name keeps the last value, like a JSON object would.
+pub(super) struct JsonRow(Vec<(String, JsonCell)>);
+
+impl Serialize for JsonRow {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, cell) in &self.0 {
+            map.serialize_entry(key, cell)?;
+        }
+        map.end()
+    }
+}
+
+/// Streams rows as the elements of a single top-level JSON array: `[` on the
+/// first write, one element per row, `]` in `finish`. Only the current row is
+/// held in memory.
 pub struct JsonWriter {
     writer: CountingWriter,
+    pretty: bool,
     started: bool,
     rows_written: u64,
     scratch: Vec<u8>,
+    indented: Vec<u8>,
 }
 
 impl JsonWriter {
-    pub fn new(writer: BufWriter<File>) -> Self {
+    pub fn new(writer: impl AsyncWrite + Send + Unpin + 'static, pretty: bool) -> Self {
         Self {
             writer: CountingWriter::new(writer),
+            pretty,
             started: false,
             rows_written: 0,
             scratch: Vec::with_capacity(1024),
+            indented: Vec::new(),
         }
     }
 
@@ -49,6 +87,27 @@ impl JsonWriter {
             }
         }
     }
+
+    fn cell(column: &ColumnInfo, value: &Value) -> JsonCell {
+        exact_decimal(column, value)
+            .and_then(|decimal| RawValue::from_string(decimal.to_string()).ok())
+            .map(JsonCell::Decimal)
+            .unwrap_or_else(|| JsonCell::Value(Self::value_to_json(value)))
+    }
+
+    /// One row as an object keyed by column name; shared with the JSONL writer.
+    pub(super) fn row_to_json(columns: &[ColumnInfo], row: &Row) -> JsonRow {
+        let mut entries: Vec<(String, JsonCell)> = Vec::with_capacity(columns.len());
+        for (idx, col) in columns.iter().enumerate() {
+            let value = row.values.get(idx).unwrap_or(&Value::Null);
+            let cell = Self::cell(col, value);
+            match entries.iter_mut().find(|(key, _)| key == col.name.as_str()) {
+                Some(entry) => entry.1 = cell,
+                None => entries.push((col.name.to_string(), cell)),
+            }
+        }
+        JsonRow(entries)
+    }
 }
 
 #[async_trait::async_trait]
@@ -60,21 +119,31 @@ impl ExportWriter for JsonWriter {
     async fn write_row(&mut self, columns: &[ColumnInfo], row: &Row) -> Result<(), String> {
         self.ensure_started().await?;
 
-        let mut obj = serde_json::Map::with_capacity(columns.len());
-        for (idx, col) in columns.iter().enumerate() {
-            let value = row.values.get(idx).unwrap_or(&Value::Null);
-            obj.insert(col.name.to_string(), Self::value_to_json(value));
-        }
-
-        let json = serde_json::Value::Object(obj);
+        let json = Self::row_to_json(columns, row);
 
         if self.rows_written > 0 {
             self.writer.write_bytes(b",\n").await?;
         }
 
         self.scratch.clear();
-        serde_json::to_writer(&mut self.scratch, &json).map_err(|e| e.to_string())?;
-        self.writer.write_bytes(&self.scratch).await?;
+        if self.pretty {
+            serde_json::to_writer_pretty(&mut self.scratch, &json).map_err(|e| e.to_string())?;
+            // Indent the element one level inside the array. Serialized JSON
+            // never contains a raw newline inside a string, so splitting on
+            // lines is safe.
+            self.indented.clear();
+            for line in self.scratch.split(|b| *b == b'\n') {
+                if !self.indented.is_empty() {
+                    self.indented.push(b'\n');
+                }
+                self.indented.extend_from_slice(b"  ");
+                self.indented.extend_from_slice(line);
+            }
+            self.writer.write_bytes(&self.indented).await?;
+        } else {
+            serde_json::to_writer(&mut self.scratch, &json).map_err(|e| e.to_string())?;
+            self.writer.write_bytes(&self.scratch).await?;
+        }
 
         self.rows_written += 1;
         Ok(())
@@ -101,3 +170,109 @@ impl ExportWriter for JsonWriter {
         self.writer.bytes_written()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::fs::File;
+    use tokio::io::BufWriter;
+
+    fn columns() -> Vec<ColumnInfo> {
+        ["id", "name", "tags"]
+            .iter()
+            .map(|name| ColumnInfo {
+                name: (*name).into(),
+                data_type: "text".into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect()
+    }
+
+    fn row(i: i64) -> Row {
+        Row {
+            values: vec![
+                Value::Int(i),
+                Value::Text(format!("line\n{}, \"quoted\"", i)),
+                Value::Array(vec![Value::Null, Value::Bool(true)]),
+            ],
+        }
+    }
+
+    async fn export(rows: usize, pretty: bool, call_header: bool) -> serde_json::Value {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.json");
+        let file = File::create(&path).await.unwrap();
+        let mut writer = JsonWriter::new(BufWriter::new(file), pretty);
+
+        let columns = columns();
+        if call_header {
+            writer.write_header(&columns).await.unwrap();
+        }
+        for i in 0..rows {
+            writer.write_row(&columns, &row(i as i64)).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(writer.bytes_written(), content.len() as u64);
+        serde_json::from_str(&content).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_zero_rows_is_empty_array() {
+        for (pretty, call_header) in [(false, false), (false, true), (true, true)] {
+            let json = export(0, pretty, call_header).await;
+            assert_eq!(json, serde_json::json!([]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_one_row() {
+        for pretty in [false, true] {
+            let json = export(1, pretty, true).await;
+            let items = json.as_array().unwrap();
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0]["id"], 0);
+            assert_eq!(items[0]["name"], "line\n0, \"quoted\"");
+            assert_eq!(items[0]["tags"], serde_json::json!([null, true]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exact_decimal_is_a_number() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.json");
+        let file = File::create(&path).await.unwrap();
+        let mut writer = JsonWriter::new(BufWriter::new(file), false);
+
+        let columns = vec![ColumnInfo {
+            name: "amount".into(),
+            data_type: "Decimaln".into(),
+            nullable: true,
+            case_insensitive: false,
+        }];
+        let row = Row {
+            values: vec![Value::Text(
+                "1234567890123456789012345678.0123456789".into(),
+            )],
+        };
+        writer.write_row(&columns, &row).await.unwrap();
+        writer.finish().await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "[\n{\"amount\":1234567890123456789012345678.0123456789}\n]\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_many_rows() {
+        for pretty in [false, true] {
+            let json = export(2_500, pretty, true).await;
+            let items = json.as_array().unwrap();
+            assert_eq!(items.len(), 2_500);
+            assert_eq!(items[2_499]["id"], 2_499);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use tokio::io::AsyncWrite;
+
+use crate::engine::types::{ColumnInfo, Row};
+use crate::export::writers::counting::CountingWriter;
+use crate::export::writers::json::JsonWriter;
+use crate::export::writers::ExportWriter;
+
+/// Streams rows as newline-delimited JSON (JSONL): one object per line, no
+/// enclosing array, so consumers can process the file line by line. Values
+/// are encoded like [`JsonWriter`] (bytes as base64).
+pub struct JsonLinesWriter {
+    writer: CountingWriter,
+    scratch: Vec<u8>,
+}
+
+impl JsonLinesWriter {
+    pub fn new(writer: impl AsyncWrite + Send + Unpin + 'static) -> Self {
+        Self {
+            writer: CountingWriter::new(writer),
+            scratch: Vec::with_capacity(1024),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExportWriter for JsonLinesWriter {
+    async fn write_header(&mut self, _columns: &[ColumnInfo]) -> Result<(), String> {
+        Ok(())
+    }
+
+    async fn write_row(&mut self, columns: &[ColumnInfo], row: &Row) -> Result<(), String> {
+        let json = JsonWriter::row_to_json(columns, row);
+
+        self.scratch.clear();
+        serde_json::to_writer(&mut self.scratch, &json).map_err(|e| e.to_string())?;
+        self.scratch.push(b'\n');
+        self.writer.write_bytes(&self.scratch).await
+    }
+
+    async fn flush(&mut self) -> Result<(), String> {
+        self.writer.flush().await
+    }
+
+    async fn finish(&mut self) -> Result<(), String> {
+        self.flush().await
+    }
+
+    fn bytes_written(&self) -> u64 {
+        self.writer.bytes_written()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::types::Value;
+    use tokio::fs::File;
+    use tokio::io::BufWriter;
+
+    fn columns() -> Vec<ColumnInfo> {
+        ["id", "note", "blob"]
+            .iter()
+            .map(|name| ColumnInfo {
+                name: (*name).into(),
+                data_type: "text".into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect()
+    }
+
+    async fn export(rows: Vec<Row>) -> String {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.jsonl");
+        let file = File::create(&path).await.unwrap();
+        let mut writer = JsonLinesWriter::new(BufWriter::new(file));
+
+        let columns = columns();
+        writer.write_header(&columns).await.unwrap();
+        for row in &rows {
+            writer.write_row(&columns, row).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(writer.bytes_written(), content.len() as u64);
+        content
+    }
+
+    #[tokio::test]
+    async fn test_empty_result_is_empty_file() {
+        assert_eq!(export(Vec::new()).await, "");
+    }
+
+    #[tokio::test]
+    async fn test_one_object_per_line() {
+        let rows = vec![
+            Row {
+                values: vec![
+                    Value::Int(1),
+                    Value::Text("line\nbreak".into()),
+                    Value::Bytes(vec![0xde, 0xad]),
+                ],
+            },
+            Row {
+                values: vec![Value::Int(2), Value::Null],
+            },
+        ];
+        let content = export(rows).await;
+        assert!(content.ends_with('\n'));
+
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({ "id": 1, "note": "line\nbreak", "blob": "3q0=" }),
+                serde_json::json!({ "id": 2, "note": null, "blob": null }),
+            ]
+        );
+    }
+}
@@ -1,17 +1,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
-use tokio::fs::File;
-use tokio::io::BufWriter;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWrite, BufWriter};
 
 use crate::engine::sql_generator::SqlDialect;
-use crate::engine::types::{ColumnInfo, Namespace, Row};
-use crate::export::types::ExportFormat;
+use crate::engine::types::{ColumnInfo, Namespace, Row, Value};
+use crate::export::types::{CsvOptions, ExportFormat, SqlInsertOptions};
 
 mod counting;
 pub mod csv;
 pub mod html;
 pub mod json;
+pub mod jsonl;
 pub mod sql;
 
 #[cfg(feature = "pro")]
@@ -28,46 +29,47 @@ pub trait ExportWriter: Send {
     fn bytes_written(&self) -> u64;
 }
 
+/// The digits of an exact decimal that a driver returned as text (SQL Server
+/// `DECIMAL`, for instance, to avoid an f64 round-trip), so writers can emit
+/// it as a number. Only plain `-123.45` literals qualify.
+pub(crate) fn exact_decimal<'a>(column: &ColumnInfo, value: &'a Value) -> Option<&'a str> {
+    let Value::Text(text) = value else {
+        return None;
+    };
+    let data_type = column.data_type.to_ascii_lowercase();
+    if !["decimal", "numeric", "money"]
+        .iter()
+        .any(|name| data_type.contains(name))
+    {
+        return None;
+    }
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, "0"));
+    let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    (is_digits(int_part) && is_digits(frac_part)).then_some(text.as_str())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn create_writer(
     format: ExportFormat,
     output_path: &str,
     include_headers: bool,
+    pretty_json: bool,
+    csv_options: &CsvOptions,
+    sql_options: &SqlInsertOptions,
     table_name: Option<String>,
     namespace: Option<Namespace>,
     driver_id: &str,
 ) -> Result<Box<dyn ExportWriter>, String> {
-    let file = File::create(output_path)
-        .await
-        .map_err(|e| format!("Failed to create export file: {}", e))?;
-    let writer = BufWriter::new(file);
-
     match format {
-        ExportFormat::Csv => {
-            Ok(Box::new(csv::CsvWriter::new(writer, include_headers)) as Box<dyn ExportWriter>)
-        }
-        ExportFormat::Json => Ok(Box::new(json::JsonWriter::new(writer)) as Box<dyn ExportWriter>),
-        ExportFormat::Html => Ok(Box::new(html::HtmlWriter::new(writer)) as Box<dyn ExportWriter>),
-        ExportFormat::SqlInsert => {
-            let table = table_name
-                .filter(|name| !name.trim().is_empty())
-                .ok_or_else(|| "Table name is required for SQL INSERT export".to_string())?;
-            let dialect = SqlDialect::from_driver_id(driver_id)
-                .ok_or_else(|| "SQL INSERT export is not supported for this driver".to_string())?;
-            Ok(
-                Box::new(sql::SqlInsertWriter::new(writer, dialect, namespace, table))
-                    as Box<dyn ExportWriter>,
-            )
-        }
         #[cfg(feature = "pro")]
         ExportFormat::Xlsx => {
             // XLSX writer manages its own file I/O
-            drop(writer);
             Ok(Box::new(xlsx::XlsxWriter::new(output_path.to_string())) as Box<dyn ExportWriter>)
         }
         #[cfg(feature = "pro")]
         ExportFormat::Parquet => {
             // Parquet writer manages its own file I/O
-            drop(writer);
             Ok(Box::new(parquet_writer::ParquetExportWriter::new(
                 output_path.to_string(),
             )) as Box<dyn ExportWriter>)
@@ -76,5 +78,113 @@ pub async fn create_writer(
         ExportFormat::Xlsx | ExportFormat::Parquet => {
             Err("XLSX and Parquet export require QoreDB Pro".to_string())
         }
+        _ => {
+            let file = File::create(output_path)
+                .await
+                .map_err(|e| format!("Failed to create export file: {}", e))?;
+            create_stream_writer(
+                format,
+                BufWriter::new(file),
+                include_headers,
+                pretty_json,
+                csv_options,
+                sql_options,
+                table_name,
+                namespace,
+                driver_id,
+            )
+        }
+    }
+}
+
+/// Reopens a partially written export for appending. The file is cut back to
+/// `offset` first, dropping output that was never checkpointed, and headers
+/// are not written again. Only formats without a header or trailer can be
+/// resumed this way.
+pub async fn create_resume_writer(
+    format: ExportFormat,
+    output_path: &str,
+    offset: u64,
+    csv_options: &CsvOptions,
+    sql_options: &SqlInsertOptions,
+    table_name: Option<String>,
+    namespace: Option<Namespace>,
+    driver_id: &str,
+) -> Result<Box<dyn ExportWriter>, String> {
+    if !format.supports_resume() {
+        return Err(
+            "Resume is only supported for CSV, JSON Lines and SQL INSERT exports".to_string(),
+        );
+    }
+    let file = OpenOptions::new()
+        .append(true)
+        .open(output_path)
+        .await
+        .map_err(|e| format!("Failed to open export file: {}", e))?;
+    file.set_len(offset)
+        .await
+        .map_err(|e| format!("Failed to truncate export file: {}", e))?;
+    // The byte order mark is already at the start of the file
+    let csv_options = CsvOptions {
+        utf8_bom: false,
+        ..*csv_options
+    };
+    create_stream_writer(
+        format,
+        BufWriter::new(file),
+        false,
+        false,
+        &csv_options,
+        sql_options,
+        table_name,
+        namespace,
+        driver_id,
+    )
+}
+
+/// Builds a writer for the text formats (CSV, JSON, JSONL, HTML, SQL INSERT) over an
+/// arbitrary sink such as stdout. XLSX and Parquet need a seekable file and go
+/// through [`create_writer`] instead.
+#[allow(clippy::too_many_arguments)]
+pub fn create_stream_writer(
+    format: ExportFormat,
+    sink: impl AsyncWrite + Send + Unpin + 'static,
+    include_headers: bool,
+    pretty_json: bool,
+    csv_options: &CsvOptions,
+    sql_options: &SqlInsertOptions,
+    table_name: Option<String>,
+    namespace: Option<Namespace>,
+    driver_id: &str,
+) -> Result<Box<dyn ExportWriter>, String> {
+    match format {
+        ExportFormat::Csv => Ok(
+            Box::new(csv::CsvWriter::new(sink, include_headers, csv_options)?)
+                as Box<dyn ExportWriter>,
+        ),
+        ExportFormat::Json => {
+            Ok(Box::new(json::JsonWriter::new(sink, pretty_json)) as Box<dyn ExportWriter>)
+        }
+        ExportFormat::JsonLines => {
+            Ok(Box::new(jsonl::JsonLinesWriter::new(sink)) as Box<dyn ExportWriter>)
+        }
+        ExportFormat::Html => Ok(Box::new(html::HtmlWriter::new(sink)) as Box<dyn ExportWriter>),
+        ExportFormat::SqlInsert => {
+            let table = table_name
+                .filter(|name| !name.trim().is_empty())
+                .ok_or_else(|| "Table name is required for SQL INSERT export".to_string())?;
+            let dialect = SqlDialect::from_driver_id(driver_id)
+                .ok_or_else(|| "SQL INSERT export is not supported for this driver".to_string())?;
+            Ok(Box::new(sql::SqlInsertWriter::new(
+                sink,
+                dialect,
+                namespace,
+                table,
+                sql_options.batch_size,
+            )) as Box<dyn ExportWriter>)
+        }
+        ExportFormat::Xlsx | ExportFormat::Parquet => {
+            Err("XLSX and Parquet export can only be written to a file".to_string())
+        }
     }
 }
@@ -17,12 +17,17 @@ use crate::export::writers::ExportWriter;
 /// Batch size for writing row groups to Parquet.
 const ROW_GROUP_SIZE: usize = 10_000;
 
+/// Writes typed Parquet columns. The Arrow schema is inferred from the values
+/// of the first row group, so the writer is only opened on the first flush;
+/// columns with no value there fall back to their declared `data_type`.
 pub struct ParquetExportWriter {
     output_path: String,
     schema: Option<Arc<Schema>>,
     columns: Vec<ColumnInfo>,
     buffered_rows: Vec<Vec<Value>>,
     bytes_written: u64,
+    /// Output file between `write_header` and the first flush.
+    file: Option<std::fs::File>,
     writer: Option<ArrowWriter<std::fs::File>>,
 }
 
@@ -34,6 +39,7 @@ impl ParquetExportWriter {
             columns: Vec::new(),
             buffered_rows: Vec::new(),
             bytes_written: 0,
+            file: None,
             writer: None,
         }
     }
@@ -58,18 +64,74 @@ impl ParquetExportWriter {
         }
     }
 
-    fn build_schema(columns: &[ColumnInfo]) -> Arc<Schema> {
+    /// Arrow type of the values in a column. Ints widen to Float64 next to
+    /// floats; any other mix of types falls back to Utf8.
+    fn infer_data_type(rows: &[Vec<Value>], col_idx: usize, declared: &str) -> DataType {
+        let mut inferred: Option<DataType> = None;
+        for value in rows.iter().filter_map(|row| row.get(col_idx)) {
+            let value_type = match value {
+                Value::Null => continue,
+                Value::Bool(_) => DataType::Boolean,
+                Value::Int(_) => DataType::Int64,
+                Value::Float(_) => DataType::Float64,
+                Value::Bytes(_) => DataType::Binary,
+                Value::Text(_) | Value::Json(_) | Value::Array(_) => DataType::Utf8,
+            };
+            inferred = Some(match inferred {
+                None => value_type,
+                Some(current) if current == value_type => current,
+                Some(DataType::Int64 | DataType::Float64)
+                    if matches!(value_type, DataType::Int64 | DataType::Float64) =>
+                {
+                    DataType::Float64
+                }
+                Some(_) => return DataType::Utf8,
+            });
+        }
+        inferred.unwrap_or_else(|| Self::map_data_type(declared))
+    }
+
+    fn build_schema(columns: &[ColumnInfo], rows: &[Vec<Value>]) -> Arc<Schema> {
         let fields: Vec<Field> = columns
             .iter()
-            .map(|col| Field::new(col.name.as_str(), Self::map_data_type(&col.data_type), true))
+            .enumerate()
+            .map(|(idx, col)| {
+                let data_type = Self::infer_data_type(rows, idx, &col.data_type);
+                Field::new(col.name.as_str(), data_type, true)
+            })
             .collect();
         Arc::new(Schema::new(fields))
     }
 
+    /// Opens the Parquet writer with a schema inferred from the buffered rows.
+    fn open_writer(&mut self) -> Result<(), String> {
+        if self.writer.is_some() {
+            return Ok(());
+        }
+
+        let file = self
+            .file
+            .take()
+            .ok_or_else(|| "Parquet writer not initialized".to_string())?;
+        let schema = Self::build_schema(&self.columns, &self.buffered_rows);
+
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+
+        let writer = ArrowWriter::try_new(file, Arc::clone(&schema), Some(props))
+            .map_err(|e| format!("Failed to initialize Parquet writer: {}", e))?;
+
+        self.schema = Some(schema);
+        self.writer = Some(writer);
+        Ok(())
+    }
+
     fn flush_buffer(&mut self) -> Result<(), String> {
         if self.buffered_rows.is_empty() {
             return Ok(());
         }
+        self.open_writer()?;
 
         let schema = self
             .schema
@@ -211,20 +273,10 @@ impl ExportWriter for ParquetExportWriter {
         }
 
         self.columns = columns.to_vec();
-        let schema = Self::build_schema(columns);
-        self.schema = Some(Arc::clone(&schema));
 
         let file = std::fs::File::create(&self.output_path)
             .map_err(|e| format!("Failed to create Parquet file: {}", e))?;
-
-        let props = WriterProperties::builder()
-            .set_compression(Compression::SNAPPY)
-            .build();
-
-        let writer = ArrowWriter::try_new(file, schema, Some(props))
-            .map_err(|e| format!("Failed to initialize Parquet writer: {}", e))?;
-
-        self.writer = Some(writer);
+        self.file = Some(file);
         Ok(())
     }
 
@@ -251,6 +303,10 @@ impl ExportWriter for ParquetExportWriter {
 
     async fn finish(&mut self) -> Result<(), String> {
         self.flush_buffer()?;
+        if self.file.is_some() {
+            // No rows: still write a valid file with the declared types.
+            self.open_writer()?;
+        }
 
         if let Some(writer) = self.writer.take() {
             let file_meta = writer
@@ -269,3 +325,113 @@ impl ExportWriter for ParquetExportWriter {
         self.bytes_written
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, data_type: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.into(),
+            data_type: data_type.into(),
+            nullable: true,
+            case_insensitive: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_keeps_column_types() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.parquet");
+        let path_str = path.to_str().unwrap().to_string();
+
+        let columns = vec![
+            column("id", "text"),
+            column("score", "text"),
+            column("active", "text"),
+            column("payload", "text"),
+            column("name", "text"),
+            column("doc", "text"),
+            column("mixed", "text"),
+            column("empty", "integer"),
+        ];
+        let rows = [
+            vec![
+                Value::Int(1),
+                Value::Float(1.5),
+                Value::Bool(true),
+                Value::Bytes(vec![0, 1, 2]),
+                Value::Text("alice".into()),
+                Value::Json(serde_json::json!({ "a": 1 })),
+                Value::Int(7),
+                Value::Null,
+            ],
+            vec![
+                Value::Int(2),
+                Value::Null,
+                Value::Bool(false),
+                Value::Null,
+                Value::Text("bob".into()),
+                Value::Null,
+                Value::Text("x".into()),
+                Value::Null,
+            ],
+        ];
+
+        let mut writer = ParquetExportWriter::new(path_str.clone());
+        writer.write_header(&columns).await.unwrap();
+        for values in rows {
+            writer.write_row(&columns, &Row { values }).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        let conn = duckdb::Connection::open_in_memory().unwrap();
+        let source = format!("read_parquet('{}')", path_str.replace('\'', "''"));
+
+        let mut stmt = conn
+            .prepare(&format!("DESCRIBE SELECT * FROM {}", source))
+            .unwrap();
+        let types: Vec<(String, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        let expected = [
+            ("id", "BIGINT"),
+            ("score", "DOUBLE"),
+            ("active", "BOOLEAN"),
+            ("payload", "BLOB"),
+            ("name", "VARCHAR"),
+            ("doc", "VARCHAR"),
+            ("mixed", "VARCHAR"),
+            ("empty", "BIGINT"),
+        ];
+        assert_eq!(
+            types,
+            expected
+                .iter()
+                .map(|(name, ty)| (name.to_string(), ty.to_string()))
+                .collect::<Vec<_>>()
+        );
+
+        let (score, mixed, doc): (Option<f64>, String, Option<String>) = conn
+            .query_row(
+                &format!("SELECT score, mixed, doc FROM {} WHERE id = 2", source),
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(score, None);
+        assert_eq!(mixed, "x");
+        assert_eq!(doc, None);
+
+        let nulls: i64 = conn
+            .query_row(
+                &format!("SELECT count(*) FROM {} WHERE empty IS NULL", source),
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(nulls, 2);
+    }
+}
@@ -1,34 +1,50 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use tokio::fs::File;
-use tokio::io::BufWriter;
+use tokio::io::AsyncWrite;
 
 use crate::engine::sql_generator::SqlDialect;
+use crate::engine::sql_safety::quote_ident;
 use crate::engine::types::{ColumnInfo, Namespace, Row, Value};
 use crate::export::writers::counting::CountingWriter;
-use crate::export::writers::ExportWriter;
+use crate::export::writers::{exact_decimal, ExportWriter};
 
+/// SQL Server rejects a `VALUES` list longer than 1000 rows.
+const SQLSERVER_MAX_VALUES_ROWS: usize = 1000;
+
+/// Writes rows as multi-row `INSERT` statements of up to `batch_size` rows.
+/// A statement is only terminated when its batch is full or on `flush`, so
+/// every flushed offset ends on a complete statement.
 pub struct SqlInsertWriter {
     writer: CountingWriter,
     dialect: SqlDialect,
     namespace: Option<Namespace>,
     table_name: String,
     columns_sql: Option<String>,
+    batch_size: usize,
+    /// Rows written to the open statement
+    pending_rows: usize,
 }
 
 impl SqlInsertWriter {
     pub fn new(
-        writer: BufWriter<File>,
+        writer: impl AsyncWrite + Send + Unpin + 'static,
         dialect: SqlDialect,
         namespace: Option<Namespace>,
         table_name: String,
+        batch_size: u32,
     ) -> Self {
+        let mut batch_size = (batch_size as usize).max(1);
+        if dialect == SqlDialect::SqlServer {
+            batch_size = batch_size.min(SQLSERVER_MAX_VALUES_ROWS);
+        }
         Self {
             writer: CountingWriter::new(writer),
             dialect,
             namespace,
             table_name,
             columns_sql: None,
+            batch_size,
+            pending_rows: 0,
         }
     }
 
@@ -36,7 +52,7 @@ impl SqlInsertWriter {
         if let Some(ref ns) = self.namespace {
             self.dialect.qualified_table(ns, &self.table_name)
         } else {
-            self.dialect.quote_ident(&self.table_name)
+            quote_ident(self.dialect, &self.table_name)
         }
     }
 
@@ -44,7 +60,7 @@ impl SqlInsertWriter {
         if self.columns_sql.is_none() {
             let cols = columns
                 .iter()
-                .map(|col| self.dialect.quote_ident(&col.name))
+                .map(|col| quote_ident(self.dialect, &col.name))
                 .collect::<Vec<_>>()
                 .join(", ");
             self.columns_sql = Some(cols);
@@ -54,6 +70,15 @@ impl SqlInsertWriter {
     fn format_value(&self, value: &Value) -> String {
         self.dialect.format_value(value)
     }
+
+    /// Terminates the open statement, if any.
+    async fn close_statement(&mut self) -> Result<(), String> {
+        if self.pending_rows == 0 {
+            return Ok(());
+        }
+        self.pending_rows = 0;
+        self.writer.write_line(";").await
+    }
 }
 
 #[async_trait::async_trait]
@@ -69,25 +94,37 @@ impl ExportWriter for SqlInsertWriter {
         }
 
         self.ensure_columns(columns);
-        let columns_sql = self.columns_sql.clone().unwrap_or_default();
         let mut values = Vec::with_capacity(columns.len());
 
         for idx in 0..columns.len() {
             let value = row.values.get(idx).unwrap_or(&Value::Null);
-            values.push(self.format_value(value));
+            match exact_decimal(&columns[idx], value) {
+                Some(decimal) => values.push(decimal.to_string()),
+                None => values.push(self.format_value(value)),
+            }
         }
 
-        let insert = format!(
-            "INSERT INTO {} ({}) VALUES ({});",
-            self.qualified_table(),
-            columns_sql,
-            values.join(", ")
-        );
+        let tuple = if self.pending_rows == 0 {
+            format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                self.qualified_table(),
+                self.columns_sql.as_deref().unwrap_or_default(),
+                values.join(", ")
+            )
+        } else {
+            format!(",\n  ({})", values.join(", "))
+        };
+        self.writer.write_bytes(tuple.as_bytes()).await?;
+        self.pending_rows += 1;
 
-        self.writer.write_line(&insert).await
+        if self.pending_rows >= self.batch_size {
+            self.close_statement().await?;
+        }
+        Ok(())
     }
 
     async fn flush(&mut self) -> Result<(), String> {
+        self.close_statement().await?;
         self.writer.flush().await
     }
 
@@ -99,3 +136,125 @@ impl ExportWriter for SqlInsertWriter {
         self.writer.bytes_written()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::fs::File;
+    use tokio::io::BufWriter;
+
+    fn columns() -> Vec<ColumnInfo> {
+        ["id", "name"]
+            .iter()
+            .map(|name| ColumnInfo {
+                name: (*name).into(),
+                data_type: "text".into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect()
+    }
+
+    async fn export(dialect: SqlDialect, batch_size: u32, rows: Vec<Row>) -> String {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.sql");
+        let file = File::create(&path).await.unwrap();
+        let mut writer = SqlInsertWriter::new(
+            BufWriter::new(file),
+            dialect,
+            None,
+            "people".into(),
+            batch_size,
+        );
+
+        let columns = columns();
+        writer.write_header(&columns).await.unwrap();
+        for row in &rows {
+            writer.write_row(&columns, row).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(writer.bytes_written(), content.len() as u64);
+        content
+    }
+
+    fn people(count: i64) -> Vec<Row> {
+        (1..=count)
+            .map(|n| Row {
+                values: vec![Value::Int(n), Value::Text(format!("person {n}"))],
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn groups_rows_into_batches_and_flushes_the_remainder() {
+        let content = export(SqlDialect::Postgres, 100, people(250)).await;
+        let statements: Vec<&str> = content.split_terminator(";\n").map(str::trim).collect();
+        assert_eq!(statements.len(), 3);
+        let row_counts: Vec<usize> = statements
+            .iter()
+            .map(|statement| statement.matches("'person ").count())
+            .collect();
+        assert_eq!(row_counts, [100, 100, 50]);
+        assert!(statements.iter().all(|statement| {
+            statement.starts_with(r#"INSERT INTO "people" ("id", "name") VALUES ("#)
+        }));
+        assert!(statements[2].ends_with("(250, 'person 250')"));
+    }
+
+    #[tokio::test]
+    async fn batch_size_one_writes_a_statement_per_row() {
+        let rows = vec![Row {
+            values: vec![Value::Int(1), Value::Text("O'Brien".into())],
+        }];
+        let content = export(SqlDialect::MySql, 1, rows).await;
+        assert_eq!(
+            content,
+            "INSERT INTO `people` (`id`, `name`) VALUES (1, 'O''Brien');\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn exact_decimals_are_written_unquoted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.sql");
+        let file = File::create(&path).await.unwrap();
+        let mut writer = SqlInsertWriter::new(
+            BufWriter::new(file),
+            SqlDialect::SqlServer,
+            None,
+            "prices".into(),
+            100,
+        );
+
+        let columns: Vec<ColumnInfo> = [("amount", "Decimaln"), ("label", "NVarchar")]
+            .iter()
+            .map(|(name, data_type)| ColumnInfo {
+                name: (*name).into(),
+                data_type: (*data_type).into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect();
+        let row = Row {
+            values: vec![
+                Value::Text("1234567890123456789012345678.0123456789".into()),
+                Value::Text("42.5".into()),
+            ],
+        };
+        writer.write_row(&columns, &row).await.unwrap();
+        writer.finish().await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "INSERT INTO [prices] ([amount], [label]) VALUES \
+             (1234567890123456789012345678.0123456789, N'42.5');\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_export_writes_nothing() {
+        assert_eq!(export(SqlDialect::Sqlite, 100, Vec::new()).await, "");
+    }
+}
@@ -145,6 +145,7 @@ impl DuckDbEngine {
                     .unwrap_or_else(|_| format!("col_{i}").into()),
                 data_type: "VARCHAR".into(),
                 nullable: true,
+                case_insensitive: false,
             })
             .collect();
 
@@ -4,6 +4,8 @@
 //!
 //! Orchestrates the full federation pipeline:
 //! parse → plan → fetch from sources → load into DuckDB → execute → return results.
+//! Registered virtual tables are loaded from their in-memory snapshot instead
+//! of being fetched.
 
 use std::sync::Arc;
 use std::time::Instant;
@@ -19,9 +21,10 @@ use crate::engine::types::{ColumnInfo, QueryId, QueryResult, Row, Value};
 use super::duckdb_engine::DuckDbEngine;
 use super::planner::{build_plan, build_source_query};
 use super::types::{
-    ConnectionAliasMap, FederationMetadata, FederationPlan, FederationQueryOptions,
-    SourceFetchPlan, SourceFetchResult,
+    ConnectionAliasMap, FederationMetadata, FederationPlan, FederationPlanPreview,
+    FederationQueryOptions, PlannedSourceQuery, SourceFetchPlan, SourceFetchResult,
 };
+use super::virtual_tables::{VirtualTable, VirtualTableStore};
 
 /// Timeout per source fetch (30 seconds).
 const SOURCE_FETCH_TIMEOUT_MS: u64 = 30_000;
@@ -32,10 +35,14 @@ const DEFAULT_GLOBAL_TIMEOUT_MS: u64 = 60_000;
 /// Executes a federation query end-to-end.
 ///
 /// Returns `(QueryResult, FederationMetadata)` for batch mode.
-#[instrument(skip(session_manager, alias_map, options), fields(query_len = sql.len()))]
+#[instrument(
+    skip(session_manager, alias_map, virtual_tables, options),
+    fields(query_len = sql.len())
+)]
 pub async fn execute_federation(
     sql: &str,
     alias_map: &ConnectionAliasMap,
+    virtual_tables: &VirtualTableStore,
     session_manager: &Arc<SessionManager>,
     options: &FederationQueryOptions,
 ) -> EngineResult<(QueryResult, FederationMetadata)> {
@@ -44,7 +51,7 @@ pub async fn execute_federation(
 
     let result = timeout(
         Duration::from_millis(global_timeout),
-        execute_federation_inner(sql, alias_map, session_manager, options),
+        execute_federation_inner(sql, alias_map, virtual_tables, session_manager, options),
     )
     .await;
 
@@ -61,10 +68,14 @@ pub async fn execute_federation(
 }
 
 /// Executes a federation query with streaming results.
-#[instrument(skip(session_manager, alias_map, options, sender), fields(query_len = sql.len()))]
+#[instrument(
+    skip(session_manager, alias_map, virtual_tables, options, sender),
+    fields(query_len = sql.len())
+)]
 pub async fn execute_federation_stream(
     sql: &str,
     alias_map: &ConnectionAliasMap,
+    virtual_tables: &VirtualTableStore,
     session_manager: &Arc<SessionManager>,
     options: &FederationQueryOptions,
     sender: StreamSender,
@@ -74,7 +85,14 @@ pub async fn execute_federation_stream(
 
     let result = timeout(
         Duration::from_millis(global_timeout),
-        execute_federation_stream_inner(sql, alias_map, session_manager, options, sender),
+        execute_federation_stream_inner(
+            sql,
+            alias_map,
+            virtual_tables,
+            session_manager,
+            options,
+            sender,
+        ),
     )
     .await;
 
@@ -90,22 +108,59 @@ pub async fn execute_federation_stream(
     }
 }
 
+/// Parses and plans a federation query without touching any source.
+///
+/// Returns the sub-query each source would receive and the DuckDB tables it
+/// would be materialized into, so a user can review the fetches (and catch
+/// full-table pulls) before running the query.
+pub fn plan_only(
+    sql: &str,
+    alias_map: &ConnectionAliasMap,
+    virtual_tables: &VirtualTableStore,
+    row_limit: Option<u64>,
+) -> EngineResult<FederationPlanPreview> {
+    let plan = build_plan(sql, alias_map, &virtual_tables.names(), row_limit, false)?;
+
+    let sources = plan
+        .sources
+        .iter()
+        .map(|source| PlannedSourceQuery {
+            alias: source.table_ref.connection_alias.clone(),
+            driver: source.driver_id.clone(),
+            namespace: source.table_ref.namespace.clone(),
+            table: source.table_ref.table.clone(),
+            query: build_source_query(source),
+            local_table: source.table_ref.local_alias.clone(),
+            row_limit: source.row_limit,
+        })
+        .collect();
+
+    Ok(FederationPlanPreview {
+        sources,
+        virtual_tables: plan.virtual_tables,
+        duckdb_query: plan.duckdb_query,
+    })
+}
+
 /// Inner implementation for batch federation execution.
 async fn execute_federation_inner(
     sql: &str,
     alias_map: &ConnectionAliasMap,
+    virtual_tables: &VirtualTableStore,
     session_manager: &Arc<SessionManager>,
     options: &FederationQueryOptions,
 ) -> EngineResult<(QueryResult, FederationMetadata)> {
     let row_limit = options.row_limit_per_source;
 
-    let plan = build_plan(sql, alias_map, row_limit, false)?;
-    let (engine, fetch_results, duckdb_start) = prepare_duckdb(&plan, session_manager).await?;
+    let plan = build_plan(sql, alias_map, &virtual_tables.names(), row_limit, false)?;
+    let snapshots = resolve_snapshots(&plan, virtual_tables)?;
+    let (engine, fetch_results, duckdb_start) =
+        prepare_duckdb(&plan, &snapshots, session_manager).await?;
 
     let query_result = engine.execute_query(&plan.duckdb_query)?;
     let duckdb_time_ms = duckdb_start.elapsed().as_secs_f64() * 1000.0;
 
-    let warnings = build_row_limit_warnings(&fetch_results);
+    let warnings = build_row_limit_warnings(&fetch_results, &snapshots);
 
     let metadata = FederationMetadata {
         source_results: fetch_results,
@@ -121,14 +176,17 @@ async fn execute_federation_inner(
 async fn execute_federation_stream_inner(
     sql: &str,
     alias_map: &ConnectionAliasMap,
+    virtual_tables: &VirtualTableStore,
     session_manager: &Arc<SessionManager>,
     options: &FederationQueryOptions,
     sender: StreamSender,
 ) -> EngineResult<FederationMetadata> {
     let row_limit = options.row_limit_per_source;
 
-    let plan = build_plan(sql, alias_map, row_limit, true)?;
-    let (engine, fetch_results, duckdb_start) = prepare_duckdb(&plan, session_manager).await?;
+    let plan = build_plan(sql, alias_map, &virtual_tables.names(), row_limit, true)?;
+    let snapshots = resolve_snapshots(&plan, virtual_tables)?;
+    let (engine, fetch_results, duckdb_start) =
+        prepare_duckdb(&plan, &snapshots, session_manager).await?;
 
     // DuckDB statement/row handles are not Send: execute sync, then stream out.
     let (columns, rows) = engine.execute_query_for_stream(&plan.duckdb_query)?;
@@ -143,7 +201,7 @@ async fn execute_federation_stream_inner(
     }
     let _ = sender.send(StreamEvent::Done(row_count)).await;
 
-    let warnings = build_row_limit_warnings(&fetch_results);
+    let warnings = build_row_limit_warnings(&fetch_results, &snapshots);
 
     Ok(FederationMetadata {
         source_results: fetch_results,
@@ -153,28 +211,50 @@ async fn execute_federation_stream_inner(
     })
 }
 
-/// Row-limit warnings for any sources that hit their per-source cap. Shared by
-/// the batch and streaming paths (cf. dédup D28).
-fn build_row_limit_warnings(fetch_results: &[SourceFetchResult]) -> Vec<String> {
-    fetch_results
+/// Row-limit warnings for any sources that hit their per-source cap, and for
+/// snapshots that were capped when captured. Shared by the batch and
+/// streaming paths (cf. dédup D28).
+fn build_row_limit_warnings(
+    fetch_results: &[SourceFetchResult],
+    snapshots: &[Arc<VirtualTable>],
+) -> Vec<String> {
+    let sources = fetch_results.iter().filter(|r| r.row_limit_hit).map(|r| {
+        format!(
+            "Source '{}.{}' returned the maximum {} rows. Results may be incomplete.",
+            r.alias, r.table, r.row_count
+        )
+    });
+    let snapshots = snapshots.iter().filter(|t| t.truncated).map(|t| {
+        format!(
+            "Virtual table '{}' was captured with the maximum {} rows. Results may be incomplete.",
+            t.alias,
+            t.rows.len()
+        )
+    });
+    sources.chain(snapshots).collect()
+}
+
+/// Looks up the snapshots a plan references up front, so one expiring
+/// mid-query cannot fail the load step.
+fn resolve_snapshots(
+    plan: &FederationPlan,
+    virtual_tables: &VirtualTableStore,
+) -> EngineResult<Vec<Arc<VirtualTable>>> {
+    plan.virtual_tables
         .iter()
-        .filter(|r| r.row_limit_hit)
-        .map(|r| {
-            format!(
-                "Source '{}.{}' returned the maximum {} rows. Results may be incomplete.",
-                r.alias, r.table, r.row_count
-            )
-        })
+        .map(|alias| virtual_tables.get(alias))
         .collect()
 }
 
 /// Fetches every source, flattens MongoDB documents to per-key columns, and
-/// loads the results into a fresh in-memory DuckDB engine. Shared setup for the
+/// loads the results (plus the referenced snapshots) into a fresh in-memory
+/// DuckDB engine. Shared setup for the
 /// batch and streaming execution paths (cf. dédup D28). The returned `Instant`
 /// is captured after the source fetch (mirroring the original timing) so the
 /// caller's `duckdb_time_ms` still covers load + query only.
 async fn prepare_duckdb(
     plan: &FederationPlan,
+    snapshots: &[Arc<VirtualTable>],
     session_manager: &Arc<SessionManager>,
 ) -> EngineResult<(DuckDbEngine, Vec<SourceFetchResult>, Instant)> {
     let (source_results, fetch_results) = fetch_all_sources(plan, session_manager).await?;
@@ -199,6 +279,11 @@ async fn prepare_duckdb(
         engine.insert_batch(&source.table_ref.local_alias, &result.rows, &result.columns)?;
     }
 
+    for snapshot in snapshots {
+        engine.create_temp_table(&snapshot.alias, &snapshot.columns)?;
+        engine.insert_batch(&snapshot.alias, &snapshot.rows, &snapshot.columns)?;
+    }
+
     Ok((engine, fetch_results, duckdb_start))
 }
 
@@ -318,6 +403,7 @@ fn flatten_mongo_documents(result: QueryResult) -> QueryResult {
             name: name.as_str().into(),
             data_type: "VARCHAR".into(),
             nullable: true,
+            case_insensitive: false,
         })
         .collect();
 
@@ -370,3 +456,145 @@ fn json_value_to_engine_value(v: &serde_json::Value) -> Value {
         _ => Value::Text(v.to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::drivers::sqlite::SqliteDriver;
+    use crate::engine::registry::DriverRegistry;
+    use crate::engine::types::{ConnectionConfig, SessionId};
+    use crate::federation::types::{AliasEntry, VirtualTableOptions};
+
+    async fn seeded_session(session_manager: &SessionManager, statements: &[&str]) -> SessionId {
+        let session = session_manager
+            .connect(ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        let driver = session_manager.get_driver(session).await.unwrap();
+        for sql in statements {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        session
+    }
+
+    #[test]
+    fn test_plan_only_lists_source_queries() {
+        let mut alias_map = ConnectionAliasMap::new();
+        for (alias, driver) in [("crm", "postgres"), ("shop", "mysql")] {
+            alias_map.insert(
+                alias.to_string(),
+                AliasEntry {
+                    session_id: SessionId::new(),
+                    driver_id: driver.to_string(),
+                    display_name: alias.to_string(),
+                },
+            );
+        }
+
+        let plan = plan_only(
+            "SELECT c.name, o.total FROM crm.public.customers c \
+             JOIN shop.sales.orders o ON o.customer_id = c.id",
+            &alias_map,
+            &VirtualTableStore::new(),
+            Some(500),
+        )
+        .unwrap();
+
+        assert_eq!(plan.sources.len(), 2);
+        let customers = &plan.sources[0];
+        assert_eq!(customers.alias, "crm");
+        assert_eq!(customers.query, r#"SELECT * FROM "customers" LIMIT 500"#);
+        assert_eq!(customers.namespace.database, "public");
+        let orders = &plan.sources[1];
+        assert_eq!(orders.alias, "shop");
+        assert_eq!(orders.query, "SELECT * FROM `orders` LIMIT 500");
+        assert_eq!(orders.namespace.database, "sales");
+
+        assert!(plan.virtual_tables.is_empty());
+        assert!(plan.duckdb_query.contains(&customers.local_table));
+        assert!(plan.duckdb_query.contains(&orders.local_table));
+    }
+
+    #[tokio::test]
+    async fn test_joins_virtual_table_with_live_source() {
+        let mut registry = DriverRegistry::new();
+        registry.register(Arc::new(SqliteDriver::new()));
+        let session_manager = Arc::new(SessionManager::new(Arc::new(registry)));
+
+        let crm = seeded_session(
+            &session_manager,
+            &[
+                "CREATE TABLE customers (id INTEGER, name TEXT, tier TEXT)",
+                "INSERT INTO customers VALUES (1, 'ada', 'vip'), (2, 'bob', 'free'), (3, 'cy', 'vip')",
+            ],
+        )
+        .await;
+        let shop = seeded_session(
+            &session_manager,
+            &[
+                "CREATE TABLE orders (id INTEGER, customer_id INTEGER, total REAL)",
+                "INSERT INTO orders VALUES (10, 1, 5.0), (11, 2, 7.5), (12, 3, 1.0), (13, 1, 2.5)",
+            ],
+        )
+        .await;
+
+        let store = VirtualTableStore::new();
+        let info = store
+            .register(
+                session_manager.get_driver(crm).await.unwrap(),
+                crm,
+                "VIP",
+                "SELECT id, name FROM customers WHERE tier = 'vip'",
+                None,
+                &VirtualTableOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(info.alias, "vip");
+        assert_eq!(info.row_count, 2);
+
+        // The snapshot is used as captured: later source changes do not show up.
+        let driver = session_manager.get_driver(crm).await.unwrap();
+        driver
+            .execute(
+                crm,
+                "UPDATE customers SET tier = 'vip' WHERE id = 2",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let mut alias_map = ConnectionAliasMap::new();
+        alias_map.insert(
+            "shop".to_string(),
+            AliasEntry {
+                session_id: shop,
+                driver_id: "sqlite".to_string(),
+                display_name: "Shop".to_string(),
+            },
+        );
+        let options = FederationQueryOptions {
+            timeout_ms: None,
+            stream: None,
+            query_id: None,
+            row_limit_per_source: None,
+        };
+
+        let (result, metadata) = execute_federation(
+            "SELECT v.name, SUM(o.total) AS spent FROM shop.main.orders o \
+             JOIN vip v ON v.id = o.customer_id GROUP BY v.name ORDER BY v.name",
+            &alias_map,
+            &store,
+            &session_manager,
+            &options,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(metadata.source_results.len(), 1);
+        assert_eq!(result.rows.len(), 2);
+        assert!(matches!(&result.rows[0].values[0], Value::Text(s) if s == "ada"));
+        assert!(matches!(result.rows[0].values[1], Value::Float(f) if f == 7.5));
+        assert!(matches!(&result.rows[1].values[0], Value::Text(s) if s == "cy"));
+    }
+}
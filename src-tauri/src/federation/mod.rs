@@ -10,3 +10,4 @@ pub mod manager;
 pub mod parser;
 pub mod planner;
 pub mod types;
+pub mod virtual_tables;
@@ -56,6 +56,50 @@ pub fn is_federation_query(sql: &str, known_aliases: &HashSet<String>) -> bool {
 pub fn parse_federation_refs(
     sql: &str,
     known_aliases: &HashSet<String>,
+) -> EngineResult<Vec<FederatedTableRef>> {
+    let federated_refs = collect_federation_refs(sql, known_aliases)?;
+
+    if federated_refs.is_empty() {
+        return Err(EngineError::validation(
+            "No cross-database table references found in query",
+        ));
+    }
+
+    Ok(federated_refs)
+}
+
+/// Extracts the single-part table references naming a registered virtual
+/// table, deduplicated and lowercased, in order of appearance.
+pub fn parse_virtual_table_refs(
+    sql: &str,
+    virtual_tables: &HashSet<String>,
+) -> EngineResult<Vec<String>> {
+    if virtual_tables.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let statements = Parser::parse_sql(&GenericDialect {}, sql.trim())
+        .map_err(|e| EngineError::syntax_error(format!("Failed to parse federation query: {e}")))?;
+
+    let mut names = Vec::new();
+    for statement in &statements {
+        for (parts, _) in extract_table_refs(statement) {
+            if parts.len() == 1 {
+                let name = parts[0].to_lowercase();
+                if virtual_tables.contains(&name) && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Validates the statement shape and collects cross-database table
+/// references, possibly none.
+pub fn collect_federation_refs(
+    sql: &str,
+    known_aliases: &HashSet<String>,
 ) -> EngineResult<Vec<FederatedTableRef>> {
     let statements = Parser::parse_sql(&GenericDialect {}, sql.trim())
         .map_err(|e| EngineError::syntax_error(format!("Failed to parse federation query: {e}")))?;
@@ -111,12 +155,6 @@ pub fn parse_federation_refs(
         }
     }
 
-    if federated_refs.is_empty() {
-        return Err(EngineError::validation(
-            "No cross-database table references found in query",
-        ));
-    }
-
     Ok(federated_refs)
 }
 
@@ -191,10 +229,8 @@ fn collect_select_refs(select: &Select, refs: &mut Vec<(Vec<String>, Option<Stri
 fn collect_table_factor_refs(factor: &TableFactor, refs: &mut Vec<(Vec<String>, Option<String>)>) {
     match factor {
         TableFactor::Table { name, .. } => {
-            let parts = name_parts(name);
-            if parts.len() >= 3 {
-                refs.push((parts, None));
-            }
+            // Single-part names may be virtual tables; callers filter by arity.
+            refs.push((name_parts(name), None));
         }
         TableFactor::Derived { subquery, .. } => {
             collect_query_refs(subquery, refs);
@@ -513,6 +549,21 @@ mod tests {
         assert!(!rewritten.contains("analytics_mongo"));
     }
 
+    #[test]
+    fn parses_virtual_table_refs() {
+        let virtual_tables: HashSet<String> = ["top_customers".to_string()].into();
+        let sql = "SELECT o.id FROM prod_pg.public.orders o JOIN Top_Customers t ON t.id = o.customer_id JOIN other x ON x.id = o.id";
+        assert_eq!(
+            parse_virtual_table_refs(sql, &virtual_tables).unwrap(),
+            vec!["top_customers".to_string()]
+        );
+
+        // Virtual tables alone are not cross-database references.
+        let sql = "SELECT * FROM top_customers";
+        assert!(collect_federation_refs(sql, &aliases()).unwrap().is_empty());
+        assert!(parse_federation_refs(sql, &aliases()).is_err());
+    }
+
     #[test]
     fn rejects_mutation() {
         let sql = "INSERT INTO prod_pg.public.users VALUES (1, 'test')";
@@ -5,12 +5,15 @@
 //! Resolves connection aliases to session IDs, analyzes WHERE clause for pushdown
 //! opportunities, and generates the final `FederationPlan`.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::engine::error::{EngineError, EngineResult};
 use crate::engine::sql_generator::SqlDialect;
 
-use super::parser::{build_dotted_name, parse_federation_refs, rewrite_query};
+use super::parser::{
+    build_dotted_name, collect_federation_refs, parse_federation_refs, parse_virtual_table_refs,
+    rewrite_query,
+};
 use super::types::{
     ConnectionAliasMap, FederatedTableRef, FederationPlan, SourceFetchPlan, DEFAULT_ROW_LIMIT,
 };
@@ -18,14 +21,23 @@ use super::types::{
 /// Builds a `FederationPlan` from a user query: extracts federated refs,
 /// resolves aliases to sessions, derives source fetch plans, and rewrites
 /// the query for DuckDB.
+///
+/// Bare table names matching `virtual_tables` reference captured snapshots;
+/// a query may use only snapshots and no live source.
 pub fn build_plan(
     sql: &str,
     alias_map: &ConnectionAliasMap,
+    virtual_tables: &HashSet<String>,
     row_limit: Option<u64>,
     streaming: bool,
 ) -> EngineResult<FederationPlan> {
     let known_aliases = alias_map.keys().cloned().collect();
-    let federated_refs = parse_federation_refs(sql, &known_aliases)?;
+    let virtual_refs = parse_virtual_table_refs(sql, virtual_tables)?;
+    let federated_refs = if virtual_refs.is_empty() {
+        parse_federation_refs(sql, &known_aliases)?
+    } else {
+        collect_federation_refs(sql, &known_aliases)?
+    };
 
     let sources = resolve_sources(&federated_refs, alias_map, row_limit)?;
     let mappings = build_rewrite_mappings(&federated_refs);
@@ -33,6 +45,7 @@ pub fn build_plan(
 
     Ok(FederationPlan {
         sources,
+        virtual_tables: virtual_refs,
         duckdb_query,
         original_query: sql.to_string(),
         streaming,
@@ -187,7 +200,7 @@ mod tests {
     fn builds_plan_from_simple_join() {
         let sql = "SELECT u.email, e.type FROM prod_pg.public.users u JOIN analytics_mongo.analytics.events e ON e.user_id = u.id";
         let alias_map = test_alias_map();
-        let plan = build_plan(sql, &alias_map, None, false).unwrap();
+        let plan = build_plan(sql, &alias_map, &HashSet::new(), None, false).unwrap();
 
         assert_eq!(plan.sources.len(), 2);
         assert_eq!(plan.sources[0].table_ref.table, "users");
@@ -202,15 +215,37 @@ mod tests {
     fn unknown_alias_errors() {
         let sql = "SELECT * FROM unknown_db.public.users";
         let alias_map = test_alias_map();
-        let result = build_plan(sql, &alias_map, None, false);
+        let result = build_plan(sql, &alias_map, &HashSet::new(), None, false);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn plans_virtual_table_join() {
+        let sql = "SELECT u.email FROM prod_pg.public.users u JOIN top_customers t ON t.id = u.id";
+        let virtual_tables: HashSet<String> = ["top_customers".to_string()].into();
+        let plan = build_plan(sql, &test_alias_map(), &virtual_tables, None, false).unwrap();
+
+        assert_eq!(plan.sources.len(), 1);
+        assert_eq!(plan.virtual_tables, vec!["top_customers".to_string()]);
+        assert!(plan.duckdb_query.contains("JOIN top_customers"));
+
+        // Snapshot-only queries need no live source.
+        let plan = build_plan(
+            "SELECT * FROM top_customers",
+            &test_alias_map(),
+            &virtual_tables,
+            None,
+            false,
+        )
+        .unwrap();
+        assert!(plan.sources.is_empty());
+    }
+
     #[test]
     fn source_query_has_limit() {
         let sql = "SELECT * FROM prod_pg.public.users";
         let alias_map = test_alias_map();
-        let plan = build_plan(sql, &alias_map, Some(50000), false).unwrap();
+        let plan = build_plan(sql, &alias_map, &HashSet::new(), Some(50000), false).unwrap();
 
         let source_sql = build_source_query(&plan.sources[0]);
         assert!(source_sql.contains("LIMIT 50000"));
@@ -220,7 +255,7 @@ mod tests {
     fn mysql_source_query_uses_backticks() {
         let sql = "SELECT * FROM app_mysql.mydb.profile";
         let alias_map = test_alias_map();
-        let plan = build_plan(sql, &alias_map, None, false).unwrap();
+        let plan = build_plan(sql, &alias_map, &HashSet::new(), None, false).unwrap();
 
         let source_sql = build_source_query(&plan.sources[0]);
         assert_eq!(plan.sources[0].driver_id, "mysql");
@@ -238,7 +273,7 @@ mod tests {
     fn postgres_source_query_uses_double_quotes() {
         let sql = "SELECT * FROM prod_pg.public.users";
         let alias_map = test_alias_map();
-        let plan = build_plan(sql, &alias_map, None, false).unwrap();
+        let plan = build_plan(sql, &alias_map, &HashSet::new(), None, false).unwrap();
 
         let source_sql = build_source_query(&plan.sources[0]);
         assert_eq!(plan.sources[0].driver_id, "postgres");
@@ -252,7 +287,7 @@ mod tests {
     fn mongo_source_query_format() {
         let sql = "SELECT * FROM analytics_mongo.analytics.events";
         let alias_map = test_alias_map();
-        let plan = build_plan(sql, &alias_map, None, false).unwrap();
+        let plan = build_plan(sql, &alias_map, &HashSet::new(), None, false).unwrap();
 
         let source_sql = build_source_query(&plan.sources[0]);
         assert!(
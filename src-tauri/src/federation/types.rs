@@ -41,6 +41,9 @@ pub struct SourceFetchPlan {
 pub struct FederationPlan {
     /// All source tables to fetch
     pub sources: Vec<SourceFetchPlan>,
+    /// Registered virtual tables referenced by the query, loaded into DuckDB
+    /// under their own alias
+    pub virtual_tables: Vec<String>,
     /// The rewritten SQL to execute on DuckDB (with local temp table names)
     pub duckdb_query: String,
     /// Original user query (for audit logging)
@@ -49,6 +52,32 @@ pub struct FederationPlan {
     pub streaming: bool,
 }
 
+/// A federation plan as shown for review, before any source is queried.
+#[derive(Debug, Clone, Serialize)]
+pub struct FederationPlanPreview {
+    /// One entry per source table, in fetch order
+    pub sources: Vec<PlannedSourceQuery>,
+    /// Virtual tables loaded from their snapshot, without a source query
+    pub virtual_tables: Vec<String>,
+    /// The rewritten SQL DuckDB runs over the materialized tables
+    pub duckdb_query: String,
+}
+
+/// The sub-query a federation plan issues against one source table.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedSourceQuery {
+    /// Connection alias
+    pub alias: String,
+    pub driver: String,
+    pub namespace: Namespace,
+    pub table: String,
+    /// Exact query sent to the source connection
+    pub query: String,
+    /// DuckDB temp table the fetched rows are materialized into
+    pub local_table: String,
+    pub row_limit: u64,
+}
+
 /// Mapping from connection alias to session info.
 pub type ConnectionAliasMap = HashMap<String, AliasEntry>;
 
@@ -115,3 +144,32 @@ pub struct FederationMetadata {
     /// Warnings (e.g., row limit hits)
     pub warnings: Vec<String>,
 }
+
+/// Options for capturing a virtual table.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VirtualTableOptions {
+    /// Row cap for the snapshot (default: 100_000)
+    pub max_rows: Option<u64>,
+    /// Seconds before the snapshot expires (default: 3600, 0 = never)
+    pub ttl_secs: Option<u64>,
+}
+
+/// A captured virtual table exposed to the frontend.
+#[derive(Debug, Clone, Serialize)]
+pub struct VirtualTableInfo {
+    /// Name used to reference the snapshot in federated queries
+    pub alias: String,
+    /// Session the query was captured from
+    pub session_id: String,
+    /// Query that produced the snapshot, re-run on refresh
+    pub query: String,
+    pub namespace: Option<Namespace>,
+    pub columns: Vec<String>,
+    pub row_count: u64,
+    /// Whether the result was cut off at the row cap
+    pub truncated: bool,
+    /// ISO 8601 timestamp of the last capture
+    pub captured_at: String,
+    /// Seconds left before expiry (None = never expires)
+    pub expires_in_secs: Option<u64>,
+}
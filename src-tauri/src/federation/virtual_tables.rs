@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: BUSL-1.1
+
+//! Virtual tables: query results captured once and referenced by name in
+//! later federated queries without re-executing the source query.
+//!
+//! Snapshots are held in memory and loaded into each federation query's
+//! DuckDB engine under their alias, next to the live source tables.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use tokio::time::timeout;
+
+use crate::engine::error::{EngineError, EngineResult};
+use crate::engine::traits::{DataEngine, StreamEvent};
+use crate::engine::types::{ColumnInfo, Namespace, QueryId, Row, SessionId};
+
+use super::types::{VirtualTableInfo, VirtualTableOptions, DEFAULT_ROW_LIMIT};
+
+/// Hard ceiling on a single snapshot's row cap.
+pub const MAX_VIRTUAL_TABLE_ROWS: u64 = 1_000_000;
+
+/// Total rows held across all snapshots.
+pub const MAX_TOTAL_VIRTUAL_ROWS: u64 = 2_000_000;
+
+/// Snapshot lifetime when the caller does not pass one.
+pub const DEFAULT_VIRTUAL_TABLE_TTL_SECS: u64 = 3600;
+
+/// A captured query result.
+#[derive(Debug)]
+pub struct VirtualTable {
+    pub alias: String,
+    pub session_id: SessionId,
+    pub query: String,
+    pub namespace: Option<Namespace>,
+    pub columns: Vec<ColumnInfo>,
+    pub rows: Vec<Row>,
+    pub truncated: bool,
+    /// Row cap the snapshot was captured with, reused on refresh
+    pub max_rows: u64,
+    pub ttl: Option<Duration>,
+    captured_at: Instant,
+    captured_at_utc: String,
+}
+
+impl VirtualTable {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.ttl
+            .is_some_and(|ttl| now.duration_since(self.captured_at) >= ttl)
+    }
+
+    pub fn info(&self) -> VirtualTableInfo {
+        let expires_in_secs = self
+            .ttl
+            .map(|ttl| ttl.saturating_sub(self.captured_at.elapsed()).as_secs());
+        VirtualTableInfo {
+            alias: self.alias.clone(),
+            session_id: self.session_id.0.to_string(),
+            query: self.query.clone(),
+            namespace: self.namespace.clone(),
+            columns: self.columns.iter().map(|c| c.name.to_string()).collect(),
+            row_count: self.rows.len() as u64,
+            truncated: self.truncated,
+            captured_at: self.captured_at_utc.clone(),
+            expires_in_secs,
+        }
+    }
+}
+
+/// In-memory registry of virtual tables, keyed by lowercase alias.
+#[derive(Default)]
+pub struct VirtualTableStore {
+    inner: Mutex<HashMap<String, Arc<VirtualTable>>>,
+}
+
+impl VirtualTableStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `query` on the session (capped) and stores the result as `alias`.
+    /// Re-using an alias replaces the previous snapshot.
+    pub async fn register(
+        &self,
+        driver: Arc<dyn DataEngine>,
+        session: SessionId,
+        alias: &str,
+        query: &str,
+        namespace: Option<Namespace>,
+        options: &VirtualTableOptions,
+    ) -> EngineResult<VirtualTableInfo> {
+        let alias = normalize_alias(alias)?;
+        let max_rows = options
+            .max_rows
+            .unwrap_or(DEFAULT_ROW_LIMIT)
+            .clamp(1, MAX_VIRTUAL_TABLE_ROWS);
+        let ttl = match options.ttl_secs.unwrap_or(DEFAULT_VIRTUAL_TABLE_TTL_SECS) {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        };
+
+        let (columns, rows, truncated) =
+            capture_query(driver, session, namespace.clone(), query, max_rows).await?;
+        if columns.is_empty() {
+            return Err(EngineError::validation(
+                "Query returned no result set to capture",
+            ));
+        }
+
+        let table = Arc::new(VirtualTable {
+            alias: alias.clone(),
+            session_id: session,
+            query: query.to_string(),
+            namespace,
+            columns,
+            rows,
+            truncated,
+            max_rows,
+            ttl,
+            captured_at: Instant::now(),
+            captured_at_utc: chrono::Utc::now().to_rfc3339(),
+        });
+
+        let mut inner = self.inner.lock();
+        purge_expired(&mut inner);
+        let held: u64 = inner
+            .values()
+            .filter(|t| t.alias != alias)
+            .map(|t| t.rows.len() as u64)
+            .sum();
+        if held + table.rows.len() as u64 > MAX_TOTAL_VIRTUAL_ROWS {
+            return Err(EngineError::validation(format!(
+                "Virtual tables are limited to {MAX_TOTAL_VIRTUAL_ROWS} rows in total; \
+                 drop an existing one before capturing '{alias}'"
+            )));
+        }
+        let info = table.info();
+        inner.insert(alias, table);
+        Ok(info)
+    }
+
+    /// Returns a live snapshot. Expired snapshots are evicted and reported
+    /// as missing.
+    pub fn get(&self, alias: &str) -> EngineResult<Arc<VirtualTable>> {
+        let alias = normalize_alias(alias)?;
+        let mut inner = self.inner.lock();
+        purge_expired(&mut inner);
+        inner
+            .get(&alias)
+            .cloned()
+            .ok_or_else(|| EngineError::validation(format!("No virtual table named '{alias}'")))
+    }
+
+    /// Aliases of all live snapshots.
+    pub fn names(&self) -> HashSet<String> {
+        let mut inner = self.inner.lock();
+        purge_expired(&mut inner);
+        inner.keys().cloned().collect()
+    }
+
+    pub fn list(&self) -> Vec<VirtualTableInfo> {
+        let mut inner = self.inner.lock();
+        purge_expired(&mut inner);
+        let mut tables: Vec<VirtualTableInfo> = inner.values().map(|t| t.info()).collect();
+        tables.sort_by(|a, b| a.alias.cmp(&b.alias));
+        tables
+    }
+
+    /// Returns `true` when the snapshot existed.
+    pub fn remove(&self, alias: &str) -> bool {
+        match normalize_alias(alias) {
+            Ok(alias) => self.inner.lock().remove(&alias).is_some(),
+            Err(_) => false,
+        }
+    }
+}
+
+fn purge_expired(tables: &mut HashMap<String, Arc<VirtualTable>>) {
+    let now = Instant::now();
+    tables.retain(|_, t| !t.is_expired(now));
+}
+
+/// Virtual tables are referenced as bare identifiers, so the alias must be a
+/// plain SQL name. The `__` prefix is reserved for federation temp tables.
+fn normalize_alias(alias: &str) -> EngineResult<String> {
+    let alias = alias.trim().to_lowercase();
+    let valid = !alias.is_empty()
+        && alias.len() <= 63
+        && !alias.starts_with("__")
+        && alias
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && alias.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(alias)
+    } else {
+        Err(EngineError::validation(format!(
+            "Invalid virtual table name '{alias}': use letters, digits and underscores"
+        )))
+    }
+}
+
+/// Streams `query` and keeps at most `max_rows` rows, cancelling the source
+/// query once the cap is reached.
+async fn capture_query(
+    driver: Arc<dyn DataEngine>,
+    session: SessionId,
+    namespace: Option<Namespace>,
+    query: &str,
+    max_rows: u64,
+) -> EngineResult<(Vec<ColumnInfo>, Vec<Row>, bool)> {
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
+    let query_id = QueryId::new();
+
+    let mut driver_task = tokio::spawn({
+        let driver = Arc::clone(&driver);
+        let query = query.to_string();
+        async move {
+            driver
+                .execute_stream_in_namespace(session, namespace, &query, query_id, sender)
+                .await
+        }
+    });
+
+    let mut columns = Vec::new();
+    let mut rows: Vec<Row> = Vec::new();
+    let mut truncated = false;
+    let mut stream_error: Option<String> = None;
+
+    loop {
+        let done = match receiver.recv().await {
+            Some(StreamEvent::Columns(cols)) => {
+                columns = cols;
+                false
+            }
+            Some(StreamEvent::Row(row)) => {
+                rows.push(row);
+                false
+            }
+            Some(StreamEvent::RowBatch(batch)) => {
+                rows.extend(batch);
+                false
+            }
+            Some(StreamEvent::Error(err)) => {
+                stream_error = Some(err);
+                true
+            }
+            Some(StreamEvent::Truncated { .. }) => false,
+            Some(StreamEvent::Done(_)) | None => true,
+        };
+        if rows.len() as u64 > max_rows {
+            rows.truncate(max_rows as usize);
+            truncated = true;
+        }
+        if done || truncated {
+            break;
+        }
+    }
+
+    // Dropping the receiver unblocks a driver still pushing rows.
+    drop(receiver);
+    if truncated || stream_error.is_some() {
+        let _ = driver.cancel(session, Some(query_id)).await;
+        if timeout(Duration::from_secs(2), &mut driver_task)
+            .await
+            .is_err()
+        {
+            driver_task.abort();
+        }
+    } else {
+        driver_task
+            .await
+            .map_err(|e| EngineError::internal(format!("Stream task failed: {e}")))??;
+    }
+
+    if let Some(err) = stream_error {
+        return Err(EngineError::execution_error(err));
+    }
+
+    Ok((columns, rows, truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_alias() {
+        assert_eq!(normalize_alias(" Top_Customers ").unwrap(), "top_customers");
+        assert!(normalize_alias("").is_err());
+        assert!(normalize_alias("1st").is_err());
+        assert!(normalize_alias("__fed_users_0").is_err());
+        assert!(normalize_alias("a.b").is_err());
+        assert!(normalize_alias("drop table x").is_err());
+    }
+}
@@ -0,0 +1,439 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Headless export: runs a query on a saved connection and streams the result
+//! to stdout with the regular export writers, without opening a window.
+//!
+//! ```text
+//! qoredb export <connection-id> <query> [--format csv|json|jsonl|html|sql]
+//!     [--database DB] [--schema SCHEMA] [--table NAME]
+//!     [--no-headers] [--pretty] [--limit N]
+//! ```
+//!
+//! Credentials are read from the vault. Set `QORE_VAULT_KEY` (and optionally
+//! `QORE_VAULT_FILE`) to use the encrypted-file vault where no OS keyring is
+//! available, and `QOREDB_VAULT_PASSWORD` when a master password is set. The
+//! query goes through the same safety preflight as the UI, so read-only and
+//! production guards apply.
+//!
+//! Windows release builds use the GUI subsystem, so there stdout has to be
+//! redirected to a file or pipe to be captured.
+
+use std::sync::Arc;
+
+use tokio::io::{AsyncWrite, BufWriter};
+use tokio::time::{timeout, Duration};
+
+use crate::engine::traits::StreamEvent;
+use crate::engine::types::{ColumnInfo, ConnectionConfig, Namespace, QueryId, SessionId};
+use crate::export::types::{CsvOptions, ExportFormat, SqlInsertOptions};
+use crate::export::writers::create_stream_writer;
+use crate::vault::backend::default_provider;
+use crate::vault::VaultStorage;
+use qore_service::ServiceContext;
+
+/// First CLI argument that switches the binary into headless export mode.
+pub const EXPORT_COMMAND: &str = "export";
+
+const VAULT_PASSWORD_ENV: &str = "QOREDB_VAULT_PASSWORD";
+
+const USAGE: &str = "usage: qoredb export <connection-id> <query> \
+                     [--format csv|json|jsonl|html|sql] [--database DB] [--schema SCHEMA] \
+                     [--table NAME] [--no-headers] [--pretty] [--limit N]";
+
+#[derive(Debug, Clone)]
+pub struct HeadlessExportArgs {
+    pub connection_id: String,
+    pub query: String,
+    pub format: ExportFormat,
+    pub namespace: Option<Namespace>,
+    /// Target table for `--format sql`
+    pub table_name: Option<String>,
+    pub include_headers: bool,
+    pub pretty_json: bool,
+    pub limit: Option<u64>,
+}
+
+impl HeadlessExportArgs {
+    /// Parses the arguments following `export`.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut format = ExportFormat::Csv;
+        let mut database = None;
+        let mut schema = None;
+        let mut table_name = None;
+        let mut include_headers = true;
+        let mut pretty_json = false;
+        let mut limit = None;
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let mut value = |flag: &str| {
+                iter.next()
+                    .cloned()
+                    .ok_or_else(|| format!("{flag} requires a value"))
+            };
+            match arg.as_str() {
+                "--format" => format = parse_format(&value("--format")?)?,
+                "--database" => database = Some(value("--database")?),
+                "--schema" => schema = Some(value("--schema")?),
+                "--table" => table_name = Some(value("--table")?),
+                "--limit" => {
+                    let raw = value("--limit")?;
+                    limit = Some(
+                        raw.parse::<u64>()
+                            .map_err(|_| format!("Invalid --limit value: {raw}"))?,
+                    );
+                }
+                "--no-headers" => include_headers = false,
+                "--pretty" => pretty_json = true,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option: {flag}")),
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        let [connection_id, query]: [String; 2] = positional
+            .try_into()
+            .map_err(|_| "Expected a connection id and a query".to_string())?;
+        if query.trim().is_empty() {
+            return Err("Query is required for export".to_string());
+        }
+        if schema.is_some() && database.is_none() {
+            return Err("--schema requires --database".to_string());
+        }
+
+        Ok(Self {
+            connection_id,
+            query,
+            format,
+            namespace: database.map(|database| Namespace { database, schema }),
+            table_name,
+            include_headers,
+            pretty_json,
+            limit,
+        })
+    }
+}
+
+fn parse_format(raw: &str) -> Result<ExportFormat, String> {
+    match raw.to_ascii_lowercase().as_str() {
+        "csv" => Ok(ExportFormat::Csv),
+        "json" => Ok(ExportFormat::Json),
+        "jsonl" | "ndjson" => Ok(ExportFormat::JsonLines),
+        "html" => Ok(ExportFormat::Html),
+        "sql" | "sql_insert" => Ok(ExportFormat::SqlInsert),
+        other => Err(format!(
+            "Unsupported format for stdout export: {other} (expected csv, json, jsonl, html or sql)"
+        )),
+    }
+}
+
+/// Entry point for `qoredb export ...`. Returns the process exit code; the
+/// export goes to stdout and diagnostics to stderr.
+pub fn run(args: &[String]) -> i32 {
+    let args = match HeadlessExportArgs::parse(args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {err}\n{USAGE}");
+            return 2;
+        }
+    };
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!("error: failed to start runtime: {err}");
+            return 1;
+        }
+    };
+
+    match runtime.block_on(export_saved_connection(&args, tokio::io::stdout())) {
+        Ok(rows) => {
+            eprintln!("{rows} rows exported");
+            0
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            1
+        }
+    }
+}
+
+/// Opens the saved connection, streams the query result into `out`, and
+/// closes the session. Returns the number of rows written.
+pub async fn export_saved_connection(
+    args: &HeadlessExportArgs,
+    out: impl AsyncWrite + Send + Unpin + 'static,
+) -> Result<u64, String> {
+    let mut ctx = ServiceContext::new();
+    unlock_vault(&mut ctx).await?;
+    let config = load_saved_config(&args.connection_id)?;
+
+    let session =
+        qore_service::connection::connect(&ctx.session_manager, &ctx.query_manager, config)
+            .await
+            .map_err(|e| e.sanitized())?;
+
+    let result = stream_query(&ctx, session, args, out).await;
+
+    let _ = qore_service::connection::disconnect(
+        &ctx.session_manager,
+        &ctx.query_manager,
+        &ctx.query_rate_limiter,
+        session,
+    )
+    .await;
+    result
+}
+
+/// Unlocks a master-password-protected vault from the environment. Vaults
+/// without a master password are already open.
+async fn unlock_vault(ctx: &mut ServiceContext) -> Result<(), String> {
+    if ctx.vault_lock.is_unlocked() {
+        return Ok(());
+    }
+    let password = std::env::var(VAULT_PASSWORD_ENV)
+        .map_err(|_| format!("Vault is locked; set {VAULT_PASSWORD_ENV} to unlock it"))?;
+    match ctx.vault_lock.unlock(&password).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err("Invalid vault password".to_string()),
+        Err(e) => Err(e.sanitized_message()),
+    }
+}
+
+fn load_saved_config(connection_id: &str) -> Result<ConnectionConfig, String> {
+    let storage = VaultStorage::new(
+        crate::paths::PROJECT_ID,
+        crate::paths::config_dir(),
+        default_provider(),
+    );
+    let saved = storage
+        .get_connection(connection_id)
+        .map_err(|e| e.sanitized_message())?;
+    let creds = storage
+        .get_credentials(connection_id)
+        .map_err(|e| e.sanitized_message())?;
+    saved
+        .to_connection_config(&creds)
+        .map_err(|e| e.sanitized_message())
+}
+
+/// Runs `args.query` on an open session and streams it through the export
+/// writer for `args.format`.
+pub async fn stream_query(
+    ctx: &ServiceContext,
+    session: SessionId,
+    args: &HeadlessExportArgs,
+    out: impl AsyncWrite + Send + Unpin + 'static,
+) -> Result<u64, String> {
+    let session_id = session.0.to_string();
+    let preflight = qore_service::query::preflight(
+        &ctx.session_manager,
+        &ctx.query_rate_limiter,
+        &ctx.interceptor,
+        &ctx.policy,
+        session,
+        &session_id,
+        &args.query,
+        args.namespace.as_ref(),
+        false,
+    )
+    .await?;
+
+    let driver = preflight.driver;
+    if !driver.capabilities().streaming {
+        return Err("Streaming is not supported by this driver".to_string());
+    }
+
+    let mut writer = create_stream_writer(
+        args.format.clone(),
+        BufWriter::new(out),
+        args.include_headers,
+        args.pretty_json,
+        &CsvOptions::default(),
+        &SqlInsertOptions::default(),
+        args.table_name.clone(),
+        args.namespace.clone(),
+        driver.driver_id(),
+    )?;
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
+    let query_id = QueryId::new();
+    let mut driver_task = tokio::spawn({
+        let driver = Arc::clone(&driver);
+        let query = args.query.clone();
+        let namespace = args.namespace.clone();
+        async move {
+            driver
+                .execute_stream_in_namespace(session, namespace, &query, query_id, sender)
+                .await
+        }
+    });
+
+    let mut columns: Vec<ColumnInfo> = Vec::new();
+    let mut rows_exported: u64 = 0;
+    let mut error: Option<String> = None;
+    let mut stopped_early = false;
+
+    'stream: while let Some(event) = receiver.recv().await {
+        let batch = match event {
+            StreamEvent::Columns(cols) => {
+                columns = cols;
+                if let Err(err) = writer.write_header(&columns).await {
+                    error = Some(err);
+                    break;
+                }
+                continue;
+            }
+            StreamEvent::Row(row) => vec![row],
+            StreamEvent::RowBatch(batch) => batch,
+            StreamEvent::Error(err) => {
+                error = Some(err);
+                break;
+            }
+            StreamEvent::Truncated { .. } => continue,
+            StreamEvent::Done(_) => break,
+        };
+        for row in batch {
+            if let Err(err) = writer.write_row(&columns, &row).await {
+                error = Some(err);
+                break 'stream;
+            }
+            rows_exported += 1;
+            if args.limit.is_some_and(|limit| rows_exported >= limit) {
+                stopped_early = true;
+                break 'stream;
+            }
+        }
+    }
+
+    drop(receiver);
+    if error.is_some() || stopped_early {
+        let _ = driver.cancel(session, Some(query_id)).await;
+        if timeout(Duration::from_secs(2), &mut driver_task)
+            .await
+            .is_err()
+        {
+            driver_task.abort();
+        }
+    } else {
+        match driver_task.await {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => error = Some(err.sanitized_message()),
+            Err(err) => error = Some(err.to_string()),
+        }
+    }
+
+    let finished = writer.finish().await;
+    match error {
+        Some(err) => Err(err),
+        None => finished.map(|_| rows_exported),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    fn args(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|s| s.to_string()).collect()
+    }
+
+    async fn export_to_string(
+        ctx: &ServiceContext,
+        session: SessionId,
+        args: &HeadlessExportArgs,
+    ) -> (Result<u64, String>, String) {
+        let (client, mut server) = tokio::io::duplex(64 * 1024);
+        let result = stream_query(ctx, session, args, client).await;
+        let mut output = String::new();
+        server.read_to_string(&mut output).await.unwrap();
+        (result, output)
+    }
+
+    #[test]
+    fn parses_export_arguments() {
+        let parsed = HeadlessExportArgs::parse(&args(&[
+            "conn-1",
+            "SELECT * FROM users",
+            "--format",
+            "json",
+            "--database",
+            "app",
+            "--schema",
+            "public",
+            "--pretty",
+            "--limit",
+            "10",
+        ]))
+        .unwrap();
+        assert_eq!(parsed.connection_id, "conn-1");
+        assert_eq!(parsed.query, "SELECT * FROM users");
+        assert!(matches!(parsed.format, ExportFormat::Json));
+        let namespace = parsed.namespace.unwrap();
+        assert_eq!(namespace.database, "app");
+        assert_eq!(namespace.schema.as_deref(), Some("public"));
+        assert!(parsed.pretty_json);
+        assert!(parsed.include_headers);
+        assert_eq!(parsed.limit, Some(10));
+
+        assert!(HeadlessExportArgs::parse(&args(&["conn-1"])).is_err());
+        assert!(HeadlessExportArgs::parse(&args(&["conn-1", "SELECT 1", "--bogus"])).is_err());
+        assert!(
+            HeadlessExportArgs::parse(&args(&["conn-1", "SELECT 1", "--format", "xlsx"])).is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn streams_sqlite_query_as_csv() {
+        let ctx = ServiceContext::new();
+        let session = ctx
+            .session_manager
+            .connect(ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        let driver = ctx.session_manager.get_driver(session).await.unwrap();
+        for sql in [
+            "CREATE TABLE users (id INTEGER, name TEXT)",
+            "INSERT INTO users VALUES (1, 'ada'), (2, 'bob, jr'), (3, 'cy')",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+
+        let export =
+            HeadlessExportArgs::parse(&args(&["unused", "SELECT id, name FROM users ORDER BY id"]))
+                .unwrap();
+        let (result, output) = export_to_string(&ctx, session, &export).await;
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(output, "id,name\n1,ada\n2,\"bob, jr\"\n3,cy\n");
+
+        let limited = HeadlessExportArgs::parse(&args(&[
+            "unused",
+            "SELECT id FROM users ORDER BY id",
+            "--no-headers",
+            "--limit",
+            "2",
+        ]))
+        .unwrap();
+        let (result, output) = export_to_string(&ctx, session, &limited).await;
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(output, "1\n2\n");
+    }
+
+    #[tokio::test]
+    async fn rejects_mutation_on_read_only_connection() {
+        let ctx = ServiceContext::new();
+        let mut config = ConnectionConfig::sqlite_memory_for_tests();
+        config.read_only = true;
+        let session = ctx.session_manager.connect(config).await.unwrap();
+
+        let export =
+            HeadlessExportArgs::parse(&args(&["unused", "DELETE FROM users RETURNING *"])).unwrap();
+        let (result, output) = export_to_string(&ctx, session, &export).await;
+        assert!(result.is_err());
+        assert!(output.is_empty());
+    }
+}
@@ -8,6 +8,7 @@ pub mod ai;
 pub mod api;
 pub mod atomic_write;
 pub mod backup;
+pub mod benchmark;
 pub mod commands;
 #[cfg(feature = "pro")]
 pub mod contracts;
@@ -15,16 +16,21 @@ pub mod engine;
 pub mod export;
 #[cfg(feature = "pro")]
 pub mod federation;
+pub mod headless;
+pub mod local_cache;
 pub mod observability;
 pub mod plugins;
 pub mod redaction;
 pub mod share;
 pub mod snapshots;
+pub mod snippets;
 pub mod time_travel;
+pub mod undo;
 pub mod workspace;
 
 pub use qore_service::{
-    cache, interceptor, license, metrics, paths, policy, ratelimit, vault, virtual_relations,
+    cache, display_transforms, interceptor, license, metrics, paths, policy, ratelimit, vault,
+    virtual_relations,
 };
 
 use std::sync::Arc;
@@ -48,10 +54,20 @@ pub struct AppState {
     pub share_manager: Arc<ShareManager>,
     #[cfg(feature = "pro")]
     pub ai_manager: Arc<ai::manager::AiManager>,
+    #[cfg(feature = "pro")]
+    pub virtual_tables: Arc<federation::virtual_tables::VirtualTableStore>,
     pub changelog_store: Arc<time_travel::ChangelogStore>,
+    pub mutation_undo: Arc<undo::MutationUndoStore>,
+    pub local_cache: Arc<local_cache::LocalCacheStore>,
+    pub result_cache: Arc<local_cache::ResultCache>,
     pub backup_tool_paths: Arc<backup::BackupToolPaths>,
     pub active_backups: Arc<backup::runner::ActiveBackups>,
+    pub active_benchmarks: Arc<benchmark::ActiveBenchmarks>,
     pub confirmation_tokens: Arc<commands::confirmation::ConfirmationTokenStore>,
+    pub server_log_follows: Arc<commands::server_log::ServerLogFollows>,
+    pub fulltext_searches: Arc<commands::fulltext_search::FulltextSearches>,
+    pub notification_listeners: Arc<commands::query::NotificationListeners>,
+    pub snippet_store: Arc<snippets::SnippetStore>,
 }
 
 impl AppState {
@@ -74,6 +90,10 @@ impl AppState {
             data_dir.join("time-travel"),
         ));
 
+        let local_cache = Arc::new(local_cache::LocalCacheStore::new(
+            data_dir.join("local-cache"),
+        ));
+
         // Load executable plugins once at startup.
         let plugin_host = Arc::new(PluginHost::new());
         plugin_host.reload();
@@ -85,10 +105,20 @@ impl AppState {
             share_manager,
             #[cfg(feature = "pro")]
             ai_manager,
+            #[cfg(feature = "pro")]
+            virtual_tables: Arc::new(federation::virtual_tables::VirtualTableStore::new()),
             changelog_store,
+            mutation_undo: Arc::new(undo::MutationUndoStore::new()),
+            local_cache,
+            result_cache: Arc::new(local_cache::ResultCache::new()),
             backup_tool_paths: Arc::new(backup::BackupToolPaths::new()),
             active_backups: Arc::new(backup::runner::ActiveBackups::new()),
+            active_benchmarks: Arc::new(benchmark::ActiveBenchmarks::new()),
             confirmation_tokens: Arc::new(commands::confirmation::ConfirmationTokenStore::new()),
+            server_log_follows: Arc::new(commands::server_log::ServerLogFollows::new()),
+            fulltext_searches: Arc::new(commands::fulltext_search::FulltextSearches::new()),
+            notification_listeners: Arc::new(commands::query::NotificationListeners::new()),
+            snippet_store: Arc::new(snippets::SnippetStore::new(data_dir.join("snippets"))),
         }
     }
 }
@@ -115,6 +145,7 @@ impl std::ops::DerefMut for AppState {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     observability::init_tracing();
+    metrics::MetricsConfig::load().apply();
     let state: SharedState = Arc::new(Mutex::new(AppState::new()));
 
     let data_dir = paths::app_data_dir();
@@ -232,28 +263,63 @@ pub fn run() {
             commands::connection::disconnect,
             commands::connection::list_sessions,
             commands::connection::check_connection_health,
+            commands::connection::ping_session,
+            commands::connection::get_pool_stats,
+            commands::connection::get_server_info,
             // Connection URL parsing
             commands::connection_url::parse_url,
             commands::connection_url::get_supported_url_schemes,
+            commands::connection_url::export_connection_env,
             // Driver commands
             commands::driver::get_driver_info,
             commands::driver::list_drivers,
             // Query commands
             commands::query::execute_query,
+            commands::query::execute_parameterized,
             commands::query::cancel_query,
+            commands::query::cancel_session_queries,
+            commands::query::get_query_queue,
+            commands::query::get_query_state,
+            commands::query::search_history,
+            commands::query::clear_history,
+            commands::query::get_history_config,
+            commands::query::set_history_config,
+            commands::query::set_query_concurrency,
             commands::query::list_namespaces,
+            commands::query::list_databases,
             commands::query::list_collections,
             commands::query::list_routines,
             commands::query::list_triggers,
             commands::query::list_events,
             commands::query::list_sequences,
             commands::query::describe_table,
+            commands::query::get_table_partitions,
+            commands::query::get_table_ddl,
+            commands::query::get_column_stats,
+            commands::query::geometry_to_geojson,
+            commands::query::bulk_import_csv,
+            commands::query::copy_table,
+            commands::query::create_index,
             commands::query::preview_table,
             commands::query::query_table,
+            commands::query::cache_result,
+            commands::query::refresh_cached_result,
+            commands::query::query_cached,
+            commands::query::listen,
+            commands::query::unlisten,
             commands::query::peek_foreign_key,
+            commands::query::execute_as_role,
+            commands::query::execute_batch,
+            commands::query::explain_query,
+            commands::query::run_aggregation,
+            commands::query::set_row_security_bypass,
             commands::query::get_creation_options,
             commands::query::create_database,
             commands::query::drop_database,
+            commands::query::attach_database,
+            commands::query::register_file_as_view,
+            // SQL lint commands
+            commands::lint::lint_query,
             // Transaction commands
             commands::query::begin_transaction,
             commands::query::commit_transaction,
@@ -261,12 +327,27 @@ pub fn run() {
             commands::query::supports_transactions,
             // Mutation commands
             commands::mutation::insert_row,
+            commands::mutation::upsert_row,
             commands::mutation::update_row,
             commands::mutation::delete_row,
+            commands::mutation::truncate_table,
+            commands::mutation::undo_last_mutation,
             commands::mutation::supports_mutations,
+            commands::mutation::redis_set_ttl,
+            commands::mutation::redis_persist,
+            commands::mutation::redis_rename_key,
             // Maintenance commands
             commands::maintenance::list_maintenance_operations,
             commands::maintenance::run_maintenance,
+            // Diagnostics commands
+            commands::diagnostics::unused_indexes,
+            commands::diagnostics::bloat_report,
+            commands::diagnostics::redis_key_info,
+            commands::diagnostics::redis_big_keys,
+            commands::diagnostics::query_progress,
+            // A/B benchmark commands
+            commands::benchmark::ab_benchmark,
+            commands::benchmark::cancel_ab_benchmark,
             // Routine management commands
             commands::routines::get_routine_definition,
             commands::routines::drop_routine,
@@ -279,12 +360,21 @@ pub fn run() {
             // Sequence management commands
             commands::sequences::get_sequence_definition,
             commands::sequences::drop_sequence,
+            // Scheduled jobs
+            commands::scheduled_jobs::list_scheduled_jobs,
+            commands::scheduled_jobs::set_scheduled_job_enabled,
+            commands::scheduled_jobs::run_scheduled_job,
+            // Server log
+            commands::server_log::read_server_log,
+            commands::server_log::follow_server_log,
+            commands::server_log::stop_server_log_follow,
             // Logs
             commands::logs::export_logs,
             commands::logs::log_frontend_message,
             // Export
             commands::export::start_export,
             commands::export::cancel_export,
+            commands::export::export_via_copy,
             // Share
             commands::share::share_prepare_export,
             commands::share::share_cleanup_export,
@@ -298,8 +388,11 @@ pub fn run() {
             commands::import::import_csv,
             // Schema export
             commands::schema_export::export_schema,
+            commands::schema_export::export_er_diagram,
             // Metrics (dev-only)
             commands::metrics::get_metrics,
+            commands::metrics::get_metrics_config,
+            commands::metrics::set_metrics_config,
             // Vault commands
             commands::vault::get_vault_status,
             commands::vault::setup_master_password,
@@ -310,6 +403,8 @@ pub fn run() {
             commands::vault::delete_saved_connection,
             commands::vault::duplicate_saved_connection,
             commands::vault::get_connection_credentials,
+            commands::vault::export_connections,
+            commands::vault::import_connections,
             // Policy commands
             commands::policy::get_safety_policy,
             commands::policy::set_safety_policy,
@@ -326,6 +421,8 @@ pub fn run() {
             commands::sandbox::apply_sandbox_changes,
             // Full-text search
             commands::fulltext_search::fulltext_search,
+            commands::fulltext_search::fulltext_search_stream,
+            commands::fulltext_search::cancel_fulltext_search,
             // Confirmation tokens for destructive commands
             commands::confirmation::request_confirmation_token,
             // Interceptor commands
@@ -335,8 +432,10 @@ pub fn run() {
             commands::interceptor::get_audit_stats,
             commands::interceptor::clear_audit_log,
             commands::interceptor::export_audit_log,
+            commands::interceptor::verify_audit_export,
             commands::interceptor::get_profiling_metrics,
             commands::interceptor::get_slow_queries,
+            commands::interceptor::get_profiling_by_table,
             commands::interceptor::clear_slow_queries,
             commands::interceptor::reset_profiling,
             commands::interceptor::export_profiling,
@@ -344,6 +443,7 @@ pub fn run() {
             commands::interceptor::add_safety_rule,
             commands::interceptor::update_safety_rule,
             commands::interceptor::remove_safety_rule,
+            commands::interceptor::simulate_safety_rules,
             // Backup / Restore commands
             commands::backup::detect_backup_tools,
             commands::backup::set_backup_tool_path,
@@ -356,11 +456,26 @@ pub fn run() {
             commands::snapshots::get_snapshot,
             commands::snapshots::delete_snapshot,
             commands::snapshots::rename_snapshot,
+            // Query snippet commands
+            commands::snippets::list_snippets,
+            commands::snippets::save_snippet,
+            commands::snippets::delete_snippet,
+            commands::snippets::render_snippet,
+            // Local result cache commands
+            commands::local_cache::cache_result_locally,
+            commands::local_cache::refresh_local_cache,
+            commands::local_cache::query_local_cache,
+            commands::local_cache::list_local_caches,
+            commands::local_cache::drop_local_cache,
             // Virtual relations commands
             commands::virtual_relations::list_virtual_relations,
             commands::virtual_relations::add_virtual_relation,
             commands::virtual_relations::update_virtual_relation,
             commands::virtual_relations::delete_virtual_relation,
+            // Display transforms commands
+            commands::display_transforms::list_display_transforms,
+            commands::display_transforms::set_display_transform,
+            commands::display_transforms::remove_display_transform,
             // License commands
             commands::license::activate_license,
             commands::license::get_license_status,
@@ -371,6 +486,11 @@ pub fn run() {
             // Federation commands
             commands::federation::execute_federation_query,
             commands::federation::list_federation_sources,
+            commands::federation::plan_federation_query,
+            commands::federation::register_virtual_table,
+            commands::federation::refresh_virtual_table,
+            commands::federation::list_virtual_tables,
+            commands::federation::drop_virtual_table,
             // AI commands
             commands::ai::ai_generate_query,
             commands::ai::ai_explain_result,
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Local result cache.
+//!
+//! Streams a remote query into a table of a file-backed DuckDB database so the
+//! copy can be sliced offline without re-hitting the server. Editor results
+//! can also be cached by query id in a private in-memory database, see
+//! `ResultCache`.
+
+pub mod results;
+pub mod store;
+pub mod types;
+
+pub use results::{RecentQuery, ResultCache};
+pub use store::LocalCacheStore;
+pub use types::*;
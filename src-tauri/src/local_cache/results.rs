@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use crate::engine::error::{EngineError, EngineResult};
+use crate::engine::traits::DataEngine;
+use crate::engine::types::{
+    Namespace, PaginatedQueryResult, QueryId, SessionId, TableQueryOptions,
+};
+
+use super::store::LocalCacheStore;
+use super::types::LocalCacheEntry;
+
+/// Queries remembered for `cache`, oldest dropped first.
+const MAX_RECENT_QUERIES: usize = 64;
+
+/// Results kept materialized at once, oldest dropped first.
+const MAX_CACHED_RESULTS: usize = 16;
+
+/// A query run from the editor, kept so its result can be cached later.
+#[derive(Debug, Clone)]
+pub struct RecentQuery {
+    pub query_id: QueryId,
+    pub session: SessionId,
+    pub namespace: Option<Namespace>,
+    pub query: String,
+}
+
+/// Opt-in cache of editor results in a private in-memory DuckDB database.
+///
+/// Caching a query id loads its result once into a DuckDB table; sorting,
+/// filtering and paging then run against that table instead of the source
+/// database, until the handle is explicitly refreshed.
+pub struct ResultCache {
+    store: LocalCacheStore,
+    recent: Mutex<VecDeque<RecentQuery>>,
+    /// Cached handles with the session they were loaded from, oldest first.
+    handles: Mutex<VecDeque<(String, SessionId)>>,
+}
+
+impl ResultCache {
+    pub fn new() -> Self {
+        Self {
+            store: LocalCacheStore::in_memory(),
+            recent: Mutex::new(VecDeque::new()),
+            handles: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Records a successful read so `cache` can find it by id.
+    pub fn remember(&self, query: RecentQuery) {
+        let mut recent = self.recent.lock().unwrap();
+        recent.retain(|q| q.query_id != query.query_id);
+        recent.push_back(query);
+        while recent.len() > MAX_RECENT_QUERIES {
+            recent.pop_front();
+        }
+    }
+
+    pub fn recent(&self, query_id: QueryId) -> Option<RecentQuery> {
+        self.recent
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|q| q.query_id == query_id)
+            .cloned()
+    }
+
+    /// Session a cached handle was loaded from, used to refresh it.
+    pub fn session_for(&self, handle: &str) -> EngineResult<SessionId> {
+        self.handles
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(h, _)| h == handle)
+            .map(|(_, session)| *session)
+            .ok_or_else(|| EngineError::validation(format!("No cached result '{}'", handle)))
+    }
+
+    /// Loads the result of `query` into the cache. The returned entry's
+    /// `name` is the handle for `query` and `refresh`.
+    pub async fn cache(
+        &self,
+        driver: Arc<dyn DataEngine>,
+        query: RecentQuery,
+    ) -> EngineResult<LocalCacheEntry> {
+        let handle = format!("result_{}", query.query_id.0.simple());
+        let entry = self
+            .store
+            .cache_query(
+                driver,
+                query.session,
+                query.namespace,
+                &query.query,
+                &handle,
+                None,
+            )
+            .await?;
+
+        let evicted = {
+            let mut handles = self.handles.lock().unwrap();
+            handles.retain(|(h, _)| *h != handle);
+            handles.push_back((handle, query.session));
+            let excess = handles.len().saturating_sub(MAX_CACHED_RESULTS);
+            handles.drain(..excess).collect::<Vec<_>>()
+        };
+        for (handle, _) in evicted {
+            let _ = self.store.remove(&handle).await;
+        }
+
+        Ok(entry)
+    }
+
+    /// Re-runs the source query of `handle`, replacing the cached rows.
+    pub async fn refresh(
+        &self,
+        driver: Arc<dyn DataEngine>,
+        handle: &str,
+    ) -> EngineResult<LocalCacheEntry> {
+        let session = self.session_for(handle)?;
+        self.store.refresh(driver, session, handle, None).await
+    }
+
+    pub async fn get(&self, handle: &str) -> EngineResult<LocalCacheEntry> {
+        self.session_for(handle)?;
+        self.store.get(handle).await
+    }
+
+    /// Sorts, filters and pages a cached result inside DuckDB.
+    pub async fn query(
+        &self,
+        handle: &str,
+        options: TableQueryOptions,
+    ) -> EngineResult<PaginatedQueryResult> {
+        self.session_for(handle)?;
+        if options
+            .computed_columns
+            .as_ref()
+            .is_some_and(|c| !c.is_empty())
+        {
+            return Err(EngineError::not_supported(
+                "Computed columns are not supported on cached results",
+            ));
+        }
+        self.store.query_table(handle, options).await
+    }
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::drivers::sqlite::SqliteDriver;
+    use crate::engine::types::{ConnectionConfig, SortDirection, Value};
+
+    async fn seeded_sqlite() -> (Arc<dyn DataEngine>, SessionId) {
+        let driver: Arc<dyn DataEngine> = Arc::new(SqliteDriver::new());
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        for sql in [
+            "CREATE TABLE scores (name TEXT, score INTEGER)",
+            "INSERT INTO scores VALUES ('ada', 30), ('bob', 10), ('cy', 20)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (driver, session)
+    }
+
+    #[tokio::test]
+    async fn test_cached_result_is_resorted_in_duckdb() {
+        let cache = ResultCache::new();
+        let (driver, session) = seeded_sqlite().await;
+        let query_id = QueryId::new();
+        cache.remember(RecentQuery {
+            query_id,
+            session,
+            namespace: None,
+            query: "SELECT name, score FROM scores ORDER BY name".to_string(),
+        });
+
+        let recent = cache.recent(query_id).unwrap();
+        let entry = cache.cache(Arc::clone(&driver), recent).await.unwrap();
+        assert_eq!(entry.row_count, 3);
+
+        // Changes at the source stay invisible until an explicit refresh.
+        driver
+            .execute(session, "DELETE FROM scores", QueryId::new())
+            .await
+            .unwrap();
+
+        let options = TableQueryOptions {
+            sort_column: Some("score".to_string()),
+            sort_direction: Some(SortDirection::Desc),
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let page = cache.query(&entry.name, options).await.unwrap();
+        assert_eq!(page.total_rows, 3);
+        let names: Vec<_> = page
+            .result
+            .rows
+            .iter()
+            .map(|row| row.values[0].clone())
+            .collect();
+        assert!(matches!(&names[..], [Value::Text(a), Value::Text(b)] if a == "ada" && b == "cy"));
+
+        let refreshed = cache.refresh(driver, &entry.name).await.unwrap();
+        assert_eq!(refreshed.row_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_handle_is_rejected() {
+        let cache = ResultCache::new();
+        assert!(cache.recent(QueryId::new()).is_none());
+        assert!(cache
+            .query("result_missing", TableQueryOptions::default())
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,757 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use duckdb::{params, params_from_iter, types::Value as DuckValue, Connection};
+use tokio::time::timeout;
+
+use crate::engine::drivers::duckdb::{execute_select, value_to_duckdb, DuckDbDriver};
+use crate::engine::error::{EngineError, EngineResult};
+use crate::engine::traits::{DataEngine, StreamEvent};
+use crate::engine::types::{
+    ColumnInfo, Namespace, PaginatedQueryResult, QueryId, QueryResult, Row, SessionId,
+    TableQueryOptions, Value,
+};
+
+use super::types::LocalCacheEntry;
+
+/// Row cap applied when the caller does not pass one.
+pub const DEFAULT_MAX_ROWS: u64 = 1_000_000;
+
+/// Hard ceiling on the row cap, whatever the caller asks for.
+pub const MAX_ROWS_LIMIT: u64 = 10_000_000;
+
+/// Rows buffered from the stream before each bulk insert.
+const INSERT_BATCH_SIZE: usize = 1000;
+
+/// DuckDB path opening a private in-memory database.
+const IN_MEMORY_PATH: &str = ":memory:";
+
+const META_TABLE: &str = "__qoredb_cache_meta";
+const STAGING_PREFIX: &str = "__qoredb_loading_";
+
+/// File-backed DuckDB database holding cached remote results, one table per
+/// entry plus a metadata table describing where each one came from.
+///
+/// The connection is opened lazily and shared; DuckDB work runs on the
+/// blocking pool.
+pub struct LocalCacheStore {
+    path: PathBuf,
+    conn: Arc<Mutex<Option<Connection>>>,
+}
+
+impl LocalCacheStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        let _ = std::fs::create_dir_all(&data_dir);
+        Self {
+            path: data_dir.join("results.duckdb"),
+            conn: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Cache held in a private in-memory DuckDB database, gone on restart.
+    pub fn in_memory() -> Self {
+        Self {
+            path: PathBuf::from(IN_MEMORY_PATH),
+            conn: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Location of the cache database, e.g. to open it with the DuckDB driver.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Runs `query` on the remote session and (re)loads its rows into the
+    /// local table `name`.
+    ///
+    /// Rows are loaded into a staging table first and swapped in at the end,
+    /// so a failed refresh leaves the previous copy untouched.
+    pub async fn cache_query(
+        &self,
+        driver: Arc<dyn DataEngine>,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        name: &str,
+        max_rows: Option<u64>,
+    ) -> EngineResult<LocalCacheEntry> {
+        validate_name(name)?;
+        let max_rows = max_rows
+            .unwrap_or(DEFAULT_MAX_ROWS)
+            .clamp(1, MAX_ROWS_LIMIT);
+
+        let staging = format!("{STAGING_PREFIX}{name}");
+        let loaded = self
+            .stream_into_staging(
+                driver,
+                session,
+                namespace.clone(),
+                query,
+                &staging,
+                max_rows,
+            )
+            .await;
+
+        let (table, truncated) = match loaded {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                let drop_sql = format!("DROP TABLE IF EXISTS {}", quote_ident(&staging));
+                let _ = self
+                    .with_conn(move |conn| {
+                        conn.execute_batch(&drop_sql)
+                            .map_err(|e| EngineError::internal(e.to_string()))
+                    })
+                    .await;
+                return Err(err);
+            }
+        };
+
+        let entry = LocalCacheEntry {
+            name: name.to_string(),
+            source_query: query.to_string(),
+            namespace,
+            columns: table.local_columns(),
+            row_count: table.row_count,
+            truncated,
+            cached_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let stored = entry.clone();
+        self.with_conn(move |conn| publish(conn, &staging, &stored))
+            .await?;
+
+        Ok(entry)
+    }
+
+    /// Re-runs the query an entry was created from and replaces its rows.
+    pub async fn refresh(
+        &self,
+        driver: Arc<dyn DataEngine>,
+        session: SessionId,
+        name: &str,
+        max_rows: Option<u64>,
+    ) -> EngineResult<LocalCacheEntry> {
+        let entry = self.get(name).await?;
+        self.cache_query(
+            driver,
+            session,
+            entry.namespace,
+            &entry.source_query,
+            name,
+            max_rows,
+        )
+        .await
+    }
+
+    pub async fn get(&self, name: &str) -> EngineResult<LocalCacheEntry> {
+        validate_name(name)?;
+        let name = name.to_string();
+        self.with_conn(move |conn| {
+            read_entries(conn, Some(&name))?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    EngineError::validation(format!("No cached result named '{}'", name))
+                })
+        })
+        .await
+    }
+
+    /// All cached entries, most recently loaded first.
+    pub async fn list(&self) -> EngineResult<Vec<LocalCacheEntry>> {
+        self.with_conn(|conn| read_entries(conn, None)).await
+    }
+
+    pub async fn remove(&self, name: &str) -> EngineResult<()> {
+        validate_name(name)?;
+        let name = name.to_string();
+        self.with_conn(move |conn| {
+            let tx = conn
+                .unchecked_transaction()
+                .map_err(|e| EngineError::internal(format!("DuckDB transaction failed: {e}")))?;
+            tx.execute_batch(&format!("DROP TABLE IF EXISTS {}", quote_ident(&name)))
+                .map_err(|e| EngineError::internal(format!("Failed to drop '{name}': {e}")))?;
+            tx.execute(
+                &format!("DELETE FROM {META_TABLE} WHERE name = ?"),
+                params![name],
+            )
+            .map_err(|e| EngineError::internal(format!("Failed to update cache index: {e}")))?;
+            tx.commit()
+                .map_err(|e| EngineError::internal(format!("DuckDB commit failed: {e}")))
+        })
+        .await
+    }
+
+    /// Runs a query against the cache database. Offline: never touches the
+    /// remote connection.
+    pub async fn query(&self, sql: &str) -> EngineResult<QueryResult> {
+        let sql = sql.to_string();
+        self.with_conn(move |conn| execute_select(conn, &sql, Instant::now()))
+            .await
+    }
+
+    /// Filtered, sorted page of the cached table `name`, without touching the
+    /// remote connection.
+    pub async fn query_table(
+        &self,
+        name: &str,
+        options: TableQueryOptions,
+    ) -> EngineResult<PaginatedQueryResult> {
+        self.get(name).await?;
+        let name = name.to_string();
+        self.with_conn(move |conn| DuckDbDriver::query_table_in(conn, "main", &name, &options))
+            .await
+    }
+
+    async fn stream_into_staging(
+        &self,
+        driver: Arc<dyn DataEngine>,
+        session: SessionId,
+        namespace: Option<Namespace>,
+        query: &str,
+        staging: &str,
+        max_rows: u64,
+    ) -> EngineResult<(StagingTable, bool)> {
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
+        let query_id = QueryId::new();
+
+        let mut driver_task = tokio::spawn({
+            let driver = Arc::clone(&driver);
+            let query = query.to_string();
+            async move {
+                driver
+                    .execute_stream_in_namespace(session, namespace, &query, query_id, sender)
+                    .await
+            }
+        });
+
+        let mut table: Option<StagingTable> = None;
+        let mut pending: Vec<Row> = Vec::new();
+        let mut truncated = false;
+        let mut stream_error: Option<String> = None;
+
+        loop {
+            let done = match receiver.recv().await {
+                Some(StreamEvent::Columns(columns)) => {
+                    table = Some(StagingTable::new(staging, &columns));
+                    false
+                }
+                Some(StreamEvent::Row(row)) => {
+                    pending.push(row);
+                    false
+                }
+                Some(StreamEvent::RowBatch(batch)) => {
+                    pending.extend(batch);
+                    false
+                }
+                Some(StreamEvent::Error(err)) => {
+                    stream_error = Some(err);
+                    true
+                }
+                Some(StreamEvent::Truncated { .. }) => false,
+                Some(StreamEvent::Done(_)) | None => true,
+            };
+            if stream_error.is_some() {
+                break;
+            }
+
+            let loaded = table.as_ref().map(|t| t.row_count).unwrap_or(0);
+            if loaded + pending.len() as u64 > max_rows {
+                pending.truncate((max_rows - loaded) as usize);
+                truncated = true;
+            }
+
+            if !pending.is_empty() && (done || truncated || pending.len() >= INSERT_BATCH_SIZE) {
+                let Some(current) = table.take() else {
+                    stream_error = Some("Rows received before column metadata".to_string());
+                    break;
+                };
+                let rows = std::mem::take(&mut pending);
+                table = Some(self.with_conn(move |conn| current.load(conn, rows)).await?);
+            }
+
+            if done || truncated {
+                break;
+            }
+        }
+
+        // Dropping the receiver unblocks a driver still pushing rows.
+        drop(receiver);
+        if truncated || stream_error.is_some() {
+            let _ = driver.cancel(session, Some(query_id)).await;
+            if timeout(Duration::from_secs(2), &mut driver_task)
+                .await
+                .is_err()
+            {
+                driver_task.abort();
+            }
+        } else {
+            driver_task
+                .await
+                .map_err(|e| EngineError::internal(format!("Stream task failed: {e}")))??;
+        }
+
+        if let Some(err) = stream_error {
+            return Err(EngineError::execution_error(err));
+        }
+
+        let table = table
+            .ok_or_else(|| EngineError::validation("Query returned no result set to cache"))?;
+        // Zero-row results still get a (typed-as-VARCHAR) table.
+        let table = if table.created {
+            table
+        } else {
+            self.with_conn(move |conn| table.load(conn, Vec::new()))
+                .await?
+        };
+
+        Ok((table, truncated))
+    }
+
+    async fn with_conn<T, F>(&self, f: F) -> EngineResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> EngineResult<T> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut guard = conn
+                .lock()
+                .map_err(|_| EngineError::internal("Local cache lock poisoned"))?;
+            if guard.is_none() {
+                *guard = Some(open_cache_db(&path)?);
+            }
+            match guard.as_ref() {
+                Some(conn) => f(conn),
+                None => Err(EngineError::internal("Local cache is not open")),
+            }
+        })
+        .await
+        .map_err(|e| EngineError::internal(format!("Local cache task failed: {e}")))?
+    }
+}
+
+fn open_cache_db(path: &Path) -> EngineResult<Connection> {
+    let conn = Connection::open(path).map_err(|e| {
+        EngineError::internal(format!(
+            "Failed to open local cache '{}': {e}",
+            path.display()
+        ))
+    })?;
+    conn.execute_batch(&format!(
+        "CREATE TABLE IF NOT EXISTS {META_TABLE} (\
+            name VARCHAR PRIMARY KEY, \
+            source_query VARCHAR NOT NULL, \
+            namespace VARCHAR, \
+            columns VARCHAR NOT NULL, \
+            row_count BIGINT NOT NULL, \
+            truncated BOOLEAN NOT NULL, \
+            cached_at VARCHAR NOT NULL)"
+    ))
+    .map_err(|e| EngineError::internal(format!("Failed to initialize local cache: {e}")))?;
+    Ok(conn)
+}
+
+/// Swaps the staging table in under its final name and records the entry.
+fn publish(conn: &Connection, staging: &str, entry: &LocalCacheEntry) -> EngineResult<()> {
+    let namespace = entry
+        .namespace
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| EngineError::internal(e.to_string()))?;
+    let columns =
+        serde_json::to_string(&entry.columns).map_err(|e| EngineError::internal(e.to_string()))?;
+    let row_count = entry.row_count as i64;
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| EngineError::internal(format!("DuckDB transaction failed: {e}")))?;
+    tx.execute_batch(&format!(
+        "DROP TABLE IF EXISTS {target}; ALTER TABLE {staging} RENAME TO {target};",
+        target = quote_ident(&entry.name),
+        staging = quote_ident(staging),
+    ))
+    .map_err(|e| EngineError::internal(format!("Failed to publish '{}': {e}", entry.name)))?;
+    tx.execute(
+        &format!("DELETE FROM {META_TABLE} WHERE name = ?"),
+        params![entry.name],
+    )
+    .map_err(|e| EngineError::internal(format!("Failed to update cache index: {e}")))?;
+    tx.execute(
+        &format!("INSERT INTO {META_TABLE} VALUES (?, ?, ?, ?, ?, ?, ?)"),
+        params![
+            entry.name,
+            entry.source_query,
+            namespace,
+            columns,
+            row_count,
+            entry.truncated,
+            entry.cached_at,
+        ],
+    )
+    .map_err(|e| EngineError::internal(format!("Failed to update cache index: {e}")))?;
+    tx.commit()
+        .map_err(|e| EngineError::internal(format!("DuckDB commit failed: {e}")))
+}
+
+fn read_entries(conn: &Connection, name: Option<&str>) -> EngineResult<Vec<LocalCacheEntry>> {
+    let filter = if name.is_some() { "WHERE name = ?" } else { "" };
+    let sql = format!(
+        "SELECT name, source_query, namespace, columns, row_count, truncated, cached_at \
+         FROM {META_TABLE} {filter} ORDER BY cached_at DESC"
+    );
+    let params: Vec<&str> = name.into_iter().collect();
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| EngineError::internal(format!("Failed to read cache index: {e}")))?;
+    let rows = stmt
+        .query_map(params_from_iter(params), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, i64>(4)?,
+                row.get::<_, bool>(5)?,
+                row.get::<_, String>(6)?,
+            ))
+        })
+        .map_err(|e| EngineError::internal(format!("Failed to read cache index: {e}")))?;
+
+    let mut entries = Vec::new();
+    for row in rows {
+        let (name, source_query, namespace, columns, row_count, truncated, cached_at) =
+            row.map_err(|e| EngineError::internal(format!("Failed to read cache index: {e}")))?;
+        entries.push(LocalCacheEntry {
+            name,
+            source_query,
+            namespace: namespace.and_then(|ns| serde_json::from_str(&ns).ok()),
+            columns: serde_json::from_str(&columns).unwrap_or_default(),
+            row_count: row_count.max(0) as u64,
+            truncated,
+            cached_at,
+        });
+    }
+    Ok(entries)
+}
+
+/// Cache names become DuckDB table names: keep them to plain identifiers.
+fn validate_name(name: &str) -> EngineResult<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 63
+        && name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("__qoredb");
+    if valid {
+        Ok(())
+    } else {
+        Err(EngineError::validation(format!(
+            "Invalid cache name '{}': use letters, digits and underscores (max 63 chars)",
+            name
+        )))
+    }
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Column type inferred from the values seen so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LocalType {
+    /// Only NULLs so far; stored as VARCHAR until a value shows up.
+    Unknown,
+    Boolean,
+    BigInt,
+    Double,
+    Blob,
+    Varchar,
+}
+
+impl LocalType {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Unknown,
+            Value::Bool(_) => Self::Boolean,
+            Value::Int(_) => Self::BigInt,
+            Value::Float(_) => Self::Double,
+            Value::Bytes(_) => Self::Blob,
+            Value::Text(_) | Value::Json(_) | Value::Array(_) => Self::Varchar,
+        }
+    }
+
+    /// Widen to a type that holds both: integers and floats meet at DOUBLE,
+    /// anything else mixed falls back to VARCHAR.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Self::Unknown, t) | (t, Self::Unknown) => t,
+            (Self::BigInt, Self::Double) | (Self::Double, Self::BigInt) => Self::Double,
+            _ => Self::Varchar,
+        }
+    }
+
+    fn sql(self) -> &'static str {
+        match self {
+            Self::Unknown | Self::Varchar => "VARCHAR",
+            Self::Boolean => "BOOLEAN",
+            Self::BigInt => "BIGINT",
+            Self::Double => "DOUBLE",
+            Self::Blob => "BLOB",
+        }
+    }
+}
+
+/// Table being filled from the stream, with per-column inferred types.
+struct StagingTable {
+    name: String,
+    columns: Vec<String>,
+    types: Vec<LocalType>,
+    created: bool,
+    row_count: u64,
+}
+
+impl StagingTable {
+    fn new(name: &str, columns: &[ColumnInfo]) -> Self {
+        // Result sets may repeat a name (`SELECT a.id, b.id`); tables cannot.
+        let mut names: Vec<String> = Vec::with_capacity(columns.len());
+        for col in columns {
+            let base = if col.name.is_empty() {
+                format!("col_{}", names.len() + 1)
+            } else {
+                col.name.to_string()
+            };
+            let mut candidate = base.clone();
+            let mut n = 2;
+            while names.iter().any(|c| c.eq_ignore_ascii_case(&candidate)) {
+                candidate = format!("{base}_{n}");
+                n += 1;
+            }
+            names.push(candidate);
+        }
+
+        Self {
+            name: name.to_string(),
+            types: vec![LocalType::Unknown; names.len()],
+            columns: names,
+            created: false,
+            row_count: 0,
+        }
+    }
+
+    fn local_columns(&self) -> Vec<ColumnInfo> {
+        self.columns
+            .iter()
+            .zip(&self.types)
+            .map(|(name, ty)| ColumnInfo {
+                name: name.as_str().into(),
+                data_type: ty.sql().into(),
+                nullable: true,
+                case_insensitive: false,
+            })
+            .collect()
+    }
+
+    /// Creates the table on first call, widens column types the new rows do
+    /// not fit, then bulk-inserts them in one transaction.
+    fn load(mut self, conn: &Connection, rows: Vec<Row>) -> EngineResult<Self> {
+        if self.columns.is_empty() {
+            return Err(EngineError::validation(
+                "Query returned no columns to cache",
+            ));
+        }
+
+        let mut widened = self.types.clone();
+        for row in &rows {
+            for (ty, value) in widened.iter_mut().zip(&row.values) {
+                *ty = ty.merge(LocalType::of(value));
+            }
+        }
+
+        let table = quote_ident(&self.name);
+        if !self.created {
+            let defs: Vec<String> = self
+                .columns
+                .iter()
+                .zip(&widened)
+                .map(|(name, ty)| format!("{} {}", quote_ident(name), ty.sql()))
+                .collect();
+            conn.execute_batch(&format!(
+                "CREATE OR REPLACE TABLE {} ({})",
+                table,
+                defs.join(", ")
+            ))
+            .map_err(|e| EngineError::internal(format!("Failed to create cache table: {e}")))?;
+            self.created = true;
+        } else {
+            for ((name, old), new) in self.columns.iter().zip(&self.types).zip(&widened) {
+                if old.sql() != new.sql() {
+                    conn.execute_batch(&format!(
+                        "ALTER TABLE {} ALTER COLUMN {} TYPE {}",
+                        table,
+                        quote_ident(name),
+                        new.sql()
+                    ))
+                    .map_err(|e| {
+                        EngineError::internal(format!("Failed to widen column '{name}': {e}"))
+                    })?;
+                }
+            }
+        }
+        self.types = widened;
+
+        if rows.is_empty() {
+            return Ok(self);
+        }
+
+        let placeholders = vec!["?"; self.columns.len()].join(", ");
+        let sql = format!("INSERT INTO {} VALUES ({})", table, placeholders);
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| EngineError::internal(format!("DuckDB transaction failed: {e}")))?;
+        {
+            let mut stmt = tx
+                .prepare_cached(&sql)
+                .map_err(|e| EngineError::internal(format!("DuckDB prepare failed: {e}")))?;
+            for row in &rows {
+                let values: Vec<DuckValue> = (0..self.columns.len())
+                    .map(|i| {
+                        row.values
+                            .get(i)
+                            .map(value_to_duckdb)
+                            .unwrap_or(DuckValue::Null)
+                    })
+                    .collect();
+                stmt.execute(params_from_iter(values.iter()))
+                    .map_err(|e| EngineError::internal(format!("DuckDB insert failed: {e}")))?;
+            }
+        }
+        tx.commit()
+            .map_err(|e| EngineError::internal(format!("DuckDB commit failed: {e}")))?;
+
+        self.row_count += rows.len() as u64;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::drivers::sqlite::SqliteDriver;
+    use crate::engine::types::ConnectionConfig;
+    use tempfile::tempdir;
+
+    async fn seeded_sqlite() -> (Arc<dyn DataEngine>, SessionId) {
+        let driver: Arc<dyn DataEngine> = Arc::new(SqliteDriver::new());
+        let config = ConnectionConfig::sqlite_memory_for_tests();
+        let session = driver.connect(&config).await.unwrap();
+        for sql in [
+            "CREATE TABLE orders (id INTEGER, customer TEXT, total REAL)",
+            "INSERT INTO orders VALUES (1, 'ada', 10.5), (2, 'bob', 3), (3, 'ada', NULL)",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (driver, session)
+    }
+
+    #[tokio::test]
+    async fn test_cache_sqlite_result_into_duckdb() {
+        let dir = tempdir().unwrap();
+        let store = LocalCacheStore::new(dir.path().to_path_buf());
+        let (driver, session) = seeded_sqlite().await;
+
+        let entry = store
+            .cache_query(
+                Arc::clone(&driver),
+                session,
+                None,
+                "SELECT id, customer, total FROM orders ORDER BY id",
+                "orders_copy",
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(entry.row_count, 3);
+        assert!(!entry.truncated);
+        let types: Vec<&str> = entry.columns.iter().map(|c| c.data_type.as_str()).collect();
+        assert_eq!(types, ["BIGINT", "VARCHAR", "DOUBLE"]);
+
+        let result = store
+            .query("SELECT customer, count(*) AS n FROM orders_copy GROUP BY customer ORDER BY customer")
+            .await
+            .unwrap();
+        assert_eq!(result.rows.len(), 2);
+        assert!(matches!(&result.rows[0].values[0], Value::Text(s) if s == "ada"));
+        assert!(matches!(result.rows[0].values[1], Value::Int(2)));
+
+        let listed = store.list().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].source_query, entry.source_query);
+    }
+
+    #[tokio::test]
+    async fn test_cache_row_cap_and_refresh() {
+        let dir = tempdir().unwrap();
+        let store = LocalCacheStore::new(dir.path().to_path_buf());
+        let (driver, session) = seeded_sqlite().await;
+
+        let entry = store
+            .cache_query(
+                Arc::clone(&driver),
+                session,
+                None,
+                "SELECT * FROM orders",
+                "capped",
+                Some(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(entry.row_count, 2);
+        assert!(entry.truncated);
+
+        driver
+            .execute(
+                session,
+                "INSERT INTO orders VALUES (4, 'cy', 1)",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        let refreshed = store
+            .refresh(Arc::clone(&driver), session, "capped", Some(100))
+            .await
+            .unwrap();
+        assert_eq!(refreshed.row_count, 4);
+        assert!(!refreshed.truncated);
+
+        store.remove("capped").await.unwrap();
+        assert!(store.get("capped").await.is_err());
+        assert!(store.query("SELECT * FROM capped").await.is_err());
+    }
+
+    #[test]
+    fn test_cache_name_validation() {
+        assert!(validate_name("orders_2024").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("1abc").is_err());
+        assert!(validate_name("a;DROP TABLE x").is_err());
+        assert!(validate_name("__qoredb_cache_meta").is_err());
+    }
+
+    #[test]
+    fn test_local_type_widening() {
+        use LocalType::*;
+        assert_eq!(Unknown.merge(BigInt), BigInt);
+        assert_eq!(BigInt.merge(Double), Double);
+        assert_eq!(BigInt.merge(Varchar), Varchar);
+        assert_eq!(Boolean.merge(BigInt), Varchar);
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::types::{ColumnInfo, Namespace};
+
+/// A remote result materialized as a table in the local cache database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalCacheEntry {
+    /// Table name inside the cache database; also the handle used to refresh
+    /// or drop the entry.
+    pub name: String,
+    /// Query that produced the cached rows, re-run on refresh.
+    pub source_query: String,
+    pub namespace: Option<Namespace>,
+    /// Columns with the DuckDB types inferred while loading.
+    pub columns: Vec<ColumnInfo>,
+    pub row_count: u64,
+    /// `true` when the remote result was cut off at the row cap.
+    pub truncated: bool,
+    /// ISO 8601 timestamp of the last (re)load.
+    pub cached_at: String,
+}
@@ -15,5 +15,11 @@ fn main() {
         std::env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
     }
 
+    // `qoredb export ...` streams a query result to stdout without a window.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some(qoredb_lib::headless::EXPORT_COMMAND) {
+        std::process::exit(qoredb_lib::headless::run(&args[1..]));
+    }
+
     qoredb_lib::run()
 }
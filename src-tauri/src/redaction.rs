@@ -63,6 +63,17 @@ pub fn is_sensitive_column(name: &str) -> bool {
         .any(|token| normalized.contains(&normalize(token)))
 }
 
+/// Returns `true` if the column name contains one of the user-supplied
+/// `patterns`, compared the same separator/case-insensitive way as the
+/// built-in list. Blank patterns never match.
+pub fn matches_column_pattern(name: &str, patterns: &[String]) -> bool {
+    let normalized = normalize(name);
+    patterns.iter().any(|pattern| {
+        let pattern = normalize(pattern);
+        !pattern.is_empty() && normalized.contains(&pattern)
+    })
+}
+
 /// The default sensitive-column list for the user-configurable Time-Travel
 /// config. Same canonical set as [`is_sensitive_column`].
 pub fn default_sensitive_columns() -> Vec<String> {
@@ -111,6 +122,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn user_patterns_match_case_and_separator_insensitively() {
+        let patterns = vec!["full_name".to_string(), " ".to_string()];
+        assert!(matches_column_pattern("FullName", &patterns));
+        assert!(matches_column_pattern("customer_full_name", &patterns));
+        assert!(!matches_column_pattern("id", &patterns));
+    }
+
     #[test]
     fn ignores_benign_columns() {
         for benign in ["id", "name", "created_at", "username", "first_name"] {
@@ -4,7 +4,7 @@ mod manager;
 pub mod types;
 
 use crate::engine::types::QueryResult;
-use crate::export::types::ExportFormat;
+use crate::export::types::{CsvOptions, ExportFormat, SqlInsertOptions};
 use crate::export::writers::create_writer;
 
 pub use manager::ShareManager;
@@ -23,6 +23,9 @@ pub async fn write_query_result_to_file(
         format,
         output_path,
         include_headers,
+        false,
+        &CsvOptions::default(),
+        &SqlInsertOptions::default(),
         table_name,
         namespace,
         driver_id,
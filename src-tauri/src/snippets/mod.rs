@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named query snippets: reusable SQL with `{{param}}` placeholders, filled
+//! in and escaped for the snippet's driver right before it runs.
+
+pub mod render;
+pub mod store;
+pub mod types;
+
+pub use render::{placeholders, render};
+pub use store::SnippetStore;
+pub use types::*;
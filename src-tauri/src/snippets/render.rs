@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Placeholder substitution. A placeholder is `{{name}}` (inner whitespace
+//! allowed) where `name` is an identifier; any other `{{...}}` is left as is.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::engine::sql_generator::SqlDialect;
+use crate::engine::types::Value;
+
+use super::types::{Snippet, SnippetParamKind};
+
+struct Placeholder<'a> {
+    name: &'a str,
+    /// Byte range of the whole `{{...}}` in the body
+    range: Range<usize>,
+}
+
+pub(crate) fn is_param_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn scan(body: &str) -> Vec<Placeholder<'_>> {
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(open) = body[pos..].find("{{") {
+        let start = pos + open;
+        let Some(close) = body[start + 2..].find("}}") else {
+            break;
+        };
+        let name = body[start + 2..start + 2 + close].trim();
+        if is_param_name(name) {
+            let end = start + 2 + close + 2;
+            found.push(Placeholder {
+                name,
+                range: start..end,
+            });
+            pos = end;
+        } else {
+            pos = start + 2;
+        }
+    }
+    found
+}
+
+/// Distinct placeholder names of `body`, in order of first use.
+pub fn placeholders(body: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for placeholder in scan(body) {
+        if !names.iter().any(|n| n == placeholder.name) {
+            names.push(placeholder.name.to_string());
+        }
+    }
+    names
+}
+
+/// SQL dialect whose quoting rules apply to `driver_id`.
+fn dialect_for_driver(driver_id: &str) -> Option<SqlDialect> {
+    SqlDialect::from_driver_id(driver_id).or_else(|| {
+        match driver_id.to_ascii_lowercase().as_str() {
+            "cockroachdb" | "neon" | "supabase" | "timescaledb" => Some(SqlDialect::Postgres),
+            _ => None,
+        }
+    })
+}
+
+/// Replaces every placeholder of the snippet body with its value from
+/// `values`, or the parameter's default, escaped for the snippet's driver.
+/// Fails naming every placeholder that has neither.
+pub fn render(snippet: &Snippet, values: &HashMap<String, Value>) -> Result<String, String> {
+    let dialect = dialect_for_driver(&snippet.driver_id).ok_or_else(|| {
+        format!(
+            "Snippets are not supported for driver '{}'",
+            snippet.driver_id
+        )
+    })?;
+    let param = |name: &str| snippet.params.iter().find(|p| p.name == name);
+    let value_of = |name: &str| {
+        values
+            .get(name)
+            .or_else(|| param(name).and_then(|p| p.default.as_ref()))
+    };
+
+    let missing: Vec<String> = placeholders(&snippet.body)
+        .into_iter()
+        .filter(|name| value_of(name).is_none())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Missing value for parameter(s): {}",
+            missing.join(", ")
+        ));
+    }
+
+    let body = &snippet.body;
+    let mut sql = String::with_capacity(body.len());
+    let mut copied = 0;
+    for placeholder in scan(body) {
+        sql.push_str(&body[copied..placeholder.range.start]);
+        let value = value_of(placeholder.name)
+            .ok_or_else(|| format!("Missing value for parameter '{}'", placeholder.name))?;
+        let kind = param(placeholder.name).map(|p| p.kind).unwrap_or_default();
+        match (kind, value) {
+            (SnippetParamKind::Value, value) => sql.push_str(&dialect.format_value(value)),
+            (SnippetParamKind::Identifier, Value::Text(name)) => {
+                sql.push_str(&dialect.quote_ident(name))
+            }
+            (SnippetParamKind::Identifier, _) => {
+                return Err(format!(
+                    "Parameter '{}' is an identifier and needs a text value",
+                    placeholder.name
+                ));
+            }
+        }
+        copied = placeholder.range.end;
+    }
+    sql.push_str(&body[copied..]);
+    Ok(sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snippets::types::SnippetParam;
+
+    fn snippet(driver_id: &str, body: &str, params: Vec<SnippetParam>) -> Snippet {
+        Snippet {
+            id: String::new(),
+            name: "test".to_string(),
+            body: body.to_string(),
+            driver_id: driver_id.to_string(),
+            params,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn param(name: &str, kind: SnippetParamKind) -> SnippetParam {
+        SnippetParam {
+            name: name.to_string(),
+            kind,
+            description: None,
+            default: None,
+        }
+    }
+
+    fn values(pairs: &[(&str, Value)]) -> HashMap<String, Value> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn substitutes_and_escapes_values() {
+        let snippet = snippet(
+            "postgres",
+            "SELECT * FROM {{ table }} WHERE owner = {{owner}} AND age > {{min_age}} OR alias = {{owner}}",
+            vec![
+                param("table", SnippetParamKind::Identifier),
+                param("owner", SnippetParamKind::Value),
+                param("min_age", SnippetParamKind::Value),
+            ],
+        );
+        let sql = render(
+            &snippet,
+            &values(&[
+                ("table", Value::Text("user \"accounts\"".to_string())),
+                ("owner", Value::Text("O'Brien".to_string())),
+                ("min_age", Value::Int(30)),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"user \"\"accounts\"\"\" WHERE owner = 'O''Brien' AND age > 30 OR alias = 'O''Brien'"
+        );
+    }
+
+    #[test]
+    fn escapes_per_driver_dialect() {
+        let body = "SELECT {{name}} FROM {{tbl}}";
+        let params = vec![
+            param("name", SnippetParamKind::Value),
+            param("tbl", SnippetParamKind::Identifier),
+        ];
+        let values = values(&[
+            ("name", Value::Text(r"it's a \ test".to_string())),
+            ("tbl", Value::Text("t".to_string())),
+        ]);
+
+        let mysql = render(&snippet("mysql", body, params.clone()), &values).unwrap();
+        assert_eq!(mysql, r"SELECT 'it''s a \\ test' FROM `t`");
+        let mssql = render(&snippet("sqlserver", body, params), &values).unwrap();
+        assert_eq!(mssql, r"SELECT N'it''s a \ test' FROM [t]");
+    }
+
+    #[test]
+    fn reports_every_missing_parameter() {
+        let mut with_default = param("limit", SnippetParamKind::Value);
+        with_default.default = Some(Value::Int(10));
+        let snippet = snippet(
+            "sqlite",
+            "SELECT {{a}}, {{b}}, {{a}} LIMIT {{limit}}",
+            vec![with_default],
+        );
+
+        let err = render(&snippet, &HashMap::new()).unwrap_err();
+        assert_eq!(err, "Missing value for parameter(s): a, b");
+
+        let sql = render(
+            &snippet,
+            &values(&[("a", Value::Int(1)), ("b", Value::Null)]),
+        )
+        .unwrap();
+        assert_eq!(sql, "SELECT 1, NULL, 1 LIMIT 10");
+    }
+
+    #[test]
+    fn leaves_non_placeholder_braces_alone() {
+        assert_eq!(
+            placeholders("SELECT '{{}}', '{{not a name}}', {{x}}, {{ x }}, {{y_2}}"),
+            ["x", "y_2"]
+        );
+        assert!(render(&snippet("redis", "GET {{k}}", vec![]), &HashMap::new()).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON-backed snippet library at `<data_dir>/snippets.json`, written
+//! atomically on every change.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use super::render::{is_param_name, placeholders};
+use super::types::Snippet;
+
+const STORE_FILE: &str = "snippets.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreFile {
+    #[serde(default)]
+    snippets: Vec<Snippet>,
+}
+
+pub struct SnippetStore {
+    path: PathBuf,
+    snippets: RwLock<Vec<Snippet>>,
+}
+
+impl SnippetStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        let _ = fs::create_dir_all(&data_dir);
+        let path = data_dir.join(STORE_FILE);
+        let snippets = match fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str::<StoreFile>(&content) {
+                Ok(file) => file.snippets,
+                Err(e) => {
+                    warn!("Failed to parse snippet library: {}", e);
+                    Vec::new()
+                }
+            },
+            Err(_) => Vec::new(),
+        };
+        Self {
+            path,
+            snippets: RwLock::new(snippets),
+        }
+    }
+
+    /// All snippets, sorted by name.
+    pub fn list(&self) -> Vec<Snippet> {
+        let mut snippets = self.snippets.read().clone();
+        snippets.sort_by_key(|s| s.name.to_lowercase());
+        snippets
+    }
+
+    pub fn get(&self, id: &str) -> Option<Snippet> {
+        self.snippets.read().iter().find(|s| s.id == id).cloned()
+    }
+
+    /// Adds `snippet` when its id is empty, otherwise replaces the stored
+    /// snippet with that id.
+    pub fn save(&self, mut snippet: Snippet) -> Result<Snippet, String> {
+        validate(&snippet)?;
+        let now = Utc::now().to_rfc3339();
+        let mut guard = self.snippets.write();
+        let mut snippets = guard.clone();
+
+        if snippet.id.is_empty() {
+            snippet.id = Uuid::new_v4().to_string();
+            snippet.created_at = now.clone();
+            snippet.updated_at = now;
+            snippets.push(snippet.clone());
+        } else {
+            let stored = snippets
+                .iter_mut()
+                .find(|s| s.id == snippet.id)
+                .ok_or_else(|| "Snippet not found".to_string())?;
+            snippet.created_at = stored.created_at.clone();
+            snippet.updated_at = now;
+            *stored = snippet.clone();
+        }
+
+        self.flush(&snippets)?;
+        *guard = snippets;
+        Ok(snippet)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), String> {
+        let mut guard = self.snippets.write();
+        let mut snippets = guard.clone();
+        let before = snippets.len();
+        snippets.retain(|s| s.id != id);
+        if snippets.len() == before {
+            return Err("Snippet not found".to_string());
+        }
+        self.flush(&snippets)?;
+        *guard = snippets;
+        Ok(())
+    }
+
+    fn flush(&self, snippets: &[Snippet]) -> Result<(), String> {
+        let file = StoreFile {
+            snippets: snippets.to_vec(),
+        };
+        let bytes = serde_json::to_vec_pretty(&file)
+            .map_err(|e| format!("Failed to serialize snippets: {}", e))?;
+        crate::atomic_write::write_atomic(&self.path, &bytes)
+            .map_err(|e| format!("Failed to write snippets: {}", e))
+    }
+}
+
+/// A snippet needs a name and a body, parameter names must be unique
+/// identifiers, and every placeholder must be a declared parameter.
+fn validate(snippet: &Snippet) -> Result<(), String> {
+    if snippet.name.trim().is_empty() {
+        return Err("Snippet name cannot be empty".to_string());
+    }
+    if snippet.body.trim().is_empty() {
+        return Err("Snippet body cannot be empty".to_string());
+    }
+
+    let mut declared = HashSet::new();
+    for param in &snippet.params {
+        if !is_param_name(&param.name) {
+            return Err(format!("Invalid parameter name '{}'", param.name));
+        }
+        if !declared.insert(param.name.as_str()) {
+            return Err(format!("Duplicate parameter '{}'", param.name));
+        }
+    }
+    if let Some(undeclared) = placeholders(&snippet.body)
+        .into_iter()
+        .find(|name| !declared.contains(name.as_str()))
+    {
+        return Err(format!(
+            "Placeholder '{{{{{}}}}}' has no matching parameter",
+            undeclared
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snippets::types::{SnippetParam, SnippetParamKind};
+    use tempfile::TempDir;
+
+    fn snippet(body: &str, params: &[&str]) -> Snippet {
+        Snippet {
+            id: String::new(),
+            name: "Locks by table".to_string(),
+            body: body.to_string(),
+            driver_id: "postgres".to_string(),
+            params: params
+                .iter()
+                .map(|name| SnippetParam {
+                    name: name.to_string(),
+                    kind: SnippetParamKind::Value,
+                    description: None,
+                    default: None,
+                })
+                .collect(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn save_update_delete_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let store = SnippetStore::new(tmp.path().to_path_buf());
+
+        let saved = store
+            .save(snippet(
+                "SELECT * FROM pg_locks WHERE relation = {{rel}}::regclass",
+                &["rel"],
+            ))
+            .unwrap();
+        assert!(Uuid::parse_str(&saved.id).is_ok());
+
+        let mut renamed = saved.clone();
+        renamed.name = "Locks".to_string();
+        store.save(renamed).unwrap();
+
+        let reloaded = SnippetStore::new(tmp.path().to_path_buf());
+        let listed = reloaded.list();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].name, "Locks");
+        assert_eq!(listed[0].created_at, saved.created_at);
+
+        reloaded.delete(&saved.id).unwrap();
+        assert!(reloaded.get(&saved.id).is_none());
+        assert!(reloaded.delete(&saved.id).is_err());
+    }
+
+    #[test]
+    fn rejects_undeclared_placeholders() {
+        let tmp = TempDir::new().unwrap();
+        let store = SnippetStore::new(tmp.path().to_path_buf());
+
+        let err = store
+            .save(snippet("SELECT {{a}}, {{b}}", &["a"]))
+            .unwrap_err();
+        assert_eq!(err, "Placeholder '{{b}}' has no matching parameter");
+        assert!(store.save(snippet("SELECT 1", &["a", "a"])).is_err());
+        assert!(store.save(snippet("SELECT 1", &["bad name"])).is_err());
+        assert!(store.list().is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::types::Value;
+
+/// How a parameter value is written into the SQL.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnippetParamKind {
+    /// A literal: text is quoted and escaped, numbers are written as is.
+    #[default]
+    Value,
+    /// A quoted identifier, such as a table or column name.
+    Identifier,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetParam {
+    /// Name used in the `{{name}}` placeholder.
+    pub name: String,
+    #[serde(default)]
+    pub kind: SnippetParamKind,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Used when no value is given for the parameter.
+    #[serde(default)]
+    pub default: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snippet {
+    /// UUID v4, assigned on first save.
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    /// SQL with `{{param}}` placeholders.
+    pub body: String,
+    pub driver_id: String,
+    #[serde(default)]
+    pub params: Vec<SnippetParam>,
+    /// ISO 8601 timestamps, set by the store.
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
@@ -13,7 +13,8 @@ use uuid::Uuid;
 
 use crate::engine::traits::DataEngine;
 use crate::engine::types::{
-    ColumnFilter, FilterOperator, Namespace, RowData, SessionId, TableQueryOptions, Value,
+    ColumnFilter, FilterOperator, Namespace, QueryResult, RowData, SessionId, TableQueryOptions,
+    Value,
 };
 
 use super::types::{ChangeOperation, ChangelogEntry};
@@ -29,6 +30,27 @@ pub async fn fetch_row_by_pk(
     table: &str,
     primary_key: &RowData,
 ) -> Option<HashMap<String, serde_json::Value>> {
+    let result = fetch_rows_by_pk(driver, session_id, namespace, table, primary_key).await?;
+    first_row_to_map(&result)
+}
+
+/// First row of a `fetch_rows_by_pk` result as a JSON column map.
+pub fn first_row_to_map(result: &QueryResult) -> Option<HashMap<String, serde_json::Value>> {
+    result
+        .rows
+        .first()
+        .map(|first_row| row_to_map(&result.columns, &first_row.values))
+}
+
+/// Like `fetch_row_by_pk`, but keeps the driver's `QueryResult` shape so the
+/// row can be shown as a result set. Same best-effort semantics.
+pub async fn fetch_rows_by_pk(
+    driver: &Arc<dyn DataEngine>,
+    session_id: SessionId,
+    namespace: &Namespace,
+    table: &str,
+    primary_key: &RowData,
+) -> Option<QueryResult> {
     let filters: Vec<ColumnFilter> = primary_key
         .columns
         .iter()
@@ -51,6 +73,7 @@ pub async fn fetch_row_by_pk(
         sort_direction: None,
         filters: Some(filters),
         search: None,
+        computed_columns: None,
     };
 
     // 2s ceiling so a slow driver can't stall the mutation path.
@@ -61,24 +84,17 @@ pub async fn fetch_row_by_pk(
     .await;
 
     match result {
-        Ok(Ok(paginated)) => {
-            if let Some(first_row) = paginated.result.rows.first() {
-                let columns = &paginated.result.columns;
-                Some(row_to_map(columns, &first_row.values))
-            } else {
-                None
-            }
-        }
+        Ok(Ok(paginated)) => Some(paginated.result),
         Ok(Err(e)) => {
             warn!(
-                "Time-travel: failed to fetch before-image for {}.{}: {}",
+                "Failed to fetch row by primary key for {}.{}: {}",
                 namespace.database, table, e
             );
             None
         }
         Err(_) => {
             warn!(
-                "Time-travel: before-image fetch timed out for {}.{}",
+                "Row fetch by primary key timed out for {}.{}",
                 namespace.database, table
             );
             None
@@ -0,0 +1,482 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Undo for single-row edits made through the data grid.
+//!
+//! Each successful `insert_row` / `update_row` / `delete_row` pushes its
+//! inverse onto a bounded per-session stack; `undo_last_mutation` pops the
+//! most recent entry and applies it through the driver's own row methods.
+//! Best-effort: an entry is refused (and dropped) once it is older than
+//! `UNDO_WINDOW` or the table's columns no longer match what was captured.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::engine::traits::DataEngine;
+use crate::engine::types::{Namespace, QueryResult, RowData, SessionId};
+
+/// Entries kept per session; older ones fall off the bottom.
+pub const MAX_UNDO_DEPTH: usize = 20;
+
+/// How long after an edit it can still be undone.
+pub const UNDO_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+/// The inverse of a recorded mutation.
+#[derive(Debug, Clone)]
+pub enum UndoAction {
+    /// Undo an insert: delete the inserted row by its primary key.
+    Delete { row: RowData },
+    /// Undo an update: write the previous values back.
+    Restore {
+        primary_key: RowData,
+        before: RowData,
+    },
+    /// Undo a delete: insert the full row again.
+    Reinsert { row: RowData },
+}
+
+#[derive(Debug, Clone)]
+pub struct UndoEntry {
+    pub namespace: Namespace,
+    pub table: String,
+    pub action: UndoAction,
+    /// Table columns when the row was captured; `None` for inserts, where
+    /// only the supplied columns are known.
+    pub columns: Option<Vec<String>>,
+    recorded_at: Instant,
+}
+
+impl UndoEntry {
+    pub fn new(
+        namespace: Namespace,
+        table: impl Into<String>,
+        action: UndoAction,
+        columns: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            namespace,
+            table: table.into(),
+            action,
+            columns,
+            recorded_at: Instant::now(),
+        }
+    }
+
+    /// Undo entry for an insert of `data`.
+    pub fn for_insert(namespace: Namespace, table: &str, data: &RowData) -> Self {
+        Self::new(
+            namespace,
+            table,
+            UndoAction::Delete { row: data.clone() },
+            None,
+        )
+    }
+
+    /// Undo entry for an update, restoring only the columns `data` touched.
+    /// `before` is the row fetched by primary key prior to the update.
+    pub fn for_update(
+        namespace: Namespace,
+        table: &str,
+        primary_key: &RowData,
+        data: &RowData,
+        before: &QueryResult,
+    ) -> Option<Self> {
+        let (row, columns) = captured_row(before)?;
+        let mut restore = RowData::new();
+        for column in data.columns.keys() {
+            restore
+                .columns
+                .insert(column.clone(), row.columns.get(column)?.clone());
+        }
+
+        // The update may itself have changed key columns.
+        let mut key_after = primary_key.clone();
+        for (column, value) in key_after.columns.iter_mut() {
+            if let Some(updated) = data.columns.get(column) {
+                *value = updated.clone();
+            }
+        }
+
+        Some(Self::new(
+            namespace,
+            table,
+            UndoAction::Restore {
+                primary_key: key_after,
+                before: restore,
+            },
+            Some(columns),
+        ))
+    }
+
+    /// Undo entry for a delete. `before` is the full row fetched by primary
+    /// key prior to the delete.
+    pub fn for_delete(namespace: Namespace, table: &str, before: &QueryResult) -> Option<Self> {
+        let (row, columns) = captured_row(before)?;
+        Some(Self::new(
+            namespace,
+            table,
+            UndoAction::Reinsert { row },
+            Some(columns),
+        ))
+    }
+
+    /// The original mutation this entry reverses.
+    pub fn operation(&self) -> &'static str {
+        match self.action {
+            UndoAction::Delete { .. } => "insert",
+            UndoAction::Restore { .. } => "update",
+            UndoAction::Reinsert { .. } => "delete",
+        }
+    }
+}
+
+/// First row of a primary-key lookup as `RowData`, with the column names.
+fn captured_row(result: &QueryResult) -> Option<(RowData, Vec<String>)> {
+    let first = result.rows.first()?;
+    let columns: Vec<String> = result.columns.iter().map(|c| c.name.to_string()).collect();
+    let row = RowData {
+        columns: columns
+            .iter()
+            .cloned()
+            .zip(first.values.iter().cloned())
+            .collect(),
+    };
+    Some((row, columns))
+}
+
+/// Bounded per-session undo stacks.
+#[derive(Default)]
+pub struct MutationUndoStore {
+    stacks: Mutex<HashMap<SessionId, VecDeque<UndoEntry>>>,
+}
+
+impl MutationUndoStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, session: SessionId, entry: UndoEntry) {
+        let mut stacks = self.stacks.lock().expect("undo store mutex poisoned");
+        let stack = stacks.entry(session).or_default();
+        stack.push_back(entry);
+        while stack.len() > MAX_UNDO_DEPTH {
+            stack.pop_front();
+        }
+    }
+
+    pub fn pop(&self, session: SessionId) -> Option<UndoEntry> {
+        let mut stacks = self.stacks.lock().expect("undo store mutex poisoned");
+        stacks.get_mut(&session).and_then(VecDeque::pop_back)
+    }
+
+    /// The entry `pop` would return, without removing it.
+    pub fn peek(&self, session: SessionId) -> Option<UndoEntry> {
+        let stacks = self.stacks.lock().expect("undo store mutex poisoned");
+        stacks.get(&session).and_then(|stack| stack.back().cloned())
+    }
+
+    pub fn depth(&self, session: SessionId) -> usize {
+        let stacks = self.stacks.lock().expect("undo store mutex poisoned");
+        stacks.get(&session).map_or(0, VecDeque::len)
+    }
+
+    pub fn clear_session(&self, session: SessionId) {
+        let mut stacks = self.stacks.lock().expect("undo store mutex poisoned");
+        stacks.remove(&session);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UndoOutcome {
+    pub table: String,
+    /// Operation that was reversed: `insert`, `update` or `delete`
+    pub operation: &'static str,
+    /// Entries still on the session's stack
+    pub remaining: usize,
+}
+
+/// Pops the most recent entry for `session` and applies its inverse.
+pub async fn undo_last(
+    store: &MutationUndoStore,
+    driver: &Arc<dyn DataEngine>,
+    session: SessionId,
+) -> Result<UndoOutcome, String> {
+    let entry = store
+        .pop(session)
+        .ok_or_else(|| "Nothing to undo".to_string())?;
+    apply(driver, session, &entry).await?;
+    Ok(UndoOutcome {
+        table: entry.table.clone(),
+        operation: entry.operation(),
+        remaining: store.depth(session),
+    })
+}
+
+async fn apply(
+    driver: &Arc<dyn DataEngine>,
+    session: SessionId,
+    entry: &UndoEntry,
+) -> Result<(), String> {
+    if entry.recorded_at.elapsed() > UNDO_WINDOW {
+        return Err(format!(
+            "The last {} on {} is older than {} minutes and can no longer be undone",
+            entry.operation(),
+            entry.table,
+            UNDO_WINDOW.as_secs() / 60
+        ));
+    }
+
+    let schema = driver
+        .describe_table(session, &entry.namespace, &entry.table)
+        .await
+        .map_err(|e| e.sanitized_message())?;
+    let current: HashSet<&str> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+    if let Some(captured) = &entry.columns {
+        let captured: HashSet<&str> = captured.iter().map(String::as_str).collect();
+        if captured != current {
+            return Err(format!(
+                "The columns of {} changed since the edit; refusing to undo",
+                entry.table
+            ));
+        }
+    }
+
+    let ns = &entry.namespace;
+    let table = entry.table.as_str();
+    let result = match &entry.action {
+        UndoAction::Delete { row } => {
+            let key_columns = schema
+                .primary_key
+                .filter(|pk| !pk.is_empty())
+                .ok_or_else(|| format!("{} has no primary key; cannot undo the insert", table))?;
+            let mut key = RowData::new();
+            for column in key_columns {
+                let value = row.columns.get(&column).ok_or_else(|| {
+                    format!(
+                        "The inserted row's {} was generated by the database; cannot undo the insert",
+                        column
+                    )
+                })?;
+                key.columns.insert(column, value.clone());
+            }
+            driver.delete_row(session, ns, table, &key).await
+        }
+        UndoAction::Restore {
+            primary_key,
+            before,
+        } => {
+            if before.columns.keys().any(|c| !current.contains(c.as_str())) {
+                return Err(format!(
+                    "The columns of {} changed since the edit; refusing to undo",
+                    table
+                ));
+            }
+            driver
+                .update_row(session, ns, table, primary_key, before)
+                .await
+        }
+        UndoAction::Reinsert { row } => driver.insert_row(session, ns, table, row).await,
+    }
+    .map_err(|e| e.sanitized_message())?;
+
+    if result.affected_rows == Some(0) {
+        return Err(format!(
+            "The row in {} no longer exists; nothing was undone",
+            table
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::types::{ConnectionConfig, QueryId, Value};
+    use qore_service::ServiceContext;
+
+    fn main_ns() -> Namespace {
+        Namespace {
+            database: "main".to_string(),
+            schema: None,
+        }
+    }
+
+    fn key(id: i64) -> RowData {
+        RowData::new().with_column("id", Value::Int(id))
+    }
+
+    async fn seeded() -> (ServiceContext, SessionId, Arc<dyn DataEngine>) {
+        let ctx = ServiceContext::new();
+        let session = ctx
+            .session_manager
+            .connect(ConnectionConfig::sqlite_memory_for_tests())
+            .await
+            .unwrap();
+        let driver = ctx.session_manager.get_driver(session).await.unwrap();
+        for sql in [
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT)",
+            "INSERT INTO users VALUES (1, 'ada', 'ada@example.com'), (2, 'bob', 'bob@example.com')",
+        ] {
+            driver.execute(session, sql, QueryId::new()).await.unwrap();
+        }
+        (ctx, session, driver)
+    }
+
+    async fn fetch(driver: &Arc<dyn DataEngine>, session: SessionId, id: i64) -> QueryResult {
+        crate::time_travel::capture::fetch_rows_by_pk(
+            driver,
+            session,
+            &main_ns(),
+            "users",
+            &key(id),
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn names(driver: &Arc<dyn DataEngine>, session: SessionId) -> Vec<String> {
+        let result = driver
+            .execute(
+                session,
+                "SELECT name FROM users ORDER BY id",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+        result
+            .rows
+            .iter()
+            .map(|row| match &row.values[0] {
+                Value::Text(name) => name.clone(),
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn undoes_an_update() {
+        let (_ctx, session, driver) = seeded().await;
+        let store = MutationUndoStore::new();
+
+        let data = RowData::new().with_column("name", Value::Text("ada lovelace".into()));
+        let before = fetch(&driver, session, 1).await;
+        driver
+            .update_row(session, &main_ns(), "users", &key(1), &data)
+            .await
+            .unwrap();
+        store.push(
+            session,
+            UndoEntry::for_update(main_ns(), "users", &key(1), &data, &before).unwrap(),
+        );
+        assert_eq!(names(&driver, session).await, ["ada lovelace", "bob"]);
+
+        let outcome = undo_last(&store, &driver, session).await.unwrap();
+        assert_eq!(outcome.operation, "update");
+        assert_eq!(outcome.remaining, 0);
+        assert_eq!(names(&driver, session).await, ["ada", "bob"]);
+        assert!(undo_last(&store, &driver, session).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn undoes_a_delete_with_the_full_row() {
+        let (_ctx, session, driver) = seeded().await;
+        let store = MutationUndoStore::new();
+
+        let before = fetch(&driver, session, 2).await;
+        driver
+            .delete_row(session, &main_ns(), "users", &key(2))
+            .await
+            .unwrap();
+        store.push(
+            session,
+            UndoEntry::for_delete(main_ns(), "users", &before).unwrap(),
+        );
+        assert_eq!(names(&driver, session).await, ["ada"]);
+
+        let outcome = undo_last(&store, &driver, session).await.unwrap();
+        assert_eq!(outcome.operation, "delete");
+        let restored = fetch(&driver, session, 2).await;
+        assert_eq!(
+            format!("{:?}", restored.rows[0].values),
+            r#"[Int(2), Text("bob"), Text("bob@example.com")]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn undoes_an_insert_by_deleting_it() {
+        let (_ctx, session, driver) = seeded().await;
+        let store = MutationUndoStore::new();
+
+        let data = RowData::new()
+            .with_column("id", Value::Int(3))
+            .with_column("name", Value::Text("cy".into()));
+        driver
+            .insert_row(session, &main_ns(), "users", &data)
+            .await
+            .unwrap();
+        store.push(session, UndoEntry::for_insert(main_ns(), "users", &data));
+
+        undo_last(&store, &driver, session).await.unwrap();
+        assert_eq!(names(&driver, session).await, ["ada", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn refuses_after_schema_change() {
+        let (_ctx, session, driver) = seeded().await;
+        let store = MutationUndoStore::new();
+
+        let before = fetch(&driver, session, 2).await;
+        driver
+            .delete_row(session, &main_ns(), "users", &key(2))
+            .await
+            .unwrap();
+        store.push(
+            session,
+            UndoEntry::for_delete(main_ns(), "users", &before).unwrap(),
+        );
+        driver
+            .execute(
+                session,
+                "ALTER TABLE users ADD COLUMN age INTEGER",
+                QueryId::new(),
+            )
+            .await
+            .unwrap();
+
+        let err = undo_last(&store, &driver, session).await.unwrap_err();
+        assert!(err.contains("changed"), "{err}");
+        assert_eq!(names(&driver, session).await, ["ada"]);
+    }
+
+    #[tokio::test]
+    async fn refuses_expired_entries() {
+        let (_ctx, session, driver) = seeded().await;
+        let store = MutationUndoStore::new();
+
+        let before = fetch(&driver, session, 2).await;
+        let Some(stale) = Instant::now().checked_sub(UNDO_WINDOW + Duration::from_secs(1)) else {
+            return;
+        };
+        let mut entry = UndoEntry::for_delete(main_ns(), "users", &before).unwrap();
+        entry.recorded_at = stale;
+        store.push(session, entry);
+
+        let err = undo_last(&store, &driver, session).await.unwrap_err();
+        assert!(err.contains("can no longer be undone"), "{err}");
+    }
+
+    #[test]
+    fn stack_is_bounded_per_session() {
+        let store = MutationUndoStore::new();
+        let session = SessionId::new();
+        for id in 0..(MAX_UNDO_DEPTH as i64 + 5) {
+            store.push(session, UndoEntry::for_insert(main_ns(), "users", &key(id)));
+        }
+        assert_eq!(store.depth(session), MAX_UNDO_DEPTH);
+        assert_eq!(store.depth(SessionId::new()), 0);
+        store.clear_session(session);
+        assert!(store.pop(session).is_none());
+    }
+}
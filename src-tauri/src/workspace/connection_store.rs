@@ -6,7 +6,7 @@
 //! Credentials (passwords) are stored in the OS keyring, never on disk.
 //! One file per connection to minimize Git merge conflicts.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -27,6 +27,8 @@ struct CredsJson {
     ssh_key_passphrase: Option<String>,
     #[serde(default)]
     proxy_password: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    extra_secrets: HashMap<String, String>,
 }
 
 /// Connection store that persists metadata in a workspace directory.
@@ -192,6 +194,11 @@ impl WorkspaceConnectionStore {
                 .proxy_password
                 .as_ref()
                 .map(|s| s.expose().clone()),
+            extra_secrets: credentials
+                .extra_secrets
+                .iter()
+                .map(|(key, value)| (key.clone(), value.expose().clone()))
+                .collect(),
         })
         .map_err(|e| EngineError::internal(format!("Serialization error: {}", e)))?;
 
@@ -220,6 +227,11 @@ impl WorkspaceConnectionStore {
             ssh_password: creds.ssh_password.map(Sensitive::new),
             ssh_key_passphrase: creds.ssh_key_passphrase.map(Sensitive::new),
             proxy_password: creds.proxy_password.map(Sensitive::new),
+            extra_secrets: creds
+                .extra_secrets
+                .into_iter()
+                .map(|(key, value)| (key, Sensitive::new(value)))
+                .collect(),
         })
     }
 
@@ -306,6 +318,13 @@ mod tests {
             clickhouse_cluster: None,
             search_auth_mode: None,
             ssl_ca_cert: None,
+            tls: None,
+            extra_params: None,
+            statement_timeout_ms: None,
+            lock_timeout_ms: None,
+            default_isolation_level: None,
+            wait_for_ready_secs: None,
+            query_timeout_secs: None,
             project_id: "ws_test".to_string(),
         }
     }
@@ -316,6 +335,7 @@ mod tests {
             ssh_password: None,
             ssh_key_passphrase: None,
             proxy_password: None,
+            extra_secrets: Default::default(),
         }
     }
 
@@ -8,8 +8,9 @@ use qoredb_lib::engine::{
     error::{EngineError, EngineResult},
     traits::DataEngine,
     types::{
-        CollectionListOptions, ConnectionConfig, Namespace, QueryId, RowData, SessionId,
-        TableQueryOptions, Value,
+        CollectionListOptions, ColumnFilter, ComputedColumn, ConnectionConfig,
+        DescribeTableOptions, FilterOperator, Namespace, QueryId, RoutineType, RowData, SessionId,
+        SortDirection, TableQueryOptions, Value,
     },
 };
 use serde_json::json;
@@ -81,6 +82,13 @@ fn postgres_config() -> ConnectionConfig {
         clickhouse_cluster: None,
         search_auth_mode: None,
         ssl_ca_cert: None,
+        tls: None,
+        extra_params: None,
+        statement_timeout_ms: None,
+        lock_timeout_ms: None,
+        default_isolation_level: None,
+        wait_for_ready_secs: None,
+        query_timeout_secs: None,
     }
 }
 
@@ -105,6 +113,13 @@ fn mysql_config() -> ConnectionConfig {
         clickhouse_cluster: None,
         search_auth_mode: None,
         ssl_ca_cert: None,
+        tls: None,
+        extra_params: None,
+        statement_timeout_ms: None,
+        lock_timeout_ms: None,
+        default_isolation_level: None,
+        wait_for_ready_secs: None,
+        query_timeout_secs: None,
     }
 }
 
@@ -129,6 +144,13 @@ fn mongo_config() -> ConnectionConfig {
         clickhouse_cluster: None,
         search_auth_mode: None,
         ssl_ca_cert: None,
+        tls: None,
+        extra_params: None,
+        statement_timeout_ms: None,
+        lock_timeout_ms: None,
+        default_isolation_level: None,
+        wait_for_ready_secs: None,
+        query_timeout_secs: None,
     }
 }
 
@@ -155,6 +177,13 @@ fn clickhouse_config() -> ConnectionConfig {
         clickhouse_cluster: None,
         search_auth_mode: None,
         ssl_ca_cert: None,
+        tls: None,
+        extra_params: None,
+        statement_timeout_ms: None,
+        lock_timeout_ms: None,
+        default_isolation_level: None,
+        wait_for_ready_secs: None,
+        query_timeout_secs: None,
     }
 }
 
@@ -179,6 +208,13 @@ fn redis_config() -> ConnectionConfig {
         clickhouse_cluster: None,
         search_auth_mode: None,
         ssl_ca_cert: None,
+        tls: None,
+        extra_params: None,
+        statement_timeout_ms: None,
+        lock_timeout_ms: None,
+        default_isolation_level: None,
+        wait_for_ready_secs: None,
+        query_timeout_secs: None,
     }
 }
 
@@ -400,6 +436,37 @@ async fn postgres_e2e() -> EngineResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn postgres_cancel_all_session_queries() -> EngineResult<()> {
+    let (driver, session, _config) = connect_postgres().await?;
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let driver = Arc::clone(&driver);
+            tokio::spawn(async move {
+                driver
+                    .execute(session, "SELECT pg_sleep(5)", QueryId::new())
+                    .await
+            })
+        })
+        .collect();
+
+    // Give both queries time to reach the server.
+    sleep(Duration::from_millis(300)).await;
+    driver.cancel(session, None).await?;
+
+    for handle in handles {
+        let result = timeout(Duration::from_secs(6), handle)
+            .await
+            .map_err(|_| EngineError::execution_error("Cancel did not return in time"))?
+            .map_err(|e| EngineError::execution_error(format!("Join error: {}", e)))?;
+        assert!(result.is_err());
+    }
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn mysql_e2e() -> EngineResult<()> {
     let (driver, session, config) = connect_mysql().await?;
@@ -521,6 +588,457 @@ async fn mysql_e2e() -> EngineResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn postgres_routine_definition() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    let function = unique_name("qoredb_pg_fn");
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| "postgres".to_string());
+    let namespace = Namespace::with_schema(db_name, "public");
+
+    for args in ["a integer, b integer", "a text, b text"] {
+        driver
+            .execute(
+                session,
+                &format!(
+                    "CREATE FUNCTION {}({}) RETURNS text LANGUAGE sql AS $$ SELECT (a || b)::text $$",
+                    function, args
+                ),
+                QueryId::new(),
+            )
+            .await?;
+    }
+
+    let definition = driver
+        .get_routine_definition(
+            session,
+            &namespace,
+            &function,
+            RoutineType::Function,
+            Some("a text, b text"),
+        )
+        .await?;
+    assert_eq!(definition.arguments, "a text, b text");
+    assert_eq!(definition.language.as_deref(), Some("sql"));
+    assert!(definition.definition.contains("SELECT (a || b)::text"));
+
+    let ambiguous = driver
+        .get_routine_definition(session, &namespace, &function, RoutineType::Function, None)
+        .await;
+    assert!(matches!(
+        ambiguous,
+        Err(EngineError::ValidationError { .. })
+    ));
+
+    for args in ["integer, integer", "text, text"] {
+        driver
+            .execute(
+                session,
+                &format!("DROP FUNCTION {}({})", function, args),
+                QueryId::new(),
+            )
+            .await?;
+    }
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mysql_routine_definition() -> EngineResult<()> {
+    let (driver, session, config) = connect_mysql().await?;
+    let function = unique_name("qoredb_mysql_fn");
+    let namespace = Namespace::new(
+        config
+            .database
+            .clone()
+            .unwrap_or_else(|| DEFAULT_DB.to_string()),
+    );
+
+    driver
+        .execute(
+            session,
+            &format!(
+                "CREATE FUNCTION {}(a INT, b INT) RETURNS INT DETERMINISTIC RETURN a + b",
+                function
+            ),
+            QueryId::new(),
+        )
+        .await?;
+
+    let definition = driver
+        .get_routine_definition(session, &namespace, &function, RoutineType::Function, None)
+        .await?;
+    assert_eq!(definition.name, function);
+    assert!(definition.definition.contains("RETURN a + b"));
+
+    driver
+        .execute(
+            session,
+            &format!("DROP FUNCTION {}", function),
+            QueryId::new(),
+        )
+        .await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_list_databases() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| "postgres".to_string());
+
+    let databases = driver.list_databases(session).await?;
+    assert!(databases.contains(&db_name));
+    assert!(!databases.iter().any(|db| db.starts_with("template")));
+
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_query_table_computed_json_column() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    let table = unique_name("qoredb_pg_json");
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| "postgres".to_string());
+    let namespace = Namespace::with_schema(db_name, "public");
+
+    for sql in [
+        format!("CREATE TABLE {} (id INT PRIMARY KEY, data JSONB)", table),
+        format!(
+            r#"INSERT INTO {} VALUES
+                (1, '{{"status": "paid", "items": [{{"sku": "B"}}]}}'),
+                (2, '{{"status": "open", "items": [{{"sku": "C"}}]}}'),
+                (3, '{{"status": "paid", "items": [{{"sku": "A"}}]}}')"#,
+            table
+        ),
+    ] {
+        driver.execute(session, &sql, QueryId::new()).await?;
+    }
+
+    let computed = |alias: &str, json_path: &str| ComputedColumn {
+        alias: alias.to_string(),
+        source_column: "data".to_string(),
+        json_path: json_path.to_string(),
+    };
+    let page = driver
+        .query_table(
+            session,
+            &namespace,
+            &table,
+            TableQueryOptions {
+                sort_column: Some("first_sku".to_string()),
+                filters: Some(vec![ColumnFilter {
+                    column: "status".to_string(),
+                    operator: FilterOperator::Eq,
+                    value: Value::Text("paid".to_string()),
+                    options: Default::default(),
+                }]),
+                computed_columns: Some(vec![
+                    computed("status", "status"),
+                    computed("first_sku", "items[0].sku"),
+                ]),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(page.total_rows, 2);
+    assert_eq!(page.result.columns.len(), 4);
+    let skus: Vec<&Value> = page.result.rows.iter().map(|row| &row.values[3]).collect();
+    assert!(matches!(
+        skus[..],
+        [Value::Text(a), Value::Text(b)] if a == "A" && b == "B"
+    ));
+
+    driver
+        .execute(session, &format!("DROP TABLE {}", table), QueryId::new())
+        .await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_postgis_columns_as_wkt() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    if let Err(err) = driver
+        .execute(
+            session,
+            "CREATE EXTENSION IF NOT EXISTS postgis",
+            QueryId::new(),
+        )
+        .await
+    {
+        eprintln!("postgres_postgis_columns_as_wkt skipped: PostGIS is unavailable: {err}");
+        driver.disconnect(session).await?;
+        return Ok(());
+    }
+    let table = unique_name("qoredb_pg_geom");
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| "postgres".to_string());
+    let namespace = Namespace::with_schema(db_name, "public");
+
+    for sql in [
+        format!(
+            "CREATE TABLE {} (id INT PRIMARY KEY, geom geometry(Point), area geography)",
+            table
+        ),
+        format!(
+            "INSERT INTO {} VALUES (1, ST_MakePoint(1, 2), 'POINT(3 4)')",
+            table
+        ),
+    ] {
+        driver.execute(session, &sql, QueryId::new()).await?;
+    }
+
+    let is_wkt = |value: &Value, wkt: &str| matches!(value, Value::Text(s) if s == wkt);
+
+    let preview = driver
+        .preview_table(session, &namespace, &table, 10)
+        .await?;
+    assert_eq!(preview.columns.len(), 3);
+    assert!(is_wkt(&preview.rows[0].values[1], "POINT(1 2)"));
+    assert!(is_wkt(&preview.rows[0].values[2], "POINT(3 4)"));
+
+    let page = driver
+        .query_table(
+            session,
+            &namespace,
+            &table,
+            TableQueryOptions {
+                filters: Some(vec![ColumnFilter {
+                    column: "geom".to_string(),
+                    operator: FilterOperator::Eq,
+                    value: Value::Text("POINT(1 2)".to_string()),
+                    options: Default::default(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(page.total_rows, 1);
+    assert_eq!(page.result.columns[1].name, "geom");
+    assert!(is_wkt(&page.result.rows[0].values[1], "POINT(1 2)"));
+
+    let geojson: serde_json::Value = serde_json::from_str(
+        &driver
+            .geometry_to_geojson(session, "SRID=4326;POINT(1 2)")
+            .await?,
+    )
+    .expect("GeoJSON is valid JSON");
+    assert_eq!(geojson, json!({"type": "Point", "coordinates": [1, 2]}));
+
+    driver
+        .execute(session, &format!("DROP TABLE {}", table), QueryId::new())
+        .await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_truncate_table() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    let table = unique_name("qoredb_pg_truncate");
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| "postgres".to_string());
+    let namespace = Namespace::with_schema(db_name, "public");
+
+    for sql in [
+        format!("CREATE TABLE {} (id SERIAL PRIMARY KEY, label TEXT)", table),
+        format!(
+            "INSERT INTO {} (label) SELECT 'row ' || g FROM generate_series(1, 25) g",
+            table
+        ),
+    ] {
+        driver.execute(session, &sql, QueryId::new()).await?;
+    }
+
+    driver.truncate_table(session, &namespace, &table).await?;
+
+    let count = driver
+        .execute(
+            session,
+            &format!("SELECT COUNT(*) FROM {}", table),
+            QueryId::new(),
+        )
+        .await?;
+    assert!(matches!(count.rows[0].values[0], Value::Int(0)));
+
+    driver
+        .execute(session, &format!("DROP TABLE {}", table), QueryId::new())
+        .await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_describe_table_row_count_estimate() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    let table = unique_name("qoredb_pg_estimate");
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| "postgres".to_string());
+    let namespace = Namespace::with_schema(db_name, "public");
+
+    for sql in [
+        format!("CREATE TABLE {} (id SERIAL PRIMARY KEY, label TEXT)", table),
+        format!(
+            "INSERT INTO {} (label) SELECT 'row ' || g FROM generate_series(1, 25) g",
+            table
+        ),
+    ] {
+        driver.execute(session, &sql, QueryId::new()).await?;
+    }
+
+    // Small tables are counted exactly unless a fast estimate is requested.
+    let exact = driver.describe_table(session, &namespace, &table).await?;
+    assert_eq!(exact.row_count_estimate, Some(25));
+    assert!(!exact.row_count_is_estimate);
+
+    let fast = driver
+        .describe_table_with_options(
+            session,
+            &namespace,
+            &table,
+            &DescribeTableOptions {
+                fast_estimate: true,
+            },
+        )
+        .await?;
+    assert!(fast.row_count_is_estimate);
+
+    driver
+        .execute(session, &format!("DROP TABLE {}", table), QueryId::new())
+        .await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn postgres_listen_receives_notify() -> EngineResult<()> {
+    let (driver, session, config) = connect_postgres().await?;
+    let notifier = driver.connect(&config).await?;
+    let channel = unique_name("qoredb_pg_listen");
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(16);
+    let listener = tokio::spawn({
+        let driver = Arc::clone(&driver);
+        let channel = channel.clone();
+        async move { driver.listen(session, &channel, sender).await }
+    });
+
+    // LISTEN is issued in the background: notify until it is in place.
+    let mut received = None;
+    for _ in 0..50 {
+        driver
+            .execute(
+                notifier,
+                &format!("NOTIFY {}, 'order 42 shipped'", channel),
+                QueryId::new(),
+            )
+            .await?;
+        if let Ok(notification) = timeout(Duration::from_millis(200), receiver.recv()).await {
+            received = notification;
+            break;
+        }
+    }
+    let notification = received.expect("no notification received");
+    assert_eq!(notification.channel, channel);
+    assert_eq!(notification.payload, "order 42 shipped");
+
+    // Dropping the receiver ends the subscription.
+    drop(receiver);
+    timeout(Duration::from_secs(5), listener)
+        .await
+        .expect("listener did not stop")
+        .expect("listener task panicked")?;
+
+    driver.disconnect(notifier).await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mysql_list_databases() -> EngineResult<()> {
+    let (driver, session, config) = connect_mysql().await?;
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| DEFAULT_DB.to_string());
+
+    let databases = driver.list_databases(session).await?;
+    assert!(databases.contains(&db_name));
+    assert!(databases.iter().any(|db| db == "information_schema"));
+
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mysql_describe_table_row_count_estimate() -> EngineResult<()> {
+    let (driver, session, config) = connect_mysql().await?;
+    let table = unique_name("qoredb_mysql_estimate");
+    let db_name = config
+        .database
+        .clone()
+        .unwrap_or_else(|| DEFAULT_DB.to_string());
+    let namespace = Namespace::new(db_name);
+
+    for sql in [
+        format!(
+            "CREATE TABLE {} (id INT AUTO_INCREMENT PRIMARY KEY, label VARCHAR(20))",
+            table
+        ),
+        format!("INSERT INTO {} (label) VALUES ('a'), ('b'), ('c')", table),
+    ] {
+        driver.execute(session, &sql, QueryId::new()).await?;
+    }
+
+    // TABLE_ROWS is only an estimate; small tables get an exact COUNT(*).
+    let exact = driver.describe_table(session, &namespace, &table).await?;
+    assert_eq!(exact.row_count_estimate, Some(3));
+    assert!(!exact.row_count_is_estimate);
+
+    let fast = driver
+        .describe_table_with_options(
+            session,
+            &namespace,
+            &table,
+            &DescribeTableOptions {
+                fast_estimate: true,
+            },
+        )
+        .await?;
+    assert!(fast.row_count_is_estimate);
+
+    driver
+        .execute(session, &format!("DROP TABLE {}", table), QueryId::new())
+        .await?;
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn mongodb_e2e() -> EngineResult<()> {
     let (driver, session, config) = connect_mongo().await?;
@@ -559,6 +1077,110 @@ async fn mongodb_e2e() -> EngineResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn mongodb_query_table() -> EngineResult<()> {
+    let (driver, session, config) = connect_mongo().await?;
+    let db_name = config.database.unwrap_or_else(|| DEFAULT_DB.to_string());
+    let collection = unique_name("qoredb_mongo_page");
+    let namespace = Namespace::new(db_name);
+
+    for (name, value) in [("alpha", 1), ("beta", 2), ("gamma", 3), ("alphabet", 4)] {
+        let data = RowData::new()
+            .with_column("name", Value::Text(name.to_string()))
+            .with_column("value", Value::Int(value));
+        driver
+            .insert_row(session, &namespace, &collection, &data)
+            .await?;
+    }
+
+    let page = driver
+        .query_table(
+            session,
+            &namespace,
+            &collection,
+            TableQueryOptions {
+                page: Some(1),
+                page_size: Some(2),
+                sort_column: Some("value".to_string()),
+                sort_direction: Some(SortDirection::Desc),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(page.total_rows, 4);
+    assert_eq!(page.result.rows.len(), 2);
+    let names: Vec<&str> = page
+        .result
+        .columns
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(names, ["_id", "name", "value"]);
+    assert!(matches!(page.result.rows[0].values[2], Value::Int(4)));
+    assert!(matches!(page.result.rows[1].values[2], Value::Int(3)));
+
+    let filtered = driver
+        .query_table(
+            session,
+            &namespace,
+            &collection,
+            TableQueryOptions {
+                page_size: Some(1),
+                filters: Some(vec![ColumnFilter {
+                    column: "name".to_string(),
+                    operator: FilterOperator::Like,
+                    value: Value::Text("ALPHA%".to_string()),
+                    options: Default::default(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(filtered.total_rows, 2);
+    assert_eq!(filtered.result.rows.len(), 1);
+
+    driver.disconnect(session).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn mongodb_describe_table_row_count_estimate() -> EngineResult<()> {
+    let (driver, session, config) = connect_mongo().await?;
+    let db_name = config.database.unwrap_or_else(|| DEFAULT_DB.to_string());
+    let collection = unique_name("qoredb_mongo_estimate");
+    let namespace = Namespace::new(db_name);
+
+    for value in 1..=3 {
+        let data = RowData::new().with_column("value", Value::Int(value));
+        driver
+            .insert_row(session, &namespace, &collection, &data)
+            .await?;
+    }
+
+    // Small collections get an exact countDocuments; the fast estimate
+    // reads estimatedDocumentCount instead.
+    let exact = driver
+        .describe_table(session, &namespace, &collection)
+        .await?;
+    assert_eq!(exact.row_count_estimate, Some(3));
+    assert!(!exact.row_count_is_estimate);
+
+    let fast = driver
+        .describe_table_with_options(
+            session,
+            &namespace,
+            &collection,
+            &DescribeTableOptions {
+                fast_estimate: true,
+            },
+        )
+        .await?;
+    assert!(fast.row_count_is_estimate);
+
+    driver.disconnect(session).await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn redis_e2e() -> EngineResult<()> {
     let (driver, session, _config) = match connect_redis().await {
@@ -698,6 +1320,81 @@ async fn redis_e2e() -> EngineResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn redis_reconnects_after_connection_killed() -> EngineResult<()> {
+    let (driver, session, config) = match connect_redis().await {
+        Ok(conn) => conn,
+        Err(err) if !redis_test_required() && is_redis_unavailable(&err) => {
+            eprintln!(
+                "redis_reconnects_after_connection_killed skipped: Redis is unavailable: {err}"
+            );
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    };
+    let killer = driver.connect(&config).await?;
+
+    let client_id = driver.execute(session, "CLIENT ID", QueryId::new()).await?;
+    let client_id = match client_id.rows.first().and_then(|row| row.values.first()) {
+        Some(Value::Int(id)) => *id,
+        other => panic!("Unexpected CLIENT ID result: {:?}", other),
+    };
+    driver
+        .execute(
+            killer,
+            &format!("CLIENT KILL ID {}", client_id),
+            QueryId::new(),
+        )
+        .await?;
+
+    // The first command may find the connection already gone; it must then
+    // fail as a connection error, and the next one runs on a new connection.
+    match driver.execute(session, "PING", QueryId::new()).await {
+        Ok(_) | Err(EngineError::ConnectionFailed { .. }) => {}
+        Err(other) => panic!("Expected a connection error, got {:?}", other),
+    }
+    driver.execute(session, "PING", QueryId::new()).await?;
+
+    driver.disconnect(killer).await?;
+    driver.disconnect(session).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn redis_cancel_all_session_queries() -> EngineResult<()> {
+    let (driver, session, _config) = match connect_redis().await {
+        Ok(conn) => conn,
+        Err(err) if !redis_test_required() && is_redis_unavailable(&err) => {
+            eprintln!("redis_cancel_all_session_queries skipped: Redis is unavailable: {err}");
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    };
+    let key = unique_name("qoredb_redis_blpop");
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let driver = Arc::clone(&driver);
+            let command = format!("BLPOP {} 3", key);
+            tokio::spawn(async move { driver.execute(session, &command, QueryId::new()).await })
+        })
+        .collect();
+
+    sleep(Duration::from_millis(200)).await;
+    driver.cancel(session, None).await?;
+
+    for handle in handles {
+        let result = timeout(Duration::from_secs(1), handle)
+            .await
+            .map_err(|_| EngineError::execution_error("Abort did not return in time"))?
+            .map_err(|e| EngineError::execution_error(format!("Join error: {}", e)))?;
+        assert!(matches!(result, Err(EngineError::Cancelled)));
+    }
+    driver.disconnect(session).await?;
+
+    Ok(())
+}
+
 async fn test_streaming<D: DataEngine + ?Sized>(
     driver: &D,
     session: SessionId,
@@ -730,6 +1427,9 @@ async fn test_streaming<D: DataEngine + ?Sized>(
                 qoredb_lib::engine::traits::StreamEvent::Error(e) => {
                     panic!("Stream error: {}", e);
                 }
+                qoredb_lib::engine::traits::StreamEvent::Truncated { .. } => {
+                    panic!("Stream truncated without a row cap");
+                }
                 qoredb_lib::engine::traits::StreamEvent::Done(count) => {
                     assert!(!done_received, "Done received twice");
                     assert_eq!(count, rows_received, "Done count mismatch");
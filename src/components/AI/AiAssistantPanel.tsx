@@ -39,7 +39,7 @@ export function AiAssistantPanel({
   getEditorContext,
 }: AiAssistantPanelProps) {
   const { t } = useTranslation();
-  const { getConfig, isReady, includeSampleRows } = useAiPreferences();
+  const { getConfig, isReady, includeSampleRows, redactPatterns } = useAiPreferences();
 
   const assistant = useAiAssistant({
     sessionId,
@@ -47,6 +47,7 @@ export function AiAssistantPanel({
     connectionId,
     getEditorContext,
     includeSampleRows,
+    redactPatterns,
   });
 
   // Auto-trigger fix_error when pendingFix arrives
@@ -670,7 +670,8 @@ export function TableBrowser({
                 <>
                   <span>•</span>
                   <span>
-                    ~{schema.row_count_estimate.toLocaleString()} {t('table.rows')}
+                    {schema.row_count_is_estimate ? '~' : ''}
+                    {schema.row_count_estimate.toLocaleString()} {t('table.rows')}
                   </span>
                 </>
               )}
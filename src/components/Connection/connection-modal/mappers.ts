@@ -27,6 +27,7 @@ export function buildConnectionConfig(formData: ConnectionFormData): ConnectionC
         : undefined,
     search_auth_mode: isSearchDriver(formData.driver) ? formData.searchAuthMode : undefined,
     ssl_ca_cert: formData.sslCaCert.trim() || undefined,
+    tls: formData.tls,
     pool_max_connections: formData.poolMaxConnections,
     pool_min_connections: formData.poolMinConnections,
     pool_acquire_timeout_secs: formData.poolAcquireTimeoutSecs,
@@ -87,6 +88,7 @@ export function buildSavedConnection(
         : undefined,
     search_auth_mode: isSearchDriver(formData.driver) ? formData.searchAuthMode : undefined,
     ssl_ca_cert: formData.sslCaCert.trim() || undefined,
+    tls: formData.tls,
     pool_max_connections: formData.poolMaxConnections,
     pool_min_connections: formData.poolMinConnections,
     pool_acquire_timeout_secs: formData.poolAcquireTimeoutSecs,
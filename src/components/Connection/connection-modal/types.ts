@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { Driver } from '@/lib/connection/drivers';
-import type { Environment, MssqlAuthMode, SearchAuthMode } from '@/lib/tauri';
+import type { Environment, MssqlAuthMode, SearchAuthMode, TlsConfig } from '@/lib/tauri';
 
 export interface ConnectionFormData {
   name: string;
@@ -22,6 +22,8 @@ export interface ConnectionFormData {
   searchAuthMode: SearchAuthMode;
   /** Path to a custom CA certificate (PEM) for TLS verification. */
   sslCaCert: string;
+  /** TLS certificates and verification mode, kept as saved */
+  tls?: TlsConfig;
   poolMaxConnections: number;
   poolMinConnections: number;
   poolAcquireTimeoutSecs: number;
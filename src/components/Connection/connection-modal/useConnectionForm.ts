@@ -73,6 +73,7 @@ export function useConnectionForm(options: {
         clickhouseCluster: editConnection.clickhouse_cluster ?? '',
         searchAuthMode: editConnection.search_auth_mode ?? 'none',
         sslCaCert: editConnection.ssl_ca_cert ?? '',
+        tls: editConnection.tls,
         poolMaxConnections: editConnection.pool_max_connections ?? 5,
         poolMinConnections: editConnection.pool_min_connections ?? 0,
         poolAcquireTimeoutSecs: editConnection.pool_acquire_timeout_secs ?? 30,
@@ -148,6 +149,15 @@ export function useConnectionForm(options: {
         password: config.password ?? prev.password,
         database: config.database ?? prev.database,
         ssl: config.ssl ?? prev.ssl,
+        ...(config.ssh_tunnel && {
+          useSshTunnel: true,
+          sshHost: config.ssh_tunnel.host,
+          sshPort: config.ssh_tunnel.port,
+          sshUsername: config.ssh_tunnel.username || prev.sshUsername,
+          sshKeyPath:
+            ('Key' in config.ssh_tunnel.auth && config.ssh_tunnel.auth.Key.private_key_path) ||
+            prev.sshKeyPath,
+        }),
       };
     });
   }, []);
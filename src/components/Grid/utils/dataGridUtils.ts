@@ -21,6 +21,9 @@ export function formatValue(value: Value, dataType?: string): string {
   if (typeof value === 'string') return value;
   if (typeof value === 'object') {
     if (Array.isArray(value)) return JSON.stringify(value);
+    // MongoDB ObjectIds arrive in their extended JSON form
+    const oid = (value as Record<string, unknown>).$oid;
+    if (typeof oid === 'string' && Object.keys(value).length === 1) return `ObjectId("${oid}")`;
     return JSON.stringify(value);
   }
   return String(value);
@@ -127,6 +127,22 @@ export function AuditEntryDetail({
                   <p>{entry.row_count}</p>
                 </div>
               )}
+              {entry.affected_rows != null && (
+                <div>
+                  <Label className="text-muted-foreground">
+                    {t('interceptor.audit.detail.affectedRows')}
+                  </Label>
+                  <p>{entry.affected_rows}</p>
+                </div>
+              )}
+              {entry.touched_columns && entry.touched_columns.length > 0 && (
+                <div>
+                  <Label className="text-muted-foreground">
+                    {t('interceptor.audit.detail.touchedColumns')}
+                  </Label>
+                  <p className="font-mono text-xs">{entry.touched_columns.join(', ')}</p>
+                </div>
+              )}
               {entry.fingerprint && (
                 <div>
                   <Label className="text-muted-foreground">
@@ -433,11 +433,15 @@ export function InterceptorSettingsPanel() {
                   </div>
                 </div>
                 <span className="text-xs bg-muted px-2 py-1 rounded">
-                  {rule.action === 'block'
-                    ? t('interceptor.safety.action.block')
-                    : rule.action === 'warn'
-                      ? t('interceptor.safety.action.warn')
-                      : t('interceptor.safety.action.confirm')}
+                  {typeof rule.action === 'object'
+                    ? t('interceptor.safety.action.throttle', {
+                        count: rule.action.throttle.per_minute,
+                      })
+                    : rule.action === 'block'
+                      ? t('interceptor.safety.action.block')
+                      : rule.action === 'warn'
+                        ? t('interceptor.safety.action.warn')
+                        : t('interceptor.safety.action.confirm')}
                 </span>
               </div>
             ))}
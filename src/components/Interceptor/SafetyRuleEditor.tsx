@@ -33,12 +33,17 @@ const OPERATION_TYPES: QueryOperationType[] = [
 
 const ENVIRONMENTS: Environment[] = ['development', 'staging', 'production'];
 
-const ACTIONS: Array<{ value: SafetyRule['action']; label: string }> = [
+type ActionKind = 'block' | 'warn' | 'require_confirmation' | 'throttle';
+
+const ACTIONS: Array<{ value: ActionKind; label: string }> = [
   { value: 'block', label: 'interceptor.safety.actions.block' },
   { value: 'warn', label: 'interceptor.safety.actions.warn' },
   { value: 'require_confirmation', label: 'interceptor.safety.actions.require_confirmation' },
+  { value: 'throttle', label: 'interceptor.safety.actions.throttle' },
 ];
 
+const DEFAULT_THROTTLE_PER_MINUTE = 60;
+
 export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorProps) {
   const { t } = useTranslation();
   const isEditing = !!rule;
@@ -50,7 +55,12 @@ export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorPro
     rule?.environments || ['production']
   );
   const [operations, setOperations] = useState<QueryOperationType[]>(rule?.operations || []);
-  const [action, setAction] = useState<SafetyRule['action']>(rule?.action || 'block');
+  const [action, setAction] = useState<ActionKind>(
+    typeof rule?.action === 'object' ? 'throttle' : rule?.action || 'block'
+  );
+  const [perMinute, setPerMinute] = useState(
+    typeof rule?.action === 'object' ? rule.action.throttle.per_minute : DEFAULT_THROTTLE_PER_MINUTE
+  );
   const [pattern, setPattern] = useState(rule?.pattern || '');
   const [patternError, setPatternError] = useState<string | null>(null);
 
@@ -86,8 +96,13 @@ export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorPro
   }, []);
 
   const isValid = useMemo(() => {
-    return name.trim().length > 0 && environments.length > 0 && !patternError;
-  }, [name, environments, patternError]);
+    return (
+      name.trim().length > 0 &&
+      environments.length > 0 &&
+      !patternError &&
+      (action !== 'throttle' || (Number.isInteger(perMinute) && perMinute >= 1))
+    );
+  }, [name, environments, patternError, action, perMinute]);
 
   const handleSave = useCallback(() => {
     if (!isValid) return;
@@ -99,7 +114,7 @@ export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorPro
       enabled,
       environments,
       operations,
-      action,
+      action: action === 'throttle' ? { throttle: { per_minute: perMinute } } : action,
       pattern: pattern.trim() || undefined,
       builtin: false,
     };
@@ -113,6 +128,7 @@ export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorPro
     environments,
     operations,
     action,
+    perMinute,
     pattern,
     isValid,
     onSave,
@@ -201,7 +217,7 @@ export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorPro
 
           <div className="space-y-2">
             <Label>{t('interceptor.safety.ruleFields.action')}</Label>
-            <Select value={action} onValueChange={v => setAction(v as SafetyRule['action'])}>
+            <Select value={action} onValueChange={v => setAction(v as ActionKind)}>
               <SelectTrigger className="w-full">
                 <SelectValue />
               </SelectTrigger>
@@ -215,6 +231,21 @@ export function SafetyRuleEditor({ rule, onSave, onCancel }: SafetyRuleEditorPro
             </Select>
           </div>
 
+          {action === 'throttle' && (
+            <div className="space-y-2">
+              <Label htmlFor="rule-per-minute">
+                {t('interceptor.safety.ruleFields.perMinute')}
+              </Label>
+              <Input
+                id="rule-per-minute"
+                type="number"
+                min={1}
+                value={perMinute}
+                onChange={e => setPerMinute(Number(e.target.value))}
+              />
+            </div>
+          )}
+
           <div className="space-y-2">
             <Label htmlFor="rule-pattern">{t('interceptor.safety.ruleFields.pattern')}</Label>
             <p className="text-xs text-muted-foreground">Optional regex to match query text</p>
@@ -83,7 +83,7 @@ export function LicenseActivation() {
     }
   };
 
-  const isActive = status.tier !== 'core' && !status.is_expired;
+  const isActive = status.tier !== 'core' && (!status.is_expired || status.in_grace_period);
   // `seats` is retained even on an expired license, so it doubles as a Team marker.
   const isTeam = status.seats != null;
   const hasLicenseInfo = status.email || status.payment_id;
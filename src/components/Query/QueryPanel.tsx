@@ -75,6 +75,8 @@ import { SaveQueryDialog } from './SaveQueryDialog';
 const EDITOR_HEIGHT_KEY = 'query-editor-height';
 const MIN_EDITOR_HEIGHT = 100;
 const DEFAULT_EDITOR_HEIGHT = 200;
+/** Error prefix of a SELECT held back by the interceptor cost check */
+const EXPENSIVE_QUERY_BLOCKED = 'Expensive query: confirmation required';
 
 function loadEditorHeight(): number {
   try {
@@ -530,6 +532,11 @@ export function QueryPanel({
         } else {
           const errorMsg = response.error || t('query.queryFailed');
           const isTimeout = /operation timed out/i.test(errorMsg);
+          if (kind === 'query' && errorMsg.startsWith(EXPENSIVE_QUERY_BLOCKED)) {
+            setPendingQuery(queryToRun);
+            setDangerConfirmInfo(errorMsg.slice(EXPENSIVE_QUERY_BLOCKED.length + 1).trim());
+            setDangerConfirmOpen(true);
+          }
           const entry: QueryResultEntry = {
             id: queryId,
             kind,
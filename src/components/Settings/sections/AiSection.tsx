@@ -152,7 +152,10 @@ export function AiSection({ searchQuery }: AiSectionProps) {
     refreshStatuses,
     includeSampleRows,
     setIncludeSampleRows,
+    redactPatterns,
+    setRedactPatterns,
   } = useAiPreferences();
+  const [redactDraft, setRedactDraft] = useState(() => redactPatterns.join(', '));
 
   const providerHasKey = Object.fromEntries(
     AI_PROVIDERS.map(p => [
@@ -194,6 +197,27 @@ export function AiSection({ searchQuery }: AiSectionProps) {
           <Switch checked={includeSampleRows} onCheckedChange={setIncludeSampleRows} />
         </SettingsCard>
 
+        <SettingsCard
+          title={t('ai.settings.redactPatterns')}
+          description={t('ai.settings.redactPatternsDescription')}
+          searchQuery={searchQuery}
+        >
+          <Input
+            value={redactDraft}
+            disabled={!includeSampleRows}
+            placeholder="name, customer_ref"
+            onChange={e => setRedactDraft(e.target.value)}
+            onBlur={() =>
+              setRedactPatterns(
+                redactDraft
+                  .split(',')
+                  .map(p => p.trim())
+                  .filter(Boolean)
+              )
+            }
+          />
+        </SettingsCard>
+
         <SettingsCard
           title={t('ai.settings.title')}
           description={t('ai.settings.description')}
@@ -19,8 +19,10 @@ import {
   clearQueryCache,
   getCacheConfig,
   getCacheStats,
+  getMetricsConfig,
   getTimeTravelConfig,
   setCacheConfig,
+  setMetricsConfig,
   updateTimeTravelConfig,
 } from '@/lib/tauri';
 import { useLicense } from '@/providers/LicenseProvider';
@@ -50,6 +52,13 @@ export function DataSection({ policy, onApplyPolicy, searchQuery }: DataSectionP
   const [analyticsEnabled, setAnalyticsEnabled] = useState<boolean>(
     AnalyticsService.isAnalyticsEnabled()
   );
+  const [metricsEnabled, setMetricsEnabled] = useState(false);
+
+  useEffect(() => {
+    getMetricsConfig()
+      .then(config => setMetricsEnabled(config.metricsEnabled))
+      .catch(() => {});
+  }, []);
 
   function updateDiagnostics(next: DiagnosticsSettings) {
     setDiagnostics(next);
@@ -132,6 +141,24 @@ export function DataSection({ policy, onApplyPolicy, searchQuery }: DataSectionP
               </span>
             </span>
           </Label>
+
+          <Label className="flex items-start gap-2.5 text-sm cursor-pointer">
+            <Checkbox
+              checked={metricsEnabled}
+              onCheckedChange={checked => {
+                const enabled = !!checked;
+                setMetricsEnabled(enabled);
+                setMetricsConfig({ metricsEnabled: enabled }).catch(() => {});
+              }}
+              className="mt-0.5"
+            />
+            <span>
+              <span className="font-medium text-foreground">{t('settings.metricsEnabled')}</span>
+              <span className="block text-xs text-muted-foreground mt-0.5">
+                {t('settings.metricsEnabledDescription')}
+              </span>
+            </span>
+          </Label>
         </div>
       </SettingsCard>
 
@@ -4,7 +4,20 @@ import { useEffect, useState } from 'react';
 import { useTranslation } from 'react-i18next';
 import { InterceptorSettingsPanel } from '@/components/Interceptor';
 import { Checkbox } from '@/components/ui/checkbox';
-import { getSafetyPolicy, type SafetyPolicy, setSafetyPolicy } from '@/lib/tauri';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import {
+  type DangerousSqlAction,
+  type EnvironmentGuards,
+  getSafetyPolicy,
+  type SafetyPolicy,
+  setSafetyPolicy,
+} from '@/lib/tauri';
 import { SettingsCard } from '../SettingsCard';
 
 interface SecuritySectionProps {
@@ -18,6 +31,15 @@ const DEFAULTS = {
   query_rate_limit_enabled: true,
 };
 
+const DEFAULT_GUARDS: EnvironmentGuards = {
+  development: 'warn',
+  staging: 'confirm',
+  production: 'block',
+};
+
+const GUARD_ENVIRONMENTS: (keyof EnvironmentGuards)[] = ['development', 'staging', 'production'];
+const GUARD_ACTIONS: DangerousSqlAction[] = ['allow', 'warn', 'confirm', 'block'];
+
 export function SecuritySection({ searchQuery }: SecuritySectionProps) {
   const { t } = useTranslation();
   const [policy, setPolicy] = useState<SafetyPolicy | null>(null);
@@ -69,7 +91,10 @@ export function SecuritySection({ searchQuery }: SecuritySectionProps) {
     policy &&
     (policy.prod_require_confirmation !== DEFAULTS.prod_require_confirmation ||
       policy.prod_block_dangerous_sql !== DEFAULTS.prod_block_dangerous_sql ||
-      (policy.query_rate_limit_enabled ?? true) !== DEFAULTS.query_rate_limit_enabled);
+      (policy.query_rate_limit_enabled ?? true) !== DEFAULTS.query_rate_limit_enabled ||
+      GUARD_ENVIRONMENTS.some(
+        env => (policy.environment_guards ?? DEFAULT_GUARDS)[env] !== DEFAULT_GUARDS[env]
+      ));
 
   return (
     <>
@@ -150,6 +175,45 @@ export function SecuritySection({ searchQuery }: SecuritySectionProps) {
             </span>
           </label>
 
+          <div className="space-y-2 pt-1">
+            <span className="block text-sm font-medium text-foreground">
+              {t('settings.safetyPolicyEnvironmentGuards')}
+            </span>
+            <span className="block text-xs text-muted-foreground">
+              {t('settings.safetyPolicyEnvironmentGuardsDescription')}
+            </span>
+            {GUARD_ENVIRONMENTS.map(env => (
+              <div key={env} className="flex items-center gap-3">
+                <span className="w-28 text-sm text-foreground">{t(`environment.${env}`)}</span>
+                <Select
+                  value={(policy?.environment_guards ?? DEFAULT_GUARDS)[env]}
+                  disabled={!policy || policySaving}
+                  onValueChange={(value: DangerousSqlAction) =>
+                    policy &&
+                    updatePolicy({
+                      ...policy,
+                      environment_guards: {
+                        ...(policy.environment_guards ?? DEFAULT_GUARDS),
+                        [env]: value,
+                      },
+                    })
+                  }
+                >
+                  <SelectTrigger className="w-48 h-8 text-sm">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {GUARD_ACTIONS.map(action => (
+                      <SelectItem key={action} value={action}>
+                        {t(`settings.safetyPolicyGuardAction.${action}`)}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              </div>
+            ))}
+          </div>
+
           <p className="text-xs text-muted-foreground pt-1">{t('settings.safetyPolicyNote')}</p>
           {policyError ? <p className="text-xs text-destructive">{policyError}</p> : null}
         </div>
@@ -79,6 +79,8 @@ export function ShareExportDialog({
     switch (format) {
       case 'json':
         return 'json';
+      case 'jsonl':
+        return 'jsonl';
       case 'sql_insert':
         return 'sql';
       case 'html':
@@ -161,6 +163,7 @@ export function ShareExportDialog({
               <SelectContent>
                 <SelectItem value="csv">{t('export.format.csv')}</SelectItem>
                 <SelectItem value="json">{t('export.format.json')}</SelectItem>
+                <SelectItem value="jsonl">{t('export.format.jsonl')}</SelectItem>
                 <SelectItem value="sql_insert">{t('export.format.sql')}</SelectItem>
                 <SelectItem value="html">{t('export.format.html')}</SelectItem>
                 {isFeatureEnabled('export_xlsx') && (
@@ -153,8 +153,8 @@ export function VirtualRelationDialog({
       };
 
       const result = isEdit
-        ? await updateVirtualRelation(connectionId, relation)
-        : await addVirtualRelation(connectionId, relation);
+        ? await updateVirtualRelation(connectionId, relation, sessionId)
+        : await addVirtualRelation(connectionId, relation, sessionId);
 
       if (result.success) {
         toast.success(t('virtualRelations.saveSuccess'));
@@ -32,6 +32,7 @@ interface UseAiAssistantOptions {
   connectionId?: string;
   getEditorContext?: () => EditorContext | undefined;
   includeSampleRows?: boolean;
+  redactPatterns?: string[];
 }
 
 export function useAiAssistant({
@@ -40,6 +41,7 @@ export function useAiAssistant({
   connectionId,
   getEditorContext,
   includeSampleRows,
+  redactPatterns,
 }: UseAiAssistantOptions) {
   const [items, setItems] = useState<AiChatItem[]>([]);
   const [loading, setLoading] = useState(false);
@@ -148,6 +150,7 @@ export function useAiAssistant({
         history,
         editor_context: getEditorContext?.(),
         include_sample_rows: includeSampleRows ?? false,
+        redact_patterns: redactPatterns ?? [],
         ...extra,
       };
 
@@ -168,7 +171,7 @@ export function useAiAssistant({
         });
       }
     },
-    [sessionId, namespace, connectionId, getEditorContext, includeSampleRows]
+    [sessionId, namespace, connectionId, getEditorContext, includeSampleRows, redactPatterns]
   );
 
   const generateQuery = useCallback(
@@ -49,6 +49,8 @@ function extensionForFormat(format: ShareLiveExportRequest['format']): string {
   switch (format) {
     case 'json':
       return 'json';
+    case 'jsonl':
+      return 'jsonl';
     case 'sql_insert':
       return 'sql';
     case 'html':
@@ -50,6 +50,7 @@ export interface AiRequest {
   history?: AiMessage[];
   editor_context?: EditorContext;
   include_sample_rows?: boolean;
+  redact_patterns?: string[];
   original_query?: string;
   error_context?: string;
   result_context?: string;
@@ -185,9 +186,17 @@ export async function aiExplainResult(
 export async function aiSummarizeSchema(
   sessionId: string,
   config: AiConfig,
-  namespace?: Namespace
+  namespace?: Namespace,
+  includeSampleRows?: boolean,
+  redactPatterns?: string[]
 ): Promise<AiResponse> {
-  return invoke('ai_summarize_schema', { sessionId, config, namespace });
+  return invoke('ai_summarize_schema', {
+    sessionId,
+    config,
+    namespace,
+    includeSampleRows,
+    redactPatterns,
+  });
 }
 
 export async function aiFixError(request: AiRequest): Promise<void> {
@@ -1,7 +1,12 @@
 // SPDX-License-Identifier: BUSL-1.1
 
 import { invoke } from '@/lib/transport';
-import { createStreamChannel, type QueryResult, type QueryStreamHandlers } from '../tauri';
+import {
+  createStreamChannel,
+  type Namespace,
+  type QueryResult,
+  type QueryStreamHandlers,
+} from '../tauri';
 
 export interface FederationSource {
   alias: string;
@@ -66,6 +71,104 @@ export async function listFederationSources(): Promise<FederationSource[]> {
   return invoke('list_federation_sources');
 }
 
+export interface PlannedSourceQuery {
+  alias: string;
+  driver: string;
+  namespace: Namespace;
+  table: string;
+  /** Exact query sent to the source connection */
+  query: string;
+  /** DuckDB temp table the rows are materialized into */
+  local_table: string;
+  row_limit: number;
+}
+
+export interface FederationPlanPreview {
+  sources: PlannedSourceQuery[];
+  virtual_tables: string[];
+  duckdb_query: string;
+}
+
+export interface FederationPlanResponse {
+  success: boolean;
+  plan?: FederationPlanPreview;
+  error?: string;
+}
+
+/**
+ * Plans a federation query without running it, returning the sub-query each
+ * source connection would receive.
+ */
+export async function planFederationQuery(
+  query: string,
+  aliasMap: Record<string, string>,
+  rowLimitPerSource?: number
+): Promise<FederationPlanResponse> {
+  return invoke('plan_federation_query', {
+    query,
+    aliasMap,
+    options:
+      rowLimitPerSource !== undefined ? { row_limit_per_source: rowLimitPerSource } : undefined,
+  });
+}
+
+export interface VirtualTableInfo {
+  alias: string;
+  session_id: string;
+  query: string;
+  namespace?: Namespace;
+  columns: string[];
+  row_count: number;
+  truncated: boolean;
+  captured_at: string;
+  /** Seconds until the snapshot expires; absent when it never expires */
+  expires_in_secs?: number;
+}
+
+export interface VirtualTableResponse {
+  success: boolean;
+  table?: VirtualTableInfo;
+  error?: string;
+}
+
+export interface VirtualTableOptions {
+  maxRows?: number;
+  /** Snapshot lifetime in seconds; 0 keeps it until dropped */
+  ttlSecs?: number;
+}
+
+/**
+ * Captures a query result once so it can be referenced by its bare `alias`
+ * in later federation queries.
+ */
+export async function registerVirtualTable(
+  alias: string,
+  sessionId: string,
+  query: string,
+  namespace?: Namespace,
+  options?: VirtualTableOptions
+): Promise<VirtualTableResponse> {
+  return invoke('register_virtual_table', {
+    alias,
+    sessionId,
+    query,
+    namespace,
+    options: options ? { max_rows: options.maxRows, ttl_secs: options.ttlSecs } : undefined,
+  });
+}
+
+export async function refreshVirtualTable(alias: string): Promise<VirtualTableResponse> {
+  return invoke('refresh_virtual_table', { alias });
+}
+
+export async function listVirtualTables(): Promise<VirtualTableInfo[]> {
+  return invoke('list_virtual_tables');
+}
+
+export async function dropVirtualTable(alias: string): Promise<boolean> {
+  return invoke('drop_virtual_table', { alias });
+}
+
 /**
  * Quick regex-based detection of whether a query contains cross-database
  * federation syntax (3-part identifiers where the first part is a known alias).
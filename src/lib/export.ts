@@ -3,9 +3,29 @@
 import { invoke } from '@/lib/transport';
 import type { Namespace } from './tauri';
 
-export type ExportFormat = 'csv' | 'json' | 'sql_insert' | 'html' | 'xlsx' | 'parquet';
+export type ExportFormat =
+  | 'csv'
+  | 'json'
+  | 'jsonl'
+  | 'sql_insert'
+  | 'html'
+  | 'xlsx'
+  | 'parquet';
 export type ExportState = 'pending' | 'running' | 'completed' | 'cancelled' | 'failed';
 
+export interface CsvOptions {
+  delimiter: string;
+  quote_style: 'always' | 'necessary' | 'never';
+  line_ending: 'lf' | 'crlf';
+  /** Start the file with a UTF-8 byte order mark so Excel detects the encoding */
+  utf8_bom: boolean;
+}
+
+export interface SqlInsertOptions {
+  /** Rows per multi-row INSERT statement; 1 writes a statement per row */
+  batch_size: number;
+}
+
 export interface ExportConfig {
   query: string;
   namespace?: Namespace;
@@ -13,8 +33,15 @@ export interface ExportConfig {
   format: ExportFormat;
   table_name?: string;
   include_headers: boolean;
+  pretty_json?: boolean;
+  csv_options?: CsvOptions;
+  sql_options?: SqlInsertOptions;
   batch_size?: number;
   limit?: number;
+  /** Table whose primary key orders the rows; enables checkpoints and resume */
+  source_table?: string;
+  /** Continue from the checkpoint left by an interrupted export */
+  resume?: boolean;
 }
 
 export interface ExportProgress {
@@ -13,6 +13,8 @@ export interface LicenseStatus {
   is_expired: boolean;
   seats: number | null;
   is_founder: boolean;
+  in_grace_period: boolean;
+  grace_expires_at: string | null;
 }
 
 export type ProFeature =
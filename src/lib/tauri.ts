@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
+export * from './tauri/benchmark';
 export * from './tauri/connection';
 export * from './tauri/data-io';
+export * from './tauri/diagnostics';
+export * from './tauri/local-cache';
 export * from './tauri/logs';
 export * from './tauri/maintenance';
 export * from './tauri/mutations';
@@ -11,6 +14,7 @@ export * from './tauri/schema-browse';
 export * from './tauri/schema-objects';
 export * from './tauri/search';
 export * from './tauri/snapshots';
+export * from './tauri/snippets';
 export * from './tauri/time-travel';
 export * from './tauri/transactions';
 export * from './tauri/types';
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+
+import { invoke } from '@/lib/transport';
+import type { Namespace } from './types';
+
+export interface LatencyStats {
+  min_ms: number;
+  median_ms: number;
+  mean_ms: number;
+  p95_ms: number;
+  max_ms: number;
+}
+
+export interface BenchmarkSide {
+  session_id: string;
+  driver: string;
+  latency: LatencyStats;
+  samples_ms: number[];
+  row_count: number;
+}
+
+export interface AbBenchmarkReport {
+  iterations: number;
+  a: BenchmarkSide;
+  b: BenchmarkSide;
+  row_counts_match: boolean;
+  /** B median divided by A median; below 1 means B is faster. */
+  median_ratio?: number | null;
+  warnings: string[];
+}
+
+export async function abBenchmark(
+  sessionA: string,
+  sessionB: string,
+  query: string,
+  options: {
+    iterations?: number;
+    namespaceA?: Namespace;
+    namespaceB?: Namespace;
+    benchmarkId?: string;
+  } = {}
+): Promise<{
+  success: boolean;
+  benchmark_id: string;
+  report?: AbBenchmarkReport;
+  error?: string;
+}> {
+  return invoke('ab_benchmark', {
+    sessionA,
+    sessionB,
+    query,
+    iterations: options.iterations,
+    namespaceA: options.namespaceA,
+    namespaceB: options.namespaceB,
+    benchmarkId: options.benchmarkId,
+  });
+}
+
+export async function cancelAbBenchmark(benchmarkId: string): Promise<boolean> {
+  return invoke('cancel_ab_benchmark', { benchmarkId });
+}
@@ -7,6 +7,7 @@ import type {
   SafetyPolicy,
   SafetyPolicyResponse,
   SessionListItem,
+  SshTunnelConfig,
 } from './types';
 
 export type ParseErrorCode =
@@ -25,6 +26,14 @@ export interface PartialConnectionConfig {
   database?: string;
   ssl?: boolean;
   options: Record<string, string>;
+  /** From the `sshHost`, `sshUser`, `sshPort` and `sshIdentityFile` query parameters */
+  ssh_tunnel?: SshTunnelConfig;
+}
+
+export interface UrlSchemeInfo {
+  scheme: string;
+  driver: string;
+  supports_ssh_tunnel: boolean;
 }
 
 export interface ParseConnectionUrlResponse {
@@ -38,10 +47,31 @@ export async function parseConnectionUrl(url: string): Promise<ParseConnectionUr
   return invoke('parse_url', { url });
 }
 
-export async function getSupportedUrlSchemes(): Promise<string[]> {
+export async function getSupportedUrlSchemes(): Promise<UrlSchemeInfo[]> {
   return invoke('get_supported_url_schemes');
 }
 
+export interface ConnectionEnvVar {
+  name: string;
+  value: string;
+}
+
+export interface ConnectionEnvResponse {
+  success: boolean;
+  variables: ConnectionEnvVar[];
+  dotenv?: string;
+  error?: string;
+}
+
+/** Without `includePassword` the password is replaced by `${DB_PASSWORD}`. */
+export async function exportConnectionEnv(
+  projectId: string,
+  connectionId: string,
+  includePassword: boolean
+): Promise<ConnectionEnvResponse> {
+  return invoke('export_connection_env', { projectId, connectionId, includePassword });
+}
+
 export async function testConnection(config: ConnectionConfig): Promise<ConnectionResponse> {
   return invoke('test_connection', { config });
 }
@@ -76,6 +106,54 @@ export async function checkConnectionHealth(sessionId: string): Promise<string>
   return invoke('check_connection_health', { sessionId });
 }
 
+export interface PoolStats {
+  size: number;
+  idle: number;
+  in_use: number;
+  max: number;
+}
+
+export async function getPoolStats(sessionId: string): Promise<{
+  success: boolean;
+  stats?: PoolStats | null;
+  error?: string;
+}> {
+  return invoke('get_pool_stats', { sessionId });
+}
+
+export type ServerFlavor = 'mysql' | 'mariadb' | 'percona' | 'postgres' | 'sqlserver';
+
+export interface ServerInfo {
+  version: string;
+  major: number;
+  minor: number;
+  patch: number;
+  flavor: ServerFlavor;
+  features: {
+    window_functions: boolean;
+    cte: boolean;
+    json_functions: boolean;
+  };
+}
+
+/** Server version and flavor, for gating version-dependent SQL features. */
+export async function getServerInfo(sessionId: string): Promise<{
+  success: boolean;
+  info?: ServerInfo | null;
+  error?: string;
+}> {
+  return invoke('get_server_info', { sessionId });
+}
+
+/** Round-trip latency of the session's cheapest no-op, reusing the live connection. */
+export async function pingSession(sessionId: string): Promise<{
+  success: boolean;
+  latency_ms?: number;
+  error?: string;
+}> {
+  return invoke('ping_session', { sessionId });
+}
+
 export type ConnectionHealth = 'healthy' | 'unhealthy' | 'reconnecting';
 
 export interface ConnectionHealthEvent {
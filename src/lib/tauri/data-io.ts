@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { invoke } from '@/lib/transport';
+import type { Namespace } from './types';
 
 export interface CsvPreviewResponse {
   detected_delimiter: string;
@@ -93,3 +94,131 @@ export async function exportSchema(
     options,
   });
 }
+
+export type ErDiagramFormat = 'mermaid' | 'dbml';
+
+export interface ErDiagramResponse {
+  success: boolean;
+  diagram?: string;
+  table_count: number;
+  error?: string;
+}
+
+export async function exportErDiagram(
+  sessionId: string,
+  namespace: Namespace,
+  format: ErDiagramFormat,
+  connectionId?: string
+): Promise<ErDiagramResponse> {
+  return invoke('export_er_diagram', {
+    sessionId,
+    namespace,
+    format,
+    connectionId,
+  });
+}
+
+export type CopySource = { kind: 'query'; query: string } | { kind: 'table'; table: string };
+
+export type CopyForceQuote = { kind: 'all' } | { kind: 'columns'; columns: string[] };
+
+export interface CopyOptions {
+  format?: 'csv' | 'text';
+  delimiter?: string;
+  null_string?: string;
+  quote?: string;
+  escape?: string;
+  force_quote?: CopyForceQuote;
+  header?: boolean;
+  encoding?: string;
+}
+
+export interface CopyExportResponse {
+  success: boolean;
+  path: string;
+  bytes_written: number;
+  error?: string;
+}
+
+/** Exports through PostgreSQL `COPY ... TO STDOUT` (requires `copy_export`). */
+export async function exportViaCopy(
+  sessionId: string,
+  namespace: Namespace,
+  source: CopySource,
+  options: CopyOptions,
+  outputPath: string
+): Promise<CopyExportResponse> {
+  return invoke('export_via_copy', {
+    sessionId,
+    namespace,
+    source,
+    options,
+    outputPath,
+  });
+}
+
+export interface BulkImportOptions {
+  delimiter?: string;
+  header?: boolean;
+  null_string?: string;
+  batch_size?: number;
+}
+
+export interface BulkImportResponse {
+  success: boolean;
+  rows_imported?: number;
+  error?: string;
+}
+
+/** Loads a CSV file into an existing table (`COPY` on PostgreSQL, batched `INSERT` elsewhere). */
+export async function bulkImportCsv(
+  sessionId: string,
+  namespace: Namespace,
+  table: string,
+  filePath: string,
+  options?: BulkImportOptions,
+  acknowledgedDangerous = false
+): Promise<BulkImportResponse> {
+  return invoke('bulk_import_csv', {
+    sessionId,
+    namespace,
+    table,
+    filePath,
+    options,
+    acknowledgedDangerous,
+  });
+}
+
+export interface CopyTableOptions {
+  create?: boolean;
+  batch_size?: number;
+}
+
+export interface CopyTableResponse {
+  success: boolean;
+  rows_copied?: number;
+  error?: string;
+}
+
+/** Copies a table between connections in batched transactions, optionally creating it first. */
+export async function copyTable(
+  sourceSession: string,
+  sourceNs: Namespace,
+  table: string,
+  destSession: string,
+  destNs: Namespace,
+  destTable: string,
+  options?: CopyTableOptions,
+  acknowledgedDangerous = false
+): Promise<CopyTableResponse> {
+  return invoke('copy_table', {
+    sourceSession,
+    sourceNs,
+    table,
+    destSession,
+    destNs,
+    destTable,
+    options,
+    acknowledgedDangerous,
+  });
+}
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0
+
+import { invoke, listen, type UnlistenFn } from '@/lib/transport';
+
+export interface BloatEntry {
+  schema: string;
+  table_name: string;
+  /** Set for index entries. */
+  index_name?: string | null;
+  size_bytes: number;
+  bloat_bytes: number;
+  bloat_percent: number;
+}
+
+export interface BloatReport {
+  tables: BloatEntry[];
+  indexes: BloatEntry[];
+  method: 'estimate' | 'pgstattuple';
+  note: string;
+}
+
+export async function bloatReport(
+  sessionId: string,
+  database: string,
+  schema?: string | null
+): Promise<{
+  success: boolean;
+  report?: BloatReport;
+  error?: string;
+}> {
+  return invoke('bloat_report', { sessionId, database, schema });
+}
+
+export interface KeyMetadata {
+  key: string;
+  key_type: string;
+  encoding?: string | null;
+  /** Remaining TTL in seconds; absent when the key never expires. */
+  ttl_secs?: number | null;
+  memory_usage_bytes?: number | null;
+  refcount?: number | null;
+  idle_time_secs?: number | null;
+  element_count?: number | null;
+}
+
+export async function redisKeyInfo(
+  sessionId: string,
+  database: string,
+  key: string
+): Promise<{
+  success: boolean;
+  metadata?: KeyMetadata;
+  error?: string;
+}> {
+  return invoke('redis_key_info', { sessionId, database, key });
+}
+
+export interface BigKeyEntry {
+  key: string;
+  key_type: string;
+  memory_usage_bytes: number;
+}
+
+export interface KeyTypeStats {
+  key_type: string;
+  key_count: number;
+  memory_usage_bytes: number;
+}
+
+export interface BigKeysReport {
+  top_keys: BigKeyEntry[];
+  by_type: KeyTypeStats[];
+  keys_sampled: number;
+  truncated: boolean;
+}
+
+/** Pass a `queryId` to be able to stop the scan with `cancelQuery`. */
+export async function redisBigKeys(
+  sessionId: string,
+  database: string,
+  sampleSize?: number,
+  topN?: number,
+  queryId?: string
+): Promise<{
+  success: boolean;
+  report?: BigKeysReport;
+  error?: string;
+}> {
+  return invoke('redis_big_keys', { sessionId, database, sampleSize, topN, queryId });
+}
+
+export interface QueryProgress {
+  backend_pid: number;
+  operation: 'vacuum' | 'analyze' | 'create_index' | 'cluster' | 'copy';
+  /** Server-reported phase; null until the operation starts reporting. */
+  phase?: string | null;
+  work_done?: number | null;
+  work_total?: number | null;
+  /** Null while the total is unknown. */
+  percent?: number | null;
+}
+
+/** Progress of a long-running VACUUM / CREATE INDEX started as `queryId` (Postgres). */
+export async function queryProgress(
+  sessionId: string,
+  queryId: string
+): Promise<{
+  success: boolean;
+  progress?: QueryProgress;
+  error?: string;
+}> {
+  return invoke('query_progress', { sessionId, queryId });
+}
+
+export type ServerLogSource = 'mysql_error_log' | 'pg_log_file' | 'sql_server_error_log';
+
+export interface ServerLogLine {
+  logged_at?: string | null;
+  level?: string | null;
+  message: string;
+}
+
+export interface ServerLogChunk {
+  source: ServerLogSource;
+  /** Oldest first. */
+  lines: ServerLogLine[];
+  /** Pass back as `after` to read only newer lines. */
+  cursor?: string | null;
+}
+
+export type ServerLogEvent =
+  | { kind: 'lines'; lines: ServerLogLine[] }
+  | { kind: 'failed'; error: string };
+
+interface ServerLogPayload {
+  follow_id: string;
+  event: ServerLogEvent;
+}
+
+/** Last `lines` lines of the server log, or the lines logged after `after`. Needs elevated privileges. */
+export async function readServerLog(
+  sessionId: string,
+  lines?: number,
+  after?: string
+): Promise<{
+  success: boolean;
+  data?: ServerLogChunk;
+  error?: string;
+}> {
+  return invoke('read_server_log', { sessionId, lines, after });
+}
+
+/**
+ * Reads the last `lines` lines, then polls for newer ones. Use
+ * {@link listenServerLog} to receive them and {@link stopServerLogFollow} to stop.
+ */
+export async function followServerLog(
+  sessionId: string,
+  followId: string,
+  lines?: number
+): Promise<{
+  success: boolean;
+  data?: ServerLogChunk;
+  error?: string;
+}> {
+  return invoke('follow_server_log', { sessionId, followId, lines });
+}
+
+export async function stopServerLogFollow(followId: string): Promise<boolean> {
+  return invoke<boolean>('stop_server_log_follow', { followId });
+}
+
+export async function listenServerLog(
+  followId: string,
+  onEvent: (event: ServerLogEvent) => void
+): Promise<UnlistenFn> {
+  return listen<ServerLogPayload>('server-log', payload => {
+    if (payload.payload.follow_id === followId) {
+      onEvent(payload.payload.event);
+    }
+  });
+}
@@ -4,6 +4,7 @@
 // stored and processed in the backend.
 
 import { invoke } from '@/lib/transport';
+import type { Namespace } from './types';
 
 export type Environment = 'development' | 'staging' | 'production';
 
@@ -21,7 +22,12 @@ export type QueryOperationType =
   | 'execute'
   | 'other';
 
-export type SafetyAction = 'block' | 'warn' | 'require_confirmation';
+export type SafetyAction =
+  | 'block'
+  | 'warn'
+  | 'require_confirmation'
+  /** Allows `per_minute` matching queries per session and minute, rejects the rest */
+  | { throttle: { per_minute: number } };
 
 export interface SafetyRule {
   id: string;
@@ -35,6 +41,26 @@ export interface SafetyRule {
   builtin: boolean;
 }
 
+export interface RuleMatchSpan {
+  start: number;
+  end: number;
+  text: string;
+}
+
+export interface SimulatedRuleMatch {
+  rule_id: string;
+  rule_name: string;
+  action: SafetyAction;
+  environments: Environment[];
+  span?: RuleMatchSpan | null;
+}
+
+export interface SafetySimulation {
+  operation_type: QueryOperationType;
+  database?: string | null;
+  matches: SimulatedRuleMatch[];
+}
+
 export const BUILTIN_SAFETY_RULE_I18N: Record<string, { nameKey: string; descriptionKey: string }> =
   {
     'builtin-no-drop-production': {
@@ -77,6 +103,9 @@ export interface AuditLogEntry {
   error?: string;
   execution_time_ms: number;
   row_count?: number;
+  affected_rows?: number;
+  /** Column names written by a row mutation, never their values */
+  touched_columns?: string[];
   blocked: boolean;
   safety_rule?: string;
   driver_id: string;
@@ -121,10 +150,23 @@ export interface SlowQueryEntry {
   execution_time_ms: number;
   environment: Environment;
   database?: string;
+  schema?: string;
+  table?: string;
   row_count?: number;
   driver_id: string;
 }
 
+export interface TableProfile {
+  schema?: string;
+  table: string;
+  query_count: number;
+  avg_execution_time_ms: number;
+  p50_execution_time_ms: number;
+  p95_execution_time_ms: number;
+  p99_execution_time_ms: number;
+  max_execution_time_ms: number;
+}
+
 export interface InterceptorConfig {
   audit_enabled: boolean;
   profiling_enabled: boolean;
@@ -134,6 +176,9 @@ export interface InterceptorConfig {
   max_slow_queries: number;
   safety_rules: SafetyRule[];
   builtin_rule_overrides: BuiltinRuleOverride[];
+  /** Run EXPLAIN before SELECT statements and ask for confirmation above the row limit */
+  cost_check_enabled?: boolean;
+  cost_check_row_limit?: number;
 }
 
 export interface BuiltinRuleOverride {
@@ -182,12 +227,24 @@ interface SlowQueriesResponse {
   error?: string;
 }
 
+interface TableProfilesResponse {
+  success: boolean;
+  tables: TableProfile[];
+  error?: string;
+}
+
 interface SafetyRulesResponse {
   success: boolean;
   rules: SafetyRule[];
   error?: string;
 }
 
+interface SafetySimulationResponse {
+  success: boolean;
+  simulation?: SafetySimulation;
+  error?: string;
+}
+
 interface GenericResponse {
   success: boolean;
   error?: string;
@@ -199,6 +256,12 @@ interface ExportResponse {
   error?: string;
 }
 
+interface VerifyAuditExportResponse {
+  success: boolean;
+  valid?: boolean;
+  error?: string;
+}
+
 export async function getInterceptorConfig(): Promise<InterceptorConfig> {
   const result = await invoke<InterceptorConfigResponse>('get_interceptor_config');
   if (!result.success || !result.config) {
@@ -256,14 +319,19 @@ export async function clearAuditLog(): Promise<void> {
  * `fromDisk = true` reads the full retained history from the rotated JSONL
  * file rather than the in-memory cache — needed when the user wants a
  * faithful audit trail beyond the current cache window.
+ *
+ * `signed = true` ignores `format` and returns a JSON document whose entries
+ * are HMAC-chained, with a manifest holding the final digest.
  */
 export async function exportAuditLog(
   format: AuditExportFormat = 'json',
-  fromDisk = false
+  fromDisk = false,
+  signed = false
 ): Promise<string> {
   const result = await invoke<ExportResponse>('export_audit_log', {
     format,
     fromDisk,
+    signed,
   });
   if (!result.success || !result.data) {
     throw new Error(result.error || 'Failed to export audit log');
@@ -271,22 +339,51 @@ export async function exportAuditLog(
   return result.data;
 }
 
-export async function getProfilingMetrics(): Promise<ProfilingMetrics> {
-  const result = await invoke<ProfilingMetricsResponse>('get_profiling_metrics');
+/** Whether the signed audit export at `path` is intact. */
+export async function verifyAuditExport(path: string): Promise<boolean> {
+  const result = await invoke<VerifyAuditExportResponse>('verify_audit_export', { path });
+  if (!result.success || result.valid == null) {
+    throw new Error(result.error || 'Failed to verify audit export');
+  }
+  return result.valid;
+}
+
+export async function getProfilingMetrics(namespace?: string): Promise<ProfilingMetrics> {
+  const result = await invoke<ProfilingMetricsResponse>('get_profiling_metrics', { namespace });
   if (!result.success || !result.metrics) {
     throw new Error(result.error || 'Failed to get profiling metrics');
   }
   return result.metrics;
 }
 
-export async function getSlowQueries(limit = 50, offset = 0): Promise<SlowQueryEntry[]> {
-  const result = await invoke<SlowQueriesResponse>('get_slow_queries', { limit, offset });
+export async function getSlowQueries(
+  limit = 50,
+  offset = 0,
+  namespace?: string
+): Promise<SlowQueryEntry[]> {
+  const result = await invoke<SlowQueriesResponse>('get_slow_queries', {
+    limit,
+    offset,
+    namespace,
+  });
   if (!result.success) {
     throw new Error(result.error || 'Failed to get slow queries');
   }
   return result.queries;
 }
 
+/** Slowest tables by p95 execution time, optionally within one namespace. */
+export async function getProfilingByTable(namespace?: string, limit = 20): Promise<TableProfile[]> {
+  const result = await invoke<TableProfilesResponse>('get_profiling_by_table', {
+    namespace,
+    limit,
+  });
+  if (!result.success) {
+    throw new Error(result.error || 'Failed to get profiling by table');
+  }
+  return result.tables;
+}
+
 export async function clearSlowQueries(): Promise<void> {
   const result = await invoke<GenericResponse>('clear_slow_queries');
   if (!result.success) {
@@ -341,6 +438,22 @@ export async function removeSafetyRule(ruleId: string): Promise<SafetyRule[]> {
   return result.rules;
 }
 
+export async function simulateSafetyRules(
+  query: string,
+  driverId: string,
+  namespace?: Namespace
+): Promise<SafetySimulation> {
+  const result = await invoke<SafetySimulationResponse>('simulate_safety_rules', {
+    query,
+    driverId,
+    namespace,
+  });
+  if (!result.success || !result.simulation) {
+    throw new Error(result.error || 'Failed to simulate safety rules');
+  }
+  return result.simulation;
+}
+
 export function formatExecutionTime(ms: number): string {
   if (ms < 1) {
     return `${(ms * 1000).toFixed(0)}µs`;
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0
+
+import { invoke } from '@/lib/transport';
+import type { PaginatedQueryResult, TableQueryOptions } from './query';
+import type { ColumnInfo, Namespace, QueryResult } from './types';
+
+export interface LocalCacheEntry {
+  name: string;
+  source_query: string;
+  namespace?: Namespace;
+  /** Columns with the DuckDB types inferred while loading. */
+  columns: ColumnInfo[];
+  row_count: number;
+  /** True when the remote result was cut off at the row cap. */
+  truncated: boolean;
+  cached_at: string;
+}
+
+export interface LocalCacheResponse {
+  success: boolean;
+  entry?: LocalCacheEntry;
+  error?: string;
+}
+
+export async function cacheResultLocally(
+  sessionId: string,
+  query: string,
+  localName: string,
+  namespace?: Namespace,
+  maxRows?: number
+): Promise<LocalCacheResponse> {
+  return invoke('cache_result_locally', { sessionId, query, localName, namespace, maxRows });
+}
+
+export async function refreshLocalCache(
+  sessionId: string,
+  localName: string,
+  maxRows?: number
+): Promise<LocalCacheResponse> {
+  return invoke('refresh_local_cache', { sessionId, localName, maxRows });
+}
+
+export async function queryLocalCache(
+  sql: string
+): Promise<{ success: boolean; result?: QueryResult; error?: string }> {
+  return invoke('query_local_cache', { sql });
+}
+
+export async function listLocalCaches(): Promise<{
+  success: boolean;
+  entries: LocalCacheEntry[];
+  path: string;
+  error?: string;
+}> {
+  return invoke('list_local_caches');
+}
+
+export async function dropLocalCache(
+  localName: string
+): Promise<{ success: boolean; error?: string }> {
+  return invoke('drop_local_cache', { localName });
+}
+
+export interface CachedResultResponse extends LocalCacheResponse {
+  /** Handle for `queryCached` and `refreshCachedResult`. */
+  handle?: string;
+}
+
+/** Loads the result of a recent editor query into the in-memory DuckDB cache. */
+export async function cacheResult(queryId: string): Promise<CachedResultResponse> {
+  return invoke('cache_result', { queryId });
+}
+
+/** Re-runs the source query of a cached result, replacing its rows. */
+export async function refreshCachedResult(handle: string): Promise<CachedResultResponse> {
+  return invoke('refresh_cached_result', { handle });
+}
+
+/** Sorts, filters and pages a cached result without touching the source database. */
+export async function queryCached(
+  handle: string,
+  options: TableQueryOptions = {}
+): Promise<{ success: boolean; result?: PaginatedQueryResult; error?: string }> {
+  return invoke('query_cached', { handle, options });
+}
@@ -5,6 +5,7 @@ import type {
   Environment,
   MssqlAuthMode,
   SavedConnection,
+  TlsConfig,
   VaultResponse,
   VaultStatus,
 } from './types';
@@ -18,9 +19,8 @@ export async function exportLogs(): Promise<{
   return invoke('export_logs');
 }
 
-export async function getMetrics(): Promise<{
-  success: boolean;
-  metrics?: {
+export interface RuntimeMetrics {
+  queries: {
     total: number;
     failed: number;
     cancelled: number;
@@ -28,11 +28,32 @@ export async function getMetrics(): Promise<{
     avg_ms?: number;
     max_ms?: number;
   };
+  active_sessions: number;
+  running_queries: number;
+  /** Connections of every open session's pool, summed */
+  pool: { size: number; idle: number; in_use: number; max: number };
+}
+
+export interface MetricsConfig {
+  metricsEnabled: boolean;
+}
+
+export async function getMetrics(): Promise<{
+  success: boolean;
+  metrics?: RuntimeMetrics;
   error?: string;
 }> {
   return invoke('get_metrics');
 }
 
+export async function getMetricsConfig(): Promise<MetricsConfig> {
+  return invoke('get_metrics_config');
+}
+
+export async function setMetricsConfig(config: MetricsConfig): Promise<MetricsConfig> {
+  return invoke('set_metrics_config', { config });
+}
+
 export async function getVaultStatus(): Promise<VaultStatus> {
   return invoke('get_vault_status');
 }
@@ -69,6 +90,7 @@ export async function saveConnection(input: {
   mssql_auth?: MssqlAuthMode;
   /** Distributed cluster name for ClickHouse DDL (`ON CLUSTER`). */
   clickhouse_cluster?: string;
+  tls?: TlsConfig;
   ssh_tunnel?: {
     host: string;
     port: number;
@@ -128,3 +150,34 @@ export async function duplicateSavedConnection(
 }> {
   return invoke('duplicate_saved_connection', { projectId, connectionId });
 }
+
+export interface ImportSummary {
+  imported: number;
+  overwritten: number;
+  skipped: number;
+}
+
+/** Returns the base64 encrypted backup of every saved connection. */
+export async function exportConnections(
+  projectId: string,
+  masterPassword: string
+): Promise<{
+  success: boolean;
+  blob?: string;
+  error?: string;
+}> {
+  return invoke('export_connections', { projectId, masterPassword });
+}
+
+export async function importConnections(
+  projectId: string,
+  blob: string,
+  masterPassword: string,
+  overwrite: boolean
+): Promise<{
+  success: boolean;
+  summary?: ImportSummary;
+  error?: string;
+}> {
+  return invoke('import_connections', { projectId, blob, masterPassword, overwrite });
+}
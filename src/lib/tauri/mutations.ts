@@ -10,6 +10,8 @@ export interface RowData {
 export interface MutationResponse {
   success: boolean;
   result?: QueryResult;
+  /** Changed rows, present when `returnRows` was requested */
+  changed_rows?: QueryResult | null;
   error?: string;
 }
 
@@ -24,6 +26,33 @@ export async function insertRow(
   return invoke('insert_row', { sessionId, database, schema, table, data, acknowledgedDangerous });
 }
 
+export type UpsertAction = 'inserted' | 'updated';
+
+export interface UpsertResponse extends MutationResponse {
+  /** Absent when the driver cannot tell whether the row was inserted or updated */
+  action?: UpsertAction | null;
+}
+
+export async function upsertRow(
+  sessionId: string,
+  database: string,
+  schema: string | null | undefined,
+  table: string,
+  data: RowData,
+  conflictColumns: string[],
+  acknowledgedDangerous?: boolean
+): Promise<UpsertResponse> {
+  return invoke('upsert_row', {
+    sessionId,
+    database,
+    schema,
+    table,
+    data,
+    conflictColumns,
+    acknowledgedDangerous,
+  });
+}
+
 export async function updateRow(
   sessionId: string,
   database: string,
@@ -31,7 +60,8 @@ export async function updateRow(
   table: string,
   primaryKey: RowData,
   data: RowData,
-  acknowledgedDangerous?: boolean
+  acknowledgedDangerous?: boolean,
+  returnRows?: boolean
 ): Promise<MutationResponse> {
   return invoke('update_row', {
     sessionId,
@@ -41,16 +71,24 @@ export async function updateRow(
     primaryKey,
     data,
     acknowledgedDangerous,
+    returnRows,
   });
 }
 
+/**
+ * Deletes the rows matching `primaryKey`. When the key matches more than one
+ * row the backend rejects the delete unless `confirmedCount` equals the number
+ * of matching rows.
+ */
 export async function deleteRow(
   sessionId: string,
   database: string,
   schema: string | null | undefined,
   table: string,
   primaryKey: RowData,
-  acknowledgedDangerous?: boolean
+  acknowledgedDangerous?: boolean,
+  returnRows?: boolean,
+  confirmedCount?: number
 ): Promise<MutationResponse> {
   return invoke('delete_row', {
     sessionId,
@@ -59,9 +97,96 @@ export async function deleteRow(
     table,
     primaryKey,
     acknowledgedDangerous,
+    returnRows,
+    confirmedCount,
   });
 }
 
+export interface TruncateResponse {
+  success: boolean;
+  result?: QueryResult | null;
+  /** Row count estimated before the truncation, when the driver reports one */
+  removed_rows_estimate?: number | null;
+  error?: string;
+}
+
+export async function truncateTable(
+  sessionId: string,
+  database: string,
+  schema: string | null | undefined,
+  table: string,
+  acknowledgedDangerous?: boolean
+): Promise<TruncateResponse> {
+  return invoke('truncate_table', { sessionId, database, schema, table, acknowledgedDangerous });
+}
+
+export interface UndoOutcome {
+  table: string;
+  /** Operation that was reversed */
+  operation: 'insert' | 'update' | 'delete';
+  /** Entries still on the session's undo stack */
+  remaining: number;
+}
+
+export interface UndoMutationResponse {
+  success: boolean;
+  undone?: UndoOutcome | null;
+  error?: string | null;
+}
+
+/** Reverts the session's most recent row insert, update or delete. */
+export async function undoLastMutation(
+  sessionId: string,
+  acknowledgedDangerous?: boolean
+): Promise<UndoMutationResponse> {
+  return invoke('undo_last_mutation', { sessionId, acknowledgedDangerous });
+}
+
 export async function supportsMutations(sessionId: string): Promise<boolean> {
   return invoke('supports_mutations', { sessionId });
 }
+
+export interface KeyMutationResponse {
+  success: boolean;
+  /** False when the key did not exist (or, for `redisPersist`, had no TTL) */
+  changed?: boolean | null;
+  error?: string | null;
+}
+
+/** Makes a Redis key expire after `seconds`. */
+export async function redisSetTtl(
+  sessionId: string,
+  database: string,
+  key: string,
+  seconds: number,
+  acknowledgedDangerous?: boolean
+): Promise<KeyMutationResponse> {
+  return invoke('redis_set_ttl', { sessionId, database, key, seconds, acknowledgedDangerous });
+}
+
+/** Removes the expiration of a Redis key. */
+export async function redisPersist(
+  sessionId: string,
+  database: string,
+  key: string,
+  acknowledgedDangerous?: boolean
+): Promise<KeyMutationResponse> {
+  return invoke('redis_persist', { sessionId, database, key, acknowledgedDangerous });
+}
+
+/** Renames a Redis key, replacing `newKey` if it exists. */
+export async function redisRenameKey(
+  sessionId: string,
+  database: string,
+  oldKey: string,
+  newKey: string,
+  acknowledgedDangerous?: boolean
+): Promise<KeyMutationResponse> {
+  return invoke('redis_rename_key', {
+    sessionId,
+    database,
+    oldKey,
+    newKey,
+    acknowledgedDangerous,
+  });
+}
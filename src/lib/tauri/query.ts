@@ -2,7 +2,7 @@
 
 import { decode as msgpackDecode } from '@msgpack/msgpack';
 import { Channel } from '@tauri-apps/api/core';
-import { invoke, isWeb, webExecuteQuery } from '@/lib/transport';
+import { invoke, isWeb, listen, type UnlistenFn, webExecuteQuery } from '@/lib/transport';
 import type { ForeignKey } from './schema-browse';
 import type { CollectionList, ColumnInfo, Namespace, QueryResult, Row, Value } from './types';
 
@@ -17,11 +17,13 @@ export interface QueryStreamHandlers {
   onRow?: (row: Row) => void;
   onRowBatch?: (rows: Row[]) => void;
   onError?: (message: string) => void;
+  /** The row cap cut the result short; `done` follows with `returned`. */
+  onTruncated?: (info: { returned: number; cap: number }) => void;
   onDone?: (affectedRows: number) => void;
 }
 
 interface StreamMsgEnvelope {
-  t: 'c' | 'r' | 'rb' | 'e' | 'd';
+  t: 'c' | 'r' | 'rb' | 'e' | 't' | 'd';
   v: unknown;
 }
 
@@ -50,6 +52,9 @@ export function createStreamChannel(handlers: QueryStreamHandlers): Channel<Arra
       case 'e':
         handlers.onError?.(msg.v as string);
         break;
+      case 't':
+        handlers.onTruncated?.(msg.v as { returned: number; cap: number });
+        break;
       case 'd':
         handlers.onDone?.(msg.v as number);
         break;
@@ -69,6 +74,10 @@ export async function executeQuery(
     namespace?: Namespace;
     streamHandlers?: QueryStreamHandlers;
     bypassLimits?: boolean;
+    /** Streamed rows past this count are dropped and the query cancelled. */
+    maxRows?: number;
+    /** Saved connection id, recorded with the query history entry. */
+    connectionId?: string;
   }
 ): Promise<{
   success: boolean;
@@ -95,10 +104,105 @@ export async function executeQuery(
     timeoutMs: options?.timeoutMs,
     stream: options?.stream,
     bypassLimits: options?.bypassLimits,
+    maxRows: options?.maxRows,
+    connectionId: options?.connectionId,
     onStream: channel,
   });
 }
 
+/**
+ * Runs one statement with `params` bound to its placeholders (`$1` on
+ * PostgreSQL, `?` on MySQL/SQLite, `@P1` on SQL Server), so UI-built values
+ * never get spliced into the SQL text.
+ */
+export async function executeParameterized(
+  sessionId: string,
+  query: string,
+  params: Value[],
+  options?: {
+    acknowledgedDangerous?: boolean;
+    namespace?: Namespace;
+  }
+): Promise<{
+  success: boolean;
+  result?: QueryResult;
+  error?: string;
+  query_id?: string;
+}> {
+  return invoke('execute_parameterized', {
+    sessionId,
+    query,
+    params,
+    namespace: options?.namespace,
+    acknowledgedDangerous: options?.acknowledgedDangerous,
+  });
+}
+
+export type QueryState = { state: 'running' } | { state: 'queued'; position: number };
+
+export interface QueryQueue {
+  success: boolean;
+  /** Maximum queries running at once; absent when the session is unlimited. */
+  limit?: number | null;
+  /** Query ids waiting for a slot, next to run first. */
+  queued: string[];
+  error?: string;
+}
+
+export async function getQueryQueue(sessionId: string): Promise<QueryQueue> {
+  return invoke('get_query_queue', { sessionId });
+}
+
+/** `null` once the query has finished. */
+export async function getQueryState(queryId: string): Promise<QueryState | null> {
+  return invoke('get_query_state', { queryId });
+}
+
+export async function setQueryConcurrency(sessionId: string, limit: number): Promise<QueryQueue> {
+  return invoke('set_query_concurrency', { sessionId, limit });
+}
+
+export type LintRule =
+  | 'delete_without_where'
+  | 'update_without_where'
+  | 'select_star'
+  | 'non_sargable_predicate'
+  | 'leading_wildcard_like';
+
+export interface SqlSyntaxError {
+  message: string;
+  /** 1-based; absent when the parser or server gave no position. */
+  line?: number | null;
+  column?: number | null;
+}
+
+export interface LintWarning {
+  rule: LintRule;
+  message: string;
+  statement_index: number;
+}
+
+export interface LintQueryResponse {
+  success: boolean;
+  syntax_errors: SqlSyntaxError[];
+  warnings: LintWarning[];
+  server_validated: boolean;
+  error?: string;
+}
+
+/**
+ * Validates and lints SQL without running it. Pass a session to let the
+ * server check a single statement with PREPARE.
+ */
+export async function lintQuery(
+  query: string,
+  dialect: string,
+  sessionId?: string,
+  namespace?: Namespace
+): Promise<LintQueryResponse> {
+  return invoke('lint_query', { query, dialect, sessionId, namespace });
+}
+
 export async function listNamespaces(sessionId: string): Promise<{
   success: boolean;
   namespaces?: Namespace[];
@@ -107,18 +211,28 @@ export async function listNamespaces(sessionId: string): Promise<{
   return invoke('list_namespaces', { sessionId });
 }
 
+/** Every database of the server, including ones the session is not connected to */
+export async function listDatabases(sessionId: string): Promise<{
+  success: boolean;
+  databases?: string[];
+  error?: string;
+}> {
+  return invoke('list_databases', { sessionId });
+}
+
 export async function listCollections(
   sessionId: string,
   namespace: Namespace,
   search?: string,
   page?: number,
-  page_size?: number
+  page_size?: number,
+  cursor?: string
 ): Promise<{
   success: boolean;
   data?: CollectionList;
   error?: string;
 }> {
-  return invoke('list_collections', { sessionId, namespace, search, page, page_size });
+  return invoke('list_collections', { sessionId, namespace, search, page, page_size, cursor });
 }
 
 export type SortDirection = 'asc' | 'desc';
@@ -150,6 +264,14 @@ export interface ColumnFilter {
   options?: FilterOptions;
 }
 
+/** Value extracted from a JSON column, sortable and filterable by `alias`. */
+export interface ComputedColumn {
+  alias: string;
+  source_column: string;
+  /** `status`, `$.status`, `address.city` or `items[0].sku` */
+  json_path: string;
+}
+
 export interface TableQueryOptions {
   page?: number;
   page_size?: number;
@@ -157,6 +279,7 @@ export interface TableQueryOptions {
   sort_direction?: SortDirection;
   filters?: ColumnFilter[];
   search?: string;
+  computed_columns?: ComputedColumn[];
 }
 
 export interface PaginatedQueryResult {
@@ -164,6 +287,53 @@ export interface PaginatedQueryResult {
   total_rows: number;
   page: number;
   page_size: number;
+  /** Caveats about the rows, e.g. row-level security filtering */
+  notes?: string[];
+}
+
+export type DisplayTransformKind =
+  | { kind: 'epoch_seconds' }
+  | { kind: 'epoch_millis' }
+  | { kind: 'human_bytes' }
+  | { kind: 'enum_labels'; labels: Record<string, string> };
+
+export interface DisplayTransform {
+  database: string;
+  schema?: string | null;
+  table: string;
+  column: string;
+  transform: DisplayTransformKind;
+}
+
+export interface FormattedColumn {
+  column: string;
+  /** Aligned with the result rows; null where the transform doesn't apply. */
+  values: (string | null)[];
+}
+
+export async function listDisplayTransforms(connectionId: string): Promise<{
+  success: boolean;
+  transforms?: DisplayTransform[];
+  error?: string;
+}> {
+  return invoke('list_display_transforms', { connectionId });
+}
+
+export async function setDisplayTransform(
+  connectionId: string,
+  transform: DisplayTransform
+): Promise<{ success: boolean; error?: string }> {
+  return invoke('set_display_transform', { connectionId, transform });
+}
+
+export async function removeDisplayTransform(
+  connectionId: string,
+  database: string,
+  schema: string | null | undefined,
+  table: string,
+  column: string
+): Promise<{ success: boolean; error?: string }> {
+  return invoke('remove_display_transform', { connectionId, database, schema, table, column });
 }
 
 export async function queryTable(
@@ -171,7 +341,8 @@ export async function queryTable(
   namespace: Namespace,
   table: string,
   options: TableQueryOptions = {},
-  bypassCache: boolean = false
+  bypassCache: boolean = false,
+  connectionId?: string
 ): Promise<{
   success: boolean;
   result?: PaginatedQueryResult;
@@ -180,8 +351,10 @@ export async function queryTable(
   cached?: boolean;
   /** Age of the cached entry in milliseconds, when served from cache. */
   cached_age_ms?: number;
+  /** Display-only renderings for columns with a configured transform. */
+  formatted_columns?: FormattedColumn[];
 }> {
-  return invoke('query_table', { sessionId, namespace, table, options, bypassCache });
+  return invoke('query_table', { sessionId, namespace, table, options, bypassCache, connectionId });
 }
 
 export interface CacheConfig {
@@ -212,6 +385,47 @@ export async function getCacheStats(): Promise<CacheStats> {
   return invoke('get_cache_stats');
 }
 
+export interface QueryHistoryEntry {
+  id: string;
+  timestamp: string;
+  connection_id?: string | null;
+  session_id: string;
+  namespace?: Namespace | null;
+  query: string;
+  success: boolean;
+  error?: string | null;
+  duration_ms: number;
+}
+
+export interface QueryHistoryFilter {
+  /** Case-insensitive substring of the query text */
+  search?: string;
+  connection_id?: string;
+  limit?: number;
+}
+
+export interface QueryHistoryConfig {
+  enabled: boolean;
+  maxEntries: number;
+}
+
+/** Searches the query history, newest first. */
+export async function searchHistory(filter?: QueryHistoryFilter): Promise<QueryHistoryEntry[]> {
+  return invoke('search_history', { filter });
+}
+
+export async function clearHistory(): Promise<void> {
+  return invoke('clear_history');
+}
+
+export async function getHistoryConfig(): Promise<QueryHistoryConfig> {
+  return invoke('get_history_config');
+}
+
+export async function setHistoryConfig(config: QueryHistoryConfig): Promise<QueryHistoryConfig> {
+  return invoke('set_history_config', { config });
+}
+
 export async function peekForeignKey(
   sessionId: string,
   namespace: Namespace,
@@ -225,3 +439,205 @@ export async function peekForeignKey(
 }> {
   return invoke('peek_foreign_key', { sessionId, namespace, foreignKey, value, limit });
 }
+
+/**
+ * Runs a single statement as `role` (SET ROLE / EXECUTE AS USER) inside a
+ * transaction that is always rolled back.
+ */
+export async function executeAsRole(
+  sessionId: string,
+  role: string,
+  query: string,
+  namespace?: Namespace,
+  options?: { acknowledgedDangerous?: boolean; queryId?: string }
+): Promise<{
+  success: boolean;
+  result?: QueryResult;
+  error?: string;
+  query_id?: string;
+}> {
+  return invoke('execute_as_role', {
+    sessionId,
+    role,
+    query,
+    namespace,
+    acknowledgedDangerous: options?.acknowledgedDangerous,
+    queryId: options?.queryId,
+  });
+}
+
+export interface BatchStatementResult {
+  index: number;
+  statement: string;
+  success: boolean;
+  result?: QueryResult;
+  error?: string;
+}
+
+export interface BatchResponse {
+  success: boolean;
+  results: BatchStatementResult[];
+  /** Index of the statement that failed; later statements did not run */
+  failed_index?: number;
+  /** True when the batch ran in one transaction, rolled back on failure */
+  transactional: boolean;
+  error?: string;
+}
+
+/**
+ * Splits `script` into statements and runs them in order, in one transaction
+ * where the driver supports it. Stops and rolls back at the first failure.
+ */
+export async function executeBatch(
+  sessionId: string,
+  script: string,
+  namespace?: Namespace,
+  options?: { acknowledgedDangerous?: boolean }
+): Promise<BatchResponse> {
+  return invoke('execute_batch', {
+    sessionId,
+    script,
+    namespace,
+    acknowledgedDangerous: options?.acknowledgedDangerous,
+  });
+}
+
+export type ExplainFormat = 'json' | 'xml' | 'text';
+
+export interface ExplainNode {
+  node_type: string;
+  relation?: string | null;
+  estimated_rows?: number | null;
+  actual_rows?: number | null;
+  /** In the engine's own cost units. */
+  estimated_cost?: number | null;
+  actual_time_ms?: number | null;
+  children: ExplainNode[];
+}
+
+export interface ExplainPlan {
+  /** Plan as returned by the server, in `format`. */
+  raw: string;
+  format: ExplainFormat;
+  /** `null` when the driver only returns a text plan. */
+  root?: ExplainNode | null;
+  analyzed: boolean;
+}
+
+/**
+ * Execution plan of `query`. `analyze` runs the query to collect actual rows
+ * and timings, so it is subject to read-only mode and safety rules.
+ */
+export async function explainQuery(
+  sessionId: string,
+  query: string,
+  namespace?: Namespace,
+  options?: { analyze?: boolean; acknowledgedDangerous?: boolean }
+): Promise<{
+  success: boolean;
+  plan?: ExplainPlan;
+  error?: string;
+}> {
+  return invoke('explain_query', {
+    sessionId,
+    query,
+    namespace,
+    analyze: options?.analyze,
+    acknowledgedDangerous: options?.acknowledgedDangerous,
+  });
+}
+
+/**
+ * Runs a MongoDB aggregation pipeline (JSON array of stages) on `collection`.
+ * Columns are the top-level keys of the returned documents. `$out` and
+ * `$merge` are refused on read-only connections.
+ */
+export async function runAggregation(
+  sessionId: string,
+  namespace: Namespace,
+  collection: string,
+  pipeline: string,
+  options?: { acknowledgedDangerous?: boolean; queryId?: string }
+): Promise<{
+  success: boolean;
+  result?: QueryResult;
+  error?: string;
+  query_id?: string;
+}> {
+  return invoke('run_aggregation', {
+    sessionId,
+    namespace,
+    collection,
+    pipeline,
+    acknowledgedDangerous: options?.acknowledgedDangerous,
+    queryId: options?.queryId,
+  });
+}
+
+/**
+ * Turns row-level security off for every statement of the session (superuser
+ * or BYPASSRLS role), or back on with `bypass = false`. Postgres only.
+ */
+export async function setRowSecurityBypass(
+  sessionId: string,
+  bypass: boolean
+): Promise<{ success: boolean; error?: string }> {
+  return invoke('set_row_security_bypass', { sessionId, bypass });
+}
+
+/**
+ * Re-expresses a PostGIS value from a result grid (WKT, EWKT or hex EWKB) as
+ * a GeoJSON document string.
+ */
+export async function geometryToGeoJson(
+  sessionId: string,
+  geometry: string
+): Promise<{ success: boolean; geojson?: string; error?: string }> {
+  return invoke('geometry_to_geojson', { sessionId, geometry });
+}
+
+export interface DbNotification {
+  channel: string;
+  payload: string;
+  /** Backend process that sent the notification. */
+  process_id: number;
+}
+
+export type DbNotificationEvent =
+  | { kind: 'received'; notification: DbNotification }
+  | { kind: 'failed'; error: string };
+
+interface DbNotificationPayload {
+  session_id: string;
+  channel: string;
+  event: DbNotificationEvent;
+}
+
+/**
+ * Subscribes the session to `LISTEN channel` on a dedicated connection. Use
+ * {@link onDbNotification} to receive notifications and {@link unlistenChannel} to stop.
+ */
+export async function listenChannel(
+  sessionId: string,
+  channel: string
+): Promise<{ success: boolean; error?: string }> {
+  return invoke('listen', { sessionId, channel });
+}
+
+export async function unlistenChannel(
+  sessionId: string,
+  channel: string
+): Promise<{ success: boolean; error?: string }> {
+  return invoke('unlisten', { sessionId, channel });
+}
+
+export async function onDbNotification(
+  sessionId: string,
+  onEvent: (channel: string, event: DbNotificationEvent) => void
+): Promise<UnlistenFn> {
+  return listen<DbNotificationPayload>('db-notification', payload => {
+    if (payload.payload.session_id === sessionId) {
+      onEvent(payload.payload.channel, payload.payload.event);
+    }
+  });
+}
@@ -2,6 +2,7 @@
 
 import { invoke } from '@/lib/transport';
 import type { Namespace, QueryResult, Value } from './types';
+import type { SortDirection } from './query';
 
 export interface CollationInfo {
   name: string;
@@ -50,6 +51,30 @@ export async function dropDatabase(
   return invoke('drop_database', { sessionId, name, acknowledgedDangerous });
 }
 
+/** Attaches a database file (or `:memory:`) to the session under `alias`. */
+export async function attachDatabase(
+  sessionId: string,
+  filePath: string,
+  alias: string
+): Promise<{
+  success: boolean;
+  error?: string;
+}> {
+  return invoke('attach_database', { sessionId, filePath, alias });
+}
+
+/** Exposes a Parquet, CSV or JSON file as a queryable view (DuckDB only). */
+export async function registerFileAsView(
+  sessionId: string,
+  filePath: string,
+  viewName: string
+): Promise<{
+  success: boolean;
+  error?: string;
+}> {
+  return invoke('register_file_as_view', { sessionId, filePath, viewName });
+}
+
 export interface ForeignKey {
   column: string;
   referenced_table: string;
@@ -83,7 +108,53 @@ export interface TableSchema {
   primary_key?: string[];
   foreign_keys: ForeignKey[];
   row_count_estimate?: number | null;
+  /** True when `row_count_estimate` comes from statistics, not an exact count */
+  row_count_is_estimate?: boolean;
   indexes: TableIndex[];
+  partitioning?: TablePartitioning;
+  row_security?: TableRowSecurity;
+}
+
+export type PartitionStrategy = 'range' | 'list' | 'hash';
+
+export interface TablePartition {
+  schema: string;
+  name: string;
+  /** Bound as printed by the server, e.g. `FOR VALUES FROM (...) TO (...)` or `DEFAULT` */
+  bound: string;
+  is_partitioned: boolean;
+}
+
+export interface TablePartitioning {
+  strategy: PartitionStrategy;
+  key: string;
+  partitions: TablePartition[];
+}
+
+export interface ColumnStats {
+  column: string;
+  total_rows: number;
+  null_count: number;
+  distinct_count: number;
+  min: Value;
+  max: Value;
+  /** Only computed for numeric columns */
+  avg: number | null;
+}
+
+export interface TableRowSecurity {
+  enabled: boolean;
+  /** Policies also apply to the table owner */
+  forced: boolean;
+  policies: string[];
+  /** Policies filter the rows this session reads */
+  filtering: boolean;
+}
+
+/** Members of an ENUM (pick one) or SET (pick several) column */
+export interface AllowedValues {
+  values: string[];
+  multiple: boolean;
 }
 
 export interface TableColumn {
@@ -93,6 +164,12 @@ export interface TableColumn {
   default_value?: string;
   is_primary_key: boolean;
   is_auto_increment?: boolean;
+  /** Computed / generated column: the database fills it and rejects explicit values */
+  is_computed?: boolean;
+  computed_definition?: string;
+  is_sparse?: boolean;
+  is_column_set?: boolean;
+  allowed_values?: AllowedValues;
 }
 
 export type CancelSupport = 'none' | 'best_effort' | 'driver';
@@ -106,6 +183,23 @@ export interface DriverCapabilities {
   streaming: boolean;
   explain: boolean;
   maintenance: boolean;
+  index_usage: boolean;
+  key_metadata: boolean;
+  big_keys: boolean;
+  key_management: boolean;
+  computed_columns: boolean;
+  copy_export: boolean;
+  bloat_report: boolean;
+  syntax_validation: boolean;
+  partitions: boolean;
+  upsert: boolean;
+  query_progress: boolean;
+  execute_as_role: boolean;
+  row_security: boolean;
+  scheduled_jobs: boolean;
+  server_log: boolean;
+  aggregation: boolean;
+  notifications: boolean;
 }
 
 export interface DriverInfo {
@@ -118,13 +212,75 @@ export async function describeTable(
   sessionId: string,
   namespace: Namespace,
   table: string,
-  connectionId?: string
+  connectionId?: string,
+  fastEstimate?: boolean
 ): Promise<{
   success: boolean;
   schema?: TableSchema;
   error?: string;
 }> {
-  return invoke('describe_table', { sessionId, namespace, table, connectionId });
+  return invoke('describe_table', { sessionId, namespace, table, connectionId, fastEstimate });
+}
+
+export async function getTablePartitions(
+  sessionId: string,
+  namespace: Namespace,
+  table: string
+): Promise<{
+  success: boolean;
+  partitioning?: TablePartitioning | null;
+  error?: string;
+}> {
+  return invoke('get_table_partitions', { sessionId, namespace, table });
+}
+
+export async function getTableDdl(
+  sessionId: string,
+  namespace: Namespace,
+  table: string
+): Promise<{
+  success: boolean;
+  ddl?: string;
+  error?: string;
+}> {
+  return invoke('get_table_ddl', { sessionId, namespace, table });
+}
+
+export async function getColumnStats(
+  sessionId: string,
+  namespace: Namespace,
+  table: string,
+  column: string
+): Promise<{
+  success: boolean;
+  stats?: ColumnStats;
+  error?: string;
+}> {
+  return invoke('get_column_stats', { sessionId, namespace, table, column });
+}
+
+export interface IndexColumnSpec {
+  name: string;
+  direction?: SortDirection;
+}
+
+export interface IndexSpec {
+  name: string;
+  columns: IndexColumnSpec[];
+  unique?: boolean;
+}
+
+export async function createIndex(
+  sessionId: string,
+  namespace: Namespace,
+  table: string,
+  spec: IndexSpec,
+  acknowledgedDangerous = false
+): Promise<{
+  success: boolean;
+  error?: string;
+}> {
+  return invoke('create_index', { sessionId, namespace, table, spec, acknowledgedDangerous });
 }
 
 export async function previewTable(
@@ -164,16 +320,20 @@ export async function listVirtualRelations(connectionId: string): Promise<{
 
 export async function addVirtualRelation(
   connectionId: string,
-  relation: VirtualRelation
+  relation: VirtualRelation,
+  sessionId?: string,
+  force = false
 ): Promise<{ success: boolean; error?: string }> {
-  return invoke('add_virtual_relation', { connectionId, relation });
+  return invoke('add_virtual_relation', { connectionId, relation, sessionId, force });
 }
 
 export async function updateVirtualRelation(
   connectionId: string,
-  relation: VirtualRelation
+  relation: VirtualRelation,
+  sessionId?: string,
+  force = false
 ): Promise<{ success: boolean; error?: string }> {
-  return invoke('update_virtual_relation', { connectionId, relation });
+  return invoke('update_virtual_relation', { connectionId, relation, sessionId, force });
 }
 
 export async function deleteVirtualRelation(
@@ -310,6 +310,77 @@ export async function dropEvent(
   });
 }
 
+export type JobScheduler = 'pg_cron' | 'mysql_events' | 'sql_server_agent';
+
+export interface ScheduledJob {
+  /** Identifier to pass back to enable/disable/run-now */
+  id: string;
+  name: string;
+  database?: string | null;
+  schedule?: string | null;
+  command?: string | null;
+  last_run?: string | null;
+  next_run?: string | null;
+  enabled: boolean;
+}
+
+export interface ScheduledJobList {
+  /** `null` when no scheduler is installed or visible */
+  scheduler: JobScheduler | null;
+  jobs: ScheduledJob[];
+}
+
+export interface ScheduledJobOperationResult {
+  success: boolean;
+  executed_command: string;
+  message?: string;
+  execution_time_ms: number;
+}
+
+export async function listScheduledJobs(sessionId: string): Promise<{
+  success: boolean;
+  data?: ScheduledJobList;
+  error?: string;
+}> {
+  return invoke('list_scheduled_jobs', { sessionId });
+}
+
+export async function setScheduledJobEnabled(
+  sessionId: string,
+  job: Pick<ScheduledJob, 'id' | 'database'>,
+  enabled: boolean,
+  acknowledgedDangerous?: boolean
+): Promise<{
+  success: boolean;
+  result?: ScheduledJobOperationResult;
+  error?: string;
+}> {
+  return invoke('set_scheduled_job_enabled', {
+    sessionId,
+    jobId: job.id,
+    database: job.database,
+    enabled,
+    acknowledgedDangerous,
+  });
+}
+
+export async function runScheduledJob(
+  sessionId: string,
+  job: Pick<ScheduledJob, 'id' | 'database'>,
+  acknowledgedDangerous?: boolean
+): Promise<{
+  success: boolean;
+  result?: ScheduledJobOperationResult;
+  error?: string;
+}> {
+  return invoke('run_scheduled_job', {
+    sessionId,
+    jobId: job.id,
+    database: job.database,
+    acknowledgedDangerous,
+  });
+}
+
 export interface Sequence {
   namespace: Namespace;
   name: string;
@@ -408,3 +479,14 @@ export async function cancelQuery(
 }> {
   return invoke('cancel_query', { sessionId, queryId });
 }
+
+/** Cancels every queued and running query of a session */
+export async function cancelSessionQueries(sessionId: string): Promise<{
+  success: boolean;
+  cancelled: number;
+  /** Running queries the driver cannot cancel */
+  not_cancellable: number;
+  error?: string;
+}> {
+  return invoke('cancel_session_queries', { sessionId });
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-import { invoke } from '@/lib/transport';
+import { invoke, listen, type UnlistenFn } from '@/lib/transport';
 import type { Namespace, Value } from './types';
 
 export interface FulltextMatch {
@@ -8,6 +8,8 @@ export interface FulltextMatch {
   table_name: string;
   column_name: string;
   value_preview: string;
+  /** Character offset of the first occurrence of the term in the value */
+  match_offset: number;
   row_preview: [string, Value][];
 }
 
@@ -50,3 +52,57 @@ export async function fulltextSearch(
 ): Promise<FulltextSearchResponse> {
   return invoke('fulltext_search', { sessionId, searchTerm, options });
 }
+
+export interface SearchProgress {
+  tables_searched: number;
+  total_tables: number;
+  matches_found: number;
+  current_table?: string | null;
+}
+
+export type FulltextSearchEvent =
+  | { kind: 'matches'; matches: FulltextMatch[] }
+  | ({ kind: 'progress' } & SearchProgress)
+  | {
+      kind: 'done';
+      total_matches: number;
+      tables_searched: number;
+      search_time_ms: number;
+      truncated: boolean;
+      cancelled: boolean;
+      stats: SearchStats;
+    }
+  | { kind: 'failed'; error: string };
+
+interface FulltextSearchPayload {
+  search_id: string;
+  event: FulltextSearchEvent;
+}
+
+/**
+ * Starts a search whose results arrive table by table. Use
+ * {@link listenFulltextSearch} to receive them and {@link cancelFulltextSearch} to stop.
+ */
+export async function fulltextSearchStream(
+  sessionId: string,
+  searchId: string,
+  searchTerm: string,
+  options?: FulltextSearchOptions
+): Promise<{ success: boolean; error?: string | null }> {
+  return invoke('fulltext_search_stream', { sessionId, searchId, searchTerm, options });
+}
+
+export async function cancelFulltextSearch(searchId: string): Promise<boolean> {
+  return invoke<boolean>('cancel_fulltext_search', { searchId });
+}
+
+export async function listenFulltextSearch(
+  searchId: string,
+  onEvent: (event: FulltextSearchEvent) => void
+): Promise<UnlistenFn> {
+  return listen<FulltextSearchPayload>('fulltext-search', payload => {
+    if (payload.payload.search_id === searchId) {
+      onEvent(payload.payload.event);
+    }
+  });
+}
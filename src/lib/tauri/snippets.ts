@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0
+
+import { invoke } from '@/lib/transport';
+import type { Value } from './types';
+
+export type SnippetParamKind = 'value' | 'identifier';
+
+export interface SnippetParam {
+  /** Name used in the `{{name}}` placeholder */
+  name: string;
+  /** `identifier` values are quoted as names, `value` ones as literals */
+  kind?: SnippetParamKind;
+  description?: string | null;
+  /** Used when no value is given */
+  default?: Value | null;
+}
+
+export interface Snippet {
+  /** Empty for a snippet that was never saved */
+  id: string;
+  name: string;
+  /** SQL with `{{param}}` placeholders */
+  body: string;
+  driver_id: string;
+  params: SnippetParam[];
+  created_at?: string;
+  updated_at?: string;
+}
+
+export async function listSnippets(): Promise<{
+  success: boolean;
+  snippets: Snippet[];
+  error?: string | null;
+}> {
+  return invoke('list_snippets');
+}
+
+/** Creates the snippet when `id` is empty, otherwise updates it. */
+export async function saveSnippet(snippet: Snippet): Promise<{
+  success: boolean;
+  snippet?: Snippet | null;
+  error?: string | null;
+}> {
+  return invoke('save_snippet', { snippet });
+}
+
+export async function deleteSnippet(
+  id: string
+): Promise<{ success: boolean; error?: string | null }> {
+  return invoke('delete_snippet', { id });
+}
+
+/** Substitutes `values` into the snippet's placeholders, escaped for its driver. */
+export async function renderSnippet(
+  id: string,
+  values: Record<string, Value>
+): Promise<{ success: boolean; sql?: string | null; error?: string | null }> {
+  return invoke('render_snippet', { id, values });
+}
@@ -4,6 +4,17 @@ export type Environment = 'development' | 'staging' | 'production';
 
 export type MssqlAuthMode = 'sql_password' | 'windows_ntlm' | 'windows_integrated';
 
+/** `full` checks the certificate chain and host name, `ca_only` the chain only */
+export type TlsVerifyMode = 'full' | 'ca_only' | 'none';
+
+export interface TlsConfig {
+  ca_cert_path?: string;
+  client_cert_path?: string;
+  client_key_path?: string;
+  /** Unset: no verification on development connections, full verification elsewhere */
+  verify_mode?: TlsVerifyMode;
+}
+
 export interface ConnectionConfig {
   driver: string;
   host: string;
@@ -27,7 +38,28 @@ export interface ConnectionConfig {
   search_auth_mode?: SearchAuthMode;
   /** Path to a custom CA certificate (PEM) for TLS verification. */
   ssl_ca_cert?: string;
-}
+  /** Certificates and server verification of TLS connections (Postgres, MySQL, SQL Server) */
+  tls?: TlsConfig;
+  /** Driver-specific settings (e.g. DuckDB httpfs / S3 credentials). */
+  extra_params?: Record<string, string>;
+  /** Session-level statement timeout applied right after connect (ms). */
+  statement_timeout_ms?: number;
+  /** Session-level lock-wait timeout applied right after connect (ms). */
+  lock_timeout_ms?: number;
+  /** Default isolation level for every transaction on the connection. */
+  default_isolation_level?: IsolationLevel;
+  /** Retry the initial connection for up to this many seconds while the server is starting. */
+  wait_for_ready_secs?: number;
+  /** Cancel and fail a query still running after this many seconds. */
+  query_timeout_secs?: number;
+}
+
+export type IsolationLevel =
+  | 'read_uncommitted'
+  | 'read_committed'
+  | 'repeatable_read'
+  | 'serializable'
+  | 'snapshot';
 
 export type SearchAuthMode = 'none' | 'basic' | 'api_key' | 'bearer';
 
@@ -71,6 +103,9 @@ export interface ConnectionResponse {
 export interface SessionListItem {
   id: string;
   display_name: string;
+  statement_timeout_ms?: number | null;
+  lock_timeout_ms?: number | null;
+  default_isolation_level?: IsolationLevel | null;
 }
 
 export interface SavedConnection {
@@ -96,6 +131,20 @@ export interface SavedConnection {
   search_auth_mode?: SearchAuthMode;
   /** Path to a custom CA certificate (PEM) for TLS verification. */
   ssl_ca_cert?: string;
+  /** Certificates and server verification of TLS connections (Postgres, MySQL, SQL Server) */
+  tls?: TlsConfig;
+  /** Driver-specific settings (e.g. DuckDB httpfs / S3 credentials). */
+  extra_params?: Record<string, string>;
+  /** Session-level statement timeout applied right after connect (ms). */
+  statement_timeout_ms?: number;
+  /** Session-level lock-wait timeout applied right after connect (ms). */
+  lock_timeout_ms?: number;
+  /** Default isolation level for every transaction on the connection. */
+  default_isolation_level?: IsolationLevel;
+  /** Retry the initial connection for up to this many seconds while the server is starting. */
+  wait_for_ready_secs?: number;
+  /** Cancel and fail a query still running after this many seconds. */
+  query_timeout_secs?: number;
   ssh_tunnel?: {
     host: string;
     port: number;
@@ -128,10 +177,23 @@ export interface VaultResponse {
   error?: string;
 }
 
+/** What preflight does with dangerous SQL (DROP, TRUNCATE, unqualified UPDATE/DELETE) */
+export type DangerousSqlAction = 'allow' | 'warn' | 'confirm' | 'block';
+
+export interface EnvironmentGuards {
+  development: DangerousSqlAction;
+  staging: DangerousSqlAction;
+  production: DangerousSqlAction;
+}
+
 export interface SafetyPolicy {
   prod_require_confirmation: boolean;
   prod_block_dangerous_sql: boolean;
   query_rate_limit_enabled?: boolean;
+  /** Directory database files may be attached from; unset disables file attachments */
+  attach_allowed_dir?: string | null;
+  /** Dangerous SQL handling per connection environment */
+  environment_guards?: EnvironmentGuards;
 }
 
 export interface SafetyPolicyResponse {
@@ -149,17 +211,23 @@ export interface Collection {
   namespace: Namespace;
   name: string;
   collection_type: 'Table' | 'View' | 'MaterializedView' | 'Collection';
+  /** Parent table name when this table is a partition */
+  partition_of?: string;
 }
 
 export interface CollectionListOptions {
   search?: string;
   page?: number;
   page_size?: number;
+  /** Resume token from `CollectionList.next_cursor`; `"0"` starts a new scan */
+  cursor?: string;
 }
 
 export interface CollectionList {
   collections: Collection[];
+  /** Estimated when paging by cursor */
   total_count: number;
+  next_cursor?: string | null;
 }
 
 export interface QueryResult {
@@ -174,6 +242,8 @@ export interface ColumnInfo {
   name: string;
   data_type: string;
   nullable: boolean;
+  /** Comparisons ignore case (Postgres `citext`); search/filter accordingly. */
+  case_insensitive?: boolean;
 }
 
 export type Row = { values: Value[] };
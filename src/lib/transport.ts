@@ -328,6 +328,9 @@ function dispatchEvent(
     case 'rows':
       handlers.onRowBatch?.(parsed as never);
       break;
+    case 'truncated':
+      handlers.onTruncated?.(parsed as { returned: number; cap: number });
+      break;
     case 'done':
       handlers.onDone?.(parsed as number);
       break;
@@ -11,6 +11,10 @@ import {
 
 const STORAGE_KEY = 'qoredb_ai_provider';
 const SAMPLE_ROWS_STORAGE_KEY = 'qoredb_ai_sample_rows';
+const REDACT_PATTERNS_STORAGE_KEY = 'qoredb_ai_redact_patterns';
+
+/** Masked on top of the backend's built-in PII list (email, phone, ssn, ...). */
+const DEFAULT_REDACT_PATTERNS = ['name'];
 
 export interface AiPreferencesContextValue {
   preferredProvider: AiProvider;
@@ -21,6 +25,8 @@ export interface AiPreferencesContextValue {
   getConfig: () => AiConfig;
   includeSampleRows: boolean;
   setIncludeSampleRows: (enabled: boolean) => void;
+  redactPatterns: string[];
+  setRedactPatterns: (patterns: string[]) => void;
 }
 
 const AiPreferencesContext = createContext<AiPreferencesContextValue | null>(null);
@@ -45,11 +51,27 @@ function loadSampleRowsPreference(): boolean {
   }
 }
 
+function loadRedactPatterns(): string[] {
+  try {
+    const saved = localStorage.getItem(REDACT_PATTERNS_STORAGE_KEY);
+    if (saved) {
+      const parsed: unknown = JSON.parse(saved);
+      if (Array.isArray(parsed)) {
+        return parsed.filter((p): p is string => typeof p === 'string');
+      }
+    }
+  } catch {
+    // ignore
+  }
+  return DEFAULT_REDACT_PATTERNS;
+}
+
 export function AiPreferencesProvider({ children }: { children: ReactNode }) {
   const [preferredProvider, setPreferredProviderState] = useState<AiProvider>(loadSavedProvider);
   const [providerStatuses, setProviderStatuses] = useState<AiProviderStatus[]>([]);
   const [includeSampleRows, setIncludeSampleRowsState] =
     useState<boolean>(loadSampleRowsPreference);
+  const [redactPatterns, setRedactPatternsState] = useState<string[]>(loadRedactPatterns);
 
   const refreshStatuses = useCallback(async () => {
     try {
@@ -74,6 +96,11 @@ export function AiPreferencesProvider({ children }: { children: ReactNode }) {
     localStorage.setItem(SAMPLE_ROWS_STORAGE_KEY, String(enabled));
   }, []);
 
+  const setRedactPatterns = useCallback((patterns: string[]) => {
+    setRedactPatternsState(patterns);
+    localStorage.setItem(REDACT_PATTERNS_STORAGE_KEY, JSON.stringify(patterns));
+  }, []);
+
   const providerInfo = AI_PROVIDERS.find(p => p.id === preferredProvider);
   const isReady =
     (providerInfo && !providerInfo.requiresKey) ||
@@ -95,6 +122,8 @@ export function AiPreferencesProvider({ children }: { children: ReactNode }) {
         getConfig,
         includeSampleRows,
         setIncludeSampleRows,
+        redactPatterns,
+        setRedactPatterns,
       }}
     >
       {children}
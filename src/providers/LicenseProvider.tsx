@@ -43,12 +43,14 @@ const DEFAULT_STATUS: LicenseStatus = {
   is_expired: false,
   seats: null,
   is_founder: false,
+  in_grace_period: false,
+  grace_expires_at: null,
 };
 
 const LicenseContext = createContext<LicenseContextValue | null>(null);
 
 function activePaidTier(status: LicenseStatus): Exclude<LicenseTier, 'core'> | null {
-  if (status.tier === 'core' || status.is_expired) return null;
+  if (status.tier === 'core' || (status.is_expired && !status.in_grace_period)) return null;
   return status.tier;
 }
 